@@ -4,6 +4,7 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use thiserror::Error;
 
+use hallucinator_pdf::identifiers::ArxivId;
 use hallucinator_pdf::{ExtractionResult, Reference, SkipStats};
 
 #[derive(Error, Debug)]
@@ -59,8 +60,10 @@ pub fn extract_references_from_bbl_str(content: &str) -> Result<ExtractionResult
                     authors: vec![],
                     doi: None,
                     arxiv_id: None,
+                    arxiv_version: None,
                     original_number: raw_idx + 1,
                     skip_reason: Some("no_title".to_string()),
+                    doi_only: false,
                 });
                 continue;
             }
@@ -72,8 +75,10 @@ pub fn extract_references_from_bbl_str(content: &str) -> Result<ExtractionResult
                     authors: vec![],
                     doi: None,
                     arxiv_id: None,
+                    arxiv_version: None,
                     original_number: raw_idx + 1,
                     skip_reason: Some("short_title".to_string()),
+                    doi_only: false,
                 });
                 continue;
             }
@@ -85,8 +90,10 @@ pub fn extract_references_from_bbl_str(content: &str) -> Result<ExtractionResult
                     authors: vec![],
                     doi: None,
                     arxiv_id: None,
+                    arxiv_version: None,
                     original_number: raw_idx + 1,
                     skip_reason: Some("no_title".to_string()),
+                    doi_only: false,
                 });
                 continue;
             }
@@ -112,30 +119,36 @@ pub fn extract_references_from_bbl_str(content: &str) -> Result<ExtractionResult
                 authors,
                 doi: None,
                 arxiv_id: None,
+                arxiv_version: None,
                 original_number: raw_idx + 1,
                 skip_reason: Some("url_only".to_string()),
+                doi_only: false,
             });
             continue;
         }
 
         // Extract identifiers
         let doi = extract_doi_from_bbl(entry);
-        let arxiv_id = hallucinator_pdf::identifiers::extract_arxiv_id(entry);
+        let arxiv = hallucinator_pdf::identifiers::extract_arxiv(entry);
 
         references.push(Reference {
             raw_citation,
             title: Some(title),
             authors,
             doi,
-            arxiv_id,
+            arxiv_version: arxiv.as_ref().and_then(|a| a.version),
+            arxiv_id: arxiv.map(|a| a.id),
             original_number: raw_idx + 1,
             skip_reason: None,
+            doi_only: false,
         });
     }
 
     Ok(ExtractionResult {
         references,
         skip_stats: stats,
+        ocr_used: false,
+        ocr_error: None,
     })
 }
 
@@ -238,8 +251,10 @@ fn process_bib_entries(entries: &[&biblatex::Entry]) -> ExtractionResult {
                     authors: vec![],
                     doi: None,
                     arxiv_id: None,
+                    arxiv_version: None,
                     original_number: raw_idx + 1,
                     skip_reason: Some("no_title".to_string()),
+                    doi_only: false,
                 });
                 continue;
             }
@@ -251,8 +266,10 @@ fn process_bib_entries(entries: &[&biblatex::Entry]) -> ExtractionResult {
                     authors: vec![],
                     doi: None,
                     arxiv_id: None,
+                    arxiv_version: None,
                     original_number: raw_idx + 1,
                     skip_reason: Some("short_title".to_string()),
+                    doi_only: false,
                 });
                 continue;
             }
@@ -264,8 +281,10 @@ fn process_bib_entries(entries: &[&biblatex::Entry]) -> ExtractionResult {
                     authors: vec![],
                     doi: None,
                     arxiv_id: None,
+                    arxiv_version: None,
                     original_number: raw_idx + 1,
                     skip_reason: Some("no_title".to_string()),
+                    doi_only: false,
                 });
                 continue;
             }
@@ -294,7 +313,7 @@ fn process_bib_entries(entries: &[&biblatex::Entry]) -> ExtractionResult {
             .and_then(|d| hallucinator_pdf::identifiers::extract_doi(&d));
 
         // Extract arXiv ID from eprint field or journal field
-        let arxiv_id = extract_arxiv_from_bib_entry(entry);
+        let arxiv = extract_arxiv_from_bib_entry(entry);
 
         // Build raw citation for display
         let mut raw_parts = Vec::new();
@@ -324,15 +343,19 @@ fn process_bib_entries(entries: &[&biblatex::Entry]) -> ExtractionResult {
             title: Some(title),
             authors,
             doi,
-            arxiv_id,
+            arxiv_version: arxiv.as_ref().and_then(|a| a.version),
+            arxiv_id: arxiv.map(|a| a.id),
             original_number: raw_idx + 1,
             skip_reason: None,
+            doi_only: false,
         });
     }
 
     ExtractionResult {
         references,
         skip_stats: stats,
+        ocr_used: false,
+        ocr_error: None,
     }
 }
 
@@ -368,7 +391,7 @@ fn format_bib_person(p: &biblatex::Person) -> String {
 }
 
 /// Extract arXiv ID from a .bib entry's `eprint` or `journal` field.
-fn extract_arxiv_from_bib_entry(entry: &biblatex::Entry) -> Option<String> {
+fn extract_arxiv_from_bib_entry(entry: &biblatex::Entry) -> Option<ArxivId> {
     // Check eprint field
     if let Some(eprint_chunks) = entry.get("eprint") {
         let eprint = chunks_to_string(eprint_chunks);
@@ -380,14 +403,14 @@ fn extract_arxiv_from_bib_entry(entry: &biblatex::Entry) -> Option<String> {
                 .map(chunks_to_string)
                 .unwrap_or_default();
             if prefix.is_empty() || prefix.eq_ignore_ascii_case("arxiv") {
-                if let Some(id) = hallucinator_pdf::identifiers::extract_arxiv_id(&eprint) {
+                if let Some(id) = hallucinator_pdf::identifiers::extract_arxiv(&eprint) {
                     return Some(id);
                 }
                 // Some .bib files have bare IDs like "2403.10573"
                 static BARE_ARXIV: Lazy<Regex> =
                     Lazy::new(|| Regex::new(r"^\d{4}\.\d{4,5}(v\d+)?$").unwrap());
                 if BARE_ARXIV.is_match(&eprint) {
-                    return Some(eprint);
+                    return Some(ArxivId::parse(&eprint));
                 }
             }
         }
@@ -396,7 +419,7 @@ fn extract_arxiv_from_bib_entry(entry: &biblatex::Entry) -> Option<String> {
     // Check journal field for "arXiv preprint arXiv:XXXX.XXXXX"
     if let Some(journal_chunks) = entry.get("journal") {
         let journal = chunks_to_string(journal_chunks);
-        if let Some(id) = hallucinator_pdf::identifiers::extract_arxiv_id(&journal) {
+        if let Some(id) = hallucinator_pdf::identifiers::extract_arxiv(&journal) {
             return Some(id);
         }
     }
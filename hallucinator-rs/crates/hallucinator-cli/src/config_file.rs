@@ -0,0 +1,258 @@
+//! `.hallucinator.toml` support for `check`. Uses the same layout as the TUI's
+//! config file, so one file can serve both; sections and keys the CLI has no
+//! use for (like `[display]`) are ignored.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ConfigFile {
+    #[serde(default)]
+    pub api_keys: ApiKeysConfig,
+    #[serde(default)]
+    pub databases: DatabasesConfig,
+    #[serde(default)]
+    pub concurrency: ConcurrencyConfig,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ApiKeysConfig {
+    pub openalex_key: Option<String>,
+    pub s2_api_key: Option<String>,
+    pub ncbi_api_key: Option<String>,
+    pub core_api_key: Option<String>,
+    pub crossref_mailto: Option<String>,
+    pub user_agent: Option<String>,
+    pub proxy: Option<String>,
+    pub extra_ca_cert: Option<PathBuf>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct DatabasesConfig {
+    pub dblp_offline_path: Option<PathBuf>,
+    pub acl_offline_path: Option<PathBuf>,
+    pub cache_path: Option<PathBuf>,
+    /// Databases to disable, like `--disable-dbs`.
+    pub disabled: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ConcurrencyConfig {
+    pub num_workers: Option<usize>,
+    pub db_timeout_secs: Option<u64>,
+    pub db_timeout_short_secs: Option<u64>,
+    /// Timeout in seconds per database name, like `--db-timeout`.
+    pub db_timeouts: Option<HashMap<String, u64>>,
+    pub max_rate_limit_retries: Option<u32>,
+    pub max_references: Option<usize>,
+    /// Look up references with the same title once, like `--dedup`.
+    pub dedup: Option<bool>,
+    /// Adjust each database's concurrent queries, like `--adaptive-concurrency`.
+    pub adaptive_concurrency: Option<bool>,
+}
+
+impl ConfigFile {
+    /// Overwrite `config`'s settings with those the file sets. Empty strings
+    /// count as unset. Like [`EnvConfig::apply`](hallucinator_core::EnvConfig::apply),
+    /// this leaves the query cache and rate limiters to the caller.
+    pub fn apply(&self, config: &mut hallucinator_core::Config) {
+        fn set<T: Clone>(target: &mut T, value: &Option<T>) {
+            if let Some(value) = value {
+                *target = value.clone();
+            }
+        }
+        fn set_some<T: Clone>(target: &mut Option<T>, value: &Option<T>) {
+            if value.is_some() {
+                *target = value.clone();
+            }
+        }
+        fn set_str(target: &mut Option<String>, value: &Option<String>) {
+            set_some(target, &value.clone().filter(|s| !s.is_empty()));
+        }
+        let keys = &self.api_keys;
+        set_str(&mut config.openalex_key, &keys.openalex_key);
+        set_str(&mut config.s2_api_key, &keys.s2_api_key);
+        set_str(&mut config.ncbi_api_key, &keys.ncbi_api_key);
+        set_str(&mut config.core_api_key, &keys.core_api_key);
+        set_str(&mut config.crossref_mailto, &keys.crossref_mailto);
+        set_str(&mut config.user_agent, &keys.user_agent);
+        // reqwest reads the proxy environment itself, so a file proxy only
+        // applies when none of those variables is set.
+        let env_proxy = ["HTTPS_PROXY", "https_proxy", "HTTP_PROXY", "http_proxy"]
+            .iter()
+            .any(|v| std::env::var_os(v).is_some());
+        if !env_proxy {
+            set_str(&mut config.proxy, &keys.proxy);
+        }
+        set_some(&mut config.extra_ca_cert, &keys.extra_ca_cert);
+
+        let dbs = &self.databases;
+        set_some(&mut config.dblp_offline_path, &dbs.dblp_offline_path);
+        set_some(&mut config.acl_offline_path, &dbs.acl_offline_path);
+        set_some(&mut config.cache_path, &dbs.cache_path);
+        set(&mut config.disabled_dbs, &dbs.disabled);
+
+        let conc = &self.concurrency;
+        set(&mut config.num_workers, &conc.num_workers);
+        set(&mut config.db_timeout_secs, &conc.db_timeout_secs);
+        set(
+            &mut config.db_timeout_short_secs,
+            &conc.db_timeout_short_secs,
+        );
+        if let Some(timeouts) = &conc.db_timeouts {
+            config.db_timeouts.extend(
+                timeouts
+                    .iter()
+                    .map(|(name, &secs)| (name.clone(), secs.max(1))),
+            );
+        }
+        set(
+            &mut config.max_rate_limit_retries,
+            &conc.max_rate_limit_retries,
+        );
+        // 0 means unlimited, as in the TUI
+        if let Some(max) = conc.max_references {
+            config.max_references = (max > 0).then_some(max);
+        }
+        set(&mut config.dedup, &conc.dedup);
+        set(&mut config.adaptive_concurrency, &conc.adaptive_concurrency);
+    }
+}
+
+/// Load the config file: `explicit` if given (it must exist), otherwise
+/// `./.hallucinator.toml` over `<config_dir>/hallucinator/config.toml`, as
+/// in the TUI. Either file may be missing; neither yields the defaults.
+pub fn load(explicit: Option<&Path>) -> anyhow::Result<ConfigFile> {
+    if let Some(path) = explicit {
+        return load_from_path(path);
+    }
+    let candidates = dirs::config_dir()
+        .map(|d| d.join("hallucinator").join("config.toml"))
+        .into_iter()
+        .chain(std::iter::once(PathBuf::from(".hallucinator.toml")));
+    let mut config = ConfigFile::default();
+    for path in candidates.filter(|p| p.is_file()) {
+        config = merge(config, load_from_path(&path)?);
+    }
+    Ok(config)
+}
+
+fn load_from_path(path: &Path) -> anyhow::Result<ConfigFile> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Cannot read config file {}: {}", path.display(), e))?;
+    toml::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("Invalid config file {}: {}", path.display(), e))
+}
+
+/// Merge two configs: `overlay` values take precedence over `base`.
+fn merge(base: ConfigFile, overlay: ConfigFile) -> ConfigFile {
+    let (keys, over) = (base.api_keys, overlay.api_keys);
+    let api_keys = ApiKeysConfig {
+        openalex_key: over.openalex_key.or(keys.openalex_key),
+        s2_api_key: over.s2_api_key.or(keys.s2_api_key),
+        ncbi_api_key: over.ncbi_api_key.or(keys.ncbi_api_key),
+        core_api_key: over.core_api_key.or(keys.core_api_key),
+        crossref_mailto: over.crossref_mailto.or(keys.crossref_mailto),
+        user_agent: over.user_agent.or(keys.user_agent),
+        proxy: over.proxy.or(keys.proxy),
+        extra_ca_cert: over.extra_ca_cert.or(keys.extra_ca_cert),
+    };
+    let (dbs, over) = (base.databases, overlay.databases);
+    let databases = DatabasesConfig {
+        dblp_offline_path: over.dblp_offline_path.or(dbs.dblp_offline_path),
+        acl_offline_path: over.acl_offline_path.or(dbs.acl_offline_path),
+        cache_path: over.cache_path.or(dbs.cache_path),
+        disabled: over.disabled.or(dbs.disabled),
+    };
+    let (conc, over) = (base.concurrency, overlay.concurrency);
+    let concurrency = ConcurrencyConfig {
+        num_workers: over.num_workers.or(conc.num_workers),
+        db_timeout_secs: over.db_timeout_secs.or(conc.db_timeout_secs),
+        db_timeout_short_secs: over.db_timeout_short_secs.or(conc.db_timeout_short_secs),
+        db_timeouts: over.db_timeouts.or(conc.db_timeouts),
+        max_rate_limit_retries: over.max_rate_limit_retries.or(conc.max_rate_limit_retries),
+        max_references: over.max_references.or(conc.max_references),
+        dedup: over.dedup.or(conc.dedup),
+        adaptive_concurrency: over.adaptive_concurrency.or(conc.adaptive_concurrency),
+    };
+    ConfigFile {
+        api_keys,
+        databases,
+        concurrency,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(toml_str: &str) -> ConfigFile {
+        toml::from_str(toml_str).unwrap()
+    }
+
+    #[test]
+    fn tui_sections_and_keys_are_ignored() {
+        let parsed = parse(
+            "[api_keys]\ns2_api_key = \"k\"\n\n[display]\ntheme = \"modern\"\n\n\
+             [concurrency]\nnum_workers = 8\nmax_concurrent_papers = 2\n",
+        );
+        assert_eq!(parsed.api_keys.s2_api_key.as_deref(), Some("k"));
+        assert_eq!(parsed.concurrency.num_workers, Some(8));
+    }
+
+    #[test]
+    fn merge_overlay_wins() {
+        let base = parse(
+            "[databases]\ncache_path = \"/base/cache.db\"\ndisabled = [\"NeurIPS\"]\n\n\
+             [concurrency]\nnum_workers = 2\n",
+        );
+        let overlay = parse("[databases]\ncache_path = \"/overlay/cache.db\"\n");
+        let merged = merge(base, overlay);
+        assert_eq!(
+            merged.databases.cache_path,
+            Some(PathBuf::from("/overlay/cache.db"))
+        );
+        // Values the overlay leaves out come from the base
+        assert_eq!(merged.databases.disabled, Some(vec!["NeurIPS".to_string()]));
+        assert_eq!(merged.concurrency.num_workers, Some(2));
+    }
+
+    #[test]
+    fn load_reads_an_explicit_file_and_reports_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("custom.toml");
+        std::fs::write(&path, "[concurrency]\ndedup = true\n").unwrap();
+        assert_eq!(load(Some(&path)).unwrap().concurrency.dedup, Some(true));
+
+        std::fs::write(&path, "[concurrency]\ndedup = \"yes\"\n").unwrap();
+        let err = load(Some(&path)).unwrap_err().to_string();
+        assert!(err.starts_with("Invalid config file"), "{}", err);
+
+        let missing = dir.path().join("missing.toml");
+        assert!(load(Some(&missing)).is_err());
+    }
+
+    #[test]
+    fn apply_overrides_defaults_and_skips_empty_values() {
+        let file = parse(
+            "[api_keys]\ns2_api_key = \"key\"\nopenalex_key = \"\"\n\n\
+             [databases]\ndisabled = [\"DBLP\"]\n\n\
+             [concurrency]\nnum_workers = 8\nmax_references = 0\n\
+             db_timeouts = { arXiv = 0 }\n",
+        );
+        let mut config = hallucinator_core::Config {
+            openalex_key: Some("kept".to_string()),
+            max_references: Some(50),
+            ..Default::default()
+        };
+        file.apply(&mut config);
+        assert_eq!(config.s2_api_key.as_deref(), Some("key"));
+        assert_eq!(config.openalex_key.as_deref(), Some("kept"));
+        assert_eq!(config.disabled_dbs, ["DBLP"]);
+        assert_eq!(config.num_workers, 8);
+        assert_eq!(config.max_references, None);
+        assert_eq!(config.db_timeouts.get("arXiv"), Some(&1));
+    }
+}
@@ -0,0 +1,360 @@
+//! `check --explain N`: a decision trace for a single reference.
+
+use std::io::Write;
+
+use hallucinator_core::authors::{AuthorMatchPolicy, AuthorOverlap};
+use hallucinator_core::matching::{DEFAULT_TITLE_THRESHOLD, title_similarity, titles_match_at};
+use hallucinator_core::{DbResult, DbStatus, Reference, Status, ValidationResult};
+use owo_colors::OwoColorize;
+use serde_json::{Value, json};
+
+use crate::output::{ColorMode, OutputFormat};
+
+pub(crate) fn status_str(status: &Status) -> &'static str {
+    match status {
+        Status::Verified => "verified",
+        Status::NotFound => "not_found",
+        Status::AuthorMismatch => "author_mismatch",
+    }
+}
+
+pub(crate) fn db_status_str(status: &DbStatus) -> &'static str {
+    match status {
+        DbStatus::Match => "match",
+        DbStatus::NoMatch => "no_match",
+        DbStatus::AuthorMismatch => "author_mismatch",
+        DbStatus::Timeout => "timeout",
+        DbStatus::Error => "error",
+        DbStatus::Skipped => "skipped",
+    }
+}
+
+/// One sentence summarizing how the verdict was reached.
+pub fn decision(result: &ValidationResult, policy: AuthorMatchPolicy) -> String {
+    let source = result.source.as_deref().unwrap_or("a database");
+    let mut text = match result.status {
+        Status::Verified if result.ref_authors.is_empty() => format!(
+            "Verified by {}: the title matched and there were no cited authors to compare.",
+            source
+        ),
+        Status::Verified => format!(
+            "Verified by {}: the title matched and at least one cited author appears in its author list.",
+            source
+        ),
+        Status::AuthorMismatch if policy == AuthorMatchPolicy::default() => format!(
+            "{} found the title, but none of the cited authors appear in its author list.",
+            source
+        ),
+        Status::AuthorMismatch => format!(
+            "{} found the title, but its author list falls short of the author policy \
+             (at least {:.0}% of cited authors{}).",
+            source,
+            policy.min_overlap * 100.0,
+            if policy.require_first_author {
+                ", including the first"
+            } else {
+                ""
+            }
+        ),
+        Status::NotFound => "No database returned a record whose title matched.".to_string(),
+    };
+    if result.status == Status::NotFound && !result.failed_dbs.is_empty() {
+        text.push_str(&format!(
+            " {} database(s) failed ({}), so the reference may exist there.",
+            result.failed_dbs.len(),
+            result
+                .failed_dbs
+                .iter()
+                .map(|f| f.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    if result.is_ambiguous() {
+        text.push_str(&format!(
+            " The match was ambiguous: {} other record(s) matched the title about as well.",
+            result.ambiguous_matches().len()
+        ));
+    }
+    text
+}
+
+/// Title similarity and author agreement for one database's answer.
+struct DbScores {
+    similarity: Option<f64>,
+    title_match: Option<bool>,
+    authors_match: Option<bool>,
+    overlap: Option<AuthorOverlap>,
+}
+
+fn scores(
+    title: &str,
+    ref_authors: &[String],
+    db: &DbResult,
+    policy: AuthorMatchPolicy,
+) -> DbScores {
+    let found = db.found_title.as_deref();
+    let overlap = (!ref_authors.is_empty() && !db.found_authors.is_empty())
+        .then(|| policy.overlap(ref_authors, &db.found_authors));
+    DbScores {
+        similarity: found.map(|f| title_similarity(title, f)),
+        title_match: found
+            .map(|f| titles_match_at(title, f, db.threshold.unwrap_or(DEFAULT_TITLE_THRESHOLD))),
+        authors_match: overlap.as_ref().map(|o| policy.accepts_overlap(o)),
+        overlap,
+    }
+}
+
+/// Explain a reference that extraction skipped, so it was never checked.
+pub fn print_skipped(
+    w: &mut dyn Write,
+    number: usize,
+    reason: &str,
+    format: OutputFormat,
+) -> std::io::Result<()> {
+    match format {
+        OutputFormat::Json => {
+            let value = json!({ "reference": number, "skipped": reason });
+            writeln!(w, "{}", serde_json::to_string_pretty(&value)?)
+        }
+        OutputFormat::Jsonl => {
+            let value = json!({ "reference": number, "skipped": reason });
+            writeln!(w, "{}", value)
+        }
+        OutputFormat::Text => writeln!(
+            w,
+            "Reference #{} was skipped during extraction ({}) and was not checked.",
+            number, reason
+        ),
+    }
+}
+
+/// Print the decision trace for one checked reference.
+pub fn print_explanation(
+    w: &mut dyn Write,
+    number: usize,
+    reference: &Reference,
+    result: &ValidationResult,
+    policy: AuthorMatchPolicy,
+    format: OutputFormat,
+    color: ColorMode,
+) -> std::io::Result<()> {
+    match format {
+        OutputFormat::Json => {
+            let value = to_json(number, reference, result, policy);
+            writeln!(w, "{}", serde_json::to_string_pretty(&value)?)
+        }
+        OutputFormat::Jsonl => writeln!(w, "{}", to_json(number, reference, result, policy)),
+        OutputFormat::Text => print_text(w, number, reference, result, policy, color),
+    }
+}
+
+fn to_json(
+    number: usize,
+    reference: &Reference,
+    result: &ValidationResult,
+    policy: AuthorMatchPolicy,
+) -> Value {
+    let databases: Vec<Value> = result
+        .db_results
+        .iter()
+        .map(|db| {
+            let s = scores(&result.title, &result.ref_authors, db, policy);
+            json!({
+                "db": db.db_name,
+                "status": db_status_str(&db.status),
+                "elapsed_ms": db.elapsed.map(|d| d.as_millis() as u64),
+                "found_title": db.found_title,
+                "similarity": s.similarity,
+                "threshold": db.threshold,
+                "title_match": s.title_match,
+                "found_authors": db.found_authors,
+                "authors_match": s.authors_match,
+                "author_overlap": s.overlap.map(|o| json!({
+                    "matched": o.matched,
+                    "cited": o.cited,
+                    "first_author": o.first_author,
+                })),
+                "url": db.paper_url,
+                "error": db.error_message,
+                "alternates": db.alternates.len(),
+            })
+        })
+        .collect();
+
+    json!({
+        "reference": number,
+        "title": result.title,
+        "authors": result.ref_authors,
+        "doi": reference.doi,
+        "arxiv_id": reference.arxiv_id,
+        "status": status_str(&result.status),
+        "source": result.source,
+        "agreeing_dbs": result.agreeing_dbs,
+        "reason": result.reason.map(|r| r.as_str()),
+        "reason_description": result.reason.map(|r| r.description()),
+        "decision": decision(result, policy),
+        "failed_dbs": result.failed_db_names(),
+        "failed_db_reasons": result
+            .failed_dbs
+            .iter()
+            .map(|f| json!({"name": f.name, "reason": f.reason.as_str()}))
+            .collect::<Vec<_>>(),
+        "databases": databases,
+        "doi_info": result.doi_info.as_ref().map(|d| json!({
+            "doi": d.doi,
+            "valid": d.valid,
+            "title": d.title,
+            "title_mismatch": d.title_mismatch,
+        })),
+        "retracted": result.retraction_info.as_ref().is_some_and(|r| r.is_retracted),
+    })
+}
+
+fn print_text(
+    w: &mut dyn Write,
+    number: usize,
+    reference: &Reference,
+    result: &ValidationResult,
+    policy: AuthorMatchPolicy,
+    color: ColorMode,
+) -> std::io::Result<()> {
+    let heading = format!("Reference #{}", number);
+    if color.enabled() {
+        writeln!(w, "{}", heading.bold())?;
+    } else {
+        writeln!(w, "{}", heading)?;
+    }
+    writeln!(w, "  Title:   {}", result.title)?;
+    if result.ref_authors.is_empty() {
+        writeln!(w, "  Authors: (none extracted)")?;
+    } else {
+        writeln!(w, "  Authors: {}", result.ref_authors.join("; "))?;
+    }
+    if let Some(ref doi) = reference.doi {
+        writeln!(w, "  DOI:     {}", doi)?;
+    }
+    if let Some(ref arxiv) = reference.arxiv_id {
+        writeln!(w, "  arXiv:   {}", arxiv)?;
+    }
+    writeln!(w)?;
+
+    writeln!(w, "Databases:")?;
+    for db in &result.db_results {
+        let elapsed = db
+            .elapsed
+            .map(|d| format!("{}ms", d.as_millis()))
+            .unwrap_or_default();
+        writeln!(
+            w,
+            "  {:<18} {:<16} {}",
+            db.db_name,
+            db_status_str(&db.status),
+            elapsed
+        )?;
+        let s = scores(&result.title, &result.ref_authors, db, policy);
+        if let Some(ref found) = db.found_title {
+            writeln!(
+                w,
+                "      returned:   \"{}\" (similarity {:.1}%, {})",
+                found,
+                s.similarity.unwrap_or(0.0) * 100.0,
+                if s.title_match == Some(true) {
+                    "title match"
+                } else {
+                    "below title threshold"
+                }
+            )?;
+        }
+        if let Some(threshold) = db.threshold.filter(|t| *t > 0.0) {
+            writeln!(w, "      threshold:  {:.0}%", threshold * 100.0)?;
+        }
+        if !db.found_authors.is_empty() {
+            let agreement = match (s.overlap, s.authors_match) {
+                (Some(o), Some(accepted)) => format!(
+                    " ({}/{} cited authors, first author {}; {})",
+                    o.matched,
+                    o.cited,
+                    if o.first_author {
+                        "matched"
+                    } else {
+                        "not matched"
+                    },
+                    if accepted { "accepted" } else { "rejected" }
+                ),
+                _ => String::new(),
+            };
+            writeln!(
+                w,
+                "      authors:    {}{}",
+                db.found_authors.join("; "),
+                agreement
+            )?;
+        }
+        if let Some(ref url) = db.paper_url {
+            writeln!(w, "      url:        {}", url)?;
+        }
+        if let Some(ref err) = db.error_message {
+            writeln!(w, "      error:      {}", err)?;
+        }
+        if db.status == DbStatus::Skipped {
+            writeln!(
+                w,
+                "      not queried: the verdict was settled before it answered"
+            )?;
+        }
+        if !db.alternates.is_empty() {
+            writeln!(
+                w,
+                "      alternates: {} near-tie record(s)",
+                db.alternates.len()
+            )?;
+        }
+    }
+
+    if let Some(ref doi) = result.doi_info {
+        writeln!(w)?;
+        writeln!(
+            w,
+            "DOI {}: {}",
+            doi.doi,
+            if doi.title_mismatch {
+                "resolves to a different title"
+            } else if doi.valid {
+                "resolves"
+            } else {
+                "does not resolve"
+            }
+        )?;
+        if doi.title_mismatch
+            && let Some(title) = &doi.title
+        {
+            writeln!(w, "  DOI title: {}", title)?;
+        }
+    }
+    if result
+        .retraction_info
+        .as_ref()
+        .is_some_and(|r| r.is_retracted)
+    {
+        writeln!(w, "Retracted: yes")?;
+    }
+
+    writeln!(w)?;
+    let verdict = format!("Verdict: {}", status_str(&result.status));
+    if color.enabled() {
+        let styled = match result.status {
+            Status::Verified => verdict.green().to_string(),
+            Status::AuthorMismatch => verdict.yellow().to_string(),
+            Status::NotFound => verdict.red().to_string(),
+        };
+        writeln!(w, "{}", styled)?;
+    } else {
+        writeln!(w, "{}", verdict)?;
+    }
+    if let Some(reason) = result.reason {
+        writeln!(w, "  Reason:   {}", reason.description())?;
+    }
+    writeln!(w, "  Decision: {}", decision(result, policy))?;
+    Ok(())
+}
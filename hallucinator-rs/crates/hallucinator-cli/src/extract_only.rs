@@ -0,0 +1,180 @@
+//! `check --extract-only`: the parsed references and skip statistics,
+//! without querying any database.
+
+use std::io::Write;
+
+use hallucinator_core::{ExtractionResult, Reference, SkipStats};
+use owo_colors::OwoColorize;
+use serde_json::{Value, json};
+
+use crate::output::{ColorMode, OutputFormat};
+
+/// Write `extraction` in `format`. `text` output starts with `label`, e.g.
+/// `"EXTRACT ONLY:"`.
+pub fn write(
+    w: &mut dyn Write,
+    label: &str,
+    file_name: &str,
+    extraction: &ExtractionResult,
+    format: OutputFormat,
+    color: ColorMode,
+) -> std::io::Result<()> {
+    match format {
+        OutputFormat::Text => write_text(w, label, file_name, extraction, color),
+        OutputFormat::Json => {
+            let doc = json!({
+                "file": file_name,
+                "ocr_used": extraction.ocr_used,
+                "ocr_error": extraction.ocr_error,
+                "references": extraction.references.iter().map(reference_json).collect::<Vec<_>>(),
+                "skip_stats": skip_stats_json(&extraction.skip_stats),
+            });
+            writeln!(
+                w,
+                "{}",
+                serde_json::to_string_pretty(&doc).unwrap_or_default()
+            )
+        }
+        OutputFormat::Jsonl => {
+            for reference in &extraction.references {
+                let mut line = reference_json(reference);
+                line["type"] = json!("reference");
+                line["file"] = json!(file_name);
+                writeln!(w, "{}", line)?;
+            }
+            let summary = json!({
+                "type": "summary",
+                "file": file_name,
+                "ocr_used": extraction.ocr_used,
+                "ocr_error": extraction.ocr_error,
+                "skip_stats": skip_stats_json(&extraction.skip_stats),
+            });
+            writeln!(w, "{}", summary)
+        }
+    }
+}
+
+fn write_text(
+    w: &mut dyn Write,
+    label: &str,
+    file_name: &str,
+    extraction: &ExtractionResult,
+    color: ColorMode,
+) -> std::io::Result<()> {
+    let stats = &extraction.skip_stats;
+    let kept = extraction
+        .references
+        .iter()
+        .filter(|r| r.skip_reason.is_none())
+        .count();
+
+    if color.enabled() {
+        writeln!(
+            w,
+            "{} {} ({} entries, {} after filtering)\n",
+            label.bold().cyan(),
+            file_name.bold(),
+            stats.total_raw,
+            kept
+        )?;
+    } else {
+        writeln!(
+            w,
+            "{} {} ({} entries, {} after filtering)\n",
+            label, file_name, stats.total_raw, kept
+        )?;
+    }
+
+    for reference in &extraction.references {
+        let number = format!("[{}]", reference.original_number);
+        if color.enabled() {
+            writeln!(w, "{}", number.bold().yellow())?;
+        } else {
+            writeln!(w, "{}", number)?;
+        }
+
+        writeln!(w, "  Title:   {}", reference.title.as_deref().unwrap_or(""))?;
+        writeln!(
+            w,
+            "  Authors: {}",
+            if reference.authors.is_empty() {
+                "(none)".to_string()
+            } else {
+                reference.authors.join("; ")
+            }
+        )?;
+
+        if let Some(ref d) = reference.doi {
+            writeln!(w, "  DOI:     {}", d)?;
+        }
+        if let Some(ref a) = reference.arxiv_id {
+            match reference.arxiv_version {
+                Some(v) => writeln!(w, "  arXiv:   {}v{}", a, v)?,
+                None => writeln!(w, "  arXiv:   {}", a)?,
+            }
+        }
+
+        // Truncate raw citation for display
+        let raw_display = match reference.raw_citation.char_indices().nth(200) {
+            Some((end, _)) => format!("{}...", &reference.raw_citation[..end]),
+            None => reference.raw_citation.clone(),
+        };
+        if color.enabled() {
+            writeln!(w, "  Raw:     {}", raw_display.dimmed())?;
+        } else {
+            writeln!(w, "  Raw:     {}", raw_display)?;
+        }
+
+        if let Some(ref reason) = reference.skip_reason {
+            let skipped = format!("SKIPPED ({})", reason.replace('_', " "));
+            if color.enabled() {
+                writeln!(w, "  {}", skipped.red())?;
+            } else {
+                writeln!(w, "  {}", skipped)?;
+            }
+        }
+
+        writeln!(w)?;
+    }
+
+    if extraction.ocr_used {
+        writeln!(w, "Text was read by OCR; expect recognition errors.")?;
+    }
+    if let Some(ref e) = extraction.ocr_error {
+        writeln!(w, "OCR failed ({}); the embedded text was used.", e)?;
+    }
+    writeln!(
+        w,
+        "Total: {} raw entries ({} kept, {} skipped: {} URL-only, {} short title, {} no title; {} without authors)",
+        stats.total_raw,
+        kept,
+        stats.url_only + stats.short_title + stats.no_title,
+        stats.url_only,
+        stats.short_title,
+        stats.no_title,
+        stats.no_authors
+    )
+}
+
+fn reference_json(reference: &Reference) -> Value {
+    json!({
+        "number": reference.original_number,
+        "title": reference.title,
+        "authors": reference.authors,
+        "doi": reference.doi,
+        "arxiv_id": reference.arxiv_id,
+        "arxiv_version": reference.arxiv_version,
+        "skip_reason": reference.skip_reason,
+        "raw_citation": reference.raw_citation,
+    })
+}
+
+fn skip_stats_json(stats: &SkipStats) -> Value {
+    json!({
+        "total_raw": stats.total_raw,
+        "url_only": stats.url_only,
+        "short_title": stats.short_title,
+        "no_title": stats.no_title,
+        "no_authors": stats.no_authors,
+    })
+}
@@ -0,0 +1,148 @@
+//! `check --format jsonl`: one JSON object per reference as it completes.
+//!
+//! Every line is self-contained. Result lines carry `"type": "result"` and
+//! the file they belong to; the files of a batch are checked together, so
+//! their result lines interleave. Once every file is checked, each gets a
+//! `"type": "summary"` line holding its totals. Each retry pass adds another
+//! result line for the references it retried; the last line for a reference
+//! is the one that counts. A batch of several files ends with one
+//! `"type": "batch_summary"` line, and a file that could not be read gets a
+//! `"type": "error"` line instead of results.
+
+use hallucinator_core::checker::{MAX_REFERENCES_SKIP_REASON, cap_references};
+use hallucinator_core::{CheckStats, Reference, ValidationResult};
+use serde_json::{Value, json};
+
+use crate::explain::{db_status_str, status_str};
+
+/// The number each of `references` has in its file, indexed as progress
+/// events index them: counting only the references left after the cap.
+pub fn reference_numbers(references: &[Reference], max_references: Option<usize>) -> Vec<usize> {
+    let mut capped = references.to_vec();
+    cap_references(&mut capped, max_references);
+    capped
+        .iter()
+        .filter(|r| r.skip_reason.as_deref() != Some(MAX_REFERENCES_SKIP_REASON))
+        .map(|r| r.original_number)
+        .collect()
+}
+
+/// The line for one checked reference.
+pub fn result_line(
+    paper: &str,
+    index: usize,
+    number: Option<usize>,
+    result: &ValidationResult,
+) -> Value {
+    let databases: Vec<Value> = result
+        .db_results
+        .iter()
+        .map(|db| {
+            json!({
+                "db": db.db_name,
+                "status": db_status_str(&db.status),
+                "elapsed_ms": db.elapsed.map(|d| d.as_millis() as u64),
+                "found_title": db.found_title,
+                "found_authors": db.found_authors,
+                "threshold": db.threshold,
+                "score": db.score,
+                "url": db.paper_url,
+                "error": db.error_message,
+            })
+        })
+        .collect();
+
+    json!({
+        "type": "result",
+        "paper": paper,
+        "index": index,
+        "reference": number,
+        "title": result.title,
+        "raw_citation": result.raw_citation,
+        "ref_authors": result.ref_authors,
+        "status": status_str(&result.status),
+        "reason": result.reason.map(|r| r.as_str()),
+        "source": result.source,
+        "agreeing_dbs": result.agreeing_dbs,
+        "found_authors": result.found_authors,
+        "match_score": result.match_score,
+        "venue": result.venue,
+        "year": result.year,
+        "orcid": result.orcid,
+        "paper_url": result.paper_url,
+        "failed_dbs": result.failed_db_names(),
+        "failed_db_reasons": result
+            .failed_dbs
+            .iter()
+            .map(|f| json!({"name": f.name, "reason": f.reason.as_str()}))
+            .collect::<Vec<_>>(),
+        "databases": databases,
+        "doi_info": result.doi_info.as_ref().map(|d| json!({
+            "doi": d.doi,
+            "valid": d.valid,
+            "structurally_valid": d.structurally_valid,
+            "title_mismatch": d.title_mismatch,
+            "title": d.title,
+        })),
+        "arxiv_info": result.arxiv_info.as_ref().map(|a| json!({
+            "arxiv_id": a.arxiv_id,
+            "valid": a.valid,
+            "title": a.title,
+        })),
+        "retraction_info": result.retraction_info.as_ref().map(|r| json!({
+            "is_retracted": r.is_retracted,
+            "retraction_doi": r.retraction_doi,
+            "retraction_source": r.retraction_source,
+        })),
+        "title_near_miss": result.title_near_miss.as_ref().map(|m| json!({
+            "cited_title": m.cited_title,
+            "found_title": m.found_title,
+            "similarity": m.similarity,
+        })),
+    })
+}
+
+/// The closing line with the run's totals.
+pub fn summary_line(paper: &str, stats: &CheckStats) -> Value {
+    json!({
+        "type": "summary",
+        "paper": paper,
+        "stats": stats_json(stats),
+    })
+}
+
+/// The line for a file of a batch that could not be read.
+pub fn error_line(paper: &str, error: &str) -> Value {
+    json!({
+        "type": "error",
+        "paper": paper,
+        "error": error,
+    })
+}
+
+/// The last line of a batch: how many files were checked and the totals
+/// over all of them.
+pub fn batch_summary_line(files: &[(String, Option<CheckStats>)], totals: &CheckStats) -> Value {
+    json!({
+        "type": "batch_summary",
+        "files": files.len(),
+        "failed_files": files
+            .iter()
+            .filter(|(_, stats)| stats.is_none())
+            .map(|(paper, _)| paper)
+            .collect::<Vec<_>>(),
+        "stats": stats_json(totals),
+    })
+}
+
+fn stats_json(stats: &CheckStats) -> Value {
+    json!({
+        "total": stats.total,
+        "verified": stats.verified,
+        "not_found": stats.not_found,
+        "author_mismatch": stats.author_mismatch,
+        "retracted": stats.retracted,
+        "skipped": stats.skipped,
+        "duplicates": stats.duplicates,
+    })
+}
@@ -1,13 +1,21 @@
+use std::collections::HashMap;
 use std::io::Write;
 use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 
 use clap::{Parser, Subcommand};
 use tokio_util::sync::CancellationToken;
 
+mod config_file;
+mod explain;
+mod extract_only;
+mod jsonl;
+mod logging;
 mod output;
 
-use output::ColorMode;
+use output::{ColorMode, OutputFormat};
 
 /// Hallucinated Reference Detector - Detect fabricated references in academic PDFs
 #[derive(Parser, Debug)]
@@ -15,19 +23,47 @@ use output::ColorMode;
 struct Cli {
     #[command(subcommand)]
     command: Command,
+
+    /// Log only errors; `check` prints just the final summary
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// Log more (repeatable): -v each database query and its latency, -vv
+    /// adds cache hits, -vvv everything. Logs go to stderr
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
 }
 
 #[derive(Subcommand, Debug)]
+#[allow(clippy::large_enum_variant)] // parsed once at startup
 enum Command {
-    /// Check a PDF, .bbl, or .bib file for hallucinated references
+    /// Check PDFs, .bbl, .bib, or .txt reference lists for hallucinated references
+    ///
+    /// Several files are checked together through one validation pool, as
+    /// in a TUI batch, and --dedup spans them. Each still gets its own
+    /// report, printed once the batch is checked, and the batch ends with a
+    /// summary of all of them.
+    ///
+    /// Exit status reports the most severe finding: 3 if any cited paper is
+    /// retracted, else 2 if any reference has an author mismatch, else 1 if any
+    /// reference was not found, else 0. --author-mismatch-is-failure ranks
+    /// author mismatches with references not found (1). Unchecked references
+    /// (skipped, past --run-deadline, or cancelled) do not affect it. Errors,
+    /// including a file of a batch that could not be read, exit with 1.
     Check {
-        /// Path to the PDF, .bbl, or .bib file to check
-        file_path: PathBuf,
+        /// PDF, .bbl, .bib, or .txt files to check. Glob patterns are
+        /// expanded, so a quoted "refs/*.pdf" works without a shell
+        #[arg(required = true, value_name = "FILE")]
+        file_paths: Vec<PathBuf>,
 
         /// Disable colored output
         #[arg(long)]
         no_color: bool,
 
+        /// Config file (default: ./.hallucinator.toml over <config dir>/hallucinator/config.toml)
+        #[arg(long, value_name = "PATH")]
+        config: Option<PathBuf>,
+
         /// OpenAlex API key
         #[arg(long)]
         openalex_key: Option<String>,
@@ -36,26 +72,169 @@ enum Command {
         #[arg(long)]
         s2_api_key: Option<String>,
 
+        /// NCBI API key for PubMed (raises its rate limit)
+        #[arg(long)]
+        ncbi_api_key: Option<String>,
+
+        /// CORE (core.ac.uk) API key; enables the CORE database
+        #[arg(long)]
+        core_api_key: Option<String>,
+
+        /// Contact email for CrossRef's polite pool (also added to the User-Agent)
+        #[arg(long)]
+        crossref_mailto: Option<String>,
+
+        /// Custom User-Agent for API requests (default: hallucinator/<version>)
+        #[arg(long)]
+        user_agent: Option<String>,
+
+        /// Proxy URL for API requests (default: HTTPS_PROXY/HTTP_PROXY from the environment)
+        #[arg(long)]
+        proxy: Option<String>,
+
+        /// PEM file with extra root certificates to trust (e.g. a corporate CA)
+        #[arg(long)]
+        extra_ca_cert: Option<PathBuf>,
+
         /// Path to output log file
         #[arg(short, long)]
         output: Option<PathBuf>,
 
+        /// Write one report per input file into this directory, named after
+        /// the file (paper.pdf -> paper.txt, or .json/.jsonl with --format);
+        /// created if missing
+        #[arg(long, value_name = "DIR", conflicts_with = "output")]
+        output_dir: Option<PathBuf>,
+
+        /// Overwrite reports already in --output-dir
+        #[arg(long, requires = "output_dir")]
+        force: bool,
+
         /// Path to offline DBLP database
         #[arg(long)]
         dblp_offline: Option<PathBuf>,
 
+        /// Warn when the offline DBLP database is at least this many days old (default: 30)
+        #[arg(long, value_name = "DAYS")]
+        dblp_max_age_days: Option<u64>,
+
         /// Path to offline ACL Anthology database
         #[arg(long)]
         acl_offline: Option<PathBuf>,
 
+        /// Local .bib library (e.g. a Zotero export) to check references against first
+        #[arg(long, value_name = "PATH")]
+        local_bib: Option<PathBuf>,
+
+        /// Retraction Watch CSV to look matched DOIs up in before asking CrossRef
+        #[arg(long, value_name = "PATH")]
+        retraction_db: Option<PathBuf>,
+
+        /// Warn when the Retraction Watch CSV is at least this many days old (default: 30)
+        #[arg(long, value_name = "DAYS")]
+        retraction_max_age_days: Option<u64>,
+
         /// Comma-separated list of databases to disable
         #[arg(long, value_delimiter = ',')]
         disable_dbs: Vec<String>,
 
+        /// Comma-separated list of the only databases to query (overrides --disable-dbs)
+        #[arg(long, value_delimiter = ',')]
+        only_dbs: Vec<String>,
+
+        /// Title-similarity threshold for one database, e.g. DBLP=0.97 (repeatable)
+        #[arg(long = "threshold", value_name = "DB=VALUE", value_parser = parse_threshold)]
+        thresholds: Vec<(String, f64)>,
+
+        /// Query timeout in seconds for one database, e.g. "Europe PMC=30" (repeatable)
+        #[arg(long = "db-timeout", value_name = "DB=SECS", value_parser = parse_db_timeout)]
+        db_timeouts: Vec<(String, u64)>,
+
         /// Flag author mismatches from OpenAlex (default: skipped)
         #[arg(long)]
         check_openalex_authors: bool,
 
+        /// Fraction of cited authors a match must share, 0–1 (default: any one)
+        #[arg(long, value_name = "FRACTION", value_parser = parse_fraction)]
+        author_min_overlap: Option<f32>,
+
+        /// Treat a match whose first author differs from the citation's as an author mismatch
+        #[arg(long)]
+        require_first_author: bool,
+
+        /// Surname similarity, 0–1, for two author names to match after
+        /// diacritics and hyphens are normalized; 1 requires equal surnames (default: 0.9)
+        #[arg(long, value_name = "SIMILARITY", value_parser = parse_similarity)]
+        author_match_threshold: Option<f64>,
+
+        /// Title similarity, 0–1, a database match must reach to count;
+        /// weaker matches are reported as not found (default: 0, no minimum)
+        #[arg(long, value_name = "SIMILARITY", value_parser = parse_similarity)]
+        min_match_score: Option<f64>,
+
+        /// Query every database instead of stopping at the first match, and
+        /// verify a reference only when several agree on its title
+        #[arg(long)]
+        consensus: bool,
+
+        /// Databases that must agree on the title with --consensus (default: 2)
+        #[arg(
+            long,
+            value_name = "N",
+            requires = "consensus",
+            value_parser = parse_consensus_min
+        )]
+        consensus_min: Option<usize>,
+
+        /// Note matches whose title is close to, but not the same as, the
+        /// cited one (a follow-up paper, say); informational, the verdict is kept
+        #[arg(long)]
+        strict_title: bool,
+
+        /// Before reporting an author mismatch, check on ORCID whether a
+        /// cited author claims the matched work (by DOI)
+        #[arg(long)]
+        orcid_check: bool,
+
+        /// Rank author mismatches with references not found: they exit
+        /// with 1 instead of 2 (the detailed report still tells them apart)
+        #[arg(long)]
+        author_mismatch_is_failure: bool,
+
+        /// Check URL-only references (datasets, software) that carry a DOI by
+        /// resolving the DOI, instead of skipping them
+        #[arg(long)]
+        check_url_only: bool,
+
+        /// Look up references with the same title once and share the result,
+        /// across all the files checked
+        #[arg(long)]
+        dedup: bool,
+
+        /// Let each database run more queries at once while it answers
+        /// without 429s, halving them when it does
+        #[arg(long)]
+        adaptive_concurrency: bool,
+
+        /// Read PDF pages in MuPDF's block order instead of detecting
+        /// two-column layouts and reading them column by column
+        #[arg(long)]
+        single_column: bool,
+
+        /// Also check citations given in footnotes (for humanities papers
+        /// that cite in notes rather than a references section)
+        #[arg(long)]
+        include_footnotes: bool,
+
+        /// Password for an encrypted PDF (or set HALLUCINATOR_PDF_PASSWORD)
+        #[arg(long, value_name = "PASSWORD")]
+        pdf_password: Option<String>,
+
+        /// Extra heading that opens the references section, e.g. "Quellen"
+        /// (repeatable; added to the built-in multilingual list)
+        #[arg(long = "section-heading", value_name = "TEXT")]
+        section_headings: Vec<String>,
+
         /// Number of concurrent reference checks (default: 4)
         #[arg(long)]
         num_workers: Option<usize>,
@@ -64,10 +243,29 @@ enum Command {
         #[arg(long)]
         max_rate_limit_retries: Option<u32>,
 
+        /// Retry passes over references not found because a database
+        /// failed; 0 disables them (default: 1)
+        #[arg(long, value_name = "N")]
+        max_retry_passes: Option<usize>,
+
+        /// Validate at most this many references; the rest are skipped
+        #[arg(long)]
+        max_references: Option<usize>,
+
+        /// Stop checking after this many seconds and report what was checked
+        #[arg(long, value_name = "SECS")]
+        run_deadline: Option<u64>,
+
         /// Dry run: extract and print references without querying databases
         #[arg(long)]
         dry_run: bool,
 
+        /// Print the parsed references (title, authors, DOI, arXiv id) and
+        /// skip statistics without querying databases; honors --format.
+        /// Exits with 1 if no reference can be checked
+        #[arg(long, conflicts_with_all = ["dry_run", "explain"])]
+        extract_only: bool,
+
         /// Path to persistent query cache database (SQLite)
         #[arg(long)]
         cache_path: Option<PathBuf>,
@@ -75,6 +273,15 @@ enum Command {
         /// Clear the query cache and exit
         #[arg(long)]
         clear_cache: bool,
+
+        /// Check only this reference (1-based number in the file) and print why it got its verdict
+        #[arg(long, value_name = "REF_NUMBER")]
+        explain: Option<usize>,
+
+        /// Output format: text, json (with --explain or --extract-only), or jsonl (one JSON
+        /// object per reference as it completes, then a summary object)
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
     },
 
     /// Download and build the offline DBLP database
@@ -91,50 +298,90 @@ enum Command {
 }
 
 #[tokio::main]
-async fn main() -> anyhow::Result<()> {
+async fn main() -> anyhow::Result<ExitCode> {
     dotenvy::dotenv().ok();
     let cli = Cli::parse();
+    logging::init(logging::level_filter(cli.quiet, cli.verbose));
 
     match cli.command {
-        Command::UpdateDblp { path } => update_dblp(&path).await,
-        Command::UpdateAcl { path } => update_acl(&path).await,
+        Command::UpdateDblp { path } => update_dblp(&path).await.map(|()| ExitCode::SUCCESS),
+        Command::UpdateAcl { path } => update_acl(&path).await.map(|()| ExitCode::SUCCESS),
         Command::Check {
-            file_path,
+            file_paths,
             no_color,
+            config,
             openalex_key,
             s2_api_key,
+            ncbi_api_key,
+            core_api_key,
+            crossref_mailto,
+            user_agent,
+            proxy,
+            extra_ca_cert,
             output,
+            output_dir,
+            force,
             dblp_offline,
+            dblp_max_age_days,
             acl_offline,
+            local_bib,
+            retraction_db,
+            retraction_max_age_days,
             disable_dbs,
+            only_dbs,
+            thresholds,
+            db_timeouts,
             check_openalex_authors,
+            author_min_overlap,
+            require_first_author,
+            author_match_threshold,
+            min_match_score,
+            consensus,
+            consensus_min,
+            strict_title,
+            orcid_check,
+            author_mismatch_is_failure,
+            check_url_only,
+            dedup,
+            adaptive_concurrency,
+            single_column,
+            include_footnotes,
+            pdf_password,
+            section_headings,
             num_workers,
             max_rate_limit_retries,
+            max_retry_passes,
+            max_references,
+            run_deadline,
             dry_run,
+            extract_only,
             cache_path,
             clear_cache,
+            explain,
+            format,
         } => {
+            let file_config = config_file::load(config.as_deref())?;
+            let env = hallucinator_core::EnvConfig::read().map_err(|e| anyhow::anyhow!(e))?;
             if clear_cache {
-                let path = cache_path.or_else(|| {
-                    std::env::var("HALLUCINATOR_CACHE_PATH")
-                        .ok()
-                        .map(PathBuf::from)
-                });
+                let path = cache_path
+                    .or(env.cache_path)
+                    .or(file_config.databases.cache_path);
                 return match path {
                     Some(p) if p.exists() => {
                         let cache = hallucinator_core::QueryCache::open(
                             &p,
                             std::time::Duration::from_secs(1),
                             std::time::Duration::from_secs(1),
+                            0,
                         )
                         .map_err(|e| anyhow::anyhow!("{}", e))?;
                         cache.clear();
                         println!("Cache cleared: {}", p.display());
-                        Ok(())
+                        Ok(ExitCode::SUCCESS)
                     }
                     Some(p) => {
                         println!("No cache file at {}", p.display());
-                        Ok(())
+                        Ok(ExitCode::SUCCESS)
                     }
                     None => {
                         anyhow::bail!(
@@ -143,22 +390,88 @@ async fn main() -> anyhow::Result<()> {
                     }
                 };
             }
-            if dry_run {
-                dry_run_check(file_path, no_color, output).await
+            let file_paths = expand_globs(file_paths)?;
+            if file_paths.len() > 1 {
+                let single = [
+                    (extract_only, "--extract-only"),
+                    (dry_run, "--dry-run"),
+                    (explain.is_some(), "--explain"),
+                ];
+                if let Some((_, flag)) = single.iter().find(|(set, _)| *set) {
+                    anyhow::bail!("{} takes a single file, got {}", flag, file_paths.len());
+                }
+            }
+            // --dry-run prints text whatever the --format
+            let report_format = if dry_run { OutputFormat::Text } else { format };
+            let reports = output_dir
+                .map(|dir| report_paths(&dir, &file_paths, report_format, force))
+                .transpose()?;
+            let output = output.or_else(|| reports.as_ref().map(|r| r[0].clone()));
+            let pdf_password =
+                pdf_password.or_else(|| std::env::var("HALLUCINATOR_PDF_PASSWORD").ok());
+            let extractor = pdf_extractor(
+                section_headings,
+                hallucinator_pdf::ExtractionOptions {
+                    check_url_only,
+                    force_single_column: single_column,
+                    password: pdf_password,
+                    include_footnotes,
+                },
+            )?;
+            if extract_only {
+                extract_only_check(file_paths[0].clone(), no_color, output, format, extractor)
+            } else if dry_run {
+                dry_run_check(file_paths[0].clone(), no_color, output, extractor)
+                    .await
+                    .map(|()| ExitCode::SUCCESS)
             } else {
                 check(
-                    file_path,
+                    file_paths,
                     no_color,
                     openalex_key,
                     s2_api_key,
+                    ncbi_api_key,
+                    core_api_key,
+                    crossref_mailto,
+                    user_agent,
+                    proxy,
+                    extra_ca_cert,
                     output,
+                    reports,
                     dblp_offline,
+                    dblp_max_age_days,
                     acl_offline,
+                    local_bib,
+                    retraction_db,
+                    retraction_max_age_days,
                     disable_dbs,
+                    only_dbs,
+                    thresholds,
+                    db_timeouts,
                     check_openalex_authors,
+                    author_min_overlap,
+                    require_first_author,
+                    author_match_threshold,
+                    min_match_score,
+                    consensus,
+                    consensus_min,
+                    strict_title,
+                    orcid_check,
+                    author_mismatch_is_failure,
+                    dedup,
+                    adaptive_concurrency,
+                    extractor,
                     num_workers,
                     max_rate_limit_retries,
+                    max_retry_passes,
+                    max_references,
+                    run_deadline,
                     cache_path,
+                    explain,
+                    format,
+                    cli.quiet,
+                    env,
+                    file_config,
                 )
                 .await
             }
@@ -166,49 +479,283 @@ async fn main() -> anyhow::Result<()> {
     }
 }
 
+/// Parse a `--threshold DB=VALUE` argument.
+fn parse_threshold(arg: &str) -> Result<(String, f64), String> {
+    let (name, value) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("expected DB=VALUE, got '{}'", arg))?;
+    let value: f64 = value
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid threshold '{}'", value))?;
+    if !(0.0..=1.0).contains(&value) {
+        return Err(format!("threshold must be between 0 and 1, got {}", value));
+    }
+    Ok((name.trim().to_string(), value))
+}
+
+/// Parse a `--db-timeout DB=SECS` argument.
+fn parse_db_timeout(arg: &str) -> Result<(String, u64), String> {
+    let (name, value) = arg
+        .split_once('=')
+        .ok_or_else(|| format!("expected DB=SECS, got '{}'", arg))?;
+    let secs: u64 = value
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid timeout '{}'", value))?;
+    if secs == 0 {
+        return Err("timeout must be at least 1 second".to_string());
+    }
+    Ok((name.trim().to_string(), secs))
+}
+
+/// Parse an `--author-min-overlap` fraction.
+fn parse_fraction(arg: &str) -> Result<f32, String> {
+    let value: f32 = arg
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid fraction '{}'", arg))?;
+    if !(0.0..=1.0).contains(&value) {
+        return Err(format!("fraction must be between 0 and 1, got {}", value));
+    }
+    Ok(value)
+}
+
+/// Parse an `--author-match-threshold` similarity.
+fn parse_similarity(arg: &str) -> Result<f64, String> {
+    let value: f64 = arg
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid similarity '{}'", arg))?;
+    if !(0.0..=1.0).contains(&value) {
+        return Err(format!("similarity must be between 0 and 1, got {}", value));
+    }
+    Ok(value)
+}
+
+fn parse_consensus_min(arg: &str) -> Result<usize, String> {
+    match arg.trim().parse() {
+        Ok(0) => Err("at least one database has to agree".to_string()),
+        Ok(value) => Ok(value),
+        Err(_) => Err(format!("invalid database count '{}'", arg)),
+    }
+}
+
+/// Expand the glob patterns among `paths`, in sorted order. A path that
+/// exists as written is kept even if it looks like a pattern; a pattern that
+/// matches nothing is an error, as it most likely is a typo.
+fn expand_globs(paths: Vec<PathBuf>) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for path in paths {
+        let pattern = path.to_string_lossy();
+        if path.exists() || !pattern.contains(['*', '?', '[']) {
+            files.push(path);
+            continue;
+        }
+        let matches = glob::glob(&pattern)
+            .map_err(|e| anyhow::anyhow!("Invalid pattern {}: {}", pattern, e))?
+            .filter_map(Result::ok)
+            .filter(|p| p.is_file())
+            .collect::<Vec<_>>();
+        if matches.is_empty() {
+            anyhow::bail!("No files match {}", pattern);
+        }
+        files.extend(matches);
+    }
+    Ok(files)
+}
+
+/// Where `--output-dir` puts each file's report: `dir/<file stem>.<format
+/// extension>`. Creates `dir`, but first refuses to overwrite a report unless
+/// `force`, or to give two files the same report.
+fn report_paths(
+    dir: &std::path::Path,
+    files: &[PathBuf],
+    format: OutputFormat,
+    force: bool,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let extension = match format {
+        OutputFormat::Text => "txt",
+        OutputFormat::Json => "json",
+        OutputFormat::Jsonl => "jsonl",
+    };
+    let mut reports: HashMap<PathBuf, &PathBuf> = HashMap::new();
+    let mut paths = Vec::with_capacity(files.len());
+    for file in files {
+        let stem = file.file_stem().unwrap_or(file.as_os_str());
+        // Appended rather than set, so a stem with dots ("paper.v2") is kept
+        let report = dir.join(format!("{}.{}", stem.to_string_lossy(), extension));
+        if let Some(other) = reports.insert(report.clone(), file) {
+            anyhow::bail!(
+                "{} and {} would both be reported in {}",
+                other.display(),
+                file.display(),
+                report.display()
+            );
+        }
+        if !force && report.exists() {
+            anyhow::bail!(
+                "{} already exists (use --force to overwrite)",
+                report.display()
+            );
+        }
+        paths.push(report);
+    }
+    std::fs::create_dir_all(dir)
+        .map_err(|e| anyhow::anyhow!("Cannot create {}: {}", dir.display(), e))?;
+    Ok(paths)
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn check(
-    file_path: PathBuf,
+    file_paths: Vec<PathBuf>,
     no_color: bool,
     openalex_key: Option<String>,
     s2_api_key: Option<String>,
+    ncbi_api_key: Option<String>,
+    core_api_key: Option<String>,
+    crossref_mailto: Option<String>,
+    user_agent: Option<String>,
+    proxy: Option<String>,
+    extra_ca_cert: Option<PathBuf>,
     output: Option<PathBuf>,
+    reports: Option<Vec<PathBuf>>,
     dblp_offline: Option<PathBuf>,
+    dblp_max_age_days: Option<u64>,
     acl_offline: Option<PathBuf>,
+    local_bib: Option<PathBuf>,
+    retraction_db_path: Option<PathBuf>,
+    retraction_max_age_days: Option<u64>,
     disable_dbs: Vec<String>,
+    only_dbs: Vec<String>,
+    thresholds: Vec<(String, f64)>,
+    db_timeouts: Vec<(String, u64)>,
     check_openalex_authors: bool,
+    author_min_overlap: Option<f32>,
+    require_first_author: bool,
+    author_match_threshold: Option<f64>,
+    min_match_score: Option<f64>,
+    consensus: bool,
+    consensus_min: Option<usize>,
+    strict_title: bool,
+    orcid_check: bool,
+    author_mismatch_is_failure: bool,
+    dedup: bool,
+    adaptive_concurrency: bool,
+    extractor: hallucinator_pdf::PdfExtractor,
     num_workers: Option<usize>,
     max_rate_limit_retries: Option<u32>,
+    max_retry_passes: Option<usize>,
+    max_references: Option<usize>,
+    run_deadline: Option<u64>,
     cache_path: Option<PathBuf>,
-) -> anyhow::Result<()> {
-    // Resolve configuration: CLI flags > env vars > defaults
-    let openalex_key = openalex_key.or_else(|| std::env::var("OPENALEX_KEY").ok());
-    let s2_api_key = s2_api_key.or_else(|| std::env::var("S2_API_KEY").ok());
-    let dblp_offline_path =
-        dblp_offline.or_else(|| std::env::var("DBLP_OFFLINE_PATH").ok().map(PathBuf::from));
-    let acl_offline_path =
-        acl_offline.or_else(|| std::env::var("ACL_OFFLINE_PATH").ok().map(PathBuf::from));
-    let db_timeout_secs: u64 = std::env::var("DB_TIMEOUT")
-        .ok()
-        .and_then(|v| v.parse().ok())
-        .unwrap_or(10);
-    let db_timeout_short_secs: u64 = std::env::var("DB_TIMEOUT_SHORT")
-        .ok()
-        .and_then(|v| v.parse().ok())
-        .unwrap_or(5);
-
-    // Determine color mode and output writer
-    let use_color = !no_color && output.is_none();
+    explain: Option<usize>,
+    format: OutputFormat,
+    quiet: bool,
+    env: hallucinator_core::EnvConfig,
+    file_config: config_file::ConfigFile,
+) -> anyhow::Result<ExitCode> {
+    if format == OutputFormat::Json && explain.is_none() {
+        anyhow::bail!("--format json needs --explain; use --format jsonl to stream all results");
+    }
+    let jsonl = format == OutputFormat::Jsonl && explain.is_none();
+
+    // Resolve configuration: defaults, then the config file, the environment
+    // and the flags, each overriding the one before
+    fn set<T>(target: &mut T, value: Option<T>) {
+        if let Some(value) = value {
+            *target = value;
+        }
+    }
+    fn set_some<T>(target: &mut Option<T>, value: Option<T>) {
+        if value.is_some() {
+            *target = value;
+        }
+    }
+    // An empty value clears the setting
+    fn set_str(target: &mut Option<String>, value: Option<String>) {
+        if let Some(value) = value {
+            *target = Some(value).filter(|s| !s.is_empty());
+        }
+    }
+    let mut env = env;
+    let mut config = hallucinator_core::Config::default();
+    file_config.apply(&mut config);
+    // The query cache is opened once, below, from the final path
+    let env_cache_path = env.cache_path.take();
+    env.apply(&mut config);
+    set_some(&mut config.cache_path, env_cache_path);
+
+    set_str(&mut config.openalex_key, openalex_key);
+    set_str(&mut config.s2_api_key, s2_api_key);
+    set_str(&mut config.ncbi_api_key, ncbi_api_key);
+    set_str(&mut config.core_api_key, core_api_key);
+    set_str(&mut config.crossref_mailto, crossref_mailto);
+    set_str(&mut config.user_agent, user_agent);
+    set_str(&mut config.proxy, proxy);
+    set_some(&mut config.extra_ca_cert, extra_ca_cert);
+    set_some(&mut config.dblp_offline_path, dblp_offline);
+    set(&mut config.dblp_max_age_days, dblp_max_age_days);
+    set_some(&mut config.acl_offline_path, acl_offline);
+    set_some(&mut config.local_bib_path, local_bib);
+    set_some(&mut config.retraction_db_path, retraction_db_path);
+    set(&mut config.retraction_max_age_days, retraction_max_age_days);
+    if !disable_dbs.is_empty() {
+        config.disabled_dbs = disable_dbs;
+    }
+    if !only_dbs.is_empty() {
+        config.only_dbs = only_dbs;
+    }
+    // --db-timeout and --threshold entries override earlier ones for the
+    // same database
+    config.db_timeouts.extend(db_timeouts);
+    config.per_db_threshold.extend(thresholds);
+    set(&mut config.author_match_min_overlap, author_min_overlap);
+    set(&mut config.author_match_threshold, author_match_threshold);
+    set(&mut config.min_match_score, min_match_score);
+    set(&mut config.consensus_min, consensus_min);
+    set(&mut config.num_workers, num_workers);
+    set(&mut config.max_rate_limit_retries, max_rate_limit_retries);
+    set(&mut config.max_retry_passes, max_retry_passes);
+    // 0 means unlimited, as in the TUI, and lifts a cap set further down
+    if let Some(max) = max_references {
+        config.max_references = (max > 0).then_some(max);
+    }
+    set_some(
+        &mut config.run_deadline,
+        run_deadline.map(std::time::Duration::from_secs),
+    );
+    set_some(&mut config.cache_path, cache_path);
+    // Switches can only be turned on by a flag; the environment can also
+    // turn them off
+    config.check_openalex_authors |= check_openalex_authors;
+    config.require_first_author_match |= require_first_author;
+    config.consensus_mode |= consensus;
+    config.strict_title |= strict_title;
+    config.orcid_check |= orcid_check;
+    config.author_mismatch_is_failure |= author_mismatch_is_failure;
+    config.dedup |= dedup;
+    config.adaptive_concurrency |= adaptive_concurrency;
+
+    // Determine color mode and output writer. With --output-dir, reports go
+    // to their own files and stdout only gets the batch summary.
+    let to_files = output.is_some() || reports.is_some();
+    let use_color = !no_color && !to_files && !jsonl;
     let color = ColorMode(use_color);
 
-    let mut writer: Box<dyn Write> = if let Some(ref output_path) = output {
+    let mut writer: Box<dyn Write + Send> = if let Some(ref output_path) = output
+        && reports.is_none()
+    {
         Box::new(std::fs::File::create(output_path)?)
     } else {
         Box::new(std::io::stdout())
     };
 
+    // Notices go to stderr when stdout carries JSON lines
+    let mut stderr = std::io::stderr();
+
     // Open offline DBLP database if configured
-    let dblp_offline_db = if let Some(ref path) = dblp_offline_path {
+    if let Some(ref path) = config.dblp_offline_path {
         if !path.exists() {
             anyhow::bail!(
                 "Offline DBLP database not found at {}. Build it with: hallucinator-cli update-dblp {}",
@@ -216,40 +763,13 @@ async fn check(
                 path.display()
             );
         }
+        // Staleness is reported by the checker, against --dblp-max-age-days
         let db = hallucinator_dblp::DblpDatabase::open(path)?;
-
-        // Check staleness
-        if let Ok(staleness) = db.check_staleness(30)
-            && staleness.is_stale
-        {
-            let msg = if let Some(days) = staleness.age_days {
-                format!(
-                    "Offline DBLP database is {} days old. Consider running: hallucinator-cli update-dblp {}",
-                    days,
-                    path.display()
-                )
-            } else {
-                format!(
-                    "Offline DBLP database may be stale. Consider running: hallucinator-cli update-dblp {}",
-                    path.display()
-                )
-            };
-            if color.enabled() {
-                use owo_colors::OwoColorize;
-                writeln!(writer, "{}", msg.yellow())?;
-            } else {
-                writeln!(writer, "{}", msg)?;
-            }
-            writeln!(writer)?;
-        }
-
-        Some(Arc::new(Mutex::new(db)))
-    } else {
-        None
-    };
+        config.dblp_offline_db = Some(Arc::new(Mutex::new(db)));
+    }
 
     // Open offline ACL Anthology database if configured
-    let acl_offline_db = if let Some(ref path) = acl_offline_path {
+    if let Some(ref path) = config.acl_offline_path {
         if !path.exists() {
             anyhow::bail!(
                 "Offline ACL database not found at {}. Build it with: hallucinator-cli update-acl {}",
@@ -274,142 +794,496 @@ async fn check(
                     path.display()
                 )
             };
+            let w: &mut dyn Write = if jsonl { &mut stderr } else { &mut writer };
             if color.enabled() {
                 use owo_colors::OwoColorize;
-                writeln!(writer, "{}", msg.yellow())?;
+                writeln!(w, "{}", msg.yellow())?;
             } else {
-                writeln!(writer, "{}", msg)?;
+                writeln!(w, "{}", msg)?;
             }
-            writeln!(writer)?;
+            writeln!(w)?;
         }
 
-        Some(Arc::new(Mutex::new(db)))
-    } else {
-        None
-    };
+        config.acl_offline_db = Some(Arc::new(Mutex::new(db)));
+    }
 
-    // Extract references from input file
-    if !file_path.exists() {
-        anyhow::bail!("File not found: {}", file_path.display());
+    // Load the library once for every file, failing early if it cannot be read
+    if let Some(ref path) = config.local_bib_path {
+        let library = hallucinator_core::db::bibtex::BibTexBackend::open(path)
+            .map_err(|e| anyhow::anyhow!("Cannot load BibTeX library {}: {}", path.display(), e))?;
+        config.local_bib = Some(Arc::new(library));
     }
 
-    let is_bbl = file_path
-        .extension()
-        .map(|e| e.eq_ignore_ascii_case("bbl"))
-        .unwrap_or(false);
-    let is_bib = file_path
-        .extension()
-        .map(|e| e.eq_ignore_ascii_case("bib"))
-        .unwrap_or(false);
-
-    let extraction = if is_bbl {
-        hallucinator_bbl::extract_references_from_bbl(&file_path)
-            .map_err(|e| anyhow::anyhow!("BBL extraction failed: {}", e))?
-    } else if is_bib {
-        hallucinator_bbl::extract_references_from_bib(&file_path)
-            .map_err(|e| anyhow::anyhow!("BIB extraction failed: {}", e))?
+    // Load the Retraction Watch data once; the checker warns if it is stale.
+    if let Some(ref path) = config.retraction_db_path {
+        let db = hallucinator_core::retraction_watch::RetractionWatch::open(path).map_err(|e| {
+            anyhow::anyhow!(
+                "Cannot load Retraction Watch data {}: {}",
+                path.display(),
+                e
+            )
+        })?;
+        config.retraction_db = Some(Arc::new(db));
+    }
+
+    config.rate_limiters = Arc::new(hallucinator_core::RateLimiters::new(
+        config.crossref_mailto.is_some(),
+        config.s2_api_key.is_some(),
+        config.ncbi_api_key.is_some(),
+    ));
+    config.query_cache = Some(hallucinator_core::build_query_cache(
+        config.cache_path.as_deref(),
+        config.cache_max_entries,
+    ));
+    // Fail early on a bad proxy or certificate rather than on every request
+    hallucinator_core::context::try_build_http_client(&config).map_err(|e| anyhow::anyhow!(e))?;
+
+    // Set up progress callback
+    let progress_writer: Arc<Mutex<Box<dyn Write + Send>>> = if to_files {
+        Arc::new(Mutex::new(Box::new(std::io::stderr())))
     } else {
-        hallucinator_pdf::extract_references(&file_path)?
+        Arc::new(Mutex::new(Box::new(std::io::stdout())))
     };
 
-    let file_name = file_path
-        .file_name()
-        .map(|n| n.to_string_lossy().to_string())
-        .unwrap_or_else(|| file_path.display().to_string());
+    let cancel = CancellationToken::new();
 
-    output::print_extraction_summary(
-        &mut writer,
-        &file_name,
-        extraction.references.len(),
-        &extraction.skip_stats,
+    // Set up Ctrl+C handler
+    let cancel_clone = cancel.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            cancel_clone.cancel();
+        }
+    });
+
+    // One cache, set of rate limiters, HTTP client and validation pool for
+    // every file, as in a TUI batch
+    let context = hallucinator_core::CheckContext::from_config(&config);
+    let run = FileRun {
+        extractor: &extractor,
+        config: &config,
+        context: &context,
+        writer: Arc::new(Mutex::new(writer)),
+        progress_writer,
+        cancel: cancel.clone(),
         color,
-    )?;
+        jsonl,
+        quiet,
+        format,
+        openalex_key: config.openalex_key.is_some(),
+    };
 
-    if extraction.references.is_empty() {
-        writeln!(writer, "No references to check.")?;
-        return Ok(());
+    let report = |i: usize| reports.as_ref().map(|r| r[i].as_path());
+    if let Some(number) = explain {
+        let run = run.with_report(report(0));
+        let stats = explain_file(&run, &file_paths[0], number).await;
+        run.lock_writer()?.flush()?;
+        return Ok(output::exit_code(
+            &stats?,
+            config.author_mismatch_is_failure,
+        ));
     }
 
-    let crossref_mailto: Option<String> = std::env::var("CROSSREF_MAILTO")
-        .ok()
-        .filter(|s| !s.is_empty());
+    // Batch: a file that can't be read is reported and the rest still run.
+    let batch = file_paths.len() > 1;
+    let mut files: Vec<(String, Option<hallucinator_core::CheckStats>)> = Vec::new();
+    let mut read = Vec::new();
+    for (i, file_path) in file_paths.iter().enumerate() {
+        if cancel.is_cancelled() {
+            break;
+        }
+        let name = file_path.display().to_string();
+        match read_file(&run, file_path) {
+            Ok(extraction) => {
+                read.push(ReadFile {
+                    path: file_path,
+                    batch_index: files.len(),
+                    run: run.with_report(report(i)),
+                    extraction,
+                });
+                files.push((name, None));
+            }
+            Err(e) if !batch => return Err(e),
+            Err(e) => {
+                if jsonl {
+                    let mut w = run.lock_writer()?;
+                    writeln!(w, "{}", jsonl::error_line(&name, &e.to_string()))?;
+                } else {
+                    log::error!("{}: {:#}", name, e);
+                }
+                files.push((name, None));
+            }
+        }
+    }
 
-    // Build config
-    let num_workers = num_workers.unwrap_or(4);
-    let max_rate_limit_retries = max_rate_limit_retries.unwrap_or(3);
-    let rate_limiters = std::sync::Arc::new(hallucinator_core::RateLimiters::new(
-        crossref_mailto.is_some(),
-        s2_api_key.is_some(),
-    ));
+    let stats = check_files(&run, &read, batch).await?;
+    for (file, stats) in read.iter().zip(stats) {
+        files[file.batch_index].1 = Some(stats);
+    }
+    if !batch {
+        let stats = files.pop().and_then(|(_, stats)| stats).unwrap_or_default();
+        return Ok(output::exit_code(&stats, config.author_mismatch_is_failure));
+    }
 
-    let cache_path = cache_path.or_else(|| {
-        std::env::var("HALLUCINATOR_CACHE_PATH")
-            .ok()
-            .map(PathBuf::from)
-    });
-    let query_cache = hallucinator_core::build_query_cache(cache_path.as_deref());
-
-    let config = hallucinator_core::Config {
-        openalex_key: openalex_key.clone(),
-        s2_api_key,
-        dblp_offline_path: dblp_offline_path.clone(),
-        dblp_offline_db,
-        acl_offline_path: acl_offline_path.clone(),
-        acl_offline_db,
-        num_workers,
-        db_timeout_secs,
-        db_timeout_short_secs,
-        disabled_dbs: disable_dbs,
-        check_openalex_authors,
-        crossref_mailto,
-        max_rate_limit_retries,
-        rate_limiters,
-        query_cache: Some(query_cache),
-        cache_path,
+    let mut w = run.lock_writer()?;
+    let totals = if jsonl {
+        let totals = output::batch_totals(&files);
+        writeln!(w, "{}", jsonl::batch_summary_line(&files, &totals))?;
+        totals
+    } else {
+        output::print_batch_summary(&mut **w, &files, color)?
     };
+    w.flush()?;
+
+    let failed_files = files.iter().any(|(_, stats)| stats.is_none());
+    Ok(
+        match output::exit_code(&totals, config.author_mismatch_is_failure) {
+            code if failed_files && code == ExitCode::SUCCESS => ExitCode::FAILURE,
+            code => code,
+        },
+    )
+}
 
-    // Set up progress callback
-    let progress_writer: Arc<Mutex<Box<dyn Write + Send>>> = if output.is_some() {
-        Arc::new(Mutex::new(Box::new(std::io::stderr())))
-    } else {
-        Arc::new(Mutex::new(Box::new(std::io::stdout())))
+/// What every file of a `check` run shares.
+#[derive(Clone)]
+struct FileRun<'a> {
+    extractor: &'a hallucinator_pdf::PdfExtractor,
+    config: &'a hallucinator_core::Config,
+    context: &'a hallucinator_core::CheckContext,
+    writer: Arc<Mutex<Box<dyn Write + Send>>>,
+    progress_writer: Arc<Mutex<Box<dyn Write + Send>>>,
+    cancel: CancellationToken,
+    color: ColorMode,
+    jsonl: bool,
+    quiet: bool,
+    format: OutputFormat,
+    /// Whether an OpenAlex key is set, for the report's hints.
+    openalex_key: bool,
+}
+
+impl FileRun<'_> {
+    fn lock_writer(&self) -> anyhow::Result<std::sync::MutexGuard<'_, Box<dyn Write + Send>>> {
+        self.writer
+            .lock()
+            .map_err(|_| anyhow::anyhow!("output lock poisoned"))
+    }
+
+    /// The run for one file, writing its report to `report` if given
+    /// (`--output-dir`) rather than to the run's output.
+    fn with_report(&self, report: Option<&std::path::Path>) -> Self {
+        match report {
+            Some(report) => FileRun {
+                writer: Arc::new(Mutex::new(Box::new(ReportFile::new(report)))),
+                ..self.clone()
+            },
+            None => self.clone(),
+        }
+    }
+}
+
+/// A report file created on the first write, so a file that fails before
+/// printing anything (say, an unreadable PDF) leaves no empty report behind.
+struct ReportFile {
+    path: PathBuf,
+    file: Option<std::io::BufWriter<std::fs::File>>,
+}
+
+impl ReportFile {
+    fn new(path: &std::path::Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            file: None,
+        }
+    }
+
+    fn file(&mut self) -> std::io::Result<&mut std::io::BufWriter<std::fs::File>> {
+        let file = match self.file.take() {
+            Some(file) => file,
+            None => std::io::BufWriter::new(std::fs::File::create(&self.path).map_err(|e| {
+                std::io::Error::new(
+                    e.kind(),
+                    format!("Cannot write {}: {}", self.path.display(), e),
+                )
+            })?),
+        };
+        Ok(self.file.insert(file))
+    }
+}
+
+impl Write for ReportFile {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.file()?.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match &mut self.file {
+            Some(file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// A file of a `check` run whose references have been read.
+struct ReadFile<'a> {
+    path: &'a std::path::Path,
+    /// Its place among the batch's files, counting those that failed.
+    batch_index: usize,
+    /// The run, with this file's report writer.
+    run: FileRun<'a>,
+    extraction: hallucinator_pdf::ExtractionResult,
+}
+
+impl ReadFile<'_> {
+    fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| self.path.display().to_string())
+    }
+}
+
+/// Read the references of `file_path`.
+fn read_file(
+    run: &FileRun<'_>,
+    file_path: &std::path::Path,
+) -> anyhow::Result<hallucinator_pdf::ExtractionResult> {
+    if !file_path.exists() {
+        anyhow::bail!("File not found: {}", file_path.display());
+    }
+    hallucinator_core::input::extract_references_from_path(file_path, run.extractor, &|_| {})
+        .map_err(extraction_error)
+}
+
+/// `--explain`: check one reference of `file_path`, skipping everything else,
+/// and print how its verdict was reached.
+async fn explain_file(
+    run: &FileRun<'_>,
+    file_path: &std::path::Path,
+    number: usize,
+) -> anyhow::Result<hallucinator_core::CheckStats> {
+    let mut extraction = read_file(run, file_path)?;
+    let total = extraction.references.len();
+    let Some(pos) = extraction
+        .references
+        .iter()
+        .position(|r| r.original_number == number)
+    else {
+        anyhow::bail!(
+            "No reference #{} in {} ({} references found)",
+            number,
+            file_path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| file_path.display().to_string()),
+            total
+        );
     };
+    let reference = extraction.references.swap_remove(pos);
+    if let Some(ref reason) = reference.skip_reason {
+        explain::print_skipped(&mut **run.lock_writer()?, number, reason, run.format)?;
+        return Ok(hallucinator_core::CheckStats::default());
+    }
+    let policy = run.config.author_policy();
+    let results = hallucinator_core::check_references_with_context(
+        vec![reference.clone()],
+        run.config.clone(),
+        run.context.clone(),
+        output::log_warning,
+        run.cancel.clone(),
+    )
+    .await;
+    if let Some(result) = results.first() {
+        explain::print_explanation(
+            &mut **run.lock_writer()?,
+            number,
+            &reference,
+            result,
+            policy,
+            run.format,
+            run.color,
+        )?;
+    }
+    Ok(hallucinator_core::CheckStats::from_results(&results, 0))
+}
+
+/// Check every file's references through one validation pool and print
+/// each file's report once the pool is done; returns each file's totals.
+/// A batch's progress lines are prefixed with their file's name, as its
+/// files are checked together, and each report starts with its file's
+/// extraction summary rather than printing it before the check.
+async fn check_files(
+    run: &FileRun<'_>,
+    files: &[ReadFile<'_>],
+    batch: bool,
+) -> anyhow::Result<Vec<hallucinator_core::CheckStats>> {
+    // The output lock is never held across an await: the progress callback
+    // may share the same stream.
+    let color = run.color;
+    let quiet = run.quiet;
+    let jsonl = run.jsonl;
+
+    if !jsonl && !quiet && !batch {
+        for file in files {
+            print_extraction_summary(file)?;
+        }
+    }
+
+    // References past --max-references are dropped before the check; the
+    // rest of those missing from the results ran out of --run-deadline.
+    let capped: Vec<usize> = files
+        .iter()
+        .map(|file| {
+            hallucinator_core::checker::cap_references(
+                &mut file.extraction.references.clone(),
+                run.config.max_references,
+            )
+        })
+        .collect();
+    let duplicates: Arc<Vec<AtomicUsize>> =
+        Arc::new(files.iter().map(|_| AtomicUsize::new(0)).collect());
 
-    let progress_color = color;
     let progress_cb = {
-        let pw = Arc::clone(&progress_writer);
-        move |event: hallucinator_core::ProgressEvent| {
+        let pw = Arc::clone(&run.progress_writer);
+        let duplicates = Arc::clone(&duplicates);
+        let names: Vec<String> = files.iter().map(ReadFile::file_name).collect();
+        // JSON lines name the file as given, and number each reference as
+        // the file does
+        let papers: Vec<String> = files.iter().map(|f| f.path.display().to_string()).collect();
+        let numbers: Vec<Vec<usize>> = files
+            .iter()
+            .map(|f| jsonl::reference_numbers(&f.extraction.references, run.config.max_references))
+            .collect();
+        let sinks: Vec<_> = files.iter().map(|f| Arc::clone(&f.run.writer)).collect();
+        move |file: usize, event: hallucinator_core::ProgressEvent| {
+            if matches!(event, hallucinator_core::ProgressEvent::Duplicate { .. }) {
+                duplicates[file].fetch_add(1, Ordering::Relaxed);
+            }
+            if jsonl {
+                if matches!(event, hallucinator_core::ProgressEvent::Warning { .. }) {
+                    output::log_warning(event);
+                } else if let hallucinator_core::ProgressEvent::Result { index, result, .. } = event
+                {
+                    let number = numbers[file].get(index).copied();
+                    let line = jsonl::result_line(&papers[file], index, number, &result);
+                    if let Ok(mut w) = sinks[file].lock() {
+                        let _ = writeln!(w, "{}", line);
+                        let _ = w.flush();
+                    }
+                }
+                return;
+            }
+            if quiet {
+                return;
+            }
             if let Ok(mut w) = pw.lock() {
-                let _ = output::print_progress(&mut *w, &event, progress_color);
+                if batch {
+                    let mut buf = Vec::new();
+                    let _ = output::print_progress(&mut buf, &event, color);
+                    for line in String::from_utf8_lossy(&buf).lines() {
+                        let _ = if line.is_empty() {
+                            writeln!(w)
+                        } else {
+                            writeln!(w, "{}: {}", names[file], line)
+                        };
+                    }
+                } else {
+                    let _ = output::print_progress(&mut *w, &event, color);
+                }
                 let _ = w.flush();
             }
         }
     };
 
-    let cancel = CancellationToken::new();
-
-    // Set up Ctrl+C handler
-    let cancel_clone = cancel.clone();
-    tokio::spawn(async move {
-        if tokio::signal::ctrl_c().await.is_ok() {
-            cancel_clone.cancel();
+    let results = hallucinator_core::check_files_with_context(
+        files
+            .iter()
+            .map(|file| file.extraction.references.clone())
+            .collect(),
+        run.config.clone(),
+        run.context.clone(),
+        progress_cb,
+        run.cancel.clone(),
+    )
+    .await;
+
+    let mut stats = Vec::with_capacity(files.len());
+    for (i, (file, results)) in files.iter().zip(results).enumerate() {
+        let submitted = file.extraction.references.len();
+        let file_stats = hallucinator_core::CheckStats {
+            duplicates: duplicates[i].load(Ordering::Relaxed),
+            ..hallucinator_core::CheckStats::from_results(
+                &results,
+                submitted.saturating_sub(results.len()),
+            )
+        };
+        if jsonl {
+            let mut w = file.run.lock_writer()?;
+            writeln!(
+                w,
+                "{}",
+                jsonl::summary_line(&file.path.display().to_string(), &file_stats)
+            )?;
+            w.flush()?;
+        } else {
+            if !quiet && batch {
+                print_extraction_summary(file)?;
+            }
+            print_report(file, &results, &file_stats, capped[i])?;
         }
-    });
-
-    let skip_stats = extraction.skip_stats.clone();
-    let results =
-        hallucinator_core::check_references(extraction.references, config, progress_cb, cancel)
-            .await;
+        stats.push(file_stats);
+    }
+    Ok(stats)
+}
 
-    // Print final report
-    writeln!(writer)?;
+fn print_extraction_summary(file: &ReadFile<'_>) -> anyhow::Result<()> {
+    let extraction = &file.extraction;
+    output::print_extraction_summary(
+        &mut **file.run.lock_writer()?,
+        &file.file_name(),
+        extraction.references.len(),
+        &extraction.skip_stats,
+        extraction.ocr_used,
+        extraction.ocr_error.as_deref(),
+        file.run.color,
+    )?;
+    Ok(())
+}
 
-    output::print_hallucination_report(&mut writer, &results, openalex_key.is_some(), color)?;
+/// Print one file's report; --quiet keeps only the summary.
+fn print_report(
+    file: &ReadFile<'_>,
+    results: &[hallucinator_core::ValidationResult],
+    stats: &hallucinator_core::CheckStats,
+    capped: usize,
+) -> anyhow::Result<()> {
+    let run = &file.run;
+    let color = run.color;
+    let mut writer = run.lock_writer()?;
+    let writer: &mut dyn Write = &mut **writer;
+    if file.extraction.references.is_empty() {
+        writeln!(writer, "No references to check.")?;
+        writer.flush()?;
+        return Ok(());
+    }
+    if !run.quiet {
+        writeln!(writer)?;
 
-    output::print_doi_issues(&mut writer, &results, color)?;
-    output::print_retraction_warnings(&mut writer, &results, color)?;
-    output::print_summary(&mut writer, &results, &skip_stats, color)?;
+        output::print_hallucination_report(writer, results, run.openalex_key, color)?;
 
+        output::print_doi_issues(writer, results, color)?;
+        output::print_retraction_warnings(writer, results, color)?;
+        output::print_editorial_notices(writer, results, color)?;
+        output::print_title_near_misses(writer, results, color)?;
+    }
+    output::print_summary(
+        writer,
+        results,
+        &file.extraction.skip_stats,
+        stats.duplicates,
+        capped,
+        stats.skipped.saturating_sub(capped),
+        color,
+    )?;
+    writer.flush()?;
     Ok(())
 }
 
@@ -417,6 +1291,7 @@ async fn dry_run_check(
     file_path: PathBuf,
     no_color: bool,
     output: Option<PathBuf>,
+    extractor: hallucinator_pdf::PdfExtractor,
 ) -> anyhow::Result<()> {
     let use_color = !no_color && output.is_none();
 
@@ -435,34 +1310,62 @@ async fn dry_run_check(
         .map(|n| n.to_string_lossy().to_string())
         .unwrap_or_else(|| file_path.display().to_string());
 
-    let is_bbl = file_path
-        .extension()
-        .map(|e| e.eq_ignore_ascii_case("bbl"))
-        .unwrap_or(false);
-    let is_bib = file_path
-        .extension()
-        .map(|e| e.eq_ignore_ascii_case("bib"))
-        .unwrap_or(false);
-
-    if is_bbl || is_bib {
-        dry_run_bbl(&file_path, &file_name, use_color, &mut writer)
+    // Only PDFs have a raw segmentation step worth showing; everything else
+    // is listed as parsed.
+    if hallucinator_core::input::InputKind::from_path(&file_path)
+        != hallucinator_core::input::InputKind::Pdf
+    {
+        dry_run_parsed(&file_path, &file_name, &extractor, use_color, &mut writer)
     } else {
-        dry_run_pdf(&file_path, &file_name, use_color, &mut writer)
+        dry_run_pdf(&file_path, &file_name, &extractor, use_color, &mut writer)
+    }
+}
+
+/// The PDF extractor for `check`, with any `--section-heading`s added to the
+/// built-in references headings.
+fn pdf_extractor(
+    section_headings: Vec<String>,
+    options: hallucinator_pdf::ExtractionOptions,
+) -> anyhow::Result<hallucinator_pdf::PdfExtractor> {
+    let config = section_headings
+        .into_iter()
+        .fold(
+            hallucinator_pdf::PdfParsingConfigBuilder::new(),
+            |builder, heading| builder.add_section_heading(heading),
+        )
+        .build()
+        .map_err(|e| anyhow::anyhow!("Invalid --section-heading: {}", e))?;
+    Ok(hallucinator_pdf::PdfExtractor::with_config(config).with_options(options))
+}
+
+/// Point an encrypted PDF's error at `--pdf-password`.
+fn extraction_error(e: hallucinator_core::CoreError) -> anyhow::Error {
+    match e {
+        hallucinator_core::CoreError::Pdf(hallucinator_pdf::PdfError::Encrypted) => {
+            anyhow::anyhow!(
+                "PDF is encrypted; pass its password with --pdf-password or HALLUCINATOR_PDF_PASSWORD"
+            )
+        }
+        e => e.into(),
     }
 }
 
 fn dry_run_pdf(
     file_path: &std::path::Path,
     file_name: &str,
+    extractor: &hallucinator_pdf::PdfExtractor,
     use_color: bool,
     writer: &mut Box<dyn Write>,
 ) -> anyhow::Result<()> {
     use owo_colors::OwoColorize;
 
-    let text = hallucinator_pdf::extract::extract_text_from_pdf(file_path)?;
-    let ref_section = hallucinator_pdf::section::find_references_section(&text)
+    let text = extractor
+        .extract_text(file_path)
+        .map_err(|e| extraction_error(e.into()))?;
+    let ref_section = extractor
+        .find_references_section(&text)
         .ok_or_else(|| anyhow::anyhow!("No references section found"))?;
-    let raw_refs = hallucinator_pdf::section::segment_references(&ref_section);
+    let raw_refs = extractor.segment_references(&ref_section);
 
     if use_color {
         writeln!(
@@ -553,102 +1456,72 @@ fn dry_run_pdf(
     Ok(())
 }
 
-fn dry_run_bbl(
+fn dry_run_parsed(
     file_path: &std::path::Path,
     file_name: &str,
+    extractor: &hallucinator_pdf::PdfExtractor,
     use_color: bool,
     writer: &mut Box<dyn Write>,
 ) -> anyhow::Result<()> {
-    use owo_colors::OwoColorize;
+    let extraction =
+        hallucinator_core::input::extract_references_from_path(file_path, extractor, &|_| {})?;
+    extract_only::write(
+        writer,
+        "DRY RUN:",
+        file_name,
+        &extraction,
+        OutputFormat::Text,
+        ColorMode(use_color),
+    )?;
+    Ok(())
+}
 
-    let is_bib = file_path
-        .extension()
-        .map(|e| e.eq_ignore_ascii_case("bib"))
-        .unwrap_or(false);
-    let extraction = if is_bib {
-        hallucinator_bbl::extract_references_from_bib(file_path)
-            .map_err(|e| anyhow::anyhow!("BIB extraction failed: {}", e))?
+/// `--extract-only`: run the extraction `check` would, print what it found,
+/// and exit 1 if nothing is left to check.
+fn extract_only_check(
+    file_path: PathBuf,
+    no_color: bool,
+    output: Option<PathBuf>,
+    format: OutputFormat,
+    extractor: hallucinator_pdf::PdfExtractor,
+) -> anyhow::Result<ExitCode> {
+    if !file_path.exists() {
+        anyhow::bail!("File not found: {}", file_path.display());
+    }
+    let color = ColorMode(!no_color && output.is_none() && format == OutputFormat::Text);
+    let mut writer: Box<dyn Write> = if let Some(ref output_path) = output {
+        Box::new(std::fs::File::create(output_path)?)
     } else {
-        hallucinator_bbl::extract_references_from_bbl(file_path)
-            .map_err(|e| anyhow::anyhow!("BBL extraction failed: {}", e))?
+        Box::new(std::io::stdout())
     };
 
-    let total = extraction.skip_stats.total_raw;
-    let kept = extraction.references.len();
-
-    if use_color {
-        writeln!(
-            writer,
-            "{} {} ({} entries, {} after filtering)\n",
-            "DRY RUN:".bold().cyan(),
-            file_name.bold(),
-            total,
-            kept
-        )?;
-    } else {
-        writeln!(
-            writer,
-            "DRY RUN: {} ({} entries, {} after filtering)\n",
-            file_name, total, kept
-        )?;
-    }
-
-    for (i, reference) in extraction.references.iter().enumerate() {
-        let title = reference.title.as_deref().unwrap_or("");
-
-        if use_color {
-            writeln!(writer, "{}", format!("[{}]", i + 1).bold().yellow())?;
-        } else {
-            writeln!(writer, "[{}]", i + 1)?;
-        }
-
-        writeln!(writer, "  Title:   {}", title)?;
-        writeln!(
-            writer,
-            "  Authors: {}",
-            if reference.authors.is_empty() {
-                "(none)".to_string()
-            } else {
-                reference.authors.join("; ")
-            }
-        )?;
-
-        if let Some(ref d) = reference.doi {
-            writeln!(writer, "  DOI:     {}", d)?;
-        }
-        if let Some(ref a) = reference.arxiv_id {
-            writeln!(writer, "  arXiv:   {}", a)?;
-        }
-
-        // Truncate raw citation for display
-        let raw_display = if reference.raw_citation.len() > 200 {
-            format!("{}...", &reference.raw_citation[..200])
-        } else {
-            reference.raw_citation.clone()
-        };
-
-        if use_color {
-            writeln!(writer, "  Raw:     {}", raw_display.dimmed())?;
-        } else {
-            writeln!(writer, "  Raw:     {}", raw_display)?;
-        }
-
-        writeln!(writer)?;
-    }
+    let file_name = file_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| file_path.display().to_string());
+    let extraction =
+        hallucinator_core::input::extract_references_from_path(&file_path, &extractor, &|_| {})
+            .map_err(extraction_error)?;
 
-    let stats = &extraction.skip_stats;
-    writeln!(
-        writer,
-        "Total: {} raw entries ({} kept, {} skipped: {} URL-only, {} short title, {} no title)",
-        stats.total_raw,
-        kept,
-        stats.url_only + stats.short_title + stats.no_title,
-        stats.url_only,
-        stats.short_title,
-        stats.no_title
+    extract_only::write(
+        &mut writer,
+        "EXTRACT ONLY:",
+        &file_name,
+        &extraction,
+        format,
+        color,
     )?;
-
-    Ok(())
+    writer.flush()?;
+
+    let checkable = extraction
+        .references
+        .iter()
+        .any(|r| r.skip_reason.is_none());
+    Ok(if checkable {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
 }
 
 async fn update_dblp(db_path: &PathBuf) -> anyhow::Result<()> {
@@ -716,6 +1589,24 @@ async fn update_dblp(db_path: &PathBuf) -> anyhow::Result<()> {
                 dl_bar.set_message("Downloading dblp.xml.gz");
             }
         }
+        hallucinator_dblp::BuildProgress::Verifying {
+            bytes_checked,
+            bytes_total,
+        } => {
+            if !dl_bar.is_finished() {
+                dl_bar.finish_with_message(format!(
+                    "Downloaded {} in {:.0?}",
+                    HumanBytes(dl_bar.position()),
+                    dl_bar.elapsed()
+                ));
+            }
+            parse_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
+            parse_bar.set_message(format!(
+                "Verifying checksum... {}/{}",
+                HumanBytes(bytes_checked),
+                HumanBytes(bytes_total)
+            ));
+        }
         hallucinator_dblp::BuildProgress::Parsing {
             records_inserted,
             bytes_read,
@@ -1,8 +1,20 @@
 use std::io::Write;
+use std::process::ExitCode;
 
-use hallucinator_core::{ProgressEvent, SkipStats, Status, ValidationResult};
+use hallucinator_core::{CheckStats, ProgressEvent, SkipStats, Status, ValidationResult};
 use owo_colors::OwoColorize;
 
+/// Output format for `check`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable report
+    Text,
+    /// One JSON document (with `--explain` or `--extract-only`)
+    Json,
+    /// One JSON object per line, written as each reference completes
+    Jsonl,
+}
+
 /// Whether to use colored output.
 #[derive(Debug, Clone, Copy)]
 pub struct ColorMode(pub bool);
@@ -13,15 +25,55 @@ impl ColorMode {
     }
 }
 
+/// Exit status for a finished check; the most severe finding wins.
+///
+/// 3: a retracted paper was cited. 2: an author mismatch. 1: a reference was
+/// not found. 0: everything checked was verified. With
+/// `author_mismatch_is_failure`, author mismatches exit with 1 as well.
+pub fn exit_code(stats: &CheckStats, author_mismatch_is_failure: bool) -> ExitCode {
+    let code = if stats.retracted > 0 {
+        3
+    } else if stats.author_mismatch > 0 && !author_mismatch_is_failure {
+        2
+    } else if stats.not_found + stats.author_mismatch > 0 {
+        1
+    } else {
+        0
+    };
+    ExitCode::from(code)
+}
+
 /// Print the extraction summary after PDF parsing.
 pub fn print_extraction_summary(
     w: &mut dyn Write,
     pdf_name: &str,
     total_refs: usize,
     skip_stats: &SkipStats,
+    ocr_used: bool,
+    ocr_error: Option<&str>,
     color: ColorMode,
 ) -> std::io::Result<()> {
     writeln!(w, "Extracting references from {}...", pdf_name)?;
+    let ocr_note = if ocr_used {
+        Some(
+            "No text layer found; references were read by OCR and may contain recognition errors"
+                .to_string(),
+        )
+    } else {
+        ocr_error.map(|e| {
+            format!(
+                "Little embedded text found and OCR failed ({}); references may be incomplete",
+                e
+            )
+        })
+    };
+    if let Some(msg) = ocr_note {
+        if color.enabled() {
+            writeln!(w, "{}", msg.yellow())?;
+        } else {
+            writeln!(w, "{}", msg)?;
+        }
+    }
     writeln!(w, "Found {} references to check", total_refs)?;
 
     let skipped = skip_stats.url_only + skip_stats.short_title;
@@ -44,6 +96,17 @@ pub fn print_extraction_summary(
             )?;
         }
     }
+    if skip_stats.unresolved_footnote > 0 {
+        let msg = format!(
+            "(Skipped {} footnotes with no title of their own, e.g. ibid.)",
+            skip_stats.unresolved_footnote
+        );
+        if color.enabled() {
+            writeln!(w, "{}", msg.dimmed())?;
+        } else {
+            writeln!(w, "{}", msg)?;
+        }
+    }
     writeln!(w)?;
     Ok(())
 }
@@ -67,6 +130,18 @@ pub fn print_progress(
             };
             writeln!(w, "[{}/{}] Checking: \"{}\"", index + 1, total, short)?;
         }
+        ProgressEvent::Duplicate { index, total, .. } => {
+            let msg = format!(
+                "[{}/{}] Same title as an earlier reference; sharing its result",
+                index + 1,
+                total
+            );
+            if color.enabled() {
+                writeln!(w, "{}", msg.dimmed())?;
+            } else {
+                writeln!(w, "{}", msg)?;
+            }
+        }
         ProgressEvent::Result {
             index,
             total,
@@ -128,16 +203,48 @@ pub fn print_progress(
                 count
             )?;
         }
+        ProgressEvent::RateLimited {
+            db_name,
+            retry_after,
+            ..
+        } => {
+            let msg = format!(
+                "{} throttled, waiting {}s",
+                db_name,
+                retry_after.as_secs_f64().ceil() as u64
+            );
+            if color.enabled() {
+                writeln!(w, "{}", msg.dimmed())?;
+            } else {
+                writeln!(w, "{}", msg)?;
+            }
+        }
         ProgressEvent::Retrying { .. }
         | ProgressEvent::DatabaseQueryComplete { .. }
         | ProgressEvent::RateLimitWait { .. }
-        | ProgressEvent::RateLimitRetry { .. } => {
+        | ProgressEvent::RateLimitRetry { .. }
+        | ProgressEvent::RateLimitStatus { .. }
+        | ProgressEvent::ConcurrencyChanged { .. } => {
             // Not displayed in CLI output
         }
     }
     Ok(())
 }
 
+/// Log a run-level warning (a stale database, say) to stderr, for output
+/// modes that print no progress: `--format jsonl` and `--explain`.
+pub fn log_warning(event: ProgressEvent) {
+    if let ProgressEvent::Warning {
+        message,
+        failed_dbs,
+        ..
+    } = event
+        && failed_dbs.is_empty()
+    {
+        log::warn!("{}", message);
+    }
+}
+
 /// Print the detailed hallucination/mismatch report for all problematic references.
 pub fn print_hallucination_report(
     w: &mut dyn Write,
@@ -316,7 +423,7 @@ pub fn print_doi_issues(
 ) -> std::io::Result<()> {
     let issues: Vec<_> = results
         .iter()
-        .filter(|r| r.doi_info.as_ref().is_some_and(|d| !d.valid))
+        .filter(|r| r.doi_info.as_ref().is_some_and(|d| d.has_issue()))
         .collect();
 
     if issues.is_empty() {
@@ -342,15 +449,25 @@ pub fn print_doi_issues(
     for result in issues {
         let doi_info = result.doi_info.as_ref().unwrap();
         let short_title = truncate(&result.title, 70);
+        let issue = if doi_info.title_mismatch {
+            format!(
+                "DOI resolves to a different title: {}",
+                doi_info.title.as_deref().unwrap_or("")
+            )
+        } else if doi_info.structurally_valid {
+            "DOI does not resolve".to_string()
+        } else {
+            "DOI is malformed (not a valid 10.NNNN/... identifier)".to_string()
+        };
         writeln!(w)?;
         if color.enabled() {
             writeln!(w, "{} {}", "Reference:".bold(), short_title)?;
             writeln!(w, "{} {}", "DOI:".bold(), doi_info.doi)?;
-            writeln!(w, "{} DOI does not resolve", "Issue:".red())?;
+            writeln!(w, "{} {}", "Issue:".red(), issue)?;
         } else {
             writeln!(w, "Reference: {}", short_title)?;
             writeln!(w, "DOI: {}", doi_info.doi)?;
-            writeln!(w, "Issue: DOI does not resolve")?;
+            writeln!(w, "Issue: {}", issue)?;
         }
     }
     writeln!(w)?;
@@ -416,11 +533,115 @@ pub fn print_retraction_warnings(
     Ok(())
 }
 
-/// Print the final summary.
+/// Print editorial notices short of a retraction, such as expressions of
+/// concern from the Retraction Watch data.
+pub fn print_editorial_notices(
+    w: &mut dyn Write,
+    results: &[ValidationResult],
+    color: ColorMode,
+) -> std::io::Result<()> {
+    let noticed: Vec<_> = results
+        .iter()
+        .filter_map(|r| {
+            let ri = r.retraction_info.as_ref().filter(|ri| !ri.is_retracted)?;
+            Some((r, ri))
+        })
+        .collect();
+
+    if noticed.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(w)?;
+    let sep = "=".repeat(60);
+    if color.enabled() {
+        writeln!(w, "{}", sep.bold().yellow())?;
+        writeln!(w, "{}", "EDITORIAL NOTICES".bold().yellow())?;
+        writeln!(w, "{}", sep.bold().yellow())?;
+    } else {
+        writeln!(w, "{}", sep)?;
+        writeln!(w, "EDITORIAL NOTICES")?;
+        writeln!(w, "{}", sep)?;
+    }
+
+    for (result, ri) in &noticed {
+        let short_title = truncate(&result.title, 70);
+        let kind = ri.retraction_source.as_deref().unwrap_or("Notice");
+        writeln!(w)?;
+        if color.enabled() {
+            writeln!(w, "{} {}", "Reference:".bold(), short_title)?;
+            writeln!(w, "{} {}", "Notice:".yellow().bold(), kind)?;
+        } else {
+            writeln!(w, "Reference: {}", short_title)?;
+            writeln!(w, "Notice: {}", kind)?;
+        }
+        if let Some(ref doi) = ri.retraction_doi {
+            writeln!(w, "Notice DOI: https://doi.org/{}", doi)?;
+        }
+    }
+    writeln!(w)?;
+    Ok(())
+}
+
+/// Print matches whose title differs from the cited one (`--strict-title`).
+/// Informational: these references keep their verdict and don't count as
+/// problems.
+pub fn print_title_near_misses(
+    w: &mut dyn Write,
+    results: &[ValidationResult],
+    color: ColorMode,
+) -> std::io::Result<()> {
+    let near_misses: Vec<_> = results
+        .iter()
+        .filter_map(|r| r.title_near_miss.as_ref().map(|m| (r, m)))
+        .collect();
+
+    if near_misses.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(w)?;
+    let sep = "=".repeat(60);
+    if color.enabled() {
+        writeln!(w, "{}", sep.bold().yellow())?;
+        writeln!(w, "{}", "TITLE NEAR MISSES".bold().yellow())?;
+        writeln!(w, "{}", sep.bold().yellow())?;
+    } else {
+        writeln!(w, "{}", sep)?;
+        writeln!(w, "TITLE NEAR MISSES")?;
+        writeln!(w, "{}", sep)?;
+    }
+
+    for (result, near_miss) in &near_misses {
+        let source = result.source.as_deref().unwrap_or("unknown");
+        writeln!(w)?;
+        if color.enabled() {
+            writeln!(w, "{} {}", "Cited:".bold(), near_miss.cited_title)?;
+            writeln!(w, "{} {}", "Found:".yellow().bold(), near_miss.found_title)?;
+        } else {
+            writeln!(w, "Cited: {}", near_miss.cited_title)?;
+            writeln!(w, "Found: {}", near_miss.found_title)?;
+        }
+        writeln!(
+            w,
+            "Similarity: {:.2} (via {})",
+            near_miss.similarity, source
+        )?;
+    }
+    writeln!(w)?;
+    Ok(())
+}
+
+/// Print the final summary. `capped` references were dropped by
+/// `--max-references` and `unchecked` ones were still pending when
+/// `--run-deadline` expired.
 pub fn print_summary(
     w: &mut dyn Write,
     results: &[ValidationResult],
     skip_stats: &SkipStats,
+    duplicates: usize,
+    capped: usize,
+    unchecked: usize,
     color: ColorMode,
 ) -> std::io::Result<()> {
     let verified = results
@@ -466,6 +687,17 @@ pub fn print_summary(
             writeln!(w, "  {}", msg)?;
         }
     }
+    if skip_stats.unresolved_footnote > 0 {
+        let msg = format!(
+            "Unresolved footnotes (ibid., op. cit.): {}",
+            skip_stats.unresolved_footnote
+        );
+        if color.enabled() {
+            writeln!(w, "  {}", msg.dimmed())?;
+        } else {
+            writeln!(w, "  {}", msg)?;
+        }
+    }
     if skip_stats.no_authors > 0 {
         let msg = format!(
             "Title-only (no authors extracted): {}",
@@ -477,6 +709,30 @@ pub fn print_summary(
             writeln!(w, "  {}", msg)?;
         }
     }
+    if capped > 0 {
+        let msg = format!("Skipped (over --max-references): {}", capped);
+        if color.enabled() {
+            writeln!(w, "  {}", msg.dimmed())?;
+        } else {
+            writeln!(w, "  {}", msg)?;
+        }
+    }
+    if unchecked > 0 {
+        let msg = format!("Unchecked (deadline): {}", unchecked);
+        if color.enabled() {
+            writeln!(w, "  {}", msg.yellow())?;
+        } else {
+            writeln!(w, "  {}", msg)?;
+        }
+    }
+    if duplicates > 0 {
+        let msg = format!("Duplicate lookups avoided: {}", duplicates);
+        if color.enabled() {
+            writeln!(w, "  {}", msg.dimmed())?;
+        } else {
+            writeln!(w, "  {}", msg)?;
+        }
+    }
     writeln!(w)?;
 
     if color.enabled() {
@@ -531,6 +787,89 @@ pub fn print_summary(
     Ok(())
 }
 
+/// Totals over the files of a batch that could be checked.
+pub fn batch_totals(files: &[(String, Option<CheckStats>)]) -> CheckStats {
+    let mut totals = CheckStats::default();
+    for stats in files.iter().filter_map(|(_, stats)| stats.as_ref()) {
+        totals.total += stats.total;
+        totals.verified += stats.verified;
+        totals.not_found += stats.not_found;
+        totals.author_mismatch += stats.author_mismatch;
+        totals.retracted += stats.retracted;
+        totals.skipped += stats.skipped;
+        totals.duplicates += stats.duplicates;
+    }
+    totals
+}
+
+/// Print the closing summary of a batch: one line per file, then the totals.
+/// Returns the totals.
+pub fn print_batch_summary(
+    w: &mut dyn Write,
+    files: &[(String, Option<CheckStats>)],
+    color: ColorMode,
+) -> std::io::Result<CheckStats> {
+    let sep = "=".repeat(60);
+    if color.enabled() {
+        writeln!(w, "{}", sep.bold())?;
+        writeln!(
+            w,
+            "{}",
+            format!("BATCH SUMMARY ({} files)", files.len()).bold()
+        )?;
+        writeln!(w, "{}", sep.bold())?;
+    } else {
+        writeln!(w, "{}", sep)?;
+        writeln!(w, "BATCH SUMMARY ({} files)", files.len())?;
+        writeln!(w, "{}", sep)?;
+    }
+
+    for (file, stats) in files {
+        let Some(stats) = stats else {
+            if color.enabled() {
+                writeln!(w, "  {}  {}", file, "could not be checked".red())?;
+            } else {
+                writeln!(w, "  {}  could not be checked", file)?;
+            }
+            continue;
+        };
+        let line = format!(
+            "  {}  {} verified, {} not found, {} author mismatches, {} retracted",
+            file, stats.verified, stats.not_found, stats.author_mismatch, stats.retracted
+        );
+        if !color.enabled() {
+            writeln!(w, "{}", line)?;
+        } else if stats.not_found + stats.retracted > 0 {
+            writeln!(w, "{}", line.red())?;
+        } else if stats.author_mismatch > 0 {
+            writeln!(w, "{}", line.yellow())?;
+        } else {
+            writeln!(w, "{}", line.green())?;
+        }
+    }
+
+    let totals = batch_totals(files);
+    writeln!(w)?;
+    writeln!(w, "  References analyzed: {}", totals.total)?;
+    writeln!(w, "  Verified: {}", totals.verified)?;
+    writeln!(w, "  Author mismatches: {}", totals.author_mismatch)?;
+    writeln!(
+        w,
+        "  Not found (potential hallucinations): {}",
+        totals.not_found
+    )?;
+    writeln!(w, "  Retracted papers: {}", totals.retracted)?;
+    if totals.skipped > 0 {
+        writeln!(
+            w,
+            "  Skipped (over --max-references or unchecked at the deadline): {}",
+            totals.skipped
+        )?;
+    }
+    writeln!(w)?;
+    Ok(totals)
+}
+
 fn truncate(s: &str, max: usize) -> String {
     if s.len() > max {
         format!("{}...", &s[..max])
@@ -1,5 +1,12 @@
 use once_cell::sync::Lazy;
 use std::collections::HashSet;
+use unicode_normalization::UnicodeNormalization;
+
+/// Default surname similarity (0.0–1.0) for two author names to match.
+///
+/// Tolerates a one-letter slip in surnames of ten or more letters; shorter
+/// surnames must agree exactly after normalization.
+pub const DEFAULT_AUTHOR_MATCH_THRESHOLD: f64 = 0.9;
 
 /// Common surname prefixes (case-insensitive).
 static SURNAME_PREFIXES: Lazy<HashSet<&'static str>> = Lazy::new(|| {
@@ -17,20 +24,105 @@ static NAME_SUFFIXES: Lazy<HashSet<&'static str>> =
 
 /// Validate that at least one author in `ref_authors` matches one in `found_authors`.
 ///
-/// Uses two modes:
-/// - **Last-name-only mode**: If most PDF-extracted authors lack first names/initials,
-///   compare only surnames (with partial suffix matching for multi-word surnames).
-/// - **Full mode**: Normalize to "FirstInitial surname" and check for set intersection.
+/// The default [`AuthorMatchPolicy`]; see [`author_overlap`] for how names
+/// are compared.
 pub fn validate_authors(ref_authors: &[String], found_authors: &[String]) -> bool {
-    if ref_authors.is_empty() || found_authors.is_empty() {
-        return false;
+    AuthorMatchPolicy::default().accepts(ref_authors, found_authors)
+}
+
+/// How many cited authors appear in a found author list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AuthorOverlap {
+    /// Cited authors found in the list.
+    pub matched: usize,
+    /// Cited authors compared (blank names are ignored).
+    pub cited: usize,
+    /// Whether the first cited author is in the list.
+    pub first_author: bool,
+}
+
+impl AuthorOverlap {
+    /// Fraction of cited authors found; 0.0 when none were compared.
+    pub fn fraction(&self) -> f32 {
+        if self.cited == 0 {
+            0.0
+        } else {
+            self.matched as f32 / self.cited as f32
+        }
+    }
+}
+
+/// How closely a found author list must agree with the cited one before the
+/// reference counts as verified rather than an author mismatch.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AuthorMatchPolicy {
+    /// Fraction of cited authors (0.0–1.0) that must be in the found list.
+    /// At least one always has to match, so 0.0 asks for nothing more.
+    pub min_overlap: f32,
+    /// Whether the first cited author must be in the found list.
+    pub require_first_author: bool,
+    /// Similarity (0.0–1.0) two normalized surnames need to count as the
+    /// same; 1.0 requires them to be equal.
+    pub surname_threshold: f64,
+}
+
+impl Default for AuthorMatchPolicy {
+    fn default() -> Self {
+        Self {
+            min_overlap: 0.0,
+            require_first_author: false,
+            surname_threshold: DEFAULT_AUTHOR_MATCH_THRESHOLD,
+        }
+    }
+}
+
+impl AuthorMatchPolicy {
+    /// Whether `found_authors` agrees with `ref_authors` under this policy.
+    pub fn accepts(&self, ref_authors: &[String], found_authors: &[String]) -> bool {
+        self.accepts_overlap(&self.overlap(ref_authors, found_authors))
+    }
+
+    /// The overlap between the two lists, comparing surnames at this
+    /// policy's threshold.
+    pub fn overlap(&self, ref_authors: &[String], found_authors: &[String]) -> AuthorOverlap {
+        author_overlap_at(ref_authors, found_authors, self.surname_threshold)
     }
 
+    /// Whether an already computed overlap satisfies this policy.
+    pub fn accepts_overlap(&self, overlap: &AuthorOverlap) -> bool {
+        overlap.matched > 0
+            && overlap.fraction() + f32::EPSILON >= self.min_overlap
+            && (!self.require_first_author || overlap.first_author)
+    }
+}
+
+/// Compare each cited author against `found_authors` at the default
+/// surname threshold.
+pub fn author_overlap(ref_authors: &[String], found_authors: &[String]) -> AuthorOverlap {
+    author_overlap_at(ref_authors, found_authors, DEFAULT_AUTHOR_MATCH_THRESHOLD)
+}
+
+/// Compare each cited author against `found_authors`.
+///
+/// Uses two modes:
+/// - **Last-name-only mode**: If most PDF-extracted authors lack first names/initials,
+///   compare only surnames (with partial suffix matching for multi-word surnames).
+/// - **Full mode**: Normalize to "FirstInitial surname" and look each one up in
+///   the normalized found set; failing that, look for a name with the same
+///   initial whose surname passes [`surnames_match`] at `surname_threshold`.
+pub fn author_overlap_at(
+    ref_authors: &[String],
+    found_authors: &[String],
+    surname_threshold: f64,
+) -> AuthorOverlap {
     let ref_clean: Vec<&str> = ref_authors
         .iter()
         .map(|a| a.trim())
         .filter(|a| !a.is_empty())
         .collect();
+    if ref_clean.is_empty() || found_authors.is_empty() {
+        return AuthorOverlap::default();
+    }
 
     // Determine if ref authors are last-name-only
     let last_name_only_count = ref_clean
@@ -39,40 +131,51 @@ pub fn validate_authors(ref_authors: &[String], found_authors: &[String]) -> boo
         .count();
     let ref_are_last_name_only = last_name_only_count > ref_clean.len() / 2;
 
-    if ref_are_last_name_only {
-        let ref_surnames: Vec<String> = ref_authors
+    let matches: Vec<bool> = if ref_are_last_name_only {
+        let found_surnames: Vec<String> = found_authors
             .iter()
-            .filter_map(|a| {
-                let s = get_last_name(a);
-                if s.is_empty() { None } else { Some(s) }
-            })
+            .map(|a| get_last_name(a))
+            .filter(|s| !s.is_empty())
             .collect();
 
-        let found_surnames: Vec<String> = found_authors
+        ref_clean
             .iter()
-            .filter_map(|a| {
-                let s = get_last_name(a);
-                if s.is_empty() { None } else { Some(s) }
+            .map(|a| get_last_name(a))
+            .filter(|s| !s.is_empty())
+            .map(|rn| {
+                found_surnames.iter().any(|fn_| {
+                    // Check if one surname ends with the other
+                    rn == *fn_
+                        || fn_.ends_with(rn.as_str())
+                        || rn.ends_with(fn_.as_str())
+                        || surnames_match(&rn, fn_, surname_threshold)
+                })
             })
-            .collect();
-
-        for rn in &ref_surnames {
-            for fn_ in &found_surnames {
-                if rn == fn_ {
-                    return true;
-                }
-                // Check if one surname ends with the other
-                if fn_.ends_with(rn.as_str()) || rn.ends_with(fn_.as_str()) {
-                    return true;
-                }
-            }
-        }
-        false
+            .collect()
     } else {
-        let ref_set: HashSet<String> = ref_authors.iter().map(|a| normalize_author(a)).collect();
         let found_set: HashSet<String> =
             found_authors.iter().map(|a| normalize_author(a)).collect();
-        !ref_set.is_disjoint(&found_set)
+        let found_split: Vec<(char, String)> = found_authors
+            .iter()
+            .filter_map(|a| split_author(a))
+            .collect();
+        ref_clean
+            .iter()
+            .map(|a| {
+                found_set.contains(&normalize_author(a))
+                    || split_author(a).is_some_and(|(initial, surname)| {
+                        found_split.iter().any(|(fi, fs)| {
+                            *fi == initial && surnames_match(&surname, fs, surname_threshold)
+                        })
+                    })
+            })
+            .collect()
+    };
+
+    AuthorOverlap {
+        matched: matches.iter().filter(|&&m| m).count(),
+        cited: matches.len(),
+        first_author: matches.first().copied().unwrap_or(false),
     }
 }
 
@@ -145,6 +248,93 @@ fn normalize_author(name: &str) -> String {
     format!("{} {}", first_initial, surname.to_lowercase())
 }
 
+/// Split an author name into a folded first initial and the surname, as
+/// [`normalize_author`] reads them.
+fn split_author(name: &str) -> Option<(char, String)> {
+    let normalized = normalize_author(name);
+    let (initial, surname) = normalized.split_once(' ')?;
+    let initial = fold_name(initial).chars().next()?;
+    Some((initial, surname.to_string()))
+}
+
+/// Whether two surnames name the same person.
+///
+/// Both are folded first (case, diacritics, hyphens and spaces), so
+/// "García-López" equals "Garcia Lopez". German transliterations are
+/// accepted ("Müller", "Mueller", "Muller"), as is one part of a hyphenated
+/// surname on its own ("Smith-Jones" vs "Jones"). Anything else must reach
+/// `threshold` similarity; 1.0 turns the fuzzy comparison off.
+pub fn surnames_match(a: &str, b: &str, threshold: f64) -> bool {
+    let (ka, kb) = (fold_name(a), fold_name(b));
+    if ka.is_empty() || kb.is_empty() {
+        return false;
+    }
+    if ka == kb || collapse_umlauts(&ka) == collapse_umlauts(&kb) {
+        return true;
+    }
+
+    let (pa, pb) = (surname_parts(a), surname_parts(b));
+    if (pa.len() > 1 && pa.contains(&kb)) || (pb.len() > 1 && pb.contains(&ka)) {
+        return true;
+    }
+
+    threshold < 1.0 && rapidfuzz::fuzz::ratio(ka.chars(), kb.chars()) >= threshold
+}
+
+/// Lowercase ASCII letters and digits of `name`, with diacritics stripped
+/// and letters NFKD cannot decompose (ß, ø, ł, …) transliterated.
+fn fold_name(name: &str) -> String {
+    let mut expanded = String::with_capacity(name.len());
+    for c in name.chars().flat_map(char::to_lowercase) {
+        match c {
+            'ß' => expanded.push_str("ss"),
+            'æ' => expanded.push_str("ae"),
+            'œ' => expanded.push_str("oe"),
+            'ø' => expanded.push('o'),
+            'ł' => expanded.push('l'),
+            'đ' | 'ð' => expanded.push('d'),
+            'þ' => expanded.push_str("th"),
+            'ı' => expanded.push('i'),
+            _ => expanded.push(c),
+        }
+    }
+    expanded
+        .nfkd()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect()
+}
+
+/// Map the German umlaut transliterations "ae", "oe", "ue" to their base
+/// vowel, so a folded "Müller" ("muller") and "Mueller" agree.
+fn collapse_umlauts(folded: &str) -> String {
+    folded
+        .replace("ae", "a")
+        .replace("oe", "o")
+        .replace("ue", "u")
+}
+
+/// The folded parts of a hyphenated or multi-word surname, without
+/// prefixes like "van" and without one- or two-letter fragments.
+fn surname_parts(surname: &str) -> Vec<String> {
+    surname
+        .split(|c: char| c == '-' || c.is_whitespace())
+        .filter(|p| !SURNAME_PREFIXES.contains(p.to_lowercase().as_str()))
+        .map(fold_name)
+        .filter(|p| p.len() >= 3)
+        .collect()
+}
+
+/// The cited authors' folded surnames, in order: what two author lists
+/// must share to count as the same citation, whatever the name format.
+pub fn surname_key(authors: &[String]) -> String {
+    authors
+        .iter()
+        .map(|a| fold_name(&get_last_name(a)))
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 /// Get the last name from an author name string.
 fn get_last_name(name: &str) -> String {
     let name = name.trim();
@@ -282,6 +472,88 @@ mod tests {
         assert_eq!(get_last_name("Jay Van Bavel"), "van bavel");
     }
 
+    #[test]
+    fn test_author_overlap_counts_each_cited_author() {
+        let cited = s(&["John Smith", "Jane Doe", "Bob Brown", "Ann Lee"]);
+        let found = s(&["John Smith", "Jane Doe", "Carl White"]);
+        let overlap = author_overlap(&cited, &found);
+        assert_eq!(overlap.matched, 2);
+        assert_eq!(overlap.cited, 4);
+        assert!(overlap.first_author);
+        assert!((overlap.fraction() - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_policy_min_overlap() {
+        let cited = s(&["John Smith", "Jane Doe", "Bob Brown", "Ann Lee"]);
+        let found = s(&["John Smith", "Jane Doe"]);
+        let at = |min_overlap| AuthorMatchPolicy {
+            min_overlap,
+            ..Default::default()
+        };
+        assert!(validate_authors(&cited, &found));
+        assert!(at(0.5).accepts(&cited, &found));
+        assert!(!at(0.75).accepts(&cited, &found));
+    }
+
+    #[test]
+    fn test_policy_require_first_author() {
+        let cited = s(&["Bob Brown", "John Smith"]);
+        let found = s(&["John Smith", "Jane Doe"]);
+        let policy = AuthorMatchPolicy {
+            require_first_author: true,
+            ..Default::default()
+        };
+        assert!(validate_authors(&cited, &found));
+        assert!(!policy.accepts(&cited, &found));
+        assert!(policy.accepts(&s(&["John Smith", "Bob Brown"]), &found));
+    }
+
+    #[test]
+    fn test_transliterated_surnames() {
+        assert!(surnames_match("Müller", "Mueller", 1.0));
+        assert!(surnames_match("Müller", "Muller", 1.0));
+        assert!(surnames_match("Strauß", "Strauss", 1.0));
+        assert!(surnames_match("Sørensen", "Sorensen", 1.0));
+        assert!(!surnames_match("Müller", "Miller", 1.0));
+
+        let cited = s(&["H. Müller", "J. Dvořák"]);
+        let found = s(&["Hans Mueller", "Jan Dvorak"]);
+        assert_eq!(author_overlap(&cited, &found).matched, 2);
+    }
+
+    #[test]
+    fn test_hyphenated_surnames() {
+        assert!(surnames_match("García-López", "Garcia Lopez", 1.0));
+        assert!(surnames_match("García-López", "Garcia", 1.0));
+        assert!(surnames_match("Smith-Jones", "Jones", 1.0));
+        assert!(!surnames_match("Smith-Jones", "Brown", 1.0));
+
+        let cited = s(&["M. García-López"]);
+        let found = s(&["Maria Garcia Lopez"]);
+        assert!(author_overlap(&cited, &found).first_author);
+    }
+
+    #[test]
+    fn test_last_first_ordering_and_initials() {
+        let cited = s(&["Smith, John", "Doe, J."]);
+        let found = s(&["J. Smith", "Jane Doe"]);
+        assert_eq!(author_overlap(&cited, &found).matched, 2);
+    }
+
+    #[test]
+    fn test_surname_typo_tolerance() {
+        let cited = s(&["A. Vaswani", "N. Shazeer", "N. Parmar"]);
+        let found = s(&["Ashish Vaswani", "Noam Shazeer", "Niki Parmarr"]);
+        assert_eq!(author_overlap(&cited, &found).matched, 3);
+
+        let strict = AuthorMatchPolicy {
+            surname_threshold: 1.0,
+            ..Default::default()
+        };
+        assert_eq!(strict.overlap(&cited, &found).matched, 2);
+    }
+
     #[test]
     fn test_empty() {
         assert!(!validate_authors(&[], &s(&["Smith"])));
@@ -11,15 +11,24 @@
 //! minor variations (diacritics, HTML entities, Greek letters) produce the same
 //! key. Only successful results are cached; transient errors (timeouts, network
 //! failures) are never cached.
+//!
+//! L1 is capped at `max_entries` (see [`QueryCache::new`]): when it is full,
+//! the least-recently-used 1% is evicted in one pass (it stays in L2).
+//!
+//! [`claim`](QueryCache::claim) coalesces concurrent lookups of the same key:
+//! while one task queries a database, duplicates (the same reference cited by
+//! several papers in a batch) wait for it and are then served from the cache.
 
 use std::path::{Path, PathBuf};
-use std::sync::Mutex;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use dashmap::DashMap;
 use rusqlite::{Connection, OpenFlags, params};
+use tokio::sync::OwnedMutexGuard;
 
+use crate::MatchCandidate;
 use crate::db::DbQueryResult;
 use crate::matching::normalize_title;
 
@@ -29,6 +38,13 @@ const DEFAULT_POSITIVE_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
 /// Default time-to-live for negative (not found) cache entries: 24 hours.
 const DEFAULT_NEGATIVE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
 
+/// Default L1 size cap (see [`Config::cache_max_entries`](crate::Config::cache_max_entries)).
+pub const DEFAULT_MAX_ENTRIES: usize = 100_000;
+
+/// A full L1 drops `max_entries / EVICTION_DIVISOR` entries per eviction
+/// pass, so the scan that picks them is paid once per that many inserts.
+const EVICTION_DIVISOR: usize = 100;
+
 /// Cache key: normalized title + database name.
 #[derive(Hash, Eq, PartialEq, Clone, Debug)]
 struct CacheKey {
@@ -39,14 +55,19 @@ struct CacheKey {
 /// What we store: either a found result or a not-found marker.
 #[derive(Clone, Debug)]
 enum CachedResult {
-    /// Paper found: (title, authors, url).
+    /// Paper found: (title, authors, url, venue, year), plus any equally
+    /// good alternates.
     Found {
         title: String,
         authors: Vec<String>,
         url: Option<String>,
+        venue: Option<String>,
+        year: Option<u32>,
+        alternates: Vec<MatchCandidate>,
     },
-    /// Paper not found in this database.
-    NotFound,
+    /// Paper not found in this database, with the title of a record it
+    /// turned down, if any (see [`DbQueryResult::rejected_title`]).
+    NotFound { rejected_title: Option<String> },
 }
 
 /// A timestamped cache entry (L1 only — uses monotonic `Instant`).
@@ -58,6 +79,8 @@ struct CacheEntry {
     /// actively read back from L1 — SQLite uses it on promotion).
     #[allow(dead_code)]
     inserted_epoch: u64,
+    /// Tick of the last insert or hit, from [`QueryCache::access_clock`].
+    last_access: u64,
 }
 
 /// Open a SQLite connection with WAL mode and standard pragmas.
@@ -95,31 +118,58 @@ impl SqliteWriter {
                  authors          TEXT,
                  paper_url        TEXT,
                  inserted_at      INTEGER NOT NULL,
+                 alternates       TEXT,
+                 venue            TEXT,
+                 year             INTEGER,
                  PRIMARY KEY (normalized_title, db_name)
              );",
         )?;
+        // Caches created before alternates, venue and year were tracked lack
+        // the columns.
+        let _ = conn.execute("ALTER TABLE query_cache ADD COLUMN alternates TEXT", []);
+        let _ = conn.execute("ALTER TABLE query_cache ADD COLUMN venue TEXT", []);
+        let _ = conn.execute("ALTER TABLE query_cache ADD COLUMN year INTEGER", []);
         Ok(Self { conn })
     }
 
     fn insert(&self, norm_title: &str, db_name: &str, result: &CachedResult, epoch: u64) {
-        let (found, found_title, authors_json, paper_url) = match result {
-            CachedResult::Found {
-                title,
-                authors,
-                url,
-            } => (
-                1i32,
-                Some(title.as_str()),
-                Some(serde_json::to_string(authors).unwrap_or_default()),
-                url.as_deref(),
-            ),
-            CachedResult::NotFound => (0i32, None, None, None),
-        };
+        let (found, found_title, authors_json, paper_url, alternates_json, venue, year) =
+            match result {
+                CachedResult::Found {
+                    title,
+                    authors,
+                    url,
+                    venue,
+                    year,
+                    alternates,
+                } => (
+                    1i32,
+                    Some(title.as_str()),
+                    Some(serde_json::to_string(authors).unwrap_or_default()),
+                    url.as_deref(),
+                    (!alternates.is_empty())
+                        .then(|| serde_json::to_string(alternates).unwrap_or_default()),
+                    venue.as_deref(),
+                    *year,
+                ),
+                // A not-found keeps the rejected record's title in the
+                // found_title column.
+                CachedResult::NotFound { rejected_title } => (
+                    0i32,
+                    rejected_title.as_deref(),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                ),
+            };
 
         let _ = self.conn.execute(
             "INSERT OR REPLACE INTO query_cache
-                 (normalized_title, db_name, found, found_title, authors, paper_url, inserted_at)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                 (normalized_title, db_name, found, found_title, authors, paper_url, inserted_at,
+                  alternates, venue, year)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
             params![
                 norm_title,
                 db_name,
@@ -127,7 +177,10 @@ impl SqliteWriter {
                 found_title,
                 authors_json,
                 paper_url,
-                epoch
+                epoch,
+                alternates_json,
+                venue,
+                year
             ],
         );
     }
@@ -215,7 +268,8 @@ impl ReadPool {
         let now = now_epoch();
         let mut stmt = conn
             .prepare_cached(
-                "SELECT found, found_title, authors, paper_url, inserted_at
+                "SELECT found, found_title, authors, paper_url, inserted_at, alternates,
+                        venue, year
                  FROM query_cache
                  WHERE normalized_title = ?1 AND db_name = ?2",
             )
@@ -228,11 +282,32 @@ impl ReadPool {
                 let authors_json: Option<String> = row.get(2)?;
                 let paper_url: Option<String> = row.get(3)?;
                 let inserted_at: u64 = row.get(4)?;
-                Ok((found, found_title, authors_json, paper_url, inserted_at))
+                let alternates_json: Option<String> = row.get(5)?;
+                let venue: Option<String> = row.get(6)?;
+                let year: Option<u32> = row.get(7)?;
+                Ok((
+                    found,
+                    found_title,
+                    authors_json,
+                    paper_url,
+                    inserted_at,
+                    alternates_json,
+                    venue,
+                    year,
+                ))
             })
             .ok()?;
 
-        let (found, found_title, authors_json, paper_url, inserted_at) = row;
+        let (
+            found,
+            found_title,
+            authors_json,
+            paper_url,
+            inserted_at,
+            alternates_json,
+            venue,
+            year,
+        ) = row;
 
         let result = if found != 0 {
             CachedResult::Found {
@@ -241,15 +316,22 @@ impl ReadPool {
                     .and_then(|j| serde_json::from_str(&j).ok())
                     .unwrap_or_default(),
                 url: paper_url,
+                venue,
+                year,
+                alternates: alternates_json
+                    .and_then(|j| serde_json::from_str(&j).ok())
+                    .unwrap_or_default(),
             }
         } else {
-            CachedResult::NotFound
+            CachedResult::NotFound {
+                rejected_title: found_title,
+            }
         };
 
         // Check TTL — if expired, return None (writer evicts on next startup)
         let ttl = match &result {
             CachedResult::Found { .. } => positive_ttl,
-            CachedResult::NotFound => negative_ttl,
+            CachedResult::NotFound { .. } => negative_ttl,
         };
         let age = Duration::from_secs(now.saturating_sub(inserted_at));
         if age > ttl {
@@ -280,44 +362,62 @@ pub struct QueryCache {
     read_pool: Option<ReadPool>,
     positive_ttl: Duration,
     negative_ttl: Duration,
+    /// L1 size cap; 0 means unbounded.
+    max_entries: usize,
+    /// Monotonic counter that orders entries by recency of use.
+    access_clock: AtomicU64,
     hits: AtomicU64,
     misses: AtomicU64,
+    evictions: AtomicU64,
     /// Running sum of lookup durations in microseconds (for computing average).
     total_lookup_us: AtomicU64,
     /// Total number of lookups (hits + misses) for average calculation.
     total_lookups: AtomicU64,
+    /// Keys currently being queried; see [`claim`](Self::claim).
+    inflight: DashMap<CacheKey, Arc<tokio::sync::Mutex<()>>>,
 }
 
 impl Default for QueryCache {
     fn default() -> Self {
-        Self::new(DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL)
+        Self::new(
+            DEFAULT_POSITIVE_TTL,
+            DEFAULT_NEGATIVE_TTL,
+            DEFAULT_MAX_ENTRIES,
+        )
     }
 }
 
 impl QueryCache {
-    /// Create an in-memory-only cache with custom TTLs (no disk persistence).
-    pub fn new(positive_ttl: Duration, negative_ttl: Duration) -> Self {
+    /// Create an in-memory-only cache with custom TTLs (no disk persistence),
+    /// holding at most `max_entries` entries (0 = unbounded).
+    pub fn new(positive_ttl: Duration, negative_ttl: Duration, max_entries: usize) -> Self {
         Self {
             entries: DashMap::new(),
             sqlite_writer: None,
             read_pool: None,
             positive_ttl,
             negative_ttl,
+            max_entries,
+            access_clock: AtomicU64::new(0),
             hits: AtomicU64::new(0),
             misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
             total_lookup_us: AtomicU64::new(0),
             total_lookups: AtomicU64::new(0),
+            inflight: DashMap::new(),
         }
     }
 
     /// Open a persistent cache backed by a SQLite database at `path`.
     ///
     /// On startup, expired entries are evicted from SQLite. The L1 DashMap
-    /// starts empty and is populated lazily as entries are accessed.
+    /// starts empty and is populated lazily as entries are accessed, up to
+    /// `max_entries` (0 = unbounded); entries evicted from L1 remain on disk.
     pub fn open(
         path: &Path,
         positive_ttl: Duration,
         negative_ttl: Duration,
+        max_entries: usize,
     ) -> Result<Self, String> {
         let writer = SqliteWriter::open(path)
             .map_err(|e| format!("Failed to open cache database at {}: {}", path.display(), e))?;
@@ -328,10 +428,14 @@ impl QueryCache {
             read_pool: Some(ReadPool::new(path)),
             positive_ttl,
             negative_ttl,
+            max_entries,
+            access_clock: AtomicU64::new(0),
             hits: AtomicU64::new(0),
             misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
             total_lookup_us: AtomicU64::new(0),
             total_lookups: AtomicU64::new(0),
+            inflight: DashMap::new(),
         })
     }
 
@@ -348,16 +452,17 @@ impl QueryCache {
         };
 
         // L1 check
-        if let Some(entry) = self.entries.get(&key) {
+        if let Some(mut entry) = self.entries.get_mut(&key) {
             let ttl = match &entry.result {
                 CachedResult::Found { .. } => self.positive_ttl,
-                CachedResult::NotFound => self.negative_ttl,
+                CachedResult::NotFound { .. } => self.negative_ttl,
             };
             if entry.inserted_at.elapsed() > ttl {
                 drop(entry);
                 self.entries.remove(&key);
                 // Fall through to L2
             } else {
+                entry.last_access = self.tick();
                 self.hits.fetch_add(1, Ordering::Relaxed);
                 self.record_lookup(start);
                 return Some(cached_to_query_result(&entry.result));
@@ -371,12 +476,13 @@ impl QueryCache {
         {
             // Promote to L1
             let query_result = cached_to_query_result(&result);
-            self.entries.insert(
+            self.insert_l1(
                 key,
                 CacheEntry {
                     result,
                     inserted_at: epoch_to_instant(epoch),
                     inserted_epoch: epoch,
+                    last_access: self.tick(),
                 },
             );
             self.hits.fetch_add(1, Ordering::Relaxed);
@@ -389,6 +495,65 @@ impl QueryCache {
         None
     }
 
+    /// Wait until no other task is querying `title` on `db_name`, then claim it.
+    ///
+    /// Hold the returned guard across the cache lookup, the request and the
+    /// insert: a concurrent duplicate blocks here and then finds the result in
+    /// the cache instead of sending its own request. Errors are not cached, so
+    /// after a failed request the next waiter queries the database itself.
+    pub async fn claim(&self, title: &str, db_name: &str) -> InFlight<'_> {
+        let key = CacheKey {
+            normalized_title: normalize_title(title),
+            db_name: db_name.to_string(),
+        };
+        let lock = Arc::clone(&self.inflight.entry(key.clone()).or_default());
+        let guard = lock.lock_owned().await;
+        InFlight {
+            cache: self,
+            key,
+            guard: Some(guard),
+        }
+    }
+
+    fn tick(&self) -> u64 {
+        self.access_clock.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Insert into L1, first evicting the least-recently-used entries when a
+    /// new key would push it past `max_entries`.
+    fn insert_l1(&self, key: CacheKey, entry: CacheEntry) {
+        if self.max_entries > 0
+            && self.entries.len() >= self.max_entries
+            && !self.entries.contains_key(&key)
+        {
+            self.evict_least_recently_used();
+        }
+        self.entries.insert(key, entry);
+    }
+
+    /// Drop the least-recently-used `max_entries / EVICTION_DIVISOR` entries
+    /// (at least one) in a single pass over L1.
+    ///
+    /// The pass costs O(n), but it frees room for that many inserts, so each
+    /// insert pays O(EVICTION_DIVISOR) on average rather than a scan.
+    fn evict_least_recently_used(&self) {
+        let mut ticks: Vec<u64> = self.entries.iter().map(|e| e.value().last_access).collect();
+        if ticks.is_empty() {
+            return;
+        }
+        let count = (self.max_entries / EVICTION_DIVISOR).clamp(1, ticks.len());
+        let (_, &mut cutoff, _) = ticks.select_nth_unstable(count - 1);
+        let mut evicted = 0;
+        self.entries.retain(|_, entry| {
+            let keep = entry.last_access > cutoff;
+            if !keep {
+                evicted += 1;
+            }
+            keep
+        });
+        self.evictions.fetch_add(evicted, Ordering::Relaxed);
+    }
+
     fn record_lookup(&self, start: Instant) {
         let us = start.elapsed().as_micros() as u64;
         self.total_lookup_us.fetch_add(us, Ordering::Relaxed);
@@ -406,24 +571,30 @@ impl QueryCache {
             db_name: db_name.to_string(),
         };
 
-        let cached = match result {
-            (Some(found_title), authors, url) => CachedResult::Found {
+        let cached = match &result.found_title {
+            Some(found_title) => CachedResult::Found {
                 title: found_title.clone(),
-                authors: authors.clone(),
-                url: url.clone(),
+                authors: result.authors.clone(),
+                url: result.paper_url.clone(),
+                venue: result.venue.clone(),
+                year: result.year,
+                alternates: result.alternates.clone(),
+            },
+            None => CachedResult::NotFound {
+                rejected_title: result.rejected_title.clone(),
             },
-            (None, _, _) => CachedResult::NotFound,
         };
 
         let epoch = now_epoch();
 
         // L1
-        self.entries.insert(
+        self.insert_l1(
             key,
             CacheEntry {
                 result: cached.clone(),
                 inserted_at: Instant::now(),
                 inserted_epoch: epoch,
+                last_access: self.tick(),
             },
         );
 
@@ -455,6 +626,16 @@ impl QueryCache {
         self.misses.load(Ordering::Relaxed)
     }
 
+    /// Number of L1 entries evicted by the size cap since creation.
+    pub fn evictions(&self) -> u64 {
+        self.evictions.load(Ordering::Relaxed)
+    }
+
+    /// The L1 size cap (0 = unbounded).
+    pub fn max_entries(&self) -> usize {
+        self.max_entries
+    }
+
     /// Average lookup time in milliseconds (hits and misses).
     pub fn avg_lookup_ms(&self) -> f64 {
         let count = self.total_lookups.load(Ordering::Relaxed);
@@ -500,14 +681,46 @@ impl QueryCache {
     }
 }
 
+/// A claimed cache key, returned by [`QueryCache::claim`]. Dropping it lets
+/// the next waiting duplicate proceed.
+pub struct InFlight<'a> {
+    cache: &'a QueryCache,
+    key: CacheKey,
+    guard: Option<OwnedMutexGuard<()>>,
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        drop(self.guard.take());
+        // Forget the key unless another task is still waiting on it.
+        self.cache
+            .inflight
+            .remove_if(&self.key, |_, lock| Arc::strong_count(lock) == 1);
+    }
+}
+
 fn cached_to_query_result(cached: &CachedResult) -> DbQueryResult {
     match cached {
         CachedResult::Found {
             title,
             authors,
             url,
-        } => (Some(title.clone()), authors.clone(), url.clone()),
-        CachedResult::NotFound => (None, vec![], None),
+            venue,
+            year,
+            alternates,
+        } => DbQueryResult {
+            found_title: Some(title.clone()),
+            authors: authors.clone(),
+            paper_url: url.clone(),
+            venue: venue.clone(),
+            year: *year,
+            alternates: alternates.clone(),
+            rejected_title: None,
+        },
+        CachedResult::NotFound { rejected_title } => DbQueryResult {
+            rejected_title: rejected_title.clone(),
+            ..DbQueryResult::not_found()
+        },
     }
 }
 
@@ -528,6 +741,8 @@ impl std::fmt::Debug for QueryCache {
             .field("l2_entries", &self.disk_len())
             .field("hits", &self.hits())
             .field("misses", &self.misses())
+            .field("evictions", &self.evictions())
+            .field("max_entries", &self.max_entries)
             .field("positive_ttl", &self.positive_ttl)
             .field("negative_ttl", &self.negative_ttl)
             .field("persistent", &self.has_persistence())
@@ -551,15 +766,20 @@ mod tests {
     #[test]
     fn cache_hit_after_insert_found() {
         let cache = QueryCache::default();
-        let result: DbQueryResult = (
-            Some("Attention Is All You Need".into()),
+        let result = DbQueryResult::found(
+            "Attention Is All You Need",
             vec!["Vaswani".into()],
             Some("https://doi.org/10.1234".into()),
         );
         cache.insert("Attention Is All You Need", "CrossRef", &result);
         let cached = cache.get("Attention Is All You Need", "CrossRef");
         assert!(cached.is_some());
-        let (title, authors, url) = cached.unwrap();
+        let DbQueryResult {
+            found_title: title,
+            authors,
+            paper_url: url,
+            ..
+        } = cached.unwrap();
         assert_eq!(title.unwrap(), "Attention Is All You Need");
         assert_eq!(authors, vec!["Vaswani"]);
         assert_eq!(url.unwrap(), "https://doi.org/10.1234");
@@ -569,11 +789,16 @@ mod tests {
     #[test]
     fn cache_hit_after_insert_not_found() {
         let cache = QueryCache::default();
-        let result: DbQueryResult = (None, vec![], None);
+        let result = DbQueryResult::not_found();
         cache.insert("Nonexistent Paper", "arXiv", &result);
         let cached = cache.get("Nonexistent Paper", "arXiv");
         assert!(cached.is_some());
-        let (title, authors, url) = cached.unwrap();
+        let DbQueryResult {
+            found_title: title,
+            authors,
+            paper_url: url,
+            ..
+        } = cached.unwrap();
         assert!(title.is_none());
         assert!(authors.is_empty());
         assert!(url.is_none());
@@ -582,7 +807,7 @@ mod tests {
     #[test]
     fn cache_miss_different_db() {
         let cache = QueryCache::default();
-        let result: DbQueryResult = (Some("A Paper".into()), vec![], None);
+        let result = DbQueryResult::found("A Paper", vec![], None);
         cache.insert("A Paper", "CrossRef", &result);
         assert!(cache.get("A Paper", "arXiv").is_none());
     }
@@ -590,7 +815,7 @@ mod tests {
     #[test]
     fn cache_normalized_key() {
         let cache = QueryCache::default();
-        let result: DbQueryResult = (Some("Résumé of Methods".into()), vec![], None);
+        let result = DbQueryResult::found("Résumé of Methods", vec![], None);
         // Insert with accented title
         cache.insert("Résumé of Methods", "CrossRef", &result);
         // Look up with ASCII equivalent (normalization strips accents)
@@ -600,8 +825,8 @@ mod tests {
 
     #[test]
     fn cache_expired_positive() {
-        let cache = QueryCache::new(Duration::from_millis(1), Duration::from_secs(3600));
-        let result: DbQueryResult = (Some("Paper".into()), vec![], None);
+        let cache = QueryCache::new(Duration::from_millis(1), Duration::from_secs(3600), 0);
+        let result = DbQueryResult::found("Paper", vec![], None);
         cache.insert("Paper", "CrossRef", &result);
         // Sleep briefly to let TTL expire
         std::thread::sleep(Duration::from_millis(10));
@@ -610,8 +835,8 @@ mod tests {
 
     #[test]
     fn cache_expired_negative() {
-        let cache = QueryCache::new(Duration::from_secs(3600), Duration::from_millis(1));
-        let result: DbQueryResult = (None, vec![], None);
+        let cache = QueryCache::new(Duration::from_secs(3600), Duration::from_millis(1), 0);
+        let result = DbQueryResult::not_found();
         cache.insert("Paper", "CrossRef", &result);
         std::thread::sleep(Duration::from_millis(10));
         assert!(cache.get("Paper", "CrossRef").is_none());
@@ -622,7 +847,7 @@ mod tests {
         let cache = QueryCache::default();
         assert!(cache.is_empty());
         assert_eq!(cache.len(), 0);
-        cache.insert("Paper", "DB", &(Some("Paper".into()), vec![], None));
+        cache.insert("Paper", "DB", &DbQueryResult::found("Paper", vec![], None));
         assert!(!cache.is_empty());
         assert_eq!(cache.len(), 1);
     }
@@ -630,7 +855,7 @@ mod tests {
     #[test]
     fn cache_clear() {
         let cache = QueryCache::default();
-        cache.insert("Paper", "DB", &(Some("Paper".into()), vec![], None));
+        cache.insert("Paper", "DB", &DbQueryResult::found("Paper", vec![], None));
         assert_eq!(cache.len(), 1);
         cache.clear();
         assert!(cache.is_empty());
@@ -658,9 +883,9 @@ mod tests {
         let path = temp_cache_path();
         let _ = std::fs::remove_file(&path);
 
-        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL).unwrap();
-        let result: DbQueryResult = (
-            Some("Deep Learning".into()),
+        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
+        let result = DbQueryResult::found(
+            "Deep Learning",
             vec!["LeCun".into(), "Bengio".into()],
             Some("https://doi.org/10.1234".into()),
         );
@@ -669,13 +894,19 @@ mod tests {
 
         // Read back from a fresh cache instance (simulating restart)
         drop(cache);
-        let cache2 = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL).unwrap();
+        let cache2 =
+            QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
         // L1 should be empty
         assert!(cache2.is_empty());
         // But get() should find it in L2
         let cached = cache2.get("Deep Learning", "CrossRef");
         assert!(cached.is_some());
-        let (title, authors, url) = cached.unwrap();
+        let DbQueryResult {
+            found_title: title,
+            authors,
+            paper_url: url,
+            ..
+        } = cached.unwrap();
         assert_eq!(title.unwrap(), "Deep Learning");
         assert_eq!(authors, vec!["LeCun", "Bengio"]);
         assert_eq!(url.unwrap(), "https://doi.org/10.1234");
@@ -690,27 +921,95 @@ mod tests {
         let path = temp_cache_path();
         let _ = std::fs::remove_file(&path);
 
-        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL).unwrap();
-        let result: DbQueryResult = (None, vec![], None);
+        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
+        let result = DbQueryResult::not_found();
         cache.insert("Fake Paper", "arXiv", &result);
 
         drop(cache);
-        let cache2 = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL).unwrap();
+        let cache2 =
+            QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
         let cached = cache2.get("Fake Paper", "arXiv");
         assert!(cached.is_some());
-        let (title, _, _) = cached.unwrap();
+        let DbQueryResult {
+            found_title: title, ..
+        } = cached.unwrap();
         assert!(title.is_none());
 
         let _ = std::fs::remove_file(&path);
     }
 
+    #[test]
+    fn sqlite_alternates_persist() {
+        let path = temp_cache_path();
+        let _ = std::fs::remove_file(&path);
+
+        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
+        let result = DbQueryResult {
+            alternates: vec![MatchCandidate {
+                title: "Same Title".into(),
+                authors: vec!["Other".into()],
+                paper_url: None,
+                venue: None,
+                year: None,
+            }],
+            ..DbQueryResult::found("Same Title", vec!["Author".into()], None)
+        };
+        cache.insert("Same Title", "CrossRef", &result);
+
+        drop(cache);
+        let cache2 =
+            QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
+        assert_eq!(cache2.get("Same Title", "CrossRef"), Some(result));
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn sqlite_rejected_title_persists() {
+        let path = temp_cache_path();
+        let _ = std::fs::remove_file(&path);
+
+        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
+        let result = DbQueryResult::rejected("Some Other Paper");
+        cache.insert("Cited Paper", "DOI", &result);
+        assert_eq!(cache.get("Cited Paper", "DOI"), Some(result.clone()));
+
+        drop(cache);
+        let cache2 =
+            QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
+        assert_eq!(cache2.get("Cited Paper", "DOI"), Some(result));
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn sqlite_venue_and_year_persist() {
+        let path = temp_cache_path();
+        let _ = std::fs::remove_file(&path);
+
+        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
+        let result = DbQueryResult {
+            venue: Some("Nature".into()),
+            year: Some(2020),
+            ..DbQueryResult::found("Protein Folding", vec!["Author".into()], None)
+        };
+        cache.insert("Protein Folding", "PubMed", &result);
+
+        drop(cache);
+        let cache2 =
+            QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
+        assert_eq!(cache2.get("Protein Folding", "PubMed"), Some(result));
+
+        let _ = std::fs::remove_file(&path);
+    }
+
     #[test]
     fn sqlite_clear() {
         let path = temp_cache_path();
         let _ = std::fs::remove_file(&path);
 
-        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL).unwrap();
-        cache.insert("Paper", "DB", &(Some("Paper".into()), vec![], None));
+        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
+        cache.insert("Paper", "DB", &DbQueryResult::found("Paper", vec![], None));
         assert_eq!(cache.disk_len(), 1);
         cache.clear();
         assert_eq!(cache.disk_len(), 0);
@@ -727,16 +1026,16 @@ mod tests {
         // Insert with 1-second TTL (SQLite uses epoch-second resolution)
         {
             let cache =
-                QueryCache::open(&path, Duration::from_secs(1), Duration::from_secs(1)).unwrap();
-            cache.insert("Paper", "DB", &(Some("Paper".into()), vec![], None));
-            cache.insert("Missing", "DB", &(None, vec![], None));
+                QueryCache::open(&path, Duration::from_secs(1), Duration::from_secs(1), 0).unwrap();
+            cache.insert("Paper", "DB", &DbQueryResult::found("Paper", vec![], None));
+            cache.insert("Missing", "DB", &DbQueryResult::not_found());
         }
 
         std::thread::sleep(Duration::from_secs(2));
 
         // Re-open — eviction should remove expired entries
         let cache2 =
-            QueryCache::open(&path, Duration::from_secs(1), Duration::from_secs(1)).unwrap();
+            QueryCache::open(&path, Duration::from_secs(1), Duration::from_secs(1), 0).unwrap();
         assert_eq!(cache2.disk_len(), 0);
 
         let _ = std::fs::remove_file(&path);
@@ -753,9 +1052,9 @@ mod tests {
 
         let positive_ttl = DEFAULT_POSITIVE_TTL;
         let negative_ttl = DEFAULT_NEGATIVE_TTL;
-        let cache = QueryCache::open(&path, positive_ttl, negative_ttl).unwrap();
+        let cache = QueryCache::open(&path, positive_ttl, negative_ttl, 0).unwrap();
 
-        let result: DbQueryResult = (Some("Persistent Paper".into()), vec!["Author".into()], None);
+        let result = DbQueryResult::found("Persistent Paper", vec!["Author".into()], None);
         cache.insert("Persistent Paper", "CrossRef", &result);
 
         // Manually expire L1 by removing the entry, simulating L1 eviction
@@ -770,7 +1069,11 @@ mod tests {
         // get() should fall through to L2 and find it
         let cached = cache.get("Persistent Paper", "CrossRef");
         assert!(cached.is_some());
-        let (title, authors, _) = cached.unwrap();
+        let DbQueryResult {
+            found_title: title,
+            authors,
+            ..
+        } = cached.unwrap();
         assert_eq!(title.unwrap(), "Persistent Paper");
         assert_eq!(authors, vec!["Author"]);
 
@@ -788,7 +1091,7 @@ mod tests {
         let path = temp_cache_path();
         let _ = std::fs::remove_file(&path);
 
-        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL).unwrap();
+        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
         assert!(cache.get("Nonexistent", "DB").is_none());
         assert_eq!(cache.misses(), 1); // exactly one miss, not two
         assert_eq!(cache.hits(), 0);
@@ -802,9 +1105,13 @@ mod tests {
         let path = temp_cache_path();
         let _ = std::fs::remove_file(&path);
 
-        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL).unwrap();
-        cache.insert("Paper A", "DB1", &(Some("Paper A".into()), vec![], None));
-        cache.insert("Paper B", "DB2", &(None, vec![], None));
+        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
+        cache.insert(
+            "Paper A",
+            "DB1",
+            &DbQueryResult::found("Paper A", vec![], None),
+        );
+        cache.insert("Paper B", "DB2", &DbQueryResult::not_found());
         assert_eq!(cache.len(), 2);
         assert_eq!(cache.disk_len(), 2);
 
@@ -814,7 +1121,8 @@ mod tests {
 
         // Restart — should still be empty
         drop(cache);
-        let cache2 = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL).unwrap();
+        let cache2 =
+            QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
         assert!(cache2.is_empty());
         assert_eq!(cache2.disk_len(), 0);
         assert!(cache2.get("Paper A", "DB1").is_none());
@@ -829,7 +1137,7 @@ mod tests {
         let _ = std::fs::remove_file(&path);
 
         let cache = std::sync::Arc::new(
-            QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL).unwrap(),
+            QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap(),
         );
 
         let mut handles = vec![];
@@ -842,7 +1150,7 @@ mod tests {
                 c.insert(
                     &title,
                     &db,
-                    &(Some(title.clone()), vec!["Author".into()], None),
+                    &DbQueryResult::found(title.clone(), vec!["Author".into()], None),
                 );
                 // Read back
                 let result = c.get(&title, &db);
@@ -870,11 +1178,11 @@ mod tests {
         // First insert a valid entry
         {
             let cache =
-                QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL).unwrap();
+                QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
             cache.insert(
                 "Test Paper",
                 "DB",
-                &(Some("Test Paper".into()), vec!["Author".into()], None),
+                &DbQueryResult::found("Test Paper", vec!["Author".into()], None),
             );
         }
 
@@ -889,10 +1197,15 @@ mod tests {
         }
 
         // Re-open and read — should fall back to empty authors, not panic
-        let cache2 = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL).unwrap();
+        let cache2 =
+            QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
         let cached = cache2.get("Test Paper", "DB");
         assert!(cached.is_some());
-        let (title, authors, _) = cached.unwrap();
+        let DbQueryResult {
+            found_title: title,
+            authors,
+            ..
+        } = cached.unwrap();
         assert_eq!(title.unwrap(), "Test Paper");
         assert!(authors.is_empty()); // corrupted JSON → empty fallback
 
@@ -901,14 +1214,14 @@ mod tests {
 
     #[test]
     fn zero_ttl_entries_expire_immediately() {
-        let cache = QueryCache::new(Duration::ZERO, Duration::ZERO);
-        cache.insert("Paper", "DB", &(Some("Paper".into()), vec![], None));
+        let cache = QueryCache::new(Duration::ZERO, Duration::ZERO, 0);
+        cache.insert("Paper", "DB", &DbQueryResult::found("Paper", vec![], None));
         // With zero TTL, any elapsed time > 0 means expired
         // The insert and get happen so fast they might share the same Instant,
         // but the `>` check (not `>=`) means Duration::ZERO elapsed == Duration::ZERO TTL
         // is NOT expired. That's fine — it's a degenerate edge case.
         // Insert a not-found too:
-        cache.insert("Missing", "DB", &(None, vec![], None));
+        cache.insert("Missing", "DB", &DbQueryResult::not_found());
         // At minimum, verify no panic and consistent state
         assert_eq!(cache.len(), 2);
     }
@@ -917,8 +1230,8 @@ mod tests {
     fn multiple_dbs_same_title() {
         // Same title cached across multiple databases should be independent.
         let cache = QueryCache::default();
-        let found: DbQueryResult = (Some("Paper X".into()), vec!["A".into()], None);
-        let not_found: DbQueryResult = (None, vec![], None);
+        let found = DbQueryResult::found("Paper X", vec!["A".into()], None);
+        let not_found = DbQueryResult::not_found();
 
         cache.insert("Paper X", "CrossRef", &found);
         cache.insert("Paper X", "arXiv", &not_found);
@@ -927,31 +1240,31 @@ mod tests {
         assert_eq!(cache.len(), 3);
 
         let cr = cache.get("Paper X", "CrossRef").unwrap();
-        assert!(cr.0.is_some());
+        assert!(cr.found_title.is_some());
 
         let arxiv = cache.get("Paper X", "arXiv").unwrap();
-        assert!(arxiv.0.is_none());
+        assert!(arxiv.found_title.is_none());
 
         let dblp = cache.get("Paper X", "DBLP").unwrap();
-        assert!(dblp.0.is_some());
+        assert!(dblp.found_title.is_some());
     }
 
     #[test]
     fn overwrite_existing_entry() {
         // Inserting the same key twice should overwrite the first entry.
         let cache = QueryCache::default();
-        cache.insert("Paper", "DB", &(None, vec![], None));
-        assert!(cache.get("Paper", "DB").unwrap().0.is_none());
+        cache.insert("Paper", "DB", &DbQueryResult::not_found());
+        assert!(cache.get("Paper", "DB").unwrap().found_title.is_none());
 
         // Now overwrite with a found result
         cache.insert(
             "Paper",
             "DB",
-            &(Some("Paper".into()), vec!["Author".into()], None),
+            &DbQueryResult::found("Paper", vec!["Author".into()], None),
         );
         let cached = cache.get("Paper", "DB").unwrap();
-        assert_eq!(cached.0.unwrap(), "Paper");
-        assert_eq!(cached.1, vec!["Author"]);
+        assert_eq!(cached.found_title.unwrap(), "Paper");
+        assert_eq!(cached.authors, vec!["Author"]);
         assert_eq!(cache.len(), 1); // still one entry, not two
     }
 
@@ -960,24 +1273,25 @@ mod tests {
         let path = temp_cache_path();
         let _ = std::fs::remove_file(&path);
 
-        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL).unwrap();
-        cache.insert("Paper", "DB", &(None, vec![], None));
+        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
+        cache.insert("Paper", "DB", &DbQueryResult::not_found());
         assert_eq!(cache.disk_len(), 1);
 
         // Overwrite with found result
         cache.insert(
             "Paper",
             "DB",
-            &(Some("Paper".into()), vec!["Author".into()], None),
+            &DbQueryResult::found("Paper", vec!["Author".into()], None),
         );
         assert_eq!(cache.disk_len(), 1); // still one row
 
         // Restart and verify the overwritten value persisted
         drop(cache);
-        let cache2 = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL).unwrap();
+        let cache2 =
+            QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
         let cached = cache2.get("Paper", "DB").unwrap();
-        assert_eq!(cached.0.unwrap(), "Paper");
-        assert_eq!(cached.1, vec!["Author"]);
+        assert_eq!(cached.found_title.unwrap(), "Paper");
+        assert_eq!(cached.authors, vec!["Author"]);
 
         let _ = std::fs::remove_file(&path);
     }
@@ -992,15 +1306,108 @@ mod tests {
         let path = temp_cache_path();
         let _ = std::fs::remove_file(&path);
         let persistent =
-            QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL).unwrap();
+            QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 0).unwrap();
         assert!(persistent.has_persistence());
 
         let _ = std::fs::remove_file(&path);
     }
 
+    #[test]
+    fn max_entries_evicts_least_recently_used() {
+        let cache = QueryCache::new(DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 2);
+        let found = |t: &str| DbQueryResult::found(t, vec![], None);
+        cache.insert("First Paper", "DB", &found("First Paper"));
+        cache.insert("Second Paper", "DB", &found("Second Paper"));
+
+        // Touch the first so the second becomes least recently used.
+        assert!(cache.get("First Paper", "DB").is_some());
+        cache.insert("Third Paper", "DB", &found("Third Paper"));
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.evictions(), 1);
+        assert!(cache.get("Second Paper", "DB").is_none());
+        assert!(cache.get("First Paper", "DB").is_some());
+        assert!(cache.get("Third Paper", "DB").is_some());
+
+        // Overwriting an existing key does not evict.
+        cache.insert("Third Paper", "DB", &found("Third Paper"));
+        assert_eq!(cache.evictions(), 1);
+    }
+
+    #[test]
+    fn full_cache_evicts_oldest_percent_in_one_pass() {
+        let cache = QueryCache::new(DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 300);
+        let found = |t: &str| DbQueryResult::found(t, vec![], None);
+        for i in 0..300 {
+            let title = format!("Paper number {}", i);
+            cache.insert(&title, "DB", &found(&title));
+        }
+        // Touch the oldest so papers 1-3 become the least recently used.
+        assert!(cache.get("Paper number 0", "DB").is_some());
+
+        cache.insert("One more paper", "DB", &found("One more paper"));
+        assert_eq!(cache.evictions(), 3);
+        assert_eq!(cache.len(), 298);
+        assert!(cache.get("Paper number 0", "DB").is_some());
+        for i in 1..=3 {
+            assert!(cache.get(&format!("Paper number {}", i), "DB").is_none());
+        }
+        assert!(cache.get("Paper number 4", "DB").is_some());
+
+        // The freed room takes the next inserts without another pass.
+        cache.insert("Yet another paper", "DB", &found("Yet another paper"));
+        assert_eq!(cache.evictions(), 3);
+    }
+
+    #[test]
+    fn evicted_entries_stay_on_disk() {
+        let path = temp_cache_path();
+        let _ = std::fs::remove_file(&path);
+        let cache = QueryCache::open(&path, DEFAULT_POSITIVE_TTL, DEFAULT_NEGATIVE_TTL, 1).unwrap();
+        cache.insert(
+            "Paper One",
+            "DB",
+            &DbQueryResult::found("Paper One", vec![], None),
+        );
+        cache.insert(
+            "Paper Two",
+            "DB",
+            &DbQueryResult::found("Paper Two", vec![], None),
+        );
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.disk_len(), 2);
+
+        // An L1 miss is served from L2 and promoted, evicting the other key.
+        assert!(cache.get("Paper One", "DB").is_some());
+        assert_eq!(cache.evictions(), 2);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[tokio::test]
+    async fn claim_serializes_same_key_and_cleans_up() {
+        let cache = QueryCache::default();
+        let first = cache.claim("A Paper", "DBLP").await;
+
+        // Another DB is a different key and doesn't wait.
+        drop(cache.claim("A Paper", "arXiv").await);
+
+        // The same normalized title on the same DB waits for `first`.
+        let waiter = cache.claim("a paper.", "DBLP");
+        tokio::pin!(waiter);
+        let early = tokio::time::timeout(Duration::from_millis(20), waiter.as_mut()).await;
+        assert!(early.is_err());
+
+        drop(first);
+        let second = waiter.await;
+        assert_eq!(cache.inflight.len(), 1);
+        drop(second);
+        assert!(cache.inflight.is_empty());
+    }
+
     #[test]
     fn ttl_accessors() {
-        let cache = QueryCache::new(Duration::from_secs(42), Duration::from_secs(7));
+        let cache = QueryCache::new(Duration::from_secs(42), Duration::from_secs(7), 0);
         assert_eq!(cache.positive_ttl(), Duration::from_secs(42));
         assert_eq!(cache.negative_ttl(), Duration::from_secs(7));
     }
@@ -1,12 +1,16 @@
-use crate::doi::{DoiMatchResult, check_doi_match, validate_doi};
-use crate::orchestrator::query_all_databases;
+use crate::context::CheckContext;
+use crate::doi::{DoiMatchResult, check_doi_match, doi_title_mismatch, looks_valid, validate_doi};
+use crate::matching::title_similarity;
+use crate::orchestrator::{DbSearchResult, OnRateLimited, apply_consensus, query_all_databases};
 use crate::pool::{RefJob, ValidationPool};
-use crate::retraction::{check_retraction, check_retraction_by_title};
+use crate::retraction::{check_retraction, check_retraction_by_title, with_crossref_fallback};
+use crate::retraction_watch::RetractionWatch;
 use crate::{
-    ArxivInfo, Config, DbResult, DbStatus, DoiInfo, ProgressEvent, Reference, RetractionInfo,
-    Status, ValidationResult,
+    Config, DbQueryError, DbResult, DbStatus, DoiInfo, FailedDb, FailureReason, FlagReason,
+    ProgressEvent, RateLimiters, Reference, RetractionInfo, Status, ValidationResult,
 };
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
 
@@ -21,148 +25,694 @@ pub async fn check_references(
     progress: impl Fn(ProgressEvent) + Send + Sync + 'static,
     cancel: CancellationToken,
 ) -> Vec<ValidationResult> {
-    let total = refs.len();
-    if total == 0 {
-        return vec![];
+    let context = CheckContext::from_config(&config);
+    check_references_with_context(refs, config, context, progress, cancel).await
+}
+
+/// Like [`check_references`], but uses the cache, rate limiters and HTTP
+/// client from a caller-owned [`CheckContext`].
+pub async fn check_references_with_context(
+    refs: Vec<Reference>,
+    config: Config,
+    context: CheckContext,
+    progress: impl Fn(ProgressEvent) + Send + Sync + 'static,
+    cancel: CancellationToken,
+) -> Vec<ValidationResult> {
+    check_files_with_context(
+        vec![refs],
+        config,
+        context,
+        move |_, event| progress(event),
+        cancel,
+    )
+    .await
+    .pop()
+    .unwrap_or_default()
+}
+
+/// Like [`check_references_with_context`], but for the references of
+/// several files, all checked through one [`ValidationPool`]: a file does not
+/// wait for the one before it to finish, and [`Config::dedup`] spans files.
+///
+/// `progress` gets each event with the index of its file, and reference
+/// indices count within that file; events about the whole run (rate limits,
+/// retry passes, a database that could not be loaded) go to the first file.
+/// The results come back per file, in the order the files were given.
+pub async fn check_files_with_context(
+    files: Vec<Vec<Reference>>,
+    config: Config,
+    context: CheckContext,
+    progress: impl Fn(usize, ProgressEvent) + Send + Sync + 'static,
+    cancel: CancellationToken,
+) -> Vec<Vec<ValidationResult>> {
+    let mut files = files;
+    let mut config = config;
+    for (file, refs) in files.iter_mut().enumerate() {
+        let capped = cap_references(refs, config.max_references);
+        if let Some(max) = config.max_references
+            && capped > 0
+        {
+            progress(file, reference_cap_warning(max, capped));
+            refs.retain(|r| r.skip_reason.as_deref() != Some(MAX_REFERENCES_SKIP_REASON));
+        }
+    }
+
+    if files.iter().all(Vec::is_empty) {
+        return files.iter().map(|_| Vec::new()).collect();
+    }
+    // Run-wide warnings count the first file's references, as they are
+    // filed under it
+    let first = files.first().map_or(0, Vec::len);
+    if let Some(warning) = load_retraction_db(&mut config, first) {
+        progress(0, warning);
+    }
+    if let Some(warning) = load_local_bib(&mut config, first) {
+        progress(0, warning);
+    }
+    if let Some(warning) = unknown_only_dbs_warning(&config, first) {
+        progress(0, warning);
+    }
+    if let Some(warning) = dblp_staleness_warning(&config, first) {
+        progress(0, warning);
     }
 
     let num_workers = config.num_workers.max(1);
+    let run_deadline = config.run_deadline;
+    let deadline = run_deadline.map(|d| tokio::time::Instant::now() + d);
     let config = Arc::new(config);
     let progress = Arc::new(progress);
 
-    // Create the pool
-    let pool = ValidationPool::new(config.clone(), cancel.clone(), num_workers);
+    // Once the deadline passes, the references still in flight are
+    // abandoned; keep their interrupted results out of the progress stream.
+    let stopped = Arc::new(AtomicBool::new(false));
+    let file_progress: Vec<Arc<dyn Fn(ProgressEvent) + Send + Sync>> = (0..files.len())
+        .map(|file| {
+            let progress = progress.clone();
+            let stopped = stopped.clone();
+            Arc::new(move |event| {
+                if !stopped.load(Ordering::Acquire) {
+                    progress(file, event)
+                }
+            }) as Arc<dyn Fn(ProgressEvent) + Send + Sync>
+        })
+        .collect();
+
+    let client = context.client.clone();
+    let rate_limit_status = tokio::spawn(report_rate_limits(
+        context.rate_limiters.clone(),
+        file_progress[0].clone(),
+    ));
+
+    // Create the pool. It stops on the caller's cancellation or the deadline.
+    let run_cancel = cancel.child_token();
+    let pool =
+        ValidationPool::with_context(config.clone(), context, run_cancel.clone(), num_workers);
+
+    // Submit all refs, file after file, and collect oneshot receivers
+    let mut receivers = Vec::new();
+    'submit: for (file, refs) in files.iter().enumerate() {
+        for (i, reference) in refs.iter().enumerate() {
+            if cancel.is_cancelled() {
+                break 'submit;
+            }
+
+            let (result_tx, result_rx) = tokio::sync::oneshot::channel();
+            let job = RefJob {
+                reference: reference.clone(),
+                result_tx,
+                ref_index: i,
+                total: refs.len(),
+                progress: file_progress[file].clone(),
+                cancel: None,
+            };
 
-    // Submit all refs and collect oneshot receivers
-    let mut receivers = Vec::with_capacity(total);
-    for (i, reference) in refs.iter().enumerate() {
-        if cancel.is_cancelled() {
-            break;
+            pool.submit(job).await;
+            receivers.push((file, i, result_rx));
         }
+    }
 
-        let (result_tx, result_rx) = tokio::sync::oneshot::channel();
-        let job = RefJob {
-            reference: reference.clone(),
-            result_tx,
-            ref_index: i,
-            total,
-            progress: progress.clone(),
+    // Collect results, routed back to their file
+    let mut results: Vec<Vec<Option<ValidationResult>>> =
+        files.iter().map(|refs| vec![None; refs.len()]).collect();
+    let mut deadline_passed = false;
+    for (file, i, mut rx) in receivers {
+        results[file][i] = match deadline {
+            // Past the deadline, keep only references that already finished.
+            _ if deadline_passed => rx.try_recv().ok(),
+            Some(deadline) => match tokio::time::timeout_at(deadline, &mut rx).await {
+                Ok(result) => result.ok(),
+                Err(_) => {
+                    deadline_passed = true;
+                    rx.try_recv().ok()
+                }
+            },
+            None => rx.await.ok(),
         };
+    }
 
-        pool.submit(job).await;
-        receivers.push((i, result_rx));
+    if !deadline_passed {
+        let retries = retry_failed_dbs(
+            &mut results,
+            &files,
+            &config,
+            &client,
+            &file_progress,
+            &run_cancel,
+        );
+        match deadline {
+            // Keep whatever the passes improved before the deadline.
+            Some(deadline) => {
+                let _ = tokio::time::timeout_at(deadline, retries).await;
+            }
+            None => retries.await,
+        }
     }
 
-    // Collect results
-    let mut results: Vec<Option<ValidationResult>> = vec![None; total];
-    for (i, rx) in receivers {
-        if let Ok(result) = rx.await {
-            results[i] = Some(result);
+    if let Some(limit) = run_deadline
+        && deadline_passed
+    {
+        stopped.store(true, Ordering::Release);
+        run_cancel.cancel();
+        for (file, file_results) in results.iter().enumerate() {
+            let checked = file_results.iter().filter(|r| r.is_some()).count();
+            progress(
+                file,
+                run_deadline_warning(limit, checked, file_results.len() - checked),
+            );
         }
     }
 
+    rate_limit_status.abort();
     pool.shutdown().await;
 
-    results.into_iter().flatten().collect()
+    results
+        .into_iter()
+        .map(|file_results| file_results.into_iter().flatten().collect())
+        .collect()
+}
+
+/// Wait before the second retry pass; it doubles for each later pass.
+pub const RETRY_PASS_BACKOFF: Duration = Duration::from_secs(2);
+
+/// Whether `result` is worth another pass: not found, with at least one
+/// database that failed rather than answered.
+fn needs_retry(result: &ValidationResult) -> bool {
+    result.status == Status::NotFound && !result.failed_dbs.is_empty()
+}
+
+/// Run up to [`Config::max_retry_passes`] retry passes over each file's
+/// `results`, re-querying only each reference's failed databases. A result
+/// is updated as soon as its retry finishes, so stopping midway loses nothing.
+async fn retry_failed_dbs(
+    results: &mut [Vec<Option<ValidationResult>>],
+    files: &[Vec<Reference>],
+    config: &Arc<Config>,
+    client: &reqwest::Client,
+    progress: &[Arc<dyn Fn(ProgressEvent) + Send + Sync>],
+    cancel: &CancellationToken,
+) {
+    let permits = Arc::new(tokio::sync::Semaphore::new(config.num_workers.max(1)));
+    for pass in 0..config.max_retry_passes {
+        let pending: Vec<(usize, usize)> = results
+            .iter()
+            .enumerate()
+            .flat_map(|(file, file_results)| {
+                file_results
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, r)| r.as_ref().is_some_and(needs_retry))
+                    .map(move |(i, _)| (file, i))
+            })
+            .collect();
+        if pending.is_empty() || cancel.is_cancelled() {
+            return;
+        }
+        if pass > 0 {
+            let backoff = RETRY_PASS_BACKOFF * 2u32.saturating_pow(pass as u32 - 1);
+            tokio::select! {
+                _ = cancel.cancelled() => return,
+                _ = tokio::time::sleep(backoff) => {}
+            }
+        }
+        progress[0](ProgressEvent::RetryPass {
+            count: pending.len(),
+        });
+
+        let mut retries = tokio::task::JoinSet::new();
+        for (file, i) in pending {
+            let failed_dbs = results[file][i]
+                .as_ref()
+                .map(ValidationResult::failed_db_names);
+            let failed_dbs = failed_dbs.unwrap_or_default();
+            let reference = files[file][i].clone();
+            progress[file](ProgressEvent::Retrying {
+                index: i,
+                total: files[file].len(),
+                title: reference.title.clone().unwrap_or_default(),
+                failed_dbs: failed_dbs.clone(),
+            });
+            let config = config.clone();
+            let client = client.clone();
+            let permits = permits.clone();
+            let progress = progress[file].clone();
+            retries.spawn(async move {
+                let _permit = permits.acquire_owned().await;
+                let retry = check_single_reference_retry(
+                    &reference,
+                    &config,
+                    &client,
+                    &failed_dbs,
+                    None,
+                    Some(&progress),
+                )
+                .await;
+                (file, i, retry)
+            });
+        }
+
+        loop {
+            let joined = tokio::select! {
+                _ = cancel.cancelled() => return,
+                joined = retries.join_next() => joined,
+            };
+            let Some(joined) = joined else { break };
+            let Ok((file, i, retry)) = joined else {
+                continue;
+            };
+            if let Some(result) = results[file][i].as_mut() {
+                merge_retry(result, retry);
+                progress[file](ProgressEvent::Result {
+                    index: i,
+                    total: files[file].len(),
+                    result: Box::new(result.clone()),
+                });
+            }
+        }
+    }
+}
+
+/// Fold a retry of `result`'s failed databases back into it. The retried
+/// databases' answers replace their failures; if one of them found the
+/// paper, its verdict replaces the original not-found one.
+fn merge_retry(result: &mut ValidationResult, retry: ValidationResult) {
+    let retried = std::mem::take(&mut result.failed_dbs);
+    result
+        .db_results
+        .retain(|r| !retried.iter().any(|f| f.name == r.db_name));
+    result.db_results.extend(retry.db_results);
+    result.failed_dbs = retry.failed_dbs;
+    if retry.status != Status::NotFound {
+        result.status = retry.status;
+        result.source = retry.source;
+        result.agreeing_dbs = retry.agreeing_dbs;
+        result.found_authors = retry.found_authors;
+        result.paper_url = retry.paper_url;
+        result.reason = retry.reason;
+        result.match_score = retry.match_score;
+        result.title_near_miss = retry.title_near_miss;
+        result.venue = retry.venue;
+        result.year = retry.year;
+        result.orcid = retry.orcid;
+    }
+}
+
+/// How often [`ProgressEvent::RateLimitStatus`] is sent while a database is
+/// throttled.
+pub const RATE_LIMIT_STATUS_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Send the rate limiters' state every [`RATE_LIMIT_STATUS_INTERVAL`] while
+/// any of them is throttled, and once more when the last one recovers.
+/// Runs until aborted.
+pub async fn report_rate_limits(
+    limiters: Arc<RateLimiters>,
+    progress: Arc<dyn Fn(ProgressEvent) + Send + Sync>,
+) {
+    let mut interval = tokio::time::interval(RATE_LIMIT_STATUS_INTERVAL);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    let mut was_throttled = false;
+    loop {
+        interval.tick().await;
+        let states = limiters.snapshot();
+        let throttled = states.iter().any(|s| s.is_throttled());
+        if throttled || was_throttled {
+            progress(ProgressEvent::RateLimitStatus { limiters: states });
+        }
+        was_throttled = throttled;
+    }
+}
+
+/// Warning emitted when a run hit [`Config::run_deadline`] with `skipped`
+/// references still unchecked.
+pub fn run_deadline_warning(deadline: Duration, checked: usize, skipped: usize) -> ProgressEvent {
+    ProgressEvent::Warning {
+        index: checked,
+        total: checked + skipped,
+        title: String::new(),
+        failed_dbs: vec![],
+        message: format!(
+            "run deadline of {}s reached after {} references; skipping the remaining {}",
+            deadline.as_secs_f64(),
+            checked,
+            skipped
+        ),
+    }
+}
+
+/// Load [`Config::local_bib_path`] into [`Config::local_bib`] unless it is
+/// already loaded. Returns a warning for a run of `total` references when
+/// the file can't be read; the run goes on without the library.
+pub fn load_local_bib(config: &mut Config, total: usize) -> Option<ProgressEvent> {
+    if config.local_bib.is_some() {
+        return None;
+    }
+    let path = config.local_bib_path.as_ref()?;
+    match crate::db::bibtex::BibTexBackend::open(path) {
+        Ok(library) => {
+            config.local_bib = Some(Arc::new(library));
+            None
+        }
+        Err(e) => Some(ProgressEvent::Warning {
+            index: 0,
+            total,
+            title: String::new(),
+            failed_dbs: vec![],
+            message: format!("cannot load BibTeX library {}: {}", path.display(), e),
+        }),
+    }
+}
+
+/// Load [`Config::retraction_db_path`] into [`Config::retraction_db`] unless
+/// it is already loaded. Returns a warning for a run of `total` references
+/// when the file can't be read or is older than
+/// [`Config::retraction_max_age_days`].
+pub fn load_retraction_db(config: &mut Config, total: usize) -> Option<ProgressEvent> {
+    let path = config.retraction_db_path.clone()?;
+    let warning = |message| ProgressEvent::Warning {
+        index: 0,
+        total,
+        title: String::new(),
+        failed_dbs: vec![],
+        message,
+    };
+
+    if config.retraction_db.is_none() {
+        match RetractionWatch::open(&path) {
+            Ok(db) => config.retraction_db = Some(Arc::new(db)),
+            Err(e) => {
+                return Some(warning(format!(
+                    "cannot load Retraction Watch data from {}: {}",
+                    path.display(),
+                    e
+                )));
+            }
+        }
+    }
+
+    let db = config.retraction_db.as_ref()?;
+    if !db.is_stale(config.retraction_max_age_days) {
+        return None;
+    }
+    let days = db.age().map_or(0, |age| age.as_secs() / 86400);
+    Some(warning(format!(
+        "Retraction Watch data at {} is {} days old; download a fresh copy to catch recent retractions",
+        path.display(),
+        days
+    )))
+}
+
+/// A warning for a run of `total` references when the offline DBLP database
+/// is at least [`Config::dblp_max_age_days`] old. Papers published since it
+/// was built would otherwise be reported as not found.
+pub fn dblp_staleness_warning(config: &Config, total: usize) -> Option<ProgressEvent> {
+    let path = config.dblp_offline_path.as_deref()?;
+    let staleness = {
+        let db = config.dblp_offline_db.as_ref()?.lock().ok()?;
+        db.check_staleness(config.dblp_max_age_days).ok()?
+    };
+    if !staleness.is_stale {
+        return None;
+    }
+    Some(ProgressEvent::Warning {
+        index: 0,
+        total,
+        title: String::new(),
+        failed_dbs: vec![],
+        message: dblp_stale_message(path, &staleness),
+    })
+}
+
+/// What to tell the user about a stale offline DBLP database at `path`.
+pub fn dblp_stale_message(
+    path: &std::path::Path,
+    staleness: &hallucinator_dblp::StalenessCheck,
+) -> String {
+    match (staleness.build_day(), staleness.age_days) {
+        (Some(day), Some(days)) => format!(
+            "offline DBLP database at {} was built on {} ({} days ago); recent papers may be reported as not found. Rebuild it with update-dblp",
+            path.display(),
+            day,
+            days
+        ),
+        _ => format!(
+            "offline DBLP database at {} has no build date and may be stale; rebuild it with update-dblp",
+            path.display()
+        ),
+    }
+}
+
+/// A warning naming the entries of [`Config::only_dbs`] that match no
+/// database, for a run of `total` references. Those names select nothing,
+/// which is almost always a typo.
+pub fn unknown_only_dbs_warning(config: &Config, total: usize) -> Option<ProgressEvent> {
+    let unknown: Vec<&str> = config
+        .only_dbs
+        .iter()
+        .map(String::as_str)
+        .filter(|name| !crate::db::is_known_database(name))
+        .collect();
+    if unknown.is_empty() {
+        return None;
+    }
+    Some(ProgressEvent::Warning {
+        index: 0,
+        total,
+        title: String::new(),
+        failed_dbs: vec![],
+        message: format!(
+            "unknown database(s) in --only-dbs: {} (known: {})",
+            unknown.join(", "),
+            crate::db::DATABASE_NAMES.join(", ")
+        ),
+    })
+}
+
+/// Skip reason recorded on references beyond [`Config::max_references`].
+pub const MAX_REFERENCES_SKIP_REASON: &str = "max_references";
+
+/// Mark every checkable reference after the first `max` as skipped.
+///
+/// References already skipped during extraction don't count towards the cap.
+/// Returns how many references were newly skipped.
+pub fn cap_references(refs: &mut [Reference], max: Option<usize>) -> usize {
+    let Some(max) = max else {
+        return 0;
+    };
+    let mut kept = 0;
+    let mut capped = 0;
+    for r in refs.iter_mut().filter(|r| r.skip_reason.is_none()) {
+        if kept < max {
+            kept += 1;
+        } else {
+            r.skip_reason = Some(MAX_REFERENCES_SKIP_REASON.to_string());
+            capped += 1;
+        }
+    }
+    capped
+}
+
+/// Warning emitted when [`cap_references`] skipped `capped` references
+/// beyond a cap of `max`.
+pub fn reference_cap_warning(max: usize, capped: usize) -> ProgressEvent {
+    let total = max + capped;
+    ProgressEvent::Warning {
+        index: max,
+        total,
+        title: String::new(),
+        failed_dbs: vec![],
+        message: format!(
+            "{} references exceed the limit of {}; skipping the remaining {}",
+            total, max, capped
+        ),
+    }
 }
 
 /// Check a single reference against all databases.
+///
+/// `on_rate_limited` receives a [`ProgressEvent::RateLimited`] whenever a
+/// query backs off after a 429.
 pub async fn check_single_reference(
     reference: &Reference,
     config: &Config,
     client: &reqwest::Client,
     longer_timeout: bool,
     on_db_complete: Option<&(dyn Fn(DbResult) + Send + Sync)>,
+    on_rate_limited: Option<&OnRateLimited>,
 ) -> ValidationResult {
+    if reference.doi_only {
+        return check_doi_only_reference(reference, config, client).await;
+    }
     let title = reference.title.as_deref().unwrap_or("");
     let timeout = Duration::from_secs(config.db_timeout_secs);
 
     // Step 1: Validate DOI if present
     let mut doi_info = None;
-    if let Some(ref doi) = reference.doi {
-        let doi_result = validate_doi(doi, client, timeout).await;
-        let match_result = check_doi_match(&doi_result, title, &reference.authors);
+    // In consensus mode a DOI that backs the citation is one vote among the
+    // databases rather than the final word.
+    let mut doi_vote = None;
+    if let Some(ref doi) = reference.doi
+        && !looks_valid(doi)
+    {
+        // Structurally impossible DOI — don't bother resolving it.
+        doi_info = Some(DoiInfo {
+            doi: doi.clone(),
+            valid: false,
+            title: None,
+            structurally_valid: false,
+            title_mismatch: false,
+        });
+    } else if let Some(ref doi) = reference.doi {
+        let doi_result = validate_doi(doi, client, config.db_timeout("DOI")).await;
+        let match_result = check_doi_match(
+            &doi_result,
+            title,
+            &reference.authors,
+            config.author_policy(),
+        );
 
         doi_info = Some(DoiInfo {
             doi: doi.clone(),
             valid: doi_result.valid,
             title: doi_result.title.clone(),
+            structurally_valid: true,
+            title_mismatch: doi_title_mismatch(&doi_result, title),
         });
 
-        match match_result {
+        let doi_match = match match_result {
             DoiMatchResult::Verified {
-                doi_title: _,
+                doi_title,
                 doi_authors,
-            } => {
-                // Check retraction
-                let retraction =
-                    check_retraction(doi, client, timeout, config.crossref_mailto.as_deref()).await;
-                let retraction_info = if retraction.retracted {
-                    Some(RetractionInfo {
-                        is_retracted: true,
-                        retraction_doi: retraction.retraction_doi,
-                        retraction_source: retraction.retraction_type,
-                    })
-                } else {
-                    None
-                };
-
-                return ValidationResult {
-                    title: title.to_string(),
-                    raw_citation: reference.raw_citation.clone(),
-                    ref_authors: reference.authors.clone(),
-                    status: Status::Verified,
-                    source: Some("DOI".into()),
-                    found_authors: doi_authors,
-                    paper_url: Some(format!("https://doi.org/{}", doi)),
-                    failed_dbs: vec![],
-                    db_results: vec![DbResult {
-                        db_name: "DOI".into(),
-                        status: DbStatus::Match,
-                        elapsed: None,
-                        found_authors: vec![],
-                        paper_url: Some(format!("https://doi.org/{}", doi)),
-                        error_message: None,
-                    }],
-                    doi_info,
-                    arxiv_info: None,
-                    retraction_info,
-                };
-            }
+            } => Some((DbStatus::Match, doi_title, doi_authors)),
             DoiMatchResult::AuthorMismatch {
-                doi_title: _,
+                doi_title,
                 doi_authors,
-            } => {
-                return ValidationResult {
-                    title: title.to_string(),
-                    raw_citation: reference.raw_citation.clone(),
-                    ref_authors: reference.authors.clone(),
-                    status: Status::AuthorMismatch,
-                    source: Some("DOI".into()),
-                    found_authors: doi_authors,
+            } => Some((DbStatus::AuthorMismatch, doi_title, doi_authors)),
+            // DOI invalid or title mismatch — fall through to DB search
+            _ => None,
+        };
+        // A DOI title scoring below the minimum is treated like a title
+        // mismatch, so the databases still get a say.
+        let score = doi_match
+            .as_ref()
+            .map(|(_, doi_title, _)| title_similarity(title, doi_title));
+        let score_ok = score.is_none_or(|s| s >= config.min_match_score);
+
+        if config.consensus_mode && score_ok {
+            doi_vote = doi_match
+                .as_ref()
+                .map(|(status, doi_title, doi_authors)| DbResult {
+                    db_name: "DOI".into(),
+                    status: status.clone(),
+                    elapsed: None,
+                    found_title: Some(doi_title.clone()),
+                    found_authors: doi_authors.clone(),
                     paper_url: Some(format!("https://doi.org/{}", doi)),
-                    failed_dbs: vec![],
-                    db_results: vec![DbResult {
-                        db_name: "DOI".into(),
-                        status: DbStatus::AuthorMismatch,
-                        elapsed: None,
-                        found_authors: vec![],
-                        paper_url: Some(format!("https://doi.org/{}", doi)),
-                        error_message: None,
-                    }],
-                    doi_info,
-                    arxiv_info: None,
-                    retraction_info: None,
-                };
-            }
-            _ => {
-                // DOI invalid or title mismatch — fall through to DB search
-            }
+                    error_message: None,
+                    alternates: vec![],
+                    threshold: None,
+                    score,
+                    venue: None,
+                    year: None,
+                });
+        }
+
+        // A low score or consensus mode also leaves it to the databases
+        if let Some((db_status, doi_title, doi_authors)) = doi_match
+            && score_ok
+            && !config.consensus_mode
+        {
+            let paper_url = format!("https://doi.org/{}", doi);
+            let mut search = DbSearchResult {
+                status: if db_status == DbStatus::Match {
+                    Status::Verified
+                } else {
+                    Status::AuthorMismatch
+                },
+                source: Some("DOI".into()),
+                agreeing_dbs: vec![],
+                found_authors: doi_authors,
+                paper_url: Some(paper_url.clone()),
+                failed_dbs: vec![],
+                db_results: vec![],
+            };
+            // With the ORCID check, an author mismatch is verified after all
+            // when a cited author claims the work
+            let orcid = confirm_with_orcid(reference, &mut search, config, client).await;
+            let verified = search.status == Status::Verified;
+
+            // Check retraction if verified, in the offline dataset first
+            let retraction_info = if verified {
+                let local = config.retraction_db.as_ref().and_then(|db| db.lookup(doi));
+                with_crossref_fallback(
+                    local,
+                    check_retraction(doi, client, timeout, config.crossref_mailto.as_deref()),
+                )
+                .await
+            } else {
+                None
+            };
+
+            let mut result = ValidationResult {
+                title: title.to_string(),
+                raw_citation: reference.raw_citation.clone(),
+                ref_authors: reference.authors.clone(),
+                status: search.status,
+                source: search.source,
+                agreeing_dbs: vec![],
+                found_authors: search.found_authors,
+                paper_url: search.paper_url,
+                failed_dbs: vec![],
+                db_results: vec![DbResult {
+                    db_name: "DOI".into(),
+                    status: db_status,
+                    elapsed: None,
+                    found_title: Some(doi_title),
+                    found_authors: vec![],
+                    paper_url: Some(paper_url),
+                    error_message: None,
+                    alternates: vec![],
+                    threshold: None,
+                    score,
+                    venue: None,
+                    year: None,
+                }],
+                doi_info,
+                arxiv_info: None,
+                retraction_info,
+                reason: (!verified).then_some(FlagReason::AuthorsDiverged),
+                match_score: score,
+                title_near_miss: None,
+                venue: None,
+                year: None,
+                orcid,
+            };
+            result.title_near_miss = crate::title_near_miss(config, &result);
+            link_open_access(&mut result, config, client).await;
+            return result;
         }
     }
 
     // Step 2: Query all databases concurrently
-    let db_result = query_all_databases(
+    let mut db_result = query_all_databases(
         title,
         &reference.authors,
         config,
@@ -170,45 +720,72 @@ pub async fn check_single_reference(
         longer_timeout,
         None,
         on_db_complete,
+        on_rate_limited,
     )
     .await;
+    if let Some(vote) = doi_vote {
+        // The DOI backend cannot answer a title search, so its vote comes
+        // from step 1.
+        db_result.db_results.retain(|r| r.db_name != "DOI");
+        db_result.db_results.insert(0, vote);
+        apply_consensus(
+            &mut db_result,
+            config.consensus_min,
+            config.check_openalex_authors,
+        );
+    }
 
-    // Step 3: Check retraction by title if verified
-    let retraction_info = if db_result.status == Status::Verified {
-        let retraction =
-            check_retraction_by_title(title, client, timeout, config.crossref_mailto.as_deref())
-                .await;
-        if retraction.retracted {
-            Some(RetractionInfo {
-                is_retracted: true,
-                retraction_doi: retraction.retraction_doi,
-                retraction_source: retraction.retraction_type,
-            })
-        } else {
-            None
-        }
-    } else {
+    // Step 3: With the ORCID check, an author mismatch is verified after all
+    // when a cited author claims the matched work
+    let orcid = confirm_with_orcid(reference, &mut db_result, config, client).await;
+
+    // Step 4: Check retraction if verified: the offline dataset by DOI, then
+    // CrossRef by title
+    let retraction_info = if db_result.status != Status::Verified {
         None
+    } else {
+        let local = offline_retraction(config, reference, db_result.paper_url.as_deref());
+        with_crossref_fallback(
+            local,
+            check_retraction_by_title(title, client, timeout, config.crossref_mailto.as_deref()),
+        )
+        .await
     };
 
-    ValidationResult {
+    let reason = crate::flag_reason(
+        config,
+        &db_result.status,
+        doi_info.as_ref(),
+        &db_result.db_results,
+    );
+    let match_score = crate::source_score(db_result.source.as_deref(), &db_result.db_results);
+    let (venue, year) =
+        crate::source_publication(db_result.source.as_deref(), &db_result.db_results);
+    let arxiv_info = crate::arxiv_info(reference, &db_result.db_results);
+    let mut result = ValidationResult {
         title: title.to_string(),
         raw_citation: reference.raw_citation.clone(),
         ref_authors: reference.authors.clone(),
         status: db_result.status,
         source: db_result.source,
+        agreeing_dbs: db_result.agreeing_dbs,
         found_authors: db_result.found_authors,
         paper_url: db_result.paper_url,
         failed_dbs: db_result.failed_dbs,
         db_results: db_result.db_results,
         doi_info,
-        arxiv_info: reference.arxiv_id.as_ref().map(|id| ArxivInfo {
-            arxiv_id: id.clone(),
-            valid: false, // Will be validated separately if needed
-            title: None,
-        }),
+        arxiv_info,
         retraction_info,
-    }
+        reason,
+        match_score,
+        title_near_miss: None,
+        venue,
+        year,
+        orcid,
+    };
+    result.title_near_miss = crate::title_near_miss(config, &result);
+    link_open_access(&mut result, config, client).await;
+    result
 }
 
 /// Retry a reference check targeting only the previously failed databases.
@@ -218,10 +795,14 @@ pub async fn check_single_reference_retry(
     client: &reqwest::Client,
     failed_dbs: &[String],
     on_db_complete: Option<&(dyn Fn(DbResult) + Send + Sync)>,
+    on_rate_limited: Option<&OnRateLimited>,
 ) -> ValidationResult {
+    if reference.doi_only {
+        return check_doi_only_reference(reference, config, client).await;
+    }
     let title = reference.title.as_deref().unwrap_or("");
 
-    let db_result = query_all_databases(
+    let mut db_result = query_all_databases(
         title,
         &reference.authors,
         config,
@@ -229,15 +810,22 @@ pub async fn check_single_reference_retry(
         true, // longer timeout for retries
         Some(failed_dbs),
         on_db_complete,
+        on_rate_limited,
     )
     .await;
+    let orcid = confirm_with_orcid(reference, &mut db_result, config, client).await;
 
-    ValidationResult {
+    let reason = crate::flag_reason(config, &db_result.status, None, &db_result.db_results);
+    let match_score = crate::source_score(db_result.source.as_deref(), &db_result.db_results);
+    let (venue, year) =
+        crate::source_publication(db_result.source.as_deref(), &db_result.db_results);
+    let mut result = ValidationResult {
         title: title.to_string(),
         raw_citation: reference.raw_citation.clone(),
         ref_authors: reference.authors.clone(),
         status: db_result.status,
         source: db_result.source,
+        agreeing_dbs: db_result.agreeing_dbs,
         found_authors: db_result.found_authors,
         paper_url: db_result.paper_url,
         failed_dbs: db_result.failed_dbs,
@@ -245,5 +833,266 @@ pub async fn check_single_reference_retry(
         doi_info: None,
         arxiv_info: None,
         retraction_info: None,
+        reason,
+        match_score,
+        title_near_miss: None,
+        venue,
+        year,
+        orcid,
+    };
+    result.title_near_miss = crate::title_near_miss(config, &result);
+    link_open_access(&mut result, config, client).await;
+    result
+}
+
+/// With the ORCID check, an author mismatch is verified after all when
+/// cited authors claim the matched work (see
+/// [`crate::orcid::confirm_mismatch`]). Returns the confirming iD.
+pub(crate) async fn confirm_with_orcid(
+    reference: &Reference,
+    db_result: &mut DbSearchResult,
+    config: &Config,
+    client: &reqwest::Client,
+) -> Option<String> {
+    if db_result.status != Status::AuthorMismatch {
+        return None;
+    }
+    let orcid = crate::orcid::confirm_mismatch(
+        reference,
+        db_result.paper_url.as_deref(),
+        &db_result.found_authors,
+        config,
+        client,
+    )
+    .await;
+    if orcid.is_some() {
+        db_result.status = Status::Verified;
+    }
+    orcid
+}
+
+/// The notice the offline Retraction Watch data
+/// ([`Config::retraction_db`]) has for a reference, looked up by its cited
+/// DOI, then by the DOI link of its match (`paper_url`).
+pub(crate) fn offline_retraction(
+    config: &Config,
+    reference: &Reference,
+    paper_url: Option<&str>,
+) -> Option<RetractionInfo> {
+    let db = config.retraction_db.as_ref()?;
+    [reference.doi.as_deref(), paper_url]
+        .into_iter()
+        .flatten()
+        .find_map(|doi| db.lookup(doi))
+}
+
+/// Point a verified result at its open-access copy, when Unpaywall has one
+/// (see [`crate::db::unpaywall::open_access_url`]).
+pub(crate) async fn link_open_access(
+    result: &mut ValidationResult,
+    config: &Config,
+    client: &reqwest::Client,
+) {
+    if let Some(url) = crate::db::unpaywall::open_access_url(result, config, client).await {
+        result.paper_url = Some(url);
+    }
+}
+
+/// Check a [`Reference::doi_only`] reference (a dataset or software citation
+/// kept for its DOI) by resolving the DOI alone: it is verified when the DOI
+/// resolves, since there is no scholarly title to search the databases for.
+pub async fn check_doi_only_reference(
+    reference: &Reference,
+    config: &Config,
+    client: &reqwest::Client,
+) -> ValidationResult {
+    let title = reference.title.as_deref().unwrap_or("");
+    let doi = reference.doi.as_deref().unwrap_or("");
+    let timeout = Duration::from_secs(config.db_timeout_secs);
+    let paper_url = format!("https://doi.org/{}", doi);
+
+    let start = std::time::Instant::now();
+    let resolution = if looks_valid(doi) {
+        Some(validate_doi(doi, client, config.db_timeout("DOI")).await)
+    } else {
+        None
+    };
+    let elapsed = start.elapsed();
+
+    let doi_info = DoiInfo {
+        doi: doi.to_string(),
+        valid: resolution.as_ref().is_some_and(|r| r.valid),
+        title: resolution.as_ref().and_then(|r| r.title.clone()),
+        structurally_valid: resolution.is_some(),
+        // A dataset or software title is not expected to match the record.
+        title_mismatch: false,
+    };
+    // A DOI that could not be looked up (timeout, network, bad metadata) is a
+    // failed query, not evidence that the DOI does not exist.
+    let error = resolution
+        .as_ref()
+        .and_then(|r| r.error.clone())
+        .filter(|e| e != "DOI not found");
+    let failure = resolution
+        .as_ref()
+        .and_then(|r| r.failure.as_ref())
+        .map_or(FailureReason::Other, DbQueryError::failure_reason);
+
+    let (status, db_status, found_authors, retraction_info) = match resolution {
+        Some(r) if r.valid => {
+            let local = offline_retraction(config, reference, None);
+            let retraction_info = with_crossref_fallback(
+                local,
+                check_retraction(doi, client, timeout, config.crossref_mailto.as_deref()),
+            )
+            .await;
+            (
+                Status::Verified,
+                DbStatus::Match,
+                r.authors,
+                retraction_info,
+            )
+        }
+        _ if error.is_some() => (Status::NotFound, DbStatus::Error, vec![], None),
+        _ => (Status::NotFound, DbStatus::NoMatch, vec![], None),
+    };
+
+    let verified = status == Status::Verified;
+    let reason = FlagReason::classify(&status, Some(&doi_info));
+    let mut result = ValidationResult {
+        title: title.to_string(),
+        raw_citation: reference.raw_citation.clone(),
+        ref_authors: reference.authors.clone(),
+        status,
+        source: verified.then(|| "DOI".to_string()),
+        agreeing_dbs: vec![],
+        found_authors: found_authors.clone(),
+        paper_url: verified.then(|| paper_url.clone()),
+        failed_dbs: if error.is_some() {
+            vec![FailedDb::new("DOI", failure)]
+        } else {
+            vec![]
+        },
+        db_results: vec![DbResult {
+            db_name: "DOI".into(),
+            status: db_status,
+            elapsed: Some(elapsed),
+            found_title: doi_info.title.clone(),
+            found_authors,
+            paper_url: verified.then_some(paper_url),
+            error_message: error,
+            alternates: vec![],
+            threshold: None,
+            score: None,
+            venue: None,
+            year: None,
+        }],
+        doi_info: Some(doi_info),
+        arxiv_info: None,
+        retraction_info,
+        reason,
+        match_score: None,
+        title_near_miss: None,
+        venue: None,
+        year: None,
+        orcid: None,
+    };
+    link_open_access(&mut result, config, client).await;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hallucinator_dblp::StalenessCheck;
+    use std::path::Path;
+
+    #[test]
+    fn dblp_stale_message_gives_build_date_and_age() {
+        let staleness = StalenessCheck {
+            is_stale: true,
+            age_days: Some(45),
+            build_date: Some("1709210096".to_string()),
+        };
+        let message = dblp_stale_message(Path::new("dblp.db"), &staleness);
+        assert!(message.contains("dblp.db was built on 2024-02-29 (45 days ago)"));
+        assert!(message.contains("update-dblp"));
+
+        let undated = StalenessCheck {
+            is_stale: true,
+            age_days: None,
+            build_date: None,
+        };
+        assert!(dblp_stale_message(Path::new("dblp.db"), &undated).contains("no build date"));
+    }
+    fn db(name: &str, status: DbStatus) -> DbResult {
+        DbResult::test(name, status)
+    }
+
+    fn result(status: Status, db_results: Vec<DbResult>, failed_dbs: &[&str]) -> ValidationResult {
+        ValidationResult {
+            status,
+            failed_dbs: failed_dbs
+                .iter()
+                .map(|&name| FailedDb::new(name, FailureReason::Timeout))
+                .collect(),
+            db_results,
+            reason: Some(FlagReason::NoMatchAnyDb),
+            ..ValidationResult::test_default()
+        }
+    }
+
+    #[test]
+    fn retry_replaces_failed_databases_and_not_found_verdict() {
+        let mut original = result(
+            Status::NotFound,
+            vec![
+                db("CrossRef", DbStatus::NoMatch),
+                db("DBLP", DbStatus::Timeout),
+                db("arXiv", DbStatus::Error),
+            ],
+            &["DBLP", "arXiv"],
+        );
+        assert!(needs_retry(&original));
+
+        // DBLP still times out: the verdict stands, arXiv is settled.
+        let retry = result(
+            Status::NotFound,
+            vec![
+                db("DBLP", DbStatus::Timeout),
+                db("arXiv", DbStatus::NoMatch),
+            ],
+            &["DBLP"],
+        );
+        merge_retry(&mut original, retry);
+        assert_eq!(original.status, Status::NotFound);
+        assert_eq!(original.failed_db_names(), vec!["DBLP"]);
+        assert_eq!(original.db_results.len(), 3);
+        assert!(needs_retry(&original));
+
+        let mut found = result(Status::Verified, vec![db("DBLP", DbStatus::Match)], &[]);
+        found.source = Some("DBLP".into());
+        found.reason = None;
+        found.match_score = Some(0.99);
+        merge_retry(&mut original, found);
+        assert_eq!(original.status, Status::Verified);
+        assert_eq!(original.source.as_deref(), Some("DBLP"));
+        assert_eq!(original.reason, None);
+        assert_eq!(original.match_score, Some(0.99));
+        assert!(original.failed_dbs.is_empty());
+        assert!(!needs_retry(&original));
+        let statuses: Vec<_> = original
+            .db_results
+            .iter()
+            .map(|r| (r.db_name.as_str(), r.status.clone()))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("CrossRef", DbStatus::NoMatch),
+                ("arXiv", DbStatus::NoMatch),
+                ("DBLP", DbStatus::Match),
+            ]
+        );
     }
 }
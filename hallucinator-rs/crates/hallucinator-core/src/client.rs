@@ -0,0 +1,303 @@
+//! HTTP access for database backends.
+//!
+//! Backends build and send requests through a [`ClientProvider`] rather than a
+//! bare `reqwest::Client`, so tests can swap in a [`RecordingClient`] that
+//! replays canned responses from fixture files instead of hitting the network.
+//!
+//! Set `HALLUCINATOR_RECORD=1` to have a [`RecordingClient`] make the real
+//! requests and (re)write the fixtures; otherwise it only replays them, and
+//! a request with no fixture panics. A backend that falls back to another
+//! request on error (a title search after a failed DOI lookup, say) would
+//! otherwise pass on the fallback's fixture without the test noticing.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+
+use serde::{Deserialize, Serialize};
+
+use crate::rate_limit::DbQueryError;
+
+/// Future returned by [`ClientProvider::send`].
+pub type SendFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<reqwest::Response, DbQueryError>> + Send + 'a>>;
+
+/// Builds and sends the HTTP requests a backend makes.
+///
+/// Implemented for `reqwest::Client`, so existing callers pass their client
+/// unchanged.
+pub trait ClientProvider: Send + Sync {
+    /// Start a GET request for `url`.
+    fn get(&self, url: &str) -> reqwest::RequestBuilder;
+
+    /// Send a request started with [`get`](Self::get).
+    fn send<'a>(&'a self, request: reqwest::RequestBuilder) -> SendFuture<'a>;
+}
+
+impl ClientProvider for reqwest::Client {
+    fn get(&self, url: &str) -> reqwest::RequestBuilder {
+        reqwest::Client::get(self, url)
+    }
+
+    fn send<'a>(&'a self, request: reqwest::RequestBuilder) -> SendFuture<'a> {
+        Box::pin(async move { request.send().await.map_err(DbQueryError::from) })
+    }
+}
+
+/// Whether a [`RecordingClient`] talks to the network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordMode {
+    /// Make real requests and save each response as a fixture.
+    Record,
+    /// Answer only from fixtures; a missing fixture panics.
+    Replay,
+}
+
+impl RecordMode {
+    /// `Record` when `HALLUCINATOR_RECORD` is set to anything but `0`/empty.
+    pub fn from_env() -> Self {
+        match std::env::var("HALLUCINATOR_RECORD") {
+            Ok(v) if !v.is_empty() && v != "0" => RecordMode::Record,
+            _ => RecordMode::Replay,
+        }
+    }
+}
+
+/// One recorded exchange, stored as pretty JSON so fixtures diff cleanly.
+#[derive(Debug, Serialize, Deserialize)]
+struct Fixture {
+    method: String,
+    url: String,
+    status: u16,
+    headers: BTreeMap<String, String>,
+    body: String,
+}
+
+/// Query parameters scrubbed from recorded URLs (and from fixture lookup), so
+/// fixtures never contain credentials and replay works without them.
+const REDACTED_PARAMS: &[&str] = &["api_key", "mailto", "key"];
+
+/// A [`ClientProvider`] that records responses to, or replays them from, a
+/// fixtures directory. Requests are matched on method and URL.
+pub struct RecordingClient {
+    inner: reqwest::Client,
+    dir: PathBuf,
+    mode: RecordMode,
+}
+
+impl RecordingClient {
+    pub fn new(inner: reqwest::Client, dir: impl Into<PathBuf>, mode: RecordMode) -> Self {
+        Self {
+            inner,
+            dir: dir.into(),
+            mode,
+        }
+    }
+
+    /// A client for `dir` in the mode selected by `HALLUCINATOR_RECORD`.
+    pub fn from_env(dir: impl Into<PathBuf>) -> Self {
+        let inner = crate::context::build_http_client(&crate::Config::default());
+        Self::new(inner, dir, RecordMode::from_env())
+    }
+
+    pub fn mode(&self) -> RecordMode {
+        self.mode
+    }
+
+    /// Path of the fixture that answers `method url`.
+    pub fn fixture_path(&self, method: &str, url: &str) -> PathBuf {
+        self.dir.join(fixture_name(method, &redact_url(url)))
+    }
+
+    async fn record(&self, request: reqwest::Request) -> Result<reqwest::Response, DbQueryError> {
+        let method = request.method().to_string();
+        let url = redact_url(request.url().as_str());
+        let resp = self
+            .inner
+            .execute(request)
+            .await
+            .map_err(DbQueryError::from)?;
+
+        let status = resp.status().as_u16();
+        let headers = resp
+            .headers()
+            .iter()
+            .filter(|(name, _)| *name != reqwest::header::SET_COOKIE)
+            .filter_map(|(name, value)| {
+                Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+            })
+            .collect();
+        let body = resp.text().await.map_err(DbQueryError::from)?;
+        let fixture = Fixture {
+            method,
+            url,
+            status,
+            headers,
+            body,
+        };
+
+        let path = self.dir.join(fixture_name(&fixture.method, &fixture.url));
+        let json = serde_json::to_string_pretty(&fixture)
+            .map_err(|e| DbQueryError::Other(e.to_string()))?;
+        std::fs::create_dir_all(&self.dir)
+            .and_then(|_| std::fs::write(&path, json + "\n"))
+            .map_err(|e| {
+                DbQueryError::Other(format!("cannot write fixture {}: {}", path.display(), e))
+            })?;
+        to_response(fixture)
+    }
+
+    /// Answer `request` from its fixture.
+    ///
+    /// # Panics
+    ///
+    /// When no fixture was recorded for the request.
+    fn replay(&self, request: &reqwest::Request) -> Result<reqwest::Response, DbQueryError> {
+        let path = self.fixture_path(request.method().as_str(), request.url().as_str());
+        let Ok(text) = std::fs::read_to_string(&path) else {
+            panic!(
+                "no recorded response for {} {} (expected {}; set HALLUCINATOR_RECORD=1 to record)",
+                request.method(),
+                redact_url(request.url().as_str()),
+                path.display()
+            );
+        };
+        let fixture: Fixture = serde_json::from_str(&text)
+            .map_err(|e| DbQueryError::Other(format!("bad fixture {}: {}", path.display(), e)))?;
+        to_response(fixture)
+    }
+}
+
+impl ClientProvider for RecordingClient {
+    fn get(&self, url: &str) -> reqwest::RequestBuilder {
+        self.inner.get(url)
+    }
+
+    fn send<'a>(&'a self, request: reqwest::RequestBuilder) -> SendFuture<'a> {
+        Box::pin(async move {
+            let request = request.build().map_err(DbQueryError::from)?;
+            match self.mode {
+                RecordMode::Record => self.record(request).await,
+                RecordMode::Replay => self.replay(&request),
+            }
+        })
+    }
+}
+
+fn to_response(fixture: Fixture) -> Result<reqwest::Response, DbQueryError> {
+    let mut builder = http::Response::builder().status(fixture.status);
+    for (name, value) in &fixture.headers {
+        builder = builder.header(name, value);
+    }
+    let resp = builder
+        .body(fixture.body)
+        .map_err(|e| DbQueryError::Other(e.to_string()))?;
+    Ok(reqwest::Response::from(resp))
+}
+
+/// Drop credential-bearing query parameters from `url`.
+fn redact_url(url: &str) -> String {
+    let Ok(mut parsed) = reqwest::Url::parse(url) else {
+        return url.to_string();
+    };
+    let kept: Vec<(String, String)> = parsed
+        .query_pairs()
+        .filter(|(k, _)| !REDACTED_PARAMS.contains(&k.as_ref()))
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+    if kept.is_empty() {
+        parsed.set_query(None);
+    } else {
+        parsed.query_pairs_mut().clear().extend_pairs(kept);
+    }
+    parsed.to_string()
+}
+
+/// `<host>-<hash>.json`: readable enough to find by eye, stable across runs
+/// and Rust versions (FNV-1a rather than `DefaultHasher`).
+fn fixture_name(method: &str, url: &str) -> String {
+    let host = reqwest::Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(str::to_string))
+        .unwrap_or_else(|| "unknown".to_string());
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in method.bytes().chain([b' ']).chain(url.bytes()) {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!("{}-{:016x}.json", host, hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redaction_drops_credentials_only() {
+        assert_eq!(
+            redact_url("https://api.example.org/works?q=graphs&api_key=SECRET&mailto=a%40b.c"),
+            "https://api.example.org/works?q=graphs"
+        );
+        assert_eq!(
+            redact_url("https://api.example.org/works?api_key=SECRET"),
+            "https://api.example.org/works"
+        );
+    }
+
+    #[test]
+    fn fixture_name_is_stable_and_ignores_credentials() {
+        let a = fixture_name(
+            "GET",
+            &redact_url("https://api.example.org/w?q=x&api_key=1"),
+        );
+        let b = fixture_name(
+            "GET",
+            &redact_url("https://api.example.org/w?q=x&api_key=2"),
+        );
+        assert_eq!(a, b);
+        assert!(a.starts_with("api.example.org-"));
+        assert_ne!(a, fixture_name("GET", "https://api.example.org/w?q=y"));
+    }
+
+    #[tokio::test]
+    async fn replays_recorded_fixture() {
+        let dir = std::env::temp_dir().join(format!("hallucinator-replay-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let client = RecordingClient::new(reqwest::Client::new(), &dir, RecordMode::Replay);
+
+        let url = "https://api.example.org/search?q=graphs";
+        let fixture = Fixture {
+            method: "GET".into(),
+            url: url.into(),
+            status: 200,
+            headers: BTreeMap::from([("content-type".into(), "application/json".into())]),
+            body: r#"{"ok":true}"#.into(),
+        };
+        std::fs::write(
+            client.fixture_path("GET", url),
+            serde_json::to_string(&fixture).unwrap(),
+        )
+        .unwrap();
+
+        let resp = client
+            .send(client.get(&format!("{}&api_key=SECRET", url)))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), 200);
+        assert_eq!(resp.text().await.unwrap(), r#"{"ok":true}"#);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "set HALLUCINATOR_RECORD=1 to record")]
+    async fn replay_panics_on_unrecorded_request() {
+        let dir =
+            std::env::temp_dir().join(format!("hallucinator-unrecorded-{}", std::process::id()));
+        let client = RecordingClient::new(reqwest::Client::new(), &dir, RecordMode::Replay);
+        let _ = client
+            .send(client.get("https://api.example.org/search?q=other"))
+            .await;
+    }
+}
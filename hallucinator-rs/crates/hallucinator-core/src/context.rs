@@ -0,0 +1,188 @@
+//! Shared state for checking runs.
+//!
+//! A [`CheckContext`] bundles the pieces of state that should outlive a single
+//! `check_references` call: the query cache, the per-DB rate limiters, and the
+//! HTTP client (with its connection pool). Construct one per batch (or per
+//! process) and pass it to each call so that cache hits and rate-limit backoff
+//! carry over between papers.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::{Config, QueryCache, RateLimiters, build_query_cache};
+
+/// Cache, rate limiters and HTTP client shared across checking calls.
+///
+/// Cloning is cheap: all fields are reference-counted handles.
+#[derive(Clone)]
+pub struct CheckContext {
+    pub cache: Option<Arc<QueryCache>>,
+    pub rate_limiters: Arc<RateLimiters>,
+    pub client: reqwest::Client,
+}
+
+impl std::fmt::Debug for CheckContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CheckContext")
+            .field("cache", &self.cache.as_ref().map(|c| format!("{:?}", c)))
+            .finish_non_exhaustive()
+    }
+}
+
+impl CheckContext {
+    /// Build a fresh context for `config`.
+    ///
+    /// Opens the query cache at `config.cache_path` (in-memory if unset),
+    /// capped at `config.cache_max_entries`, and
+    /// creates rate limiters sized for the configured CrossRef mailto,
+    /// Semantic Scholar key and NCBI key. The cache and limiters already on `config` are
+    /// ignored.
+    pub fn new(config: &Config) -> Self {
+        Self {
+            cache: Some(build_query_cache(
+                config.cache_path.as_deref(),
+                config.cache_max_entries,
+            )),
+            rate_limiters: Arc::new(RateLimiters::new(
+                config.crossref_mailto.is_some(),
+                config.s2_api_key.is_some(),
+                config.ncbi_api_key.is_some(),
+            )),
+            client: build_http_client(config),
+        }
+    }
+
+    /// Build a context that reuses the cache and rate limiters already held
+    /// by `config`, with a new HTTP client.
+    ///
+    /// This is the one-shot path used when callers don't supply a context.
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            cache: config.query_cache.clone(),
+            rate_limiters: config.rate_limiters.clone(),
+            client: build_http_client(config),
+        }
+    }
+
+    /// Point `config` at this context's cache and rate limiters.
+    pub fn apply_to(&self, config: &mut Config) {
+        config.query_cache = self.cache.clone();
+        config.rate_limiters = self.rate_limiters.clone();
+    }
+}
+
+/// Build the HTTP client used for database queries.
+///
+/// A proxy or CA certificate that can't be used is logged and ignored; call
+/// [`try_build_http_client`] first to surface those errors to the user.
+pub fn build_http_client(config: &Config) -> reqwest::Client {
+    try_build_http_client(config).unwrap_or_else(|e| {
+        log::warn!("{}; falling back to default HTTP client", e);
+        reqwest::Client::builder()
+            .user_agent(config.user_agent())
+            .build()
+            .unwrap_or_else(|_| reqwest::Client::new())
+    })
+}
+
+/// Build the HTTP client, failing if the configured proxy URL or extra CA
+/// certificate is invalid.
+///
+/// Without an explicit `proxy`, reqwest picks up `HTTPS_PROXY`, `HTTP_PROXY`
+/// and `NO_PROXY` from the environment. An explicit proxy still honors
+/// `NO_PROXY`.
+pub fn try_build_http_client(config: &Config) -> Result<reqwest::Client, String> {
+    let mut builder = reqwest::Client::builder()
+        .user_agent(config.user_agent())
+        .pool_max_idle_per_host(2)
+        .pool_idle_timeout(Duration::from_secs(30));
+
+    if let Some(ref url) = config.proxy {
+        let proxy = reqwest::Proxy::all(url)
+            .map_err(|e| format!("Invalid proxy URL {}: {}", url, e))?
+            .no_proxy(reqwest::NoProxy::from_env());
+        builder = builder.proxy(proxy);
+    }
+
+    if let Some(ref path) = config.extra_ca_cert {
+        let pem = std::fs::read(path)
+            .map_err(|e| format!("Failed to read CA certificate {}: {}", path.display(), e))?;
+        let certs = reqwest::Certificate::from_pem_bundle(&pem)
+            .map_err(|e| format!("Invalid CA certificate {}: {}", path.display(), e))?;
+        if certs.is_empty() {
+            return Err(format!("No certificates found in {}", path.display()));
+        }
+        for cert in certs {
+            builder = builder.add_root_certificate(cert);
+        }
+    }
+
+    builder
+        .build()
+        .map_err(|e| format!("Failed to build HTTP client: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_proxy_is_an_error() {
+        let config = Config {
+            proxy: Some("not a url".into()),
+            ..Config::default()
+        };
+        assert!(try_build_http_client(&config).is_err());
+
+        let config = Config {
+            proxy: Some("http://proxy.example:3128".into()),
+            ..Config::default()
+        };
+        assert!(try_build_http_client(&config).is_ok());
+    }
+
+    #[test]
+    fn missing_or_empty_ca_cert_is_an_error() {
+        let config = Config {
+            extra_ca_cert: Some("/nonexistent/ca.pem".into()),
+            ..Config::default()
+        };
+        assert!(try_build_http_client(&config).is_err());
+
+        let path =
+            std::env::temp_dir().join(format!("hallucinator-empty-ca-{}.pem", std::process::id()));
+        std::fs::write(&path, "").unwrap();
+        let config = Config {
+            extra_ca_cert: Some(path.clone()),
+            ..Config::default()
+        };
+        let result = try_build_http_client(&config);
+        let _ = std::fs::remove_file(&path);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn from_config_shares_handles() {
+        let config = Config::default();
+        let ctx = CheckContext::from_config(&config);
+        assert!(Arc::ptr_eq(&ctx.rate_limiters, &config.rate_limiters));
+        assert!(Arc::ptr_eq(
+            ctx.cache.as_ref().unwrap(),
+            config.query_cache.as_ref().unwrap()
+        ));
+    }
+
+    #[test]
+    fn apply_to_replaces_handles() {
+        let ctx = CheckContext::new(&Config::default());
+        let mut config = Config::default();
+        assert!(!Arc::ptr_eq(&ctx.rate_limiters, &config.rate_limiters));
+
+        ctx.apply_to(&mut config);
+        assert!(Arc::ptr_eq(&ctx.rate_limiters, &config.rate_limiters));
+        assert!(Arc::ptr_eq(
+            ctx.cache.as_ref().unwrap(),
+            config.query_cache.as_ref().unwrap()
+        ));
+    }
+}
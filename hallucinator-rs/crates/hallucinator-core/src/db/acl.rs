@@ -1,5 +1,7 @@
 use super::{DatabaseBackend, DbQueryError, DbQueryResult};
-use crate::matching::titles_match;
+use crate::MatchCandidate;
+use crate::client::ClientProvider;
+use crate::matching::{MIN_TITLE_THRESHOLD, is_title_candidate};
 use crate::rate_limit::check_rate_limit_response;
 use std::future::Future;
 use std::pin::Pin;
@@ -22,10 +24,14 @@ impl DatabaseBackend for AclOffline {
         true
     }
 
+    fn default_title_threshold(&self) -> f64 {
+        hallucinator_acl::DEFAULT_THRESHOLD
+    }
+
     fn query<'a>(
         &'a self,
         title: &'a str,
-        _client: &'a reqwest::Client,
+        _client: &'a dyn ClientProvider,
         _timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         let db = Arc::clone(&self.db);
@@ -33,15 +39,19 @@ impl DatabaseBackend for AclOffline {
         Box::pin(async move {
             let result = tokio::task::spawn_blocking(move || {
                 let db = db.lock().map_err(|e| DbQueryError::Other(e.to_string()))?;
-                db.query(&title)
+                db.query_with_threshold(&title, MIN_TITLE_THRESHOLD)
                     .map_err(|e| DbQueryError::Other(e.to_string()))
             })
             .await
             .map_err(|e| DbQueryError::Other(e.to_string()))??;
 
             match result {
-                Some(qr) => Ok((Some(qr.record.title), qr.record.authors, qr.record.url)),
-                None => Ok((None, vec![], None)),
+                Some(qr) => Ok(DbQueryResult::found(
+                    qr.record.title,
+                    qr.record.authors,
+                    qr.record.url,
+                )),
+                None => Ok(DbQueryResult::not_found()),
             }
         })
     }
@@ -55,7 +65,7 @@ impl DatabaseBackend for AclAnthology {
     fn query<'a>(
         &'a self,
         title: &'a str,
-        client: &'a reqwest::Client,
+        client: &'a dyn ClientProvider,
         timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         Box::pin(async move {
@@ -64,22 +74,14 @@ impl DatabaseBackend for AclAnthology {
                 urlencoding::encode(title)
             );
 
-            let resp = client
-                .get(&url)
-                .timeout(timeout)
-                .send()
-                .await
-                .map_err(|e| DbQueryError::Other(e.to_string()))?;
+            let resp = client.send(client.get(&url).timeout(timeout)).await?;
 
             check_rate_limit_response(&resp)?;
             if !resp.status().is_success() {
-                return Err(DbQueryError::Other(format!("HTTP {}", resp.status())));
+                return Err(DbQueryError::Http(resp.status()));
             }
 
-            let body = resp
-                .text()
-                .await
-                .map_err(|e| DbQueryError::Other(e.to_string()))?;
+            let body = resp.text().await.map_err(DbQueryError::from)?;
             let title_owned = title.to_string();
 
             // Parse in spawn_blocking to avoid !Send scraper types
@@ -98,10 +100,11 @@ fn parse_acl_results(html: &str, title: &str) -> Result<DbQueryResult, DbQueryEr
     let author_sel = scraper::Selector::parse("span.badge.badge-light").unwrap();
     let link_sel = scraper::Selector::parse("a[href*='/papers/']").unwrap();
 
+    let mut candidates = Vec::new();
     for entry in document.select(&entry_sel) {
         if let Some(title_el) = entry.select(&title_sel).next() {
             let found_title: String = title_el.text().collect();
-            if titles_match(title, &found_title) {
+            if is_title_candidate(title, &found_title) {
                 let authors: Vec<String> = entry
                     .select(&author_sel)
                     .map(|a| a.text().collect::<String>().trim().to_string())
@@ -113,10 +116,16 @@ fn parse_acl_results(html: &str, title: &str) -> Result<DbQueryResult, DbQueryEr
                     .and_then(|a| a.value().attr("href"))
                     .map(|href| format!("https://aclanthology.org{}", href));
 
-                return Ok((Some(found_title.trim().to_string()), authors, paper_url));
+                candidates.push(MatchCandidate {
+                    title: found_title.trim().to_string(),
+                    authors,
+                    paper_url,
+                    venue: None,
+                    year: None,
+                });
             }
         }
     }
 
-    Ok((None, vec![], None))
+    Ok(DbQueryResult::best_of(title, candidates))
 }
@@ -1,5 +1,7 @@
 use super::{DatabaseBackend, DbQueryError, DbQueryResult};
-use crate::matching::titles_match;
+use crate::MatchCandidate;
+use crate::client::ClientProvider;
+use crate::matching::is_title_candidate;
 use hallucinator_pdf::identifiers::get_query_words;
 use std::future::Future;
 use std::pin::Pin;
@@ -15,7 +17,7 @@ impl DatabaseBackend for Arxiv {
     fn query<'a>(
         &'a self,
         title: &'a str,
-        client: &'a reqwest::Client,
+        client: &'a dyn ClientProvider,
         timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         Box::pin(async move {
@@ -26,21 +28,13 @@ impl DatabaseBackend for Arxiv {
                 urlencoding::encode(&query)
             );
 
-            let resp = client
-                .get(&url)
-                .timeout(timeout)
-                .send()
-                .await
-                .map_err(|e| DbQueryError::Other(e.to_string()))?;
+            let resp = client.send(client.get(&url).timeout(timeout)).await?;
 
             if !resp.status().is_success() {
-                return Err(DbQueryError::Other(format!("HTTP {}", resp.status())));
+                return Err(DbQueryError::Http(resp.status()));
             }
 
-            let body = resp
-                .text()
-                .await
-                .map_err(|e| DbQueryError::Other(e.to_string()))?;
+            let body = resp.text().await.map_err(DbQueryError::from)?;
 
             // Parse Atom XML feed
             parse_arxiv_response(&body, title)
@@ -66,6 +60,7 @@ fn parse_arxiv_response(xml: &str, title: &str) -> Result<DbQueryResult, DbQuery
     let mut current_link = String::new();
 
     let mut buf = Vec::new();
+    let mut candidates = Vec::new();
 
     loop {
         match reader.read_event_into(&mut buf) {
@@ -101,12 +96,10 @@ fn parse_arxiv_response(xml: &str, title: &str) -> Result<DbQueryResult, DbQuery
                     }
                 }
             }
-            Ok(Event::Empty(ref e)) => {
-                if e.local_name().as_ref() == b"link" && in_entry {
-                    for attr in e.attributes().flatten() {
-                        if attr.key.as_ref() == b"href" && current_link.is_empty() {
-                            current_link = String::from_utf8_lossy(&attr.value).to_string();
-                        }
+            Ok(Event::Empty(ref e)) if e.local_name().as_ref() == b"link" && in_entry => {
+                for attr in e.attributes().flatten() {
+                    if attr.key.as_ref() == b"href" && current_link.is_empty() {
+                        current_link = String::from_utf8_lossy(&attr.value).to_string();
                     }
                 }
             }
@@ -125,13 +118,19 @@ fn parse_arxiv_response(xml: &str, title: &str) -> Result<DbQueryResult, DbQuery
                     b"entry" => {
                         // Check if this entry matches
                         let entry_title = current_title.trim().to_string();
-                        if titles_match(title, &entry_title) {
+                        if is_title_candidate(title, &entry_title) {
                             let link = if current_link.is_empty() {
                                 None
                             } else {
                                 Some(current_link.clone())
                             };
-                            return Ok((Some(entry_title), current_authors.clone(), link));
+                            candidates.push(MatchCandidate {
+                                title: entry_title,
+                                authors: current_authors.clone(),
+                                paper_url: link,
+                                venue: None,
+                                year: None,
+                            });
                         }
                         in_entry = false;
                     }
@@ -153,5 +152,5 @@ fn parse_arxiv_response(xml: &str, title: &str) -> Result<DbQueryResult, DbQuery
         buf.clear();
     }
 
-    Ok((None, vec![], None))
+    Ok(DbQueryResult::best_of(title, candidates))
 }
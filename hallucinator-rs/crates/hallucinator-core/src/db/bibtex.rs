@@ -0,0 +1,184 @@
+//! Local BibTeX library backend.
+//!
+//! Loads a `.bib` file (e.g. a Zotero or JabRef export) once into memory and
+//! answers title queries against it, so a manuscript can be checked against
+//! the author's own curated library without touching the network.
+
+use super::{DatabaseBackend, DbQueryError, DbQueryResult};
+use crate::MatchCandidate;
+use crate::client::ClientProvider;
+use crate::matching::is_title_candidate;
+use hallucinator_bbl::BblError;
+use std::future::Future;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// One library entry with a title.
+#[derive(Debug, Clone)]
+struct LibraryEntry {
+    title: String,
+    authors: Vec<String>,
+    doi: Option<String>,
+}
+
+/// In-memory index of a local `.bib` library.
+pub struct BibTexBackend {
+    entries: Vec<LibraryEntry>,
+}
+
+impl BibTexBackend {
+    /// Parse the `.bib` file at `path`.
+    pub fn open(path: &Path) -> Result<Self, BblError> {
+        let content = std::fs::read_to_string(path)?;
+        Self::from_bib_str(&content)
+    }
+
+    /// Parse `.bib` content. Entries without a title are left out.
+    pub fn from_bib_str(content: &str) -> Result<Self, BblError> {
+        let parsed = hallucinator_bbl::extract_references_from_bib_str(content)?;
+        let entries = parsed
+            .references
+            .into_iter()
+            .filter_map(|r| {
+                Some(LibraryEntry {
+                    title: r.title?,
+                    authors: r.authors,
+                    doi: r.doi,
+                })
+            })
+            .collect();
+        Ok(Self { entries })
+    }
+
+    /// Number of entries in the index.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn lookup(&self, title: &str) -> DbQueryResult {
+        let candidates = self
+            .entries
+            .iter()
+            .filter(|e| is_title_candidate(title, &e.title))
+            .map(|e| MatchCandidate {
+                title: e.title.clone(),
+                authors: e.authors.clone(),
+                paper_url: e.doi.as_ref().map(|d| format!("https://doi.org/{}", d)),
+                venue: None,
+                year: None,
+            })
+            .collect();
+        DbQueryResult::best_of(title, candidates)
+    }
+}
+
+impl DatabaseBackend for BibTexBackend {
+    fn name(&self) -> &str {
+        "BibTeX"
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn query<'a>(
+        &'a self,
+        title: &'a str,
+        _client: &'a dyn ClientProvider,
+        _timeout: Duration,
+    ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
+        Box::pin(async move { Ok(self.lookup(title)) })
+    }
+}
+
+/// The library loaded once into [`Config::local_bib`](crate::Config::local_bib)
+/// and shared by every database list built from it.
+impl DatabaseBackend for Arc<BibTexBackend> {
+    fn name(&self) -> &str {
+        BibTexBackend::name(self)
+    }
+
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn query<'a>(
+        &'a self,
+        title: &'a str,
+        client: &'a dyn ClientProvider,
+        timeout: Duration,
+    ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
+        BibTexBackend::query(self, title, client, timeout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIBRARY: &str = r#"
+@inproceedings{smith2020,
+  title = {Detecting Fabricated Citations in Machine Learning Papers},
+  author = {Smith, Jane and Doe, John},
+  booktitle = {Proceedings of NeurIPS},
+  year = {2020},
+  doi = {10.1000/example.123}
+}
+
+@article{lee2019,
+  title = {A Survey of Reference Validation Techniques for Scholarly Text},
+  author = {Lee, Kim},
+  journal = {Journal of Examples},
+  year = {2019}
+}
+
+@misc{notitle,
+  author = {Nobody, Anne},
+  year = {2021}
+}
+"#;
+
+    #[test]
+    fn indexes_titled_entries() {
+        let backend = BibTexBackend::from_bib_str(LIBRARY).unwrap();
+        assert_eq!(backend.len(), 2);
+    }
+
+    #[test]
+    fn lookup_returns_title_authors_and_doi() {
+        let backend = BibTexBackend::from_bib_str(LIBRARY).unwrap();
+        let result = backend.lookup("Detecting fabricated citations in machine learning papers");
+        assert_eq!(
+            result.found_title.as_deref(),
+            Some("Detecting Fabricated Citations in Machine Learning Papers")
+        );
+        assert_eq!(result.authors.len(), 2);
+        assert_eq!(
+            result.paper_url.as_deref(),
+            Some("https://doi.org/10.1000/example.123")
+        );
+
+        let survey =
+            backend.lookup("A survey of reference validation techniques for scholarly text");
+        assert_eq!(survey.authors.len(), 1);
+        assert!(survey.paper_url.is_none());
+    }
+
+    #[test]
+    fn lookup_misses_unrelated_title() {
+        let backend = BibTexBackend::from_bib_str(LIBRARY).unwrap();
+        let result = backend.lookup("Quantum error correction with surface codes at scale");
+        assert!(result.found_title.is_none());
+    }
+
+    #[test]
+    fn empty_library_is_an_error() {
+        assert!(BibTexBackend::from_bib_str("% nothing here\n").is_err());
+    }
+}
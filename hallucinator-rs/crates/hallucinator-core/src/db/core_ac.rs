@@ -0,0 +1,146 @@
+//! CORE (core.ac.uk) backend.
+//!
+//! CORE aggregates open-access copies from institutional and subject
+//! repositories, so it finds preprints and theses that CrossRef and Semantic
+//! Scholar sometimes miss. The v3 API needs a (free) key.
+
+use super::{DatabaseBackend, DbQueryError, DbQueryResult};
+use crate::MatchCandidate;
+use crate::client::ClientProvider;
+use crate::matching::is_title_candidate;
+use crate::rate_limit::check_rate_limit_response;
+use hallucinator_pdf::identifiers::get_query_words;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+pub struct Core {
+    pub api_key: String,
+}
+
+impl DatabaseBackend for Core {
+    fn name(&self) -> &str {
+        "CORE"
+    }
+
+    fn query<'a>(
+        &'a self,
+        title: &'a str,
+        client: &'a dyn ClientProvider,
+        timeout: Duration,
+    ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let words = get_query_words(title, 6);
+            let query = format!("title:({})", words.join(" "));
+            let url = format!(
+                "https://api.core.ac.uk/v3/search/works?q={}&limit=10",
+                urlencoding::encode(&query)
+            );
+
+            let req = client
+                .get(&url)
+                .timeout(timeout)
+                .header("Authorization", format!("Bearer {}", self.api_key));
+            let resp = client.send(req).await?;
+
+            check_rate_limit_response(&resp)?;
+            if !resp.status().is_success() {
+                return Err(DbQueryError::Http(resp.status()));
+            }
+
+            let data: serde_json::Value = resp.json().await.map_err(DbQueryError::from)?;
+            let results = data["results"].as_array().cloned().unwrap_or_default();
+
+            let candidates = results
+                .iter()
+                .filter_map(|item| parse_work(title, item))
+                .collect();
+            Ok(DbQueryResult::best_of(title, candidates))
+        })
+    }
+}
+
+/// Map a search hit to a candidate if its title matches.
+///
+/// The link prefers the DOI, then the CORE record page, then the full-text
+/// download.
+fn parse_work(title: &str, item: &serde_json::Value) -> Option<MatchCandidate> {
+    let found_title = item["title"].as_str()?.trim();
+    if found_title.is_empty() || !is_title_candidate(title, found_title) {
+        return None;
+    }
+
+    let authors = item["authors"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|a| a["name"].as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let paper_url = item["doi"]
+        .as_str()
+        .filter(|d| !d.is_empty())
+        .map(|d| format!("https://doi.org/{}", d))
+        .or_else(|| {
+            item["id"]
+                .as_u64()
+                .map(|id| format!("https://core.ac.uk/works/{}", id))
+        })
+        .or_else(|| item["downloadUrl"].as_str().map(String::from));
+
+    Some(MatchCandidate {
+        title: found_title.to_string(),
+        authors,
+        paper_url,
+        venue: None,
+        year: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const TITLE: &str = "Detecting fabricated citations in machine learning papers";
+
+    #[test]
+    fn parse_work_prefers_doi_link() {
+        let item = json!({
+            "id": 12345,
+            "title": "Detecting Fabricated Citations in Machine Learning Papers",
+            "authors": [{"name": "Smith, Jane"}, {"name": "Doe, John"}],
+            "doi": "10.1000/example.123",
+            "downloadUrl": "https://core.ac.uk/download/12345.pdf"
+        });
+        let c = parse_work(TITLE, &item).unwrap();
+        assert_eq!(c.authors, vec!["Smith, Jane", "Doe, John"]);
+        assert_eq!(
+            c.paper_url.as_deref(),
+            Some("https://doi.org/10.1000/example.123")
+        );
+    }
+
+    #[test]
+    fn parse_work_falls_back_to_record_page() {
+        let item = json!({
+            "id": 12345,
+            "title": "Detecting fabricated citations in machine learning papers",
+            "authors": [],
+            "doi": null
+        });
+        let c = parse_work(TITLE, &item).unwrap();
+        assert_eq!(
+            c.paper_url.as_deref(),
+            Some("https://core.ac.uk/works/12345")
+        );
+    }
+
+    #[test]
+    fn parse_work_skips_unrelated_title() {
+        let item = json!({"id": 1, "title": "Quantum error correction with surface codes"});
+        assert!(parse_work(TITLE, &item).is_none());
+    }
+}
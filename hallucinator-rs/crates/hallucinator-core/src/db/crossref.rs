@@ -1,5 +1,7 @@
 use super::{DatabaseBackend, DbQueryError, DbQueryResult};
-use crate::matching::titles_match;
+use crate::MatchCandidate;
+use crate::client::ClientProvider;
+use crate::matching::is_title_candidate;
 use crate::rate_limit::check_rate_limit_response;
 use hallucinator_pdf::identifiers::get_query_words;
 use std::future::Future;
@@ -18,7 +20,7 @@ impl DatabaseBackend for CrossRef {
     fn query<'a>(
         &'a self,
         title: &'a str,
-        client: &'a reqwest::Client,
+        client: &'a dyn ClientProvider,
         timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         Box::pin(async move {
@@ -29,35 +31,25 @@ impl DatabaseBackend for CrossRef {
                 urlencoding::encode(&query)
             );
 
-            let user_agent = if let Some(ref email) = self.mailto {
+            // The User-Agent (including any mailto) is set on the shared client.
+            if let Some(ref email) = self.mailto {
                 url.push_str(&format!("&mailto={}", urlencoding::encode(email)));
-                format!("HallucinatedReferenceChecker/1.0 (mailto:{})", email)
-            } else {
-                "Academic Reference Parser".to_string()
-            };
+            }
 
-            let resp = client
-                .get(&url)
-                .header("User-Agent", user_agent)
-                .timeout(timeout)
-                .send()
-                .await
-                .map_err(|e| DbQueryError::Other(e.to_string()))?;
+            let resp = client.send(client.get(&url).timeout(timeout)).await?;
 
             check_rate_limit_response(&resp)?;
             if !resp.status().is_success() {
-                return Err(DbQueryError::Other(format!("HTTP {}", resp.status())));
+                return Err(DbQueryError::Http(resp.status()));
             }
 
-            let data: serde_json::Value = resp
-                .json()
-                .await
-                .map_err(|e| DbQueryError::Other(e.to_string()))?;
+            let data: serde_json::Value = resp.json().await.map_err(DbQueryError::from)?;
             let items = data["message"]["items"]
                 .as_array()
                 .cloned()
                 .unwrap_or_default();
 
+            let mut candidates = Vec::new();
             for item in items {
                 let found_title = item["title"]
                     .as_array()
@@ -65,7 +57,7 @@ impl DatabaseBackend for CrossRef {
                     .and_then(|v| v.as_str())
                     .unwrap_or("");
 
-                if titles_match(title, found_title) {
+                if is_title_candidate(title, found_title) {
                     let authors: Vec<String> = item["author"]
                         .as_array()
                         .map(|arr| {
@@ -82,11 +74,17 @@ impl DatabaseBackend for CrossRef {
                     let doi = item["DOI"].as_str();
                     let paper_url = doi.map(|d| format!("https://doi.org/{}", d));
 
-                    return Ok((Some(found_title.to_string()), authors, paper_url));
+                    candidates.push(MatchCandidate {
+                        title: found_title.to_string(),
+                        authors,
+                        paper_url,
+                        venue: None,
+                        year: None,
+                    });
                 }
             }
 
-            Ok((None, vec![], None))
+            Ok(DbQueryResult::best_of(title, candidates))
         })
     }
 }
@@ -0,0 +1,246 @@
+use super::{DatabaseBackend, DbQueryError, DbQueryResult, DoiQueryResult};
+use crate::MatchCandidate;
+use crate::client::ClientProvider;
+use crate::matching::is_title_candidate;
+use crate::rate_limit::check_rate_limit_response;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+const DOIS_URL: &str = "https://api.datacite.org/dois";
+
+/// DataCite, the registry behind Zenodo, figshare and most other dataset
+/// and software DOIs, which CrossRef does not index.
+pub struct DataCite;
+
+impl DatabaseBackend for DataCite {
+    fn name(&self) -> &str {
+        "DataCite"
+    }
+
+    fn query<'a>(
+        &'a self,
+        title: &'a str,
+        client: &'a dyn ClientProvider,
+        timeout: Duration,
+    ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
+        Box::pin(async move {
+            // Drop Lucene syntax so the title is searched as plain words
+            static SPECIAL: Lazy<Regex> =
+                Lazy::new(|| Regex::new(r#"[+\-!(){}\[\]^"~*?:\\/&|]"#).unwrap());
+            let clean_title = SPECIAL.replace_all(title, " ");
+            static WS: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s+").unwrap());
+            let clean_title = WS.replace_all(clean_title.trim(), " ");
+            let words: String = clean_title.chars().take(200).collect();
+            let query = format!("titles.title:({})", words);
+
+            let request = client
+                .get(DOIS_URL)
+                .query(&[("query", query.as_str()), ("page[size]", "10")])
+                .timeout(timeout);
+            let data = fetch(client, request).await?;
+
+            let mut candidates = Vec::new();
+            for item in data["data"].as_array().into_iter().flatten() {
+                let attributes = &item["attributes"];
+                for found_title in record_titles(attributes) {
+                    if is_title_candidate(title, found_title) {
+                        candidates.push(parse_record(attributes, found_title));
+                        break;
+                    }
+                }
+            }
+
+            Ok(DbQueryResult::best_of(title, candidates))
+        })
+    }
+
+    /// Look a DataCite DOI up directly. DOIs registered elsewhere (CrossRef,
+    /// mEDRA) come back 404, which falls back to the title search.
+    fn query_doi<'a>(
+        &'a self,
+        doi: &'a str,
+        title: &'a str,
+        _authors: &'a [String],
+        client: &'a dyn ClientProvider,
+        timeout: Duration,
+    ) -> DoiQueryResult<'a> {
+        Box::pin(async move {
+            if !crate::doi::looks_valid(doi) {
+                return None;
+            }
+
+            let url = format!(
+                "{}/{}",
+                DOIS_URL,
+                urlencoding::encode(doi).replace("%2F", "/")
+            );
+            let request = client.get(&url).timeout(timeout);
+            let resp = match client.send(request).await {
+                Ok(resp) => resp,
+                Err(e) => return Some(Err(e)),
+            };
+            if resp.status() == reqwest::StatusCode::NOT_FOUND {
+                return None;
+            }
+            let data = match parse_response(resp).await {
+                Ok(data) => data,
+                Err(e) => return Some(Err(e)),
+            };
+
+            let attributes = &data["data"]["attributes"];
+            let found_title = record_titles(attributes)
+                .into_iter()
+                .find(|t| is_title_candidate(title, t))?;
+            Some(Ok(DbQueryResult::from_candidate(
+                parse_record(attributes, found_title),
+                vec![],
+            )))
+        })
+    }
+}
+
+async fn fetch(
+    client: &dyn ClientProvider,
+    request: reqwest::RequestBuilder,
+) -> Result<serde_json::Value, DbQueryError> {
+    let resp = client.send(request).await?;
+    parse_response(resp).await
+}
+
+async fn parse_response(resp: reqwest::Response) -> Result<serde_json::Value, DbQueryError> {
+    check_rate_limit_response(&resp)?;
+    if !resp.status().is_success() {
+        return Err(DbQueryError::Http(resp.status()));
+    }
+    resp.json().await.map_err(DbQueryError::from)
+}
+
+/// The record's titles, main title first. Datasets often carry a subtitle
+/// or translated title that the citation may use instead.
+fn record_titles(attributes: &serde_json::Value) -> Vec<&str> {
+    attributes["titles"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|t| t["title"].as_str())
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+/// Map a DataCite record to a candidate. Personal creators are given as
+/// "Given Family"; organisations keep their name. The venue is the
+/// container (e.g. a Zenodo community or journal) or else the publisher.
+fn parse_record(attributes: &serde_json::Value, found_title: &str) -> MatchCandidate {
+    let authors = attributes["creators"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(
+            |c| match (c["givenName"].as_str(), c["familyName"].as_str()) {
+                (Some(given), Some(family)) => Some(format!("{} {}", given, family)),
+                _ => c["name"].as_str().map(String::from),
+            },
+        )
+        .filter(|a| !a.trim().is_empty())
+        .collect();
+
+    let paper_url = attributes["doi"]
+        .as_str()
+        .map(|doi| format!("https://doi.org/{}", doi))
+        .or_else(|| attributes["url"].as_str().map(String::from));
+
+    let publisher = match &attributes["publisher"] {
+        serde_json::Value::String(name) => Some(name.as_str()),
+        // Newer API versions return `{"name": ...}` objects
+        other => other["name"].as_str(),
+    };
+    let venue = attributes["container"]["title"]
+        .as_str()
+        .or(publisher)
+        .filter(|v| !v.is_empty())
+        .map(String::from);
+
+    let year = match &attributes["publicationYear"] {
+        serde_json::Value::Number(n) => n.as_u64().map(|y| y as u32),
+        serde_json::Value::String(s) => s.parse().ok(),
+        _ => None,
+    };
+
+    MatchCandidate {
+        title: found_title.to_string(),
+        authors,
+        paper_url,
+        venue,
+        year,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parse_record_reads_creators_doi_and_publisher() {
+        let attributes = json!({
+            "doi": "10.5281/zenodo.123",
+            "url": "https://zenodo.org/records/123",
+            "titles": [{"title": "Benchmark Dataset for Reference Checking"}],
+            "creators": [
+                {"name": "Smith, Jane", "givenName": "Jane", "familyName": "Smith"},
+                {"name": "The Hallucinator Project", "nameType": "Organizational"}
+            ],
+            "publisher": "Zenodo",
+            "publicationYear": 2023
+        });
+        let candidate = parse_record(&attributes, "Benchmark Dataset for Reference Checking");
+        assert_eq!(
+            candidate.authors,
+            vec!["Jane Smith", "The Hallucinator Project"]
+        );
+        assert_eq!(
+            candidate.paper_url.as_deref(),
+            Some("https://doi.org/10.5281/zenodo.123")
+        );
+        assert_eq!(candidate.venue.as_deref(), Some("Zenodo"));
+        assert_eq!(candidate.year, Some(2023));
+    }
+
+    #[test]
+    fn parse_record_prefers_container_and_accepts_publisher_objects() {
+        let attributes = json!({
+            "url": "https://example.org/data",
+            "container": {"title": "Scientific Data"},
+            "publisher": {"name": "figshare"},
+            "publicationYear": "2021"
+        });
+        let candidate = parse_record(&attributes, "Some Data");
+        assert_eq!(
+            candidate.paper_url.as_deref(),
+            Some("https://example.org/data")
+        );
+        assert_eq!(candidate.venue.as_deref(), Some("Scientific Data"));
+        assert_eq!(candidate.year, Some(2021));
+
+        let attributes = json!({"publisher": {"name": "figshare"}});
+        assert_eq!(
+            parse_record(&attributes, "Some Data").venue.as_deref(),
+            Some("figshare")
+        );
+    }
+
+    #[test]
+    fn record_titles_lists_every_title() {
+        let attributes = json!({
+            "titles": [
+                {"title": "Main Title"},
+                {"title": "A Subtitle", "titleType": "Subtitle"},
+                {"title": ""}
+            ]
+        });
+        assert_eq!(record_titles(&attributes), vec!["Main Title", "A Subtitle"]);
+    }
+}
@@ -1,5 +1,7 @@
 use super::{DatabaseBackend, DbQueryError, DbQueryResult};
-use crate::matching::titles_match;
+use crate::MatchCandidate;
+use crate::client::ClientProvider;
+use crate::matching::{MIN_TITLE_THRESHOLD, is_title_candidate};
 use crate::rate_limit::check_rate_limit_response;
 use hallucinator_pdf::identifiers::get_query_words;
 use std::future::Future;
@@ -23,10 +25,14 @@ impl DatabaseBackend for DblpOffline {
         true
     }
 
+    fn default_title_threshold(&self) -> f64 {
+        hallucinator_dblp::DEFAULT_THRESHOLD
+    }
+
     fn query<'a>(
         &'a self,
         title: &'a str,
-        _client: &'a reqwest::Client,
+        _client: &'a dyn ClientProvider,
         _timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         let db = Arc::clone(&self.db);
@@ -34,15 +40,19 @@ impl DatabaseBackend for DblpOffline {
         Box::pin(async move {
             let result = tokio::task::spawn_blocking(move || {
                 let db = db.lock().map_err(|e| DbQueryError::Other(e.to_string()))?;
-                db.query(&title)
+                db.query_with_threshold(&title, MIN_TITLE_THRESHOLD)
                     .map_err(|e| DbQueryError::Other(e.to_string()))
             })
             .await
             .map_err(|e| DbQueryError::Other(e.to_string()))??;
 
             match result {
-                Some(qr) => Ok((Some(qr.record.title), qr.record.authors, qr.record.url)),
-                None => Ok((None, vec![], None)),
+                Some(qr) => Ok(DbQueryResult::found(
+                    qr.record.title,
+                    qr.record.authors,
+                    qr.record.url,
+                )),
+                None => Ok(DbQueryResult::not_found()),
             }
         })
     }
@@ -56,7 +66,7 @@ impl DatabaseBackend for DblpOnline {
     fn query<'a>(
         &'a self,
         title: &'a str,
-        client: &'a reqwest::Client,
+        client: &'a dyn ClientProvider,
         timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         Box::pin(async move {
@@ -67,32 +77,25 @@ impl DatabaseBackend for DblpOnline {
                 urlencoding::encode(&query)
             );
 
-            let resp = client
-                .get(&url)
-                .timeout(timeout)
-                .send()
-                .await
-                .map_err(|e| DbQueryError::Other(e.to_string()))?;
+            let resp = client.send(client.get(&url).timeout(timeout)).await?;
 
             check_rate_limit_response(&resp)?;
             if !resp.status().is_success() {
-                return Err(DbQueryError::Other(format!("HTTP {}", resp.status())));
+                return Err(DbQueryError::Http(resp.status()));
             }
 
-            let data: serde_json::Value = resp
-                .json()
-                .await
-                .map_err(|e| DbQueryError::Other(e.to_string()))?;
+            let data: serde_json::Value = resp.json().await.map_err(DbQueryError::from)?;
             let hits = data["result"]["hits"]["hit"]
                 .as_array()
                 .cloned()
                 .unwrap_or_default();
 
+            let mut candidates = Vec::new();
             for hit in hits {
                 let info = &hit["info"];
                 let found_title = info["title"].as_str().unwrap_or("");
 
-                if titles_match(title, found_title) {
+                if is_title_candidate(title, found_title) {
                     let authors = match &info["authors"]["author"] {
                         serde_json::Value::Array(arr) => arr
                             .iter()
@@ -117,11 +120,17 @@ impl DatabaseBackend for DblpOnline {
 
                     let paper_url = info["url"].as_str().map(String::from);
 
-                    return Ok((Some(found_title.to_string()), authors, paper_url));
+                    candidates.push(MatchCandidate {
+                        title: found_title.to_string(),
+                        authors,
+                        paper_url,
+                        venue: None,
+                        year: None,
+                    });
                 }
             }
 
-            Ok((None, vec![], None))
+            Ok(DbQueryResult::best_of(title, candidates))
         })
     }
 }
@@ -1,11 +1,16 @@
 //! DOI resolver backend — validates references by looking up their DOI at doi.org.
 
+use crate::client::ClientProvider;
 use std::future::Future;
 use std::pin::Pin;
 use std::time::Duration;
 
-use crate::doi::{DoiMatchResult, check_doi_match, validate_doi};
-use crate::rate_limit::DbQueryError;
+use crate::DoiInfo;
+use crate::authors::AuthorMatchPolicy;
+use crate::doi::{
+    DoiMatchResult, DoiValidation, check_doi_match, doi_title_mismatch, validate_doi,
+};
+use crate::rate_limit::{DbQueryError, RateLimiters, query_with_retry};
 
 use super::{DbQueryResult, DoiQueryResult};
 
@@ -21,14 +26,20 @@ impl super::DatabaseBackend for DoiResolver {
         true
     }
 
+    /// The DOI is the evidence here, and [`check_doi_match`] already applies
+    /// its own title rules, so no further threshold by default.
+    fn default_title_threshold(&self) -> f64 {
+        0.0
+    }
+
     /// Title-based search is not supported — always returns not-found.
     fn query<'a>(
         &'a self,
         _title: &'a str,
-        _client: &'a reqwest::Client,
+        _client: &'a dyn ClientProvider,
         _timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
-        Box::pin(async { Ok((None, vec![], None)) })
+        Box::pin(async { Ok(DbQueryResult::not_found()) })
     }
 
     fn query_doi<'a>(
@@ -36,18 +47,21 @@ impl super::DatabaseBackend for DoiResolver {
         doi: &'a str,
         title: &'a str,
         authors: &'a [String],
-        client: &'a reqwest::Client,
+        client: &'a dyn ClientProvider,
         timeout: Duration,
     ) -> DoiQueryResult<'a> {
         Box::pin(async move {
             let doi_result = validate_doi(doi, client, timeout).await;
-            let match_result = check_doi_match(&doi_result, title, authors);
+            // Both author outcomes return the record; the caller applies its
+            // own author policy.
+            let match_result =
+                check_doi_match(&doi_result, title, authors, AuthorMatchPolicy::default());
 
             match match_result {
                 DoiMatchResult::Verified { doi_authors, .. } => {
                     let url = format!("https://doi.org/{}", doi);
-                    Some(Ok((
-                        doi_result.title.or_else(|| Some(title.to_string())),
+                    Some(Ok(DbQueryResult::found(
+                        doi_result.title.unwrap_or_else(|| title.to_string()),
                         doi_authors,
                         Some(url),
                     )))
@@ -56,17 +70,116 @@ impl super::DatabaseBackend for DoiResolver {
                     // Return found title + authors — report_result will handle
                     // the author mismatch classification.
                     let url = format!("https://doi.org/{}", doi);
-                    Some(Ok((
-                        doi_result.title.or_else(|| Some(title.to_string())),
+                    Some(Ok(DbQueryResult::found(
+                        doi_result.title.unwrap_or_else(|| title.to_string()),
                         doi_authors,
                         Some(url),
                     )))
                 }
-                DoiMatchResult::TitleMismatch { .. } | DoiMatchResult::Invalid { .. } => {
-                    // DOI didn't match or was invalid — return not-found
-                    Some(Ok((None, vec![], None)))
+                DoiMatchResult::TitleMismatch { doi_title, .. } => {
+                    // The DOI resolved to another paper — not found, but keep
+                    // what it resolved to for the reference's DOI info
+                    Some(Ok(DbQueryResult::rejected(doi_title)))
+                }
+                DoiMatchResult::Invalid { .. } => Some(Ok(DbQueryResult::not_found())),
+            }
+        })
+    }
+}
+
+/// Resolves a DOI with no citation to check it against: the query "title" is
+/// the DOI itself. Named "DOI" so lookups share [`DoiResolver`]'s rate limiter.
+struct DoiLookup;
+
+impl super::DatabaseBackend for DoiLookup {
+    fn name(&self) -> &str {
+        "DOI"
+    }
+
+    fn query<'a>(
+        &'a self,
+        doi: &'a str,
+        client: &'a dyn ClientProvider,
+        timeout: Duration,
+    ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
+        Box::pin(async move {
+            let doi_result = validate_doi(doi, client, timeout).await;
+            if doi_result.valid {
+                Ok(DbQueryResult::found(
+                    doi_result.title.unwrap_or_default(),
+                    doi_result.authors,
+                    Some(format!("https://doi.org/{}", doi)),
+                ))
+            } else {
+                match doi_result.failure {
+                    // A timeout or network failure says nothing about the DOI.
+                    Some(e) => Err(e),
+                    None => Ok(DbQueryResult::not_found()),
                 }
             }
         })
     }
 }
+
+/// Resolve a reference's DOI at doi.org independently of its title search,
+/// for when the DOI backend did not settle it — another database verified the
+/// reference first, or the resolved record did not match.
+///
+/// Runs through [`query_with_retry`], so it waits on the same "DOI" rate
+/// limiter and timeout as the backend.
+pub(crate) async fn resolve_doi_info(
+    doi: &str,
+    title: &str,
+    client: &reqwest::Client,
+    timeout: Duration,
+    rate_limiters: &RateLimiters,
+) -> DoiInfo {
+    let lookup = query_with_retry(
+        &DoiLookup,
+        doi,
+        client,
+        timeout,
+        rate_limiters,
+        0,
+        None,
+        None,
+    )
+    .await;
+    let resolution = match lookup.result {
+        Ok(DbQueryResult {
+            found_title: Some(found_title),
+            authors,
+            ..
+        }) => DoiValidation {
+            valid: true,
+            title: Some(found_title).filter(|t| !t.is_empty()),
+            authors,
+            error: None,
+            failure: None,
+        },
+        Ok(_) => DoiValidation {
+            valid: false,
+            title: None,
+            authors: vec![],
+            error: Some("DOI not found".into()),
+            failure: None,
+        },
+        Err(e) => {
+            log::debug!("DOI: could not resolve {}: {}", doi, e);
+            DoiValidation {
+                valid: false,
+                title: None,
+                authors: vec![],
+                error: Some(e.to_string()),
+                failure: Some(e),
+            }
+        }
+    };
+    DoiInfo {
+        doi: doi.to_string(),
+        valid: resolution.valid,
+        title: resolution.title.clone(),
+        structurally_valid: true,
+        title_mismatch: doi_title_mismatch(&resolution, title),
+    }
+}
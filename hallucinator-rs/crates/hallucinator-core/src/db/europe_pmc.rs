@@ -1,5 +1,7 @@
-use super::{DatabaseBackend, DbQueryError, DbQueryResult};
-use crate::matching::titles_match;
+use super::{DatabaseBackend, DbQueryError, DbQueryResult, DoiQueryResult};
+use crate::MatchCandidate;
+use crate::client::ClientProvider;
+use crate::matching::is_title_candidate;
 use crate::rate_limit::check_rate_limit_response;
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -7,6 +9,8 @@ use std::future::Future;
 use std::pin::Pin;
 use std::time::Duration;
 
+const SEARCH_URL: &str = "https://www.ebi.ac.uk/europepmc/webservices/rest/search";
+
 pub struct EuropePmc;
 
 impl DatabaseBackend for EuropePmc {
@@ -17,7 +21,7 @@ impl DatabaseBackend for EuropePmc {
     fn query<'a>(
         &'a self,
         title: &'a str,
-        client: &'a reqwest::Client,
+        client: &'a dyn ClientProvider,
         timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         Box::pin(async move {
@@ -28,64 +32,149 @@ impl DatabaseBackend for EuropePmc {
             let clean_title = WS.replace_all(&clean_title, " ");
             let query: String = clean_title.chars().take(100).collect();
 
-            let url = "https://www.ebi.ac.uk/europepmc/webservices/rest/search";
-
-            let resp = client
-                .get(url)
-                .query(&[
-                    ("query", query.as_str()),
-                    ("format", "json"),
-                    ("pageSize", "15"),
-                ])
-                .header("User-Agent", "Academic Reference Parser")
-                .timeout(timeout)
-                .send()
-                .await
-                .map_err(|e| DbQueryError::Other(e.to_string()))?;
-
-            check_rate_limit_response(&resp)?;
-            if !resp.status().is_success() {
-                return Err(DbQueryError::Other(format!("HTTP {}", resp.status())));
-            }
-
-            let data: serde_json::Value = resp
-                .json()
-                .await
-                .map_err(|e| DbQueryError::Other(e.to_string()))?;
-            let results = data["resultList"]["result"]
-                .as_array()
-                .cloned()
-                .unwrap_or_default();
+            let results = search(&query, "15", client, timeout).await?;
 
+            let mut candidates = Vec::new();
             for item in results {
                 let found_title = item["title"].as_str().unwrap_or("");
-                if !found_title.is_empty() && titles_match(title, found_title) {
-                    let author_string = item["authorString"].as_str().unwrap_or("");
-                    let authors: Vec<String> = if author_string.is_empty() {
-                        vec![]
-                    } else {
-                        author_string
-                            .split(',')
-                            .map(|a| a.trim().to_string())
-                            .filter(|a| !a.is_empty())
-                            .collect()
-                    };
-
-                    let paper_url = if let Some(doi) = item["doi"].as_str() {
-                        Some(format!("https://doi.org/{}", doi))
-                    } else if let Some(pmcid) = item["pmcid"].as_str() {
-                        Some(format!("https://europepmc.org/article/PMC/{}", pmcid))
-                    } else {
-                        item["pmid"]
-                            .as_str()
-                            .map(|pmid| format!("https://europepmc.org/article/MED/{}", pmid))
-                    };
-
-                    return Ok((Some(found_title.to_string()), authors, paper_url));
+                if !found_title.is_empty() && is_title_candidate(title, found_title) {
+                    candidates.push(parse_hit(&item, found_title));
                 }
             }
 
-            Ok((None, vec![], None))
+            Ok(DbQueryResult::best_of(title, candidates))
+        })
+    }
+
+    /// Look the reference up by DOI first; an identifier hit is more reliable
+    /// than a title search for biomedical papers with generic titles.
+    ///
+    /// Falls back to the title search (by returning `None`) when the DOI is
+    /// malformed, unknown to Europe PMC, or belongs to a differently titled
+    /// record.
+    fn query_doi<'a>(
+        &'a self,
+        doi: &'a str,
+        title: &'a str,
+        _authors: &'a [String],
+        client: &'a dyn ClientProvider,
+        timeout: Duration,
+    ) -> DoiQueryResult<'a> {
+        Box::pin(async move {
+            if !crate::doi::looks_valid(doi) {
+                return None;
+            }
+
+            let query = format!("DOI:\"{}\"", doi);
+            let results = match search(&query, "1", client, timeout).await {
+                Ok(results) => results,
+                Err(e) => return Some(Err(e)),
+            };
+
+            let item = results.first()?;
+            let found_title = item["title"].as_str().unwrap_or("");
+            if found_title.is_empty() || !is_title_candidate(title, found_title) {
+                return None;
+            }
+
+            Some(Ok(DbQueryResult::from_candidate(
+                parse_hit(item, found_title),
+                vec![],
+            )))
         })
     }
 }
+
+/// Run a Europe PMC search and return the raw result list.
+async fn search(
+    query: &str,
+    page_size: &str,
+    client: &dyn ClientProvider,
+    timeout: Duration,
+) -> Result<Vec<serde_json::Value>, DbQueryError> {
+    let request = client
+        .get(SEARCH_URL)
+        .query(&[
+            ("query", query),
+            ("format", "json"),
+            ("pageSize", page_size),
+        ])
+        .timeout(timeout);
+    let resp = client.send(request).await?;
+
+    check_rate_limit_response(&resp)?;
+    if !resp.status().is_success() {
+        return Err(DbQueryError::Http(resp.status()));
+    }
+
+    let data: serde_json::Value = resp.json().await.map_err(DbQueryError::from)?;
+    Ok(data["resultList"]["result"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default())
+}
+
+/// Map a search hit to a candidate, linking the most stable identifier it has
+/// (DOI, then PMCID, then PMID) and keeping its journal and year.
+fn parse_hit(item: &serde_json::Value, found_title: &str) -> MatchCandidate {
+    let author_string = item["authorString"].as_str().unwrap_or("");
+    let authors: Vec<String> = author_string
+        .split(',')
+        .map(|a| a.trim().trim_end_matches('.').to_string())
+        .filter(|a| !a.is_empty())
+        .collect();
+
+    let paper_url = if let Some(doi) = item["doi"].as_str() {
+        Some(format!("https://doi.org/{}", doi))
+    } else if let Some(pmcid) = item["pmcid"].as_str() {
+        Some(format!("https://europepmc.org/article/PMC/{}", pmcid))
+    } else {
+        item["pmid"]
+            .as_str()
+            .map(|pmid| format!("https://europepmc.org/article/MED/{}", pmid))
+    };
+
+    MatchCandidate {
+        title: found_title.to_string(),
+        authors,
+        paper_url,
+        venue: item["journalTitle"]
+            .as_str()
+            .filter(|j| !j.is_empty())
+            .map(String::from),
+        year: item["pubYear"].as_str().and_then(|y| y.parse().ok()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parse_hit_keeps_journal_and_year() {
+        let item = json!({
+            "title": "Highly accurate protein structure prediction with AlphaFold.",
+            "authorString": "Jumper J, Evans R, Pritzel A.",
+            "journalTitle": "Nature",
+            "pubYear": "2021",
+            "doi": "10.1038/s41586-021-03819-2"
+        });
+        let c = parse_hit(&item, item["title"].as_str().unwrap());
+        assert_eq!(c.authors, vec!["Jumper J", "Evans R", "Pritzel A"]);
+        assert_eq!(c.venue.as_deref(), Some("Nature"));
+        assert_eq!(c.year, Some(2021));
+    }
+
+    #[test]
+    fn parse_hit_without_journal_or_year() {
+        let item = json!({
+            "title": "A preprint",
+            "authorString": "",
+            "pmid": "123"
+        });
+        let c = parse_hit(&item, "A preprint");
+        assert_eq!(c.venue, None);
+        assert_eq!(c.year, None);
+    }
+}
@@ -1,5 +1,6 @@
 //! Mock database backend for testing.
 
+use crate::client::ClientProvider;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Mutex;
@@ -7,6 +8,7 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
 use super::{DatabaseBackend, DbQueryResult};
+use crate::MatchCandidate;
 use crate::rate_limit::DbQueryError;
 
 /// A configurable mock response for [`MockDb`].
@@ -19,6 +21,8 @@ pub enum MockResponse {
         authors: Vec<String>,
         url: Option<String>,
     },
+    /// Simulate a search returning several title-matching records.
+    Candidates(Vec<MatchCandidate>),
     /// Simulate "not found in this database".
     NotFound,
     /// Simulate a 429 rate-limit response.
@@ -32,7 +36,8 @@ pub enum MockResponse {
 /// Supports:
 /// - A fixed response (used for every call), **or**
 /// - A sequence of responses (one per call, cycling the last if exhausted).
-/// - Optional per-call latency.
+/// - Optional per-call latency, which fails the call as a timeout when it
+///   exceeds the query's timeout.
 /// - Call counting via [`call_count()`](MockDb::call_count).
 pub struct MockDb {
     name: &'static str,
@@ -104,9 +109,9 @@ impl DatabaseBackend for MockDb {
 
     fn query<'a>(
         &'a self,
-        _title: &'a str,
-        _client: &'a reqwest::Client,
-        _timeout: Duration,
+        title: &'a str,
+        _client: &'a dyn ClientProvider,
+        timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         self.call_count.fetch_add(1, Ordering::SeqCst);
         let response = self.next_response();
@@ -114,6 +119,10 @@ impl DatabaseBackend for MockDb {
 
         Box::pin(async move {
             if let Some(d) = delay {
+                if d > timeout {
+                    tokio::time::sleep(timeout).await;
+                    return Err(DbQueryError::Timeout);
+                }
                 tokio::time::sleep(d).await;
             }
 
@@ -122,8 +131,11 @@ impl DatabaseBackend for MockDb {
                     title,
                     authors,
                     url,
-                } => Ok((Some(title), authors, url)),
-                MockResponse::NotFound => Ok((None, vec![], None)),
+                } => Ok(DbQueryResult::found(title, authors, url)),
+                MockResponse::Candidates(candidates) => {
+                    Ok(DbQueryResult::best_of(title, candidates))
+                }
+                MockResponse::NotFound => Ok(DbQueryResult::not_found()),
                 MockResponse::RateLimited { retry_after } => {
                     Err(DbQueryError::RateLimited { retry_after })
                 }
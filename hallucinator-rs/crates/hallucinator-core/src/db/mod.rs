@@ -2,7 +2,10 @@
 
 pub mod acl;
 pub mod arxiv;
+pub mod bibtex;
+pub mod core_ac;
 pub mod crossref;
+pub mod datacite;
 pub mod dblp;
 pub mod doi_resolver;
 pub mod europe_pmc;
@@ -11,18 +14,234 @@ pub mod openalex;
 pub mod pubmed;
 pub mod semantic_scholar;
 pub mod ssrn;
+pub mod unpaywall;
 
 #[cfg(test)]
 pub(crate) mod mock;
 
+use crate::client::ClientProvider;
 use std::future::Future;
 use std::pin::Pin;
 
+use crate::MatchCandidate;
+use crate::authors::AuthorMatchPolicy;
+use crate::matching::{
+    AMBIGUITY_EPSILON, DEFAULT_TITLE_THRESHOLD, normalize_title, title_similarity, titles_match,
+    titles_match_at,
+};
+
 pub use crate::rate_limit::DbQueryError;
 
-/// Result of a database query: (found_title, authors, paper_url).
-/// `None` title means not found.
-pub type DbQueryResult = (Option<String>, Vec<String>, Option<String>);
+/// Names of the backends [`Config`](crate::Config) can enable, as used in
+/// `disabled_dbs` and `only_dbs`.
+pub const DATABASE_NAMES: &[&str] = &[
+    "BibTeX",
+    "CrossRef",
+    "arXiv",
+    "DBLP",
+    "Semantic Scholar",
+    "ACL Anthology",
+    "Europe PMC",
+    "PubMed",
+    "DataCite",
+    "DOI",
+    "Unpaywall",
+    "CORE",
+    "OpenAlex",
+];
+
+/// Whether `name` is one of [`DATABASE_NAMES`], ignoring case.
+pub fn is_known_database(name: &str) -> bool {
+    DATABASE_NAMES.iter().any(|n| n.eq_ignore_ascii_case(name))
+}
+
+/// Result of a database query.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DbQueryResult {
+    /// Title of the best match. `None` means not found.
+    pub found_title: Option<String>,
+    pub authors: Vec<String>,
+    pub paper_url: Option<String>,
+    /// Journal or venue of the best match, when the backend reports one.
+    pub venue: Option<String>,
+    /// Publication year of the best match, when the backend reports one.
+    pub year: Option<u32>,
+    /// Distinct records whose titles matched about as well as the best one.
+    pub alternates: Vec<MatchCandidate>,
+    /// With no match: the title of a record the backend found but turned
+    /// down, such as the paper a cited DOI resolves to when its title is a
+    /// different one.
+    pub rejected_title: Option<String>,
+}
+
+impl DbQueryResult {
+    /// A single unambiguous match.
+    pub fn found(
+        title: impl Into<String>,
+        authors: Vec<String>,
+        paper_url: Option<String>,
+    ) -> Self {
+        Self {
+            found_title: Some(title.into()),
+            authors,
+            paper_url,
+            venue: None,
+            year: None,
+            alternates: vec![],
+            rejected_title: None,
+        }
+    }
+
+    /// `candidate` as the match, with `alternates` beside it.
+    pub fn from_candidate(candidate: MatchCandidate, alternates: Vec<MatchCandidate>) -> Self {
+        Self {
+            found_title: Some(candidate.title),
+            authors: candidate.authors,
+            paper_url: candidate.paper_url,
+            venue: candidate.venue,
+            year: candidate.year,
+            alternates,
+            rejected_title: None,
+        }
+    }
+
+    /// No matching record.
+    pub fn not_found() -> Self {
+        Self::default()
+    }
+
+    /// No match, although the backend found a record titled `title`.
+    pub fn rejected(title: impl Into<String>) -> Self {
+        Self {
+            rejected_title: Some(title.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Pick the best of several title-matching records returned by a search.
+    ///
+    /// Candidates that clear the default title threshold rank first, then by
+    /// [`title_similarity`] against `query_title` (ties keep the backend's
+    /// order). Any other candidate scoring within [`AMBIGUITY_EPSILON`] of the
+    /// best is kept as an alternate, unless it is a duplicate of a record
+    /// already kept.
+    pub fn best_of(query_title: &str, candidates: Vec<MatchCandidate>) -> Self {
+        let mut scored: Vec<(bool, f64, MatchCandidate)> = candidates
+            .into_iter()
+            .map(|c| {
+                (
+                    titles_match(query_title, &c.title),
+                    title_similarity(query_title, &c.title),
+                    c,
+                )
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.total_cmp(&a.1)));
+
+        let mut scored = scored.into_iter();
+        let Some((_, best_score, best)) = scored.next() else {
+            return Self::not_found();
+        };
+
+        let mut kept = vec![best];
+        for (_, score, candidate) in scored {
+            if best_score - score > AMBIGUITY_EPSILON {
+                break;
+            }
+            if !kept.iter().any(|k| same_record(k, &candidate)) {
+                kept.push(candidate);
+            }
+        }
+
+        let best = kept.remove(0);
+        Self::from_candidate(best, kept)
+    }
+
+    /// Keep only matches whose title clears `threshold`, promoting the best
+    /// surviving alternate if the top match falls below it. A threshold of
+    /// zero keeps everything.
+    pub fn at_threshold(self, query_title: &str, threshold: f64) -> Self {
+        if threshold <= 0.0 {
+            return self;
+        }
+        self.retain_titles(|t| titles_match_at(query_title, t, threshold))
+    }
+
+    /// Keep only matches whose [`title_similarity`] to `query_title` is at
+    /// least `min_score`, like [`at_threshold`](Self::at_threshold) but
+    /// without its allowance for titles cut short before a subtitle.
+    pub fn at_min_score(self, query_title: &str, min_score: f64) -> Self {
+        if min_score <= 0.0 {
+            return self;
+        }
+        self.retain_titles(|t| title_similarity(query_title, t) >= min_score)
+    }
+
+    /// Drop the matches whose titles fail `passes`, promoting the first
+    /// surviving alternate if the top match is dropped.
+    fn retain_titles(mut self, passes: impl Fn(&str) -> bool) -> Self {
+        let Some(found) = self.found_title.take() else {
+            return self;
+        };
+        self.alternates.retain(|alt| passes(&alt.title));
+        if passes(&found) {
+            self.found_title = Some(found);
+            return self;
+        }
+        if self.alternates.is_empty() {
+            return Self::not_found();
+        }
+        let promoted = self.alternates.remove(0);
+        Self::from_candidate(promoted, self.alternates)
+    }
+
+    /// When the best match's authors disagree with `ref_authors` but an
+    /// alternate's agree, promote that alternate.
+    ///
+    /// Between equally good title matches, the one the reference's authors
+    /// point to is the right one; this keeps a near-tie from turning into a
+    /// spurious author mismatch. The demoted record stays in `alternates`.
+    pub fn prefer_authors(mut self, ref_authors: &[String], policy: AuthorMatchPolicy) -> Self {
+        if ref_authors.is_empty()
+            || self.found_title.is_none()
+            || policy.accepts(ref_authors, &self.authors)
+        {
+            return self;
+        }
+        let Some(pos) = self
+            .alternates
+            .iter()
+            .position(|alt| policy.accepts(ref_authors, &alt.authors))
+        else {
+            return self;
+        };
+
+        let promoted = self.alternates.remove(pos);
+        let demoted = MatchCandidate {
+            title: self.found_title.take().unwrap_or_default(),
+            authors: std::mem::take(&mut self.authors),
+            paper_url: self.paper_url.take(),
+            venue: self.venue.take(),
+            year: self.year.take(),
+        };
+        self.alternates.insert(0, demoted);
+        self.found_title = Some(promoted.title);
+        self.authors = promoted.authors;
+        self.paper_url = promoted.paper_url;
+        self.venue = promoted.venue;
+        self.year = promoted.year;
+        self
+    }
+}
+
+/// Whether two candidates are the same record listed twice (e.g. a preprint
+/// and its published version with identical metadata).
+fn same_record(a: &MatchCandidate, b: &MatchCandidate) -> bool {
+    let authors = |c: &MatchCandidate| -> Vec<String> {
+        c.authors.iter().map(|n| n.trim().to_lowercase()).collect()
+    };
+    normalize_title(&a.title) == normalize_title(&b.title) && authors(a) == authors(b)
+}
 
 /// Result type for `query_doi`: `None` means the backend doesn't handle DOI queries.
 pub type DoiQueryResult<'a> =
@@ -38,6 +257,12 @@ pub trait DatabaseBackend: Send + Sync {
         false
     }
 
+    /// Title similarity this backend's matches must reach when the config
+    /// sets no override for it.
+    fn default_title_threshold(&self) -> f64 {
+        DEFAULT_TITLE_THRESHOLD
+    }
+
     /// Whether this backend requires a DOI instead of a title search.
     /// When true, the drainer skips refs without a DOI and uses `query_doi` instead of `query`.
     fn requires_doi(&self) -> bool {
@@ -48,22 +273,166 @@ pub trait DatabaseBackend: Send + Sync {
     fn query<'a>(
         &'a self,
         title: &'a str,
-        client: &'a reqwest::Client,
+        client: &'a dyn ClientProvider,
         timeout: std::time::Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>>;
 
     /// Query the database using a DOI.
     ///
-    /// Returns `None` if this backend doesn't support DOI queries (default).
+    /// Returns `None` if this backend doesn't support DOI queries (default), or
+    /// couldn't resolve this DOI; the caller then falls back to [`query`](Self::query).
     /// Returns `Some(result)` when the backend handled the query via DOI.
     fn query_doi<'a>(
         &'a self,
         _doi: &'a str,
         _title: &'a str,
         _authors: &'a [String],
-        _client: &'a reqwest::Client,
+        _client: &'a dyn ClientProvider,
         _timeout: std::time::Duration,
     ) -> DoiQueryResult<'a> {
         Box::pin(async { None })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(title: &str, authors: &[&str]) -> MatchCandidate {
+        MatchCandidate {
+            title: title.to_string(),
+            authors: authors.iter().map(|a| a.to_string()).collect(),
+            paper_url: None,
+            venue: None,
+            year: None,
+        }
+    }
+
+    #[test]
+    fn best_of_empty_is_not_found() {
+        assert_eq!(
+            DbQueryResult::best_of("Anything", vec![]),
+            DbQueryResult::not_found()
+        );
+    }
+
+    #[test]
+    fn best_of_keeps_close_candidates_as_alternates() {
+        let result = DbQueryResult::best_of(
+            "Deep Learning for Graphs",
+            vec![
+                candidate("Deep Learning for Graphs", &["Alice Smith"]),
+                candidate("Deep Learning for Graphs.", &["Bob Jones"]),
+                candidate("Deep Learning for Graph Data", &["Carol White"]),
+            ],
+        );
+        assert_eq!(
+            result.found_title.as_deref(),
+            Some("Deep Learning for Graphs")
+        );
+        assert_eq!(result.alternates.len(), 1);
+        assert_eq!(result.alternates[0].authors, vec!["Bob Jones"]);
+    }
+
+    #[test]
+    fn best_of_drops_duplicate_records() {
+        let result = DbQueryResult::best_of(
+            "Deep Learning for Graphs",
+            vec![
+                candidate("Deep Learning for Graphs", &["Alice Smith"]),
+                candidate("Deep learning for graphs", &["alice smith"]),
+            ],
+        );
+        assert!(result.alternates.is_empty());
+    }
+
+    #[test]
+    fn prefer_authors_resolves_near_tie() {
+        // Two real papers with the same title; the backend happened to rank
+        // the one the reference does not cite first.
+        let result = DbQueryResult::best_of(
+            "Attention Is All You Need",
+            vec![
+                candidate("Attention Is All You Need", &["Someone Else"]),
+                candidate(
+                    "Attention is all you need",
+                    &["Ashish Vaswani", "Noam Shazeer"],
+                ),
+            ],
+        );
+        assert_eq!(result.authors, vec!["Someone Else"]);
+
+        let resolved =
+            result.prefer_authors(&["A. Vaswani".to_string()], AuthorMatchPolicy::default());
+        assert_eq!(resolved.authors, vec!["Ashish Vaswani", "Noam Shazeer"]);
+        assert_eq!(resolved.alternates.len(), 1);
+        assert_eq!(resolved.alternates[0].authors, vec!["Someone Else"]);
+    }
+
+    #[test]
+    fn prefer_authors_keeps_best_when_it_agrees() {
+        let result = DbQueryResult::best_of(
+            "Attention Is All You Need",
+            vec![
+                candidate("Attention Is All You Need", &["Ashish Vaswani"]),
+                candidate("Attention is all you need", &["Someone Else"]),
+            ],
+        )
+        .prefer_authors(&["A. Vaswani".to_string()], AuthorMatchPolicy::default());
+        assert_eq!(result.authors, vec!["Ashish Vaswani"]);
+        assert_eq!(result.alternates.len(), 1);
+    }
+
+    #[test]
+    fn at_threshold_rejects_match_below_threshold() {
+        let result = DbQueryResult::best_of(
+            "Deep Learning for Graphs",
+            vec![candidate("Deep Learning for Graph Data", &["Alice Smith"])],
+        );
+        assert!(result.found_title.is_some());
+        assert_eq!(
+            result.at_threshold("Deep Learning for Graphs", 0.99),
+            DbQueryResult::not_found()
+        );
+    }
+
+    #[test]
+    fn at_min_score_drops_prefix_match() {
+        let cited = "Won't somebody think of the children? Examining COPPA compliance at scale";
+        let found = "Won't somebody think of the children? Examining COPPA compliance at scale \
+                     in mobile apps for kids";
+        let result = DbQueryResult::found(found, vec![], None);
+        assert!(
+            result
+                .clone()
+                .at_threshold(cited, DEFAULT_TITLE_THRESHOLD)
+                .found_title
+                .is_some()
+        );
+        assert_eq!(
+            result.clone().at_min_score(cited, 0.95),
+            DbQueryResult::not_found()
+        );
+        assert_eq!(result.clone().at_min_score(cited, 0.0), result);
+    }
+
+    #[test]
+    fn at_threshold_promotes_surviving_alternate() {
+        let result = DbQueryResult {
+            found_title: Some("Deep Learning for Graph Data".into()),
+            authors: vec!["Alice Smith".into()],
+            paper_url: None,
+            venue: None,
+            year: None,
+            alternates: vec![candidate("Deep Learning for Graphs", &["Bob Jones"])],
+            rejected_title: None,
+        }
+        .at_threshold("Deep Learning for Graphs", 0.99);
+        assert_eq!(
+            result.found_title.as_deref(),
+            Some("Deep Learning for Graphs")
+        );
+        assert_eq!(result.authors, vec!["Bob Jones"]);
+        assert!(result.alternates.is_empty());
+    }
+}
@@ -1,5 +1,7 @@
 use super::{DatabaseBackend, DbQueryError, DbQueryResult};
-use crate::matching::titles_match;
+use crate::MatchCandidate;
+use crate::client::ClientProvider;
+use crate::matching::{is_title_candidate, titles_match};
 use std::future::Future;
 use std::pin::Pin;
 use std::time::Duration;
@@ -14,12 +16,13 @@ impl DatabaseBackend for NeurIPS {
     fn query<'a>(
         &'a self,
         title: &'a str,
-        client: &'a reqwest::Client,
+        client: &'a dyn ClientProvider,
         timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         Box::pin(async move {
             let years = [2023, 2022, 2021, 2020, 2019, 2018];
             let title_owned = title.to_string();
+            let mut near_miss = None;
 
             for year in years {
                 let url = format!(
@@ -27,64 +30,79 @@ impl DatabaseBackend for NeurIPS {
                     year
                 );
 
-                let resp = client
-                    .get(&url)
-                    .timeout(timeout)
-                    .send()
-                    .await
-                    .map_err(|e| DbQueryError::Other(e.to_string()))?;
+                let resp = client.send(client.get(&url).timeout(timeout)).await?;
 
                 if !resp.status().is_success() {
                     continue;
                 }
 
-                let body = resp
-                    .text()
-                    .await
-                    .map_err(|e| DbQueryError::Other(e.to_string()))?;
+                let body = resp.text().await.map_err(DbQueryError::from)?;
 
                 // Parse in spawn_blocking to avoid !Send scraper types in async context
                 let title_clone = title_owned.clone();
-                let match_result =
+                let best =
                     tokio::task::spawn_blocking(move || parse_neurips_index(&body, &title_clone))
                         .await
                         .map_err(|e| DbQueryError::Other(e.to_string()))?;
 
-                if let Some((found_title, href)) = match_result {
-                    let paper_url = format!("https://papers.nips.cc{}", href);
-
-                    // Fetch author page
-                    let authors = match client.get(&paper_url).timeout(timeout).send().await {
-                        Ok(resp) if resp.status().is_success() => {
-                            let body = resp.text().await.unwrap_or_default();
-                            tokio::task::spawn_blocking(move || parse_neurips_authors(&body))
-                                .await
-                                .unwrap_or_default()
-                        }
-                        _ => vec![],
-                    };
-
-                    return Ok((Some(found_title), authors, Some(paper_url)));
+                // A clear match ends the search; a near miss is kept in case no
+                // later year does better.
+                match best.found_title.as_deref() {
+                    Some(found) if titles_match(title, found) => {
+                        return Ok(with_authors(best, client, timeout).await);
+                    }
+                    Some(_) if near_miss.is_none() => near_miss = Some(best),
+                    _ => {}
                 }
             }
 
-            Ok((None, vec![], None))
+            match near_miss {
+                Some(best) => Ok(with_authors(best, client, timeout).await),
+                None => Ok(DbQueryResult::not_found()),
+            }
         })
     }
 }
 
-fn parse_neurips_index(html: &str, title: &str) -> Option<(String, String)> {
+/// Fill in the authors of `result`'s paper from its abstract page.
+async fn with_authors(
+    mut result: DbQueryResult,
+    client: &dyn ClientProvider,
+    timeout: Duration,
+) -> DbQueryResult {
+    let Some(ref paper_url) = result.paper_url else {
+        return result;
+    };
+    if let Ok(resp) = client.send(client.get(paper_url).timeout(timeout)).await
+        && resp.status().is_success()
+    {
+        let body = resp.text().await.unwrap_or_default();
+        result.authors = tokio::task::spawn_blocking(move || parse_neurips_authors(&body))
+            .await
+            .unwrap_or_default();
+    }
+    result
+}
+
+fn parse_neurips_index(html: &str, title: &str) -> DbQueryResult {
     let document = scraper::Html::parse_document(html);
     let selector = scraper::Selector::parse("a").unwrap();
 
+    let mut candidates = Vec::new();
     for element in document.select(&selector) {
         let link_text = element.text().collect::<String>();
-        if titles_match(title, &link_text) {
-            let href = element.value().attr("href").unwrap_or("").to_string();
-            return Some((link_text.trim().to_string(), href));
+        if is_title_candidate(title, &link_text) {
+            let href = element.value().attr("href").unwrap_or("");
+            candidates.push(MatchCandidate {
+                title: link_text.trim().to_string(),
+                authors: vec![],
+                paper_url: Some(format!("https://papers.nips.cc{}", href)),
+                venue: None,
+                year: None,
+            });
         }
     }
-    None
+    DbQueryResult::best_of(title, candidates)
 }
 
 fn parse_neurips_authors(html: &str) -> Vec<String> {
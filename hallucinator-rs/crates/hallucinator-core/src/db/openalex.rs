@@ -1,5 +1,7 @@
 use super::{DatabaseBackend, DbQueryError, DbQueryResult};
-use crate::matching::titles_match;
+use crate::MatchCandidate;
+use crate::client::ClientProvider;
+use crate::matching::is_title_candidate;
 use crate::rate_limit::check_rate_limit_response;
 use hallucinator_pdf::identifiers::get_query_words;
 use std::future::Future;
@@ -18,7 +20,7 @@ impl DatabaseBackend for OpenAlex {
     fn query<'a>(
         &'a self,
         title: &'a str,
-        client: &'a reqwest::Client,
+        client: &'a dyn ClientProvider,
         timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         Box::pin(async move {
@@ -30,28 +32,20 @@ impl DatabaseBackend for OpenAlex {
                 urlencoding::encode(&self.api_key)
             );
 
-            let resp = client
-                .get(&url)
-                .header("User-Agent", "Academic Reference Parser")
-                .timeout(timeout)
-                .send()
-                .await
-                .map_err(|e| DbQueryError::Other(e.to_string()))?;
+            let resp = client.send(client.get(&url).timeout(timeout)).await?;
 
             check_rate_limit_response(&resp)?;
             if !resp.status().is_success() {
-                return Err(DbQueryError::Other(format!("HTTP {}", resp.status())));
+                return Err(DbQueryError::Http(resp.status()));
             }
 
-            let data: serde_json::Value = resp
-                .json()
-                .await
-                .map_err(|e| DbQueryError::Other(e.to_string()))?;
+            let data: serde_json::Value = resp.json().await.map_err(DbQueryError::from)?;
             let results = data["results"].as_array().cloned().unwrap_or_default();
 
+            let mut candidates = Vec::new();
             for item in results.iter().take(5) {
                 let found_title = item["title"].as_str().unwrap_or("");
-                if !found_title.is_empty() && titles_match(title, found_title) {
+                if !found_title.is_empty() && is_title_candidate(title, found_title) {
                     let authors: Vec<String> = item["authorships"]
                         .as_array()
                         .map(|arr| {
@@ -68,11 +62,17 @@ impl DatabaseBackend for OpenAlex {
                         .map(String::from)
                         .or_else(|| item["id"].as_str().map(String::from));
 
-                    return Ok((Some(found_title.to_string()), authors, paper_url));
+                    candidates.push(MatchCandidate {
+                        title: found_title.to_string(),
+                        authors,
+                        paper_url,
+                        venue: None,
+                        year: None,
+                    });
                 }
             }
 
-            Ok((None, vec![], None))
+            Ok(DbQueryResult::best_of(title, candidates))
         })
     }
 }
@@ -1,12 +1,17 @@
 use super::{DatabaseBackend, DbQueryError, DbQueryResult};
-use crate::matching::titles_match;
+use crate::MatchCandidate;
+use crate::client::ClientProvider;
+use crate::matching::is_title_candidate;
 use crate::rate_limit::check_rate_limit_response;
 use hallucinator_pdf::identifiers::get_query_words;
 use std::future::Future;
 use std::pin::Pin;
 use std::time::Duration;
 
-pub struct PubMed;
+pub struct PubMed {
+    /// NCBI API key, sent as `api_key` on every E-utilities request.
+    pub api_key: Option<String>,
+}
 
 impl DatabaseBackend for PubMed {
     fn name(&self) -> &str {
@@ -16,7 +21,7 @@ impl DatabaseBackend for PubMed {
     fn query<'a>(
         &'a self,
         title: &'a str,
-        client: &'a reqwest::Client,
+        client: &'a dyn ClientProvider,
         timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         Box::pin(async move {
@@ -27,29 +32,24 @@ impl DatabaseBackend for PubMed {
             let search_url = "https://eutils.ncbi.nlm.nih.gov/entrez/eutils/esearch.fcgi";
             let term = format!("{}[Title]", query);
 
-            let resp = client
-                .get(search_url)
-                .query(&[
-                    ("db", "pubmed"),
-                    ("term", &term),
-                    ("retmode", "json"),
-                    ("retmax", "10"),
-                ])
-                .header("User-Agent", "Academic Reference Parser")
-                .timeout(timeout)
-                .send()
-                .await
-                .map_err(|e| DbQueryError::Other(e.to_string()))?;
+            let mut req = client.get(search_url).query(&[
+                ("db", "pubmed"),
+                ("term", &term),
+                ("retmode", "json"),
+                ("retmax", "10"),
+            ]);
+            if let Some(ref key) = self.api_key {
+                req = req.query(&[("api_key", key)]);
+            }
+
+            let resp = client.send(req.timeout(timeout)).await?;
 
             check_rate_limit_response(&resp)?;
             if !resp.status().is_success() {
-                return Err(DbQueryError::Other(format!("HTTP {}", resp.status())));
+                return Err(DbQueryError::Http(resp.status()));
             }
 
-            let data: serde_json::Value = resp
-                .json()
-                .await
-                .map_err(|e| DbQueryError::Other(e.to_string()))?;
+            let data: serde_json::Value = resp.json().await.map_err(DbQueryError::from)?;
             let id_list: Vec<String> = data["esearchresult"]["idlist"]
                 .as_array()
                 .map(|arr| {
@@ -60,39 +60,37 @@ impl DatabaseBackend for PubMed {
                 .unwrap_or_default();
 
             if id_list.is_empty() {
-                return Ok((None, vec![], None));
+                return Ok(DbQueryResult::not_found());
             }
 
             // Step 2: Fetch details
             let fetch_url = "https://eutils.ncbi.nlm.nih.gov/entrez/eutils/esummary.fcgi";
             let ids = id_list.join(",");
 
-            let resp = client
-                .get(fetch_url)
-                .query(&[("db", "pubmed"), ("id", ids.as_str()), ("retmode", "json")])
-                .header("User-Agent", "Academic Reference Parser")
-                .timeout(timeout)
-                .send()
-                .await
-                .map_err(|e| DbQueryError::Other(e.to_string()))?;
+            let mut req = client.get(fetch_url).query(&[
+                ("db", "pubmed"),
+                ("id", ids.as_str()),
+                ("retmode", "json"),
+            ]);
+            if let Some(ref key) = self.api_key {
+                req = req.query(&[("api_key", key)]);
+            }
+
+            let resp = client.send(req.timeout(timeout)).await?;
 
+            check_rate_limit_response(&resp)?;
             if !resp.status().is_success() {
-                return Err(DbQueryError::Other(format!(
-                    "HTTP {} on fetch",
-                    resp.status()
-                )));
+                return Err(DbQueryError::Http(resp.status()));
             }
 
-            let data: serde_json::Value = resp
-                .json()
-                .await
-                .map_err(|e| DbQueryError::Other(e.to_string()))?;
+            let data: serde_json::Value = resp.json().await.map_err(DbQueryError::from)?;
             let results = &data["result"];
 
+            let mut candidates = Vec::new();
             for pmid in &id_list {
                 let item = &results[pmid];
                 let found_title = item["title"].as_str().unwrap_or("");
-                if !found_title.is_empty() && titles_match(title, found_title) {
+                if !found_title.is_empty() && is_title_candidate(title, found_title) {
                     let authors: Vec<String> = item["authors"]
                         .as_array()
                         .map(|arr| {
@@ -104,11 +102,25 @@ impl DatabaseBackend for PubMed {
 
                     let paper_url = format!("https://pubmed.ncbi.nlm.nih.gov/{}/", pmid);
 
-                    return Ok((Some(found_title.to_string()), authors, Some(paper_url)));
+                    candidates.push(MatchCandidate {
+                        title: found_title.to_string(),
+                        authors,
+                        paper_url: Some(paper_url),
+                        venue: ["fulljournalname", "source"]
+                            .iter()
+                            .filter_map(|k| item[k].as_str())
+                            .find(|j| !j.is_empty())
+                            .map(String::from),
+                        // "2020 Jan 15", "2019 Spring", ...
+                        year: item["pubdate"]
+                            .as_str()
+                            .and_then(|d| d.get(..4))
+                            .and_then(|y| y.parse().ok()),
+                    });
                 }
             }
 
-            Ok((None, vec![], None))
+            Ok(DbQueryResult::best_of(title, candidates))
         })
     }
 }
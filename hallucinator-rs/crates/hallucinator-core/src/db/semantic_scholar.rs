@@ -1,5 +1,7 @@
-use super::{DatabaseBackend, DbQueryError, DbQueryResult};
-use crate::matching::titles_match;
+use super::{DatabaseBackend, DbQueryError, DbQueryResult, DoiQueryResult};
+use crate::MatchCandidate;
+use crate::client::ClientProvider;
+use crate::matching::is_title_candidate;
 use crate::rate_limit::check_rate_limit_response;
 use hallucinator_pdf::identifiers::get_query_words;
 use std::future::Future;
@@ -10,6 +12,57 @@ pub struct SemanticScholar {
     pub api_key: Option<String>,
 }
 
+/// A title-matching search hit together with its external identifiers.
+struct Hit {
+    candidate: MatchCandidate,
+    doi: Option<String>,
+}
+
+impl SemanticScholar {
+    /// Search by title and return every hit whose title matches.
+    ///
+    /// An empty result set (no `data` array) is a clean not-found, not an error.
+    async fn search(
+        &self,
+        title: &str,
+        client: &dyn ClientProvider,
+        timeout: Duration,
+    ) -> Result<Vec<Hit>, DbQueryError> {
+        let words = get_query_words(title, 6);
+        let query = words.join(" ");
+        let url = format!(
+            "https://api.semanticscholar.org/graph/v1/paper/search?query={}&limit=10&fields=title,authors,externalIds,url",
+            urlencoding::encode(&query)
+        );
+
+        let mut req = client.get(&url).timeout(timeout);
+
+        // Without a key we rely on the shared keyless rate (see `RateLimiters`).
+        if let Some(ref key) = self.api_key {
+            req = req.header("x-api-key", key);
+        }
+
+        let resp = client.send(req).await?;
+
+        check_rate_limit_response(&resp)?;
+        if !resp.status().is_success() {
+            return Err(DbQueryError::Http(resp.status()));
+        }
+
+        let data: serde_json::Value = resp.json().await.map_err(DbQueryError::from)?;
+        let results = data["data"].as_array().cloned().unwrap_or_default();
+
+        let mut hits = Vec::new();
+        for item in results {
+            let found_title = item["title"].as_str().unwrap_or("");
+            if !found_title.is_empty() && is_title_candidate(title, found_title) {
+                hits.push(parse_hit(&item, found_title));
+            }
+        }
+        Ok(hits)
+    }
+}
+
 impl DatabaseBackend for SemanticScholar {
     fn name(&self) -> &str {
         "Semantic Scholar"
@@ -18,61 +71,83 @@ impl DatabaseBackend for SemanticScholar {
     fn query<'a>(
         &'a self,
         title: &'a str,
-        client: &'a reqwest::Client,
+        client: &'a dyn ClientProvider,
         timeout: Duration,
     ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
         Box::pin(async move {
-            let words = get_query_words(title, 6);
-            let query = words.join(" ");
-            let url = format!(
-                "https://api.semanticscholar.org/graph/v1/paper/search?query={}&limit=10&fields=title,authors,url",
-                urlencoding::encode(&query)
-            );
-
-            let mut req = client
-                .get(&url)
-                .header("User-Agent", "Academic Reference Parser")
-                .timeout(timeout);
-
-            if let Some(ref key) = self.api_key {
-                req = req.header("x-api-key", key);
-            }
+            let hits = self.search(title, client, timeout).await?;
+            Ok(DbQueryResult::best_of(
+                title,
+                hits.into_iter().map(|h| h.candidate).collect(),
+            ))
+        })
+    }
 
-            let resp = req
-                .send()
-                .await
-                .map_err(|e| DbQueryError::Other(e.to_string()))?;
+    /// Same title search as [`query`](DatabaseBackend::query), but a hit whose
+    /// `externalIds.DOI` equals the reference's DOI wins outright.
+    ///
+    /// This costs no extra request and settles near-ties between records with
+    /// the same title.
+    fn query_doi<'a>(
+        &'a self,
+        doi: &'a str,
+        title: &'a str,
+        _authors: &'a [String],
+        client: &'a dyn ClientProvider,
+        timeout: Duration,
+    ) -> DoiQueryResult<'a> {
+        Box::pin(async move {
+            let hits = match self.search(title, client, timeout).await {
+                Ok(hits) => hits,
+                Err(e) => return Some(Err(e)),
+            };
 
-            check_rate_limit_response(&resp)?;
-            if !resp.status().is_success() {
-                return Err(DbQueryError::Other(format!("HTTP {}", resp.status())));
+            if let Some(hit) = hits.iter().find(|h| {
+                h.doi
+                    .as_deref()
+                    .is_some_and(|d| d.eq_ignore_ascii_case(doi))
+            }) {
+                let c = hit.candidate.clone();
+                return Some(Ok(DbQueryResult::found(c.title, c.authors, c.paper_url)));
             }
 
-            let data: serde_json::Value = resp
-                .json()
-                .await
-                .map_err(|e| DbQueryError::Other(e.to_string()))?;
-            let results = data["data"].as_array().cloned().unwrap_or_default();
-
-            for item in results {
-                let found_title = item["title"].as_str().unwrap_or("");
-                if !found_title.is_empty() && titles_match(title, found_title) {
-                    let authors: Vec<String> = item["authors"]
-                        .as_array()
-                        .map(|arr| {
-                            arr.iter()
-                                .filter_map(|a| a["name"].as_str().map(String::from))
-                                .collect()
-                        })
-                        .unwrap_or_default();
-
-                    let paper_url = item["url"].as_str().map(String::from);
-
-                    return Ok((Some(found_title.to_string()), authors, paper_url));
-                }
-            }
+            Some(Ok(DbQueryResult::best_of(
+                title,
+                hits.into_iter().map(|h| h.candidate).collect(),
+            )))
+        })
+    }
+}
 
-            Ok((None, vec![], None))
+/// Map a search hit, falling back to a DOI or arXiv link when S2 gives no URL.
+fn parse_hit(item: &serde_json::Value, found_title: &str) -> Hit {
+    let authors: Vec<String> = item["authors"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|a| a["name"].as_str().map(String::from))
+                .collect()
         })
+        .unwrap_or_default();
+
+    let ids = &item["externalIds"];
+    let doi = ids["DOI"].as_str().map(String::from);
+    let arxiv = ids["ArXiv"].as_str();
+
+    let paper_url = item["url"]
+        .as_str()
+        .map(String::from)
+        .or_else(|| doi.as_ref().map(|d| format!("https://doi.org/{}", d)))
+        .or_else(|| arxiv.map(|a| format!("https://arxiv.org/abs/{}", a)));
+
+    Hit {
+        candidate: MatchCandidate {
+            title: found_title.to_string(),
+            authors,
+            paper_url,
+            venue: None,
+            year: None,
+        },
+        doi,
     }
 }
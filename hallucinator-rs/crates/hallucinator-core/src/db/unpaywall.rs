@@ -0,0 +1,254 @@
+//! Unpaywall backend — looks up a reference's DOI at api.unpaywall.org.
+//!
+//! Unpaywall knows where legal open-access copies of a DOI live, so a match
+//! here links to readable full text rather than a paywalled landing page.
+//! The API only takes DOIs and requires a contact email on every request
+//! (the same [`Config::crossref_mailto`] CrossRef's polite pool gets).
+//!
+//! Unpaywall says nothing about whether a citation is real, so it is not one
+//! of the databases searched for a verdict: [`open_access_url`] asks it once
+//! a reference is verified.
+
+use super::{DatabaseBackend, DbQueryError, DbQueryResult, DoiQueryResult};
+use crate::client::ClientProvider;
+use crate::rate_limit::{DoiContext, check_rate_limit_response, query_with_rate_limit};
+use crate::retraction_watch::normalize_doi;
+use crate::{Config, DoiInfo, Status, ValidationResult};
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+pub struct Unpaywall {
+    pub mailto: String,
+}
+
+impl DatabaseBackend for Unpaywall {
+    fn name(&self) -> &str {
+        "Unpaywall"
+    }
+
+    fn requires_doi(&self) -> bool {
+        true
+    }
+
+    /// Title-based search is not supported — always returns not-found.
+    fn query<'a>(
+        &'a self,
+        _title: &'a str,
+        _client: &'a dyn ClientProvider,
+        _timeout: Duration,
+    ) -> Pin<Box<dyn Future<Output = Result<DbQueryResult, DbQueryError>> + Send + 'a>> {
+        Box::pin(async { Ok(DbQueryResult::not_found()) })
+    }
+
+    fn query_doi<'a>(
+        &'a self,
+        doi: &'a str,
+        _title: &'a str,
+        _authors: &'a [String],
+        client: &'a dyn ClientProvider,
+        timeout: Duration,
+    ) -> DoiQueryResult<'a> {
+        Box::pin(async move {
+            // The DOI goes in the path as-is, slashes included.
+            let url = format!(
+                "https://api.unpaywall.org/v2/{}?email={}",
+                urlencoding::encode(doi).replace("%2F", "/"),
+                urlencoding::encode(&self.mailto)
+            );
+            let resp = match client.send(client.get(&url).timeout(timeout)).await {
+                Ok(resp) => resp,
+                Err(e) => return Some(Err(e)),
+            };
+
+            if let Err(e) = check_rate_limit_response(&resp) {
+                return Some(Err(e));
+            }
+            // Unknown DOIs come back as 404.
+            if resp.status() == reqwest::StatusCode::NOT_FOUND {
+                return Some(Ok(DbQueryResult::not_found()));
+            }
+            if !resp.status().is_success() {
+                return Some(Err(DbQueryError::Http(resp.status())));
+            }
+
+            let data: serde_json::Value = match resp.json().await {
+                Ok(data) => data,
+                Err(e) => return Some(Err(DbQueryError::from(e))),
+            };
+            Some(Ok(parse_record(&data)))
+        })
+    }
+}
+
+/// Map an Unpaywall DOI record to a result, linking the best open-access
+/// location when there is one and the DOI landing page otherwise.
+fn parse_record(item: &serde_json::Value) -> DbQueryResult {
+    let Some(found_title) = item["title"]
+        .as_str()
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+    else {
+        return DbQueryResult::not_found();
+    };
+
+    let authors = item["z_authors"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|a| {
+                    let given = a["given"].as_str().unwrap_or("").trim();
+                    let family = a["family"].as_str()?.trim();
+                    Some(if given.is_empty() {
+                        family.to_string()
+                    } else {
+                        format!("{} {}", given, family)
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let best = &item["best_oa_location"];
+    let paper_url = best["url"]
+        .as_str()
+        .or_else(|| best["url_for_pdf"].as_str())
+        .or_else(|| best["url_for_landing_page"].as_str())
+        .or_else(|| item["doi_url"].as_str())
+        .map(String::from);
+
+    DbQueryResult::found(found_title, authors, paper_url)
+}
+
+/// The DOI to ask Unpaywall about for a verified result: the matched
+/// record's, or else the cited one when it resolved to the cited paper.
+pub fn lookup_doi(paper_url: Option<&str>, doi_info: Option<&DoiInfo>) -> Option<String> {
+    paper_url.and_then(normalize_doi).or_else(|| {
+        let info = doi_info.filter(|i| i.valid && !i.title_mismatch)?;
+        normalize_doi(&info.doi)
+    })
+}
+
+/// Unpaywall's open-access link for a verified `result` (see
+/// [`lookup_doi`]), so it points at readable full text. `None` unless
+/// [`Config::crossref_mailto`] is set and Unpaywall is enabled, and when
+/// Unpaywall has no copy or can't be reached.
+pub(crate) async fn open_access_url(
+    result: &ValidationResult,
+    config: &Config,
+    client: &reqwest::Client,
+) -> Option<String> {
+    if result.status != Status::Verified || !config.db_enabled("Unpaywall") {
+        return None;
+    }
+    let backend = Unpaywall {
+        mailto: config.crossref_mailto.clone()?,
+    };
+    let doi = lookup_doi(result.paper_url.as_deref(), result.doi_info.as_ref())?;
+    let outcome = query_with_rate_limit(
+        &backend,
+        &result.title,
+        client,
+        config.db_timeout("Unpaywall"),
+        &config.rate_limiters,
+        config.query_cache.as_deref(),
+        Some(&DoiContext {
+            doi: &doi,
+            authors: &[],
+        }),
+    )
+    .await;
+    match outcome.result {
+        Ok(found) => found.paper_url.filter(|_| found.found_title.is_some()),
+        Err(e) => {
+            log::debug!("Unpaywall: lookup for {} failed: {}", doi, e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parse_record_prefers_best_oa_location() {
+        let item = json!({
+            "doi": "10.1038/nature12373",
+            "doi_url": "https://doi.org/10.1038/nature12373",
+            "title": "Nanometre-scale thermometry in a living cell",
+            "is_oa": true,
+            "best_oa_location": {
+                "url": "https://europepmc.org/articles/pmc4221854?pdf=render",
+                "url_for_pdf": "https://europepmc.org/articles/pmc4221854?pdf=render",
+                "url_for_landing_page": "https://europepmc.org/articles/pmc4221854"
+            },
+            "z_authors": [
+                {"given": "G.", "family": "Kucsko"},
+                {"family": "Maurer"}
+            ]
+        });
+        let result = parse_record(&item);
+        assert_eq!(
+            result.found_title.as_deref(),
+            Some("Nanometre-scale thermometry in a living cell")
+        );
+        assert_eq!(result.authors, vec!["G. Kucsko", "Maurer"]);
+        assert_eq!(
+            result.paper_url.as_deref(),
+            Some("https://europepmc.org/articles/pmc4221854?pdf=render")
+        );
+    }
+
+    #[test]
+    fn parse_record_falls_back_to_doi_url() {
+        let item = json!({
+            "doi_url": "https://doi.org/10.1000/closed",
+            "title": "A paywalled paper",
+            "is_oa": false,
+            "best_oa_location": null,
+            "z_authors": null
+        });
+        let result = parse_record(&item);
+        assert!(result.authors.is_empty());
+        assert_eq!(
+            result.paper_url.as_deref(),
+            Some("https://doi.org/10.1000/closed")
+        );
+    }
+
+    #[test]
+    fn parse_record_without_title_is_not_found() {
+        let item = json!({"doi_url": "https://doi.org/10.1000/x", "title": null});
+        assert_eq!(parse_record(&item), DbQueryResult::not_found());
+    }
+
+    #[test]
+    fn looks_up_the_matched_doi_or_a_cited_one_that_resolved() {
+        let cited = |valid, title_mismatch| DoiInfo {
+            doi: "10.1000/Cited.1".into(),
+            valid,
+            title: None,
+            structurally_valid: true,
+            title_mismatch,
+        };
+        assert_eq!(
+            lookup_doi(
+                Some("https://doi.org/10.1000/Matched.2"),
+                Some(&cited(true, false))
+            )
+            .as_deref(),
+            Some("10.1000/matched.2")
+        );
+        let dblp = Some("https://dblp.org/rec/conf/x/Y21");
+        assert_eq!(
+            lookup_doi(dblp, Some(&cited(true, false))).as_deref(),
+            Some("10.1000/cited.1")
+        );
+        // A cited DOI for another paper, or one that didn't resolve
+        assert_eq!(lookup_doi(dblp, Some(&cited(true, true))), None);
+        assert_eq!(lookup_doi(dblp, Some(&cited(false, false))), None);
+        assert_eq!(lookup_doi(dblp, None), None);
+    }
+}
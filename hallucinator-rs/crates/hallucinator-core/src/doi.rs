@@ -1,7 +1,14 @@
-use crate::authors::validate_authors;
-use crate::matching::normalize_title;
+use crate::authors::AuthorMatchPolicy;
+use crate::client::ClientProvider;
+use crate::matching::{normalize_title, title_similarity};
+use crate::rate_limit::{DbQueryError, check_rate_limit_response};
 use std::time::Duration;
 
+/// Fuzzy title similarity below which a resolved DOI is taken to name a
+/// different paper than the citation. Far below the match bar in
+/// [`check_doi_match`], so near misses are not reported as mismatches.
+pub const DOI_TITLE_MISMATCH_THRESHOLD: f64 = 0.5;
+
 /// Result of DOI validation.
 #[derive(Debug, Clone)]
 pub struct DoiValidation {
@@ -9,6 +16,9 @@ pub struct DoiValidation {
     pub title: Option<String>,
     pub authors: Vec<String>,
     pub error: Option<String>,
+    /// Why doi.org could not be asked, when it could not (timeout, network,
+    /// an HTTP error). `None` when it answered, even that the DOI is unknown.
+    pub failure: Option<DbQueryError>,
 }
 
 /// Result of checking DOI match against a reference.
@@ -31,25 +41,63 @@ pub enum DoiMatchResult {
     },
 }
 
+/// Check whether a DOI has a plausible `10.NNNN/suffix` structure.
+///
+/// This is a purely offline sanity check: it flags strings that could never
+/// resolve (missing `10.` directory, non-numeric or implausible registrant
+/// code, empty suffix, embedded whitespace). A DOI that passes here may still
+/// fail to resolve — that is a separate signal reported by [`validate_doi`].
+pub fn looks_valid(doi: &str) -> bool {
+    let Some(rest) = doi.strip_prefix("10.") else {
+        return false;
+    };
+    let Some((registrant, suffix)) = rest.split_once('/') else {
+        return false;
+    };
+
+    // Registrant code: 4+ digits, optionally followed by dot-separated
+    // numeric sub-divisions (e.g. "1000.10"). All-zero codes are never issued.
+    let mut parts = registrant.split('.');
+    let main = parts.next().unwrap_or("");
+    if main.len() < 4 || main.len() > 9 || !main.bytes().all(|b| b.is_ascii_digit()) {
+        return false;
+    }
+    if main.bytes().all(|b| b == b'0') {
+        return false;
+    }
+    if !parts.all(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit())) {
+        return false;
+    }
+
+    // Suffix: non-empty, no whitespace/control chars, at least one alphanumeric.
+    !suffix.is_empty()
+        && suffix.len() <= 300
+        && !suffix.chars().any(|c| c.is_whitespace() || c.is_control())
+        && suffix.chars().any(|c| c.is_alphanumeric())
+}
+
 /// Validate a DOI by querying doi.org for metadata.
-pub async fn validate_doi(doi: &str, client: &reqwest::Client, timeout: Duration) -> DoiValidation {
+pub async fn validate_doi(
+    doi: &str,
+    client: &dyn ClientProvider,
+    timeout: Duration,
+) -> DoiValidation {
     if doi.is_empty() {
         return DoiValidation {
             valid: false,
             title: None,
             authors: vec![],
             error: Some("No DOI provided".into()),
+            failure: None,
         };
     }
 
     let url = format!("https://doi.org/{}", doi);
-    let result = client
+    let request = client
         .get(&url)
         .header("Accept", "application/vnd.citationstyles.csl+json")
-        .header("User-Agent", "HallucinatedReferenceChecker/1.0")
-        .timeout(timeout)
-        .send()
-        .await;
+        .timeout(timeout);
+    let result = client.send(request).await;
 
     match result {
         Ok(resp) => {
@@ -85,14 +133,19 @@ pub async fn validate_doi(doi: &str, client: &reqwest::Client, timeout: Duration
                             title,
                             authors,
                             error: None,
+                            failure: None,
+                        }
+                    }
+                    Err(e) => {
+                        let error = format!("Failed to parse DOI metadata: {}", e);
+                        DoiValidation {
+                            valid: false,
+                            title: None,
+                            authors: vec![],
+                            error: Some(error.clone()),
+                            failure: Some(DbQueryError::Other(error)),
                         }
                     }
-                    Err(e) => DoiValidation {
-                        valid: false,
-                        title: None,
-                        authors: vec![],
-                        error: Some(format!("Failed to parse DOI metadata: {}", e)),
-                    },
                 }
             } else if resp.status().as_u16() == 404 {
                 DoiValidation {
@@ -100,13 +153,18 @@ pub async fn validate_doi(doi: &str, client: &reqwest::Client, timeout: Duration
                     title: None,
                     authors: vec![],
                     error: Some("DOI not found".into()),
+                    failure: None,
                 }
             } else {
+                let failure = check_rate_limit_response(&resp)
+                    .err()
+                    .unwrap_or_else(|| DbQueryError::Http(resp.status()));
                 DoiValidation {
                     valid: false,
                     title: None,
                     authors: vec![],
                     error: Some(format!("DOI lookup failed: HTTP {}", resp.status())),
+                    failure: Some(failure),
                 }
             }
         }
@@ -115,6 +173,7 @@ pub async fn validate_doi(doi: &str, client: &reqwest::Client, timeout: Duration
             title: None,
             authors: vec![],
             error: Some(format!("DOI lookup failed: {}", e)),
+            failure: Some(e),
         },
     }
 }
@@ -124,6 +183,7 @@ pub fn check_doi_match(
     doi_result: &DoiValidation,
     ref_title: &str,
     ref_authors: &[String],
+    author_policy: AuthorMatchPolicy,
 ) -> DoiMatchResult {
     if !doi_result.valid {
         return DoiMatchResult::Invalid {
@@ -173,7 +233,7 @@ pub fn check_doi_match(
 
     // Check author match
     if !ref_authors.is_empty() && !doi_authors.is_empty() {
-        if validate_authors(ref_authors, doi_authors) {
+        if author_policy.accepts(ref_authors, doi_authors) {
             DoiMatchResult::Verified {
                 doi_title: doi_title.to_string(),
                 doi_authors: doi_authors.clone(),
@@ -191,3 +251,107 @@ pub fn check_doi_match(
         }
     }
 }
+
+/// Whether a resolved DOI's title strongly disagrees with the citation's.
+///
+/// The title must fail [`check_doi_match`] *and* score below
+/// [`DOI_TITLE_MISMATCH_THRESHOLD`], so subtitles, tool-name prefixes and
+/// formatting noise do not count. Unresolved DOIs and untitled citations
+/// never mismatch.
+pub fn doi_title_mismatch(doi_result: &DoiValidation, ref_title: &str) -> bool {
+    if !doi_result.valid || normalize_title(ref_title).is_empty() {
+        return false;
+    }
+    let Some(doi_title) = doi_result.title.as_deref() else {
+        return false;
+    };
+    matches!(
+        check_doi_match(doi_result, ref_title, &[], AuthorMatchPolicy::default()),
+        DoiMatchResult::TitleMismatch { .. }
+    ) && title_similarity(ref_title, doi_title) < DOI_TITLE_MISMATCH_THRESHOLD
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolved(title: &str) -> DoiValidation {
+        DoiValidation {
+            valid: true,
+            title: Some(title.to_string()),
+            authors: vec![],
+            error: None,
+            failure: None,
+        }
+    }
+
+    #[test]
+    fn doi_title_mismatch_flags_a_different_paper() {
+        let doi = resolved("Deep Residual Learning for Image Recognition");
+        assert!(doi_title_mismatch(
+            &doi,
+            "A Survey of Byzantine Fault Tolerant Consensus Protocols"
+        ));
+    }
+
+    #[test]
+    fn doi_title_mismatch_ignores_near_misses() {
+        let doi = resolved("ReCon");
+        assert!(!doi_title_mismatch(
+            &doi,
+            "ReCon: Revealing and Controlling PII Leaks in Mobile Network Traffic"
+        ));
+        let doi = resolved("Deep Residual Learning for Image Recognition");
+        assert!(!doi_title_mismatch(
+            &doi,
+            "Deep residual learning for image recognition."
+        ));
+        assert!(!doi_title_mismatch(&doi, ""));
+        let unresolved = DoiValidation {
+            valid: false,
+            title: None,
+            authors: vec![],
+            error: Some("DOI not found".into()),
+            failure: None,
+        };
+        assert!(!doi_title_mismatch(&unresolved, "Anything at all"));
+    }
+
+    #[test]
+    fn looks_valid_accepts_real_dois() {
+        for doi in [
+            "10.1145/3133956.3134093",
+            "10.1109/SP.2019.00002",
+            "10.48550/arXiv.1706.03762",
+            "10.1038/nature14539",
+            "10.1007/978-3-030-58452-8_13",
+            "10.18653/v1/N19-1423",
+            "10.1002/(SICI)1097-4571(199806)49:8<693::AID-ASI4>3.0.CO;2-0",
+            "10.1000.10/123456",
+            "10.5555/3295222.3295349",
+        ] {
+            assert!(looks_valid(doi), "expected valid: {doi}");
+        }
+    }
+
+    #[test]
+    fn looks_valid_rejects_garbage() {
+        for doi in [
+            "",
+            "10.1145",
+            "10.1145/",
+            "11.1145/3133956",
+            "doi:10.1145/3133956",
+            "10.abc/3133956",
+            "10.12/3133956",
+            "10.0000/3133956",
+            "10.1234567890/abc",
+            "10.1145./abc",
+            "10.1145/abc def",
+            "10.1145/---",
+            "10./abc",
+        ] {
+            assert!(!looks_valid(doi), "expected invalid: {doi:?}");
+        }
+    }
+}
@@ -0,0 +1,464 @@
+//! Settings from environment variables, for [`Config::from_env`].
+//!
+//! Every tunable in [`Config`] can be set from the environment, so a
+//! container can be configured without flags or a config file. API keys and
+//! offline database paths keep their historical names (`OPENALEX_KEY`,
+//! `DBLP_OFFLINE_PATH`, `DB_TIMEOUT`, ...); everything else is
+//! `HALLUCINATOR_<SETTING>`. An empty variable counts as unset.
+//!
+//! The frontends rank these below their own flags and above the config file.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::{Config, RateLimiters};
+
+/// Settings read from the environment; `None` where the variable is unset.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EnvConfig {
+    // API keys and identity
+    pub openalex_key: Option<String>,
+    pub s2_api_key: Option<String>,
+    pub ncbi_api_key: Option<String>,
+    pub core_api_key: Option<String>,
+    pub crossref_mailto: Option<String>,
+    pub user_agent: Option<String>,
+    pub proxy: Option<String>,
+    pub extra_ca_cert: Option<PathBuf>,
+    // Local databases
+    pub dblp_offline_path: Option<PathBuf>,
+    pub dblp_max_age_days: Option<u64>,
+    pub acl_offline_path: Option<PathBuf>,
+    pub local_bib_path: Option<PathBuf>,
+    pub retraction_db_path: Option<PathBuf>,
+    pub retraction_max_age_days: Option<u64>,
+    pub cache_path: Option<PathBuf>,
+    pub cache_max_entries: Option<usize>,
+    // Which databases, and how they are matched
+    pub disabled_dbs: Option<Vec<String>>,
+    pub only_dbs: Option<Vec<String>>,
+    pub per_db_threshold: Option<HashMap<String, f64>>,
+    pub check_openalex_authors: Option<bool>,
+    pub author_match_min_overlap: Option<f32>,
+    pub require_first_author_match: Option<bool>,
+    pub author_match_threshold: Option<f64>,
+    pub min_match_score: Option<f64>,
+    pub consensus_mode: Option<bool>,
+    pub consensus_min: Option<usize>,
+    pub strict_title: Option<bool>,
+    pub orcid_check: Option<bool>,
+    pub author_mismatch_is_failure: Option<bool>,
+    // Concurrency, timeouts and retries
+    pub num_workers: Option<usize>,
+    pub max_concurrent_papers: Option<usize>,
+    pub dedup: Option<bool>,
+    pub adaptive_concurrency: Option<bool>,
+    pub db_timeout_secs: Option<u64>,
+    pub db_timeout_short_secs: Option<u64>,
+    pub db_timeouts: Option<HashMap<String, u64>>,
+    pub max_rate_limit_retries: Option<u32>,
+    pub max_retry_passes: Option<usize>,
+    pub max_references: Option<usize>,
+    pub run_deadline: Option<Duration>,
+}
+
+impl EnvConfig {
+    /// Read the process environment. A malformed value is an error naming
+    /// the variable, rather than a setting silently left at its default.
+    pub fn read() -> Result<Self, String> {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    /// Read settings through `var`, which looks a variable up by name.
+    pub fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Self, String> {
+        let vars = Vars(&var);
+        Ok(Self {
+            openalex_key: vars.string("OPENALEX_KEY"),
+            s2_api_key: vars.string("S2_API_KEY"),
+            ncbi_api_key: vars.string("NCBI_API_KEY"),
+            core_api_key: vars.string("CORE_API_KEY"),
+            crossref_mailto: vars.string("CROSSREF_MAILTO"),
+            user_agent: vars.string("HALLUCINATOR_USER_AGENT"),
+            proxy: vars.string("HALLUCINATOR_PROXY"),
+            extra_ca_cert: vars.path("HALLUCINATOR_EXTRA_CA_CERT"),
+            dblp_offline_path: vars.path("DBLP_OFFLINE_PATH"),
+            dblp_max_age_days: vars.parse("HALLUCINATOR_DBLP_MAX_AGE_DAYS")?,
+            acl_offline_path: vars.path("ACL_OFFLINE_PATH"),
+            local_bib_path: vars.path("HALLUCINATOR_LOCAL_BIB"),
+            retraction_db_path: vars.path("HALLUCINATOR_RETRACTION_DB"),
+            retraction_max_age_days: vars.parse("HALLUCINATOR_RETRACTION_MAX_AGE_DAYS")?,
+            cache_path: vars.path("HALLUCINATOR_CACHE_PATH"),
+            // 0 means unbounded
+            cache_max_entries: vars.parse("HALLUCINATOR_CACHE_MAX_ENTRIES")?,
+            disabled_dbs: vars.list("HALLUCINATOR_DISABLED_DBS"),
+            only_dbs: vars.list("HALLUCINATOR_ONLY_DBS"),
+            per_db_threshold: vars
+                .pairs("HALLUCINATOR_THRESHOLDS")?
+                .map(|pairs| check_fractions("HALLUCINATOR_THRESHOLDS", pairs))
+                .transpose()?,
+            check_openalex_authors: vars.flag("HALLUCINATOR_CHECK_OPENALEX_AUTHORS")?,
+            author_match_min_overlap: vars.fraction("HALLUCINATOR_AUTHOR_MIN_OVERLAP")?,
+            require_first_author_match: vars.flag("HALLUCINATOR_REQUIRE_FIRST_AUTHOR")?,
+            author_match_threshold: vars.fraction("HALLUCINATOR_AUTHOR_MATCH_THRESHOLD")?,
+            min_match_score: vars.fraction("HALLUCINATOR_MIN_MATCH_SCORE")?,
+            consensus_mode: vars.flag("HALLUCINATOR_CONSENSUS")?,
+            consensus_min: vars.at_least_one("HALLUCINATOR_CONSENSUS_MIN")?,
+            strict_title: vars.flag("HALLUCINATOR_STRICT_TITLE")?,
+            orcid_check: vars.flag("HALLUCINATOR_ORCID_CHECK")?,
+            author_mismatch_is_failure: vars.flag("HALLUCINATOR_AUTHOR_MISMATCH_IS_FAILURE")?,
+            num_workers: vars.at_least_one("HALLUCINATOR_NUM_WORKERS")?,
+            max_concurrent_papers: vars.at_least_one("HALLUCINATOR_MAX_CONCURRENT_PAPERS")?,
+            dedup: vars.flag("HALLUCINATOR_DEDUP")?,
+            adaptive_concurrency: vars.flag("HALLUCINATOR_ADAPTIVE_CONCURRENCY")?,
+            db_timeout_secs: vars.at_least_one("DB_TIMEOUT")?,
+            db_timeout_short_secs: vars.at_least_one("DB_TIMEOUT_SHORT")?,
+            db_timeouts: vars.pairs::<u64>("HALLUCINATOR_DB_TIMEOUTS")?.map(|pairs| {
+                pairs
+                    .into_iter()
+                    .map(|(db, secs)| (db, secs.max(1)))
+                    .collect()
+            }),
+            max_rate_limit_retries: vars.parse("HALLUCINATOR_MAX_RATE_LIMIT_RETRIES")?,
+            max_retry_passes: vars.parse("HALLUCINATOR_MAX_RETRY_PASSES")?,
+            // 0 means unlimited, as in the config file; kept so it can lift
+            // a cap set elsewhere
+            max_references: vars.parse("HALLUCINATOR_MAX_REFERENCES")?,
+            run_deadline: vars
+                .at_least_one("HALLUCINATOR_RUN_DEADLINE")?
+                .map(Duration::from_secs),
+        })
+    }
+
+    /// Override `config` with every setting present here. Rate limiters and
+    /// the query cache are rebuilt when the keys, cache path or cache size
+    /// they depend on changed.
+    pub fn apply(&self, config: &mut Config) {
+        fn set<T: Clone>(target: &mut T, value: &Option<T>) {
+            if let Some(value) = value {
+                *target = value.clone();
+            }
+        }
+        fn set_some<T: Clone>(target: &mut Option<T>, value: &Option<T>) {
+            if value.is_some() {
+                *target = value.clone();
+            }
+        }
+        set_some(&mut config.openalex_key, &self.openalex_key);
+        set_some(&mut config.s2_api_key, &self.s2_api_key);
+        set_some(&mut config.ncbi_api_key, &self.ncbi_api_key);
+        set_some(&mut config.core_api_key, &self.core_api_key);
+        set_some(&mut config.crossref_mailto, &self.crossref_mailto);
+        set_some(&mut config.user_agent, &self.user_agent);
+        set_some(&mut config.proxy, &self.proxy);
+        set_some(&mut config.extra_ca_cert, &self.extra_ca_cert);
+        set_some(&mut config.dblp_offline_path, &self.dblp_offline_path);
+        set(&mut config.dblp_max_age_days, &self.dblp_max_age_days);
+        set_some(&mut config.acl_offline_path, &self.acl_offline_path);
+        set_some(&mut config.local_bib_path, &self.local_bib_path);
+        set_some(&mut config.retraction_db_path, &self.retraction_db_path);
+        set(
+            &mut config.retraction_max_age_days,
+            &self.retraction_max_age_days,
+        );
+        set(&mut config.disabled_dbs, &self.disabled_dbs);
+        set(&mut config.only_dbs, &self.only_dbs);
+        if let Some(thresholds) = &self.per_db_threshold {
+            config.per_db_threshold.extend(thresholds.clone());
+        }
+        set(
+            &mut config.check_openalex_authors,
+            &self.check_openalex_authors,
+        );
+        set(
+            &mut config.author_match_min_overlap,
+            &self.author_match_min_overlap,
+        );
+        set(
+            &mut config.require_first_author_match,
+            &self.require_first_author_match,
+        );
+        set(
+            &mut config.author_match_threshold,
+            &self.author_match_threshold,
+        );
+        set(&mut config.min_match_score, &self.min_match_score);
+        set(&mut config.consensus_mode, &self.consensus_mode);
+        set(&mut config.consensus_min, &self.consensus_min);
+        set(&mut config.strict_title, &self.strict_title);
+        set(&mut config.orcid_check, &self.orcid_check);
+        set(
+            &mut config.author_mismatch_is_failure,
+            &self.author_mismatch_is_failure,
+        );
+        set(&mut config.num_workers, &self.num_workers);
+        set(
+            &mut config.max_concurrent_papers,
+            &self.max_concurrent_papers,
+        );
+        set(&mut config.dedup, &self.dedup);
+        set(&mut config.adaptive_concurrency, &self.adaptive_concurrency);
+        set(&mut config.db_timeout_secs, &self.db_timeout_secs);
+        set(
+            &mut config.db_timeout_short_secs,
+            &self.db_timeout_short_secs,
+        );
+        if let Some(timeouts) = &self.db_timeouts {
+            config.db_timeouts.extend(timeouts.clone());
+        }
+        set(
+            &mut config.max_rate_limit_retries,
+            &self.max_rate_limit_retries,
+        );
+        set(&mut config.max_retry_passes, &self.max_retry_passes);
+        if let Some(max) = self.max_references {
+            config.max_references = (max > 0).then_some(max);
+        }
+        set_some(&mut config.run_deadline, &self.run_deadline);
+
+        if self.crossref_mailto.is_some()
+            || self.s2_api_key.is_some()
+            || self.ncbi_api_key.is_some()
+        {
+            config.rate_limiters = Arc::new(RateLimiters::new(
+                config.crossref_mailto.is_some(),
+                config.s2_api_key.is_some(),
+                config.ncbi_api_key.is_some(),
+            ));
+        }
+        set_some(&mut config.cache_path, &self.cache_path);
+        set(&mut config.cache_max_entries, &self.cache_max_entries);
+        if self.cache_path.is_some() || self.cache_max_entries.is_some() {
+            config.query_cache = Some(crate::build_query_cache(
+                config.cache_path.as_deref(),
+                config.cache_max_entries,
+            ));
+        }
+    }
+}
+
+impl Config {
+    /// The default configuration with the environment's settings applied
+    /// (see [`EnvConfig`]).
+    pub fn from_env() -> Result<Self, String> {
+        let mut config = Config::default();
+        EnvConfig::read()?.apply(&mut config);
+        Ok(config)
+    }
+}
+
+/// Typed lookups over a variable source.
+struct Vars<'a>(&'a dyn Fn(&str) -> Option<String>);
+
+impl Vars<'_> {
+    fn string(&self, name: &str) -> Option<String> {
+        (self.0)(name)
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    }
+
+    fn path(&self, name: &str) -> Option<PathBuf> {
+        self.string(name).map(PathBuf::from)
+    }
+
+    fn parse<T: FromStr>(&self, name: &str) -> Result<Option<T>, String> {
+        self.string(name)
+            .map(|v| v.parse().map_err(|_| format!("Invalid {}: {}", name, v)))
+            .transpose()
+    }
+
+    /// A count or duration that has to be at least 1.
+    fn at_least_one<T: FromStr + PartialOrd + From<u8>>(
+        &self,
+        name: &str,
+    ) -> Result<Option<T>, String> {
+        match self.parse::<T>(name)? {
+            Some(v) if v < T::from(1) => Err(format!("{} must be at least 1", name)),
+            v => Ok(v),
+        }
+    }
+
+    fn fraction<T: FromStr + PartialOrd + From<u8>>(
+        &self,
+        name: &str,
+    ) -> Result<Option<T>, String> {
+        match self.parse::<T>(name)? {
+            Some(v) if !(T::from(0)..=T::from(1)).contains(&v) => {
+                Err(format!("{} must be between 0 and 1", name))
+            }
+            v => Ok(v),
+        }
+    }
+
+    fn flag(&self, name: &str) -> Result<Option<bool>, String> {
+        self.string(name)
+            .map(|v| match v.to_ascii_lowercase().as_str() {
+                "1" | "true" | "yes" | "on" => Ok(true),
+                "0" | "false" | "no" | "off" => Ok(false),
+                _ => Err(format!("Invalid {}: {} (expected true or false)", name, v)),
+            })
+            .transpose()
+    }
+
+    /// A comma-separated list, e.g. `OpenAlex,PubMed`.
+    fn list(&self, name: &str) -> Option<Vec<String>> {
+        self.string(name).map(|v| {
+            v.split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(String::from)
+                .collect()
+        })
+    }
+
+    /// Comma-separated `DB=VALUE` pairs, e.g. `DBLP=0.97,arXiv=0.9`.
+    fn pairs<T: FromStr>(&self, name: &str) -> Result<Option<HashMap<String, T>>, String> {
+        let Some(items) = self.list(name) else {
+            return Ok(None);
+        };
+        items
+            .iter()
+            .map(|item| {
+                let (db, value) = item
+                    .split_once('=')
+                    .ok_or_else(|| format!("Invalid {}: expected DB=VALUE, got {}", name, item))?;
+                let value = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("Invalid {}: {}", name, item))?;
+                Ok((db.trim().to_string(), value))
+            })
+            .collect::<Result<_, String>>()
+            .map(Some)
+    }
+}
+
+fn check_fractions(
+    name: &str,
+    pairs: HashMap<String, f64>,
+) -> Result<HashMap<String, f64>, String> {
+    match pairs.iter().find(|(_, v)| !(0.0..=1.0).contains(*v)) {
+        Some((db, _)) => Err(format!(
+            "{}: threshold for {} must be between 0 and 1",
+            name, db
+        )),
+        None => Ok(pairs),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(vars: &[(&str, &str)]) -> Result<EnvConfig, String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        EnvConfig::from_vars(|name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn unset_and_empty_variables_change_nothing() {
+        let env = read(&[("OPENALEX_KEY", ""), ("HALLUCINATOR_NUM_WORKERS", " ")]).unwrap();
+        assert_eq!(env, EnvConfig::default());
+    }
+
+    #[test]
+    fn reads_every_kind_of_setting() {
+        let env = read(&[
+            ("OPENALEX_KEY", "key"),
+            ("DB_TIMEOUT", "20"),
+            ("HALLUCINATOR_CACHE_PATH", "/tmp/cache.db"),
+            ("HALLUCINATOR_CACHE_MAX_ENTRIES", "5000"),
+            ("HALLUCINATOR_DISABLED_DBS", "OpenAlex, PubMed,"),
+            ("HALLUCINATOR_THRESHOLDS", "DBLP=0.97,arXiv=0.9"),
+            ("HALLUCINATOR_DB_TIMEOUTS", "Europe PMC=30"),
+            ("HALLUCINATOR_STRICT_TITLE", "yes"),
+            ("HALLUCINATOR_DEDUP", "0"),
+            ("HALLUCINATOR_AUTHOR_MIN_OVERLAP", "0.5"),
+            ("HALLUCINATOR_MAX_REFERENCES", "0"),
+            ("HALLUCINATOR_RUN_DEADLINE", "600"),
+        ])
+        .unwrap();
+        assert_eq!(env.openalex_key.as_deref(), Some("key"));
+        assert_eq!(env.db_timeout_secs, Some(20));
+        assert_eq!(env.cache_path, Some(PathBuf::from("/tmp/cache.db")));
+        assert_eq!(env.cache_max_entries, Some(5000));
+        assert_eq!(
+            env.disabled_dbs,
+            Some(vec!["OpenAlex".to_string(), "PubMed".to_string()])
+        );
+        assert_eq!(env.per_db_threshold.unwrap()["arXiv"], 0.9);
+        assert_eq!(env.db_timeouts.unwrap()["Europe PMC"], 30);
+        assert_eq!(env.strict_title, Some(true));
+        assert_eq!(env.dedup, Some(false));
+        assert_eq!(env.author_match_min_overlap, Some(0.5));
+        assert_eq!(env.max_references, Some(0));
+        assert_eq!(env.run_deadline, Some(Duration::from_secs(600)));
+    }
+
+    #[test]
+    fn malformed_values_name_the_variable() {
+        for (name, value) in [
+            ("DB_TIMEOUT", "soon"),
+            ("DB_TIMEOUT", "0"),
+            ("HALLUCINATOR_STRICT_TITLE", "maybe"),
+            ("HALLUCINATOR_MIN_MATCH_SCORE", "1.5"),
+            ("HALLUCINATOR_THRESHOLDS", "DBLP"),
+            ("HALLUCINATOR_THRESHOLDS", "DBLP=2"),
+        ] {
+            let err = read(&[(name, value)]).unwrap_err();
+            assert!(err.contains(name), "{}={}: {}", name, value, err);
+        }
+    }
+
+    #[test]
+    fn apply_overrides_only_what_is_set() {
+        let env = read(&[
+            ("S2_API_KEY", "s2"),
+            ("HALLUCINATOR_NUM_WORKERS", "8"),
+            ("HALLUCINATOR_THRESHOLDS", "DBLP=0.97"),
+        ])
+        .unwrap();
+        let mut config = Config {
+            openalex_key: Some("from-file".into()),
+            per_db_threshold: HashMap::from([("arXiv".to_string(), 0.9)]),
+            ..Config::default()
+        };
+        env.apply(&mut config);
+        assert_eq!(config.openalex_key.as_deref(), Some("from-file"));
+        assert_eq!(config.s2_api_key.as_deref(), Some("s2"));
+        assert_eq!(config.num_workers, 8);
+        assert_eq!(config.db_timeout_secs, 10);
+        assert_eq!(config.per_db_threshold.len(), 2);
+    }
+
+    #[test]
+    fn cache_max_entries_rebuilds_the_cache() {
+        let mut config = Config::default();
+        read(&[("HALLUCINATOR_CACHE_MAX_ENTRIES", "10")])
+            .unwrap()
+            .apply(&mut config);
+        assert_eq!(config.cache_max_entries, 10);
+        assert_eq!(config.query_cache.unwrap().max_entries(), 10);
+    }
+
+    #[test]
+    fn zero_max_references_lifts_a_cap() {
+        let mut config = Config {
+            max_references: Some(50),
+            ..Config::default()
+        };
+        read(&[("HALLUCINATOR_MAX_REFERENCES", "100")])
+            .unwrap()
+            .apply(&mut config);
+        assert_eq!(config.max_references, Some(100));
+        read(&[("HALLUCINATOR_MAX_REFERENCES", "0")])
+            .unwrap()
+            .apply(&mut config);
+        assert_eq!(config.max_references, None);
+    }
+}
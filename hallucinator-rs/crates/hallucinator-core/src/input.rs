@@ -0,0 +1,122 @@
+//! Reference extraction for every supported input file type.
+//!
+//! Frontends hand a path to [`extract_references_from_path`], which picks the
+//! extractor from the file extension, so a PDF, a `.bbl`, a `.bib` or a plain
+//! `.txt` reference list all come back as the same [`ExtractionResult`].
+
+use std::path::Path;
+
+use hallucinator_pdf::{ExtractionProgress, PdfError, PdfExtractor};
+
+use crate::{CoreError, ExtractionResult};
+
+/// The kind of input a path holds, judged by its extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    Pdf,
+    /// BibTeX-generated bibliography (`\bibitem` entries).
+    Bbl,
+    /// BibTeX database.
+    Bib,
+    /// Plain reference list, one citation per blank-line-separated block.
+    Text,
+}
+
+impl InputKind {
+    /// Anything without a recognised extension is treated as a PDF.
+    pub fn from_path(path: &Path) -> Self {
+        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        if ext.eq_ignore_ascii_case("bbl") {
+            Self::Bbl
+        } else if ext.eq_ignore_ascii_case("bib") {
+            Self::Bib
+        } else if ext.eq_ignore_ascii_case("txt") {
+            Self::Text
+        } else {
+            Self::Pdf
+        }
+    }
+}
+
+/// Extract references from `path`, dispatching on its [`InputKind`].
+///
+/// PDFs go through `extractor` (MuPDF) and report `progress`; `.bbl` and
+/// `.bib` files are read from their BibTeX fields; `.txt` files are split into
+/// citations and parsed with `extractor`'s reference parser. Skip stats are
+/// filled in the same way for every kind.
+pub fn extract_references_from_path(
+    path: &Path,
+    extractor: &PdfExtractor,
+    progress: &dyn Fn(ExtractionProgress),
+) -> Result<ExtractionResult, CoreError> {
+    match InputKind::from_path(path) {
+        InputKind::Pdf => Ok(extractor.extract_references_with_progress(path, progress)?),
+        InputKind::Bbl => Ok(hallucinator_bbl::extract_references_from_bbl(path)?),
+        InputKind::Bib => Ok(hallucinator_bbl::extract_references_from_bib(path)?),
+        InputKind::Text => {
+            let text = std::fs::read_to_string(path).map_err(PdfError::Io)?;
+            let result = extractor.extract_references_from_citation_list(&text);
+            if result.references.is_empty() {
+                return Err(PdfError::NoReferencesSection.into());
+            }
+            Ok(result)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kind_follows_extension() {
+        assert_eq!(InputKind::from_path(Path::new("a.PDF")), InputKind::Pdf);
+        assert_eq!(InputKind::from_path(Path::new("a.bbl")), InputKind::Bbl);
+        assert_eq!(InputKind::from_path(Path::new("refs.Bib")), InputKind::Bib);
+        assert_eq!(InputKind::from_path(Path::new("list.txt")), InputKind::Text);
+        assert_eq!(InputKind::from_path(Path::new("paper")), InputKind::Pdf);
+    }
+
+    #[test]
+    fn text_list_is_parsed_with_skip_stats() {
+        let path =
+            std::env::temp_dir().join(format!("hallucinator-refs-{}.txt", std::process::id()));
+        std::fs::write(
+            &path,
+            "J. Smith and A. Doe. Detecting fabricated citations in machine learning papers. \
+             In Proceedings of NeurIPS, 2020.\n\n\
+             K. Lee. A survey of reference validation techniques for scholarly text. \
+             Journal of Examples, 2019.\n\n\
+             Project page. https://example.com/tool\n",
+        )
+        .unwrap();
+        let result = extract_references_from_path(&path, &PdfExtractor::new(), &|_| {});
+        std::fs::remove_file(&path).unwrap();
+        let result = result.unwrap();
+
+        assert_eq!(result.skip_stats.total_raw, 3);
+        assert_eq!(result.skip_stats.url_only, 1);
+        let titles: Vec<_> = result
+            .references
+            .iter()
+            .filter(|r| r.skip_reason.is_none())
+            .filter_map(|r| r.title.as_deref())
+            .collect();
+        assert_eq!(titles.len(), 2, "{:?}", titles);
+        assert!(titles[0].starts_with("Detecting fabricated citations"));
+        assert_eq!(result.references[2].original_number, 3);
+    }
+
+    #[test]
+    fn empty_text_list_is_an_error() {
+        let path =
+            std::env::temp_dir().join(format!("hallucinator-empty-{}.txt", std::process::id()));
+        std::fs::write(&path, "\n \n").unwrap();
+        let result = extract_references_from_path(&path, &PdfExtractor::new(), &|_| {});
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(
+            result,
+            Err(CoreError::Pdf(PdfError::NoReferencesSection))
+        ));
+    }
+}
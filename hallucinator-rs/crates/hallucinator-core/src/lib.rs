@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
@@ -7,19 +8,27 @@ use tokio_util::sync::CancellationToken;
 pub mod authors;
 pub mod cache;
 pub mod checker;
+pub mod client;
+pub mod context;
 pub mod db;
 pub mod doi;
+pub mod env;
+pub mod input;
 pub mod matching;
 pub mod orchestrator;
+pub mod orcid;
 pub mod pool;
 pub mod rate_limit;
 pub mod retraction;
+pub mod retraction_watch;
 
 // Re-export for convenience
 pub use cache::QueryCache;
+pub use context::CheckContext;
+pub use env::EnvConfig;
 pub use hallucinator_pdf::{ExtractionResult, Reference, SkipStats};
 pub use orchestrator::{DbSearchResult, query_all_databases};
-pub use rate_limit::{DbQueryError, RateLimitedResult, RateLimiters};
+pub use rate_limit::{DbQueryError, RateLimitState, RateLimitedResult, RateLimiters};
 
 /// Status of a single database query within an orchestrator run.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -38,9 +47,37 @@ pub struct DbResult {
     pub db_name: String,
     pub status: DbStatus,
     pub elapsed: Option<Duration>,
+    /// Title of the record the database returned, when it found one. For a
+    /// [`DbStatus::NoMatch`], a record it found but turned down, if it
+    /// reports one (the paper a cited DOI resolves to).
+    pub found_title: Option<String>,
     pub found_authors: Vec<String>,
     pub paper_url: Option<String>,
     pub error_message: Option<String>,
+    /// Other records from this database whose titles matched about as well as
+    /// the one reported. Non-empty means the match was ambiguous.
+    pub alternates: Vec<MatchCandidate>,
+    /// Title-similarity threshold this database's answer was held to, when
+    /// it answered.
+    pub threshold: Option<f64>,
+    /// Title similarity (0.0–1.0) between the reference and `found_title`.
+    pub score: Option<f64>,
+    /// Journal or venue of the matched record, from backends that report it.
+    pub venue: Option<String>,
+    /// Publication year of the matched record, from backends that report it.
+    pub year: Option<u32>,
+}
+
+/// A record returned by a database whose title matched the query.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MatchCandidate {
+    pub title: String,
+    pub authors: Vec<String>,
+    pub paper_url: Option<String>,
+    #[serde(default)]
+    pub venue: Option<String>,
+    #[serde(default)]
+    pub year: Option<u32>,
 }
 
 #[derive(Error, Debug)]
@@ -53,6 +90,8 @@ pub enum CoreError {
     Dblp(#[from] hallucinator_dblp::DblpError),
     #[error("ACL error: {0}")]
     Acl(#[from] hallucinator_acl::AclError),
+    #[error("BibTeX error: {0}")]
+    Bbl(#[from] hallucinator_bbl::BblError),
     #[error("validation error: {0}")]
     Validation(String),
 }
@@ -65,30 +104,236 @@ pub enum Status {
     AuthorMismatch,
 }
 
+/// Machine-readable explanation of why a reference was not verified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlagReason {
+    /// No database returned a record whose title matched.
+    NoMatchAnyDb,
+    /// A database matched the title, but the authors disagree.
+    AuthorsDiverged,
+    /// The cited DOI did not resolve, and no database matched the title
+    /// either.
+    DoiDidNotResolve,
+    /// The cited DOI resolves to a different paper, and no database matched
+    /// the title either.
+    DoiTitleMismatch,
+    /// In consensus mode, fewer databases than required matched the title.
+    NoConsensus,
+}
+
+impl FlagReason {
+    /// Derive the reason from a final status and the DOI lookup, if any.
+    ///
+    /// Verified references have no reason.
+    pub fn classify(status: &Status, doi_info: Option<&DoiInfo>) -> Option<Self> {
+        match status {
+            Status::Verified => None,
+            Status::AuthorMismatch => Some(Self::AuthorsDiverged),
+            Status::NotFound if doi_info.is_some_and(|d| !d.valid) => Some(Self::DoiDidNotResolve),
+            Status::NotFound if doi_info.is_some_and(|d| d.title_mismatch) => {
+                Some(Self::DoiTitleMismatch)
+            }
+            Status::NotFound => Some(Self::NoMatchAnyDb),
+        }
+    }
+
+    /// Human-readable description for detail views and reports.
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::NoMatchAnyDb => "No database has a paper with this title",
+            Self::AuthorsDiverged => "Title found, but the authors differ",
+            Self::DoiDidNotResolve => "Cited DOI does not resolve",
+            Self::DoiTitleMismatch => "Cited DOI resolves to a different paper",
+            Self::NoConsensus => "Too few databases agree on this title",
+        }
+    }
+
+    /// JSON-serializable string key.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::NoMatchAnyDb => "no_match_any_db",
+            Self::AuthorsDiverged => "authors_diverged",
+            Self::DoiDidNotResolve => "doi_did_not_resolve",
+            Self::DoiTitleMismatch => "doi_title_mismatch",
+            Self::NoConsensus => "no_consensus",
+        }
+    }
+}
+
+impl std::str::FromStr for FlagReason {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "no_match_any_db" => Ok(Self::NoMatchAnyDb),
+            "authors_diverged" => Ok(Self::AuthorsDiverged),
+            "doi_did_not_resolve" => Ok(Self::DoiDidNotResolve),
+            "doi_title_mismatch" => Ok(Self::DoiTitleMismatch),
+            "no_consensus" => Ok(Self::NoConsensus),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Why a database failed to answer a query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureReason {
+    /// No answer within the database's timeout.
+    Timeout,
+    /// The database kept answering 429 Too Many Requests.
+    RateLimited,
+    /// The request never reached the database (DNS, connection, TLS).
+    Network,
+    /// The database answered with an HTTP error status.
+    Http,
+    /// Anything else, such as a response that could not be parsed.
+    Other,
+}
+
+impl FailureReason {
+    /// Human-readable description for detail views and reports.
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Timeout => "timed out",
+            Self::RateLimited => "rate limited (429)",
+            Self::Network => "network error",
+            Self::Http => "HTTP error",
+            Self::Other => "error",
+        }
+    }
+
+    /// JSON-serializable string key.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Timeout => "timeout",
+            Self::RateLimited => "rate_limited",
+            Self::Network => "network",
+            Self::Http => "http",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl std::str::FromStr for FailureReason {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "timeout" => Ok(Self::Timeout),
+            "rate_limited" => Ok(Self::RateLimited),
+            "network" => Ok(Self::Network),
+            "http" => Ok(Self::Http),
+            "other" => Ok(Self::Other),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A database that failed to answer, and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailedDb {
+    pub name: String,
+    pub reason: FailureReason,
+}
+
+impl FailedDb {
+    pub fn new(name: impl Into<String>, reason: FailureReason) -> Self {
+        Self {
+            name: name.into(),
+            reason,
+        }
+    }
+}
+
+impl std::fmt::Display for FailedDb {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.name, self.reason.description())
+    }
+}
+
 /// Information about a DOI lookup.
 #[derive(Debug, Clone)]
 pub struct DoiInfo {
     pub doi: String,
+    /// Whether the DOI resolved at doi.org.
     pub valid: bool,
+    /// Title of the record the DOI resolved to.
     pub title: Option<String>,
+    /// Whether the DOI has a plausible `10.NNNN/suffix` shape
+    /// (see [`doi::looks_valid`]). Malformed DOIs are never sent to doi.org.
+    pub structurally_valid: bool,
+    /// The DOI resolved, but to a paper whose title strongly disagrees with
+    /// the citation's (see [`doi::doi_title_mismatch`]).
+    pub title_mismatch: bool,
+}
+
+impl DoiInfo {
+    /// Whether the DOI fails to back up the citation: it is malformed, does
+    /// not resolve, or resolves to a different paper.
+    pub fn has_issue(&self) -> bool {
+        !self.valid || self.title_mismatch
+    }
 }
 
 /// Information about an arXiv lookup.
 #[derive(Debug, Clone)]
 pub struct ArxivInfo {
+    /// Canonical id, without version (see [`hallucinator_pdf::identifiers::ArxivId`]).
     pub arxiv_id: String,
     pub valid: bool,
     pub title: Option<String>,
+    /// Version the citation pointed at (`v2` → `2`), if it gave one.
+    pub cited_version: Option<u32>,
+    /// Latest version arXiv reports, when the arXiv search found this id.
+    pub latest_version: Option<u32>,
+}
+
+impl ArxivInfo {
+    /// Whether the citation pins an older version than arXiv's latest.
+    /// The id still counts as valid; this is only worth a note.
+    pub fn newer_version_available(&self) -> bool {
+        matches!(
+            (self.cited_version, self.latest_version),
+            (Some(cited), Some(latest)) if latest > cited
+        )
+    }
+
+    /// "cites v1, latest is v3" when a newer version is available.
+    pub fn version_note(&self) -> Option<String> {
+        if !self.newer_version_available() {
+            return None;
+        }
+        Some(format!(
+            "cites v{}, latest is v{}",
+            self.cited_version?, self.latest_version?
+        ))
+    }
 }
 
 /// Information about a retraction check.
 #[derive(Debug, Clone)]
 pub struct RetractionInfo {
+    /// Whether the paper was retracted. Unset for an editorial notice that
+    /// stops short of a retraction, such as a Retraction Watch expression
+    /// of concern; `retraction_source` names the kind.
     pub is_retracted: bool,
     pub retraction_doi: Option<String>,
     pub retraction_source: Option<String>,
 }
 
+/// A match whose title passed fuzzy matching but differs from the cited
+/// one, as when a follow-up paper shares most of its title. Only reported
+/// with [`Config::strict_title`], and only as a note: see
+/// [`ValidationResult::title_near_miss`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TitleNearMiss {
+    pub cited_title: String,
+    pub found_title: String,
+    /// [`matching::title_similarity`] of the two, below
+    /// [`matching::STRICT_TITLE_THRESHOLD`].
+    pub similarity: f64,
+}
+
 /// The result of validating a single reference.
 #[derive(Debug, Clone)]
 pub struct ValidationResult {
@@ -96,14 +341,225 @@ pub struct ValidationResult {
     pub raw_citation: String,
     pub ref_authors: Vec<String>,
     pub status: Status,
+    /// Database the verdict rests on.
     pub source: Option<String>,
+    /// In consensus mode, every database that matched the title, `source`
+    /// first; empty otherwise.
+    pub agreeing_dbs: Vec<String>,
     pub found_authors: Vec<String>,
     pub paper_url: Option<String>,
-    pub failed_dbs: Vec<String>,
+    /// Databases that failed to answer, each with the reason.
+    pub failed_dbs: Vec<FailedDb>,
     pub db_results: Vec<DbResult>,
     pub doi_info: Option<DoiInfo>,
     pub arxiv_info: Option<ArxivInfo>,
     pub retraction_info: Option<RetractionInfo>,
+    /// Why the reference was flagged; `None` when verified.
+    pub reason: Option<FlagReason>,
+    /// Title similarity of the match the verdict rests on, from the
+    /// `source` database.
+    pub match_score: Option<f64>,
+    /// Set in strict title mode when the matched title is close to, but not
+    /// the same as, the cited one. Informational: the status, [`CheckStats`],
+    /// the paper verdict and exit codes are left as they are, for a reader
+    /// to judge. Not to be confused with [`DoiInfo::title_mismatch`], a cited
+    /// DOI that resolves to another paper.
+    pub title_near_miss: Option<TitleNearMiss>,
+    /// Journal or venue of the match the verdict rests on, when its database
+    /// reports one (PubMed, Europe PMC).
+    pub venue: Option<String>,
+    /// Publication year of the match the verdict rests on, when its database
+    /// reports one.
+    pub year: Option<u32>,
+    /// ORCID iD of the cited author who claims the matched work, when the
+    /// ORCID check ([`Config::orcid_check`]) turned an author mismatch into
+    /// a verified match.
+    pub orcid: Option<String>,
+}
+
+impl ValidationResult {
+    /// Names of the databases in `failed_dbs`, the ones a retry queries.
+    pub fn failed_db_names(&self) -> Vec<String> {
+        self.failed_dbs.iter().map(|f| f.name.clone()).collect()
+    }
+
+    /// Records that matched the title about as well as the reported match,
+    /// from the database that produced the verdict.
+    pub fn ambiguous_matches(&self) -> &[MatchCandidate] {
+        self.source
+            .as_deref()
+            .and_then(|source| {
+                self.db_results
+                    .iter()
+                    .find(|r| r.db_name == source && !r.alternates.is_empty())
+            })
+            .map(|r| r.alternates.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Whether the verdict rests on one of several equally good title matches,
+    /// and so deserves lower confidence.
+    pub fn is_ambiguous(&self) -> bool {
+        !self.ambiguous_matches().is_empty()
+    }
+
+    /// The answer of the database the verdict rests on.
+    pub fn verdict_db(&self) -> Option<&DbResult> {
+        let source = self.source.as_deref()?;
+        self.db_results.iter().find(|r| r.db_name == source)
+    }
+
+    /// How long the reference waited on its databases: the slowest query,
+    /// since they run side by side. `None` when no query was timed.
+    pub fn query_time(&self) -> Option<Duration> {
+        self.db_results.iter().filter_map(|r| r.elapsed).max()
+    }
+
+    /// The database whose query took longest.
+    pub fn slowest_db(&self) -> Option<&DbResult> {
+        self.db_results
+            .iter()
+            .filter(|r| r.elapsed.is_some())
+            .max_by_key(|r| r.elapsed)
+    }
+}
+
+#[cfg(test)]
+impl DbResult {
+    /// An answer from `db_name` with `status` and nothing else, for tests to
+    /// fill in the fields they check.
+    pub(crate) fn test(db_name: &str, status: DbStatus) -> Self {
+        Self {
+            db_name: db_name.into(),
+            status,
+            elapsed: None,
+            found_title: None,
+            found_authors: vec![],
+            paper_url: None,
+            error_message: None,
+            alternates: vec![],
+            threshold: None,
+            score: None,
+            venue: None,
+            year: None,
+        }
+    }
+}
+
+#[cfg(test)]
+impl ValidationResult {
+    /// A verified result for "A Paper" with every other field empty, for
+    /// tests to fill in the fields they check.
+    pub(crate) fn test_default() -> Self {
+        Self {
+            title: "A Paper".into(),
+            raw_citation: String::new(),
+            ref_authors: vec![],
+            status: Status::Verified,
+            source: None,
+            agreeing_dbs: vec![],
+            found_authors: vec![],
+            paper_url: None,
+            failed_dbs: vec![],
+            db_results: vec![],
+            doi_info: None,
+            arxiv_info: None,
+            retraction_info: None,
+            reason: None,
+            match_score: None,
+            title_near_miss: None,
+            venue: None,
+            year: None,
+            orcid: None,
+        }
+    }
+}
+
+/// [`FlagReason::classify`], telling a consensus that fell short (some
+/// database did match the title) apart from no match at all.
+pub(crate) fn flag_reason(
+    config: &Config,
+    status: &Status,
+    doi_info: Option<&DoiInfo>,
+    db_results: &[DbResult],
+) -> Option<FlagReason> {
+    let title_matched = db_results
+        .iter()
+        .any(|r| matches!(r.status, DbStatus::Match | DbStatus::AuthorMismatch));
+    if config.consensus_mode && *status == Status::NotFound && title_matched {
+        return Some(FlagReason::NoConsensus);
+    }
+    FlagReason::classify(status, doi_info)
+}
+
+/// Score of the `source` database's answer among `db_results`, for
+/// [`ValidationResult::match_score`].
+pub(crate) fn source_score(source: Option<&str>, db_results: &[DbResult]) -> Option<f64> {
+    let source = source?;
+    db_results
+        .iter()
+        .find(|r| r.db_name == source)
+        .and_then(|r| r.score)
+}
+
+/// Venue and year of the `source` database's answer among `db_results`, for
+/// [`ValidationResult::venue`] and [`ValidationResult::year`].
+pub(crate) fn source_publication(
+    source: Option<&str>,
+    db_results: &[DbResult],
+) -> (Option<String>, Option<u32>) {
+    let Some(source) = source else {
+        return (None, None);
+    };
+    db_results
+        .iter()
+        .find(|r| r.db_name == source)
+        .map(|r| (r.venue.clone(), r.year))
+        .unwrap_or_default()
+}
+
+/// The cited and matched titles of `result` when [`Config::strict_title`] is
+/// on and the title the verdict rests on scores below
+/// [`matching::STRICT_TITLE_THRESHOLD`].
+pub(crate) fn title_near_miss(config: &Config, result: &ValidationResult) -> Option<TitleNearMiss> {
+    if !config.strict_title || result.status == Status::NotFound {
+        return None;
+    }
+    let source = result.source.as_deref()?;
+    let found_title = result
+        .db_results
+        .iter()
+        .find(|r| r.db_name == source)?
+        .found_title
+        .as_deref()?;
+    let similarity = matching::title_similarity(&result.title, found_title);
+    (similarity < matching::STRICT_TITLE_THRESHOLD).then(|| TitleNearMiss {
+        cited_title: result.title.clone(),
+        found_title: found_title.to_string(),
+        similarity,
+    })
+}
+
+/// arXiv info for a reference that cites an arXiv id.
+///
+/// The id counts as valid when the arXiv search returned that same paper;
+/// its link carries the latest version, e.g. `arxiv.org/abs/2103.12345v3`.
+pub(crate) fn arxiv_info(reference: &Reference, db_results: &[DbResult]) -> Option<ArxivInfo> {
+    let arxiv_id = reference.arxiv_id.clone()?;
+    let found = db_results.iter().find_map(|r| {
+        if r.db_name != "arXiv" {
+            return None;
+        }
+        let found = hallucinator_pdf::identifiers::extract_arxiv(r.paper_url.as_deref()?)?;
+        (found.id == arxiv_id).then(|| (r.found_title.clone(), found.version))
+    });
+    Some(ArxivInfo {
+        valid: found.is_some(),
+        title: found.as_ref().and_then(|(title, _)| title.clone()),
+        cited_version: reference.arxiv_version,
+        latest_version: found.and_then(|(_, version)| version),
+        arxiv_id,
+    })
 }
 
 /// Progress events emitted during validation.
@@ -119,6 +575,14 @@ pub enum ProgressEvent {
         total: usize,
         result: Box<ValidationResult>,
     },
+    /// A reference with the same normalized title and authors as one already
+    /// looked up (see [`Config::dedup`]) takes that lookup's result; its `Result`
+    /// follows without a `Checking` of its own.
+    Duplicate {
+        index: usize,
+        total: usize,
+        title: String,
+    },
     Warning {
         index: usize,
         total: usize,
@@ -153,6 +617,25 @@ pub enum ProgressEvent {
         attempt: u32,
         backoff: Duration,
     },
+    /// A database answered 429 and the query is waiting `retry_after`
+    /// before trying again.
+    RateLimited {
+        db_name: String,
+        retry_after: Duration,
+        attempt: u32,
+    },
+    /// Every rate limiter's state, sent every
+    /// [`checker::RATE_LIMIT_STATUS_INTERVAL`] while any database is
+    /// throttled, and once more when the last one recovers.
+    RateLimitStatus {
+        limiters: Vec<RateLimitState>,
+    },
+    /// With [`Config::adaptive_concurrency`], the number of queries
+    /// `db_name` now runs at once, sent whenever its window changes.
+    ConcurrencyChanged {
+        db_name: String,
+        limit: usize,
+    },
 }
 
 /// Summary statistics for a complete check run.
@@ -164,6 +647,33 @@ pub struct CheckStats {
     pub author_mismatch: usize,
     pub retracted: usize,
     pub skipped: usize,
+    /// References answered by an earlier lookup of the same citation instead
+    /// of their own (see [`Config::dedup`]); callers count
+    /// [`ProgressEvent::Duplicate`] events.
+    pub duplicates: usize,
+}
+
+impl CheckStats {
+    /// Tally a run's results. `skipped` counts references that were never
+    /// checked (beyond `max_references`, past the deadline, or cancelled).
+    pub fn from_results(results: &[ValidationResult], skipped: usize) -> Self {
+        let mut stats = CheckStats {
+            total: results.len() + skipped,
+            skipped,
+            ..Default::default()
+        };
+        for r in results {
+            match r.status {
+                Status::Verified => stats.verified += 1,
+                Status::NotFound => stats.not_found += 1,
+                Status::AuthorMismatch => stats.author_mismatch += 1,
+            }
+            if r.retraction_info.as_ref().is_some_and(|ri| ri.is_retracted) {
+                stats.retracted += 1;
+            }
+        }
+        stats
+    }
 }
 
 /// Configuration for the reference checker.
@@ -171,22 +681,130 @@ pub struct CheckStats {
 pub struct Config {
     pub openalex_key: Option<String>,
     pub s2_api_key: Option<String>,
+    /// NCBI E-utilities API key; raises the PubMed rate limit from 3/s to 10/s.
+    pub ncbi_api_key: Option<String>,
+    /// CORE (core.ac.uk) API key; CORE is only queried when one is set.
+    pub core_api_key: Option<String>,
     pub dblp_offline_path: Option<PathBuf>,
     pub dblp_offline_db: Option<Arc<Mutex<hallucinator_dblp::DblpDatabase>>>,
+    /// Warn when the offline DBLP database was built at least this many
+    /// days ago.
+    pub dblp_max_age_days: u64,
     pub acl_offline_path: Option<PathBuf>,
     pub acl_offline_db: Option<Arc<Mutex<hallucinator_acl::AclDatabase>>>,
+    /// Local `.bib` library (e.g. a Zotero export) to check references
+    /// against, offline, before the public databases. Loaded once per
+    /// [`check_references`] run or [`ValidationPool`](pool::ValidationPool)
+    /// unless `local_bib` is already set.
+    pub local_bib_path: Option<PathBuf>,
+    pub local_bib: Option<Arc<db::bibtex::BibTexBackend>>,
+    /// Retraction Watch CSV to look matched DOIs up in before asking
+    /// CrossRef. Loaded once per [`check_references`] run unless
+    /// `retraction_db` is already set.
+    pub retraction_db_path: Option<PathBuf>,
+    pub retraction_db: Option<Arc<retraction_watch::RetractionWatch>>,
+    /// Warn when the Retraction Watch file is at least this many days old.
+    pub retraction_max_age_days: u64,
     pub num_workers: usize,
+    /// Papers a batch checks at once, all feeding references into one shared
+    /// [`pool::ValidationPool`], so a slow paper doesn't leave workers idle.
+    /// `num_workers` still bounds the references in flight. 0 means no limit.
+    pub max_concurrent_papers: usize,
+    /// Look up references with the same normalized title, cited surnames
+    /// and DOI once per pool (within a paper, and across the papers sharing a pool)
+    /// and hand the result to every occurrence.
+    pub dedup: bool,
+    /// Let each remote database run several queries at once, growing the
+    /// number while it answers without 429s and halving it when it does
+    /// (see [`rate_limit::ConcurrencyWindow`]). Off, each runs one at a time.
+    pub adaptive_concurrency: bool,
     pub db_timeout_secs: u64,
     pub db_timeout_short_secs: u64,
+    /// Timeout in seconds per database name, overriding `db_timeout_secs`
+    /// for slow backends (e.g. `"Europe PMC"`). The retry pass doubles an
+    /// override just as it doubles the global timeout; `db_timeout_short_secs`
+    /// is left as it is. See [`Config::db_timeout`].
+    pub db_timeouts: HashMap<String, u64>,
     pub disabled_dbs: Vec<String>,
+    /// When non-empty, query only these databases (by name, ignoring case),
+    /// and ignore `disabled_dbs`. See [`db::DATABASE_NAMES`].
+    pub only_dbs: Vec<String>,
     pub check_openalex_authors: bool,
+    /// Fraction of cited authors (0.0–1.0) that must appear in a matched
+    /// record's author list for it to verify. At least one always has to;
+    /// the default 0.0 asks for nothing more.
+    pub author_match_min_overlap: f32,
+    /// Flag an author mismatch unless the first cited author is among the
+    /// matched record's authors.
+    pub require_first_author_match: bool,
+    /// Similarity (0.0–1.0) a cited surname needs with a matched record's
+    /// surname to count as the same author, after case, diacritics and
+    /// hyphenation are normalized away. 1.0 disables the fuzzy comparison.
+    pub author_match_threshold: f64,
+    /// Title similarity (0.0–1.0) a database match must reach to count.
+    /// Matches that pass the per-database threshold but score below this are
+    /// treated as not found. The default 0.0 adds no requirement.
+    pub min_match_score: f64,
+    /// Query every enabled database instead of stopping at the first match,
+    /// and verify only when at least `consensus_min` of them match the title.
+    pub consensus_mode: bool,
+    /// Databases that must agree on the title in consensus mode.
+    pub consensus_min: usize,
+    /// Note matches whose title differs from the cited one by more than
+    /// [`matching::STRICT_TITLE_THRESHOLD`] allows, in
+    /// [`ValidationResult::title_near_miss`]. The verdict is unaffected.
+    pub strict_title: bool,
+    /// Rank author mismatches as failures, at the same severity as
+    /// references that were not found: the CLI's exit status and the
+    /// level of SARIF results. The [`Status::AuthorMismatch`] verdict itself
+    /// is kept.
+    pub author_mismatch_is_failure: bool,
+    /// Before settling on an author mismatch, look up who claims the matched
+    /// work on ORCID; if cited authors who are among them, together with
+    /// those the database listed, satisfy [`Config::author_policy`], the
+    /// reference is verified and [`ValidationResult::orcid`] records the iD.
+    pub orcid_check: bool,
+    /// Contact email for CrossRef's polite pool, and the one Unpaywall
+    /// requires on every request. When set, verified references with a DOI
+    /// also link Unpaywall's open-access copy, if there is one (see
+    /// [`db::unpaywall`]).
     pub crossref_mailto: Option<String>,
+    /// Custom User-Agent sent with every API request. When unset, a default
+    /// `hallucinator/<version>` agent is used (see [`Config::user_agent`]).
+    pub user_agent: Option<String>,
+    /// Proxy URL for all API requests (e.g. `http://proxy.corp:3128`). When
+    /// unset, the standard `HTTPS_PROXY`/`HTTP_PROXY`/`NO_PROXY` variables apply.
+    pub proxy: Option<String>,
+    /// PEM file with extra root certificates to trust, for proxies or
+    /// networks that re-sign TLS with an internal CA.
+    pub extra_ca_cert: Option<PathBuf>,
+    /// Title-similarity threshold per database name, overriding that
+    /// database's default (0.95; offline DBLP 0.90, offline ACL 0.95).
+    /// Values below [`matching::MIN_TITLE_THRESHOLD`] act as that minimum.
+    pub per_db_threshold: HashMap<String, f64>,
     pub max_rate_limit_retries: u32,
+    /// After the main pass, re-query the databases that failed for
+    /// references still not found, up to this many times. Each pass after
+    /// the first waits longer (see [`checker::RETRY_PASS_BACKOFF`]).
+    /// `0` disables retrying.
+    pub max_retry_passes: usize,
+    /// Validate at most this many references per paper; the rest are
+    /// counted as skipped. Guards against bibliography-stuffed or badly
+    /// segmented PDFs. `None` means no limit.
+    pub max_references: Option<usize>,
+    /// Stop dispatching references once a run has taken this long and return
+    /// what has been checked so far; the rest are counted as skipped.
+    /// `None` means no deadline.
+    pub run_deadline: Option<Duration>,
     pub rate_limiters: Arc<RateLimiters>,
     pub query_cache: Option<Arc<QueryCache>>,
     /// Path to the persistent SQLite cache database (optional).
     /// When set, the query cache is backed by SQLite for persistence across restarts.
     pub cache_path: Option<PathBuf>,
+    /// Most entries the query cache keeps in memory (0 = unbounded). Past
+    /// it, the least recently used are evicted; a persistent cache keeps
+    /// them on disk.
+    pub cache_max_entries: usize,
 }
 
 impl std::fmt::Debug for Config {
@@ -194,31 +812,70 @@ impl std::fmt::Debug for Config {
         f.debug_struct("Config")
             .field("openalex_key", &self.openalex_key.as_ref().map(|_| "***"))
             .field("s2_api_key", &self.s2_api_key.as_ref().map(|_| "***"))
+            .field("ncbi_api_key", &self.ncbi_api_key.as_ref().map(|_| "***"))
+            .field("core_api_key", &self.core_api_key.as_ref().map(|_| "***"))
             .field("dblp_offline_path", &self.dblp_offline_path)
             .field(
                 "dblp_offline_db",
                 &self.dblp_offline_db.as_ref().map(|_| "<open>"),
             )
+            .field("dblp_max_age_days", &self.dblp_max_age_days)
             .field("acl_offline_path", &self.acl_offline_path)
             .field(
                 "acl_offline_db",
                 &self.acl_offline_db.as_ref().map(|_| "<open>"),
             )
+            .field("local_bib_path", &self.local_bib_path)
+            .field("local_bib", &self.local_bib.as_ref().map(|_| "<loaded>"))
+            .field("retraction_db_path", &self.retraction_db_path)
+            .field(
+                "retraction_db",
+                &self.retraction_db.as_ref().map(|_| "<loaded>"),
+            )
+            .field("retraction_max_age_days", &self.retraction_max_age_days)
             .field("num_workers", &self.num_workers)
+            .field("max_concurrent_papers", &self.max_concurrent_papers)
+            .field("dedup", &self.dedup)
+            .field("adaptive_concurrency", &self.adaptive_concurrency)
             .field("db_timeout_secs", &self.db_timeout_secs)
             .field("db_timeout_short_secs", &self.db_timeout_short_secs)
+            .field("db_timeouts", &self.db_timeouts)
             .field("disabled_dbs", &self.disabled_dbs)
+            .field("only_dbs", &self.only_dbs)
             .field("check_openalex_authors", &self.check_openalex_authors)
+            .field("author_match_min_overlap", &self.author_match_min_overlap)
+            .field(
+                "require_first_author_match",
+                &self.require_first_author_match,
+            )
+            .field("author_match_threshold", &self.author_match_threshold)
+            .field("min_match_score", &self.min_match_score)
+            .field("consensus_mode", &self.consensus_mode)
+            .field("consensus_min", &self.consensus_min)
+            .field("strict_title", &self.strict_title)
+            .field(
+                "author_mismatch_is_failure",
+                &self.author_mismatch_is_failure,
+            )
+            .field("orcid_check", &self.orcid_check)
             .field(
                 "crossref_mailto",
                 &self.crossref_mailto.as_ref().map(|_| "***"),
             )
+            .field("user_agent", &self.user_agent)
+            .field("proxy", &self.proxy)
+            .field("extra_ca_cert", &self.extra_ca_cert)
+            .field("per_db_threshold", &self.per_db_threshold)
             .field("max_rate_limit_retries", &self.max_rate_limit_retries)
+            .field("max_retry_passes", &self.max_retry_passes)
+            .field("max_references", &self.max_references)
+            .field("run_deadline", &self.run_deadline)
             .field(
                 "query_cache",
                 &self.query_cache.as_ref().map(|c| format!("{:?}", c)),
             )
             .field("cache_path", &self.cache_path)
+            .field("cache_max_entries", &self.cache_max_entries)
             .finish()
     }
 }
@@ -228,29 +885,122 @@ impl Default for Config {
         Self {
             openalex_key: None,
             s2_api_key: None,
+            ncbi_api_key: None,
+            core_api_key: None,
             dblp_offline_path: None,
             dblp_offline_db: None,
+            dblp_max_age_days: hallucinator_dblp::DEFAULT_MAX_AGE_DAYS,
             acl_offline_path: None,
             acl_offline_db: None,
+            local_bib_path: None,
+            local_bib: None,
+            retraction_db_path: None,
+            retraction_db: None,
+            retraction_max_age_days: retraction_watch::DEFAULT_MAX_AGE_DAYS,
             num_workers: 4,
+            max_concurrent_papers: pool::DEFAULT_MAX_CONCURRENT_PAPERS,
+            dedup: false,
+            adaptive_concurrency: false,
             db_timeout_secs: 10,
             db_timeout_short_secs: 5,
+            db_timeouts: HashMap::new(),
             disabled_dbs: vec![],
+            only_dbs: vec![],
             check_openalex_authors: false,
+            author_match_min_overlap: 0.0,
+            require_first_author_match: false,
+            author_match_threshold: authors::DEFAULT_AUTHOR_MATCH_THRESHOLD,
+            min_match_score: 0.0,
+            consensus_mode: false,
+            consensus_min: orchestrator::DEFAULT_CONSENSUS_MIN,
+            strict_title: false,
+            author_mismatch_is_failure: false,
+            orcid_check: false,
             crossref_mailto: None,
+            user_agent: None,
+            proxy: None,
+            extra_ca_cert: None,
+            per_db_threshold: HashMap::new(),
             max_rate_limit_retries: 3,
+            max_retry_passes: 1,
+            max_references: None,
+            run_deadline: None,
             rate_limiters: Arc::new(RateLimiters::default()),
             query_cache: Some(Arc::new(QueryCache::default())),
             cache_path: None,
+            cache_max_entries: cache::DEFAULT_MAX_ENTRIES,
+        }
+    }
+}
+
+impl Config {
+    /// The User-Agent string to send with API requests.
+    ///
+    /// Uses the custom `user_agent` if set, otherwise `hallucinator/<version>`.
+    /// When a CrossRef mailto is configured it is appended as
+    /// `(mailto:...)`, which is what CrossRef's polite pool looks for.
+    pub fn user_agent(&self) -> String {
+        let base = self
+            .user_agent
+            .clone()
+            .unwrap_or_else(|| format!("hallucinator/{}", env!("CARGO_PKG_VERSION")));
+        match self.crossref_mailto {
+            Some(ref email) if !base.contains("mailto:") => format!("{} (mailto:{})", base, email),
+            _ => base,
+        }
+    }
+
+    /// How closely matched authors must agree with the cited ones.
+    pub fn author_policy(&self) -> authors::AuthorMatchPolicy {
+        authors::AuthorMatchPolicy {
+            min_overlap: self.author_match_min_overlap,
+            require_first_author: self.require_first_author_match,
+            surname_threshold: self.author_match_threshold,
+        }
+    }
+
+    /// The query timeout for the database named `db`: its entry in
+    /// `db_timeouts`, or `db_timeout_secs` when it has none.
+    pub fn db_timeout(&self, db: &str) -> Duration {
+        Duration::from_secs(
+            self.db_timeouts
+                .get(db)
+                .copied()
+                .unwrap_or(self.db_timeout_secs),
+        )
+    }
+
+    /// Whether the database named `name` is enabled: listed in `only_dbs`
+    /// when that is non-empty, and otherwise not in `disabled_dbs`.
+    pub fn db_enabled(&self, name: &str) -> bool {
+        if self.only_dbs.is_empty() {
+            !self
+                .disabled_dbs
+                .iter()
+                .any(|d| d.eq_ignore_ascii_case(name))
+        } else {
+            self.only_dbs.iter().any(|d| d.eq_ignore_ascii_case(name))
         }
     }
+
+    /// The title-similarity threshold to hold `db`'s matches to.
+    pub fn title_threshold(&self, db: &dyn db::DatabaseBackend) -> f64 {
+        self.per_db_threshold
+            .get(db.name())
+            .map(|t| t.clamp(matching::MIN_TITLE_THRESHOLD, 1.0))
+            .unwrap_or_else(|| db.default_title_threshold())
+    }
 }
 
 /// Build a [`QueryCache`] from configuration.
 ///
 /// If `cache_path` is set, opens a persistent SQLite-backed cache.
-/// Otherwise, returns an in-memory-only cache.
-pub fn build_query_cache(cache_path: Option<&std::path::Path>) -> Arc<QueryCache> {
+/// Otherwise, returns an in-memory-only cache. Either way the in-memory tier
+/// holds at most `max_entries` (0 = unbounded; see [`Config::cache_max_entries`]).
+pub fn build_query_cache(
+    cache_path: Option<&std::path::Path>,
+    max_entries: usize,
+) -> Arc<QueryCache> {
     if let Some(path) = cache_path {
         // Ensure parent directory exists
         if let Some(parent) = path.parent() {
@@ -260,6 +1010,7 @@ pub fn build_query_cache(cache_path: Option<&std::path::Path>) -> Arc<QueryCache
             path,
             std::time::Duration::from_secs(7 * 24 * 60 * 60),
             std::time::Duration::from_secs(24 * 60 * 60),
+            max_entries,
         ) {
             Ok(cache) => {
                 log::info!("Opened persistent cache at {}", path.display());
@@ -274,7 +1025,138 @@ pub fn build_query_cache(cache_path: Option<&std::path::Path>) -> Arc<QueryCache
             }
         }
     }
-    Arc::new(QueryCache::default())
+    Arc::new(QueryCache::new(
+        std::time::Duration::from_secs(7 * 24 * 60 * 60),
+        std::time::Duration::from_secs(24 * 60 * 60),
+        max_entries,
+    ))
+}
+
+/// Check a list of references against academic databases.
+///
+/// Validates each reference concurrently, querying multiple databases in parallel.
+/// Progress events are emitted via the callback. The operation can be cancelled
+/// via the CancellationToken.
+pub async fn check_references(
+    refs: Vec<Reference>,
+    config: Config,
+    progress: impl Fn(ProgressEvent) + Send + Sync + 'static,
+    cancel: CancellationToken,
+) -> Vec<ValidationResult> {
+    checker::check_references(refs, config, progress, cancel).await
+}
+
+/// Check a list of references using a shared [`CheckContext`].
+///
+/// Construct the context once (e.g. with [`CheckContext::new`]) and pass it to
+/// every call in a batch so that the query cache, rate-limit backoff and HTTP
+/// connection pool are shared across papers.
+pub async fn check_references_with_context(
+    refs: Vec<Reference>,
+    config: Config,
+    context: CheckContext,
+    progress: impl Fn(ProgressEvent) + Send + Sync + 'static,
+    cancel: CancellationToken,
+) -> Vec<ValidationResult> {
+    checker::check_references_with_context(refs, config, context, progress, cancel).await
+}
+
+/// Check the references of several files through one validation pool.
+///
+/// The files share workers and, with [`Config::dedup`], lookups. Progress
+/// events come tagged with the index of their file, and results come back
+/// per file. See [`checker::check_files_with_context`].
+pub async fn check_files_with_context(
+    files: Vec<Vec<Reference>>,
+    config: Config,
+    context: CheckContext,
+    progress: impl Fn(usize, ProgressEvent) + Send + Sync + 'static,
+    cancel: CancellationToken,
+) -> Vec<Vec<ValidationResult>> {
+    checker::check_files_with_context(files, config, context, progress, cancel).await
+}
+
+/// Check one already-parsed reference against all enabled databases.
+///
+/// [`check_references`] is the usual entry point; this is for embedders
+/// (an editor or LaTeX build plugin, say) that want to validate citations
+/// one at a time under their own concurrency control. The result is the
+/// same one [`check_references`] would produce for `reference`, without
+/// progress events, retry passes or cancellation.
+///
+/// The rate limiters and query cache belong to `config` (and are shared by
+/// its clones), so a caller validating in a loop should reuse one `Config`
+/// and one `client` rather than building them per call; otherwise every
+/// call starts with fresh limiters and the databases' rate limits are no
+/// longer respected. [`CheckContext::new`] followed by
+/// [`CheckContext::apply_to`] gives a config and client set up this way.
+///
+/// References carrying a [`Reference::skip_reason`] are checked anyway;
+/// filter them out first if that is not wanted. Likewise a
+/// [`Config::local_bib_path`] is only searched once loaded into
+/// [`Config::local_bib`], e.g. with [`checker::load_local_bib`].
+pub async fn check_single_reference(
+    reference: &Reference,
+    config: &Config,
+    client: &reqwest::Client,
+) -> ValidationResult {
+    checker::check_single_reference(reference, config, client, false, None, None).await
+}
+
+/// Validate a single citation string.
+///
+/// Parses `citation` with the default PDF reference parser (title, authors,
+/// DOI, arXiv ID) and runs the full check against all enabled databases.
+/// The query cache and rate limiters are taken from `config`, so repeated
+/// calls with the same (cloned) config share them.
+///
+/// Returns [`CoreError::Validation`] if no checkable title can be parsed
+/// from the citation.
+pub async fn validate_one(citation: &str, config: &Config) -> Result<ValidationResult, CoreError> {
+    let context = CheckContext::from_config(config);
+    validate_one_with_context(citation, config, &context).await
+}
+
+/// Like [`validate_one`], but uses the cache, rate limiters and HTTP client
+/// from `context`. Use this when validating many citations in a row.
+pub async fn validate_one_with_context(
+    citation: &str,
+    config: &Config,
+    context: &CheckContext,
+) -> Result<ValidationResult, CoreError> {
+    let reference = parse_citation(citation)?;
+    let mut config = config.clone();
+    context.apply_to(&mut config);
+    Ok(
+        checker::check_single_reference(&reference, &config, &context.client, false, None, None)
+            .await,
+    )
+}
+
+/// Parse a single citation string into a checkable [`Reference`].
+fn parse_citation(citation: &str) -> Result<Reference, CoreError> {
+    use hallucinator_pdf::extractor::{ParsedRef, SkipReason};
+
+    let citation = citation.trim();
+    if citation.is_empty() {
+        return Err(CoreError::Validation("empty citation".into()));
+    }
+    match hallucinator_pdf::PdfExtractor::new().parse_reference(citation, &[]) {
+        ParsedRef::Ref(mut reference) if reference.title.is_some() => {
+            reference.original_number = 1;
+            Ok(reference)
+        }
+        ParsedRef::Ref(_)
+        | ParsedRef::Skip(SkipReason::NoTitle | SkipReason::UnresolvedFootnote, _, _) => Err(
+            CoreError::Validation("could not extract a title from the citation".into()),
+        ),
+        ParsedRef::Skip(SkipReason::UrlOnly, _, _) => Err(CoreError::Validation(
+            "citation only references a non-academic URL".into(),
+        )),
+        ParsedRef::Skip(SkipReason::ShortTitle, _, _) => Err(CoreError::Validation(
+            "citation title is too short to check reliably".into(),
+        )),
+    }
 }
 
 #[cfg(test)]
@@ -297,16 +1179,22 @@ mod build_cache_tests {
 
     #[test]
     fn none_path_returns_in_memory() {
-        let cache = build_query_cache(None);
+        let cache = build_query_cache(None, cache::DEFAULT_MAX_ENTRIES);
         assert!(!cache.has_persistence());
     }
 
+    #[test]
+    fn max_entries_reaches_the_cache() {
+        assert_eq!(build_query_cache(None, 500).max_entries(), 500);
+        assert_eq!(build_query_cache(None, 0).max_entries(), 0);
+    }
+
     #[test]
     fn valid_path_returns_persistent() {
         let path = temp_path();
         let _ = std::fs::remove_file(&path);
 
-        let cache = build_query_cache(Some(&path));
+        let cache = build_query_cache(Some(&path), cache::DEFAULT_MAX_ENTRIES);
         assert!(cache.has_persistence());
 
         // Verify default TTLs (7 days positive, 24 hours negative)
@@ -330,7 +1218,7 @@ mod build_cache_tests {
             let _ = std::fs::remove_dir_all(parent);
         }
 
-        let cache = build_query_cache(Some(&path));
+        let cache = build_query_cache(Some(&path), cache::DEFAULT_MAX_ENTRIES);
         assert!(cache.has_persistence());
         assert!(path.parent().unwrap().exists());
 
@@ -338,16 +1226,378 @@ mod build_cache_tests {
     }
 }
 
-/// Check a list of references against academic databases.
-///
-/// Validates each reference concurrently, querying multiple databases in parallel.
-/// Progress events are emitted via the callback. The operation can be cancelled
-/// via the CancellationToken.
-pub async fn check_references(
-    refs: Vec<Reference>,
-    config: Config,
-    progress: impl Fn(ProgressEvent) + Send + Sync + 'static,
-    cancel: CancellationToken,
-) -> Vec<ValidationResult> {
-    checker::check_references(refs, config, progress, cancel).await
+#[cfg(test)]
+mod timing_tests {
+    use super::*;
+
+    fn db(name: &str, status: DbStatus, millis: Option<u64>) -> DbResult {
+        DbResult {
+            elapsed: millis.map(Duration::from_millis),
+            ..DbResult::test(name, status)
+        }
+    }
+
+    fn result(source: Option<&str>, db_results: Vec<DbResult>) -> ValidationResult {
+        ValidationResult {
+            source: source.map(String::from),
+            db_results,
+            ..ValidationResult::test_default()
+        }
+    }
+
+    #[test]
+    fn verdict_from_the_slowest_database() {
+        let result = result(
+            Some("OpenAlex"),
+            vec![
+                db("CrossRef", DbStatus::Match, Some(300)),
+                db("arXiv", DbStatus::NoMatch, Some(120)),
+                db("OpenAlex", DbStatus::Match, Some(4200)),
+                db("DBLP", DbStatus::Skipped, None),
+            ],
+        );
+        assert_eq!(result.verdict_db().unwrap().db_name, "OpenAlex");
+        assert_eq!(result.slowest_db().unwrap().db_name, "OpenAlex");
+        assert_eq!(result.query_time(), Some(Duration::from_millis(4200)));
+    }
+
+    #[test]
+    fn no_verdict_or_timings() {
+        let result = result(None, vec![db("DBLP", DbStatus::Skipped, None)]);
+        assert!(result.verdict_db().is_none());
+        assert!(result.slowest_db().is_none());
+        assert_eq!(result.query_time(), None);
+    }
+}
+
+#[cfg(test)]
+mod user_agent_tests {
+    use super::*;
+
+    #[test]
+    fn default_user_agent_has_version() {
+        let config = Config::default();
+        assert_eq!(
+            config.user_agent(),
+            format!("hallucinator/{}", env!("CARGO_PKG_VERSION"))
+        );
+    }
+
+    #[test]
+    fn custom_user_agent_with_mailto() {
+        let config = Config {
+            user_agent: Some("MyLab-Checker/2.0".into()),
+            crossref_mailto: Some("lab@example.org".into()),
+            ..Config::default()
+        };
+        assert_eq!(
+            config.user_agent(),
+            "MyLab-Checker/2.0 (mailto:lab@example.org)"
+        );
+    }
+
+    #[test]
+    fn mailto_not_duplicated() {
+        let config = Config {
+            user_agent: Some("Tool/1.0 (mailto:a@b.org)".into()),
+            crossref_mailto: Some("a@b.org".into()),
+            ..Config::default()
+        };
+        assert_eq!(config.user_agent(), "Tool/1.0 (mailto:a@b.org)");
+    }
+}
+
+#[cfg(test)]
+mod validate_one_tests {
+    use super::*;
+
+    #[test]
+    fn parse_citation_extracts_fields() {
+        let reference = parse_citation(
+            r#"J. Smith, A. Jones, and C. Williams, "Detecting Fake References in Academic Papers," in Proc. IEEE Conf., 2023. doi: 10.1145/3133956.3134093"#,
+        )
+        .unwrap();
+        assert!(
+            reference
+                .title
+                .as_deref()
+                .unwrap()
+                .contains("Detecting Fake References")
+        );
+        assert!(!reference.authors.is_empty());
+        assert_eq!(reference.doi.as_deref(), Some("10.1145/3133956.3134093"));
+        assert_eq!(reference.original_number, 1);
+    }
+
+    #[test]
+    fn parse_citation_rejects_empty() {
+        assert!(matches!(
+            parse_citation("   "),
+            Err(CoreError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn parse_citation_rejects_url_only() {
+        assert!(matches!(
+            parse_citation(
+                "See https://github.com/some/repo for details about the implementation."
+            ),
+            Err(CoreError::Validation(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn validate_one_with_all_dbs_disabled_is_not_found() {
+        let config = Config {
+            disabled_dbs: [
+                "CrossRef",
+                "arXiv",
+                "DBLP",
+                "Semantic Scholar",
+                "ACL Anthology",
+                "NeurIPS",
+                "Europe PMC",
+                "PubMed",
+                "DataCite",
+                "OpenAlex",
+                "SSRN",
+                "DOI",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+            ..Config::default()
+        };
+        let result = validate_one(
+            r#"J. Smith and A. Jones, "Detecting Fake References in Academic Papers," in Proc. IEEE Conf., 2023."#,
+            &config,
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.status, Status::NotFound);
+    }
+
+    #[tokio::test]
+    async fn check_single_reference_with_all_dbs_disabled_is_not_found() {
+        let config = Config {
+            disabled_dbs: [
+                "CrossRef",
+                "arXiv",
+                "DBLP",
+                "Semantic Scholar",
+                "ACL Anthology",
+                "NeurIPS",
+                "Europe PMC",
+                "PubMed",
+                "DataCite",
+                "OpenAlex",
+                "SSRN",
+                "DOI",
+            ]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+            ..Config::default()
+        };
+        let reference = parse_citation(
+            r#"J. Smith and A. Jones, "Detecting Fake References in Academic Papers," in Proc. IEEE Conf., 2023."#,
+        )
+        .unwrap();
+        let client = reqwest::Client::new();
+        let result = check_single_reference(&reference, &config, &client).await;
+        assert_eq!(result.status, Status::NotFound);
+        assert_eq!(result.ref_authors, reference.authors);
+    }
+}
+
+#[cfg(test)]
+mod arxiv_info_tests {
+    use super::*;
+
+    fn arxiv_hit(paper_url: &str) -> DbResult {
+        DbResult {
+            found_title: Some("Attention Is All You Need".into()),
+            paper_url: Some(paper_url.into()),
+            ..DbResult::test("arXiv", DbStatus::Match)
+        }
+    }
+
+    fn cited(citation: &str) -> Reference {
+        parse_citation(citation).unwrap()
+    }
+
+    #[test]
+    fn older_cited_version_is_valid_with_note() {
+        let reference =
+            cited("A. Vaswani et al. Attention is all you need. arXiv:1706.03762v1, 2017.");
+        let info = arxiv_info(
+            &reference,
+            &[arxiv_hit("http://arxiv.org/abs/1706.03762v7")],
+        )
+        .unwrap();
+        assert!(info.valid);
+        assert_eq!(info.cited_version, Some(1));
+        assert_eq!(info.latest_version, Some(7));
+        assert!(info.newer_version_available());
+        assert_eq!(
+            info.version_note().as_deref(),
+            Some("cites v1, latest is v7")
+        );
+    }
+
+    #[test]
+    fn old_style_id_matches_canonical_link() {
+        let reference = cited(
+            "W. Thurston. Three-dimensional manifolds and geometry. arXiv:math.GT/0309136v1, 2003.",
+        );
+        let info = arxiv_info(
+            &reference,
+            &[arxiv_hit("http://arxiv.org/abs/math/0309136v1")],
+        )
+        .unwrap();
+        assert!(info.valid);
+        assert!(!info.newer_version_available());
+        assert_eq!(info.version_note(), None);
+    }
+
+    #[test]
+    fn other_paper_leaves_id_unconfirmed() {
+        let reference =
+            cited("A. Vaswani et al. Attention is all you need. arXiv:1706.03762, 2017.");
+        let info = arxiv_info(
+            &reference,
+            &[arxiv_hit("http://arxiv.org/abs/2103.12345v2")],
+        )
+        .unwrap();
+        assert!(!info.valid);
+        assert_eq!(info.latest_version, None);
+    }
+}
+
+#[cfg(test)]
+mod consensus_tests {
+    use super::*;
+
+    fn crossref(score: f64) -> DbResult {
+        DbResult {
+            found_title: Some("Attention Is All You Need".into()),
+            score: Some(score),
+            ..DbResult::test("CrossRef", DbStatus::Match)
+        }
+    }
+
+    #[test]
+    fn short_consensus_is_its_own_reason() {
+        let config = Config {
+            consensus_mode: true,
+            ..Config::default()
+        };
+        let db_results = [crossref(0.98)];
+        assert_eq!(
+            flag_reason(&config, &Status::NotFound, None, &db_results),
+            Some(FlagReason::NoConsensus)
+        );
+        assert_eq!(
+            flag_reason(&config, &Status::NotFound, None, &[]),
+            Some(FlagReason::NoMatchAnyDb)
+        );
+        assert_eq!(
+            flag_reason(&Config::default(), &Status::NotFound, None, &db_results),
+            Some(FlagReason::NoMatchAnyDb)
+        );
+    }
+
+    #[test]
+    fn match_score_comes_from_the_leading_source() {
+        let mut dblp = crossref(0.91);
+        dblp.db_name = "DBLP".into();
+        let db_results = [crossref(0.98), dblp];
+        assert_eq!(source_score(Some("DBLP"), &db_results), Some(0.91));
+        assert_eq!(source_score(Some("CrossRef"), &db_results), Some(0.98));
+    }
+}
+
+#[cfg(test)]
+mod strict_title_tests {
+    use super::*;
+
+    fn verified(cited: &str, found: &str) -> ValidationResult {
+        ValidationResult {
+            title: cited.into(),
+            source: Some("DBLP".into()),
+            db_results: vec![DbResult {
+                found_title: Some(found.into()),
+                ..DbResult::test("DBLP", DbStatus::Match)
+            }],
+            ..ValidationResult::test_default()
+        }
+    }
+
+    #[test]
+    fn near_miss_is_flagged_only_in_strict_mode() {
+        let strict = Config {
+            strict_title: true,
+            ..Config::default()
+        };
+        let result = verified("Attention Is All You Need", "Attention Is Not All You Need");
+
+        let near_miss = title_near_miss(&strict, &result).unwrap();
+        assert_eq!(near_miss.cited_title, "Attention Is All You Need");
+        assert_eq!(near_miss.found_title, "Attention Is Not All You Need");
+        assert!(near_miss.similarity < matching::STRICT_TITLE_THRESHOLD);
+
+        assert_eq!(title_near_miss(&Config::default(), &result), None);
+    }
+
+    #[test]
+    fn same_title_in_other_case_is_not_a_near_miss() {
+        let strict = Config {
+            strict_title: true,
+            ..Config::default()
+        };
+        let result = verified("Attention is all you need.", "Attention Is All You Need");
+        assert_eq!(title_near_miss(&strict, &result), None);
+
+        let mut not_found = verified("Attention Is All You Need", "Something Else");
+        not_found.status = Status::NotFound;
+        assert_eq!(title_near_miss(&strict, &not_found), None);
+    }
+}
+
+#[cfg(test)]
+mod doi_reason_tests {
+    use super::*;
+
+    fn doi_info(valid: bool, title_mismatch: bool) -> DoiInfo {
+        DoiInfo {
+            doi: "10.1000/x.1".into(),
+            valid,
+            title: valid.then(|| "Another Paper".into()),
+            structurally_valid: true,
+            title_mismatch,
+        }
+    }
+
+    #[test]
+    fn unresolved_and_mismatched_dois_are_told_apart() {
+        let unresolved = doi_info(false, false);
+        let elsewhere = doi_info(true, true);
+        assert_eq!(
+            FlagReason::classify(&Status::NotFound, Some(&unresolved)),
+            Some(FlagReason::DoiDidNotResolve)
+        );
+        assert_eq!(
+            FlagReason::classify(&Status::NotFound, Some(&elsewhere)),
+            Some(FlagReason::DoiTitleMismatch)
+        );
+        assert_eq!(
+            "doi_title_mismatch".parse(),
+            Ok(FlagReason::DoiTitleMismatch)
+        );
+        assert_eq!(
+            FlagReason::classify(&Status::NotFound, Some(&doi_info(true, false))),
+            Some(FlagReason::NoMatchAnyDb)
+        );
+    }
 }
@@ -120,17 +120,101 @@ fn fix_separated_diacritics(title: &str) -> String {
         .to_string()
 }
 
+/// Markup tags, such as the MathML (`<mml:mi>`) and `<i>`/`<sub>` tags
+/// Crossref leaves in titles.
+static MARKUP_TAG_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"</?[A-Za-z][\w:.-]*(\s[^<>]*)?/?>").unwrap());
+
+/// Numeric character references, e.g. `&#x2062;` or `&#955;`.
+static NUMERIC_ENTITY_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"&#([xX][0-9A-Fa-f]+|[0-9]+);").unwrap());
+
+/// A LaTeX command: a backslash and a name, or a backslash and one symbol.
+static LATEX_COMMAND_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\\([A-Za-z]+|[^A-Za-z])").unwrap());
+
+/// Replace a LaTeX command name by its plain approximation. Formatting and
+/// accent commands vanish, leaving their (unwrapped) argument; symbols spell
+/// out as their Unicode counterparts do in [`normalize_title`]; anything else,
+/// e.g. `\log` or `\alpha`, keeps its name.
+fn latex_command_text(name: &str) -> &str {
+    match name {
+        "textit" | "textbf" | "textrm" | "textsf" | "texttt" | "textsc" | "textup" | "textsl"
+        | "textnormal" | "textsuperscript" | "textsubscript" | "text" | "emph" | "em" | "it"
+        | "bf" | "rm" | "sf" | "tt" | "sc" | "mathcal" | "mathbb" | "mathbf" | "mathrm"
+        | "mathit" | "mathsf" | "mathtt" | "mathfrak" | "mathscr" | "mathnormal" | "boldsymbol"
+        | "bm" | "operatorname" | "mbox" | "hbox" | "underline" | "overline" | "widetilde"
+        | "widehat" | "tilde" | "hat" | "bar" | "vec" | "dot" | "ddot" | "left" | "right"
+        | "big" | "Big" | "bigg" | "Bigg" | "cdot" | "cdots" | "ldots" | "dots" | "quad"
+        | "qquad" | "displaystyle" | "protect" | "url" | "c" | "v" | "u" | "H" | "k" | "r"
+        | "d" | "b" | "t" => "",
+        "infty" => "infinity",
+        "le" | "leq" | "leqslant" => "leq",
+        "ge" | "geq" | "geqslant" => "geq",
+        "ne" | "neq" => "neq",
+        "to" | "rightarrow" | "mapsto" => "to",
+        "leftarrow" | "gets" => "from",
+        "Rightarrow" | "implies" => "implies",
+        "Leftarrow" | "impliedby" => "impliedby",
+        "leftrightarrow" | "Leftrightarrow" | "iff" => "iff",
+        "land" | "wedge" => "and",
+        "lor" | "vee" => "or",
+        "lnot" | "neg" => "not",
+        "varepsilon" => "epsilon",
+        "vartheta" => "theta",
+        "varphi" => "phi",
+        "varrho" => "rho",
+        "varsigma" => "sigma",
+        "varpi" => "pi",
+        "i" => "i",
+        "j" => "j",
+        "o" | "O" => "o",
+        "l" | "L" => "l",
+        "aa" | "AA" => "a",
+        "ss" => "ss",
+        "ae" | "AE" => "ae",
+        "oe" | "OE" => "oe",
+        name => name,
+    }
+}
+
+/// Strip markup an extracted title may carry: MathML and other tags, LaTeX
+/// commands (`\textit{X}` → `X`, `\"{o}` → `o`, `$\mathcal{O}$` → `O`),
+/// brace groups and math delimiters.
+fn strip_markup(title: &str) -> String {
+    let title = if title.contains('<') {
+        MARKUP_TAG_RE.replace_all(title, " ").into_owned()
+    } else {
+        title.to_string()
+    };
+    if !title.contains(['\\', '$', '{', '}']) {
+        return title;
+    }
+    // Symbol commands (`\&`, `\"`, `\,`) vanish; an accent's letter follows
+    let title = LATEX_COMMAND_RE.replace_all(&title, |caps: &regex::Captures| {
+        let name = &caps[1];
+        if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            latex_command_text(name).to_string()
+        } else {
+            String::new()
+        }
+    });
+    // Super- and subscripts go too: `^` would otherwise read as a circumflex
+    title.replace(['{', '}', '$', '^', '_'], "")
+}
+
 /// Normalize title for comparison — strips to lowercase alphanumeric only.
 ///
 /// Steps (order matters):
 /// 1. Unescape HTML entities
-/// 2. Fix separated diacritics from PDF extraction (e.g., "B ¨UNZ" → "BÜNZ")
-/// 3. Transliterate Greek letters (e.g., "αdiff" → "alphadiff")
-/// 4. Replace math symbols (e.g., "√n" → "sqrtn", "∞" → "infinity")
-/// 5. Unicode NFKD normalization (decomposes accents)
-/// 6. Strip to ASCII
-/// 7. Keep only `[a-zA-Z0-9]`
-/// 8. Lowercase
+/// 2. Strip markup: MathML tags, LaTeX commands, braces and `$...$` math
+/// 3. Fix separated diacritics from PDF extraction (e.g., "B ¨UNZ" → "BÜNZ")
+/// 4. Transliterate Greek letters (e.g., "αdiff" → "alphadiff")
+/// 5. Replace math symbols (e.g., "√n" → "sqrtn", "∞" → "infinity")
+/// 6. Unicode NFKD normalization (decomposes accents)
+/// 7. Strip to ASCII
+/// 8. Keep only `[a-zA-Z0-9]`
+/// 9. Lowercase
 pub fn normalize_title(title: &str) -> String {
     // 1. Simple HTML entity unescaping for common cases
     let title = title
@@ -140,11 +224,26 @@ pub fn normalize_title(title: &str) -> String {
         .replace("&quot;", "\"")
         .replace("&#39;", "'")
         .replace("&apos;", "'");
-
-    // 2. Fix separated diacritics from PDF extraction (before NFKD)
+    let title = NUMERIC_ENTITY_RE.replace_all(&title, |caps: &regex::Captures| {
+        let code = &caps[1];
+        let value = match code.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok(),
+            None => code.parse().ok(),
+        };
+        value
+            .and_then(char::from_u32)
+            .map(String::from)
+            .unwrap_or_default()
+    });
+
+    // 2. Strip MathML tags and LaTeX markup, before its `^` and `~` can read
+    // as separated diacritics
+    let title = strip_markup(&title);
+
+    // 3. Fix separated diacritics from PDF extraction (before NFKD)
     let title = fix_separated_diacritics(&title);
 
-    // 3. Transliterate Greek letters (NFKD doesn't convert these to ASCII)
+    // 4. Transliterate Greek letters (NFKD doesn't convert these to ASCII)
     let title = title
         .replace(['α', 'Α'], "alpha")
         .replace(['β', 'Β'], "beta")
@@ -171,7 +270,7 @@ pub fn normalize_title(title: &str) -> String {
         .replace(['ψ', 'Ψ'], "psi")
         .replace(['ω', 'Ω'], "omega");
 
-    // 4. Replace mathematical symbols before NFKD strips them
+    // 5. Replace mathematical symbols before NFKD strips them
     let title = title
         .replace('∞', "infinity")
         .replace('√', "sqrt")
@@ -202,22 +301,41 @@ pub fn normalize_title(title: &str) -> String {
         .replace('⇐', "impliedby")
         .replace('⇔', "iff");
 
-    // 5-6. NFKD normalization and strip to ASCII
+    // 6-7. NFKD normalization and strip to ASCII
     let normalized: String = title.nfkd().filter(|c| c.is_ascii()).collect();
 
-    // 7-8. Keep only alphanumeric, lowercase
+    // 8-9. Keep only alphanumeric, lowercase
     static NON_ALNUM: Lazy<Regex> = Lazy::new(|| Regex::new(r"[^a-zA-Z0-9]").unwrap());
     NON_ALNUM.replace_all(&normalized, "").to_lowercase()
 }
 
+/// Title similarity a match must reach unless a backend or the config says
+/// otherwise (see [`Config::per_db_threshold`](crate::Config::per_db_threshold)).
+pub const DEFAULT_TITLE_THRESHOLD: f64 = 0.95;
+
+/// Lowest similarity backends report as a candidate. Configured thresholds
+/// below this have no further effect.
+pub const MIN_TITLE_THRESHOLD: f64 = 0.80;
+
 /// Check if two titles match using fuzzy comparison (95% threshold).
+pub fn titles_match(title_a: &str, title_b: &str) -> bool {
+    titles_match_at(title_a, title_b, DEFAULT_TITLE_THRESHOLD)
+}
+
+/// Whether a backend should report `found` as a candidate for `query`; the
+/// caller applies the effective threshold afterwards.
+pub fn is_title_candidate(query: &str, found: &str) -> bool {
+    titles_match_at(query, found, MIN_TITLE_THRESHOLD)
+}
+
+/// Check if two titles match with fuzzy similarity of at least `threshold`.
 ///
 /// Includes conservative prefix matching: if a shorter title is a prefix of a
 /// longer one but they differ on subtitle presence (text after `?` or `!`),
 /// the match is rejected unless there is ≥70% length coverage. This prevents
 /// false matches like `"Won't Somebody Think of the Children?"` matching
 /// `"Won't somebody think of the children?" Examining COPPA...` (different papers).
-pub fn titles_match(title_a: &str, title_b: &str) -> bool {
+pub fn titles_match_at(title_a: &str, title_b: &str, threshold: f64) -> bool {
     let norm_a = normalize_title(title_a);
     let norm_b = normalize_title(title_b);
 
@@ -226,7 +344,7 @@ pub fn titles_match(title_a: &str, title_b: &str) -> bool {
     }
 
     let score = rapidfuzz::fuzz::ratio(norm_a.chars(), norm_b.chars());
-    if score >= 0.95 {
+    if score >= threshold {
         return true;
     }
 
@@ -271,6 +389,30 @@ pub fn titles_match(title_a: &str, title_b: &str) -> bool {
     true
 }
 
+/// Two title matches whose similarity scores differ by at most this much are
+/// treated as equally good.
+pub const AMBIGUITY_EPSILON: f64 = 0.02;
+
+/// In strict title mode, a match whose [`title_similarity`] to the cited
+/// title falls below this is reported as a near miss. It is well above
+/// the matching thresholds, so only near-identical titles get through.
+pub const STRICT_TITLE_THRESHOLD: f64 = 0.98;
+
+/// Fuzzy similarity of two titles in `0.0..=1.0`, after normalization.
+///
+/// This is the score [`titles_match_at`] compares against its threshold; use
+/// it to rank several candidates that all match.
+pub fn title_similarity(title_a: &str, title_b: &str) -> f64 {
+    let norm_a = normalize_title(title_a);
+    let norm_b = normalize_title(title_b);
+
+    if norm_a.is_empty() || norm_b.is_empty() {
+        return 0.0;
+    }
+
+    rapidfuzz::fuzz::ratio(norm_a.chars(), norm_b.chars())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -325,6 +467,15 @@ mod tests {
         assert!(!titles_match("Something", ""));
     }
 
+    #[test]
+    fn test_titles_match_at_threshold() {
+        let a = "Graph Neural Networks for Molecules";
+        let b = "Graph Neural Networks for Proteins";
+        assert!(!titles_match(a, b));
+        assert!(titles_match_at(a, b, MIN_TITLE_THRESHOLD));
+        assert!(is_title_candidate(a, b));
+    }
+
     // =========================================================================
     // Greek letter transliteration
     // =========================================================================
@@ -521,6 +672,69 @@ mod tests {
         );
     }
 
+    // =========================================================================
+    // LaTeX and MathML markup
+    // =========================================================================
+
+    #[test]
+    fn test_latex_math_mode() {
+        assert_eq!(
+            normalize_title(r"On $\mathcal{O}(n\log n)$ Sorting"),
+            normalize_title("On O(n log n) Sorting")
+        );
+        assert_eq!(
+            normalize_title(r"Breaking the $o(\sqrt{n})$-bit barrier"),
+            "breakingtheosqrtnbitbarrier"
+        );
+    }
+
+    #[test]
+    fn test_latex_symbols_match_unicode() {
+        assert_eq!(
+            normalize_title(r"$\alpha$-diff: Cross-version differential"),
+            normalize_title("αdiff: Cross-version differential")
+        );
+        assert_eq!(
+            normalize_title(r"Robust $H_\infty$ filtering"),
+            normalize_title("Robust H∞ filtering")
+        );
+        assert_eq!(
+            normalize_title(r"$\varepsilon$-nets and $k \le n$"),
+            normalize_title("ε-nets and k ≤ n")
+        );
+    }
+
+    #[test]
+    fn test_latex_formatting_and_braces() {
+        assert_eq!(
+            normalize_title(r"\textit{Attention} Is All You {N}eed"),
+            "attentionisallyouneed"
+        );
+        assert_eq!(
+            normalize_title(r"{BERT}: Pre-training of {\emph{Deep}} Transformers"),
+            "bertpretrainingofdeeptransformers"
+        );
+    }
+
+    #[test]
+    fn test_latex_accents() {
+        assert_eq!(
+            normalize_title(r#"G\"{o}del, Erd\H{o}s and Cz\'{a}ki"#),
+            normalize_title("Gödel, Erdős and Czáki")
+        );
+        // A superscript caret is not a circumflex
+        assert_eq!(normalize_title(r"Graphs of size $2^k$"), "graphsofsize2k");
+    }
+
+    #[test]
+    fn test_mathml_fragments() {
+        let crossref = "Sparse <mml:math><mml:mi>k</mml:mi><mml:mo>&#x2062;</mml:mo>\
+                        <mml:mi>n</mml:mi></mml:math>-means in <i>linear</i> time";
+        assert_eq!(normalize_title(crossref), "sparseknmeansinlineartime");
+        // A bare comparison is not a tag
+        assert_eq!(normalize_title("x < y and y > z"), "xyandyz");
+    }
+
     // =========================================================================
     // Combined pipeline
     // =========================================================================
@@ -1,19 +1,46 @@
-use crate::authors::validate_authors;
-use crate::db::DatabaseBackend;
+use crate::authors::AuthorMatchPolicy;
+use crate::db::{DatabaseBackend, DbQueryResult};
+use crate::matching::title_similarity;
 use crate::rate_limit;
-use crate::{Config, DbResult, DbStatus, Status};
+use crate::{Config, DbResult, DbStatus, FailedDb, ProgressEvent, Status};
 use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Duration;
 
+/// Databases that must agree on a title in consensus mode by default.
+pub const DEFAULT_CONSENSUS_MIN: usize = 2;
+
+/// Receives a [`ProgressEvent::RateLimited`] each time a query backs off
+/// after a 429, as the pool reports them.
+pub type OnRateLimited = Arc<dyn Fn(ProgressEvent) + Send + Sync>;
+
+/// Turn `on_rate_limited` into the [`rate_limit::OnBackoff`] callback for
+/// `db_name`'s query.
+fn backoff_reporter(
+    on_rate_limited: Option<&OnRateLimited>,
+    db_name: &str,
+) -> Option<impl Fn(Duration, u32) + Send + Sync + 'static> {
+    let report = on_rate_limited?.clone();
+    let db_name = db_name.to_string();
+    Some(move |retry_after, attempt| {
+        report(ProgressEvent::RateLimited {
+            db_name: db_name.clone(),
+            retry_after,
+            attempt,
+        })
+    })
+}
+
 /// Result of querying all databases for a single reference.
 #[derive(Debug, Clone)]
 pub struct DbSearchResult {
     pub status: Status,
     pub source: Option<String>,
+    /// Databases that matched the title in consensus mode, `source` first.
+    pub agreeing_dbs: Vec<String>,
     pub found_authors: Vec<String>,
     pub paper_url: Option<String>,
-    pub failed_dbs: Vec<String>,
+    pub failed_dbs: Vec<FailedDb>,
     pub db_results: Vec<DbResult>,
 }
 
@@ -21,7 +48,9 @@ pub struct DbSearchResult {
 ///
 /// This is a convenience wrapper that calls [`query_local_databases`] followed by
 /// [`query_remote_databases`]. For the pool's split architecture, use those
-/// functions directly.
+/// functions directly. In consensus mode the verdict is settled by
+/// [`apply_consensus`] once every database has answered.
+#[allow(clippy::too_many_arguments)]
 pub async fn query_all_databases(
     title: &str,
     ref_authors: &[String],
@@ -30,6 +59,7 @@ pub async fn query_all_databases(
     longer_timeout: bool,
     only_dbs: Option<&[String]>,
     on_db_complete: Option<&(dyn Fn(DbResult) + Send + Sync)>,
+    on_rate_limited: Option<&OnRateLimited>,
 ) -> DbSearchResult {
     let local_result = query_local_databases(
         title,
@@ -39,6 +69,7 @@ pub async fn query_all_databases(
         longer_timeout,
         only_dbs,
         on_db_complete,
+        on_rate_limited,
     )
     .await;
 
@@ -46,7 +77,7 @@ pub async fn query_all_databases(
         return local_result;
     }
 
-    query_remote_databases(
+    let mut result = query_remote_databases(
         title,
         ref_authors,
         config,
@@ -54,15 +85,77 @@ pub async fn query_all_databases(
         longer_timeout,
         only_dbs,
         on_db_complete,
+        on_rate_limited,
         local_result,
     )
-    .await
+    .await;
+    if config.consensus_mode {
+        apply_consensus(
+            &mut result,
+            config.consensus_min,
+            config.check_openalex_authors,
+        );
+    }
+    result
+}
+
+/// Settle a reference from every database's answer: it stands only if at
+/// least `min_agree` databases matched the title, and `agreeing_dbs` then
+/// names all of them. The verdict, and `source`, rest on the first database
+/// that also accepted the authors, or on the first title match when none did.
+///
+/// As outside consensus mode, an OpenAlex author mismatch is ignored unless
+/// `check_openalex_authors` is set.
+pub fn apply_consensus(
+    result: &mut DbSearchResult,
+    min_agree: usize,
+    check_openalex_authors: bool,
+) {
+    let agreeing: Vec<&DbResult> = result
+        .db_results
+        .iter()
+        .filter(|r| match r.status {
+            DbStatus::Match => true,
+            DbStatus::AuthorMismatch => r.db_name != "OpenAlex" || check_openalex_authors,
+            _ => false,
+        })
+        .collect();
+    if agreeing.is_empty() || agreeing.len() < min_agree {
+        result.status = Status::NotFound;
+        result.source = None;
+        result.agreeing_dbs.clear();
+        result.found_authors.clear();
+        result.paper_url = None;
+        return;
+    }
+
+    let lead = agreeing
+        .iter()
+        .find(|r| r.status == DbStatus::Match)
+        .unwrap_or(&agreeing[0]);
+    let mut names = vec![lead.db_name.clone()];
+    names.extend(
+        agreeing
+            .iter()
+            .filter(|r| r.db_name != lead.db_name)
+            .map(|r| r.db_name.clone()),
+    );
+    result.status = if lead.status == DbStatus::Match {
+        Status::Verified
+    } else {
+        Status::AuthorMismatch
+    };
+    result.source = Some(lead.db_name.clone());
+    result.agreeing_dbs = names;
+    result.found_authors = lead.found_authors.clone();
+    result.paper_url = lead.paper_url.clone();
 }
 
 /// Query only local/offline databases (DBLP offline, ACL offline).
 ///
 /// Returns immediately (<1ms). If a local DB matches, the result has
 /// `status == Verified` and remaining DBs are marked Skipped.
+#[allow(clippy::too_many_arguments)]
 pub async fn query_local_databases(
     title: &str,
     ref_authors: &[String],
@@ -71,9 +164,8 @@ pub async fn query_local_databases(
     longer_timeout: bool,
     only_dbs: Option<&[String]>,
     on_db_complete: Option<&(dyn Fn(DbResult) + Send + Sync)>,
+    on_rate_limited: Option<&OnRateLimited>,
 ) -> DbSearchResult {
-    let timeout = compute_timeout(config, longer_timeout);
-
     let all_databases: Vec<Arc<dyn DatabaseBackend>> = build_database_list(config, only_dbs)
         .into_iter()
         .map(Arc::from)
@@ -104,6 +196,9 @@ pub async fn query_local_databases(
 
     for db in &local_dbs {
         let name = db.name().to_string();
+        let threshold = config.title_threshold(db.as_ref());
+        let timeout = compute_timeout(config, &name, longer_timeout);
+        let on_backoff = backoff_reporter(on_rate_limited, &name);
         let rl_result = rate_limit::query_with_retry(
             db.as_ref(),
             title,
@@ -112,6 +207,7 @@ pub async fn query_local_databases(
             &rate_limiters,
             max_retries,
             cache,
+            on_backoff.as_ref().map(|f| f as rate_limit::OnBackoff<'_>),
         )
         .await;
         let elapsed = rl_result.elapsed;
@@ -119,16 +215,21 @@ pub async fn query_local_databases(
 
         match process_query_result(
             name,
+            title,
+            threshold,
+            config.min_match_score,
             rl_result.result,
             elapsed,
             ref_authors,
+            config.author_policy(),
             config.check_openalex_authors,
             on_db_complete,
             &mut db_results,
             &mut failed_dbs,
             &mut first_mismatch,
         ) {
-            Some(verified) => {
+            // In consensus mode every database gets a say
+            Some(verified) if !config.consensus_mode => {
                 // Mark all remaining DBs as Skipped
                 emit_skipped(
                     &all_db_names,
@@ -141,7 +242,7 @@ pub async fn query_local_databases(
                     ..verified
                 };
             }
-            None => continue,
+            _ => continue,
         }
     }
 
@@ -154,6 +255,7 @@ pub async fn query_local_databases(
     DbSearchResult {
         status: Status::NotFound,
         source: None,
+        agreeing_dbs: vec![],
         found_authors: vec![],
         paper_url: None,
         failed_dbs,
@@ -174,10 +276,12 @@ pub async fn query_remote_databases(
     longer_timeout: bool,
     only_dbs: Option<&[String]>,
     on_db_complete: Option<&(dyn Fn(DbResult) + Send + Sync)>,
+    on_rate_limited: Option<&OnRateLimited>,
     local_result: DbSearchResult,
 ) -> DbSearchResult {
     let check_openalex_authors = config.check_openalex_authors;
-    let timeout = compute_timeout(config, longer_timeout);
+    let author_policy = config.author_policy();
+    let min_match_score = config.min_match_score;
 
     let all_databases: Vec<Arc<dyn DatabaseBackend>> = build_database_list(config, only_dbs)
         .into_iter()
@@ -221,6 +325,7 @@ pub async fn query_remote_databases(
         return DbSearchResult {
             status: Status::NotFound,
             source: None,
+            agreeing_dbs: vec![],
             found_authors: vec![],
             paper_url: None,
             failed_dbs,
@@ -233,11 +338,14 @@ pub async fn query_remote_databases(
 
     for db in &remote_dbs {
         let db = Arc::clone(db);
+        let threshold = config.title_threshold(db.as_ref());
+        let timeout = compute_timeout(config, db.name(), longer_timeout);
         let title = title.to_string();
         let client = client.clone();
         let ref_authors = ref_authors.to_vec();
         let rate_limiters = rate_limiters.clone();
         let cache = cache.clone();
+        let on_backoff = backoff_reporter(on_rate_limited, db.name());
 
         join_set.spawn(async move {
             let name = db.name().to_string();
@@ -249,14 +357,21 @@ pub async fn query_remote_databases(
                 &rate_limiters,
                 max_retries,
                 cache.as_deref(),
+                on_backoff.as_ref().map(|f| f as rate_limit::OnBackoff<'_>),
             )
             .await;
-            (name, rl_result.result, ref_authors, rl_result.elapsed)
+            (
+                name,
+                threshold,
+                rl_result.result,
+                ref_authors,
+                rl_result.elapsed,
+            )
         });
     }
 
     while let Some(result) = join_set.join_next().await {
-        let (name, query_result, ref_authors, elapsed) = match result {
+        let (name, threshold, query_result, ref_authors, elapsed) = match result {
             Ok(r) => r,
             Err(_) => continue,
         };
@@ -265,16 +380,20 @@ pub async fn query_remote_databases(
 
         match process_query_result(
             name,
+            title,
+            threshold,
+            min_match_score,
             query_result,
             elapsed,
             &ref_authors,
+            author_policy,
             check_openalex_authors,
             on_db_complete,
             &mut db_results,
             &mut failed_dbs,
             &mut first_mismatch,
         ) {
-            Some(verified) => {
+            Some(verified) if !config.consensus_mode => {
                 join_set.abort_all();
                 emit_skipped(
                     &all_db_names,
@@ -287,7 +406,7 @@ pub async fn query_remote_databases(
                     ..verified
                 };
             }
-            None => continue,
+            _ => continue,
         }
     }
 
@@ -299,6 +418,7 @@ pub async fn query_remote_databases(
     DbSearchResult {
         status: Status::NotFound,
         source: None,
+        agreeing_dbs: vec![],
         found_authors: vec![],
         paper_url: None,
         failed_dbs,
@@ -308,18 +428,17 @@ pub async fn query_remote_databases(
 
 // ── Helpers ───────────────────────────────────────────────────────────────
 
-fn compute_timeout(config: &Config, longer: bool) -> Duration {
-    if longer {
-        Duration::from_secs(config.db_timeout_secs * 2)
-    } else {
-        Duration::from_secs(config.db_timeout_secs)
-    }
+/// `db`'s timeout (see [`Config::db_timeout`]), doubled for the retry pass.
+fn compute_timeout(config: &Config, db: &str, longer: bool) -> Duration {
+    let timeout = config.db_timeout(db);
+    if longer { timeout * 2 } else { timeout }
 }
 
 fn empty_result() -> DbSearchResult {
     DbSearchResult {
         status: Status::NotFound,
         source: None,
+        agreeing_dbs: vec![],
         found_authors: vec![],
         paper_url: None,
         failed_dbs: vec![],
@@ -332,25 +451,51 @@ fn empty_result() -> DbSearchResult {
 #[allow(clippy::too_many_arguments)]
 fn process_query_result(
     name: String,
-    result: Result<crate::db::DbQueryResult, crate::rate_limit::DbQueryError>,
+    title: &str,
+    threshold: f64,
+    min_score: f64,
+    result: Result<DbQueryResult, crate::rate_limit::DbQueryError>,
     elapsed: Duration,
     ref_authors: &[String],
+    author_policy: AuthorMatchPolicy,
     check_openalex_authors: bool,
     on_db_complete: Option<&(dyn Fn(DbResult) + Send + Sync)>,
     db_results: &mut Vec<DbResult>,
-    failed_dbs: &mut Vec<String>,
+    failed_dbs: &mut Vec<FailedDb>,
     first_mismatch: &mut Option<DbSearchResult>,
 ) -> Option<DbSearchResult> {
-    match result {
-        Ok((Some(_found_title), found_authors, paper_url)) => {
-            if ref_authors.is_empty() || validate_authors(ref_authors, &found_authors) {
+    // Hold the answer to this database's threshold and the minimum score,
+    // then, among equally good title matches, go with the one the authors
+    // point to.
+    match result.map(|r| {
+        r.at_threshold(title, threshold)
+            .at_min_score(title, min_score)
+            .prefer_authors(ref_authors, author_policy)
+    }) {
+        Ok(DbQueryResult {
+            found_title: Some(found_title),
+            authors: found_authors,
+            paper_url,
+            alternates,
+            venue,
+            year,
+            ..
+        }) => {
+            let score = Some(title_similarity(title, &found_title));
+            if ref_authors.is_empty() || author_policy.accepts(ref_authors, &found_authors) {
                 let db_result = DbResult {
                     db_name: name.clone(),
                     status: DbStatus::Match,
                     elapsed: Some(elapsed),
+                    found_title: Some(found_title),
                     found_authors: found_authors.clone(),
                     paper_url: paper_url.clone(),
                     error_message: None,
+                    alternates,
+                    threshold: Some(threshold),
+                    score,
+                    venue,
+                    year,
                 };
                 if let Some(cb) = on_db_complete {
                     cb(db_result.clone());
@@ -360,6 +505,7 @@ fn process_query_result(
                 return Some(DbSearchResult {
                     status: Status::Verified,
                     source: Some(name),
+                    agreeing_dbs: vec![],
                     found_authors,
                     paper_url,
                     failed_dbs: vec![],
@@ -370,9 +516,15 @@ fn process_query_result(
                     db_name: name.clone(),
                     status: DbStatus::AuthorMismatch,
                     elapsed: Some(elapsed),
+                    found_title: Some(found_title),
                     found_authors: found_authors.clone(),
                     paper_url: paper_url.clone(),
                     error_message: None,
+                    alternates,
+                    threshold: Some(threshold),
+                    score,
+                    venue,
+                    year,
                 };
                 if let Some(cb) = on_db_complete {
                     cb(db_result.clone());
@@ -383,6 +535,7 @@ fn process_query_result(
                     *first_mismatch = Some(DbSearchResult {
                         status: Status::AuthorMismatch,
                         source: Some(name),
+                        agreeing_dbs: vec![],
                         found_authors,
                         paper_url,
                         failed_dbs: vec![],
@@ -391,14 +544,24 @@ fn process_query_result(
                 }
             }
         }
-        Ok((None, _, _)) => {
+        Ok(DbQueryResult {
+            found_title: None,
+            rejected_title,
+            ..
+        }) => {
             let db_result = DbResult {
                 db_name: name,
                 status: DbStatus::NoMatch,
                 elapsed: Some(elapsed),
+                found_title: rejected_title,
                 found_authors: vec![],
                 paper_url: None,
                 error_message: None,
+                alternates: vec![],
+                threshold: Some(threshold),
+                score: None,
+                venue: None,
+                year: None,
             };
             if let Some(cb) = on_db_complete {
                 cb(db_result.clone());
@@ -410,16 +573,22 @@ fn process_query_result(
                 db_name: name.clone(),
                 status: DbStatus::Error,
                 elapsed: Some(elapsed),
+                found_title: None,
                 found_authors: vec![],
                 paper_url: None,
                 error_message: Some(err.to_string()),
+                alternates: vec![],
+                threshold: None,
+                score: None,
+                venue: None,
+                year: None,
             };
             if let Some(cb) = on_db_complete {
                 cb(db_result.clone());
             }
             db_results.push(db_result);
             log::debug!("{}: {}", name, err);
-            failed_dbs.push(name);
+            failed_dbs.push(FailedDb::new(name, err.failure_reason()));
         }
     }
     None
@@ -438,9 +607,15 @@ fn emit_skipped(
                 db_name: db_name.clone(),
                 status: DbStatus::Skipped,
                 elapsed: None,
+                found_title: None,
                 found_authors: vec![],
                 paper_url: None,
                 error_message: None,
+                alternates: vec![],
+                threshold: None,
+                score: None,
+                venue: None,
+                year: None,
             };
             if let Some(cb) = on_db_complete {
                 cb(skipped.clone());
@@ -459,12 +634,9 @@ pub(crate) fn build_database_list(
 
     let mut databases: Vec<Box<dyn DatabaseBackend>> = Vec::new();
 
+    // A non-empty `only_dbs` in the config overrides `disabled_dbs`.
     let should_include = |name: &str| -> bool {
-        if config
-            .disabled_dbs
-            .iter()
-            .any(|d| d.eq_ignore_ascii_case(name))
-        {
+        if !config.db_enabled(name) {
             return false;
         }
         match only_dbs {
@@ -473,6 +645,11 @@ pub(crate) fn build_database_list(
         }
     };
 
+    if let Some(ref library) = config.local_bib
+        && should_include("BibTeX")
+    {
+        databases.push(Box::new(std::sync::Arc::clone(library)));
+    }
     if should_include("CrossRef") {
         databases.push(Box::new(crossref::CrossRef {
             mailto: config.crossref_mailto.clone(),
@@ -508,11 +685,23 @@ pub(crate) fn build_database_list(
         databases.push(Box::new(europe_pmc::EuropePmc));
     }
     if should_include("PubMed") {
-        databases.push(Box::new(pubmed::PubMed));
+        databases.push(Box::new(pubmed::PubMed {
+            api_key: config.ncbi_api_key.clone(),
+        }));
+    }
+    if should_include("DataCite") {
+        databases.push(Box::new(datacite::DataCite));
     }
     if should_include("DOI") {
         databases.push(Box::new(doi_resolver::DoiResolver));
     }
+    if let Some(ref key) = config.core_api_key
+        && should_include("CORE")
+    {
+        databases.push(Box::new(core_ac::Core {
+            api_key: key.clone(),
+        }));
+    }
     if let Some(ref key) = config.openalex_key
         && should_include("OpenAlex")
     {
@@ -530,6 +719,7 @@ pub(crate) fn build_database_list(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::FailureReason;
     use crate::db::mock::{MockDb, MockResponse};
 
     fn config_all_disabled() -> Config {
@@ -542,6 +732,7 @@ mod tests {
                 "ACL Anthology".into(),
                 "Europe PMC".into(),
                 "PubMed".into(),
+                "DataCite".into(),
                 "OpenAlex".into(),
                 "DOI".into(),
             ],
@@ -562,6 +753,7 @@ mod tests {
             "ACL Anthology",
             "Europe PMC",
             "PubMed",
+            "DataCite",
             "DOI",
         ] {
             assert!(names.contains(&expected), "missing {expected}");
@@ -588,6 +780,24 @@ mod tests {
         assert_eq!(dbs[0].name(), "arXiv");
     }
 
+    #[test]
+    fn config_only_dbs_overrides_disabled() {
+        let config = Config {
+            only_dbs: vec!["crossref".into(), "arXiv".into()],
+            disabled_dbs: vec!["CrossRef".into()],
+            ..Config::default()
+        };
+        let dbs = build_database_list(&config, None);
+        let names: Vec<&str> = dbs.iter().map(|db| db.name()).collect();
+        assert_eq!(names, ["CrossRef", "arXiv"]);
+
+        // Retries narrow the whitelist further.
+        let only = vec!["arXiv".into()];
+        let dbs = build_database_list(&config, Some(&only));
+        assert_eq!(dbs.len(), 1);
+        assert_eq!(dbs[0].name(), "arXiv");
+    }
+
     #[test]
     fn openalex_requires_key() {
         let config = Config::default();
@@ -603,12 +813,70 @@ mod tests {
         assert_eq!(dbs[0].name(), "OpenAlex");
     }
 
+    #[test]
+    fn unpaywall_is_not_searched_for_a_verdict() {
+        let config = Config {
+            crossref_mailto: Some("lab@example.org".into()),
+            ..Config::default()
+        };
+        let dbs = build_database_list(&config, None);
+        assert!(!dbs.iter().any(|db| db.name() == "Unpaywall"));
+    }
+
+    #[test]
+    fn core_requires_key() {
+        let config = Config::default();
+        let dbs = build_database_list(&config, None);
+        assert!(!dbs.iter().any(|db| db.name() == "CORE"));
+
+        let config_with_key = Config {
+            core_api_key: Some("test-key".into()),
+            ..Config::default()
+        };
+        let dbs = build_database_list(&config_with_key, None);
+        assert_eq!(dbs.last().map(|db| db.name()), Some("CORE"));
+    }
+
+    #[test]
+    fn local_bib_path_adds_library() {
+        let path =
+            std::env::temp_dir().join(format!("hallucinator-lib-{}.bib", std::process::id()));
+        std::fs::write(
+            &path,
+            "@article{a, title = {A Study of Local Library Lookups}, author = {Roe, R.}}\n",
+        )
+        .unwrap();
+        let mut config = Config {
+            local_bib_path: Some(path.clone()),
+            ..Config::default()
+        };
+        assert!(crate::checker::load_local_bib(&mut config, 1).is_none());
+        std::fs::remove_file(&path).unwrap();
+
+        // The library is read once, not for every database list
+        for _ in 0..2 {
+            let dbs = build_database_list(&config, None);
+            let library = dbs.iter().find(|db| db.name() == "BibTeX").unwrap();
+            assert!(library.is_local());
+        }
+
+        // An unreadable library is left out, with a warning, rather than
+        // failing the run.
+        let mut config = Config {
+            local_bib_path: Some(path),
+            ..Config::default()
+        };
+        assert!(crate::checker::load_local_bib(&mut config, 1).is_some());
+        let dbs = build_database_list(&config, None);
+        assert!(dbs.iter().all(|db| db.name() != "BibTeX"));
+    }
+
     #[tokio::test]
     async fn empty_db_list_returns_not_found() {
         let config = config_all_disabled();
         let client = reqwest::Client::new();
         let result =
-            query_all_databases("Some Title", &[], &config, &client, false, None, None).await;
+            query_all_databases("Some Title", &[], &config, &client, false, None, None, None).await;
         assert_eq!(result.status, Status::NotFound);
         assert!(result.db_results.is_empty());
     }
@@ -617,9 +885,16 @@ mod tests {
         mock: Arc<dyn DatabaseBackend>,
         ref_authors: &[String],
     ) -> DbSearchResult {
-        let config = config_all_disabled();
+        query_single_mock_db_with(mock, ref_authors, config_all_disabled()).await
+    }
+
+    async fn query_single_mock_db_with(
+        mock: Arc<dyn DatabaseBackend>,
+        ref_authors: &[String],
+        config: Config,
+    ) -> DbSearchResult {
         let client = reqwest::Client::new();
-        let timeout = Duration::from_secs(config.db_timeout_secs);
+        let timeout = compute_timeout(&config, mock.name(), false);
         let rate_limiters = config.rate_limiters.clone();
         let max_retries = config.max_rate_limit_retries;
 
@@ -639,6 +914,7 @@ mod tests {
                 &rate_limiters_clone,
                 max_retries,
                 None,
+                None,
             )
             .await;
             (name, rl_result.result, ref_authors_owned, rl_result.elapsed)
@@ -652,9 +928,13 @@ mod tests {
             let (name, query_result, ref_authors, elapsed) = result.unwrap();
             match process_query_result(
                 name,
+                title,
+                crate::matching::DEFAULT_TITLE_THRESHOLD,
+                0.0,
                 query_result,
                 elapsed,
                 &ref_authors,
+                AuthorMatchPolicy::default(),
                 false,
                 None,
                 &mut db_results,
@@ -679,6 +959,7 @@ mod tests {
         DbSearchResult {
             status: Status::NotFound,
             source: None,
+            agreeing_dbs: vec![],
             found_authors: vec![],
             paper_url: None,
             failed_dbs,
@@ -715,6 +996,80 @@ mod tests {
         assert_eq!(result.status, Status::AuthorMismatch);
     }
 
+    #[tokio::test]
+    async fn near_tie_uses_candidate_matching_authors() {
+        // Two records with practically the same title; the first one listed
+        // is not the paper the reference cites.
+        let mock: Arc<dyn DatabaseBackend> = Arc::new(MockDb::new(
+            "TestDB",
+            MockResponse::Candidates(vec![
+                crate::MatchCandidate {
+                    title: "Test Paper Title".into(),
+                    authors: vec!["Jones".into()],
+                    paper_url: Some("https://example.com/jones".into()),
+                    venue: None,
+                    year: None,
+                },
+                crate::MatchCandidate {
+                    title: "Test paper title.".into(),
+                    authors: vec!["Smith".into()],
+                    paper_url: Some("https://example.com/smith".into()),
+                    venue: None,
+                    year: None,
+                },
+            ]),
+        ));
+        let result = query_single_mock_db(mock, &["Smith".into()]).await;
+        assert_eq!(result.status, Status::Verified);
+        assert_eq!(result.found_authors, vec!["Smith"]);
+        assert_eq!(result.db_results[0].alternates.len(), 1);
+        assert_eq!(result.db_results[0].alternates[0].authors, vec!["Jones"]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn per_db_timeout_overrides_global() {
+        let slow_db = || -> Arc<dyn DatabaseBackend> {
+            Arc::new(
+                MockDb::new(
+                    "SlowDB",
+                    MockResponse::Found {
+                        title: "Test Paper Title".into(),
+                        authors: vec!["Smith".into()],
+                        url: None,
+                    },
+                )
+                .with_delay(Duration::from_secs(8)),
+            )
+        };
+        let authors = ["Smith".to_string()];
+
+        let config = Config {
+            db_timeout_secs: 5,
+            ..config_all_disabled()
+        };
+        let result = query_single_mock_db_with(slow_db(), &authors, config).await;
+        assert_eq!(result.status, Status::NotFound);
+        assert_eq!(
+            result.failed_dbs,
+            vec![FailedDb::new("SlowDB", FailureReason::Timeout)]
+        );
+
+        let config = Config {
+            db_timeout_secs: 5,
+            db_timeouts: std::collections::HashMap::from([("SlowDB".to_string(), 15)]),
+            ..config_all_disabled()
+        };
+        assert_eq!(config.db_timeout("SlowDB"), Duration::from_secs(15));
+        assert_eq!(config.db_timeout("CrossRef"), Duration::from_secs(5));
+        assert_eq!(
+            compute_timeout(&config, "SlowDB", true),
+            Duration::from_secs(30)
+        );
+        let result = query_single_mock_db_with(slow_db(), &authors, config).await;
+        assert_eq!(result.status, Status::Verified);
+        assert!(result.failed_dbs.is_empty());
+    }
+
     #[tokio::test]
     async fn error_tracked_in_failed_dbs() {
         let mock: Arc<dyn DatabaseBackend> = Arc::new(MockDb::new(
@@ -723,6 +1078,157 @@ mod tests {
         ));
         let result = query_single_mock_db(mock, &[]).await;
         assert_eq!(result.status, Status::NotFound);
-        assert!(result.failed_dbs.contains(&"FailDB".to_string()));
+        assert_eq!(
+            result.failed_dbs,
+            vec![FailedDb::new("FailDB", FailureReason::Other)]
+        );
+    }
+
+    #[tokio::test]
+    async fn rate_limit_tracked_as_failure_reason() {
+        let mock: Arc<dyn DatabaseBackend> = Arc::new(MockDb::new(
+            "BusyDB",
+            MockResponse::RateLimited {
+                retry_after: Some(Duration::from_millis(1)),
+            },
+        ));
+        let result = query_single_mock_db(mock, &[]).await;
+        assert_eq!(result.status, Status::NotFound);
+        assert_eq!(
+            result.failed_dbs,
+            vec![FailedDb::new("BusyDB", FailureReason::RateLimited)]
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn rate_limit_backoff_reported_as_progress() {
+        let db = MockDb::new(
+            "BusyDB",
+            MockResponse::RateLimited {
+                retry_after: Some(Duration::from_secs(5)),
+            },
+        );
+        let events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = events.clone();
+        let on_rate_limited: OnRateLimited =
+            Arc::new(move |event| sink.lock().unwrap().push(event));
+        let on_backoff = backoff_reporter(Some(&on_rate_limited), db.name());
+
+        rate_limit::query_with_retry(
+            &db,
+            "Test Paper Title",
+            &reqwest::Client::new(),
+            Duration::from_secs(10),
+            &crate::RateLimiters::new(false, false, false),
+            0,
+            None,
+            on_backoff.as_ref().map(|f| f as rate_limit::OnBackoff<'_>),
+        )
+        .await;
+
+        let events = events.lock().unwrap();
+        assert!(matches!(
+            events.as_slice(),
+            [ProgressEvent::RateLimited { db_name, retry_after, attempt: 1 }]
+                if db_name == "BusyDB" && *retry_after == Duration::from_secs(5)
+        ));
+    }
+
+    fn answer(db_name: &str, status: DbStatus) -> DbResult {
+        let found = matches!(status, DbStatus::Match | DbStatus::AuthorMismatch);
+        DbResult {
+            found_title: found.then(|| "Test Paper Title".into()),
+            found_authors: if found { vec![db_name.into()] } else { vec![] },
+            paper_url: found.then(|| format!("https://example.com/{}", db_name)),
+            ..DbResult::test(db_name, status)
+        }
+    }
+
+    fn search(db_results: Vec<DbResult>) -> DbSearchResult {
+        DbSearchResult {
+            status: Status::Verified,
+            source: Some(db_results[0].db_name.clone()),
+            agreeing_dbs: vec![],
+            found_authors: vec![],
+            paper_url: None,
+            failed_dbs: vec![],
+            db_results,
+        }
+    }
+
+    #[test]
+    fn consensus_needs_enough_agreeing_dbs() {
+        let mut result = search(vec![
+            answer("CrossRef", DbStatus::Match),
+            answer("DBLP", DbStatus::NoMatch),
+            answer("arXiv", DbStatus::Error),
+        ]);
+        apply_consensus(&mut result, 2, false);
+        assert_eq!(result.status, Status::NotFound);
+        assert_eq!(result.source, None);
+        assert!(result.agreeing_dbs.is_empty());
+        assert!(result.found_authors.is_empty());
+        assert_eq!(result.paper_url, None);
+    }
+
+    #[test]
+    fn consensus_lists_every_agreeing_db() {
+        let mut result = search(vec![
+            answer("CrossRef", DbStatus::AuthorMismatch),
+            answer("DBLP", DbStatus::Match),
+            answer("arXiv", DbStatus::NoMatch),
+            answer("OpenAlex", DbStatus::Match),
+        ]);
+        apply_consensus(&mut result, 2, false);
+        assert_eq!(result.status, Status::Verified);
+        // The first database that accepted the authors leads
+        assert_eq!(result.source.as_deref(), Some("DBLP"));
+        assert_eq!(result.agreeing_dbs, ["DBLP", "CrossRef", "OpenAlex"]);
+        assert_eq!(result.found_authors, vec!["DBLP"]);
+        assert_eq!(
+            result.paper_url.as_deref(),
+            Some("https://example.com/DBLP")
+        );
+    }
+
+    #[test]
+    fn consensus_of_author_mismatches_is_a_mismatch() {
+        let mut result = search(vec![
+            answer("CrossRef", DbStatus::AuthorMismatch),
+            answer("DBLP", DbStatus::AuthorMismatch),
+        ]);
+        apply_consensus(&mut result, 2, false);
+        assert_eq!(result.status, Status::AuthorMismatch);
+        assert_eq!(result.source.as_deref(), Some("CrossRef"));
+        assert_eq!(result.agreeing_dbs, ["CrossRef", "DBLP"]);
+    }
+
+    #[test]
+    fn consensus_ignores_openalex_author_mismatch_unless_checked() {
+        let answers = || {
+            search(vec![
+                answer("OpenAlex", DbStatus::AuthorMismatch),
+                answer("DBLP", DbStatus::Match),
+                answer("arXiv", DbStatus::NoMatch),
+            ])
+        };
+
+        let mut result = answers();
+        apply_consensus(&mut result, 2, false);
+        assert_eq!(result.status, Status::NotFound);
+        assert!(result.agreeing_dbs.is_empty());
+
+        let mut result = answers();
+        apply_consensus(&mut result, 2, true);
+        assert_eq!(result.status, Status::Verified);
+        assert_eq!(result.agreeing_dbs, ["DBLP", "OpenAlex"]);
+
+        // An OpenAlex title and author match counts either way
+        let mut result = search(vec![
+            answer("OpenAlex", DbStatus::Match),
+            answer("DBLP", DbStatus::Match),
+        ]);
+        apply_consensus(&mut result, 2, false);
+        assert_eq!(result.agreeing_dbs, ["OpenAlex", "DBLP"]);
     }
 }
@@ -0,0 +1,281 @@
+//! ORCID author disambiguation, for [`Config::orcid_check`].
+//!
+//! A common name can make a correct citation look like an author mismatch:
+//! the database spells the author differently (a transliteration, a maiden
+//! name, a middle name), or lists only initials. ORCID knows which
+//! researchers claim a work, and under which names, so a cited author who
+//! resolves to one of those researchers is the work's author after all.
+
+use std::time::Duration;
+
+use crate::authors::{AuthorMatchPolicy, AuthorOverlap};
+use crate::rate_limit::{DbQueryError, RateLimiters, check_rate_limit_response};
+use crate::{Config, Reference};
+
+/// Most claimants read for a single work.
+const MAX_CLAIMANTS: usize = 50;
+
+/// A researcher who lists a work on their ORCID record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Claimant {
+    pub orcid: String,
+    /// Every name the record goes by: given and family names, the credit
+    /// name and any other names.
+    pub names: Vec<String>,
+}
+
+/// Researchers who claim the work with `doi` on ORCID, from the public API's
+/// expanded search.
+pub async fn claimants(
+    doi: &str,
+    client: &reqwest::Client,
+    timeout: Duration,
+    rate_limiters: &RateLimiters,
+) -> Result<Vec<Claimant>, DbQueryError> {
+    let limiter = rate_limiters.get("ORCID");
+    if let Some(limiter) = limiter {
+        limiter.acquire().await;
+    }
+    let query = format!("doi-self:\"{}\"", doi.replace('"', ""));
+    let url = format!(
+        "https://pub.orcid.org/v3.0/expanded-search/?q={}&rows={}",
+        urlencoding::encode(&query),
+        MAX_CLAIMANTS
+    );
+    let resp = client
+        .get(&url)
+        .header("Accept", "application/json")
+        .timeout(timeout)
+        .send()
+        .await
+        .map_err(DbQueryError::from)?;
+    if let Err(e) = check_rate_limit_response(&resp) {
+        if let Some(limiter) = limiter {
+            limiter.on_rate_limited();
+        }
+        return Err(e);
+    }
+    if !resp.status().is_success() {
+        return Err(DbQueryError::Http(resp.status()));
+    }
+    let data: serde_json::Value = resp.json().await.map_err(DbQueryError::from)?;
+    Ok(parse_claimants(&data))
+}
+
+/// Read the claimants out of an expanded-search response.
+pub fn parse_claimants(data: &serde_json::Value) -> Vec<Claimant> {
+    let Some(results) = data["expanded-result"].as_array() else {
+        return vec![];
+    };
+    results
+        .iter()
+        .filter_map(|r| {
+            let orcid = r["orcid-id"].as_str()?.to_string();
+            let mut names = Vec::new();
+            let given = r["given-names"].as_str().unwrap_or("").trim();
+            let family = r["family-names"].as_str().unwrap_or("").trim();
+            if !family.is_empty() {
+                names.push(format!("{} {}", given, family).trim().to_string());
+            }
+            if let Some(credit) = r["credit-name"].as_str().filter(|s| !s.trim().is_empty()) {
+                names.push(credit.trim().to_string());
+            }
+            if let Some(others) = r["other-name"].as_array() {
+                names.extend(
+                    others
+                        .iter()
+                        .filter_map(|n| n.as_str())
+                        .filter(|n| !n.trim().is_empty())
+                        .map(|n| n.trim().to_string()),
+                );
+            }
+            Some(Claimant { orcid, names })
+        })
+        .collect()
+}
+
+/// The ORCID iD of the first cited author who is one of `claimants`, by
+/// any of the names on their record, provided that counting such authors as
+/// found makes the author lists agree under `policy`. Cited authors already
+/// in `found_authors` count as usual.
+pub fn matching_orcid(
+    policy: &AuthorMatchPolicy,
+    ref_authors: &[String],
+    found_authors: &[String],
+    claimants: &[Claimant],
+) -> Option<String> {
+    let mut overlap = AuthorOverlap::default();
+    let mut orcid = None;
+    for author in ref_authors.iter().filter(|a| !a.trim().is_empty()) {
+        let cited = std::slice::from_ref(author);
+        let found = policy.accepts(cited, found_authors) || {
+            let claimant = claimants.iter().find(|c| policy.accepts(cited, &c.names));
+            if let Some(c) = claimant {
+                orcid.get_or_insert_with(|| c.orcid.clone());
+            }
+            claimant.is_some()
+        };
+        if found {
+            overlap.matched += 1;
+            overlap.first_author |= overlap.cited == 0;
+        }
+        overlap.cited += 1;
+    }
+    orcid.filter(|_| policy.accepts_overlap(&overlap))
+}
+
+/// The DOI to ask ORCID about: the matched record's. A cited DOI that
+/// names a different work rules the check out, since its claimants say
+/// nothing about the matched one.
+pub fn work_doi(cited_doi: Option<&str>, paper_url: Option<&str>) -> Option<String> {
+    let doi = paper_url.and_then(crate::retraction_watch::normalize_doi)?;
+    match cited_doi.and_then(crate::retraction_watch::normalize_doi) {
+        Some(cited) if cited != doi => None,
+        _ => Some(doi),
+    }
+}
+
+/// For an author mismatch: the ORCID iD that shows one of the reference's
+/// authors wrote the matched work, looked up by the matched record's DOI
+/// (see [`work_doi`]) and accepted only under [`Config::author_policy`].
+/// `None` when the check is off, there is no usable DOI, nobody matches, or
+/// ORCID can't be reached; a failed lookup leaves the mismatch as it was.
+pub(crate) async fn confirm_mismatch(
+    reference: &Reference,
+    paper_url: Option<&str>,
+    found_authors: &[String],
+    config: &Config,
+    client: &reqwest::Client,
+) -> Option<String> {
+    if !config.orcid_check || reference.authors.is_empty() {
+        return None;
+    }
+    let doi = work_doi(reference.doi.as_deref(), paper_url)?;
+    match claimants(
+        &doi,
+        client,
+        config.db_timeout("ORCID"),
+        &config.rate_limiters,
+    )
+    .await
+    {
+        Ok(claimants) => matching_orcid(
+            &config.author_policy(),
+            &reference.authors,
+            found_authors,
+            &claimants,
+        ),
+        Err(e) => {
+            log::debug!("ORCID: lookup for {} failed: {}", doi, e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response() -> serde_json::Value {
+        serde_json::json!({
+            "expanded-result": [
+                {
+                    "orcid-id": "0000-0002-1825-0097",
+                    "given-names": "Wei",
+                    "family-names": "Zhang",
+                    "credit-name": null,
+                    "other-name": []
+                },
+                {
+                    "orcid-id": "0000-0001-5109-3700",
+                    "given-names": "Maria",
+                    "family-names": "Garcia Lopez",
+                    "credit-name": "M. García-López",
+                    "other-name": ["Maria Schmidt"]
+                }
+            ],
+            "num-found": 2
+        })
+    }
+
+    #[test]
+    fn parses_every_name_a_claimant_goes_by() {
+        let claimants = parse_claimants(&response());
+        assert_eq!(claimants.len(), 2);
+        assert_eq!(claimants[0].names, vec!["Wei Zhang"]);
+        assert_eq!(
+            claimants[1].names,
+            vec!["Maria Garcia Lopez", "M. García-López", "Maria Schmidt"]
+        );
+        assert!(parse_claimants(&serde_json::json!({"num-found": 0})).is_empty());
+    }
+
+    #[test]
+    fn cited_author_matches_a_claimant_by_any_name() {
+        let claimants = parse_claimants(&response());
+        // Cited under a former name
+        let cited = vec!["J. Doe".to_string(), "M. Schmidt".to_string()];
+        let policy = AuthorMatchPolicy::default();
+        assert_eq!(
+            matching_orcid(&policy, &cited, &[], &claimants).as_deref(),
+            Some("0000-0001-5109-3700")
+        );
+        // A namesake's initial doesn't match
+        let cited = vec!["X. Zhang".to_string()];
+        assert_eq!(matching_orcid(&policy, &cited, &[], &claimants), None);
+    }
+
+    #[test]
+    fn confirmed_authors_must_satisfy_the_author_policy() {
+        let claimants = parse_claimants(&response());
+        let found = vec!["Wei Zhang".to_string()];
+        let cited = vec![
+            "A. Fake".to_string(),
+            "B. Invented".to_string(),
+            "M. Schmidt".to_string(),
+        ];
+        // One confirmed author of three is enough by default...
+        let policy = AuthorMatchPolicy::default();
+        assert!(matching_orcid(&policy, &cited, &found, &claimants).is_some());
+        // ...but not for a stricter policy
+        let strict = AuthorMatchPolicy {
+            min_overlap: 0.5,
+            ..policy
+        };
+        assert_eq!(matching_orcid(&strict, &cited, &found, &claimants), None);
+        let first = AuthorMatchPolicy {
+            require_first_author: true,
+            ..policy
+        };
+        assert_eq!(matching_orcid(&first, &cited, &found, &claimants), None);
+        // Authors in the found list and on ORCID add up
+        let cited = vec!["M. Schmidt".to_string(), "W. Zhang".to_string()];
+        let strict = AuthorMatchPolicy {
+            min_overlap: 1.0,
+            require_first_author: true,
+            ..policy
+        };
+        assert_eq!(
+            matching_orcid(&strict, &cited, &found, &claimants).as_deref(),
+            Some("0000-0001-5109-3700")
+        );
+    }
+
+    #[test]
+    fn asks_about_the_matched_work_only() {
+        let matched = Some("https://doi.org/10.1000/Real.1");
+        assert_eq!(work_doi(None, matched).as_deref(), Some("10.1000/real.1"));
+        assert_eq!(
+            work_doi(Some("doi:10.1000/REAL.1"), matched).as_deref(),
+            Some("10.1000/real.1")
+        );
+        // A cited DOI for some other work the cited authors did write
+        assert_eq!(work_doi(Some("10.1000/other.2"), matched), None);
+        // Without a matched DOI the cited one is not used on its own
+        assert_eq!(work_doi(Some("10.1000/real.1"), None), None);
+        assert_eq!(
+            work_doi(None, Some("https://dblp.org/rec/conf/x/Y21")),
+            None
+        );
+    }
+}
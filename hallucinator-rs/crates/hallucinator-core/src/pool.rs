@@ -5,6 +5,7 @@
 //! to per-DB drainer queues. Each drainer is the sole consumer of its DB's
 //! rate limiter, eliminating governor contention.
 
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
@@ -13,17 +14,24 @@ use tokio::sync::oneshot;
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 
-use crate::authors::validate_authors;
-use crate::db::DatabaseBackend;
-use crate::orchestrator::{build_database_list, query_local_databases};
+use crate::context::CheckContext;
+use crate::db::{DatabaseBackend, DbQueryResult};
+use crate::matching::{normalize_title, title_similarity};
+use crate::orchestrator::{
+    DbSearchResult, apply_consensus, build_database_list, query_local_databases,
+};
 use crate::rate_limit::{self, DoiContext};
+use crate::retraction::{check_retraction, check_retraction_by_title, with_crossref_fallback};
 use crate::{
-    ArxivInfo, Config, DbResult, DbStatus, DoiInfo, ProgressEvent, Reference, Status,
+    Config, DbResult, DbStatus, DoiInfo, FailedDb, ProgressEvent, Reference, Status,
     ValidationResult,
 };
 
 // ── Public API (unchanged) ──────────────────────────────────────────────
 
+/// Default for [`Config::max_concurrent_papers`].
+pub const DEFAULT_MAX_CONCURRENT_PAPERS: usize = 4;
+
 /// A reference validation job submitted to the pool.
 pub struct RefJob {
     pub reference: Reference,
@@ -32,6 +40,10 @@ pub struct RefJob {
     pub total: usize,
     /// Progress callback for this job (emits Checking, Result, Warning, etc.).
     pub progress: Arc<dyn Fn(ProgressEvent) + Send + Sync>,
+    /// Cancels just this job, e.g. when its paper is abandoned. A cancelled
+    /// job stops querying and is dropped without a result, so `result_tx`
+    /// closes instead. `None` runs it until the pool itself is cancelled.
+    pub cancel: Option<CancellationToken>,
 }
 
 /// A pool of coordinator + drainer tasks that process reference validation jobs.
@@ -49,12 +61,30 @@ impl ValidationPool {
     /// One drainer task is spawned per enabled remote DB. Coordinators handle
     /// local DBs inline, then fan out to per-DB drainer queues (including DOI).
     pub fn new(config: Arc<Config>, cancel: CancellationToken, num_workers: usize) -> Self {
+        let context = CheckContext::from_config(&config);
+        Self::with_context(config, context, cancel, num_workers)
+    }
+
+    /// Create a new pool that uses the cache, rate limiters and HTTP client
+    /// from `context` instead of those on `config`.
+    pub fn with_context(
+        config: Arc<Config>,
+        context: CheckContext,
+        cancel: CancellationToken,
+        num_workers: usize,
+    ) -> Self {
         let (job_tx, job_rx) = async_channel::unbounded::<RefJob>();
-        let client = reqwest::Client::builder()
-            .pool_max_idle_per_host(2)
-            .pool_idle_timeout(Duration::from_secs(30))
-            .build()
-            .unwrap_or_else(|_| reqwest::Client::new());
+        let config = {
+            let mut config = (*config).clone();
+            context.apply_to(&mut config);
+            if let Some(ProgressEvent::Warning { message, .. }) =
+                crate::checker::load_local_bib(&mut config, 0)
+            {
+                log::warn!("{}", message);
+            }
+            Arc::new(config)
+        };
+        let client = context.client;
 
         // Build database list and partition into local/remote
         let all_dbs: Vec<Arc<dyn DatabaseBackend>> = build_database_list(&config, None)
@@ -81,6 +111,9 @@ impl ValidationPool {
         }
 
         let drainer_txs = Arc::new(drainer_txs);
+        let dedup = config
+            .dedup
+            .then(|| Arc::new(DedupTable::new(job_tx.downgrade())));
 
         // Spawn coordinator tasks
         let pool_handle = tokio::spawn(async move {
@@ -94,6 +127,7 @@ impl ValidationPool {
                     cancel.clone(),
                     local_dbs.clone(),
                     drainer_txs.clone(),
+                    dedup.clone(),
                 )));
             }
 
@@ -148,6 +182,9 @@ struct RefCollector {
     total: usize,
     title: String,
     progress: Arc<dyn Fn(ProgressEvent) + Send + Sync>,
+    cancel: Option<CancellationToken>,
+    /// The pool's token, for the lookups made while finalizing.
+    pool_cancel: CancellationToken,
     config: Arc<Config>,
     client: reqwest::Client,
 
@@ -166,11 +203,34 @@ struct RefCollector {
     local_result: crate::orchestrator::DbSearchResult,
 }
 
+impl RefCollector {
+    /// Whether the job's own token (not the pool's) has been cancelled.
+    fn is_cancelled(&self) -> bool {
+        self.cancel.as_ref().is_some_and(|c| c.is_cancelled())
+    }
+
+    /// Resolves once the job's own token is cancelled; never without one.
+    async fn cancelled(&self) {
+        match &self.cancel {
+            Some(cancel) => cancel.cancelled().await,
+            None => std::future::pending().await,
+        }
+    }
+
+    /// Resolves once the pool or the job is cancelled.
+    async fn stopped(&self) {
+        tokio::select! {
+            _ = self.pool_cancel.cancelled() => {}
+            _ = self.cancelled() => {}
+        }
+    }
+}
+
 /// Mutable aggregation state protected by a Mutex.
 struct AggState {
     verified_info: Option<VerifiedInfo>,
     first_mismatch: Option<MismatchInfo>,
-    failed_dbs: Vec<String>,
+    failed_dbs: Vec<FailedDb>,
     db_results: Vec<DbResult>,
 }
 
@@ -194,8 +254,8 @@ struct DrainerJob {
 // ── Drainer ─────────────────────────────────────────────────────────────
 
 /// Drainer task for a remote DB. Processes refs sequentially at the DB's natural
-/// rate. Multiple drainers may share a channel for the same DB to pipeline
-/// requests when response time exceeds the governor interval.
+/// rate, or with [`Config::adaptive_concurrency`] several at once, as many as
+/// the DB's [`ConcurrencyWindow`](rate_limit::ConcurrencyWindow) allows.
 async fn drainer_loop(
     rx: async_channel::Receiver<DrainerJob>,
     db: Arc<dyn DatabaseBackend>,
@@ -203,53 +263,128 @@ async fn drainer_loop(
     client: reqwest::Client,
     cancel: CancellationToken,
 ) {
-    let timeout = Duration::from_secs(config.db_timeout_secs);
-    let rate_limiters = config.rate_limiters.clone();
-    let cache = config.query_cache.clone();
-    let requires_doi = db.requires_doi();
+    if !config.adaptive_concurrency {
+        while let Ok(job) = rx.recv().await {
+            drain_job(&job.collector, db.as_ref(), &config, &client, &cancel).await;
+        }
+        return;
+    }
 
+    let window = Arc::new(rate_limit::ConcurrencyWindow::new(rate_limit_count(
+        &config,
+        db.name(),
+    )));
+    let mut queries = tokio::task::JoinSet::new();
     while let Ok(job) = rx.recv().await {
-        let collector = &job.collector;
+        let permit = window.acquire().await;
+        let (window, db, config, client, cancel) = (
+            window.clone(),
+            db.clone(),
+            config.clone(),
+            client.clone(),
+            cancel.clone(),
+        );
+        queries.spawn(async move {
+            let collector = &job.collector;
+            let queried = drain_job(collector, db.as_ref(), &config, &client, &cancel).await;
+            let count = rate_limit_count(&config, db.name());
+            if let Some(limit) = window.complete(permit, count, queried) {
+                (collector.progress)(ProgressEvent::ConcurrencyChanged {
+                    db_name: db.name().to_string(),
+                    limit,
+                });
+            }
+        });
+        while queries.try_join_next().is_some() {}
+    }
+    while queries.join_next().await.is_some() {}
+}
 
-        // Skip remaining jobs after cancellation
-        if cancel.is_cancelled() {
-            skip_and_decrement(collector, db.name()).await;
-            continue;
-        }
+/// 429s `db_name` has answered so far, per its rate limiter.
+fn rate_limit_count(config: &Config, db_name: &str) -> u32 {
+    config
+        .rate_limiters
+        .get(db_name)
+        .map_or(0, |l| l.rate_limit_count())
+}
+
+/// Query `db` for one ref, or skip it, and report to its collector. Returns
+/// whether the query reached the database (or its cache).
+async fn drain_job(
+    collector: &RefCollector,
+    db: &dyn DatabaseBackend,
+    config: &Config,
+    client: &reqwest::Client,
+    cancel: &CancellationToken,
+) -> bool {
+    let timeout = config.db_timeout(db.name());
+
+    // Skip remaining jobs after cancellation of the run or the job
+    if cancel.is_cancelled() || collector.is_cancelled() {
+        skip_and_decrement(collector, db.name()).await;
+        return false;
+    }
+
+    // Skip if already verified by another drainer, unless every
+    // database's answer is needed for a consensus
+    if !config.consensus_mode && collector.verified.load(Ordering::Acquire) {
+        skip_and_decrement(collector, db.name()).await;
+        return false;
+    }
 
-        // Skip if already verified by another drainer
-        if collector.verified.load(Ordering::Acquire) {
+    // DOI-requiring backends skip refs without a (well-formed) DOI
+    if db.requires_doi()
+        && !collector
+            .reference
+            .doi
+            .as_deref()
+            .is_some_and(crate::doi::looks_valid)
+    {
+        skip_and_decrement(collector, db.name()).await;
+        return false;
+    }
+
+    // Build DOI context if this ref has a DOI (used by DOI backend)
+    let doi_ctx = collector.reference.doi.as_deref().map(|doi| DoiContext {
+        doi,
+        authors: &collector.reference.authors,
+    });
+
+    // Query (includes cache check + governor acquire + HTTP call),
+    // abandoning it if the run is cancelled meanwhile
+    let on_backoff = |retry_after: Duration, attempt: u32| {
+        (collector.progress)(ProgressEvent::RateLimited {
+            db_name: db.name().to_string(),
+            retry_after,
+            attempt,
+        })
+    };
+    let query = rate_limit::query_with_rate_limit_observed(
+        db,
+        &collector.title,
+        client,
+        timeout,
+        &config.rate_limiters,
+        config.query_cache.as_deref(),
+        doi_ctx.as_ref(),
+        Some(&on_backoff),
+    );
+    let rl_result = tokio::select! {
+        rl_result = query => rl_result,
+        _ = cancel.cancelled() => {
             skip_and_decrement(collector, db.name()).await;
-            continue;
+            return false;
         }
-
-        // DOI-requiring backends skip refs without a DOI
-        if requires_doi && collector.reference.doi.is_none() {
+        _ = collector.cancelled() => {
             skip_and_decrement(collector, db.name()).await;
-            continue;
+            return false;
         }
+    };
 
-        // Build DOI context if this ref has a DOI (used by DOI backend)
-        let doi_ctx = collector.reference.doi.as_deref().map(|doi| DoiContext {
-            doi,
-            authors: &collector.reference.authors,
-        });
-
-        // Query (includes cache check + governor acquire + HTTP call)
-        let rl_result = rate_limit::query_with_rate_limit(
-            db.as_ref(),
-            &collector.title,
-            &client,
-            timeout,
-            &rate_limiters,
-            cache.as_deref(),
-            doi_ctx.as_ref(),
-        )
-        .await;
-
-        // Process result and decrement remaining
-        report_result(collector, db.name(), rl_result).await;
-    }
+    // Process result and decrement remaining
+    let threshold = config.title_threshold(db);
+    report_result(collector, db.name(), threshold, rl_result).await;
+    true
 }
 
 /// Emit a Skipped event and decrement the collector's remaining counter.
@@ -268,9 +403,15 @@ async fn skip_and_decrement(collector: &RefCollector, db_name: &str) {
             db_name: db_name.to_string(),
             status: DbStatus::Skipped,
             elapsed: None,
+            found_title: None,
             found_authors: vec![],
             paper_url: None,
             error_message: None,
+            alternates: vec![],
+            threshold: None,
+            score: None,
+            venue: None,
+            year: None,
         });
     }
 
@@ -284,15 +425,33 @@ async fn skip_and_decrement(collector: &RefCollector, db_name: &str) {
 async fn report_result(
     collector: &RefCollector,
     db_name: &str,
+    threshold: f64,
     rl_result: rate_limit::RateLimitedResult,
 ) {
     let elapsed = rl_result.elapsed;
     let check_openalex_authors = collector.config.check_openalex_authors;
-
-    match rl_result.result {
-        Ok((Some(_found_title), found_authors, paper_url)) => {
-            let ref_authors = &collector.reference.authors;
-            if ref_authors.is_empty() || validate_authors(ref_authors, &found_authors) {
+    let author_policy = collector.config.author_policy();
+
+    let ref_authors = &collector.reference.authors;
+    // Hold the answer to this database's threshold and the minimum score,
+    // then, among equally good title matches, go with the one the authors
+    // point to.
+    match rl_result.result.map(|r| {
+        r.at_threshold(&collector.title, threshold)
+            .at_min_score(&collector.title, collector.config.min_match_score)
+            .prefer_authors(ref_authors, author_policy)
+    }) {
+        Ok(DbQueryResult {
+            found_title: Some(found_title),
+            authors: found_authors,
+            paper_url,
+            alternates,
+            venue,
+            year,
+            ..
+        }) => {
+            let score = Some(title_similarity(&collector.title, &found_title));
+            if ref_authors.is_empty() || author_policy.accepts(ref_authors, &found_authors) {
                 // Verified — set flag so other drainers can skip
                 collector.verified.store(true, Ordering::Release);
 
@@ -309,9 +468,15 @@ async fn report_result(
                     db_name: db_name.to_string(),
                     status: DbStatus::Match,
                     elapsed: Some(elapsed),
+                    found_title: Some(found_title),
                     found_authors: found_authors.clone(),
                     paper_url: paper_url.clone(),
                     error_message: None,
+                    alternates,
+                    threshold: Some(threshold),
+                    score,
+                    venue,
+                    year,
                 });
                 if state.verified_info.is_none() {
                     state.verified_info = Some(VerifiedInfo {
@@ -335,9 +500,15 @@ async fn report_result(
                     db_name: db_name.to_string(),
                     status: DbStatus::AuthorMismatch,
                     elapsed: Some(elapsed),
+                    found_title: Some(found_title),
                     found_authors: found_authors.clone(),
                     paper_url: paper_url.clone(),
                     error_message: None,
+                    alternates,
+                    threshold: Some(threshold),
+                    score,
+                    venue,
+                    year,
                 });
                 if state.first_mismatch.is_none()
                     && (db_name != "OpenAlex" || check_openalex_authors)
@@ -350,7 +521,11 @@ async fn report_result(
                 }
             }
         }
-        Ok((None, _, _)) => {
+        Ok(DbQueryResult {
+            found_title: None,
+            rejected_title,
+            ..
+        }) => {
             (collector.progress)(ProgressEvent::DatabaseQueryComplete {
                 paper_index: 0,
                 ref_index: collector.ref_index,
@@ -364,9 +539,15 @@ async fn report_result(
                 db_name: db_name.to_string(),
                 status: DbStatus::NoMatch,
                 elapsed: Some(elapsed),
+                found_title: rejected_title,
                 found_authors: vec![],
                 paper_url: None,
                 error_message: None,
+                alternates: vec![],
+                threshold: Some(threshold),
+                score: None,
+                venue: None,
+                year: None,
             });
         }
         Err(err) => {
@@ -383,12 +564,20 @@ async fn report_result(
                 db_name: db_name.to_string(),
                 status: DbStatus::Error,
                 elapsed: Some(elapsed),
+                found_title: None,
                 found_authors: vec![],
                 paper_url: None,
                 error_message: Some(err.to_string()),
+                alternates: vec![],
+                threshold: None,
+                score: None,
+                venue: None,
+                year: None,
             });
             log::debug!("{}: {}", db_name, err);
-            state.failed_dbs.push(db_name.to_string());
+            state
+                .failed_dbs
+                .push(FailedDb::new(db_name, err.failure_reason()));
         }
     }
 
@@ -397,10 +586,77 @@ async fn report_result(
     }
 }
 
+/// Build the reference's [`DoiInfo`] from the DOI drainer's result, resolving
+/// the DOI on its own only when the drainer did not get to it.
+///
+/// The drainer skips references another database already verified (or
+/// fails), and without the extra lookup a verified reference would report
+/// its DOI as unresolved. `None` if the pool or the job is cancelled during
+/// that lookup.
+async fn build_doi_info(
+    collector: &RefCollector,
+    doi: &str,
+    db_results: &[DbResult],
+) -> Option<DoiInfo> {
+    let resolved = |title: Option<String>, title_mismatch: bool| DoiInfo {
+        doi: doi.to_string(),
+        valid: true,
+        title,
+        structurally_valid: true,
+        title_mismatch,
+    };
+    let unresolved = || DoiInfo {
+        doi: doi.to_string(),
+        valid: false,
+        title: None,
+        structurally_valid: crate::doi::looks_valid(doi),
+        title_mismatch: false,
+    };
+    let doi_result = db_results.iter().find(|r| r.db_name == "DOI");
+    match doi_result.map(|r| (&r.status, r.found_title.as_ref())) {
+        Some((DbStatus::Match | DbStatus::AuthorMismatch, title)) => {
+            return Some(resolved(title.cloned(), false));
+        }
+        // The DOI resolved to a paper the drainer turned down
+        Some((DbStatus::NoMatch, Some(title))) => {
+            let resolution = crate::doi::DoiValidation {
+                valid: true,
+                title: Some(title.clone()),
+                authors: vec![],
+                error: None,
+                failure: None,
+            };
+            let mismatch = crate::doi::doi_title_mismatch(&resolution, &collector.title);
+            return Some(resolved(Some(title.clone()), mismatch));
+        }
+        Some((DbStatus::NoMatch, None)) => return Some(unresolved()),
+        // No DOI result at all means the DOI backend is disabled, and
+        // malformed DOIs are never sent to doi.org.
+        None => return Some(unresolved()),
+        _ if !crate::doi::looks_valid(doi) => return Some(unresolved()),
+        _ => {}
+    }
+    let lookup = crate::db::doi_resolver::resolve_doi_info(
+        doi,
+        &collector.title,
+        &collector.client,
+        collector.config.db_timeout("DOI"),
+        &collector.config.rate_limiters,
+    );
+    tokio::select! {
+        info = lookup => Some(info),
+        _ = collector.stopped() => None,
+    }
+}
+
 /// Build the final result and send it on the oneshot channel.
 ///
 /// Called exactly once, by whichever drainer decrements `remaining` to 0.
+/// A cancelled job gets no result; dropping the collector closes its channel.
 async fn finalize_collector(collector: &RefCollector) {
+    if collector.is_cancelled() {
+        return;
+    }
     let (status, source, found_authors, paper_url, remote_failed_dbs, remote_db_results) = {
         let state = collector.state.lock().unwrap_or_else(|e| e.into_inner());
 
@@ -441,60 +697,128 @@ async fn finalize_collector(collector: &RefCollector) {
     let mut all_failed_dbs = collector.local_result.failed_dbs.clone();
     all_failed_dbs.extend(remote_failed_dbs);
 
-    // Build doi_info from reference DOI + DOI drainer result
-    let doi_info = collector.reference.doi.as_ref().map(|doi| {
-        let valid = all_db_results.iter().any(|r| {
-            r.db_name == "DOI" && matches!(r.status, DbStatus::Match | DbStatus::AuthorMismatch)
-        });
-        DoiInfo {
-            doi: doi.clone(),
-            valid,
-            title: None,
-        }
-    });
-
-    // Retraction check if verified
-    let retraction_info = if status == Status::Verified {
-        // Prefer DOI-based retraction check when available
-        if let Some(ref doi) = collector.reference.doi {
-            check_retraction_for_doi(
-                doi,
-                &collector.client,
-                Duration::from_secs(collector.config.db_timeout_secs),
-                collector.config.crossref_mailto.as_deref(),
+    let (status, source, agreeing_dbs, found_authors, paper_url) =
+        if collector.config.consensus_mode {
+            let mut search = DbSearchResult {
+                status,
+                source,
+                agreeing_dbs: vec![],
+                found_authors,
+                paper_url,
+                failed_dbs: vec![],
+                db_results: all_db_results.clone(),
+            };
+            apply_consensus(
+                &mut search,
+                collector.config.consensus_min,
+                collector.config.check_openalex_authors,
+            );
+            (
+                search.status,
+                search.source,
+                search.agreeing_dbs,
+                search.found_authors,
+                search.paper_url,
             )
-            .await
         } else {
-            check_retraction_for_title(
-                &collector.title,
-                &collector.client,
-                Duration::from_secs(collector.config.db_timeout_secs),
-                collector.config.crossref_mailto.as_deref(),
-            )
-            .await
-        }
+            (status, source, vec![], found_authors, paper_url)
+        };
+
+    // With the ORCID check, an author mismatch is verified after all when a
+    // cited author claims the matched work
+    let orcid = if status == Status::AuthorMismatch {
+        crate::orcid::confirm_mismatch(
+            &collector.reference,
+            paper_url.as_deref(),
+            &found_authors,
+            &collector.config,
+            &collector.client,
+        )
+        .await
     } else {
         None
     };
+    let status = if orcid.is_some() {
+        Status::Verified
+    } else {
+        status
+    };
+
+    let doi_info = match collector.reference.doi.as_deref() {
+        Some(doi) => match build_doi_info(collector, doi, &all_db_results).await {
+            Some(info) => Some(info),
+            None => return,
+        },
+        None => None,
+    };
 
-    let result = ValidationResult {
+    // Retraction check if verified: the offline dataset by DOI first, then
+    // CrossRef, preferring the DOI when available
+    let retraction_info = if status != Status::Verified {
+        None
+    } else {
+        let config = &collector.config;
+        let local =
+            crate::checker::offline_retraction(config, &collector.reference, paper_url.as_deref());
+        let timeout = Duration::from_secs(config.db_timeout_secs);
+        let mailto = config.crossref_mailto.as_deref();
+        match collector.reference.doi.as_deref() {
+            Some(doi) => {
+                let crossref = check_retraction(doi, &collector.client, timeout, mailto);
+                with_crossref_fallback(local, crossref).await
+            }
+            None => {
+                let crossref =
+                    check_retraction_by_title(&collector.title, &collector.client, timeout, mailto);
+                with_crossref_fallback(local, crossref).await
+            }
+        }
+    };
+
+    let reason = crate::flag_reason(
+        &collector.config,
+        &status,
+        doi_info.as_ref(),
+        &all_db_results,
+    );
+    let match_score = crate::source_score(source.as_deref(), &all_db_results);
+    let (venue, year) = crate::source_publication(source.as_deref(), &all_db_results);
+    let arxiv_info = crate::arxiv_info(&collector.reference, &all_db_results);
+    let mut result = ValidationResult {
         title: collector.title.clone(),
         raw_citation: collector.reference.raw_citation.clone(),
         ref_authors: collector.reference.authors.clone(),
         status,
         source,
+        agreeing_dbs,
         found_authors,
         paper_url,
         failed_dbs: all_failed_dbs,
         db_results: all_db_results,
         doi_info,
-        arxiv_info: collector.reference.arxiv_id.as_ref().map(|id| ArxivInfo {
-            arxiv_id: id.clone(),
-            valid: false,
-            title: None,
-        }),
+        arxiv_info,
         retraction_info,
+        reason,
+        match_score,
+        title_near_miss: None,
+        venue,
+        year,
+        orcid,
     };
+    result.title_near_miss = crate::title_near_miss(&collector.config, &result);
+
+    // Link a verified reference's open-access copy, whichever database
+    // verified it
+    let open_access =
+        crate::db::unpaywall::open_access_url(&result, &collector.config, &collector.client);
+    tokio::select! {
+        url = open_access => {
+            if let Some(url) = url {
+                result.paper_url = Some(url);
+            }
+        }
+        _ = collector.stopped() => return,
+    }
 
     emit_final_events(
         collector.progress.as_ref(),
@@ -514,6 +838,145 @@ async fn finalize_collector(collector: &RefCollector) {
     }
 }
 
+// ── Deduplication ───────────────────────────────────────────────────────
+
+/// Lookups shared by references with the same normalized title, cited
+/// surnames and DOI, for [`Config::dedup`]. The first such reference is
+/// looked up; later ones wait for its result, or take it at once if it is
+/// already in. The authors are part of the key because the verdict depends
+/// on them: the same title with other authors may be an author mismatch.
+/// So is the DOI, which each reference needs resolved and checked on its own.
+struct DedupTable {
+    entries: Mutex<HashMap<String, DedupEntry>>,
+    /// Hands waiting jobs back to the pool when their lookup is cancelled;
+    /// weak, so the table doesn't keep the pool open.
+    resubmit: async_channel::WeakSender<RefJob>,
+}
+
+enum DedupEntry {
+    /// Being looked up; these jobs wait for the result.
+    InFlight(Vec<RefJob>),
+    Done(Box<ValidationResult>),
+}
+
+impl DedupTable {
+    fn new(resubmit: async_channel::WeakSender<RefJob>) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            resubmit,
+        }
+    }
+
+    /// Take `job` over if its title, authors and DOI were seen before,
+    /// returning `None`.
+    /// Otherwise hand it back to be looked up, with its result now passing
+    /// through the table on the way to its own receiver.
+    fn claim(self: &Arc<Self>, mut job: RefJob) -> Option<RefJob> {
+        if job.reference.doi_only {
+            return Some(job);
+        }
+        let title = normalize_title(job.reference.title.as_deref().unwrap_or_default());
+        if title.is_empty() {
+            return Some(job);
+        }
+        let doi = job.reference.doi.as_deref().map(|doi| {
+            crate::retraction_watch::normalize_doi(doi).unwrap_or_else(|| doi.trim().to_lowercase())
+        });
+        let key = format!(
+            "{}\n{}\n{}",
+            title,
+            crate::authors::surname_key(&job.reference.authors),
+            doi.unwrap_or_default()
+        );
+
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        match entries.get_mut(&key) {
+            Some(DedupEntry::Done(result)) => {
+                let result = (**result).clone();
+                drop(entries);
+                deliver_duplicate(job, result);
+                None
+            }
+            Some(DedupEntry::InFlight(waiting)) => {
+                waiting.push(job);
+                None
+            }
+            None => {
+                entries.insert(key.clone(), DedupEntry::InFlight(Vec::new()));
+                let (result_tx, result_rx) = oneshot::channel();
+                let own_tx = std::mem::replace(&mut job.result_tx, result_tx);
+                tokio::spawn(Arc::clone(self).fan_out(key, result_rx, own_tx));
+                Some(job)
+            }
+        }
+    }
+
+    /// Pass a lookup's result to its own reference and to every one waiting
+    /// on it. If the lookup was cancelled instead, the waiting jobs go back
+    /// to the pool, where the first of them is looked up in its place.
+    async fn fan_out(
+        self: Arc<Self>,
+        key: String,
+        result_rx: oneshot::Receiver<ValidationResult>,
+        own_tx: oneshot::Sender<ValidationResult>,
+    ) {
+        let result = result_rx.await.ok();
+        let waiting = {
+            let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+            let entry = match &result {
+                Some(result) => entries.insert(key, DedupEntry::Done(Box::new(result.clone()))),
+                None => entries.remove(&key),
+            };
+            match entry {
+                Some(DedupEntry::InFlight(waiting)) => waiting,
+                _ => Vec::new(),
+            }
+        };
+
+        match result {
+            Some(result) => {
+                let _ = own_tx.send(result.clone());
+                for job in waiting {
+                    deliver_duplicate(job, result.clone());
+                }
+            }
+            None => {
+                if let Some(tx) = self.resubmit.upgrade() {
+                    for job in waiting {
+                        let _ = tx.try_send(job);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Complete `job` with the result of another reference's lookup, keeping
+/// its own title and citation.
+fn deliver_duplicate(job: RefJob, mut result: ValidationResult) {
+    if job.cancel.as_ref().is_some_and(|c| c.is_cancelled()) {
+        return;
+    }
+    let title = job.reference.title.unwrap_or_default();
+    result.title = title.clone();
+    result.raw_citation = job.reference.raw_citation;
+    result.ref_authors = job.reference.authors;
+
+    (job.progress)(ProgressEvent::Duplicate {
+        index: job.ref_index,
+        total: job.total,
+        title: title.clone(),
+    });
+    emit_final_events(
+        job.progress.as_ref(),
+        &result,
+        job.ref_index,
+        job.total,
+        &title,
+    );
+    let _ = job.result_tx.send(result);
+}
+
 // ── Coordinator ─────────────────────────────────────────────────────────
 
 /// Coordinator loop: pick a ref, run local DBs inline, fan out to drainers.
@@ -524,20 +987,36 @@ async fn coordinator_loop(
     cancel: CancellationToken,
     _local_dbs: Vec<Arc<dyn DatabaseBackend>>,
     drainer_txs: Arc<Vec<(String, async_channel::Sender<DrainerJob>)>>,
+    dedup: Option<Arc<DedupTable>>,
 ) {
     while let Ok(job) = job_rx.recv().await {
         if cancel.is_cancelled() {
             break;
         }
 
+        // A title seen before waits for (or takes) that lookup's result
+        let job = match &dedup {
+            Some(table) => match table.claim(job) {
+                Some(job) => job,
+                None => continue,
+            },
+            None => job,
+        };
+
         let RefJob {
             reference,
             result_tx,
             ref_index,
             total,
             progress,
+            cancel: job_cancel,
         } = job;
 
+        // A job cancelled while queued is dropped, closing its result channel
+        if job_cancel.as_ref().is_some_and(|c| c.is_cancelled()) {
+            continue;
+        }
+
         let title = reference.title.clone().unwrap_or_default();
 
         // Emit Checking event
@@ -547,8 +1026,21 @@ async fn coordinator_loop(
             title: title.clone(),
         });
 
-        // --- Local DB phase (inline, <1ms) ---
         let db_complete_cb = make_db_callback(progress.clone(), ref_index);
+
+        // URL-only references kept for their DOI skip the title search
+        if reference.doi_only {
+            let result =
+                crate::checker::check_doi_only_reference(&reference, &config, &client).await;
+            for db_result in &result.db_results {
+                db_complete_cb(db_result.clone());
+            }
+            emit_final_events(progress.as_ref(), &result, ref_index, total, &title);
+            let _ = result_tx.send(result);
+            continue;
+        }
+
+        // --- Local DB phase (inline, <1ms) ---
         let local_result = query_local_databases(
             &title,
             &reference.authors,
@@ -557,13 +1049,15 @@ async fn coordinator_loop(
             false,
             None,
             Some(&db_complete_cb),
+            None,
         )
         .await;
 
         if local_result.status == Status::Verified {
             // query_local_databases already emitted Skipped for remaining DBs
             // (including remote) via the on_db_complete callback
-            let result = build_validation_result(&reference, &title, local_result, None);
+            let result =
+                build_validation_result(&reference, &title, &config, &client, local_result).await;
             emit_final_events(progress.as_ref(), &result, ref_index, total, &title);
             let _ = result_tx.send(result);
             continue;
@@ -572,7 +1066,16 @@ async fn coordinator_loop(
         // --- Fan out to drainer queues ---
         if drainer_txs.is_empty() {
             // No remote DBs enabled — build result from local phase
-            let result = build_validation_result(&reference, &title, local_result, None);
+            let mut local_result = local_result;
+            if config.consensus_mode {
+                apply_consensus(
+                    &mut local_result,
+                    config.consensus_min,
+                    config.check_openalex_authors,
+                );
+            }
+            let result =
+                build_validation_result(&reference, &title, &config, &client, local_result).await;
             emit_final_events(progress.as_ref(), &result, ref_index, total, &title);
             let _ = result_tx.send(result);
             continue;
@@ -584,6 +1087,8 @@ async fn coordinator_loop(
             total,
             title,
             progress,
+            cancel: job_cancel,
+            pool_cancel: cancel.clone(),
             config: config.clone(),
             client: client.clone(),
             remaining: AtomicUsize::new(drainer_txs.len()),
@@ -656,8 +1161,17 @@ fn emit_final_events(
             index: ref_index,
             total,
             title: title.to_string(),
-            failed_dbs: result.failed_dbs.clone(),
-            message: format!("{} timed out; {}", result.failed_dbs.join(", "), context),
+            failed_dbs: result.failed_db_names(),
+            message: format!(
+                "{}; {}",
+                result
+                    .failed_dbs
+                    .iter()
+                    .map(FailedDb::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                context
+            ),
         });
     }
 
@@ -668,68 +1182,51 @@ fn emit_final_events(
     });
 }
 
-/// Build ValidationResult from a DbSearchResult.
-fn build_validation_result(
+/// Build ValidationResult from a DbSearchResult settled without the
+/// drainers. As in [`finalize_collector`], an author mismatch goes through
+/// the ORCID check, and a verified reference links its open-access copy. It
+/// is checked against the offline Retraction Watch data only, as that costs
+/// no request.
+async fn build_validation_result(
     reference: &Reference,
     title: &str,
-    db_result: crate::orchestrator::DbSearchResult,
-    retraction_info: Option<crate::RetractionInfo>,
+    config: &Config,
+    client: &reqwest::Client,
+    mut db_result: DbSearchResult,
 ) -> ValidationResult {
-    ValidationResult {
+    let orcid = crate::checker::confirm_with_orcid(reference, &mut db_result, config, client).await;
+    let retraction_info = if db_result.status == Status::Verified {
+        crate::checker::offline_retraction(config, reference, db_result.paper_url.as_deref())
+    } else {
+        None
+    };
+    let reason = crate::flag_reason(config, &db_result.status, None, &db_result.db_results);
+    let match_score = crate::source_score(db_result.source.as_deref(), &db_result.db_results);
+    let (venue, year) =
+        crate::source_publication(db_result.source.as_deref(), &db_result.db_results);
+    let arxiv_info = crate::arxiv_info(reference, &db_result.db_results);
+    let mut result = ValidationResult {
         title: title.to_string(),
         raw_citation: reference.raw_citation.clone(),
         ref_authors: reference.authors.clone(),
         status: db_result.status,
         source: db_result.source,
+        agreeing_dbs: db_result.agreeing_dbs,
         found_authors: db_result.found_authors,
         paper_url: db_result.paper_url,
         failed_dbs: db_result.failed_dbs,
         db_results: db_result.db_results,
         doi_info: None,
-        arxiv_info: reference.arxiv_id.as_ref().map(|id| ArxivInfo {
-            arxiv_id: id.clone(),
-            valid: false,
-            title: None,
-        }),
+        arxiv_info,
         retraction_info,
-    }
-}
-
-/// Check retraction by DOI, returning info if retracted.
-async fn check_retraction_for_doi(
-    doi: &str,
-    client: &reqwest::Client,
-    timeout: std::time::Duration,
-    mailto: Option<&str>,
-) -> Option<crate::RetractionInfo> {
-    let retraction = crate::retraction::check_retraction(doi, client, timeout, mailto).await;
-    if retraction.retracted {
-        Some(crate::RetractionInfo {
-            is_retracted: true,
-            retraction_doi: retraction.retraction_doi,
-            retraction_source: retraction.retraction_type,
-        })
-    } else {
-        None
-    }
-}
-
-/// Check retraction by title, returning info if retracted.
-async fn check_retraction_for_title(
-    title: &str,
-    client: &reqwest::Client,
-    timeout: std::time::Duration,
-    mailto: Option<&str>,
-) -> Option<crate::RetractionInfo> {
-    let retraction =
-        crate::retraction::check_retraction_by_title(title, client, timeout, mailto).await;
-    if retraction.retracted {
-        Some(crate::RetractionInfo {
-            is_retracted: true,
-            retraction_doi: retraction.retraction_doi,
-            retraction_source: retraction.retraction_type,
-        })
-    } else {
-        None
-    }
+        reason,
+        match_score,
+        title_near_miss: None,
+        venue,
+        year,
+        orcid,
+    };
+    result.title_near_miss = crate::title_near_miss(config, &result);
+    crate::checker::link_open_access(&mut result, config, client).await;
+    result
 }
@@ -5,15 +5,17 @@
 //! and the error is returned immediately.
 
 use std::collections::HashMap;
-use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
 use arc_swap::ArcSwap;
 use governor::clock::DefaultClock;
 use governor::state::{InMemoryState, NotKeyed};
 use governor::{Quota, RateLimiter};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 
+use crate::FailureReason;
 use crate::cache::QueryCache;
 use crate::db::{DatabaseBackend, DbQueryResult};
 
@@ -25,10 +27,29 @@ type DirectLimiter = RateLimiter<NotKeyed, InMemoryState, DefaultClock>;
 pub enum DbQueryError {
     /// Server returned 429 Too Many Requests.
     RateLimited { retry_after: Option<Duration> },
+    /// No answer within the database's timeout.
+    Timeout,
+    /// The request never reached the server (DNS, connection refused, TLS).
+    Network(String),
+    /// The server answered with an unexpected HTTP status.
+    Http(reqwest::StatusCode),
     /// Any other error.
     Other(String),
 }
 
+impl DbQueryError {
+    /// The kind of failure, for reports that tell throttling from outages.
+    pub fn failure_reason(&self) -> FailureReason {
+        match self {
+            DbQueryError::RateLimited { .. } => FailureReason::RateLimited,
+            DbQueryError::Timeout => FailureReason::Timeout,
+            DbQueryError::Network(_) => FailureReason::Network,
+            DbQueryError::Http(_) => FailureReason::Http,
+            DbQueryError::Other(_) => FailureReason::Other,
+        }
+    }
+}
+
 impl std::fmt::Display for DbQueryError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -36,7 +57,9 @@ impl std::fmt::Display for DbQueryError {
                 retry_after: Some(d),
             } => write!(f, "Rate limited (429), retry after {:.1}s", d.as_secs_f64()),
             DbQueryError::RateLimited { retry_after: None } => write!(f, "Rate limited (429)"),
-            DbQueryError::Other(msg) => write!(f, "{}", msg),
+            DbQueryError::Timeout => write!(f, "Timed out"),
+            DbQueryError::Http(status) => write!(f, "HTTP {}", status),
+            DbQueryError::Network(msg) | DbQueryError::Other(msg) => write!(f, "{}", msg),
         }
     }
 }
@@ -49,18 +72,44 @@ impl From<String> for DbQueryError {
     }
 }
 
+impl From<reqwest::Error> for DbQueryError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            DbQueryError::Timeout
+        } else if e.is_connect() || e.is_request() {
+            DbQueryError::Network(e.to_string())
+        } else {
+            DbQueryError::Other(e.to_string())
+        }
+    }
+}
+
+/// Quiet period, with no 429s, after which a slowed limiter halves its
+/// slowdown factor.
+pub const DECAY_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Largest slowdown factor a run of 429s can push a limiter to.
+const MAX_SLOWDOWN_FACTOR: u32 = 16;
+
 /// Per-DB rate limiter with adaptive rate adjustment via ArcSwap.
 ///
 /// When a 429 is received, the governor is atomically swapped to a slower rate.
-/// After a cooldown period (60s) with no 429s, the original rate is restored.
+/// Recovery is gradual: each [`DECAY_INTERVAL`] without a 429 halves the
+/// slowdown, so a limiter at 16x steps through 8x, 4x and 2x before returning
+/// to its base rate, rather than jumping straight back into the burst limit
+/// it just hit.
 pub struct AdaptiveDbLimiter {
     limiter: ArcSwap<DirectLimiter>,
     /// Base period between allowed requests.
     base_period: Duration,
     /// Current slowdown factor (1 = normal, 2 = half rate, etc.).
     current_factor: AtomicU32,
-    /// Timestamp of the last 429 response.
+    /// Start of the current quiet period: the last 429 or decay step.
+    quiet_since: std::sync::Mutex<Option<Instant>>,
+    /// When the last 429 was received.
     last_429: std::sync::Mutex<Option<Instant>>,
+    /// Number of 429s received so far.
+    rate_limit_count: AtomicU32,
 }
 
 impl AdaptiveDbLimiter {
@@ -72,7 +121,9 @@ impl AdaptiveDbLimiter {
             limiter: ArcSwap::from(limiter),
             base_period: period,
             current_factor: AtomicU32::new(1),
+            quiet_since: std::sync::Mutex::new(None),
             last_429: std::sync::Mutex::new(None),
+            rate_limit_count: AtomicU32::new(0),
         }
     }
 
@@ -94,42 +145,190 @@ impl AdaptiveDbLimiter {
 
     /// Called when a 429 is received. Doubles the slowdown factor and swaps the governor.
     pub fn on_rate_limited(&self) {
-        if let Ok(mut last) = self.last_429.lock() {
-            *last = Some(Instant::now());
-        }
+        let now = Instant::now();
+        *self.last_429.lock().unwrap_or_else(|e| e.into_inner()) = Some(now);
+        self.rate_limit_count.fetch_add(1, Ordering::SeqCst);
+        let mut quiet_since = self.quiet_since.lock().unwrap_or_else(|e| e.into_inner());
+        *quiet_since = Some(now);
+
+        let factor = (self.current_factor.load(Ordering::SeqCst) * 2).min(MAX_SLOWDOWN_FACTOR);
+        self.set_factor(factor);
+    }
 
-        // Double factor, cap at 16x slowdown
-        let _ = self
-            .current_factor
-            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |f| {
-                Some((f * 2).min(16))
-            });
+    /// Halve the slowdown factor once for every [`DECAY_INTERVAL`] that has
+    /// passed since the last 429 or decay step. Each step starts a new quiet
+    /// period, so the next one needs a full interval of its own.
+    fn try_decay(&self) {
+        self.try_decay_at(Instant::now());
+    }
 
+    /// [`try_decay`](Self::try_decay) as of `now`.
+    fn try_decay_at(&self, now: Instant) {
+        let mut quiet_since = self.quiet_since.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(since) = *quiet_since else {
+            return;
+        };
         let factor = self.current_factor.load(Ordering::SeqCst);
+        if factor <= 1 {
+            *quiet_since = None;
+            return;
+        }
+
+        let quiet = now.saturating_duration_since(since);
+        let steps = (quiet.as_nanos() / DECAY_INTERVAL.as_nanos()) as u32;
+        if steps == 0 {
+            return;
+        }
+        *quiet_since = Some(since + DECAY_INTERVAL * steps);
+        self.set_factor(factor.checked_shr(steps).unwrap_or(0).max(1));
+    }
+
+    /// The current slowdown factor, after any decay that is due, so a
+    /// limiter nobody has queried lately isn't reported as still throttled.
+    pub fn slowdown_factor(&self) -> u32 {
+        self.try_decay();
+        self.current_factor.load(Ordering::SeqCst)
+    }
+
+    /// Time since the last 429, or `None` if there has not been one.
+    pub fn since_last_429(&self) -> Option<Duration> {
+        self.last_429
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .map(|t| t.elapsed())
+    }
+
+    /// Number of 429s this limiter has seen, for callers that react to new
+    /// ones (see [`ConcurrencyWindow`]).
+    pub fn rate_limit_count(&self) -> u32 {
+        self.rate_limit_count.load(Ordering::SeqCst)
+    }
+
+    /// Record `factor` and swap in a governor running that many times slower
+    /// than the base rate.
+    fn set_factor(&self, factor: u32) {
+        self.current_factor.store(factor, Ordering::SeqCst);
         if let Some(scaled) = self.base_period.checked_mul(factor)
             && let Some(quota) = Quota::with_period(scaled)
         {
-            let new_limiter = Arc::new(DirectLimiter::direct(quota));
-            self.limiter.store(new_limiter);
+            self.limiter.store(Arc::new(DirectLimiter::direct(quota)));
         }
     }
+}
 
-    /// If 30s have passed since the last 429, restore the original rate.
-    fn try_decay(&self) {
-        let should_restore = self
-            .last_429
-            .lock()
-            .ok()
-            .and_then(|last| last.map(|t| t.elapsed().as_secs() >= 30))
-            .unwrap_or(false);
-
-        if should_restore && self.current_factor.load(Ordering::SeqCst) > 1 {
-            self.current_factor.store(1, Ordering::SeqCst);
-            let quota = Quota::with_period(self.base_period).expect("base period valid");
-            let limiter = Arc::new(DirectLimiter::direct(quota));
-            self.limiter.store(limiter);
+/// Most queries a database runs at once with
+/// [`Config::adaptive_concurrency`](crate::Config::adaptive_concurrency).
+pub const MAX_ADAPTIVE_CONCURRENCY: usize = 8;
+
+/// How many queries one database runs at once in adaptive mode, adjusted
+/// the way TCP congestion control adjusts its window.
+///
+/// The window starts at one query. Each full window of queries that completes
+/// without a new 429 grows it by one, up to [`MAX_ADAPTIVE_CONCURRENCY`]; a
+/// new 429 on the database's [`AdaptiveDbLimiter`] halves it. The limiter
+/// still spaces requests at its rate, so the window only decides how many
+/// queries may wait on it, or on a slow response, at the same time.
+pub struct ConcurrencyWindow {
+    permits: Arc<Semaphore>,
+    state: Mutex<WindowState>,
+}
+
+struct WindowState {
+    limit: usize,
+    /// Permits still held by queries that a halving took away; they are
+    /// retired as those queries finish instead of being released.
+    debt: usize,
+    /// Queries completed since the window last grew.
+    clean: usize,
+    /// The limiter's 429 count when the window last reacted to one.
+    seen_429s: u32,
+}
+
+impl ConcurrencyWindow {
+    /// A window of one query; `rate_limit_count` is the database limiter's
+    /// current [`AdaptiveDbLimiter::rate_limit_count`], so earlier 429s
+    /// don't count against it.
+    pub fn new(rate_limit_count: u32) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(1)),
+            state: Mutex::new(WindowState {
+                limit: 1,
+                debt: 0,
+                clean: 0,
+                seen_429s: rate_limit_count,
+            }),
         }
     }
+
+    /// The number of queries currently allowed at once.
+    pub fn limit(&self) -> usize {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).limit
+    }
+
+    /// Wait for room in the window for one more query.
+    pub async fn acquire(&self) -> OwnedSemaphorePermit {
+        self.permits
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("window semaphore is never closed")
+    }
+
+    /// Give back the permit of a finished query and adjust the window.
+    /// `rate_limit_count` is the limiter's count now; `queried` is false for
+    /// a query that was skipped without reaching the database, which says
+    /// nothing about its limits. Returns the new limit if it changed.
+    pub fn complete(
+        &self,
+        permit: OwnedSemaphorePermit,
+        rate_limit_count: u32,
+        queried: bool,
+    ) -> Option<usize> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let before = state.limit;
+        if rate_limit_count != state.seen_429s {
+            state.seen_429s = rate_limit_count;
+            let halved = (state.limit / 2).max(1);
+            state.debt += state.limit - halved;
+            state.limit = halved;
+            state.clean = 0;
+        } else if queried {
+            state.clean += 1;
+            if state.clean >= state.limit && state.limit < MAX_ADAPTIVE_CONCURRENCY {
+                state.clean = 0;
+                state.limit += 1;
+                if state.debt > 0 {
+                    state.debt -= 1;
+                } else {
+                    self.permits.add_permits(1);
+                }
+            }
+        }
+        if state.debt > 0 {
+            state.debt -= 1;
+            permit.forget();
+        } else {
+            drop(permit);
+        }
+        (state.limit != before).then_some(state.limit)
+    }
+}
+
+/// A snapshot of one database's [`AdaptiveDbLimiter`], from
+/// [`RateLimiters::snapshot`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimitState {
+    pub db_name: String,
+    /// Slowdown factor: 1 at the base rate, 2/4/8/16 while throttled.
+    pub factor: u32,
+    /// Time since the database last answered 429, if it ever has.
+    pub since_last_429: Option<Duration>,
+}
+
+impl RateLimitState {
+    pub fn is_throttled(&self) -> bool {
+        self.factor > 1
+    }
 }
 
 /// Collection of per-database rate limiters.
@@ -139,13 +338,13 @@ pub struct RateLimiters {
 
 impl Default for RateLimiters {
     fn default() -> Self {
-        Self::new(false, false)
+        Self::new(false, false, false)
     }
 }
 
 impl RateLimiters {
     /// Build rate limiters based on whether API keys/mailto are configured.
-    pub fn new(has_crossref_mailto: bool, has_s2_api_key: bool) -> Self {
+    pub fn new(has_crossref_mailto: bool, has_s2_api_key: bool, has_ncbi_api_key: bool) -> Self {
         let mut limiters = HashMap::new();
 
         // CrossRef: 1/s without mailto, 3/s with mailto
@@ -172,8 +371,12 @@ impl RateLimiters {
         // Europe PMC: not documented, conservative 2/s
         limiters.insert("Europe PMC", AdaptiveDbLimiter::per_second(2));
 
-        // PubMed: 3/s without key
-        limiters.insert("PubMed", AdaptiveDbLimiter::per_second(3));
+        // PubMed: 3/s without key, 10/s with an NCBI API key
+        let pubmed_rate = if has_ncbi_api_key { 10 } else { 3 };
+        limiters.insert("PubMed", AdaptiveDbLimiter::per_second(pubmed_rate));
+
+        // DataCite: 3000 requests per 5 minutes per IP (10/s) — stay under
+        limiters.insert("DataCite", AdaptiveDbLimiter::per_second(5));
 
         // ACL Anthology (online scraping): conservative 2/s
         limiters.insert("ACL Anthology", AdaptiveDbLimiter::per_second(2));
@@ -181,8 +384,14 @@ impl RateLimiters {
         // OpenAlex: 10/s without key, 100/s with key — light governor so adaptive
         // backoff kicks in if we get 429'd
         limiters.insert("OpenAlex", AdaptiveDbLimiter::per_second(10));
+        // CORE: ~10/s with a key (it is only queried with one) — stay well under
+        limiters.insert("CORE", AdaptiveDbLimiter::per_second(5));
         // DOI (doi.org): generous limit, no documented cap but be polite
         limiters.insert("DOI", AdaptiveDbLimiter::per_second(3));
+        // Unpaywall: asks clients to stay under 10/s (and 100k/day)
+        limiters.insert("Unpaywall", AdaptiveDbLimiter::per_second(5));
+        // ORCID public API: 24/s with bursts of 40 — only the ORCID check uses it
+        limiters.insert("ORCID", AdaptiveDbLimiter::per_second(8));
 
         // SSRN: disabled, skip limiter
         // NeurIPS: disabled, skip limiter
@@ -196,6 +405,21 @@ impl RateLimiters {
         self.limiters.get(db_name)
     }
 
+    /// The state of every limiter, sorted by database name.
+    pub fn snapshot(&self) -> Vec<RateLimitState> {
+        let mut states: Vec<RateLimitState> = self
+            .limiters
+            .iter()
+            .map(|(name, limiter)| RateLimitState {
+                db_name: name.to_string(),
+                factor: limiter.slowdown_factor(),
+                since_last_429: limiter.since_last_429(),
+            })
+            .collect();
+        states.sort_by(|a, b| a.db_name.cmp(&b.db_name));
+        states
+    }
+
     /// Return the current backoff factor for a database (1 = normal, 2/4/8/16 = throttled).
     pub fn backoff_factor(&self, db_name: &str) -> u32 {
         self.limiters
@@ -274,6 +498,10 @@ async fn execute_query(
     db.query(title, client, timeout).await
 }
 
+/// Called with the wait and the attempt number (1-based) just before a
+/// query backs off after a 429.
+pub type OnBackoff<'a> = &'a (dyn Fn(Duration, u32) + Send + Sync);
+
 pub async fn query_with_rate_limit(
     db: &dyn DatabaseBackend,
     title: &str,
@@ -282,10 +510,41 @@ pub async fn query_with_rate_limit(
     rate_limiters: &RateLimiters,
     cache: Option<&QueryCache>,
     doi_context: Option<&DoiContext<'_>>,
+) -> RateLimitedResult {
+    query_with_rate_limit_observed(
+        db,
+        title,
+        client,
+        timeout,
+        rate_limiters,
+        cache,
+        doi_context,
+        None,
+    )
+    .await
+}
+
+/// [`query_with_rate_limit`], reporting each 429 backoff to `on_backoff`
+/// before sleeping so callers can show why the query is slow.
+#[allow(clippy::too_many_arguments)]
+pub async fn query_with_rate_limit_observed(
+    db: &dyn DatabaseBackend,
+    title: &str,
+    client: &reqwest::Client,
+    timeout: Duration,
+    rate_limiters: &RateLimiters,
+    cache: Option<&QueryCache>,
+    doi_context: Option<&DoiContext<'_>>,
+    on_backoff: Option<OnBackoff<'_>>,
 ) -> RateLimitedResult {
     // Check cache before making any network request or waiting on the governor.
     // Skip cache for local/offline backends — they have their own SQLite DBs.
     let use_cache = !db.is_local();
+    // Concurrent duplicates wait here for the first to fill the cache.
+    let _flight = match cache {
+        Some(c) if use_cache => Some(c.claim(title, db.name()).await),
+        _ => None,
+    };
     if use_cache
         && let Some(c) = cache
         && let Some(cached_result) = c.get(title, db.name())
@@ -330,6 +589,9 @@ pub async fn query_with_rate_limit(
                 db.name(),
                 wait.as_secs_f64()
             );
+            if let Some(notify) = on_backoff {
+                notify(wait, 1);
+            }
             tokio::time::sleep(wait).await;
 
             // Re-acquire governor token after sleeping
@@ -352,16 +614,37 @@ pub async fn query_with_rate_limit(
         c.insert(title, db.name(), query_result);
     }
 
-    RateLimitedResult {
-        result,
-        elapsed: start.elapsed(),
+    let elapsed = start.elapsed();
+    match &result {
+        Ok(r) => log::info!(
+            "{}: {} in {:.2}s for {:?}",
+            db.name(),
+            if r.found_title.is_some() {
+                "found"
+            } else {
+                "not found"
+            },
+            elapsed.as_secs_f64(),
+            title
+        ),
+        Err(e) => log::info!(
+            "{}: failed after {:.2}s for {:?}: {}",
+            db.name(),
+            elapsed.as_secs_f64(),
+            title,
+            e
+        ),
     }
+
+    RateLimitedResult { result, elapsed }
 }
 
-/// Legacy wrapper: calls [`query_with_rate_limit`] (ignores `max_retries`).
+/// Legacy wrapper: calls [`query_with_rate_limit_observed`] (ignores
+/// `max_retries`), reporting 429 backoffs to `on_backoff`.
 ///
 /// Kept for API compatibility; inline retry has been replaced by
 /// the pool-level retry queue.
+#[allow(clippy::too_many_arguments)]
 pub async fn query_with_retry(
     db: &dyn DatabaseBackend,
     title: &str,
@@ -370,8 +653,19 @@ pub async fn query_with_retry(
     rate_limiters: &RateLimiters,
     _max_retries: u32,
     cache: Option<&QueryCache>,
+    on_backoff: Option<OnBackoff<'_>>,
 ) -> RateLimitedResult {
-    query_with_rate_limit(db, title, client, timeout, rate_limiters, cache, None).await
+    query_with_rate_limit_observed(
+        db,
+        title,
+        client,
+        timeout,
+        rate_limiters,
+        cache,
+        None,
+        on_backoff,
+    )
+    .await
 }
 
 #[cfg(test)]
@@ -379,6 +673,18 @@ mod tests {
     use super::*;
     use crate::db::mock::{MockDb, MockResponse};
 
+    // ── DbQueryError ───────────────────────────────────────────────────
+
+    #[test]
+    fn http_status_is_an_http_failure() {
+        let err = DbQueryError::Http(reqwest::StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(err.failure_reason(), FailureReason::Http);
+        assert_eq!(err.to_string(), "HTTP 503 Service Unavailable");
+        // A message that merely looks like one is not
+        let other = DbQueryError::Other("HTTP-ish parse failure".into());
+        assert_eq!(other.failure_reason(), FailureReason::Other);
+    }
+
     // ── parse_retry_after ──────────────────────────────────────────────
 
     #[test]
@@ -479,22 +785,149 @@ mod tests {
         limiter.acquire().await;
     }
 
+    /// Pretend the current quiet period started `ago`. Returns false, leaving
+    /// it alone, when the monotonic clock is younger than `ago`.
+    fn backdate_quiet_period(limiter: &AdaptiveDbLimiter, ago: Duration) -> bool {
+        let Some(start) = Instant::now().checked_sub(ago) else {
+            return false;
+        };
+        *limiter.quiet_since.lock().unwrap() = Some(start);
+        true
+    }
+
+    /// Decay as if `quiet` has passed since the current quiet period started.
+    fn decay_after(limiter: &AdaptiveDbLimiter, quiet: Duration) {
+        let since = limiter.quiet_since.lock().unwrap().unwrap();
+        limiter.try_decay_at(since + quiet);
+    }
+
+    fn factor(limiter: &AdaptiveDbLimiter) -> u32 {
+        limiter.current_factor.load(Ordering::SeqCst)
+    }
+
     #[tokio::test]
-    async fn decay_restores_after_30s() {
+    async fn decay_halves_after_quiet_period() {
         let limiter = AdaptiveDbLimiter::per_second(10);
         limiter.on_rate_limited();
         limiter.on_rate_limited();
-        assert_eq!(limiter.current_factor.load(Ordering::SeqCst), 4);
+        assert_eq!(factor(&limiter), 4);
 
-        // Manually backdate last_429 to 31 seconds ago
-        {
-            let mut last = limiter.last_429.lock().unwrap();
-            *last = Some(Instant::now() - Duration::from_secs(31));
+        // Backdating needs a clock older than the interval
+        if !backdate_quiet_period(&limiter, DECAY_INTERVAL + Duration::from_secs(1)) {
+            return;
         }
 
         // acquire() calls try_decay() internally
         limiter.acquire().await;
-        assert_eq!(limiter.current_factor.load(Ordering::SeqCst), 1);
+        assert_eq!(factor(&limiter), 2);
+    }
+
+    #[test]
+    fn decay_steps_down_one_quiet_period_at_a_time() {
+        let limiter = AdaptiveDbLimiter::per_second(10);
+        for _ in 0..4 {
+            limiter.on_rate_limited();
+        }
+        assert_eq!(factor(&limiter), 16);
+
+        for expected in [8, 4, 2, 1] {
+            decay_after(&limiter, DECAY_INTERVAL + Duration::from_millis(100));
+            assert_eq!(factor(&limiter), expected);
+            // The step started a new quiet period; nothing more until it ends.
+            limiter.try_decay();
+            assert_eq!(factor(&limiter), expected);
+        }
+    }
+
+    #[test]
+    fn no_decay_before_quiet_period_ends() {
+        let limiter = AdaptiveDbLimiter::per_second(10);
+        limiter.on_rate_limited();
+        limiter.on_rate_limited();
+        decay_after(&limiter, DECAY_INTERVAL - Duration::from_secs(1));
+        assert_eq!(factor(&limiter), 4);
+    }
+
+    #[test]
+    fn repeated_429s_restart_the_quiet_period() {
+        let limiter = AdaptiveDbLimiter::per_second(10);
+        limiter.on_rate_limited();
+        limiter.on_rate_limited();
+        decay_after(&limiter, DECAY_INTERVAL + Duration::from_secs(1));
+        assert_eq!(factor(&limiter), 2);
+
+        // Hit the limit again mid-recovery: back up, and the clock restarts.
+        limiter.on_rate_limited();
+        assert_eq!(factor(&limiter), 4);
+        limiter.try_decay();
+        assert_eq!(factor(&limiter), 4);
+
+        decay_after(&limiter, DECAY_INTERVAL + Duration::from_secs(1));
+        assert_eq!(factor(&limiter), 2);
+    }
+
+    #[test]
+    fn long_quiet_period_takes_several_steps() {
+        let limiter = AdaptiveDbLimiter::per_second(10);
+        for _ in 0..4 {
+            limiter.on_rate_limited();
+        }
+        // Two and a half intervals: two steps, with half an interval banked
+        // toward the third.
+        decay_after(&limiter, DECAY_INTERVAL * 5 / 2);
+        assert_eq!(factor(&limiter), 4);
+
+        decay_after(&limiter, DECAY_INTERVAL * 20);
+        assert_eq!(factor(&limiter), 1);
+    }
+
+    // ── ConcurrencyWindow ──────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn window_grows_per_clean_window_and_halves_on_429() {
+        let limiter = AdaptiveDbLimiter::per_second(10);
+        let window = ConcurrencyWindow::new(limiter.rate_limit_count());
+
+        // One clean query opens a second slot, two more a third
+        let permit = window.acquire().await;
+        assert_eq!(window.complete(permit, 0, true), Some(2));
+        let (a, b) = (window.acquire().await, window.acquire().await);
+        assert_eq!(window.complete(a, 0, true), None);
+        assert_eq!(window.complete(b, 0, true), Some(3));
+        // Skipped queries don't count towards growth
+        let permit = window.acquire().await;
+        assert_eq!(window.complete(permit, 0, false), None);
+
+        // A 429 while three queries are out halves the window once, and the
+        // permits taken away are retired as their queries finish
+        let (a, b, c) = (
+            window.acquire().await,
+            window.acquire().await,
+            window.acquire().await,
+        );
+        limiter.on_rate_limited();
+        let count = limiter.rate_limit_count();
+        assert_eq!(window.complete(a, count, true), Some(1));
+        assert_eq!(window.permits.available_permits(), 0);
+        assert_eq!(window.complete(b, count, true), Some(2));
+        assert_eq!(window.complete(c, count, true), None);
+        assert_eq!(window.permits.available_permits(), 2);
+    }
+
+    #[tokio::test]
+    async fn window_stays_within_bounds() {
+        let window = ConcurrencyWindow::new(0);
+        for _ in 0..200 {
+            let permit = window.acquire().await;
+            window.complete(permit, 0, true);
+        }
+        assert_eq!(window.limit(), MAX_ADAPTIVE_CONCURRENCY);
+        for count in 1..=10 {
+            let permit = window.acquire().await;
+            window.complete(permit, count, true);
+        }
+        assert_eq!(window.limit(), 1);
+        assert_eq!(window.permits.available_permits(), 1);
     }
 
     // ── RateLimiters ───────────────────────────────────────────────────
@@ -509,8 +942,11 @@ mod tests {
             "Semantic Scholar",
             "Europe PMC",
             "PubMed",
+            "DataCite",
             "ACL Anthology",
+            "CORE",
             "DOI",
+            "Unpaywall",
         ] {
             assert!(limiters.get(name).is_some(), "missing limiter for {name}");
         }
@@ -519,11 +955,11 @@ mod tests {
     #[test]
     fn crossref_rate_varies_with_mailto() {
         // Without mailto, CrossRef gets 1/s → base_period = 1000ms
-        let without = RateLimiters::new(false, false);
+        let without = RateLimiters::new(false, false, false);
         let period_without = without.get("CrossRef").unwrap().base_period;
 
         // With mailto, CrossRef gets 3/s → base_period = 333ms
-        let with = RateLimiters::new(true, false);
+        let with = RateLimiters::new(true, false, false);
         let period_with = with.get("CrossRef").unwrap().base_period;
 
         assert!(
@@ -532,6 +968,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn pubmed_rate_varies_with_ncbi_key() {
+        let without = RateLimiters::new(false, false, false);
+        let with = RateLimiters::new(false, false, true);
+        assert!(
+            with.get("PubMed").unwrap().base_period < without.get("PubMed").unwrap().base_period,
+            "an NCBI key should allow a faster PubMed rate"
+        );
+    }
+
+    #[test]
+    fn snapshot_reports_throttled_databases() {
+        let limiters = RateLimiters::new(false, false, false);
+        assert!(limiters.snapshot().iter().all(|s| !s.is_throttled()));
+
+        let arxiv = limiters.get("arXiv").unwrap();
+        arxiv.on_rate_limited();
+        arxiv.on_rate_limited();
+
+        let snapshot = limiters.snapshot();
+        let names: Vec<&str> = snapshot.iter().map(|s| s.db_name.as_str()).collect();
+        let mut sorted = names.clone();
+        sorted.sort();
+        assert_eq!(names, sorted);
+
+        let throttled: Vec<&RateLimitState> =
+            snapshot.iter().filter(|s| s.is_throttled()).collect();
+        assert_eq!(throttled.len(), 1);
+        assert_eq!(throttled[0].db_name, "arXiv");
+        assert_eq!(throttled[0].factor, 4);
+        assert!(throttled[0].since_last_429.unwrap() < Duration::from_secs(5));
+
+        let crossref = snapshot.iter().find(|s| s.db_name == "CrossRef").unwrap();
+        assert_eq!(crossref.since_last_429, None);
+    }
+
+    #[test]
+    fn snapshot_applies_due_decay() {
+        let limiters = RateLimiters::new(false, false, false);
+        let arxiv = limiters.get("arXiv").unwrap();
+        arxiv.on_rate_limited();
+        // Backdating needs a clock older than the interval
+        if !backdate_quiet_period(arxiv, DECAY_INTERVAL + Duration::from_secs(1)) {
+            return;
+        }
+
+        let snapshot = limiters.snapshot();
+        let state = snapshot.iter().find(|s| s.db_name == "arXiv").unwrap();
+        assert_eq!(state.factor, 1);
+        assert!(state.since_last_429.is_some());
+    }
+
     #[test]
     fn unknown_db_returns_none() {
         let limiters = RateLimiters::default();
@@ -551,7 +1039,7 @@ mod tests {
             },
         );
         let client = reqwest::Client::new();
-        let limiters = RateLimiters::new(false, false);
+        let limiters = RateLimiters::new(false, false, false);
 
         let rl_result = query_with_rate_limit(
             &db,
@@ -565,7 +1053,7 @@ mod tests {
         .await;
 
         assert!(rl_result.result.is_ok());
-        let (title, _, _) = rl_result.result.unwrap();
+        let title = rl_result.result.unwrap().found_title;
         assert_eq!(title.unwrap(), "A Paper");
         assert_eq!(db.call_count(), 1);
     }
@@ -579,7 +1067,7 @@ mod tests {
             },
         );
         let client = reqwest::Client::new();
-        let limiters = RateLimiters::new(false, false);
+        let limiters = RateLimiters::new(false, false, false);
 
         let rl_result = query_with_rate_limit(
             &db,
@@ -597,11 +1085,40 @@ mod tests {
         assert_eq!(db.call_count(), 2);
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn rate_limited_reports_backoff() {
+        let db = MockDb::new(
+            "TestDB",
+            MockResponse::RateLimited {
+                retry_after: Some(Duration::from_secs(5)),
+            },
+        );
+        let client = reqwest::Client::new();
+        let limiters = RateLimiters::new(false, false, false);
+        let backoffs = std::sync::Mutex::new(Vec::new());
+        let on_backoff =
+            |wait: Duration, attempt: u32| backoffs.lock().unwrap().push((wait, attempt));
+
+        query_with_rate_limit_observed(
+            &db,
+            "A Paper",
+            &client,
+            Duration::from_secs(10),
+            &limiters,
+            None,
+            None,
+            Some(&on_backoff),
+        )
+        .await;
+
+        assert_eq!(*backoffs.lock().unwrap(), vec![(Duration::from_secs(5), 1)]);
+    }
+
     #[tokio::test(start_paused = true)]
     async fn other_error_no_retry() {
         let db = MockDb::new("TestDB", MockResponse::Error("connection refused".into()));
         let client = reqwest::Client::new();
-        let limiters = RateLimiters::new(false, false);
+        let limiters = RateLimiters::new(false, false, false);
 
         let rl_result = query_with_rate_limit(
             &db,
@@ -629,7 +1146,7 @@ mod tests {
             },
         );
         let client = reqwest::Client::new();
-        let limiters = RateLimiters::new(false, false);
+        let limiters = RateLimiters::new(false, false, false);
         let cache = QueryCache::default();
 
         // First call: cache miss, queries DB
@@ -670,7 +1187,7 @@ mod tests {
         // Verify that not-found results are cached (negative caching).
         let db = MockDb::new("TestDB", MockResponse::NotFound);
         let client = reqwest::Client::new();
-        let limiters = RateLimiters::new(false, false);
+        let limiters = RateLimiters::new(false, false, false);
         let cache = QueryCache::default();
 
         let rl_result = query_with_rate_limit(
@@ -684,7 +1201,7 @@ mod tests {
         )
         .await;
         assert!(rl_result.result.is_ok());
-        let (title, _, _) = rl_result.result.unwrap();
+        let title = rl_result.result.unwrap().found_title;
         assert!(title.is_none());
         assert_eq!(cache.len(), 1); // not-found cached
 
@@ -721,7 +1238,7 @@ mod tests {
             ],
         );
         let client = reqwest::Client::new();
-        let limiters = RateLimiters::new(false, false);
+        let limiters = RateLimiters::new(false, false, false);
         let cache = QueryCache::default();
 
         let rl_result = query_with_rate_limit(
@@ -764,7 +1281,7 @@ mod tests {
             },
         );
         let client = reqwest::Client::new();
-        let limiters = RateLimiters::new(false, false);
+        let limiters = RateLimiters::new(false, false, false);
         let cache = QueryCache::default();
 
         let rl_result = query_with_rate_limit(
@@ -781,11 +1298,48 @@ mod tests {
         assert!(cache.is_empty()); // errors never cached
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn concurrent_duplicates_share_one_request() {
+        let db = MockDb::new(
+            "TestDB",
+            MockResponse::Found {
+                title: "A Paper".into(),
+                authors: vec!["Author".into()],
+                url: None,
+            },
+        )
+        .with_delay(Duration::from_millis(200));
+        let client = reqwest::Client::new();
+        let limiters = RateLimiters::new(false, false, false);
+        let cache = QueryCache::default();
+        let query = |title| {
+            query_with_rate_limit(
+                &db,
+                title,
+                &client,
+                Duration::from_secs(10),
+                &limiters,
+                Some(&cache),
+                None,
+            )
+        };
+
+        // Three papers citing the same reference, queried at once.
+        let (a, b, c) = tokio::join!(query("A Paper"), query("A paper."), query("a paper"));
+        for rl_result in [a, b, c] {
+            let result = rl_result.result.unwrap();
+            assert_eq!(result.found_title.as_deref(), Some("A Paper"));
+        }
+        assert_eq!(db.call_count(), 1);
+        assert_eq!(cache.misses(), 1);
+        assert_eq!(cache.hits(), 2);
+    }
+
     #[tokio::test(start_paused = true)]
     async fn cache_does_not_store_errors() {
         let db = MockDb::new("TestDB", MockResponse::Error("connection refused".into()));
         let client = reqwest::Client::new();
-        let limiters = RateLimiters::new(false, false);
+        let limiters = RateLimiters::new(false, false, false);
         let cache = QueryCache::default();
 
         let rl_result = query_with_rate_limit(
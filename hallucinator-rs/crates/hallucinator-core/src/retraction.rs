@@ -1,3 +1,4 @@
+use crate::RetractionInfo;
 use crate::matching::titles_match;
 use std::time::Duration;
 
@@ -21,20 +22,11 @@ pub async fn check_retraction(
         return RetractionResult::default();
     }
 
-    let user_agent = match mailto {
-        Some(email) => format!("HallucinatedReferenceChecker/1.0 (mailto:{})", email),
-        None => "HallucinatedReferenceChecker/1.0 (mailto:hallucination-checker@example.com)"
-            .to_string(),
-    };
-
-    let url = format!("https://api.crossref.org/works/{}", doi);
-    let resp = match client
-        .get(&url)
-        .header("User-Agent", &user_agent)
-        .timeout(timeout)
-        .send()
-        .await
-    {
+    let mut url = format!("https://api.crossref.org/works/{}", doi);
+    if let Some(email) = mailto {
+        url.push_str(&format!("?mailto={}", urlencoding::encode(email)));
+    }
+    let resp = match client.get(&url).timeout(timeout).send().await {
         Ok(r) => r,
         Err(e) => {
             return RetractionResult {
@@ -121,24 +113,15 @@ pub async fn check_retraction_by_title(
         return RetractionResult::default();
     }
 
-    let url = format!(
+    let mut url = format!(
         "https://api.crossref.org/works?query.title={}&filter=has-update:true&rows=5",
         urlencoding::encode(title)
     );
+    if let Some(email) = mailto {
+        url.push_str(&format!("&mailto={}", urlencoding::encode(email)));
+    }
 
-    let user_agent = match mailto {
-        Some(email) => format!("HallucinatedReferenceChecker/1.0 (mailto:{})", email),
-        None => "HallucinatedReferenceChecker/1.0 (mailto:hallucination-checker@example.com)"
-            .to_string(),
-    };
-
-    let resp = match client
-        .get(&url)
-        .header("User-Agent", &user_agent)
-        .timeout(timeout)
-        .send()
-        .await
-    {
+    let resp = match client.get(&url).timeout(timeout).send().await {
         Ok(r) => r,
         Err(e) => {
             return RetractionResult {
@@ -217,3 +200,72 @@ pub async fn check_retraction_by_title(
 
     RetractionResult::default()
 }
+
+impl RetractionResult {
+    /// The retraction to report on a result, if CrossRef found one.
+    pub fn into_info(self) -> Option<RetractionInfo> {
+        self.retracted.then_some(RetractionInfo {
+            is_retracted: true,
+            retraction_doi: self.retraction_doi,
+            retraction_source: self.retraction_type,
+        })
+    }
+}
+
+/// Settle a verified reference's retraction notice from the offline
+/// Retraction Watch data (`local`) and CrossRef. A retraction found offline
+/// stands; otherwise CrossRef is asked, and an expression of concern found
+/// offline is kept when CrossRef has nothing more.
+pub(crate) async fn with_crossref_fallback(
+    local: Option<RetractionInfo>,
+    crossref: impl Future<Output = RetractionResult>,
+) -> Option<RetractionInfo> {
+    if local.as_ref().is_some_and(|info| info.is_retracted) {
+        return local;
+    }
+    crossref.await.into_info().or(local)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn concern() -> RetractionInfo {
+        RetractionInfo {
+            is_retracted: false,
+            retraction_doi: None,
+            retraction_source: Some("Retraction Watch: Expression of Concern".into()),
+        }
+    }
+
+    #[tokio::test]
+    async fn expression_of_concern_falls_through_to_crossref() {
+        let retracted = RetractionResult {
+            retracted: true,
+            retraction_doi: Some("10.1000/retraction".into()),
+            retraction_type: Some("Retraction".into()),
+            error: None,
+        };
+        let info = with_crossref_fallback(Some(concern()), async { retracted })
+            .await
+            .unwrap();
+        assert!(info.is_retracted);
+        assert_eq!(info.retraction_doi.as_deref(), Some("10.1000/retraction"));
+
+        // With nothing more from CrossRef, the concern is kept
+        let info = with_crossref_fallback(Some(concern()), async { RetractionResult::default() })
+            .await
+            .unwrap();
+        assert!(!info.is_retracted);
+
+        let offline = RetractionInfo {
+            is_retracted: true,
+            ..concern()
+        };
+        let info = with_crossref_fallback(Some(offline), async {
+            unreachable!("a retraction found offline needs no CrossRef lookup")
+        })
+        .await;
+        assert!(info.unwrap().is_retracted);
+    }
+}
@@ -0,0 +1,247 @@
+//! Offline retraction lookups against the Retraction Watch dataset.
+//!
+//! Retraction Watch publishes its database as a CSV through CrossRef
+//! (`retraction_watch.csv`). Loading it once and looking references up by
+//! DOI finds retractions that CrossRef's own `update-to` metadata misses,
+//! without a network round trip per reference.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use thiserror::Error;
+
+use crate::RetractionInfo;
+
+/// Default age, in days, after which a loaded dataset is reported as stale.
+pub const DEFAULT_MAX_AGE_DAYS: u64 = 30;
+
+#[derive(Error, Debug)]
+pub enum RetractionWatchError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("missing column '{0}'")]
+    MissingColumn(&'static str),
+}
+
+/// One retraction notice, keyed by the retracted paper's DOI.
+#[derive(Debug, Clone)]
+struct Notice {
+    retraction_doi: Option<String>,
+    nature: String,
+}
+
+/// The Retraction Watch dataset, indexed by original-paper DOI.
+pub struct RetractionWatch {
+    by_doi: HashMap<String, Notice>,
+    modified: Option<SystemTime>,
+}
+
+impl std::fmt::Debug for RetractionWatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetractionWatch")
+            .field("entries", &self.by_doi.len())
+            .field("modified", &self.modified)
+            .finish()
+    }
+}
+
+impl RetractionWatch {
+    /// Load the CSV at `path`. The file's modification time is kept to
+    /// judge staleness.
+    pub fn open(path: &Path) -> Result<Self, RetractionWatchError> {
+        let file = std::fs::File::open(path)?;
+        let modified = file.metadata().and_then(|m| m.modified()).ok();
+        let mut db = Self::from_reader(std::io::BufReader::new(file))?;
+        db.modified = modified;
+        Ok(db)
+    }
+
+    /// Load the dataset from CSV content with a header row.
+    ///
+    /// Only retractions and expressions of concern are kept; corrections
+    /// and reinstatements do not make a paper unreliable to cite. Rows
+    /// without a usable original-paper DOI are skipped.
+    pub fn from_reader(reader: impl Read) -> Result<Self, RetractionWatchError> {
+        let mut csv = csv::ReaderBuilder::new().flexible(true).from_reader(reader);
+        let headers = csv.headers()?.clone();
+        let column = |name: &'static str| {
+            headers
+                .iter()
+                .position(|h| h.trim().eq_ignore_ascii_case(name))
+                .ok_or(RetractionWatchError::MissingColumn(name))
+        };
+        let original_doi = column("OriginalPaperDOI")?;
+        let retraction_doi = column("RetractionDOI")?;
+        let nature = column("RetractionNature")?;
+
+        let mut by_doi = HashMap::new();
+        for record in csv.records() {
+            let record = record?;
+            let Some(doi) = record.get(original_doi).and_then(normalize_doi) else {
+                continue;
+            };
+            let nature = record.get(nature).unwrap_or("").trim();
+            if !(nature.eq_ignore_ascii_case("Retraction")
+                || nature.eq_ignore_ascii_case("Expression of Concern"))
+            {
+                continue;
+            }
+            let notice = Notice {
+                retraction_doi: record.get(retraction_doi).and_then(normalize_doi),
+                nature: nature.to_string(),
+            };
+            // A retraction outranks an earlier expression of concern.
+            by_doi
+                .entry(doi)
+                .and_modify(|existing: &mut Notice| {
+                    if notice.nature.eq_ignore_ascii_case("Retraction") {
+                        *existing = notice.clone();
+                    }
+                })
+                .or_insert(notice);
+        }
+
+        Ok(Self {
+            by_doi,
+            modified: None,
+        })
+    }
+
+    /// Number of retracted DOIs in the index.
+    pub fn len(&self) -> usize {
+        self.by_doi.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_doi.is_empty()
+    }
+
+    /// The notice for `doi`, if the dataset lists one. Only a retraction
+    /// sets `is_retracted`; an expression of concern comes back with it
+    /// unset, named in `retraction_source`.
+    pub fn lookup(&self, doi: &str) -> Option<RetractionInfo> {
+        let notice = self.by_doi.get(&normalize_doi(doi)?)?;
+        Some(RetractionInfo {
+            is_retracted: notice.nature.eq_ignore_ascii_case("Retraction"),
+            retraction_doi: notice.retraction_doi.clone(),
+            retraction_source: Some(format!("Retraction Watch: {}", notice.nature)),
+        })
+    }
+
+    /// How long ago the loaded file was last modified.
+    pub fn age(&self) -> Option<Duration> {
+        SystemTime::now().duration_since(self.modified?).ok()
+    }
+
+    /// Whether the file is at least `max_age_days` old. A dataset with no
+    /// known modification time is never reported as stale.
+    pub fn is_stale(&self, max_age_days: u64) -> bool {
+        self.age()
+            .is_some_and(|age| age >= Duration::from_secs(max_age_days * 86400))
+    }
+}
+
+/// Lowercase `doi` and strip any resolver prefix; `None` unless it looks
+/// like a DOI (the dataset writes "unavailable" for missing ones).
+pub(crate) fn normalize_doi(doi: &str) -> Option<String> {
+    let doi = doi.trim();
+    let lower = doi.to_lowercase();
+    let bare = [
+        "https://doi.org/",
+        "http://doi.org/",
+        "https://dx.doi.org/",
+        "doi:",
+    ]
+    .iter()
+    .find_map(|p| lower.strip_prefix(p))
+    .unwrap_or(&lower)
+    .trim();
+    bare.starts_with("10.").then(|| bare.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATASET: &str = "\
+Record ID,Title,Journal,RetractionDate,RetractionDOI,OriginalPaperDOI,RetractionNature,Reason
+1,\"Fabricated results, revisited\",J. Examples,1/2/2021,10.1000/retract.1,10.1000/ORIG.1,Retraction,+Fabrication;
+2,A corrected paper,J. Examples,3/4/2021,10.1000/corr.2,10.1000/orig.2,Correction,+Error;
+3,Concerning paper,J. Examples,5/6/2021,unavailable,10.1000/orig.3,Expression of Concern,+Concerns;
+4,Concerning then retracted,J. Examples,5/6/2021,10.1000/eoc.4,10.1000/orig.4,Expression of Concern,+Concerns;
+5,Concerning then retracted,J. Examples,7/8/2022,10.1000/retract.4,10.1000/orig.4,Retraction,+Misconduct;
+6,No DOI,J. Examples,7/8/2022,10.1000/retract.6,unavailable,Retraction,+Misconduct;
+";
+
+    fn dataset() -> RetractionWatch {
+        RetractionWatch::from_reader(DATASET.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn indexes_retractions_and_concerns_only() {
+        assert_eq!(dataset().len(), 3);
+    }
+
+    #[test]
+    fn lookup_normalizes_doi() {
+        let db = dataset();
+        let info = db.lookup("https://doi.org/10.1000/orig.1").unwrap();
+        assert!(info.is_retracted);
+        assert_eq!(info.retraction_doi.as_deref(), Some("10.1000/retract.1"));
+        assert_eq!(
+            info.retraction_source.as_deref(),
+            Some("Retraction Watch: Retraction")
+        );
+        assert!(db.lookup("10.1000/orig.2").is_none());
+        assert!(db.lookup("10.1000/unknown").is_none());
+    }
+
+    #[test]
+    fn retraction_outranks_expression_of_concern() {
+        let db = dataset();
+        let concern = db.lookup("10.1000/orig.3").unwrap();
+        assert!(concern.retraction_doi.is_none());
+        let retracted = db.lookup("10.1000/orig.4").unwrap();
+        assert!(retracted.is_retracted);
+        assert_eq!(
+            retracted.retraction_doi.as_deref(),
+            Some("10.1000/retract.4")
+        );
+    }
+
+    #[test]
+    fn expression_of_concern_is_not_a_retraction() {
+        let concern = dataset().lookup("10.1000/orig.3").unwrap();
+        assert!(!concern.is_retracted);
+        assert_eq!(
+            concern.retraction_source.as_deref(),
+            Some("Retraction Watch: Expression of Concern")
+        );
+    }
+
+    #[test]
+    fn missing_column_is_an_error() {
+        let err = RetractionWatch::from_reader("Record ID,Title\n1,x\n".as_bytes()).unwrap_err();
+        assert!(matches!(
+            err,
+            RetractionWatchError::MissingColumn("OriginalPaperDOI")
+        ));
+    }
+
+    #[test]
+    fn staleness_follows_file_age() {
+        let db = dataset();
+        assert!(!db.is_stale(0));
+
+        let aged = RetractionWatch {
+            modified: Some(SystemTime::now() - Duration::from_secs(40 * 86400)),
+            ..dataset()
+        };
+        assert!(aged.is_stale(30));
+        assert!(!aged.is_stale(60));
+    }
+}
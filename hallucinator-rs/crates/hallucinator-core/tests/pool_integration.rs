@@ -21,6 +21,7 @@ fn config_no_network() -> Config {
             "ACL Anthology".into(),
             "Europe PMC".into(),
             "PubMed".into(),
+            "DataCite".into(),
             "OpenAlex".into(),
         ],
         rate_limiters: Arc::new(RateLimiters::default()),
@@ -37,8 +38,10 @@ fn dummy_ref(title: &str) -> Reference {
         authors: vec![],
         doi: None,
         arxiv_id: None,
+        arxiv_version: None,
         original_number: 1,
         skip_reason: None,
+        doi_only: false,
     }
 }
 
@@ -55,6 +58,7 @@ async fn single_job_completes() {
         ref_index: 0,
         total: 1,
         progress: Arc::new(|_| {}),
+        cancel: None,
     };
 
     pool.submit(job).await;
@@ -82,6 +86,7 @@ async fn multiple_jobs_all_collected() {
             ref_index: i,
             total,
             progress: Arc::new(|_| {}),
+            cancel: None,
         };
         pool.submit(job).await;
         receivers.push(rx);
@@ -116,6 +121,7 @@ async fn cancellation_stops_pool() {
         ref_index: 0,
         total: 1,
         progress: Arc::new(|_| {}),
+        cancel: None,
     };
     pool.submit(job).await;
 
@@ -145,6 +151,7 @@ async fn shutdown_waits_for_completion() {
             ref_index: i,
             total,
             progress: Arc::new(|_| {}),
+            cancel: None,
         })
         .await;
         receivers.push(rx);
@@ -189,6 +196,7 @@ async fn progress_events_emitted() {
         ref_index: 0,
         total: 1,
         progress,
+        cancel: None,
     })
     .await;
 
@@ -205,3 +213,480 @@ async fn progress_events_emitted() {
         "should emit Result event, got: {collected:?}"
     );
 }
+
+#[tokio::test]
+async fn cancelled_job_is_dropped_without_a_result() {
+    // CrossRef queries hang on a proxy that never answers, so the job is
+    // still in flight when it is cancelled.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let proxy = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let mut held = Vec::new();
+        while let Ok((socket, _)) = listener.accept().await {
+            held.push(socket);
+        }
+    });
+
+    let mut disabled_dbs = config_no_network().disabled_dbs;
+    disabled_dbs.retain(|db| db != "CrossRef");
+    let config = Config {
+        disabled_dbs,
+        proxy: Some(proxy),
+        db_timeout_secs: 30,
+        ..config_no_network()
+    };
+    let pool = ValidationPool::new(Arc::new(config), CancellationToken::new(), 1);
+
+    let results = Arc::new(Mutex::new(0));
+    let results_clone = results.clone();
+    let job_cancel = CancellationToken::new();
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    pool.submit(RefJob {
+        reference: dummy_ref("Slow One"),
+        result_tx: tx,
+        ref_index: 0,
+        total: 1,
+        progress: Arc::new(move |event| {
+            if let ProgressEvent::Result { .. } = event {
+                *results_clone.lock().unwrap() += 1;
+            }
+        }),
+        cancel: Some(job_cancel.clone()),
+    })
+    .await;
+
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    job_cancel.cancel();
+
+    let outcome = tokio::time::timeout(std::time::Duration::from_secs(10), rx)
+        .await
+        .expect("the cancelled job should settle promptly");
+    assert!(outcome.is_err(), "a cancelled job sends no result");
+    assert_eq!(*results.lock().unwrap(), 0);
+
+    // A job cancelled before it is picked up never starts.
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    pool.submit(RefJob {
+        reference: dummy_ref("Never Checked"),
+        result_tx: tx,
+        ref_index: 0,
+        total: 1,
+        progress: Arc::new(|_| {}),
+        cancel: Some(job_cancel),
+    })
+    .await;
+    assert!(rx.await.is_err());
+
+    pool.shutdown().await;
+}
+
+#[tokio::test]
+async fn max_references_caps_checked_refs() {
+    let config = Config {
+        max_references: Some(2),
+        ..config_no_network()
+    };
+    let refs: Vec<Reference> = ["One", "Two", "Three", "Four"]
+        .iter()
+        .map(|t| dummy_ref(t))
+        .collect();
+
+    let warnings: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let warnings_clone = warnings.clone();
+    let progress = move |event: ProgressEvent| {
+        if let ProgressEvent::Warning { message, .. } = event {
+            warnings_clone.lock().unwrap().push(message);
+        }
+    };
+
+    let results =
+        hallucinator_core::check_references(refs, config, progress, CancellationToken::new()).await;
+
+    let titles: Vec<&str> = results.iter().map(|r| r.title.as_str()).collect();
+    assert_eq!(titles, ["One", "Two"]);
+    let collected = warnings.lock().unwrap();
+    assert_eq!(collected.len(), 1, "got: {collected:?}");
+    assert!(collected[0].contains("skipping the remaining 2"));
+}
+
+#[tokio::test]
+async fn dedup_looks_up_each_title_once() {
+    let config = Config {
+        dedup: true,
+        ..config_no_network()
+    };
+    let refs: Vec<Reference> = [
+        "Attention Is All You Need",
+        "Something Else Entirely",
+        "attention is all you need.",
+    ]
+    .iter()
+    .map(|t| dummy_ref(t))
+    .collect();
+
+    let events: Arc<Mutex<Vec<(&'static str, usize)>>> = Arc::new(Mutex::new(Vec::new()));
+    let events_clone = events.clone();
+    let progress = move |event: ProgressEvent| match event {
+        ProgressEvent::Checking { index, .. } => {
+            events_clone.lock().unwrap().push(("checking", index))
+        }
+        ProgressEvent::Duplicate { index, .. } => {
+            events_clone.lock().unwrap().push(("duplicate", index))
+        }
+        _ => {}
+    };
+
+    let results =
+        hallucinator_core::check_references(refs, config, progress, CancellationToken::new()).await;
+
+    let titles: Vec<&str> = results.iter().map(|r| r.title.as_str()).collect();
+    assert_eq!(
+        titles,
+        [
+            "Attention Is All You Need",
+            "Something Else Entirely",
+            "attention is all you need."
+        ]
+    );
+    assert_eq!(results[2].raw_citation, "[1] attention is all you need.");
+    let mut collected = events.lock().unwrap().clone();
+    collected.sort();
+    assert_eq!(
+        collected,
+        [("checking", 0), ("checking", 1), ("duplicate", 2)]
+    );
+}
+
+#[tokio::test]
+async fn dedup_keeps_citations_with_other_authors_apart() {
+    let path = std::env::temp_dir().join(format!("hallucinator-dedup-{}.bib", std::process::id()));
+    std::fs::write(
+        &path,
+        "@article{v, title = {Attention Is All You Need}, \
+         author = {Vaswani, Ashish and Shazeer, Noam}}\n",
+    )
+    .unwrap();
+    let config = Config {
+        dedup: true,
+        local_bib_path: Some(path.clone()),
+        ..config_no_network()
+    };
+    let cited = |authors: &[&str]| Reference {
+        authors: authors.iter().map(|a| a.to_string()).collect(),
+        ..dummy_ref("Attention Is All You Need")
+    };
+    let refs = vec![
+        cited(&["Ashish Vaswani", "Noam Shazeer"]),
+        cited(&["Jane Fabricated"]),
+        cited(&["A. Vaswani", "N. Shazeer"]),
+    ];
+
+    let duplicates: Arc<Mutex<Vec<usize>>> = Arc::new(Mutex::new(Vec::new()));
+    let duplicates_clone = duplicates.clone();
+    let progress = move |event: ProgressEvent| {
+        if let ProgressEvent::Duplicate { index, .. } = event {
+            duplicates_clone.lock().unwrap().push(index);
+        }
+    };
+
+    let results =
+        hallucinator_core::check_references(refs, config, progress, CancellationToken::new()).await;
+    std::fs::remove_file(&path).unwrap();
+
+    let statuses: Vec<&Status> = results.iter().map(|r| &r.status).collect();
+    assert_eq!(
+        statuses,
+        [
+            &Status::Verified,
+            &Status::AuthorMismatch,
+            &Status::Verified
+        ]
+    );
+    assert_eq!(results[1].ref_authors, ["Jane Fabricated"]);
+    // Only the same authors, in another format, share a lookup
+    assert_eq!(*duplicates.lock().unwrap(), [2]);
+}
+
+#[tokio::test]
+async fn dedup_checks_each_doi_on_its_own() {
+    let mut config = Config {
+        dedup: true,
+        ..config_no_network()
+    };
+    config.disabled_dbs.push("DOI".into());
+    let cited = |doi: &str| Reference {
+        doi: Some(doi.to_string()),
+        ..dummy_ref("Attention Is All You Need")
+    };
+    let refs = vec![
+        cited("10.5555/real.1"),
+        cited("10.5555/made-up.2"),
+        cited("https://doi.org/10.5555/REAL.1"),
+    ];
+
+    let duplicates: Arc<Mutex<Vec<usize>>> = Arc::new(Mutex::new(Vec::new()));
+    let duplicates_clone = duplicates.clone();
+    let progress = move |event: ProgressEvent| {
+        if let ProgressEvent::Duplicate { index, .. } = event {
+            duplicates_clone.lock().unwrap().push(index);
+        }
+    };
+
+    let results =
+        hallucinator_core::check_references(refs, config, progress, CancellationToken::new()).await;
+
+    assert_eq!(results.len(), 3);
+    // Only the same DOI, written another way, shares a lookup
+    assert_eq!(*duplicates.lock().unwrap(), [2]);
+}
+
+#[tokio::test]
+async fn offline_match_is_checked_against_offline_retractions() {
+    let dir = std::env::temp_dir();
+    let bib = dir.join(format!("hallucinator-retracted-{}.bib", std::process::id()));
+    let csv = dir.join(format!("hallucinator-retracted-{}.csv", std::process::id()));
+    std::fs::write(
+        &bib,
+        "@article{r, title = {Fabricated Results in Offline Checks}, author = {Roe, Richard}}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        &csv,
+        "Record ID,RetractionDOI,OriginalPaperDOI,RetractionNature\n\
+         1,10.1000/retract.1,10.1000/orig.1,Retraction\n",
+    )
+    .unwrap();
+    let mut config = Config {
+        local_bib_path: Some(bib.clone()),
+        retraction_db_path: Some(csv.clone()),
+        ..config_no_network()
+    };
+    config.disabled_dbs.push("DOI".into());
+    let reference = Reference {
+        authors: vec!["Richard Roe".into()],
+        doi: Some("10.1000/orig.1".into()),
+        ..dummy_ref("Fabricated Results in Offline Checks")
+    };
+
+    let results = hallucinator_core::check_references(
+        vec![reference],
+        config,
+        |_| {},
+        CancellationToken::new(),
+    )
+    .await;
+    std::fs::remove_file(&bib).unwrap();
+    std::fs::remove_file(&csv).unwrap();
+
+    assert_eq!(results[0].status, Status::Verified);
+    let retraction = results[0].retraction_info.as_ref().unwrap();
+    assert!(retraction.is_retracted);
+    assert_eq!(
+        retraction.retraction_doi.as_deref(),
+        Some("10.1000/retract.1")
+    );
+}
+
+#[test]
+fn cap_references_ignores_already_skipped() {
+    let mut refs = vec![dummy_ref("One"), dummy_ref("Short"), dummy_ref("Two")];
+    refs[1].skip_reason = Some("short_title".into());
+
+    let capped = hallucinator_core::checker::cap_references(&mut refs, Some(1));
+
+    assert_eq!(capped, 1);
+    assert_eq!(refs[0].skip_reason, None);
+    assert_eq!(refs[1].skip_reason.as_deref(), Some("short_title"));
+    assert_eq!(
+        refs[2].skip_reason.as_deref(),
+        Some(hallucinator_core::checker::MAX_REFERENCES_SKIP_REASON)
+    );
+}
+
+#[tokio::test]
+async fn run_deadline_returns_partial_results() {
+    // A "proxy" that accepts connections and never answers, so every CrossRef
+    // query hangs until its 30s timeout.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let proxy = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let mut held = Vec::new();
+        while let Ok((socket, _)) = listener.accept().await {
+            held.push(socket);
+        }
+    });
+
+    let mut disabled_dbs = config_no_network().disabled_dbs;
+    disabled_dbs.retain(|db| db != "CrossRef");
+    let config = Config {
+        disabled_dbs,
+        proxy: Some(proxy),
+        db_timeout_secs: 30,
+        run_deadline: Some(std::time::Duration::from_millis(300)),
+        ..config_no_network()
+    };
+
+    // The first reference never reaches a database: its DOI is malformed, so
+    // it is settled at once.
+    let mut quick = dummy_ref("A Dataset");
+    quick.doi = Some("not-a-doi".into());
+    quick.doi_only = true;
+    let refs = vec![quick, dummy_ref("Slow One"), dummy_ref("Slow Two")];
+
+    let warnings: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let warnings_clone = warnings.clone();
+    let progress = move |event: ProgressEvent| {
+        if let ProgressEvent::Warning { message, .. } = event {
+            warnings_clone.lock().unwrap().push(message);
+        }
+    };
+
+    let start = std::time::Instant::now();
+    let results =
+        hallucinator_core::check_references(refs, config, progress, CancellationToken::new()).await;
+
+    assert!(start.elapsed() < std::time::Duration::from_secs(10));
+    let titles: Vec<&str> = results.iter().map(|r| r.title.as_str()).collect();
+    assert_eq!(titles, ["A Dataset"]);
+    let collected = warnings.lock().unwrap();
+    assert_eq!(collected.len(), 1, "got: {collected:?}");
+    assert!(collected[0].contains("skipping the remaining 2"));
+}
+
+#[tokio::test]
+async fn throttled_databases_are_reported() {
+    // As above: CrossRef queries hang on a silent proxy, keeping the run
+    // going until its deadline.
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let proxy = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let mut held = Vec::new();
+        while let Ok((socket, _)) = listener.accept().await {
+            held.push(socket);
+        }
+    });
+
+    let rate_limiters = Arc::new(RateLimiters::default());
+    rate_limiters.get("arXiv").unwrap().on_rate_limited();
+
+    let mut disabled_dbs = config_no_network().disabled_dbs;
+    disabled_dbs.retain(|db| db != "CrossRef");
+    let config = Config {
+        disabled_dbs,
+        proxy: Some(proxy),
+        db_timeout_secs: 30,
+        run_deadline: Some(std::time::Duration::from_millis(300)),
+        rate_limiters,
+        ..config_no_network()
+    };
+
+    let statuses: Arc<Mutex<Vec<Vec<hallucinator_core::RateLimitState>>>> =
+        Arc::new(Mutex::new(Vec::new()));
+    let statuses_clone = statuses.clone();
+    let progress = move |event: ProgressEvent| {
+        if let ProgressEvent::RateLimitStatus { limiters } = event {
+            statuses_clone.lock().unwrap().push(limiters);
+        }
+    };
+
+    hallucinator_core::check_references(
+        vec![dummy_ref("Slow One")],
+        config,
+        progress,
+        CancellationToken::new(),
+    )
+    .await;
+
+    let collected = statuses.lock().unwrap();
+    let first = collected.first().expect("a rate-limit status event");
+    let throttled: Vec<(&str, u32)> = first
+        .iter()
+        .filter(|s| s.is_throttled())
+        .map(|s| (s.db_name.as_str(), s.factor))
+        .collect();
+    assert_eq!(throttled, [("arXiv", 2)]);
+}
+
+#[tokio::test]
+async fn adaptive_concurrency_widens_while_a_database_allows_it() {
+    // CrossRef answers from the cache, so its queries finish without a 429
+    let titles: Vec<String> = (0..6).map(|i| format!("Cached Paper {i}")).collect();
+    let cache = Arc::new(hallucinator_core::QueryCache::default());
+    for title in &titles {
+        cache.insert(
+            title,
+            "CrossRef",
+            &hallucinator_core::db::DbQueryResult::not_found(),
+        );
+    }
+    let mut disabled_dbs = config_no_network().disabled_dbs;
+    disabled_dbs.retain(|db| db != "CrossRef");
+    let config = Config {
+        disabled_dbs,
+        query_cache: Some(cache),
+        adaptive_concurrency: true,
+        ..config_no_network()
+    };
+
+    let limits: Arc<Mutex<Vec<(String, usize)>>> = Arc::new(Mutex::new(Vec::new()));
+    let limits_clone = limits.clone();
+    let progress = move |event: ProgressEvent| {
+        if let ProgressEvent::ConcurrencyChanged { db_name, limit } = event {
+            limits_clone.lock().unwrap().push((db_name, limit));
+        }
+    };
+
+    let refs = titles.iter().map(|t| dummy_ref(t)).collect();
+    hallucinator_core::check_references(refs, config, progress, CancellationToken::new()).await;
+
+    // One clean query opens a second slot, two more a third, three a fourth
+    let collected = limits.lock().unwrap();
+    let expected: Vec<(String, usize)> = (2..=4).map(|n| ("CrossRef".to_string(), n)).collect();
+    assert_eq!(*collected, expected);
+}
+
+#[tokio::test]
+async fn files_share_one_pool_and_keep_their_results() {
+    let config = Config {
+        dedup: true,
+        ..config_no_network()
+    };
+    let context = hallucinator_core::CheckContext::from_config(&config);
+    let files = vec![
+        vec![dummy_ref("Shared Paper"), dummy_ref("First Only")],
+        vec![],
+        vec![dummy_ref("Second Only"), dummy_ref("Shared Paper")],
+    ];
+
+    let duplicates: Arc<Mutex<Vec<(usize, usize)>>> = Arc::new(Mutex::new(Vec::new()));
+    let duplicates_clone = duplicates.clone();
+    let progress = move |file: usize, event: ProgressEvent| {
+        if let ProgressEvent::Duplicate { index, .. } = event {
+            duplicates_clone.lock().unwrap().push((file, index));
+        }
+    };
+
+    let results = hallucinator_core::check_files_with_context(
+        files,
+        config,
+        context,
+        progress,
+        CancellationToken::new(),
+    )
+    .await;
+
+    let titles: Vec<Vec<&str>> = results
+        .iter()
+        .map(|file| file.iter().map(|r| r.title.as_str()).collect())
+        .collect();
+    assert_eq!(
+        titles,
+        [
+            vec!["Shared Paper", "First Only"],
+            vec![],
+            vec!["Second Only", "Shared Paper"],
+        ]
+    );
+    // The second file's copy shares the first file's lookup
+    assert_eq!(*duplicates.lock().unwrap(), [(2, 1)]);
+}
@@ -7,7 +7,7 @@ use std::cell::Cell;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufReader, Read, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
 use futures_util::StreamExt;
@@ -24,10 +24,17 @@ pub const DEFAULT_DBLP_URL: &str = "https://dblp.uni-trier.de/xml/dblp.xml.gz";
 /// Keeps WAL size reasonable while avoiding per-record fsync overhead.
 const COMMIT_INTERVAL: u64 = 50_000;
 
+/// How many bytes to check between `Verifying` progress events.
+const VERIFY_PROGRESS_INTERVAL: u64 = 64 * 1024 * 1024;
+
 /// Build (or update) the offline DBLP database by downloading from dblp.org.
 ///
-/// Phase 1: Downloads `dblp.xml.gz` to a temporary file with progress reporting.
-/// Phase 2: Parses the XML and inserts into SQLite (runs in a blocking thread).
+/// Phase 1: Downloads `dblp.xml.gz` to `dblp.xml.gz.part` next to the database,
+/// resuming an earlier interrupted download with an HTTP range request.
+/// Phase 2: Verifies the dump against dblp.org's published MD5 (or, if that is
+/// unavailable, checks the gzip stream's own CRC).
+/// Phase 3: Parses the XML into a fresh SQLite file (in a blocking thread) and
+/// moves it over `db_path` only once it is complete.
 ///
 /// Uses ETag/Last-Modified headers for conditional requests — if the remote
 /// file hasn't changed since the last build, returns `Ok(false)`.
@@ -35,12 +42,15 @@ pub async fn build(
     db_path: &Path,
     mut progress: impl FnMut(BuildProgress),
 ) -> Result<bool, DblpError> {
-    let conn = Connection::open(db_path)?;
-    db::init_database(&conn)?;
-
     // Check stored ETag/Last-Modified for conditional request
-    let stored_etag = db::get_metadata(&conn, "etag")?;
-    let stored_last_modified = db::get_metadata(&conn, "last_modified")?;
+    let stored = {
+        let conn = Connection::open(db_path)?;
+        db::init_database(&conn)?;
+        Validators {
+            etag: db::get_metadata(&conn, "etag")?,
+            last_modified: db::get_metadata(&conn, "last_modified")?,
+        }
+    };
 
     // Build HTTP client
     let client = reqwest::Client::builder()
@@ -48,116 +58,386 @@ pub async fn build(
         .build()
         .map_err(|e| DblpError::Download(e.to_string()))?;
 
-    // Conditional GET
-    let mut request = client.get(DEFAULT_DBLP_URL);
-    if let Some(ref etag) = stored_etag {
-        request = request.header("If-None-Match", etag.as_str());
-    }
-    if let Some(ref lm) = stored_last_modified {
-        request = request.header("If-Modified-Since", lm.as_str());
-    }
-
-    let response = request
-        .send()
-        .await
-        .map_err(|e| DblpError::Download(e.to_string()))?;
-
-    if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+    // Phase 1: Download (or finish downloading) .xml.gz next to the database
+    let db_dir = db_path.parent().unwrap_or(Path::new("."));
+    let part = PartialDownload::new(db_dir);
+    let Some(validators) =
+        download(&client, DEFAULT_DBLP_URL, &stored, &part, &mut progress).await?
+    else {
         progress(BuildProgress::Complete {
             publications: 0,
             authors: 0,
             skipped: true,
         });
         return Ok(false);
+    };
+
+    // Phase 2: Verify the dump before touching the database
+    let expected_md5 = fetch_md5(&client, &format!("{}.md5", DEFAULT_DBLP_URL)).await;
+    let gz_path = part.path.clone();
+    let verified = run_blocking(&mut progress, move |send| match expected_md5 {
+        Some(md5) => verify_md5(&gz_path, &md5, send),
+        None => verify_gzip(&gz_path, send),
+    })
+    .await;
+    if let Err(e) = verified {
+        part.discard();
+        return Err(e);
     }
 
-    if !response.status().is_success() {
-        return Err(DblpError::Download(format!(
-            "HTTP error: {}",
-            response.status()
-        )));
+    // Phase 3: Parse XML into a fresh SQLite file, then swap it in.
+    // Runs in a blocking thread since XML parsing and SQLite writes are sync I/O.
+    let db_path = db_path.to_path_buf();
+    let gz_path = part.path.clone();
+    let (pubs, authors) = run_blocking(&mut progress, move |send| {
+        let building = building_path(&db_path);
+        let _ = std::fs::remove_file(&building);
+        remove_journal_files(&building);
+
+        let result = (|| {
+            let conn = Connection::open(&building)?;
+            db::init_database(&conn)?;
+            db::begin_bulk_load(&conn)?;
+
+            parse_and_insert(&conn, &gz_path, &mut *send)?;
+
+            send(BuildProgress::RebuildingIndex);
+            db::rebuild_fts_index(&conn)?;
+
+            // Update metadata
+            let timestamp = now_unix_timestamp();
+            db::set_metadata(&conn, "last_updated", &timestamp)?;
+            db::set_metadata(&conn, "schema_version", "3")?;
+
+            if let Some(etag) = validators.etag {
+                db::set_metadata(&conn, "etag", &etag)?;
+            }
+            if let Some(lm) = validators.last_modified {
+                db::set_metadata(&conn, "last_modified", &lm)?;
+            }
+
+            let (pubs, authors, _) = db::get_counts(&conn)?;
+            db::set_metadata(&conn, "publication_count", &pubs.to_string())?;
+            db::set_metadata(&conn, "author_count", &authors.to_string())?;
+
+            send(BuildProgress::Compacting);
+            db::vacuum(&conn)?;
+
+            Ok::<(i64, i64), DblpError>((pubs, authors))
+        })();
+
+        match result {
+            Ok(counts) => {
+                // A WAL left behind by the old file must not be replayed
+                // onto the new one.
+                remove_journal_files(&db_path);
+                std::fs::rename(&building, &db_path)?;
+                Ok(counts)
+            }
+            Err(e) => {
+                let _ = std::fs::remove_file(&building);
+                remove_journal_files(&building);
+                Err(e)
+            }
+        }
+    })
+    .await?;
+
+    part.discard();
+
+    progress(BuildProgress::Complete {
+        publications: pubs as u64,
+        authors: authors as u64,
+        skipped: false,
+    });
+
+    Ok(true)
+}
+
+/// Validators identifying one version of the remote dump.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Validators {
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+impl Validators {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        let get = |name| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(String::from)
+        };
+        Self {
+            etag: get(reqwest::header::ETAG),
+            last_modified: get(reqwest::header::LAST_MODIFIED),
+        }
     }
 
-    // Capture new ETag/Last-Modified from response
-    let new_etag = response
-        .headers()
-        .get("etag")
-        .and_then(|v| v.to_str().ok())
-        .map(String::from);
-    let new_last_modified = response
-        .headers()
-        .get("last-modified")
-        .and_then(|v| v.to_str().ok())
-        .map(String::from);
-
-    let total_bytes = response.content_length();
-
-    // Phase 1: Download .xml.gz to a temporary file
-    let db_dir = db_path.parent().unwrap_or(Path::new("."));
-    let tmp_dir = tempfile::TempDir::new_in(db_dir).map_err(DblpError::Io)?;
-    let gz_path = tmp_dir.path().join("dblp.xml.gz");
+    /// The value to send as `If-Range`, preferring the strong ETag.
+    fn if_range(&self) -> Option<&str> {
+        self.etag.as_deref().or(self.last_modified.as_deref())
+    }
+}
+
+/// The `.part` file an interrupted download resumes from, and the sidecar
+/// recording which version of the dump it holds.
+struct PartialDownload {
+    path: PathBuf,
+    validators_path: PathBuf,
+}
+
+impl PartialDownload {
+    fn new(dir: &Path) -> Self {
+        Self {
+            path: dir.join("dblp.xml.gz.part"),
+            validators_path: dir.join("dblp.xml.gz.part.validators"),
+        }
+    }
+
+    /// Bytes already downloaded and the version they belong to, if the
+    /// partial file can be resumed.
+    fn resumable(&self) -> Option<(u64, Validators)> {
+        let len = std::fs::metadata(&self.path).ok()?.len();
+        let saved = std::fs::read_to_string(&self.validators_path).ok()?;
+        let mut lines = saved
+            .lines()
+            .map(|l| Some(l.to_string()).filter(|l| !l.is_empty()));
+        let validators = Validators {
+            etag: lines.next().flatten(),
+            last_modified: lines.next().flatten(),
+        };
+        (len > 0 && validators.if_range().is_some()).then_some((len, validators))
+    }
+
+    fn start(&self, validators: &Validators) -> Result<File, DblpError> {
+        std::fs::write(
+            &self.validators_path,
+            format!(
+                "{}\n{}\n",
+                validators.etag.as_deref().unwrap_or(""),
+                validators.last_modified.as_deref().unwrap_or("")
+            ),
+        )?;
+        Ok(File::create(&self.path)?)
+    }
+
+    fn discard(&self) {
+        let _ = std::fs::remove_file(&self.path);
+        let _ = std::fs::remove_file(&self.validators_path);
+    }
+}
 
+/// Download `url` into `part`, resuming it when a previous run was cut off.
+///
+/// Returns the validators of the downloaded version, or `None` when nothing
+/// was started and the remote file still matches `stored` (HTTP 304).
+async fn download(
+    client: &reqwest::Client,
+    url: &str,
+    stored: &Validators,
+    part: &PartialDownload,
+    progress: &mut impl FnMut(BuildProgress),
+) -> Result<Option<Validators>, DblpError> {
+    let resume = part.resumable();
+
+    let mut request = client.get(url);
+    match &resume {
+        Some((offset, saved)) => {
+            // If-Range makes the server send the whole file instead if it
+            // changed since the partial download began.
+            request = request.header(reqwest::header::RANGE, format!("bytes={}-", offset));
+            if let Some(v) = saved.if_range() {
+                request = request.header(reqwest::header::IF_RANGE, v);
+            }
+        }
+        None => {
+            if let Some(ref etag) = stored.etag {
+                request = request.header("If-None-Match", etag.as_str());
+            }
+            if let Some(ref lm) = stored.last_modified {
+                request = request.header("If-Modified-Since", lm.as_str());
+            }
+        }
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| DblpError::Download(e.to_string()))?;
+    let status = response.status();
+
+    let (mut out, offset, validators) = match resume {
+        _ if status == reqwest::StatusCode::NOT_MODIFIED => return Ok(None),
+        // The partial file already holds everything.
+        Some((_, saved)) if status == reqwest::StatusCode::RANGE_NOT_SATISFIABLE => {
+            return Ok(Some(saved));
+        }
+        Some((offset, saved)) if status == reqwest::StatusCode::PARTIAL_CONTENT => {
+            let out = std::fs::OpenOptions::new().append(true).open(&part.path)?;
+            (out, offset, saved)
+        }
+        _ if status.is_success() => {
+            let validators = Validators::from_headers(response.headers());
+            (part.start(&validators)?, 0, validators)
+        }
+        _ => return Err(DblpError::Download(format!("HTTP error: {}", status))),
+    };
+
+    let total_bytes = response.content_length().map(|len| len + offset);
+    let mut bytes_downloaded = offset;
     progress(BuildProgress::Downloading {
-        bytes_downloaded: 0,
+        bytes_downloaded,
         total_bytes,
         bytes_decompressed: 0,
     });
 
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| {
+            DblpError::Download(format!(
+                "download interrupted after {} bytes ({}); run the update again to resume",
+                bytes_downloaded, e
+            ))
+        })?;
+        out.write_all(&chunk)?;
+        bytes_downloaded += chunk.len() as u64;
+
+        progress(BuildProgress::Downloading {
+            bytes_downloaded,
+            total_bytes,
+            bytes_decompressed: 0,
+        });
+    }
+    out.flush()?;
+
+    if let Some(total) = total_bytes
+        && bytes_downloaded < total
     {
-        let mut out = File::create(&gz_path)?;
-        let mut stream = response.bytes_stream();
-        let mut bytes_downloaded: u64 = 0;
-
-        while let Some(chunk) = stream.next().await {
-            let chunk = chunk.map_err(|e| DblpError::Download(e.to_string()))?;
-            out.write_all(&chunk)?;
-            bytes_downloaded += chunk.len() as u64;
-
-            progress(BuildProgress::Downloading {
-                bytes_downloaded,
-                total_bytes,
-                bytes_decompressed: 0,
-            });
-        }
-        out.flush()?;
+        return Err(DblpError::Download(format!(
+            "download ended after {} of {} bytes; run the update again to resume",
+            bytes_downloaded, total
+        )));
     }
 
-    // Phase 2: Parse XML and insert into SQLite.
-    // Runs in a blocking thread since XML parsing and SQLite writes are sync I/O.
-    let (progress_tx, mut progress_rx) = tokio::sync::mpsc::channel::<BuildProgress>(64);
+    Ok(Some(validators))
+}
 
-    let parse_handle = tokio::task::spawn_blocking(move || {
-        let _tmp_dir = tmp_dir; // keep temp directory alive until parsing is done
+/// Fetch the MD5 digest dblp.org publishes next to the dump, if any.
+async fn fetch_md5(client: &reqwest::Client, url: &str) -> Option<String> {
+    let response = client.get(url).send().await.ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    parse_md5_file(&response.text().await.ok()?)
+}
 
-        db::begin_bulk_load(&conn)?;
+/// The digest from an `md5sum`-style line (`<hex>  dblp.xml.gz`).
+fn parse_md5_file(content: &str) -> Option<String> {
+    let digest = content.split_whitespace().next()?.to_ascii_lowercase();
+    (digest.len() == 32 && digest.bytes().all(|b| b.is_ascii_hexdigit())).then_some(digest)
+}
 
-        parse_and_insert(&conn, &gz_path, |evt| {
-            let _ = progress_tx.blocking_send(evt);
-        })?;
+/// Check `gz_path` against an expected MD5 hex digest.
+fn verify_md5(
+    gz_path: &Path,
+    expected: &str,
+    mut progress: impl FnMut(BuildProgress),
+) -> Result<(), DblpError> {
+    use md5::Digest;
+
+    let mut file = File::open(gz_path)?;
+    let bytes_total = file.metadata()?.len();
+    let mut hasher = md5::Md5::new();
+    let mut buf = vec![0u8; 1024 * 1024];
+    let mut bytes_checked = 0u64;
+    let mut last_report = 0u64;
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+        bytes_checked += n as u64;
+        if bytes_checked - last_report >= VERIFY_PROGRESS_INTERVAL {
+            last_report = bytes_checked;
+            progress(BuildProgress::Verifying {
+                bytes_checked,
+                bytes_total,
+            });
+        }
+    }
+    progress(BuildProgress::Verifying {
+        bytes_checked,
+        bytes_total,
+    });
 
-        let _ = progress_tx.blocking_send(BuildProgress::RebuildingIndex);
-        db::rebuild_fts_index(&conn)?;
+    let actual: String = hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    if actual != expected {
+        return Err(DblpError::Download(format!(
+            "dblp.xml.gz is corrupt (MD5 {} does not match the published {}); \
+             the download was discarded, run the update again",
+            actual, expected
+        )));
+    }
+    Ok(())
+}
 
-        // Update metadata
-        let timestamp = now_unix_timestamp();
-        db::set_metadata(&conn, "last_updated", &timestamp)?;
-        db::set_metadata(&conn, "schema_version", "3")?;
+/// Decompress `gz_path` to the end, so a truncated file or a CRC mismatch
+/// is caught before parsing starts.
+fn verify_gzip(gz_path: &Path, mut progress: impl FnMut(BuildProgress)) -> Result<(), DblpError> {
+    let file = File::open(gz_path)?;
+    let bytes_total = file.metadata()?.len();
+    let bytes_read = Rc::new(Cell::new(0u64));
+    let mut decoder = flate2::read::GzDecoder::new(CountingReader {
+        inner: file,
+        bytes_read: Rc::clone(&bytes_read),
+    });
 
-        if let Some(etag) = new_etag {
-            db::set_metadata(&conn, "etag", &etag)?;
+    let mut buf = vec![0u8; 1024 * 1024];
+    let mut last_report = 0u64;
+    loop {
+        let n = decoder.read(&mut buf).map_err(|e| {
+            DblpError::Download(format!(
+                "dblp.xml.gz is truncated or corrupt ({}); \
+                 the download was discarded, run the update again",
+                e
+            ))
+        })?;
+        if n == 0 {
+            break;
         }
-        if let Some(lm) = new_last_modified {
-            db::set_metadata(&conn, "last_modified", &lm)?;
+        if bytes_read.get() - last_report >= VERIFY_PROGRESS_INTERVAL {
+            last_report = bytes_read.get();
+            progress(BuildProgress::Verifying {
+                bytes_checked: last_report,
+                bytes_total,
+            });
         }
+    }
+    progress(BuildProgress::Verifying {
+        bytes_checked: bytes_total,
+        bytes_total,
+    });
+    Ok(())
+}
 
-        let (pubs, authors, _) = db::get_counts(&conn)?;
-        db::set_metadata(&conn, "publication_count", &pubs.to_string())?;
-        db::set_metadata(&conn, "author_count", &authors.to_string())?;
-
-        let _ = progress_tx.blocking_send(BuildProgress::Compacting);
-        db::vacuum(&conn)?;
+/// Run `work` on a blocking thread, forwarding the progress events it sends.
+async fn run_blocking<T: Send + 'static>(
+    progress: &mut impl FnMut(BuildProgress),
+    work: impl FnOnce(&mut dyn FnMut(BuildProgress)) -> Result<T, DblpError> + Send + 'static,
+) -> Result<T, DblpError> {
+    let (progress_tx, mut progress_rx) = tokio::sync::mpsc::channel::<BuildProgress>(64);
 
-        Ok::<(i64, i64), DblpError>((pubs, authors))
+    let handle = tokio::task::spawn_blocking(move || {
+        work(&mut |evt| {
+            let _ = progress_tx.blocking_send(evt);
+        })
     });
 
     // Forward progress events from the blocking task to the caller
@@ -165,17 +445,25 @@ pub async fn build(
         progress(evt);
     }
 
-    let (pubs, authors) = parse_handle
+    handle
         .await
-        .map_err(|e| DblpError::Download(format!("parse task panicked: {}", e)))??;
+        .map_err(|e| DblpError::Download(format!("build task panicked: {}", e)))?
+}
 
-    progress(BuildProgress::Complete {
-        publications: pubs as u64,
-        authors: authors as u64,
-        skipped: false,
-    });
+/// Where a new database is built before it replaces `db_path`.
+fn building_path(db_path: &Path) -> PathBuf {
+    let mut name = db_path.as_os_str().to_owned();
+    name.push(".building");
+    PathBuf::from(name)
+}
 
-    Ok(true)
+/// Remove the WAL and shared-memory files of the SQLite file at `path`.
+fn remove_journal_files(path: &Path) {
+    for suffix in ["-wal", "-shm"] {
+        let mut name = path.as_os_str().to_owned();
+        name.push(suffix);
+        let _ = std::fs::remove_file(PathBuf::from(name));
+    }
 }
 
 /// Build the offline DBLP database from a local `.xml.gz` file.
@@ -498,4 +786,85 @@ mod tests {
         assert_eq!(authors, 2);
         assert_eq!(rels, 2);
     }
+
+    #[test]
+    fn test_parse_md5_file() {
+        assert_eq!(
+            parse_md5_file("D41D8CD98F00B204E9800998ECF8427E  dblp.xml.gz\n").as_deref(),
+            Some("d41d8cd98f00b204e9800998ecf8427e")
+        );
+        assert!(parse_md5_file("<html>Not Found</html>").is_none());
+        assert!(parse_md5_file("").is_none());
+    }
+
+    #[test]
+    fn test_verify_md5() {
+        use md5::Digest;
+
+        let gz_data = create_test_xml_gz();
+        let dir = tempfile::tempdir().unwrap();
+        let gz_path = dir.path().join("dblp.xml.gz.part");
+        std::fs::write(&gz_path, &gz_data).unwrap();
+
+        let digest: String = md5::Md5::digest(&gz_data)
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        let mut events = Vec::new();
+        verify_md5(&gz_path, &digest, |e| events.push(e)).unwrap();
+        assert!(matches!(
+            events.last(),
+            Some(BuildProgress::Verifying { bytes_checked, bytes_total })
+                if bytes_checked == bytes_total
+        ));
+
+        let err = verify_md5(&gz_path, &"0".repeat(32), |_| {}).unwrap_err();
+        assert!(matches!(err, DblpError::Download(ref m) if m.contains("corrupt")));
+    }
+
+    #[test]
+    fn test_verify_gzip_rejects_truncated_dump() {
+        let gz_data = create_test_xml_gz();
+        let dir = tempfile::tempdir().unwrap();
+        let gz_path = dir.path().join("dblp.xml.gz.part");
+
+        std::fs::write(&gz_path, &gz_data).unwrap();
+        verify_gzip(&gz_path, |_| {}).unwrap();
+
+        std::fs::write(&gz_path, &gz_data[..gz_data.len() - 12]).unwrap();
+        let err = verify_gzip(&gz_path, |_| {}).unwrap_err();
+        assert!(matches!(err, DblpError::Download(ref m) if m.contains("truncated")));
+    }
+
+    #[test]
+    fn test_partial_download_resumes_with_validators() {
+        let dir = tempfile::tempdir().unwrap();
+        let part = PartialDownload::new(dir.path());
+        assert!(part.resumable().is_none());
+
+        let validators = Validators {
+            etag: Some("\"abc\"".into()),
+            last_modified: None,
+        };
+        let mut out = part.start(&validators).unwrap();
+        out.write_all(b"partial").unwrap();
+        drop(out);
+
+        let (offset, saved) = part.resumable().unwrap();
+        assert_eq!(offset, 7);
+        assert_eq!(saved, validators);
+
+        part.discard();
+        assert!(part.resumable().is_none());
+        assert!(!part.path.exists());
+    }
+
+    #[test]
+    fn test_partial_download_without_validator_starts_over() {
+        let dir = tempfile::tempdir().unwrap();
+        let part = PartialDownload::new(dir.path());
+        let mut out = part.start(&Validators::default()).unwrap();
+        out.write_all(b"partial").unwrap();
+        assert!(part.resumable().is_none());
+    }
 }
@@ -19,6 +19,9 @@ use thiserror::Error;
 pub use builder::DEFAULT_DBLP_URL;
 pub use query::DEFAULT_THRESHOLD;
 
+/// Default age, in days, at which an offline database is reported as stale.
+pub const DEFAULT_MAX_AGE_DAYS: u64 = 30;
+
 #[derive(Error, Debug)]
 pub enum DblpError {
     #[error("database error: {0}")]
@@ -65,6 +68,11 @@ pub enum BuildProgress {
         total_bytes: Option<u64>,
         bytes_decompressed: u64,
     },
+    /// Checking the downloaded dump (MD5, or the gzip CRC) before parsing.
+    Verifying {
+        bytes_checked: u64,
+        bytes_total: u64,
+    },
     Parsing {
         /// Publications inserted into the database.
         records_inserted: u64,
@@ -87,9 +95,33 @@ pub enum BuildProgress {
 pub struct StalenessCheck {
     pub is_stale: bool,
     pub age_days: Option<u64>,
+    /// Build time as Unix seconds, as stored in the database.
     pub build_date: Option<String>,
 }
 
+impl StalenessCheck {
+    /// The build date as `YYYY-MM-DD` (UTC), if the database records one.
+    pub fn build_day(&self) -> Option<String> {
+        let secs: u64 = self.build_date.as_deref()?.parse().ok()?;
+        let (year, month, day) = civil_from_days((secs / 86400) as i64);
+        Some(format!("{:04}-{:02}-{:02}", year, month, day))
+    }
+}
+
+/// Proleptic Gregorian date of a day count since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
 /// Handle to an opened offline DBLP database.
 pub struct DblpDatabase {
     conn: Connection,
@@ -189,9 +221,9 @@ impl DblpDatabase {
         })
     }
 
-    /// Convenience: check staleness with the default 30-day threshold.
+    /// Convenience: check staleness with the default [`DEFAULT_MAX_AGE_DAYS`] threshold.
     pub fn is_stale(&self) -> Result<bool, DblpError> {
-        Ok(self.check_staleness(30)?.is_stale)
+        Ok(self.check_staleness(DEFAULT_MAX_AGE_DAYS)?.is_stale)
     }
 
     /// Get the path to the database file.
@@ -219,3 +251,26 @@ pub fn build_database_from_file(
 ) -> Result<(), DblpError> {
     builder::build_from_file(db_path, xml_gz_path, progress)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn built_at(secs: &str) -> StalenessCheck {
+        StalenessCheck {
+            is_stale: true,
+            age_days: None,
+            build_date: Some(secs.to_string()),
+        }
+    }
+
+    #[test]
+    fn build_day_formats_unix_seconds() {
+        assert_eq!(built_at("0").build_day().as_deref(), Some("1970-01-01"));
+        assert_eq!(
+            built_at("1709210096").build_day().as_deref(),
+            Some("2024-02-29")
+        );
+        assert_eq!(built_at("not a time").build_day(), None);
+    }
+}
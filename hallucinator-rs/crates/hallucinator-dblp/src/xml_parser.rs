@@ -130,11 +130,9 @@ pub fn parse_xml<R: BufRead>(reader: R, mut on_pub: impl FnMut(Publication)) {
                 }
             }
 
-            Ok(Event::CData(ref e)) => {
-                if reading.is_some() {
-                    let text = String::from_utf8_lossy(e.as_ref());
-                    text_buf.push_str(&text);
-                }
+            Ok(Event::CData(ref e)) if reading.is_some() => {
+                let text = String::from_utf8_lossy(e.as_ref());
+                text_buf.push_str(&text);
             }
 
             Ok(Event::End(ref e)) => {
@@ -155,17 +153,13 @@ pub fn parse_xml<R: BufRead>(reader: R, mut on_pub: impl FnMut(Publication)) {
                             Some(Field::Title) => {
                                 current_title = text;
                             }
-                            Some(Field::Author) => {
-                                if !text.is_empty() {
-                                    current_authors.push(text);
-                                }
+                            Some(Field::Author) if !text.is_empty() => {
+                                current_authors.push(text);
                             }
-                            Some(Field::Url) => {
-                                if !text.is_empty() {
-                                    current_url = Some(text);
-                                }
+                            Some(Field::Url) if !text.is_empty() => {
+                                current_url = Some(text);
                             }
-                            None => {}
+                            Some(Field::Author) | Some(Field::Url) | None => {}
                         }
                         reading_editor = false;
                     }
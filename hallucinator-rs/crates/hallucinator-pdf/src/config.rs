@@ -34,11 +34,14 @@ impl<T: Clone> ListOverride<T> {
 #[derive(Debug, Clone)]
 pub struct PdfParsingConfig {
     // ── section.rs ──
-    /// Regex to locate the references section header.
+    /// Regex to locate the references section header. Overrides `section_headings`.
     pub(crate) section_header_re: Option<Regex>,
+    /// Heading texts that open the references section (case-insensitive).
+    pub(crate) section_headings: ListOverride<String>,
     /// Regex to find end markers (Appendix, Acknowledgments, etc.).
     pub(crate) section_end_re: Option<Regex>,
-    /// Fraction of document to use as fallback when no header is found (0.0–1.0).
+    /// Without a header, the citation-block fallback only accepts a block that
+    /// extends past this fraction of the document (0.0–1.0).
     pub(crate) fallback_fraction: f64,
     /// Regex for IEEE-style segmentation: `[1]`, `[2]`, etc.
     pub(crate) ieee_segment_re: Option<Regex>,
@@ -70,6 +73,7 @@ impl Default for PdfParsingConfig {
     fn default() -> Self {
         Self {
             section_header_re: None,
+            section_headings: ListOverride::Default,
             section_end_re: None,
             fallback_fraction: 0.7,
             ieee_segment_re: None,
@@ -91,6 +95,7 @@ impl Default for PdfParsingConfig {
 #[derive(Debug, Clone, Default)]
 pub struct PdfParsingConfigBuilder {
     section_header_re: Option<String>,
+    section_headings: ListOverridePlainBuilder,
     section_end_re: Option<String>,
     fallback_fraction: Option<f64>,
     ieee_segment_re: Option<String>,
@@ -133,6 +138,19 @@ impl PdfParsingConfigBuilder {
         self
     }
 
+    pub fn set_section_headings(mut self, headings: Vec<String>) -> Self {
+        self.section_headings = ListOverridePlainBuilder::Replace(headings);
+        self
+    }
+
+    pub fn add_section_heading(mut self, heading: String) -> Self {
+        match &mut self.section_headings {
+            ListOverridePlainBuilder::Extend(v) => v.push(heading),
+            _ => self.section_headings = ListOverridePlainBuilder::Extend(vec![heading]),
+        }
+        self
+    }
+
     pub fn section_end_regex(mut self, pattern: &str) -> Self {
         self.section_end_re = Some(pattern.to_string());
         self
@@ -250,6 +268,7 @@ impl PdfParsingConfigBuilder {
 
         Ok(PdfParsingConfig {
             section_header_re: compile(self.section_header_re)?,
+            section_headings: compile_plain(self.section_headings),
             section_end_re: compile(self.section_end_re)?,
             fallback_fraction: self.fallback_fraction.unwrap_or(0.7),
             ieee_segment_re: compile(self.ieee_segment_re)?,
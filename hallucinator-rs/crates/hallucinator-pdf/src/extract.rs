@@ -2,21 +2,83 @@ use std::path::Path;
 
 use mupdf::{Document, TextPageFlags};
 
-use crate::PdfError;
+use crate::layout::{self, TextBlock};
 use crate::text_processing::expand_ligatures;
+use crate::{ExtractionOptions, PdfError};
 
 /// Extract text from a PDF file using MuPDF.
 ///
-/// Opens the PDF, iterates all pages, extracts text from each,
-/// joins with newlines, and expands typographic ligatures.
+/// Opens the PDF, iterates all pages, extracts text from each (reading
+/// two-column pages column by column), joins with newlines, and expands
+/// typographic ligatures.
 pub fn extract_text_from_pdf(pdf_path: &Path) -> Result<String, PdfError> {
+    extract_text_from_pdf_with_progress(pdf_path, &|_, _| {})
+}
+
+/// Like [`extract_text_from_pdf`], but calls `on_page(page, total_pages)`
+/// after each page has been extracted (`page` is 1-based).
+pub fn extract_text_from_pdf_with_progress(
+    pdf_path: &Path,
+    on_page: &dyn Fn(usize, usize),
+) -> Result<String, PdfError> {
+    extract_text_from_pdf_with_options(pdf_path, &ExtractionOptions::default(), on_page)
+}
+
+/// Like [`extract_text_from_pdf_with_progress`], honouring
+/// [`ExtractionOptions::force_single_column`] and
+/// [`ExtractionOptions::password`].
+pub fn extract_text_from_pdf_with_options(
+    pdf_path: &Path,
+    options: &ExtractionOptions,
+    on_page: &dyn Fn(usize, usize),
+) -> Result<String, PdfError> {
+    extract_pdf_text(pdf_path, options, on_page).map(|t| t.text)
+}
+
+/// Text extracted from a PDF.
+pub struct PdfText {
+    pub text: String,
+    /// The text was read from page images by OCR (only with the `ocr`
+    /// feature), so it is likely noisier than embedded text.
+    pub ocr_used: bool,
+    /// The embedded text was too sparse, but OCR failed with this error (e.g.
+    /// tesseract is not installed), so the embedded text was used anyway.
+    pub ocr_error: Option<String>,
+}
+
+/// Like [`extract_text_from_pdf_with_options`], also reporting whether the
+/// text came from OCR. With the `ocr` feature, a PDF whose embedded text is
+/// too sparse to be real (a scan) has its trailing pages OCRed instead; if
+/// OCR fails, the sparse text is kept and the failure reported in
+/// [`PdfText::ocr_error`].
+pub fn extract_pdf_text(
+    pdf_path: &Path,
+    options: &ExtractionOptions,
+    on_page: &dyn Fn(usize, usize),
+) -> Result<PdfText, PdfError> {
     let path_str = pdf_path
         .to_str()
         .ok_or_else(|| PdfError::OpenError("invalid path encoding".into()))?;
 
-    let document = Document::open(path_str).map_err(|e| PdfError::OpenError(e.to_string()))?;
+    let mut document = Document::open(path_str).map_err(|e| PdfError::OpenError(e.to_string()))?;
+    if document
+        .needs_password()
+        .map_err(|e| PdfError::OpenError(e.to_string()))?
+    {
+        let password = options.password.as_deref().ok_or(PdfError::Encrypted)?;
+        let unlocked = document
+            .authenticate(password)
+            .map_err(|e| PdfError::OpenError(e.to_string()))?;
+        if !unlocked {
+            return Err(PdfError::Encrypted);
+        }
+    }
 
-    let mut pages_text = Vec::new();
+    let total_pages = document
+        .page_count()
+        .map_err(|e| PdfError::ExtractionError(e.to_string()))?
+        .max(0) as usize;
+    let mut pages_text = Vec::with_capacity(total_pages);
 
     for page_result in document
         .pages()
@@ -28,22 +90,65 @@ pub fn extract_text_from_pdf(pdf_path: &Path) -> Result<String, PdfError> {
             .map_err(|e| PdfError::ExtractionError(e.to_string()))?;
 
         // Use block/line iteration to match PyMuPDF's get_text() behavior
-        let mut page_text = String::new();
-        for block in text_page.blocks() {
-            for line in block.lines() {
-                let line_text: String = line
-                    .chars()
-                    .map(|c| c.char().unwrap_or('\u{FFFD}'))
-                    .collect();
-                page_text.push_str(&line_text);
-                page_text.push('\n');
+        let blocks: Vec<TextBlock> = text_page
+            .blocks()
+            .map(|block| {
+                let bounds = block.bounds();
+                TextBlock {
+                    x0: bounds.x0,
+                    y0: bounds.y0,
+                    x1: bounds.x1,
+                    y1: bounds.y1,
+                    lines: block
+                        .lines()
+                        .map(|line| {
+                            line.chars()
+                                .map(|c| c.char().unwrap_or('\u{FFFD}'))
+                                .collect()
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+        let blocks = if options.force_single_column {
+            blocks
+        } else {
+            let page_bounds = page
+                .bounds()
+                .map_err(|e| PdfError::ExtractionError(e.to_string()))?;
+            layout::order_blocks(
+                blocks,
+                page_bounds.x1 - page_bounds.x0,
+                page_bounds.y1 - page_bounds.y0,
+            )
+        };
+        pages_text.push(layout::blocks_to_text(&blocks));
+        on_page(pages_text.len(), total_pages);
+    }
+
+    #[cfg_attr(not(feature = "ocr"), allow(unused_mut))]
+    let mut ocr_error = None;
+    #[cfg(feature = "ocr")]
+    if crate::ocr::text_is_sparse(&pages_text) {
+        match crate::ocr::ocr_trailing_pages(&document) {
+            Ok(ocr_pages) => {
+                return Ok(PdfText {
+                    text: expand_ligatures(&ocr_pages.join("\n")),
+                    ocr_used: true,
+                    ocr_error: None,
+                });
             }
+            // The sparse text may still hold a references section
+            Err(e) => ocr_error = Some(e.to_string()),
         }
-        pages_text.push(page_text);
     }
 
     let text = pages_text.join("\n");
 
     // Expand typographic ligatures (ﬁ → fi, ﬂ → fl, etc.)
-    Ok(expand_ligatures(&text))
+    Ok(PdfText {
+        text: expand_ligatures(&text),
+        ocr_used: false,
+        ocr_error,
+    })
 }
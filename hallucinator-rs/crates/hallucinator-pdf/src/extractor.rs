@@ -4,8 +4,10 @@ use regex::Regex;
 use std::path::Path;
 
 use crate::config::PdfParsingConfig;
-use crate::{ExtractionResult, PdfError, Reference, SkipStats};
-use crate::{authors, identifiers, section, text_processing, title};
+use crate::{
+    ExtractionOptions, ExtractionProgress, ExtractionResult, PdfError, Reference, SkipStats,
+};
+use crate::{authors, footnotes, identifiers, section, text_processing, title};
 
 /// A configurable PDF reference extraction pipeline.
 ///
@@ -14,6 +16,7 @@ use crate::{authors, identifiers, section, text_processing, title};
 /// to supply custom regex patterns and thresholds.
 pub struct PdfExtractor {
     config: PdfParsingConfig,
+    options: ExtractionOptions,
 }
 
 impl Default for PdfExtractor {
@@ -25,14 +28,21 @@ impl Default for PdfExtractor {
 impl PdfExtractor {
     /// Create an extractor with default configuration.
     pub fn new() -> Self {
-        Self {
-            config: PdfParsingConfig::default(),
-        }
+        Self::with_config(PdfParsingConfig::default())
     }
 
     /// Create an extractor with a custom configuration.
     pub fn with_config(config: PdfParsingConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            options: ExtractionOptions::default(),
+        }
+    }
+
+    /// Replace the [`ExtractionOptions`].
+    pub fn with_options(mut self, options: ExtractionOptions) -> Self {
+        self.options = options;
+        self
     }
 
     /// Get a reference to the current config.
@@ -40,10 +50,15 @@ impl PdfExtractor {
         &self.config
     }
 
+    /// Get the current extraction options.
+    pub fn options(&self) -> &ExtractionOptions {
+        &self.options
+    }
+
     /// Extract raw text from a PDF file (step 1).
     #[cfg(feature = "pdf")]
     pub fn extract_text(&self, path: &Path) -> Result<String, PdfError> {
-        crate::extract::extract_text_from_pdf(path)
+        crate::extract::extract_text_from_pdf_with_options(path, &self.options, &|_, _| {})
     }
 
     /// Locate the references section in document text (step 2).
@@ -66,18 +81,126 @@ impl PdfExtractor {
     /// Run the full extraction pipeline on a PDF file.
     #[cfg(feature = "pdf")]
     pub fn extract_references(&self, pdf_path: &Path) -> Result<ExtractionResult, PdfError> {
-        let text = self.extract_text(pdf_path)?;
-        self.extract_references_from_text(&text)
+        self.extract_references_with_progress(pdf_path, &|_| {})
+    }
+
+    /// Run the full extraction pipeline on a PDF file, reporting each step.
+    #[cfg(feature = "pdf")]
+    pub fn extract_references_with_progress(
+        &self,
+        pdf_path: &Path,
+        progress: &dyn Fn(ExtractionProgress),
+    ) -> Result<ExtractionResult, PdfError> {
+        let pdf_text =
+            crate::extract::extract_pdf_text(pdf_path, &self.options, &|page, total| {
+                progress(ExtractionProgress::ExtractingText {
+                    page,
+                    total_pages: total,
+                })
+            })?;
+        let mut result =
+            self.extract_references_from_text_with_progress(&pdf_text.text, progress)?;
+        result.ocr_used = pdf_text.ocr_used;
+        result.ocr_error = pdf_text.ocr_error;
+        Ok(result)
     }
 
     /// Run the extraction pipeline on already-extracted text.
     pub fn extract_references_from_text(&self, text: &str) -> Result<ExtractionResult, PdfError> {
-        let ref_section = self
-            .find_references_section(text)
-            .ok_or(PdfError::NoReferencesSection)?;
+        self.extract_references_from_text_with_progress(text, &|_| {})
+    }
+
+    /// Run the extraction pipeline on already-extracted text, reporting each step.
+    pub fn extract_references_from_text_with_progress(
+        &self,
+        text: &str,
+        progress: &dyn Fn(ExtractionProgress),
+    ) -> Result<ExtractionResult, PdfError> {
+        progress(ExtractionProgress::LocatingReferences);
+        let ref_section = self.find_references_section(text);
+        if !self.options.include_footnotes {
+            let ref_section = ref_section.ok_or(PdfError::NoReferencesSection)?;
+            progress(ExtractionProgress::Segmenting);
+            let raw_refs = self.segment_references(&ref_section);
+            return Ok(self.parse_segments(&raw_refs, progress));
+        }
+
+        // Notes sit in the body; scanning the references section as well
+        // would read its numbered entries as notes.
+        let body = ref_section
+            .as_deref()
+            .and_then(|section| text.find(section))
+            .map_or(text, |start| &text[..start]);
+        let notes = footnotes::find_footnote_citations(body);
+        if ref_section.is_none() && notes.is_empty() {
+            return Err(PdfError::NoReferencesSection);
+        }
+
+        progress(ExtractionProgress::Segmenting);
+        let raw_refs = ref_section
+            .map(|section| self.segment_references(&section))
+            .unwrap_or_default();
+        let mut result = self.parse_segments(&raw_refs, progress);
+        self.add_footnotes(&mut result, &notes);
+        Ok(result)
+    }
+
+    /// Append footnote citations to `result`, after the references section,
+    /// leaving out works it already lists.
+    fn add_footnotes(&self, result: &mut ExtractionResult, notes: &[String]) {
+        let key = |title: &str| -> String {
+            title
+                .chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect()
+        };
+        let mut seen: std::collections::HashSet<String> = result
+            .references
+            .iter()
+            .filter(|r| r.skip_reason.is_none())
+            .filter_map(|r| r.title.as_deref().map(key))
+            .collect();
+
+        for note in notes {
+            let number = result.skip_stats.total_raw + 1;
+            match footnotes::parse_footnote(note, &self.config) {
+                ParsedRef::Ref(mut r) => {
+                    if !seen.insert(r.title.as_deref().map(key).unwrap_or_default()) {
+                        continue;
+                    }
+                    r.original_number = number;
+                    if r.authors.is_empty() {
+                        result.skip_stats.no_authors += 1;
+                    }
+                    result.references.push(r);
+                }
+                ParsedRef::Skip(reason, raw_citation, title) => {
+                    let r = skipped_reference(&mut result.skip_stats, reason, raw_citation, title);
+                    result.references.push(Reference {
+                        original_number: number,
+                        ..r
+                    });
+                }
+            }
+            result.skip_stats.total_raw += 1;
+        }
+    }
 
-        let raw_refs = self.segment_references(&ref_section);
+    /// Parse a plain reference list, e.g. a pasted bibliography in a `.txt`
+    /// file. There is no references heading to find: every block separated
+    /// by a blank line is a citation (see [`section::segment_citation_list`]).
+    pub fn extract_references_from_citation_list(&self, text: &str) -> ExtractionResult {
+        let raw_refs = section::segment_citation_list(text);
+        self.parse_segments(&raw_refs, &|_| {})
+    }
 
+    /// Parse already-segmented citations into references and skip stats.
+    fn parse_segments(
+        &self,
+        raw_refs: &[String],
+        progress: &dyn Fn(ExtractionProgress),
+    ) -> ExtractionResult {
         let mut stats = SkipStats {
             total_raw: raw_refs.len(),
             ..Default::default()
@@ -87,24 +210,40 @@ impl PdfExtractor {
         let mut previous_authors: Vec<String> = Vec::new();
 
         for (raw_idx, ref_text) in raw_refs.iter().enumerate() {
+            progress(ExtractionProgress::Parsing {
+                current: raw_idx + 1,
+                total: raw_refs.len(),
+            });
             let parsed = parse_single_reference(ref_text, &previous_authors, &self.config);
+            let parsed = match parsed {
+                // A dataset or software citation with a DOI: keep it and let
+                // the checker resolve the DOI.
+                ParsedRef::Skip(SkipReason::UrlOnly, raw_citation, title)
+                    if self.options.check_url_only =>
+                {
+                    match identifiers::extract_doi(ref_text) {
+                        Some(doi) => ParsedRef::Ref(Reference {
+                            raw_citation,
+                            title,
+                            authors: vec![],
+                            doi: Some(doi),
+                            arxiv_id: None,
+                            arxiv_version: None,
+                            original_number: 0,
+                            skip_reason: None,
+                            doi_only: true,
+                        }),
+                        None => ParsedRef::Skip(SkipReason::UrlOnly, raw_citation, title),
+                    }
+                }
+                parsed => parsed,
+            };
             match parsed {
                 ParsedRef::Skip(reason, raw_citation, title) => {
-                    match reason {
-                        SkipReason::UrlOnly => stats.url_only += 1,
-                        SkipReason::ShortTitle => stats.short_title += 1,
-                    }
+                    let r = skipped_reference(&mut stats, reason, raw_citation, title);
                     references.push(Reference {
-                        raw_citation,
-                        title,
-                        authors: vec![],
-                        doi: None,
-                        arxiv_id: None,
                         original_number: raw_idx + 1,
-                        skip_reason: Some(match reason {
-                            SkipReason::UrlOnly => "url_only".to_string(),
-                            SkipReason::ShortTitle => "short_title".to_string(),
-                        }),
+                        ..r
                     });
                 }
                 ParsedRef::Ref(mut r) => {
@@ -119,10 +258,39 @@ impl PdfExtractor {
             }
         }
 
-        Ok(ExtractionResult {
+        ExtractionResult {
             references,
             skip_stats: stats,
-        })
+            ocr_used: false,
+            ocr_error: None,
+        }
+    }
+}
+
+/// Count a skipped reference in `stats` and build its placeholder entry.
+/// The caller sets `original_number`.
+fn skipped_reference(
+    stats: &mut SkipStats,
+    reason: SkipReason,
+    raw_citation: String,
+    title: Option<String>,
+) -> Reference {
+    match reason {
+        SkipReason::UrlOnly => stats.url_only += 1,
+        SkipReason::ShortTitle => stats.short_title += 1,
+        SkipReason::NoTitle => stats.no_title += 1,
+        SkipReason::UnresolvedFootnote => stats.unresolved_footnote += 1,
+    }
+    Reference {
+        raw_citation,
+        title,
+        authors: vec![],
+        doi: None,
+        arxiv_id: None,
+        arxiv_version: None,
+        original_number: 0,
+        skip_reason: Some(reason.as_str().to_string()),
+        doi_only: false,
     }
 }
 
@@ -137,17 +305,34 @@ pub enum ParsedRef {
 pub enum SkipReason {
     UrlOnly,
     ShortTitle,
+    /// No title could be extracted from the segment at all.
+    NoTitle,
+    /// A footnote that names no work of its own ("Ibid.", "op. cit.") or
+    /// whose title could not be made out.
+    UnresolvedFootnote,
+}
+
+impl SkipReason {
+    /// The `Reference::skip_reason` string for this reason.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SkipReason::UrlOnly => "url_only",
+            SkipReason::ShortTitle => "short_title",
+            SkipReason::NoTitle => "no_title",
+            SkipReason::UnresolvedFootnote => "unresolved_footnote",
+        }
+    }
 }
 
 /// Parse a single reference string, applying config overrides.
-fn parse_single_reference(
+pub(crate) fn parse_single_reference(
     ref_text: &str,
     prev_authors: &[String],
     config: &PdfParsingConfig,
 ) -> ParsedRef {
     // Extract DOI and arXiv ID BEFORE fixing hyphenation
     let doi = identifiers::extract_doi(ref_text);
-    let arxiv_id = identifiers::extract_arxiv_id(ref_text);
+    let arxiv = identifiers::extract_arxiv(ref_text);
 
     // Remove standalone page/column numbers on their own lines
     static PAGE_NUM_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\n\d{1,4}\n").unwrap());
@@ -193,17 +378,15 @@ fn parse_single_reference(
         // Note: from_quotes alone is not a strong signal — most IEEE/ACM refs
         // use quoted titles, which would bypass min_title_words for nearly everything.
         let has_strong_signal = !cleaned_title.is_empty()
-            && (doi.is_some() || arxiv_id.is_some() || looks_like_citation(&ref_text));
+            && (doi.is_some() || arxiv.is_some() || looks_like_citation(&ref_text));
 
         if !has_strong_signal {
             static WS_SKIP_RE2: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s+").unwrap());
             let raw = WS_SKIP_RE2.replace_all(&ref_text, " ").trim().to_string();
-            let title = if cleaned_title.is_empty() {
-                None
-            } else {
-                Some(cleaned_title)
-            };
-            return ParsedRef::Skip(SkipReason::ShortTitle, raw, title);
+            if cleaned_title.is_empty() {
+                return ParsedRef::Skip(SkipReason::NoTitle, raw, None);
+            }
+            return ParsedRef::Skip(SkipReason::ShortTitle, raw, Some(cleaned_title));
         }
     }
 
@@ -232,9 +415,11 @@ fn parse_single_reference(
         title: Some(cleaned_title),
         authors: ref_authors,
         doi,
-        arxiv_id,
+        arxiv_version: arxiv.as_ref().and_then(|a| a.version),
+        arxiv_id: arxiv.map(|a| a.id),
         original_number: 0, // placeholder; overwritten by caller
         skip_reason: None,
+        doi_only: false,
     })
 }
 
@@ -319,6 +504,42 @@ mod tests {
         assert_eq!(result.references.len(), 3);
     }
 
+    #[test]
+    fn test_extractor_reports_progress_steps() {
+        let ext = PdfExtractor::new();
+        let mut text = String::new();
+        text.push_str("Body text.\n\nReferences\n42\n");
+        text.push_str("[1] J. Smith, A. Jones, \"Detecting Fake References in Academic Papers,\" in Proc. IEEE Conf., 2023.\n");
+        text.push_str("[2] A. Brown, B. Davis, \"Another Important Paper on Machine Learning Approaches,\" in Proc. AAAI, 2022.\n");
+        text.push_str("[3] C. Wilson, \"A Third Paper About Natural Language Processing Systems,\" in Proc. ACL, 2021.\n");
+
+        let events = std::sync::Mutex::new(Vec::new());
+        ext.extract_references_from_text_with_progress(&text, &|p| events.lock().unwrap().push(p))
+            .unwrap();
+
+        let events = events.into_inner().unwrap();
+        assert_eq!(
+            events,
+            vec![
+                ExtractionProgress::LocatingReferences,
+                ExtractionProgress::Segmenting,
+                ExtractionProgress::Parsing {
+                    current: 1,
+                    total: 3
+                },
+                ExtractionProgress::Parsing {
+                    current: 2,
+                    total: 3
+                },
+                ExtractionProgress::Parsing {
+                    current: 3,
+                    total: 3
+                },
+            ]
+        );
+        assert!(events.last().unwrap().fraction() >= 0.99);
+    }
+
     #[test]
     fn test_extractor_skips_url_only_refs() {
         let ext = PdfExtractor::new();
@@ -327,7 +548,10 @@ mod tests {
         let parsed = ext.parse_reference(ref_text, &[]);
         match parsed {
             ParsedRef::Skip(SkipReason::UrlOnly, _, _) => {} // expected
-            ParsedRef::Skip(SkipReason::ShortTitle, _, _) => {} // also acceptable
+            ParsedRef::Skip(SkipReason::ShortTitle | SkipReason::NoTitle, _, _) => {} // also acceptable
+            ParsedRef::Skip(SkipReason::UnresolvedFootnote, _, _) => {
+                panic!("only footnotes are unresolved")
+            }
             ParsedRef::Ref(r) => panic!("URL-only ref should be skipped, got: {:?}", r.title),
         }
 
@@ -343,12 +567,60 @@ mod tests {
     #[test]
     fn test_extractor_no_references_section() {
         let ext = PdfExtractor::new();
-        // Very short text with no references header — fallback will kick in but
-        // there won't be meaningful references to parse
+        // No references header and nothing citation-shaped for the fallback
         let text = "Short.";
         let result = ext.extract_references_from_text(text);
-        // Fallback returns empty section text, which is still Ok but with 0 refs
-        assert!(result.is_ok());
+        assert!(matches!(result, Err(PdfError::NoReferencesSection)));
+    }
+
+    #[test]
+    fn test_extractor_footnotes_without_and_alongside_bibliography() {
+        let notes = "Readers annotated their books heavily.1 Some did so in code.2\n\
+            1 Jane Smith, The History of Reading in Early Modern Europe (Chicago:\n\
+            University of Chicago Press, 2003), 45.\n\
+            2 Ibid., 50.\n\
+            3 John Doe, “Marginalia and the Making of Early Modern Readers,” Journal\n\
+            of Book History 12 (2009): 45–67.\n";
+        let ext = PdfExtractor::new().with_options(ExtractionOptions {
+            include_footnotes: true,
+            ..Default::default()
+        });
+
+        // Footnotes only: off by default, found with the option
+        assert!(matches!(
+            PdfExtractor::new().extract_references_from_text(notes),
+            Err(PdfError::NoReferencesSection)
+        ));
+        let result = ext.extract_references_from_text(notes).unwrap();
+        assert_eq!(result.references.len(), 3);
+        assert_eq!(result.skip_stats.total_raw, 3);
+        assert_eq!(result.skip_stats.unresolved_footnote, 1);
+        assert_eq!(
+            result.references[1].skip_reason.as_deref(),
+            Some("unresolved_footnote")
+        );
+        assert_eq!(result.references[2].original_number, 3);
+
+        // Alongside a bibliography: notes citing a listed work are not repeated
+        let text = format!(
+            "{}\nReferences\n[1] J. Smith, \"The History of Reading in Early Modern Europe,\" \
+             University of Chicago Press, 2003.\n",
+            notes
+        );
+        let result = ext.extract_references_from_text(&text).unwrap();
+        let titles: Vec<_> = result
+            .references
+            .iter()
+            .filter_map(|r| r.title.as_deref())
+            .collect();
+        assert_eq!(
+            titles,
+            [
+                "The History of Reading in Early Modern Europe",
+                "Marginalia and the Making of Early Modern Readers"
+            ]
+        );
+        assert_eq!(result.skip_stats.unresolved_footnote, 1);
     }
 
     // ── Custom config actually takes effect ──
@@ -366,16 +638,13 @@ mod tests {
         let section = ext.find_references_section(text).unwrap();
         assert!(section.contains("[1] Primer ref."));
 
-        // Default "References" header should NOT match with this custom regex —
-        // the extractor falls back to the last 30% of the document.
-        // Make the text long enough so fallback doesn't include the header.
+        // Default "References" header should NOT match with this custom regex,
+        // and there is no citation block for the fallback to find.
         let padding = "X ".repeat(200);
         let text2 = format!("{}.\n\nReferences\n\nSome refs here.\n", padding);
-        let section2 = ext.find_references_section(&text2).unwrap();
-        // Fallback grabs the tail — shouldn't start cleanly after "References"
         assert!(
-            !section2.starts_with("\n["),
-            "Should be fallback, not header-matched"
+            ext.find_references_section(&text2).is_none(),
+            "Should not be header-matched"
         );
     }
 
@@ -401,11 +670,21 @@ mod tests {
             .unwrap();
         let ext = PdfExtractor::with_config(config);
 
-        // No references header, so fallback kicks in
-        let text = "A".repeat(100) + " last ten percent here";
-        let section = ext.find_references_section(&text).unwrap();
-        // With 0.9 fraction, we get the last ~10%
-        assert!(section.len() < text.len() / 2);
+        // No references header; the citation block ends well before the last 10%
+        let text = "Body.\n[1] A. Smith. First. In Proc. X, 2020.\n[2] B. Jones. Second. In Proc. Y, 2021.\n[3] C. Lee. Third. In Proc. Z, 2022.\n"
+            .to_string()
+            + &"Trailing prose without citations. ".repeat(20);
+        assert!(ext.find_references_section(&text).is_none());
+        assert!(PdfExtractor::new().find_references_section(&text).is_none());
+
+        let relaxed = PdfParsingConfigBuilder::new()
+            .fallback_fraction(0.1)
+            .build()
+            .unwrap();
+        let section = PdfExtractor::with_config(relaxed)
+            .find_references_section(&text)
+            .unwrap();
+        assert!(section.starts_with("[1] A. Smith."));
     }
 
     #[test]
@@ -625,12 +904,59 @@ mod tests {
                 );
             }
             ParsedRef::Ref(_) => panic!("Non-academic URL should be skipped"),
-            ParsedRef::Skip(SkipReason::ShortTitle, _, _) => {
-                panic!("Should be UrlOnly skip, not ShortTitle")
+            ParsedRef::Skip(
+                SkipReason::ShortTitle | SkipReason::NoTitle | SkipReason::UnresolvedFootnote,
+                _,
+                _,
+            ) => {
+                panic!("Should be UrlOnly skip, not a title skip")
             }
         }
     }
 
+    #[test]
+    fn test_segment_without_title_counts_as_no_title() {
+        let text = "References\n[1] J. Smith, \"A Study of Reference Extraction from Scientific PDFs,\" in Proc. ACL, 2023.\n[2] ---\n[3] K. Lee, \"Detecting Fabricated Citations in Machine Learning Papers,\" in Proc. EMNLP, 2022.\n";
+        let result = PdfExtractor::new()
+            .extract_references_from_text(text)
+            .unwrap();
+        assert_eq!(result.skip_stats.no_title, 1);
+        assert_eq!(result.skip_stats.short_title, 0);
+        assert_eq!(
+            result.references[1].skip_reason.as_deref(),
+            Some("no_title")
+        );
+    }
+
+    #[test]
+    fn test_check_url_only_keeps_refs_with_doi() {
+        let mut text = String::new();
+        text.push_str("Body text.\n\nReferences\n42\n");
+        text.push_str("[1] J. Smith, \"Benchmark Dataset for Reference Checking,\" https://zenodo.org/records/123, doi:10.5281/zenodo.123, 2023.\n");
+        text.push_str("[2] A. Doe, \"Some Interesting Report About Software,\" https://example.com/report, 2022.\n");
+        text.push_str("[3] C. Wilson, \"A Third Paper About Natural Language Processing Systems,\" in Proc. ACL, 2021.\n");
+
+        let default = PdfExtractor::new()
+            .extract_references_from_text(&text)
+            .unwrap();
+        assert_eq!(default.skip_stats.url_only, 2);
+
+        let ext = PdfExtractor::new().with_options(ExtractionOptions {
+            check_url_only: true,
+            ..Default::default()
+        });
+        let result = ext.extract_references_from_text(&text).unwrap();
+        assert_eq!(result.skip_stats.url_only, 1);
+        let kept = &result.references[0];
+        assert!(kept.doi_only);
+        assert!(kept.skip_reason.is_none());
+        assert_eq!(kept.doi.as_deref(), Some("10.5281/zenodo.123"));
+        assert_eq!(
+            result.references[1].skip_reason.as_deref(),
+            Some("url_only")
+        );
+    }
+
     #[test]
     fn test_two_word_title_rescued_by_venue() {
         // "Translation-based Recommendation" is 2 words — below min_title_words=4.
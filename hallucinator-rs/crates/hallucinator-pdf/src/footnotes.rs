@@ -0,0 +1,228 @@
+//! Citations given in footnotes rather than a references section, as in much
+//! of the humanities (Chicago notes-and-bibliography style). Enabled with
+//! [`ExtractionOptions::include_footnotes`](crate::ExtractionOptions::include_footnotes).
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::Reference;
+use crate::config::PdfParsingConfig;
+use crate::extractor::{ParsedRef, SkipReason};
+use crate::{identifiers, text_processing, title};
+
+/// A note line: its number (superscripts come out of the PDF as plain
+/// digits, sometimes followed by a period) and the start of its text.
+static NOTE_START_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\s*(\d{1,3})\.?\s+(\S.*)$").unwrap());
+
+/// Notes that point back at an earlier one instead of naming a work.
+static BACK_REFERENCE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^(?:ibid\b|ib\.|id\.|idem\b)|\b(?:op\.?\s*cit|loc\.?\s*cit)\b|\bsupra\s+note\b",
+    )
+    .unwrap()
+});
+
+/// Longest a note may run over continuation lines.
+const MAX_NOTE_LINES: usize = 8;
+
+/// The footnote citations in `text`, in order, without their note numbers.
+///
+/// A note is a line starting with its number, continued over the following
+/// lines until one ends with a period. It counts as a citation when it has a
+/// year in parentheses ("(Chicago: UCP, 2003)", "12 (2003): 45") or a quoted
+/// title next to a year, or when it is a back-reference such as "Ibid., 45."
+/// so that it can be reported as unresolved. Note numbers must rise (or start
+/// over at 1, for per-chapter numbering), which keeps numbered headings and
+/// list items in the body text out.
+pub fn find_footnote_citations(text: &str) -> Vec<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut notes = Vec::new();
+    let mut last_number = 0;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let Some(caps) = NOTE_START_RE.captures(lines[i]) else {
+            i += 1;
+            continue;
+        };
+        let number: usize = caps[1].parse().unwrap_or(0);
+        let mut note = caps[2].trim_end().to_string();
+        let mut end = i + 1;
+        while !note.ends_with('.') && end < lines.len() && end - i < MAX_NOTE_LINES {
+            let next = lines[end].trim();
+            if next.is_empty() || NOTE_START_RE.is_match(lines[end]) {
+                break;
+            }
+            note.push('\n');
+            note.push_str(next);
+            end += 1;
+        }
+
+        let in_sequence = number > last_number || number == 1;
+        if in_sequence && (is_back_reference(&note) || looks_like_note_citation(&note)) {
+            last_number = number;
+            notes.push(note);
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+    notes
+}
+
+/// Whether a note refers back to an earlier citation ("Ibid.", "Id.",
+/// "Smith, op. cit., 12", "See supra note 4") rather than naming a work.
+pub fn is_back_reference(note: &str) -> bool {
+    BACK_REFERENCE_RE.is_match(note.trim_start())
+}
+
+fn looks_like_note_citation(note: &str) -> bool {
+    static YEAR_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b(?:1[5-9]|20)\d{2}\b").unwrap());
+    static PAREN_YEAR_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"\([^()]*\b(?:1[5-9]|20)\d{2}[a-z]?\)").unwrap());
+    static QUOTED_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"[“"][^”"]{10,}[”"]"#).unwrap());
+
+    YEAR_RE.is_match(note) && (PAREN_YEAR_RE.is_match(note) || QUOTED_RE.is_match(note))
+}
+
+/// Parse a footnote citation into a reference.
+///
+/// Handles the two Chicago note forms, `Author, “Article,” Journal 12 (2003): 45.`
+/// and `Author, Book Title (Place: Publisher, 2003), 45.`, and otherwise
+/// falls back to the bibliography parser. Back-references and notes without
+/// a recognisable title are skipped as [`SkipReason::UnresolvedFootnote`].
+pub fn parse_footnote(note: &str, config: &PdfParsingConfig) -> ParsedRef {
+    static WS_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s+").unwrap());
+    static ARTICLE_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r#"^(?P<authors>[^“"]+?),\s*[“"](?P<title>[^”"]+?)[,.]?\s*[”"]"#).unwrap()
+    });
+    static BOOK_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"^(?P<authors>[^,()]+?),\s*(?P<title>[^()]+?)\s*\([^()]*\b\d{4}[^()]*\)")
+            .unwrap()
+    });
+
+    let doi = identifiers::extract_doi(note);
+    let arxiv = identifiers::extract_arxiv(note);
+    let text = text_processing::fix_hyphenation_with_config(note, config);
+    let raw_citation = WS_RE.replace_all(&text, " ").trim().to_string();
+
+    if is_back_reference(&raw_citation) {
+        return ParsedRef::Skip(SkipReason::UnresolvedFootnote, raw_citation, None);
+    }
+
+    let (authors, found_title, from_quotes) = if let Some(c) = ARTICLE_RE.captures(&raw_citation) {
+        (c["authors"].to_string(), c["title"].to_string(), true)
+    } else if let Some(c) = BOOK_RE.captures(&raw_citation) {
+        (c["authors"].to_string(), c["title"].to_string(), false)
+    } else {
+        return match crate::extractor::parse_single_reference(&raw_citation, &[], config) {
+            ParsedRef::Skip(SkipReason::NoTitle, raw, title) => {
+                ParsedRef::Skip(SkipReason::UnresolvedFootnote, raw, title)
+            }
+            parsed => parsed,
+        };
+    };
+
+    let cleaned_title = title::clean_title_with_config(&found_title, from_quotes, config);
+    if cleaned_title.is_empty() {
+        return ParsedRef::Skip(SkipReason::UnresolvedFootnote, raw_citation, None);
+    }
+    if cleaned_title.split_whitespace().count() < config.min_title_words {
+        return ParsedRef::Skip(SkipReason::ShortTitle, raw_citation, Some(cleaned_title));
+    }
+
+    ParsedRef::Ref(Reference {
+        raw_citation,
+        title: Some(cleaned_title),
+        authors: split_note_authors(&authors),
+        doi,
+        arxiv_version: arxiv.as_ref().and_then(|a| a.version),
+        arxiv_id: arxiv.map(|a| a.id),
+        original_number: 0, // placeholder; overwritten by caller
+        skip_reason: None,
+        doi_only: false,
+    })
+}
+
+/// Split a note's author part ("Jane Smith, John Doe, and Ann Lee, eds.")
+/// into names. Notes give names in reading order, so commas only separate
+/// authors.
+fn split_note_authors(authors: &str) -> Vec<String> {
+    static SEP_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r",?\s+(?:and|&)\s+|,\s*").unwrap());
+    static ROLE_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?i)^(?:eds?\.?|trans\.?|et al\.?)$").unwrap());
+
+    SEP_RE
+        .split(authors)
+        .map(str::trim)
+        .filter(|a| !a.is_empty() && !ROLE_RE.is_match(a))
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTES: &str = "\
+The archive was reorganised twice in the period.1 Later historians
+disagreed about its scope.2
+2 Sources and Method
+1 Jane Smith, The History of Reading in Early Modern Europe (Chicago:
+University of Chicago Press, 2003), 45.
+2 John Doe and Ann Lee, “Marginalia and the Making of Readers,” Journal
+of Book History 12 (2009): 45–67.
+3 Ibid., 50.
+4 Smith, op. cit., 12.
+";
+
+    #[test]
+    fn finds_numbered_notes_and_skips_body_headings() {
+        let notes = find_footnote_citations(NOTES);
+        assert_eq!(notes.len(), 4, "{:?}", notes);
+        assert!(notes[0].starts_with("Jane Smith"));
+        assert!(notes[0].ends_with("45."));
+        assert!(notes[1].contains("Marginalia"));
+        assert_eq!(notes[2], "Ibid., 50.");
+    }
+
+    #[test]
+    fn parses_book_and_article_notes() {
+        let config = PdfParsingConfig::default();
+        let notes = find_footnote_citations(NOTES);
+
+        let ParsedRef::Ref(book) = parse_footnote(&notes[0], &config) else {
+            panic!("book note not parsed");
+        };
+        assert_eq!(
+            book.title.as_deref(),
+            Some("The History of Reading in Early Modern Europe")
+        );
+        assert_eq!(book.authors, vec!["Jane Smith"]);
+
+        let ParsedRef::Ref(article) = parse_footnote(&notes[1], &config) else {
+            panic!("article note not parsed");
+        };
+        assert_eq!(
+            article.title.as_deref(),
+            Some("Marginalia and the Making of Readers")
+        );
+        assert_eq!(article.authors, vec!["John Doe", "Ann Lee"]);
+    }
+
+    #[test]
+    fn back_references_are_unresolved() {
+        let config = PdfParsingConfig::default();
+        for note in ["Ibid., 50.", "Smith, op. cit., 12.", "See supra note 4."] {
+            assert!(
+                matches!(
+                    parse_footnote(note, &config),
+                    ParsedRef::Skip(SkipReason::UnresolvedFootnote, _, None)
+                ),
+                "{}",
+                note
+            );
+        }
+    }
+}
@@ -2,9 +2,10 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use std::collections::HashSet;
 
-/// Strip unbalanced trailing parentheses, brackets, and braces from a DOI.
+/// Strip trailing punctuation and closing quotes, plus unbalanced trailing
+/// parentheses, brackets, and braces, from a DOI.
 fn clean_doi(doi: &str) -> String {
-    let mut doi = doi.trim_end_matches(['.', ',', ';', ':']);
+    let mut doi = doi.trim_end_matches(['.', ',', ';', ':', '"', '\u{201d}']);
 
     // Strip unbalanced trailing )
     loop {
@@ -39,23 +40,38 @@ fn clean_doi(doi: &str) -> String {
     doi.to_string()
 }
 
-/// Extract DOI from reference text.
+/// Canonical form of a matched DOI: trailing punctuation stripped and
+/// lowercased (DOIs are case-insensitive, so `10.1109/SP.2019` and
+/// `10.1109/sp.2019` are the same record).
+fn canonical_doi(doi: &str) -> String {
+    clean_doi(doi).to_lowercase()
+}
+
+/// Extract DOI from reference text as a canonical bare DOI.
 ///
 /// Handles formats like:
 /// - `10.1234/example`
 /// - `doi:10.1234/example`
 /// - `https://doi.org/10.1234/example`
-/// - `http://dx.doi.org/10.1234/example`
+/// - `http://dx.doi.org/10.1234/example`, `dx.doi.org/10.1234/example`
 ///
 /// Also handles DOIs split across lines (common in PDFs) and DOIs
 /// containing parentheses (e.g., `10.1016/0021-9681(87)90171-8`).
+///
+/// When the text holds several DOIs, the one nearest the end wins: a
+/// citation's own DOI usually closes it, while earlier ones tend to belong
+/// to a venue or a related work mentioned in passing.
 pub fn extract_doi(text: &str) -> Option<String> {
     // Fix DOIs that are split across lines
 
+    // Pattern 0: line break right after the prefix slash
+    static FIX0: Lazy<Regex> = Lazy::new(|| Regex::new(r"(10\.\d{4,}/)\s*\n\s*(\S)").unwrap());
+    let text = FIX0.replace_all(text, "$1$2");
+
     // Pattern 1: DOI ending with period + newline + 3+ digits
     static FIX1: Lazy<Regex> =
         Lazy::new(|| Regex::new(r"(10\.\d{4,}/[^\s\]>,]+\.)\s*\n\s*(\d{3,})").unwrap());
-    let text_fixed = FIX1.replace_all(text, "$1$2");
+    let text_fixed = FIX1.replace_all(&text, "$1$2");
 
     // Pattern 1b: DOI ending with digits + newline + DOI continuation
     static FIX1B: Lazy<Regex> =
@@ -83,73 +99,119 @@ pub fn extract_doi(text: &str) -> Option<String> {
     });
     let text_fixed = FIX3B.replace_all(&text_fixed, "$1$2");
 
-    // Priority 1: Extract from URL format (most reliable)
-    static URL_RE: Lazy<Regex> = Lazy::new(|| {
-        Regex::new(r"(?i)https?://(?:dx\.)?doi\.org/(10\.\d{4,}/[^\s\]>},]+)").unwrap()
-    });
-    if let Some(caps) = URL_RE.captures(&text_fixed) {
-        let doi = caps.get(1).unwrap().as_str();
-        return Some(clean_doi(doi));
-    }
-
-    // Priority 2: DOI pattern without URL prefix
+    // `doi:`, `doi.org/` and `dx.doi.org/` prefixes all end right before the
+    // bare `10.NNNN/...` form, so matching that alone covers every variant.
     static DOI_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"10\.\d{4,}/[^\s\]>},]+").unwrap());
-    if let Some(m) = DOI_RE.find(&text_fixed) {
-        let doi = m.as_str();
-        return Some(clean_doi(doi));
+    DOI_RE
+        .find_iter(&text_fixed)
+        .map(|m| canonical_doi(m.as_str()))
+        .filter(|doi| !doi.ends_with('/'))
+        .last()
+}
+
+/// An arXiv identifier with its version suffix split off.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArxivId {
+    /// Canonical id without version, e.g. `2301.12345` or `hep-th/9901001`.
+    pub id: String,
+    /// Version number from a `vN` suffix, if the citation gave one.
+    pub version: Option<u32>,
+}
+
+impl ArxivId {
+    /// Split a raw id like `2301.12345v2` into id and version, and bring it
+    /// to the one form arXiv itself reports.
+    ///
+    /// Whitespace left by PDF extraction is dropped, old-style archives are
+    /// lowercased, and their optional subject class is removed, since
+    /// `math.GT/0309136` and `math/0309136` name the same paper.
+    pub fn parse(raw: &str) -> Self {
+        static VERSION: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)v(\d+)$").unwrap());
+        let raw: String = raw.chars().filter(|c| !c.is_whitespace()).collect();
+        let (id, version) = match VERSION.captures(&raw) {
+            Some(caps) => (&raw[..caps.get(0).unwrap().start()], caps[1].parse().ok()),
+            None => (raw.as_str(), None),
+        };
+        let id = match id.split_once('/') {
+            Some((archive, number)) => {
+                let archive = archive.split('.').next().unwrap_or(archive);
+                format!("{}/{}", archive.to_lowercase(), number)
+            }
+            None => id.to_string(),
+        };
+        Self { id, version }
     }
+}
 
-    None
+/// Extract arXiv ID from reference text, without its version suffix.
+///
+/// See [`extract_arxiv`] for the accepted formats.
+pub fn extract_arxiv_id(text: &str) -> Option<String> {
+    extract_arxiv(text).map(|a| a.id)
 }
 
-/// Extract arXiv ID from reference text.
+/// Extract an arXiv identifier (and version, if any) from reference text.
 ///
 /// Handles formats like:
 /// - `arXiv:2301.12345`
 /// - `arXiv:2301.12345v1`
-/// - `arxiv.org/abs/2301.12345`
-/// - `arXiv:hep-th/9901001` (old format)
+/// - `arxiv.org/abs/2301.12345v2`, `arxiv.org/pdf/2301.12345v2.pdf`
+/// - `10.48550/arXiv.2301.12345` (arXiv-issued DOI)
+/// - `arXiv:hep-th/9901001`, `arXiv:math.GT/0309136` (old format, with or
+///   without subject class)
 ///
-/// Also handles IDs split across lines.
-pub fn extract_arxiv_id(text: &str) -> Option<String> {
-    // Fix IDs split across lines
+/// Also handles IDs split across lines or broken up by stray whitespace,
+/// e.g. `arXiv: 2103. 12345 v2` or `arXiv:hep-\nth/9901001`. The id is
+/// canonicalized by [`ArxivId::parse`].
+pub fn extract_arxiv(text: &str) -> Option<ArxivId> {
+    // Fix IDs split across lines or padded with stray whitespace
     static FIX1: Lazy<Regex> =
-        Lazy::new(|| Regex::new(r"(?i)(arXiv:\d{4}\.)\s*\n\s*(\d+)").unwrap());
-    let text_fixed = FIX1.replace_all(text, "$1$2");
+        Lazy::new(|| Regex::new(r"(?i)(arXiv[:\s]\s*\d{4})\s*\.\s*(\d{4,5})").unwrap());
+    let text_fixed = FIX1.replace_all(text, "$1.$2");
 
     static FIX2: Lazy<Regex> =
-        Lazy::new(|| Regex::new(r"(?i)(arxiv\.org/abs/\d{4}\.)\s*\n\s*(\d+)").unwrap());
+        Lazy::new(|| Regex::new(r"(?i)(arxiv\.org/(?:abs|pdf)/\d{4}\.)\s*\n\s*(\d+)").unwrap());
     let text_fixed = FIX2.replace_all(&text_fixed, "$1$2");
 
-    // New format: YYMM.NNNNN (with optional version)
-    static NEW_FMT: Lazy<Regex> =
-        Lazy::new(|| Regex::new(r"(?i)arXiv[:\s]+(\d{4}\.\d{4,5}(?:v\d+)?)").unwrap());
-    if let Some(caps) = NEW_FMT.captures(&text_fixed) {
-        return Some(caps.get(1).unwrap().as_str().to_string());
-    }
-
-    // URL format: arxiv.org/abs/YYMM.NNNNN
-    static URL_FMT: Lazy<Regex> =
-        Lazy::new(|| Regex::new(r"(?i)arxiv\.org/abs/(\d{4}\.\d{4,5}(?:v\d+)?)").unwrap());
-    if let Some(caps) = URL_FMT.captures(&text_fixed) {
-        return Some(caps.get(1).unwrap().as_str().to_string());
-    }
-
-    // Old format: category/YYMMNNN (e.g., hep-th/9901001)
-    static OLD_FMT: Lazy<Regex> =
-        Lazy::new(|| Regex::new(r"(?i)arXiv[:\s]+([a-z-]+/\d{7}(?:v\d+)?)").unwrap());
-    if let Some(caps) = OLD_FMT.captures(&text_fixed) {
-        return Some(caps.get(1).unwrap().as_str().to_string());
-    }
+    // Old-style archive hyphenated at a line end: `hep-\nth`
+    static FIX3: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?i)(arXiv[:\s]\s*[a-z]+-)\s+([a-z]+)").unwrap());
+    let text_fixed = FIX3.replace_all(&text_fixed, "$1$2");
 
-    // URL old format
-    static URL_OLD_FMT: Lazy<Regex> =
-        Lazy::new(|| Regex::new(r"(?i)arxiv\.org/abs/([a-z-]+/\d{7}(?:v\d+)?)").unwrap());
-    if let Some(caps) = URL_OLD_FMT.captures(&text_fixed) {
-        return Some(caps.get(1).unwrap().as_str().to_string());
-    }
+    // Old-style id with whitespace around the slash: `math.GT / 0309136`
+    static FIX4: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?i)(arXiv[:\s]\s*[a-z-]+(?:\.[a-z-]+)?)\s*/\s*(\d{7})").unwrap()
+    });
+    let text_fixed = FIX4.replace_all(&text_fixed, "$1/$2");
 
-    None
+    // Version suffix detached from the id: `2103.12345 v2`
+    static FIX5: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?i)(arXiv[:\s]\s*(?:\d{4}\.\d{4,5}|[a-z-]+(?:\.[a-z-]+)?/\d{7}))\s+(v\d+)\b")
+            .unwrap()
+    });
+    let text_fixed = FIX5.replace_all(&text_fixed, "$1$2");
+
+    static PATTERNS: Lazy<Vec<Regex>> = Lazy::new(|| {
+        [
+            // New format: YYMM.NNNNN (with optional version)
+            r"(?i)arXiv[:\s]+(\d{4}\.\d{4,5}(?:v\d+)?)",
+            // URL format: arxiv.org/abs/YYMM.NNNNN or arxiv.org/pdf/YYMM.NNNNN
+            r"(?i)arxiv\.org/(?:abs|pdf)/(\d{4}\.\d{4,5}(?:v\d+)?)",
+            // DataCite DOI arXiv assigns to every paper
+            r"(?i)10\.48550/arXiv\.(\d{4}\.\d{4,5}(?:v\d+)?)",
+            // Old format: archive[.SC]/YYMMNNN (e.g., hep-th/9901001, math.GT/0309136)
+            r"(?i)arXiv[:\s]+([a-z-]+(?:\.[a-z-]+)?/\d{7}(?:v\d+)?)",
+            // URL old format
+            r"(?i)arxiv\.org/(?:abs|pdf)/([a-z-]+(?:\.[a-z-]+)?/\d{7}(?:v\d+)?)",
+        ]
+        .iter()
+        .map(|p| Regex::new(p).unwrap())
+        .collect()
+    });
+    PATTERNS
+        .iter()
+        .find_map(|re| re.captures(&text_fixed))
+        .map(|caps| ArxivId::parse(caps.get(1).unwrap().as_str()))
 }
 
 /// Common words to skip when building search queries.
@@ -239,6 +301,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_extract_doi_prefix_forms_agree() {
+        for text in [
+            "https://doi.org/10.1109/SP.2019.00002.",
+            "http://dx.doi.org/10.1109/SP.2019.00002,",
+            "dx.doi.org/10.1109/SP.2019.00002",
+            "doi:10.1109/SP.2019.00002).",
+            "DOI: 10.1109/SP.2019.00002;",
+            "(10.1109/SP.2019.00002)",
+        ] {
+            assert_eq!(
+                extract_doi(text).as_deref(),
+                Some("10.1109/sp.2019.00002"),
+                "input: {text}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_extract_doi_sentence_end_punct() {
+        assert_eq!(
+            extract_doi("In Proc. IEEE S&P, 2019. https://doi.org/10.1145/3442381.3450048."),
+            Some("10.1145/3442381.3450048".into())
+        );
+        assert_eq!(
+            extract_doi("see doi:10.1016/0021-9681(87)90171-8), pp. 3"),
+            Some("10.1016/0021-9681(87)90171-8".into())
+        );
+    }
+
+    #[test]
+    fn test_extract_doi_prefers_last_candidate() {
+        // The venue's DOI comes first; the cited paper's own DOI closes it.
+        assert_eq!(
+            extract_doi(
+                "J. Smith. Deep nets. In Proc. of ICSE (10.1109/ICSE.2019), \
+                 pp. 1-10, 2019. doi:10.1109/ICSE.2019.00012."
+            ),
+            Some("10.1109/icse.2019.00012".into())
+        );
+        assert_eq!(
+            extract_doi(
+                "A. Doe. Reply to 10.1000/abc123. Nature 5, 2020. \
+                 https://dx.doi.org/10.1038/S41586-020-2649-2"
+            ),
+            Some("10.1038/s41586-020-2649-2".into())
+        );
+    }
+
+    #[test]
+    fn test_extract_doi_messy_line_wraps() {
+        // Wrapped right after the prefix slash
+        assert_eq!(
+            extract_doi(
+                "[12] K. Lee and M. Park. Fuzzing the kernel. In CCS '19.\nACM, 2019. https://doi.org/10.1145/\n3319535.3354200"
+            ),
+            Some("10.1145/3319535.3354200".into())
+        );
+        // Wrapped at a hyphen inside the suffix
+        assert_eq!(
+            extract_doi(
+                "R. Gupta. Sparse attention. Neural Computation 33(4), 2021.\ndoi: 10.1162/neco_a_01-\n376."
+            ),
+            Some("10.1162/neco_a_01-376".into())
+        );
+        // Wrapped mid-number in a URL
+        assert_eq!(
+            extract_doi(
+                "T. Nguyen. Graph learning. IEEE TPAMI, 2022. http://dx.doi.org/10.1109/TPAMI.2022.31\n45678"
+            ),
+            Some("10.1109/tpami.2022.3145678".into())
+        );
+    }
+
+    #[test]
+    fn test_extract_doi_closing_quote() {
+        assert_eq!(
+            extract_doi("Available: \u{201c}https://doi.org/10.1145/3442381.3450048\u{201d}"),
+            Some("10.1145/3442381.3450048".into())
+        );
+        assert_eq!(
+            extract_doi("url = \"https://doi.org/10.1145/3442381.3450048\","),
+            Some("10.1145/3442381.3450048".into())
+        );
+    }
+
     #[test]
     fn test_extract_doi_none() {
         assert_eq!(extract_doi("No DOI here"), None);
@@ -327,9 +475,61 @@ mod tests {
 
     #[test]
     fn test_extract_arxiv_with_version() {
+        assert_eq!(
+            extract_arxiv("arXiv:2301.12345v2"),
+            Some(ArxivId {
+                id: "2301.12345".into(),
+                version: Some(2),
+            })
+        );
         assert_eq!(
             extract_arxiv_id("arXiv:2301.12345v2"),
-            Some("2301.12345v2".into())
+            Some("2301.12345".into())
+        );
+    }
+
+    #[test]
+    fn test_extract_arxiv_versioned_abs_url() {
+        assert_eq!(
+            extract_arxiv("https://arxiv.org/abs/2101.00001v2"),
+            Some(ArxivId {
+                id: "2101.00001".into(),
+                version: Some(2),
+            })
+        );
+    }
+
+    #[test]
+    fn test_extract_arxiv_pdf_url() {
+        assert_eq!(
+            extract_arxiv("https://arxiv.org/pdf/2101.00001v3.pdf"),
+            Some(ArxivId {
+                id: "2101.00001".into(),
+                version: Some(3),
+            })
+        );
+        assert_eq!(
+            extract_arxiv_id("http://arxiv.org/pdf/2101.00001"),
+            Some("2101.00001".into())
+        );
+    }
+
+    #[test]
+    fn test_extract_arxiv_old_format_url() {
+        assert_eq!(
+            extract_arxiv("https://arxiv.org/abs/hep-th/9901001v1"),
+            Some(ArxivId {
+                id: "hep-th/9901001".into(),
+                version: Some(1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_extract_arxiv_doi() {
+        assert_eq!(
+            extract_arxiv_id("doi: 10.48550/arXiv.2101.00001"),
+            Some("2101.00001".into())
         );
     }
 
@@ -349,6 +549,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_extract_arxiv_old_format_subject_class() {
+        assert_eq!(
+            extract_arxiv("W. Thurston. Three-manifolds. arXiv:math.GT/0309136v2, 2003."),
+            Some(ArxivId {
+                id: "math/0309136".into(),
+                version: Some(2),
+            })
+        );
+        assert_eq!(
+            extract_arxiv_id("https://arxiv.org/abs/cond-mat.stat-mech/0501001"),
+            Some("cond-mat/0501001".into())
+        );
+        assert_eq!(
+            extract_arxiv_id("arxiv.org/abs/cs.LG/0101001"),
+            Some("cs/0101001".into())
+        );
+        assert_eq!(
+            extract_arxiv_id("arXiv:Hep-Th/9901001"),
+            Some("hep-th/9901001".into())
+        );
+    }
+
+    #[test]
+    fn test_extract_arxiv_whitespace_corrupted() {
+        assert_eq!(
+            extract_arxiv("preprint arXiv: 2103. 12345 v2, 2021."),
+            Some(ArxivId {
+                id: "2103.12345".into(),
+                version: Some(2),
+            })
+        );
+        assert_eq!(
+            extract_arxiv("CoRR, abs/2103.12345, arXiv 2103 .12345V3"),
+            Some(ArxivId {
+                id: "2103.12345".into(),
+                version: Some(3),
+            })
+        );
+        assert_eq!(
+            extract_arxiv_id("E. Witten. String theory dynamics. arXiv:hep-\nth/9503124"),
+            Some("hep-th/9503124".into())
+        );
+        assert_eq!(
+            extract_arxiv_id("arXiv:math.GT /\n0309136"),
+            Some("math/0309136".into())
+        );
+    }
+
+    #[test]
+    fn test_arxiv_id_parse_canonicalizes() {
+        assert_eq!(
+            ArxivId::parse("math.GT/0309136v1"),
+            ArxivId {
+                id: "math/0309136".into(),
+                version: Some(1),
+            }
+        );
+        assert_eq!(
+            ArxivId::parse("2103. 12345"),
+            ArxivId {
+                id: "2103.12345".into(),
+                version: None,
+            }
+        );
+    }
+
     #[test]
     fn test_extract_arxiv_split() {
         assert_eq!(
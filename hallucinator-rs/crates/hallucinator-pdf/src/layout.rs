@@ -0,0 +1,281 @@
+//! Reading-order reconstruction for multi-column pages.
+//!
+//! MuPDF returns text blocks in content-stream order, which on many
+//! two-column conference papers interleaves the columns: a line of the left
+//! column, then the right, and so on. That scrambles the references section
+//! badly enough that entries are split or never found. [`order_blocks`]
+//! detects a column gutter from the blocks' x-coordinates and emits the
+//! page column by column instead.
+
+/// A block of text on a page with its bounding box, in PDF points.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextBlock {
+    pub x0: f32,
+    pub y0: f32,
+    pub x1: f32,
+    pub y1: f32,
+    pub lines: Vec<String>,
+}
+
+/// Blocks wider than this fraction of the page span both columns (titles,
+/// abstracts, full-width figures) and are never assigned to a column.
+const MAX_COLUMN_WIDTH_FRACTION: f32 = 0.6;
+
+/// Blocks narrower than this fraction of the page (page numbers, stray
+/// labels) say nothing about the columns and may sit in the gutter.
+const MIN_COLUMN_WIDTH_FRACTION: f32 = 0.1;
+
+/// Blocks wholly within this fraction of the page height from the top or
+/// bottom edge are running headers and footers, often centered across the
+/// gutter.
+const MARGIN_BAND_FRACTION: f32 = 0.08;
+
+/// Smallest gap, in points, between the columns for a gutter to count.
+const MIN_GUTTER_WIDTH: f32 = 4.0;
+
+/// Find the x-coordinate of the gutter between two columns, if the page has
+/// them: every column-sized block must lie wholly on one side of it, with at
+/// least one block on each side. Very narrow blocks and blocks in the top and
+/// bottom margins are left out.
+pub fn find_gutter(blocks: &[TextBlock], page_width: f32, page_height: f32) -> Option<f32> {
+    if page_width <= 0.0 {
+        return None;
+    }
+    let mid = page_width / 2.0;
+    let (top, bottom) = (
+        page_height * MARGIN_BAND_FRACTION,
+        page_height * (1.0 - MARGIN_BAND_FRACTION),
+    );
+    let narrow = blocks.iter().filter(|b| {
+        let width = b.x1 - b.x0;
+        width <= page_width * MAX_COLUMN_WIDTH_FRACTION
+            && width >= page_width * MIN_COLUMN_WIDTH_FRACTION
+            && b.y1 > top
+            && b.y0 < bottom
+    });
+
+    let mut left_edge: Option<f32> = None;
+    let mut right_edge: Option<f32> = None;
+    for b in narrow {
+        if (b.x0 + b.x1) / 2.0 < mid {
+            left_edge = Some(left_edge.map_or(b.x1, |x| x.max(b.x1)));
+        } else {
+            right_edge = Some(right_edge.map_or(b.x0, |x| x.min(b.x0)));
+        }
+    }
+
+    let (left, right) = (left_edge?, right_edge?);
+    (right - left >= MIN_GUTTER_WIDTH).then_some((left + right) / 2.0)
+}
+
+/// Put a page's blocks in reading order.
+///
+/// Without a gutter the blocks keep their original order. With one, blocks
+/// that cross it split the page into horizontal bands; within each band the
+/// left column is read top to bottom, then the right.
+pub fn order_blocks(blocks: Vec<TextBlock>, page_width: f32, page_height: f32) -> Vec<TextBlock> {
+    let Some(gutter) = find_gutter(&blocks, page_width, page_height) else {
+        return blocks;
+    };
+
+    let mut blocks = blocks;
+    blocks.sort_by(|a, b| a.y0.total_cmp(&b.y0));
+
+    let mut ordered = Vec::with_capacity(blocks.len());
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+    for block in blocks {
+        if block.x0 < gutter && block.x1 > gutter {
+            ordered.append(&mut left);
+            ordered.append(&mut right);
+            ordered.push(block);
+        } else if block.x1 <= gutter {
+            left.push(block);
+        } else {
+            right.push(block);
+        }
+    }
+    ordered.append(&mut left);
+    ordered.append(&mut right);
+    ordered
+}
+
+/// Join ordered blocks into page text, one line per line.
+pub fn blocks_to_text(blocks: &[TextBlock]) -> String {
+    let mut text = String::new();
+    for block in blocks {
+        for line in &block.lines {
+            text.push_str(line);
+            text.push('\n');
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE_WIDTH: f32 = 612.0;
+    const PAGE_HEIGHT: f32 = 792.0;
+
+    fn block(x0: f32, y0: f32, x1: f32, lines: &[&str]) -> TextBlock {
+        TextBlock {
+            x0,
+            y0,
+            x1,
+            y1: y0 + 10.0 * lines.len() as f32,
+            lines: lines.iter().map(|l| l.to_string()).collect(),
+        }
+    }
+
+    /// A references page as MuPDF hands it back when the columns interleave:
+    /// each left-column entry is followed by the right-column entry at the
+    /// same height.
+    fn interleaved_references_page() -> Vec<TextBlock> {
+        vec![
+            block(54.0, 72.0, 296.0, &["References"]),
+            block(
+                316.0,
+                72.0,
+                558.0,
+                &[
+                    "[4] D. Park. Graph neural networks for",
+                    "code search. In ICSE, 2019.",
+                ],
+            ),
+            block(
+                54.0,
+                96.0,
+                296.0,
+                &[
+                    "[1] A. Smith and B. Jones. Deep learning",
+                    "for program repair. In PLDI, 2018.",
+                ],
+            ),
+            block(
+                316.0,
+                108.0,
+                558.0,
+                &[
+                    "[5] E. Chen. Fuzzing with learned",
+                    "grammars. In CCS, 2020.",
+                ],
+            ),
+            block(
+                54.0,
+                132.0,
+                296.0,
+                &["[2] C. Lee. Static analysis at scale.", "In OOPSLA, 2017."],
+            ),
+            block(
+                316.0,
+                144.0,
+                558.0,
+                &[
+                    "[6] F. Wu. Symbolic execution of",
+                    "smart contracts. In S&P, 2021.",
+                ],
+            ),
+            block(
+                54.0,
+                168.0,
+                296.0,
+                &["[3] D. Kim. Type inference for Python.", "In ECOOP, 2016."],
+            ),
+        ]
+    }
+
+    #[test]
+    fn two_columns_are_read_left_then_right() {
+        let blocks = order_blocks(interleaved_references_page(), PAGE_WIDTH, PAGE_HEIGHT);
+        let firsts: Vec<&str> = blocks.iter().map(|b| &b.lines[0][..3]).collect();
+        assert_eq!(firsts, ["Ref", "[1]", "[2]", "[3]", "[4]", "[5]", "[6]"]);
+    }
+
+    #[test]
+    fn deinterleaved_page_yields_all_references_in_order() {
+        let text = blocks_to_text(&order_blocks(
+            interleaved_references_page(),
+            PAGE_WIDTH,
+            PAGE_HEIGHT,
+        ));
+        let result = crate::PdfExtractor::new()
+            .extract_references_from_text(&text)
+            .unwrap();
+        let numbers: Vec<usize> = result
+            .references
+            .iter()
+            .map(|r| r.original_number)
+            .collect();
+        assert_eq!(numbers, [1, 2, 3, 4, 5, 6]);
+        assert!(
+            result.references[0]
+                .raw_citation
+                .contains("Deep learning for program repair")
+        );
+        assert!(
+            result.references[3]
+                .raw_citation
+                .contains("Graph neural networks for code search")
+        );
+    }
+
+    #[test]
+    fn full_width_blocks_split_the_page_into_bands() {
+        let blocks = vec![
+            block(54.0, 72.0, 558.0, &["Title spanning the page"]),
+            block(316.0, 100.0, 558.0, &["right top"]),
+            block(54.0, 100.0, 296.0, &["left top"]),
+            block(54.0, 300.0, 558.0, &["full-width figure caption"]),
+            block(316.0, 320.0, 558.0, &["right bottom"]),
+            block(54.0, 320.0, 296.0, &["left bottom"]),
+        ];
+        let text = blocks_to_text(&order_blocks(blocks, PAGE_WIDTH, PAGE_HEIGHT));
+        assert_eq!(
+            text,
+            "Title spanning the page\nleft top\nright top\n\
+             full-width figure caption\nleft bottom\nright bottom\n"
+        );
+    }
+
+    #[test]
+    fn centered_header_and_folio_do_not_hide_the_gutter() {
+        let mut blocks = interleaved_references_page();
+        blocks.insert(0, block(250.0, 36.0, 362.0, &["Conference 2024"]));
+        blocks.push(block(295.0, 750.0, 317.0, &["12"]));
+        assert_eq!(find_gutter(&blocks, PAGE_WIDTH, PAGE_HEIGHT), Some(306.0));
+
+        let blocks = order_blocks(blocks, PAGE_WIDTH, PAGE_HEIGHT);
+        let firsts: Vec<String> = blocks
+            .iter()
+            .map(|b| b.lines[0].chars().take(3).collect())
+            .collect();
+        assert_eq!(
+            firsts,
+            ["Con", "Ref", "[1]", "[2]", "[3]", "[4]", "[5]", "[6]", "12"]
+        );
+    }
+
+    #[test]
+    fn single_column_page_keeps_its_order() {
+        let blocks = vec![
+            block(72.0, 72.0, 540.0, &["First paragraph"]),
+            block(72.0, 200.0, 540.0, &["Second paragraph"]),
+            block(72.0, 120.0, 540.0, &["Out-of-order but full width"]),
+        ];
+        assert_eq!(
+            order_blocks(blocks.clone(), PAGE_WIDTH, PAGE_HEIGHT),
+            blocks
+        );
+    }
+
+    #[test]
+    fn overlapping_narrow_blocks_have_no_gutter() {
+        let blocks = vec![
+            block(54.0, 72.0, 320.0, &["left, running past the middle"]),
+            block(300.0, 100.0, 558.0, &["right, starting before it"]),
+        ];
+        assert_eq!(find_gutter(&blocks, PAGE_WIDTH, PAGE_HEIGHT), None);
+    }
+}
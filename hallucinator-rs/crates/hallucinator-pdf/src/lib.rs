@@ -10,7 +10,11 @@ pub mod config;
 #[cfg(feature = "pdf")]
 pub mod extract;
 pub mod extractor;
+pub mod footnotes;
 pub mod identifiers;
+pub mod layout;
+#[cfg(feature = "ocr")]
+pub mod ocr;
 pub mod section;
 pub mod text_processing;
 pub mod title;
@@ -22,6 +26,10 @@ pub use extractor::PdfExtractor;
 pub enum PdfError {
     #[error("failed to open PDF: {0}")]
     OpenError(String),
+    /// The PDF is password-protected and no password, or a wrong one, was
+    /// given (see [`ExtractionOptions::password`]).
+    #[error("PDF is encrypted; a password is needed to read it")]
+    Encrypted,
     #[error("failed to extract text: {0}")]
     ExtractionError(String),
     #[error("no references section found")]
@@ -37,11 +45,40 @@ pub struct Reference {
     pub title: Option<String>,
     pub authors: Vec<String>,
     pub doi: Option<String>,
+    /// Canonical arXiv id, without any version suffix.
     pub arxiv_id: Option<String>,
+    /// arXiv version the citation pointed at (`v2` → `2`), if given.
+    pub arxiv_version: Option<u32>,
     /// 1-based position in the original PDF reference list (before skip filtering).
     pub original_number: usize,
-    /// If set, this reference was skipped during extraction (e.g. "url_only", "short_title").
+    /// If set, this reference was skipped during extraction (e.g. "url_only", "short_title",
+    /// "no_title", "unresolved_footnote").
     pub skip_reason: Option<String>,
+    /// Check this reference by resolving its DOI alone, without a title
+    /// search. Set on URL-only references (datasets, software) kept by
+    /// [`ExtractionOptions::check_url_only`].
+    pub doi_only: bool,
+}
+
+/// Optional extraction behaviour, separate from the parsing heuristics in
+/// [`PdfParsingConfig`].
+#[derive(Debug, Clone, Default)]
+pub struct ExtractionOptions {
+    /// Keep URL-only references that carry a DOI (e.g. Zenodo datasets) and
+    /// mark them [`Reference::doi_only`] instead of skipping them. Off by
+    /// default, so `SkipStats::url_only` counts every URL-only reference.
+    pub check_url_only: bool,
+    /// Keep MuPDF's block order instead of detecting two-column pages and
+    /// reading them column by column. For layouts where detection misfires.
+    pub force_single_column: bool,
+    /// Password for encrypted PDFs. Without it (or with a wrong one) they
+    /// fail with [`PdfError::Encrypted`].
+    pub password: Option<String>,
+    /// Also read citations from footnotes (see [`footnotes`]), for papers
+    /// that cite in notes rather than, or as well as, a references section.
+    /// Works a note cites that the references section already lists are
+    /// not added twice.
+    pub include_footnotes: bool,
 }
 
 /// Statistics about references that were skipped during extraction.
@@ -50,6 +87,9 @@ pub struct SkipStats {
     pub url_only: usize,
     pub short_title: usize,
     pub no_title: usize,
+    /// Footnotes that could not be resolved to a title: back-references
+    /// ("Ibid.", "op. cit.") and notes in no recognisable citation form.
+    pub unresolved_footnote: usize,
     pub no_authors: usize,
     pub total_raw: usize,
 }
@@ -59,12 +99,67 @@ pub struct SkipStats {
 pub struct ExtractionResult {
     pub references: Vec<Reference>,
     pub skip_stats: SkipStats,
+    /// The PDF had next to no embedded text and was read by OCR (`ocr`
+    /// feature), so titles and authors may contain recognition errors.
+    pub ocr_used: bool,
+    /// OCR was needed but failed with this error; references come from the
+    /// sparse embedded text.
+    pub ocr_error: Option<String>,
+}
+
+/// Progress of a single extraction, reported between pipeline steps.
+///
+/// Emitted by [`PdfExtractor::extract_references_with_progress`] so that
+/// callers can show movement while a large PDF is being processed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExtractionProgress {
+    /// Text extraction finished `page` of `total_pages` (1-based).
+    ExtractingText { page: usize, total_pages: usize },
+    /// Searching the document text for the references section.
+    LocatingReferences,
+    /// Splitting the references section into individual entries.
+    Segmenting,
+    /// Parsed `current` of `total` raw reference entries (1-based).
+    Parsing { current: usize, total: usize },
+}
+
+impl ExtractionProgress {
+    /// Approximate overall completion in `0.0..=1.0`.
+    ///
+    /// Text extraction dominates the runtime, so it is weighted as the first
+    /// 80% of the bar; parsing fills the rest.
+    pub fn fraction(&self) -> f64 {
+        match *self {
+            Self::ExtractingText { page, total_pages } if total_pages > 0 => {
+                0.8 * (page as f64 / total_pages as f64)
+            }
+            Self::ExtractingText { .. } => 0.0,
+            Self::LocatingReferences => 0.8,
+            Self::Segmenting => 0.85,
+            Self::Parsing { current, total } if total > 0 => {
+                0.9 + 0.1 * (current as f64 / total as f64)
+            }
+            Self::Parsing { .. } => 0.9,
+        }
+    }
+
+    /// Short human-readable label, e.g. `"page 3/12"` or `"parsing 40/85"`.
+    pub fn label(&self) -> String {
+        match self {
+            Self::ExtractingText { page, total_pages } => {
+                format!("page {}/{}", page, total_pages)
+            }
+            Self::LocatingReferences => "locating references".to_string(),
+            Self::Segmenting => "segmenting".to_string(),
+            Self::Parsing { current, total } => format!("parsing {}/{}", current, total),
+        }
+    }
 }
 
 /// Extract references from a PDF file.
 ///
 /// Pipeline:
-/// 1. Extract text from the PDF using MuPDF
+/// 1. Extract text from the PDF using MuPDF, de-interleaving two-column pages
 /// 2. Locate the References/Bibliography section
 /// 3. Segment individual references
 /// 4. For each reference, extract DOI, arXiv ID, title, and authors
@@ -76,3 +171,15 @@ pub struct ExtractionResult {
 pub fn extract_references(pdf_path: &Path) -> Result<ExtractionResult, PdfError> {
     PdfExtractor::new().extract_references(pdf_path)
 }
+
+/// Extract references from a PDF file, reporting progress through `progress`.
+///
+/// See [`extract_references`] for the pipeline; this variant additionally
+/// emits an [`ExtractionProgress`] event after each page and pipeline step.
+#[cfg(feature = "pdf")]
+pub fn extract_references_with_progress(
+    pdf_path: &Path,
+    progress: &dyn Fn(ExtractionProgress),
+) -> Result<ExtractionResult, PdfError> {
+    PdfExtractor::new().extract_references_with_progress(pdf_path, progress)
+}
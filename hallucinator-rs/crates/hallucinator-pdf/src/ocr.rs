@@ -0,0 +1,104 @@
+//! OCR fallback for scanned PDFs, behind the `ocr` feature.
+//!
+//! Image-only PDFs give MuPDF next to no text, so the references section is
+//! never found. When that happens the trailing pages, where references live,
+//! are rendered to grayscale PNGs and read with the `tesseract` command-line
+//! tool, which has to be on `PATH`.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use mupdf::{Colorspace, Document, ImageFormat, Matrix};
+
+use crate::PdfError;
+
+/// Below this many non-whitespace characters per page on average, the PDF
+/// is taken to be scanned and OCR is attempted.
+pub const MIN_CHARS_PER_PAGE: usize = 200;
+
+/// Most pages OCRed, counted back from the end of the document.
+pub const MAX_OCR_PAGES: usize = 12;
+
+/// Rendering scale; 72 dpi × 4 ≈ 300 dpi, which Tesseract reads well.
+const RENDER_SCALE: f32 = 300.0 / 72.0;
+
+/// Whether MuPDF's per-page text is too sparse to hold a references section.
+pub fn text_is_sparse(pages: &[String]) -> bool {
+    if pages.is_empty() {
+        return false;
+    }
+    let chars: usize = pages
+        .iter()
+        .map(|p| p.chars().filter(|c| !c.is_whitespace()).count())
+        .sum();
+    chars / pages.len() < MIN_CHARS_PER_PAGE
+}
+
+/// OCR the trailing half of the document (at most [`MAX_OCR_PAGES`] pages),
+/// returning the text of each page in document order.
+pub fn ocr_trailing_pages(document: &Document) -> Result<Vec<String>, PdfError> {
+    let total = document
+        .page_count()
+        .map_err(|e| PdfError::ExtractionError(e.to_string()))?
+        .max(0) as usize;
+    let count = total.div_ceil(2).min(MAX_OCR_PAGES);
+
+    let matrix = Matrix::new_scale(RENDER_SCALE, RENDER_SCALE);
+    let gray = Colorspace::device_gray();
+    let mut pages = Vec::with_capacity(count);
+    for index in total - count..total {
+        let page = document
+            .load_page(index as i32)
+            .map_err(|e| PdfError::ExtractionError(e.to_string()))?;
+        let pixmap = page
+            .to_pixmap(&matrix, &gray, false, false)
+            .map_err(|e| PdfError::ExtractionError(e.to_string()))?;
+        let mut png = Vec::new();
+        pixmap
+            .write_to(&mut png, ImageFormat::PNG)
+            .map_err(|e| PdfError::ExtractionError(e.to_string()))?;
+        pages.push(tesseract(&png)?);
+    }
+    Ok(pages)
+}
+
+/// Run `tesseract stdin stdout` on a PNG image.
+fn tesseract(png: &[u8]) -> Result<String, PdfError> {
+    let ocr_error = |e: &dyn std::fmt::Display| {
+        PdfError::ExtractionError(format!("OCR with tesseract failed: {}", e))
+    };
+
+    let mut child = Command::new("tesseract")
+        .args(["stdin", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| ocr_error(&e))?;
+    child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(png)
+        .map_err(|e| ocr_error(&e))?;
+    let output = child.wait_with_output().map_err(|e| ocr_error(&e))?;
+    if !output.status.success() {
+        return Err(ocr_error(&output.status));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sparse_text_triggers_ocr() {
+        let scanned = vec!["12\n".to_string(), String::new(), "\n".to_string()];
+        assert!(text_is_sparse(&scanned));
+
+        let page = "word ".repeat(MIN_CHARS_PER_PAGE);
+        assert!(!text_is_sparse(&[page.clone(), page]));
+        assert!(!text_is_sparse(&[]));
+    }
+}
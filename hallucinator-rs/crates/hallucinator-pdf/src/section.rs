@@ -3,11 +3,43 @@ use regex::Regex;
 
 use crate::config::PdfParsingConfig;
 
+/// Headings that open a references section, matched case-insensitively on a
+/// line of their own (optionally numbered, e.g. "7 References" or
+/// "VII. REFERENCES"). Extend or replace with
+/// [`PdfParsingConfigBuilder::add_section_heading`](crate::PdfParsingConfigBuilder::add_section_heading).
+pub const SECTION_HEADINGS: &[&str] = &[
+    "References",
+    "Reference List",
+    "List of References",
+    "Bibliography",
+    "Works Cited",
+    "Literature Cited",
+    "Cited Literature",
+    "References and Notes",
+    // French, German, Spanish, Portuguese, Italian
+    "Références",
+    "Bibliographie",
+    "Literatur",
+    "Literaturverzeichnis",
+    "Referencias",
+    "Bibliografía",
+    "Referências",
+    "Bibliografia",
+    "Riferimenti bibliografici",
+    // Russian, Chinese/Japanese, Korean
+    "Литература",
+    "Список литературы",
+    "参考文献",
+    "引用文献",
+    "참고문헌",
+];
+
 /// Locate the references section in the document text.
 ///
-/// Searches for common reference section headers (References, Bibliography, Works Cited)
-/// and returns the text between the header and any end markers (Appendix, Acknowledgments, etc.).
-/// Falls back to the last 30% of the document if no header is found.
+/// Searches for a references heading (see [`SECTION_HEADINGS`]) and returns the
+/// text between the heading and any end marker (Appendix, Acknowledgments, etc.).
+/// Without a heading, falls back to the last dense block of citation-shaped
+/// lines in the final 30% of the document; returns `None` if there is none.
 pub fn find_references_section(text: &str) -> Option<String> {
     find_references_section_with_config(text, &PdfParsingConfig::default())
 }
@@ -17,51 +49,136 @@ pub(crate) fn find_references_section_with_config(
     text: &str,
     config: &PdfParsingConfig,
 ) -> Option<String> {
-    static HEADER_RE: Lazy<Regex> = Lazy::new(|| {
-        Regex::new(r"(?i)\n\s*(?:References|Bibliography|Works\s+Cited)\s*\n").unwrap()
+    static END_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?i)\n\s*(?:Appendix|Acknowledgments|Acknowledgements|Supplementary|Ethics\s+Statement|Ethical\s+Considerations|Broader\s+Impact|Paper\s+Checklist|Checklist)")
+            .unwrap()
     });
+    let end_re = config.section_end_re.as_ref().unwrap_or(&END_RE);
+    let until_end_marker = |rest: &str| -> Option<String> {
+        let ref_end = end_re.find(rest).map_or(rest.len(), |m| m.start());
+        let section = &rest[..ref_end];
+        (!section.trim().is_empty()).then(|| section.to_string())
+    };
 
-    let header_re = config.section_header_re.as_ref().unwrap_or(&HEADER_RE);
-
-    if let Some(m) = header_re.find(text) {
-        let ref_start = m.end();
-        let rest = &text[ref_start..];
+    let header_start = match &config.section_header_re {
+        Some(re) => re.find(text).map(|m| m.end()),
+        None => heading_regex(config).find(text).map(|m| m.end()),
+    };
+    if let Some(section) = header_start.and_then(|start| until_end_marker(&text[start..])) {
+        return Some(section);
+    }
 
-        static END_RE: Lazy<Regex> = Lazy::new(|| {
-            Regex::new(r"(?i)\n\s*(?:Appendix|Acknowledgments|Acknowledgements|Supplementary|Ethics\s+Statement|Ethical\s+Considerations|Broader\s+Impact|Paper\s+Checklist|Checklist)")
-                .unwrap()
-        });
+    // Fallback: the last block of citation-shaped lines, provided it reaches
+    // into the tail of the document (by default the last 30%).
+    let cutoff = (text.len() as f64 * config.fallback_fraction) as usize;
+    let (start, end) = last_citation_block(text)?;
+    if end < cutoff {
+        return None;
+    }
+    until_end_marker(&text[start..])
+}
 
-        let end_re = config.section_end_re.as_ref().unwrap_or(&END_RE);
+/// Build the heading regex from the configured [`SECTION_HEADINGS`].
+fn heading_regex(config: &PdfParsingConfig) -> std::borrow::Cow<'static, Regex> {
+    fn build(headings: &[String]) -> Regex {
+        let alternatives: Vec<String> = headings
+            .iter()
+            .map(|h| {
+                h.split_whitespace()
+                    .map(regex::escape)
+                    .collect::<Vec<_>>()
+                    .join(r"\s+")
+            })
+            .collect();
+        // Own line, optional section number ("7", "7.", "VII."), optional colon
+        Regex::new(&format!(
+            r"(?im)^[ \t]*(?:(?:\d{{1,2}}|[IVXL]{{1,5}})\.?[ \t]+)?(?:{})[ \t]*:?[ \t]*$",
+            alternatives.join("|")
+        ))
+        .unwrap()
+    }
 
-        let ref_end = if let Some(end_m) = end_re.find(rest) {
-            end_m.start()
-        } else {
-            rest.len()
-        };
+    static DEFAULT_RE: Lazy<Regex> = Lazy::new(|| {
+        build(
+            &SECTION_HEADINGS
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>(),
+        )
+    });
 
-        let section = &rest[..ref_end];
-        if !section.trim().is_empty() {
-            return Some(section.to_string());
+    match config.section_headings {
+        crate::ListOverride::Default => std::borrow::Cow::Borrowed(&DEFAULT_RE),
+        ref headings => {
+            let defaults: Vec<String> = SECTION_HEADINGS.iter().map(|s| s.to_string()).collect();
+            std::borrow::Cow::Owned(build(&headings.resolve(&defaults)))
         }
     }
+}
 
-    // Fallback: last N% of document (default 30%, i.e. fraction = 0.7)
-    let cutoff = (text.len() as f64 * config.fallback_fraction) as usize;
-    // Don't split in the middle of a UTF-8 codepoint
-    let cutoff = text
-        .char_indices()
-        .map(|(i, _)| i)
-        .find(|&i| i >= cutoff)
-        .unwrap_or(cutoff);
-    Some(text[cutoff..].to_string())
+/// Whether a line looks like (the start of) a bibliography entry: a `[n]` or
+/// `n.` marker, or a year alongside author initials or publication details.
+fn is_citation_line(line: &str) -> bool {
+    static MARKER_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"^\s*(?:\[\d{1,3}\]|\d{1,3}\.\s)").unwrap());
+    static YEAR_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b(?:19|20)\d{2}[a-z]?\b").unwrap());
+    static DETAIL_RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(?:\b[A-Z]\.\s|\bet al\b|\bProc\b|\bIn\s|\bpp\.|\bvol\.|\bdoi\b|arXiv|Journal|Conference)")
+            .unwrap()
+    });
+    MARKER_RE.is_match(line) || (YEAR_RE.is_match(line) && DETAIL_RE.is_match(line))
+}
+
+/// Byte range of the last dense run of citation-shaped lines in `text`.
+///
+/// Entries wrap over several lines, so a run tolerates a few non-citation lines
+/// in a row; it counts as a bibliography once it has at least
+/// [`MIN_BLOCK_CITATIONS`] citation lines.
+fn last_citation_block(text: &str) -> Option<(usize, usize)> {
+    const MIN_BLOCK_CITATIONS: usize = 3;
+    const MAX_GAP_LINES: usize = 4;
+
+    let mut best = None;
+    // (start, end of last citation line, citation lines) of the current run
+    let mut current: Option<(usize, usize, usize)> = None;
+    let mut gap = 0;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let (start, end) = (offset, offset + line.len());
+        offset = end;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if is_citation_line(line) {
+            gap = 0;
+            current = Some(match current {
+                Some((s, _, n)) => (s, end, n + 1),
+                None => (start, end, 1),
+            });
+            continue;
+        }
+        gap += 1;
+        if gap > MAX_GAP_LINES
+            && let Some((s, e, n)) = current.take()
+            && n >= MIN_BLOCK_CITATIONS
+        {
+            best = Some((s, e));
+        }
+    }
+    if let Some((s, e, n)) = current
+        && n >= MIN_BLOCK_CITATIONS
+    {
+        best = Some((s, e));
+    }
+    best
 }
 
 /// Split a references section into individual reference strings.
 ///
 /// Tries multiple segmentation strategies in order:
-/// 1. IEEE style: `[1]`, `[2]`, etc.
-/// 2. Numbered list: `1.`, `2.`, etc. (sequential starting from 1)
+/// 1. Numbered markers: `[1]`, `1.` or `(1)`, whichever style forms the
+///    longest ascending sequence (see [`try_numbered_markers`])
+/// 2. Hanging indent: entries start flush left, continuation lines are indented
 /// 3. AAAI/ACM author-year with semicolons
 /// 4. Springer/Nature: lines starting with uppercase + `(YYYY)` pattern
 /// 5. Fallback: double-newline splitting
@@ -74,13 +191,13 @@ pub(crate) fn segment_references_with_config(
     ref_text: &str,
     config: &PdfParsingConfig,
 ) -> Vec<String> {
-    // Strategy 1: IEEE style [1], [2], ...
-    if let Some(refs) = try_ieee_with_config(ref_text, config) {
+    // Strategy 1: numbered markers [1], 1., (1)
+    if let Some(refs) = try_numbered_markers(ref_text, config) {
         return refs;
     }
 
-    // Strategy 2: Numbered list 1., 2., ...
-    if let Some(refs) = try_numbered_with_config(ref_text, config) {
+    // Strategy 2: author-year with hanging indent
+    if let Some(refs) = try_hanging_indent(ref_text) {
         return refs;
     }
 
@@ -103,24 +220,78 @@ pub(crate) fn segment_references_with_config(
     fallback_double_newline_with_config(ref_text, config)
 }
 
-fn try_ieee_with_config(ref_text: &str, config: &PdfParsingConfig) -> Option<Vec<String>> {
-    static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\n\s*\[(\d+)\]\s*").unwrap());
+/// Split a plain reference list (e.g. pasted into a `.txt` file) into
+/// citations: one per block of lines separated by a blank line. A list
+/// without any blank lines is taken as one citation per line.
+pub fn segment_citation_list(text: &str) -> Vec<String> {
+    static BLANK_LINE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\n[ \t]*\n").unwrap());
+
+    let text = text.replace("\r\n", "\n");
+    let blocks: Vec<&str> = BLANK_LINE_RE
+        .split(&text)
+        .map(str::trim)
+        .filter(|b| !b.is_empty())
+        .collect();
+    let citations: Vec<&str> = if blocks.len() == 1 {
+        blocks[0]
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .collect()
+    } else {
+        blocks
+    };
+    citations.into_iter().map(str::to_string).collect()
+}
 
-    let re = config.ieee_segment_re.as_ref().unwrap_or(&RE);
-    let matches: Vec<_> = re.find_iter(ref_text).collect();
-    if matches.len() < 3 {
-        return None;
+/// A candidate entry marker: where it sits in the text and its number.
+#[derive(Debug, Clone, Copy)]
+struct Marker {
+    start: usize,
+    end: usize,
+    number: usize,
+}
+
+/// Largest jump between consecutive entry numbers still taken as the next
+/// entry, so a marker or two lost at a page break doesn't end the list.
+const MAX_NUMBER_GAP: usize = 3;
+
+/// Split at numbered entry markers, using the style that dominates the
+/// section: `[1]` (IEEE), `1.` or `(1)`.
+///
+/// Only markers that continue an ascending sequence count as entry starts;
+/// anything else that looks like a marker (a `[12]` or `2019.` at the start
+/// of a wrapped line) stays part of the entry it appears in. The `1.` and
+/// `(1)` styles must start at 1, since years and page numbers look alike.
+fn try_numbered_markers(ref_text: &str, config: &PdfParsingConfig) -> Option<Vec<String>> {
+    static BRACKET_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?:^|\n)\s*\[(\d+)\]\s*").unwrap());
+    static DOT_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?m)(?:^|\n)\s*(\d+)\.\s+").unwrap());
+    static PAREN_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?:^|\n)\s*\((\d{1,3})\)\s+").unwrap());
+
+    let styles: [(&Regex, bool); 3] = [
+        (
+            config.ieee_segment_re.as_ref().unwrap_or(&BRACKET_RE),
+            false,
+        ),
+        (config.numbered_segment_re.as_ref().unwrap_or(&DOT_RE), true),
+        (&PAREN_RE, true),
+    ];
+
+    // Earlier styles win ties
+    let mut best: Option<Vec<Marker>> = None;
+    for (re, from_one) in styles {
+        let chain = sequential_markers(&find_markers(ref_text, re), from_one);
+        if chain.len() >= 3 && best.as_ref().is_none_or(|b| chain.len() > b.len()) {
+            best = Some(chain);
+        }
     }
+    let markers = best?;
 
     let mut refs = Vec::new();
-    for i in 0..matches.len() {
-        let start = matches[i].end();
-        let end = if i + 1 < matches.len() {
-            matches[i + 1].start()
-        } else {
-            ref_text.len()
-        };
-        let content = ref_text[start..end].trim();
+    for (i, marker) in markers.iter().enumerate() {
+        let end = markers.get(i + 1).map_or(ref_text.len(), |m| m.start);
+        let content = ref_text[marker.end..end].trim();
         if !content.is_empty() {
             refs.push(content.to_string());
         }
@@ -128,45 +299,60 @@ fn try_ieee_with_config(ref_text: &str, config: &PdfParsingConfig) -> Option<Vec
     Some(refs)
 }
 
-fn try_numbered_with_config(ref_text: &str, config: &PdfParsingConfig) -> Option<Vec<String>> {
-    static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?m)(?:^|\n)\s*(\d+)\.\s+").unwrap());
-
-    let re = config.numbered_segment_re.as_ref().unwrap_or(&RE);
-    let matches: Vec<_> = re.find_iter(ref_text).collect();
-    if matches.len() < 3 {
-        return None;
-    }
-
-    // Extract the captured numbers to check sequentiality
-    // When using a custom regex, we still need capture group 1 for numbers
-    let caps: Vec<_> = re.captures_iter(ref_text).collect();
-    let first_nums: Vec<i64> = caps
-        .iter()
-        .take(5)
-        .filter_map(|c| c.get(1)?.as_str().parse().ok())
-        .collect();
+/// Every match of `re`, with the entry number from capture group 1.
+fn find_markers(ref_text: &str, re: &Regex) -> Vec<Marker> {
+    re.captures_iter(ref_text)
+        .filter_map(|caps| {
+            let whole = caps.get(0)?;
+            Some(Marker {
+                start: whole.start(),
+                end: whole.end(),
+                number: caps.get(1)?.as_str().parse().ok()?,
+            })
+        })
+        .collect()
+}
 
-    if first_nums.is_empty() || first_nums[0] != 1 {
-        return None;
+/// The markers that form the entry sequence: starting at the first `1` (or
+/// the first marker, unless `from_one`), each next entry's number is a
+/// little above the previous one. Out-of-sequence markers are dropped.
+fn sequential_markers(markers: &[Marker], from_one: bool) -> Vec<Marker> {
+    let start = match markers.iter().position(|m| m.number == 1) {
+        Some(i) => i,
+        None if !from_one && !markers.is_empty() => 0,
+        None => return Vec::new(),
+    };
+    let mut chain = vec![markers[start]];
+    for &marker in &markers[start + 1..] {
+        let last = chain[chain.len() - 1].number;
+        if marker.number > last && marker.number <= last + MAX_NUMBER_GAP {
+            chain.push(marker);
+        }
     }
+    chain
+}
 
-    let is_sequential = first_nums.windows(2).all(|w| w[1] == w[0] + 1);
-
-    if !is_sequential {
+/// Author-year lists typeset with a hanging indent: each entry's first line
+/// is flush left and its continuation lines are indented. Only applies when
+/// the extracted text kept the indentation.
+fn try_hanging_indent(ref_text: &str) -> Option<Vec<String>> {
+    let lines: Vec<&str> = ref_text.lines().filter(|l| !l.trim().is_empty()).collect();
+    let is_indented = |l: &&str| l.starts_with([' ', '\t']);
+    let starts = lines.iter().filter(|l| !is_indented(l)).count();
+    let continuations = lines.len() - starts;
+    if starts < 3 || continuations * 2 < starts || lines.first().is_none_or(is_indented) {
         return None;
     }
 
-    let mut refs = Vec::new();
-    for i in 0..matches.len() {
-        let start = matches[i].end();
-        let end = if i + 1 < matches.len() {
-            matches[i + 1].start()
+    let mut refs: Vec<String> = Vec::new();
+    for line in lines {
+        if is_indented(&line) {
+            if let Some(last) = refs.last_mut() {
+                last.push('\n');
+                last.push_str(line.trim());
+            }
         } else {
-            ref_text.len()
-        };
-        let content = ref_text[start..end].trim();
-        if !content.is_empty() {
-            refs.push(content.to_string());
+            refs.push(line.trim().to_string());
         }
     }
     Some(refs)
@@ -384,6 +570,22 @@ mod tests {
         assert!(!section.contains("Extra stuff"));
     }
 
+    #[test]
+    fn test_segment_citation_list_blank_lines() {
+        let text = "Smith, J. A first paper title.\nIn Proc. X, 2020.\n\n  \nDoe, A. A second paper title. 2021.\r\n\r\nRoe, B. Third. 2022.\n";
+        let refs = segment_citation_list(text);
+        assert_eq!(refs.len(), 3);
+        assert_eq!(refs[0], "Smith, J. A first paper title.\nIn Proc. X, 2020.");
+        assert_eq!(refs[2], "Roe, B. Third. 2022.");
+    }
+
+    #[test]
+    fn test_segment_citation_list_one_per_line() {
+        let text = "Smith, J. A first paper title. 2020.\nDoe, A. A second paper title. 2021.\n";
+        assert_eq!(segment_citation_list(text).len(), 2);
+        assert!(segment_citation_list(" \n\n").is_empty());
+    }
+
     #[test]
     fn test_segment_ieee() {
         let text = "\n[1] First reference text here.\n[2] Second reference text here.\n[3] Third reference.\n";
@@ -401,6 +603,47 @@ mod tests {
         assert!(refs[0].starts_with("First"));
     }
 
+    #[test]
+    fn test_segment_parenthesized() {
+        let text =
+            "(1) First ref content here.\n(2) Second ref content here.\n(3) Third ref content.\n";
+        let refs = segment_references(text);
+        assert_eq!(refs.len(), 3);
+        assert!(refs[1].starts_with("Second"));
+    }
+
+    #[test]
+    fn test_segment_rejoins_out_of_sequence_marker() {
+        let text = "[1] First reference text.\n[2] A title that wraps onto\n[12] a marker-like line.\n[3] Third reference.\n";
+        let refs = segment_references(text);
+        assert_eq!(refs.len(), 3);
+        assert!(refs[1].ends_with("a marker-like line."));
+    }
+
+    #[test]
+    fn test_segment_tolerates_a_lost_marker() {
+        let text = "[1] First.\n[2] Second.\n[4] Fourth, [3] lost at a page break.\n[5] Fifth.\n";
+        let refs = segment_references(text);
+        assert_eq!(refs.len(), 4);
+        assert!(refs[3].starts_with("Fifth"));
+    }
+
+    #[test]
+    fn test_segment_numbered_ignores_year_lines() {
+        let text = "1. Smith J. First title here. In: Proc. ACL;\n2023. p. 1-9.\n2. Lee K. Second title here.\n3. Kim L. Third title here.\n";
+        let refs = segment_references(text);
+        assert_eq!(refs.len(), 3);
+        assert!(refs[0].ends_with("2023. p. 1-9."));
+    }
+
+    #[test]
+    fn test_segment_hanging_indent() {
+        let text = "Alpha, A. 2023. First title\n    continues here.\nBeta, B. 2022. Second title\n    continues here.\nGamma, C. 2021. Third title.\n";
+        let refs = segment_references(text);
+        assert_eq!(refs.len(), 3);
+        assert_eq!(refs[0], "Alpha, A. 2023. First title\ncontinues here.");
+    }
+
     #[test]
     fn test_segment_fallback() {
         let text = "This is a long enough reference paragraph one.\n\nThis is a long enough reference paragraph two.\n\nShort.\n\nThis is a long enough reference paragraph three.";
@@ -446,11 +689,64 @@ mod tests {
             .fallback_fraction(0.5)
             .build()
             .unwrap();
-        // No header → fallback returns last 50%
-        let text = "AAAA BBBB CCCC DDDD";
+        // No header: the citation block must reach past the halfway point
+        let refs = "Smith, J. 2020. A first paper. In Proc. ACL.\nJones, K. 2021. A second paper. In Proc. EMNLP.\nLee, M. 2022. A third paper. Journal of Things.\n";
+        let early = format!("Intro.\n{}{}", refs, "Closing prose. ".repeat(20));
+        assert!(find_references_section_with_config(&early, &config).is_none());
+        let late = format!("{}\n{}", "Body prose. ".repeat(20), refs);
+        let section = find_references_section_with_config(&late, &config).unwrap();
+        assert!(section.starts_with("Smith, J. 2020."));
+    }
+
+    #[test]
+    fn test_fallback_takes_last_citation_block() {
+        let text = "Intro.\n\
+[1] A. Smith. Cited in the intro. In Proc. X, 2019.\n\
+Prose one.\nProse two.\nProse three.\nProse four.\nProse five.\n\
+[1] A. Smith. First reference. In Proc. X, 2019.\n\
+[2] B. Jones. Second reference,\nwrapped onto a second line. In Proc. Y, 2020.\n\
+[3] C. Lee. Third reference. Journal of Z, 2021.\n";
+        let section = find_references_section(text).unwrap();
+        assert!(section.starts_with("[1] A. Smith. First reference."));
+        assert!(section.contains("wrapped onto a second line"));
+    }
+
+    #[test]
+    fn test_fallback_none_without_citations() {
+        let text = "Just prose.\n".repeat(50);
+        assert!(find_references_section(&text).is_none());
+    }
+
+    #[test]
+    fn test_find_section_numbered_and_uppercase_headings() {
+        for heading in [
+            "7 References",
+            "VII. REFERENCES",
+            "8. Literature Cited",
+            "References:",
+        ] {
+            let text = format!("Body.\n\n{}\n[1] Ref one.\n", heading);
+            let section = find_references_section(&text)
+                .unwrap_or_else(|| panic!("no section for {:?}", heading));
+            assert!(section.contains("[1] Ref one."), "heading {:?}", heading);
+        }
+        // A heading word inside running text is not a heading
+        let text = "Body.\nSee the references below for details.\n";
+        assert!(find_references_section(text).is_none());
+    }
+
+    #[test]
+    fn test_find_section_added_heading() {
+        let config = crate::PdfParsingConfigBuilder::new()
+            .add_section_heading("Kaynakça".to_string())
+            .build()
+            .unwrap();
+        let text = "Body.\n\nKAYNAKÇA\n\nRef A.\n";
         let section = find_references_section_with_config(text, &config).unwrap();
-        // Should get roughly the last half
-        assert!(section.len() <= text.len() / 2 + 2);
+        assert!(section.contains("Ref A."));
+        // Defaults still apply when extending
+        let text = "Body.\n\nReferences\n\nRef B.\n";
+        assert!(find_references_section_with_config(text, &config).is_some());
     }
 
     #[test]
@@ -0,0 +1,52 @@
+//! Reference segmentation across list styles.
+//!
+//! Each file in `tests/fixtures/styles/` is a short paper whose bibliography
+//! lists the same four references in a different style: `[1]`, `1.`, `(1)`,
+//! and author-year with a hanging indent. `bracketed.txt` also wraps a title
+//! onto a line that starts with `[12]`, which must not start a new entry.
+
+use std::path::Path;
+
+use hallucinator_pdf::PdfExtractor;
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/styles");
+
+const TITLES: [&str; 4] = [
+    "Detecting fabricated citations in scientific papers",
+    "Large language models and the bibliography",
+    "Reference extraction from PDF documents at scale",
+    "Citation graphs for plagiarism detection in theses",
+];
+
+#[test]
+fn every_style_yields_the_four_references() {
+    let mut names: Vec<_> = std::fs::read_dir(FIXTURES)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    assert_eq!(names.len(), 4, "fixtures missing: {:?}", names);
+
+    for name in names {
+        let text = std::fs::read_to_string(Path::new(FIXTURES).join(&name)).unwrap();
+        let result = PdfExtractor::new()
+            .extract_references_from_text(&text)
+            .unwrap_or_else(|e| panic!("{}: {}", name, e));
+        assert_eq!(result.skip_stats.total_raw, 4, "{}: {:?}", name, result);
+
+        let titles: Vec<_> = result
+            .references
+            .iter()
+            .map(|r| r.title.as_deref().unwrap_or(""))
+            .collect();
+        for (title, expected) in titles.iter().zip(TITLES) {
+            assert!(
+                title.starts_with(expected),
+                "{}: expected {:?}, got {:?}",
+                name,
+                expected,
+                titles
+            );
+        }
+    }
+}
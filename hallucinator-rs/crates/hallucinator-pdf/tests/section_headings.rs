@@ -0,0 +1,95 @@
+//! References-section detection across heading variants.
+//!
+//! Each file in `tests/fixtures/sections/` is the text of a short paper whose
+//! bibliography opens with the same three references, introduced by a different
+//! heading (or none, for the citation-block fallback).
+
+use std::path::Path;
+
+use hallucinator_pdf::section::find_references_section;
+use hallucinator_pdf::{PdfExtractor, PdfParsingConfigBuilder};
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/sections");
+
+fn fixture(name: &str) -> String {
+    std::fs::read_to_string(Path::new(FIXTURES).join(name))
+        .unwrap_or_else(|e| panic!("cannot read fixture {}: {}", name, e))
+}
+
+#[test]
+fn every_heading_variant_yields_the_bibliography() {
+    let mut names: Vec<_> = std::fs::read_dir(FIXTURES)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    assert!(names.len() >= 8, "fixtures missing: {:?}", names);
+
+    for name in names {
+        let text = fixture(&name);
+        let section = find_references_section(&text)
+            .unwrap_or_else(|| panic!("{}: no references section", name));
+        assert!(
+            !section.contains("Prior work"),
+            "{}: section starts in the body",
+            name
+        );
+        assert!(
+            !section.contains("Additional experiments"),
+            "{}: section runs into the appendix",
+            name
+        );
+
+        let result = PdfExtractor::new()
+            .extract_references_from_text(&text)
+            .unwrap_or_else(|e| panic!("{}: {}", name, e));
+        let titles: Vec<_> = result
+            .references
+            .iter()
+            .filter_map(|r| r.title.as_deref())
+            .collect();
+        assert!(titles.len() >= 3, "{}: got {:?}", name, titles);
+        assert!(
+            titles[0].starts_with("Detecting fabricated citations"),
+            "{}: got {:?}",
+            name,
+            titles
+        );
+    }
+}
+
+#[test]
+fn prose_without_a_bibliography_is_not_a_references_section() {
+    let text = fixture("no_heading.txt");
+    let body_only = &text[..text.find("Alpha, A.").unwrap()];
+    assert!(find_references_section(body_only).is_none());
+    assert!(matches!(
+        PdfExtractor::new().extract_references_from_text(body_only),
+        Err(hallucinator_pdf::PdfError::NoReferencesSection)
+    ));
+}
+
+#[test]
+fn added_heading_is_recognised_alongside_the_defaults() {
+    // A long appendix keeps the citation-block fallback from finding the
+    // bibliography, so only the heading can.
+    let text = fixture("german.txt").replace("Literaturverzeichnis", "7 Quellenangaben")
+        + &"Further tables and figures follow in this appendix.\n".repeat(40);
+    assert!(PdfExtractor::new().find_references_section(&text).is_none());
+
+    let config = PdfParsingConfigBuilder::new()
+        .add_section_heading("Quellenangaben".to_string())
+        .build()
+        .unwrap();
+    let extractor = PdfExtractor::with_config(config);
+    let section = extractor.find_references_section(&text).unwrap();
+    assert!(section.trim_start().starts_with("Alpha, A."));
+    assert!(!section.contains("Additional experiments"));
+
+    // The built-in headings still apply
+    assert!(
+        extractor
+            .find_references_section(&fixture("spanish.txt"))
+            .is_some_and(|s| s.trim_start().starts_with("Alpha, A."))
+    );
+}
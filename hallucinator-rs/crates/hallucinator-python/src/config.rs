@@ -1,7 +1,8 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
-use pyo3::exceptions::PyRuntimeError;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
 
 use hallucinator_core::Config;
@@ -20,16 +21,42 @@ use hallucinator_core::Config;
 pub struct PyValidatorConfig {
     pub(crate) openalex_key: Option<String>,
     pub(crate) s2_api_key: Option<String>,
+    pub(crate) ncbi_api_key: Option<String>,
+    pub(crate) core_api_key: Option<String>,
     pub(crate) dblp_offline_path: Option<String>,
+    pub(crate) dblp_max_age_days: u64,
     pub(crate) acl_offline_path: Option<String>,
+    pub(crate) local_bib_path: Option<String>,
+    pub(crate) retraction_db_path: Option<String>,
+    pub(crate) retraction_max_age_days: u64,
     pub(crate) cache_path: Option<String>,
+    pub(crate) cache_max_entries: usize,
     pub(crate) num_workers: usize,
+    pub(crate) dedup: bool,
+    pub(crate) adaptive_concurrency: bool,
     pub(crate) max_rate_limit_retries: u32,
+    pub(crate) max_retry_passes: usize,
+    pub(crate) max_references: Option<usize>,
+    pub(crate) run_deadline: Option<std::time::Duration>,
     pub(crate) db_timeout_secs: u64,
     pub(crate) db_timeout_short_secs: u64,
+    pub(crate) db_timeouts: HashMap<String, u64>,
     pub(crate) disabled_dbs: Vec<String>,
+    pub(crate) only_dbs: Vec<String>,
+    pub(crate) per_db_threshold: HashMap<String, f64>,
     pub(crate) check_openalex_authors: bool,
+    pub(crate) author_match_min_overlap: f32,
+    pub(crate) require_first_author_match: bool,
+    pub(crate) author_match_threshold: f64,
+    pub(crate) min_match_score: f64,
+    pub(crate) consensus_mode: bool,
+    pub(crate) consensus_min: usize,
+    pub(crate) strict_title: bool,
+    pub(crate) orcid_check: bool,
     pub(crate) crossref_mailto: Option<String>,
+    pub(crate) user_agent: Option<String>,
+    pub(crate) proxy: Option<String>,
+    pub(crate) extra_ca_cert: Option<String>,
 }
 
 impl PyValidatorConfig {
@@ -58,29 +85,88 @@ impl PyValidatorConfig {
             None => None,
         };
 
+        let local_bib = match &self.local_bib_path {
+            Some(path) => Some(Arc::new(
+                hallucinator_core::db::bibtex::BibTexBackend::open(std::path::Path::new(path))
+                    .map_err(|e| {
+                        PyRuntimeError::new_err(format!("Failed to load BibTeX library: {}", e))
+                    })?,
+            )),
+            None => None,
+        };
+
+        let retraction_db = match &self.retraction_db_path {
+            Some(path) => Some(Arc::new(
+                hallucinator_core::retraction_watch::RetractionWatch::open(
+                    std::path::Path::new(path),
+                )
+                .map_err(|e| {
+                    PyRuntimeError::new_err(format!(
+                        "Failed to load Retraction Watch data: {}",
+                        e
+                    ))
+                })?,
+            )),
+            None => None,
+        };
+
         let rate_limiters = std::sync::Arc::new(hallucinator_core::RateLimiters::new(
             self.crossref_mailto.is_some(),
             self.s2_api_key.is_some(),
+            self.ncbi_api_key.is_some(),
         ));
 
         Ok(Config {
             openalex_key: self.openalex_key.clone(),
             s2_api_key: self.s2_api_key.clone(),
+            ncbi_api_key: self.ncbi_api_key.clone(),
+            core_api_key: self.core_api_key.clone(),
             dblp_offline_path: self.dblp_offline_path.as_ref().map(PathBuf::from),
             dblp_offline_db,
+            dblp_max_age_days: self.dblp_max_age_days,
             acl_offline_path: self.acl_offline_path.as_ref().map(PathBuf::from),
+            local_bib_path: self.local_bib_path.as_ref().map(PathBuf::from),
+            local_bib,
+            retraction_db_path: self.retraction_db_path.as_ref().map(PathBuf::from),
+            retraction_db,
+            retraction_max_age_days: self.retraction_max_age_days,
             acl_offline_db,
             num_workers: self.num_workers,
+            max_concurrent_papers: hallucinator_core::pool::DEFAULT_MAX_CONCURRENT_PAPERS,
+            dedup: self.dedup,
+            adaptive_concurrency: self.adaptive_concurrency,
             db_timeout_secs: self.db_timeout_secs,
             db_timeout_short_secs: self.db_timeout_short_secs,
+            db_timeouts: self.db_timeouts.clone(),
             disabled_dbs: self.disabled_dbs.clone(),
+            only_dbs: self.only_dbs.clone(),
+            per_db_threshold: self.per_db_threshold.clone(),
             check_openalex_authors: self.check_openalex_authors,
+            author_match_min_overlap: self.author_match_min_overlap,
+            require_first_author_match: self.require_first_author_match,
+            author_match_threshold: self.author_match_threshold,
+            min_match_score: self.min_match_score,
+            consensus_mode: self.consensus_mode,
+            consensus_min: self.consensus_min,
+            strict_title: self.strict_title,
+            orcid_check: self.orcid_check,
+            // Only ranks exit statuses and SARIF exports, which the
+            // bindings do not produce
+            author_mismatch_is_failure: false,
             crossref_mailto: self.crossref_mailto.clone(),
+            user_agent: self.user_agent.clone(),
+            proxy: self.proxy.clone(),
+            extra_ca_cert: self.extra_ca_cert.as_ref().map(PathBuf::from),
             max_rate_limit_retries: self.max_rate_limit_retries,
+            max_retry_passes: self.max_retry_passes,
+            max_references: self.max_references,
+            run_deadline: self.run_deadline,
             rate_limiters,
             cache_path: self.cache_path.as_ref().map(PathBuf::from),
+            cache_max_entries: self.cache_max_entries,
             query_cache: Some(hallucinator_core::build_query_cache(
                 self.cache_path.as_ref().map(std::path::Path::new),
+                self.cache_max_entries,
             )),
         })
     }
@@ -93,16 +179,42 @@ impl PyValidatorConfig {
         Self {
             openalex_key: None,
             s2_api_key: None,
+            ncbi_api_key: None,
+            core_api_key: None,
             dblp_offline_path: None,
+            dblp_max_age_days: hallucinator_dblp::DEFAULT_MAX_AGE_DAYS,
             acl_offline_path: None,
+            local_bib_path: None,
+            retraction_db_path: None,
+            retraction_max_age_days: hallucinator_core::retraction_watch::DEFAULT_MAX_AGE_DAYS,
             cache_path: None,
+            cache_max_entries: hallucinator_core::cache::DEFAULT_MAX_ENTRIES,
             num_workers: 4,
+            dedup: false,
+            adaptive_concurrency: false,
             max_rate_limit_retries: 3,
+            max_retry_passes: 1,
+            max_references: None,
+            run_deadline: None,
             db_timeout_secs: 10,
             db_timeout_short_secs: 5,
+            db_timeouts: HashMap::new(),
             disabled_dbs: vec![],
+            only_dbs: vec![],
+            per_db_threshold: HashMap::new(),
             check_openalex_authors: false,
+            author_match_min_overlap: 0.0,
+            require_first_author_match: false,
+            author_match_threshold: hallucinator_core::authors::DEFAULT_AUTHOR_MATCH_THRESHOLD,
+            min_match_score: 0.0,
+            consensus_mode: false,
+            consensus_min: hallucinator_core::orchestrator::DEFAULT_CONSENSUS_MIN,
+            strict_title: false,
+            orcid_check: false,
             crossref_mailto: None,
+            user_agent: None,
+            proxy: None,
+            extra_ca_cert: None,
         }
     }
 
@@ -128,6 +240,28 @@ impl PyValidatorConfig {
         self.s2_api_key = value;
     }
 
+    /// NCBI API key for PubMed (optional; raises the rate limit).
+    #[getter]
+    fn get_ncbi_api_key(&self) -> Option<&str> {
+        self.ncbi_api_key.as_deref()
+    }
+
+    #[setter]
+    fn set_ncbi_api_key(&mut self, value: Option<String>) {
+        self.ncbi_api_key = value;
+    }
+
+    /// CORE (core.ac.uk) API key (optional; CORE is only queried when set).
+    #[getter]
+    fn get_core_api_key(&self) -> Option<&str> {
+        self.core_api_key.as_deref()
+    }
+
+    #[setter]
+    fn set_core_api_key(&mut self, value: Option<String>) {
+        self.core_api_key = value;
+    }
+
     /// Path to offline DBLP SQLite database (optional).
     #[getter]
     fn get_dblp_offline_path(&self) -> Option<&str> {
@@ -139,6 +273,17 @@ impl PyValidatorConfig {
         self.dblp_offline_path = value;
     }
 
+    /// Days after which the offline DBLP database is reported as stale (default: 30).
+    #[getter]
+    fn get_dblp_max_age_days(&self) -> u64 {
+        self.dblp_max_age_days
+    }
+
+    #[setter]
+    fn set_dblp_max_age_days(&mut self, value: u64) {
+        self.dblp_max_age_days = value;
+    }
+
     /// Path to offline ACL Anthology SQLite database (optional).
     #[getter]
     fn get_acl_offline_path(&self) -> Option<&str> {
@@ -150,6 +295,39 @@ impl PyValidatorConfig {
         self.acl_offline_path = value;
     }
 
+    /// Path to a local ``.bib`` library to check references against (optional).
+    #[getter]
+    fn get_local_bib_path(&self) -> Option<&str> {
+        self.local_bib_path.as_deref()
+    }
+
+    #[setter]
+    fn set_local_bib_path(&mut self, value: Option<String>) {
+        self.local_bib_path = value;
+    }
+
+    /// Path to a Retraction Watch CSV to look matched DOIs up in (optional).
+    #[getter]
+    fn get_retraction_db_path(&self) -> Option<&str> {
+        self.retraction_db_path.as_deref()
+    }
+
+    #[setter]
+    fn set_retraction_db_path(&mut self, value: Option<String>) {
+        self.retraction_db_path = value;
+    }
+
+    /// Days after which the Retraction Watch CSV is reported as stale (default: 30).
+    #[getter]
+    fn get_retraction_max_age_days(&self) -> u64 {
+        self.retraction_max_age_days
+    }
+
+    #[setter]
+    fn set_retraction_max_age_days(&mut self, value: u64) {
+        self.retraction_max_age_days = value;
+    }
+
     /// Path to persistent query cache SQLite database (optional).
     #[getter]
     fn get_cache_path(&self) -> Option<&str> {
@@ -161,6 +339,17 @@ impl PyValidatorConfig {
         self.cache_path = value;
     }
 
+    /// Most query cache entries kept in memory (default: 100000; 0 = unbounded).
+    #[getter]
+    fn get_cache_max_entries(&self) -> usize {
+        self.cache_max_entries
+    }
+
+    #[setter]
+    fn set_cache_max_entries(&mut self, value: usize) {
+        self.cache_max_entries = value;
+    }
+
     /// Number of concurrent reference checks (default: 4).
     #[getter]
     fn get_num_workers(&self) -> usize {
@@ -172,6 +361,29 @@ impl PyValidatorConfig {
         self.num_workers = value;
     }
 
+    /// Look up references with the same title once and share the result (default: False).
+    #[getter]
+    fn get_dedup(&self) -> bool {
+        self.dedup
+    }
+
+    #[setter]
+    fn set_dedup(&mut self, value: bool) {
+        self.dedup = value;
+    }
+
+    /// Let each database run more queries at once while it answers without
+    /// 429s, halving them when it does (default: False).
+    #[getter]
+    fn get_adaptive_concurrency(&self) -> bool {
+        self.adaptive_concurrency
+    }
+
+    #[setter]
+    fn set_adaptive_concurrency(&mut self, value: bool) {
+        self.adaptive_concurrency = value;
+    }
+
     /// Maximum 429 retries per database query (default: 3).
     #[getter]
     fn get_max_rate_limit_retries(&self) -> u32 {
@@ -183,6 +395,44 @@ impl PyValidatorConfig {
         self.max_rate_limit_retries = value;
     }
 
+    /// Retry passes over references not found because a database failed;
+    /// 0 disables them (default: 1).
+    #[getter]
+    fn get_max_retry_passes(&self) -> usize {
+        self.max_retry_passes
+    }
+
+    #[setter]
+    fn set_max_retry_passes(&mut self, value: usize) {
+        self.max_retry_passes = value;
+    }
+
+    /// Validate at most this many references; the rest are skipped (default: None).
+    #[getter]
+    fn get_max_references(&self) -> Option<usize> {
+        self.max_references
+    }
+
+    #[setter]
+    fn set_max_references(&mut self, value: Option<usize>) {
+        self.max_references = value;
+    }
+
+    /// Stop after this many seconds and return the results so far (default: None).
+    #[getter]
+    fn get_run_deadline(&self) -> Option<f64> {
+        self.run_deadline.map(|d| d.as_secs_f64())
+    }
+
+    #[setter]
+    fn set_run_deadline(&mut self, value: Option<f64>) -> PyResult<()> {
+        self.run_deadline = value
+            .map(std::time::Duration::try_from_secs_f64)
+            .transpose()
+            .map_err(|_| PyValueError::new_err("run_deadline must be a non-negative number"))?;
+        Ok(())
+    }
+
     /// Timeout in seconds for database queries (default: 10).
     #[getter]
     fn get_db_timeout_secs(&self) -> u64 {
@@ -205,6 +455,25 @@ impl PyValidatorConfig {
         self.db_timeout_short_secs = value;
     }
 
+    /// Timeout in seconds per database name, overriding ``db_timeout_secs``,
+    /// e.g. ``{"Europe PMC": 30}``.
+    #[getter]
+    fn get_db_timeouts(&self) -> HashMap<String, u64> {
+        self.db_timeouts.clone()
+    }
+
+    #[setter]
+    fn set_db_timeouts(&mut self, value: HashMap<String, u64>) -> PyResult<()> {
+        if let Some((name, _)) = value.iter().find(|(_, secs)| **secs == 0) {
+            return Err(PyValueError::new_err(format!(
+                "timeout for {} must be at least 1 second",
+                name
+            )));
+        }
+        self.db_timeouts = value;
+        Ok(())
+    }
+
     /// List of database names to skip (e.g. ``["openalex"]``).
     #[getter]
     fn get_disabled_dbs(&self) -> Vec<String> {
@@ -216,6 +485,17 @@ impl PyValidatorConfig {
         self.disabled_dbs = value;
     }
 
+    /// The only databases to query; when non-empty, ``disabled_dbs`` is ignored.
+    #[getter]
+    fn get_only_dbs(&self) -> Vec<String> {
+        self.only_dbs.clone()
+    }
+
+    #[setter]
+    fn set_only_dbs(&mut self, value: Vec<String>) {
+        self.only_dbs = value;
+    }
+
     /// Whether to verify authors for OpenAlex matches (default: False).
     #[getter]
     fn get_check_openalex_authors(&self) -> bool {
@@ -227,7 +507,138 @@ impl PyValidatorConfig {
         self.check_openalex_authors = value;
     }
 
-    /// CrossRef mailto address for polite pool (optional).
+    /// Title-similarity threshold per database name, e.g. ``{"DBLP": 0.97}``.
+    #[getter]
+    fn get_per_db_threshold(&self) -> HashMap<String, f64> {
+        self.per_db_threshold.clone()
+    }
+
+    #[setter]
+    fn set_per_db_threshold(&mut self, value: HashMap<String, f64>) -> PyResult<()> {
+        if let Some((name, t)) = value.iter().find(|(_, t)| !(0.0..=1.0).contains(*t)) {
+            return Err(PyValueError::new_err(format!(
+                "threshold for {} must be between 0 and 1, got {}",
+                name, t
+            )));
+        }
+        self.per_db_threshold = value;
+        Ok(())
+    }
+
+    /// Fraction of cited authors a match must share, 0–1 (default: 0.0, any one).
+    #[getter]
+    fn get_author_match_min_overlap(&self) -> f32 {
+        self.author_match_min_overlap
+    }
+
+    #[setter]
+    fn set_author_match_min_overlap(&mut self, value: f32) -> PyResult<()> {
+        if !(0.0..=1.0).contains(&value) {
+            return Err(PyValueError::new_err(format!(
+                "author_match_min_overlap must be between 0 and 1, got {}",
+                value
+            )));
+        }
+        self.author_match_min_overlap = value;
+        Ok(())
+    }
+
+    /// Whether the first cited author must be among the found authors (default: False).
+    #[getter]
+    fn get_require_first_author_match(&self) -> bool {
+        self.require_first_author_match
+    }
+
+    #[setter]
+    fn set_require_first_author_match(&mut self, value: bool) {
+        self.require_first_author_match = value;
+    }
+
+    /// Surname similarity, 0–1, for two author names to match (default: 0.9; 1.0 = exact).
+    #[getter]
+    fn get_author_match_threshold(&self) -> f64 {
+        self.author_match_threshold
+    }
+
+    #[setter]
+    fn set_author_match_threshold(&mut self, value: f64) -> PyResult<()> {
+        if !(0.0..=1.0).contains(&value) {
+            return Err(PyValueError::new_err(format!(
+                "author_match_threshold must be between 0 and 1, got {}",
+                value
+            )));
+        }
+        self.author_match_threshold = value;
+        Ok(())
+    }
+
+    /// Title similarity, 0–1, a match must reach to count (default: 0.0 = no minimum).
+    #[getter]
+    fn get_min_match_score(&self) -> f64 {
+        self.min_match_score
+    }
+
+    #[setter]
+    fn set_min_match_score(&mut self, value: f64) -> PyResult<()> {
+        if !(0.0..=1.0).contains(&value) {
+            return Err(PyValueError::new_err(format!(
+                "min_match_score must be between 0 and 1, got {}",
+                value
+            )));
+        }
+        self.min_match_score = value;
+        Ok(())
+    }
+
+    /// Query every database and verify only when several agree on the title (default: False).
+    #[getter]
+    fn get_consensus_mode(&self) -> bool {
+        self.consensus_mode
+    }
+
+    #[setter]
+    fn set_consensus_mode(&mut self, value: bool) {
+        self.consensus_mode = value;
+    }
+
+    /// Databases that must agree on the title in consensus mode (default: 2).
+    #[getter]
+    fn get_consensus_min(&self) -> usize {
+        self.consensus_min
+    }
+
+    #[setter]
+    fn set_consensus_min(&mut self, value: usize) -> PyResult<()> {
+        if value == 0 {
+            return Err(PyValueError::new_err("consensus_min must be at least 1"));
+        }
+        self.consensus_min = value;
+        Ok(())
+    }
+
+    /// Flag matches whose title is close to, but not the same as, the cited one (default: False).
+    #[getter]
+    fn get_strict_title(&self) -> bool {
+        self.strict_title
+    }
+
+    #[setter]
+    fn set_strict_title(&mut self, value: bool) {
+        self.strict_title = value;
+    }
+
+    /// Settle author mismatches by checking whether a cited author claims the matched work on ORCID (default: False).
+    #[getter]
+    fn get_orcid_check(&self) -> bool {
+        self.orcid_check
+    }
+
+    #[setter]
+    fn set_orcid_check(&mut self, value: bool) {
+        self.orcid_check = value;
+    }
+
+    /// Contact email for CrossRef's polite pool (optional).
     #[getter]
     fn get_crossref_mailto(&self) -> Option<&str> {
         self.crossref_mailto.as_deref()
@@ -238,6 +649,39 @@ impl PyValidatorConfig {
         self.crossref_mailto = value;
     }
 
+    /// Custom User-Agent for API requests (default: ``hallucinator/<version>``).
+    #[getter]
+    fn get_user_agent(&self) -> Option<&str> {
+        self.user_agent.as_deref()
+    }
+
+    #[setter]
+    fn set_user_agent(&mut self, value: Option<String>) {
+        self.user_agent = value;
+    }
+
+    /// Proxy URL for API requests (default: ``HTTPS_PROXY``/``HTTP_PROXY``).
+    #[getter]
+    fn get_proxy(&self) -> Option<&str> {
+        self.proxy.as_deref()
+    }
+
+    #[setter]
+    fn set_proxy(&mut self, value: Option<String>) {
+        self.proxy = value;
+    }
+
+    /// Path to a PEM file with extra root certificates to trust (optional).
+    #[getter]
+    fn get_extra_ca_cert(&self) -> Option<&str> {
+        self.extra_ca_cert.as_deref()
+    }
+
+    #[setter]
+    fn set_extra_ca_cert(&mut self, value: Option<String>) {
+        self.extra_ca_cert = value;
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ValidatorConfig(num_workers={}, db_timeout={}s, disabled_dbs={:?})",
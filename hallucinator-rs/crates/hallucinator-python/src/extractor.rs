@@ -118,6 +118,23 @@ impl PyPdfExtractor {
         self.invalidate();
     }
 
+    /// Add an extra references-section heading (appended to the built-in
+    /// multilingual list). Matched case-insensitively on its own line, with
+    /// an optional section number.
+    fn add_section_heading(&mut self, heading: &str) {
+        self.builder = self
+            .builder
+            .clone()
+            .add_section_heading(heading.to_string());
+        self.invalidate();
+    }
+
+    /// Replace all references-section headings with the given list.
+    fn set_section_headings(&mut self, headings: Vec<String>) {
+        self.builder = self.builder.clone().set_section_headings(headings);
+        self.invalidate();
+    }
+
     /// Add an extra venue cutoff pattern (appended to defaults).
     fn add_venue_cutoff_pattern(&mut self, pattern: &str) {
         self.builder = self
@@ -255,7 +272,7 @@ impl PyPdfExtractor {
     /// Parse a single reference string, returning skip reason if skipped.
     ///
     /// Returns `(Reference, None)` on success or `(None, reason)` on skip.
-    /// `reason` is `"url_only"` or `"short_title"`.
+    /// `reason` is `"url_only"`, `"short_title"` or `"no_title"`.
     #[pyo3(signature = (text, prev_authors=None))]
     fn parse_reference_detailed(
         &mut self,
@@ -270,11 +287,7 @@ impl PyPdfExtractor {
                 Ok((Some(PyReference::from(r)), None))
             }
             hallucinator_pdf::extractor::ParsedRef::Skip(reason, _, _) => {
-                let reason_str = match reason {
-                    hallucinator_pdf::extractor::SkipReason::UrlOnly => "url_only",
-                    hallucinator_pdf::extractor::SkipReason::ShortTitle => "short_title",
-                };
-                Ok((None, Some(reason_str.to_string())))
+                Ok((None, Some(reason.as_str().to_string())))
             }
         }
     }
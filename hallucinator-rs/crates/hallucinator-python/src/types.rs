@@ -47,12 +47,18 @@ impl PyReference {
         self.inner.doi.as_deref()
     }
 
-    /// The arXiv ID, if found.
+    /// The arXiv ID without version suffix, if found.
     #[getter]
     fn arxiv_id(&self) -> Option<&str> {
         self.inner.arxiv_id.as_deref()
     }
 
+    /// The arXiv version the citation pointed at (`v2` → `2`), if given.
+    #[getter]
+    fn arxiv_version(&self) -> Option<u32> {
+        self.inner.arxiv_version
+    }
+
     /// 1-based position in the original PDF reference list (before skip filtering).
     #[getter]
     fn original_number(&self) -> usize {
@@ -108,6 +114,13 @@ impl PySkipStats {
         self.inner.no_title
     }
 
+    /// Number of footnote citations that could not be resolved to a title
+    /// ("Ibid.", "op. cit." and the like).
+    #[getter]
+    fn unresolved_footnote(&self) -> usize {
+        self.inner.unresolved_footnote
+    }
+
     /// Number of references where no authors could be extracted.
     #[getter]
     fn no_authors(&self) -> usize {
@@ -122,11 +135,12 @@ impl PySkipStats {
 
     fn __repr__(&self) -> String {
         format!(
-            "SkipStats(total_raw={}, url_only={}, short_title={}, no_title={}, no_authors={})",
+            "SkipStats(total_raw={}, url_only={}, short_title={}, no_title={}, unresolved_footnote={}, no_authors={})",
             self.inner.total_raw,
             self.inner.url_only,
             self.inner.short_title,
             self.inner.no_title,
+            self.inner.unresolved_footnote,
             self.inner.no_authors,
         )
     }
@@ -171,6 +185,12 @@ impl PyExtractionResult {
         PySkipStats::from(self.inner.skip_stats.clone())
     }
 
+    /// Whether the PDF was read by OCR, so the text may be noisier.
+    #[getter]
+    fn ocr_used(&self) -> bool {
+        self.inner.ocr_used
+    }
+
     /// Construct an ExtractionResult from parts (used by the Python wrapper).
     #[staticmethod]
     fn _from_parts(
@@ -188,11 +208,14 @@ impl PyExtractionResult {
             short_title,
             no_title,
             no_authors,
+            ..Default::default()
         };
         Self {
             inner: ExtractionResult {
                 references,
                 skip_stats,
+                ocr_used: false,
+                ocr_error: None,
             },
         }
     }
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use pyo3::prelude::*;
 
 use hallucinator_core::{
@@ -56,18 +58,71 @@ impl PyValidationResult {
         }
     }
 
+    /// Why the reference was flagged: "no_match_any_db", "authors_diverged",
+    /// "doi_did_not_resolve", "doi_title_mismatch" or "no_consensus".
+    /// ``None`` when verified.
+    #[getter]
+    fn reason(&self) -> Option<&str> {
+        self.inner.reason.map(|r| r.as_str())
+    }
+
     /// The database source that verified this reference, if any.
     #[getter]
     fn source(&self) -> Option<&str> {
         self.inner.source.as_deref()
     }
 
+    /// With ``consensus_mode``, every database that matched the title,
+    /// ``source`` first; empty otherwise.
+    #[getter]
+    fn agreeing_dbs(&self) -> Vec<String> {
+        self.inner.agreeing_dbs.clone()
+    }
+
     /// Authors found in the matching database record.
     #[getter]
     fn found_authors(&self) -> Vec<String> {
         self.inner.found_authors.clone()
     }
 
+    /// Title similarity (0.0–1.0) of the match from ``source``, or None.
+    #[getter]
+    fn match_score(&self) -> Option<f64> {
+        self.inner.match_score
+    }
+
+    /// ``(cited_title, found_title, similarity)`` when ``strict_title`` found
+    /// the match's title to differ from the cited one, or None. Informational:
+    /// the status is unaffected.
+    #[getter]
+    fn title_near_miss(&self) -> Option<(String, String, f64)> {
+        self.inner
+            .title_near_miss
+            .as_ref()
+            .map(|m| (m.cited_title.clone(), m.found_title.clone(), m.similarity))
+    }
+
+    /// Journal or venue of the match from ``source``, when its database
+    /// reports one (PubMed, Europe PMC, DataCite), or None.
+    #[getter]
+    fn venue(&self) -> Option<String> {
+        self.inner.venue.clone()
+    }
+
+    /// Publication year of the match from ``source``, when its database
+    /// reports one, or None.
+    #[getter]
+    fn year(&self) -> Option<u32> {
+        self.inner.year
+    }
+
+    /// ORCID iD of the cited author who claims the matched work, when an
+    /// author mismatch was settled by ``Config.orcid_check``, or None.
+    #[getter]
+    fn orcid(&self) -> Option<&str> {
+        self.inner.orcid.as_deref()
+    }
+
     /// URL of the paper in the matching database, if any.
     #[getter]
     fn paper_url(&self) -> Option<&str> {
@@ -77,7 +132,19 @@ impl PyValidationResult {
     /// List of database names that failed/timed out.
     #[getter]
     fn failed_dbs(&self) -> Vec<String> {
-        self.inner.failed_dbs.clone()
+        self.inner.failed_db_names()
+    }
+
+    /// ``(name, reason)`` for each database in ``failed_dbs``. The reason is
+    /// ``"timeout"``, ``"rate_limited"``, ``"network"``, ``"http"`` or
+    /// ``"other"``.
+    #[getter]
+    fn failed_db_reasons(&self) -> Vec<(String, &'static str)> {
+        self.inner
+            .failed_dbs
+            .iter()
+            .map(|f| (f.name.clone(), f.reason.as_str()))
+            .collect()
     }
 
     /// Per-database query results.
@@ -168,12 +235,24 @@ impl PyDbResult {
         self.inner.elapsed.map(|d| d.as_secs_f64() * 1000.0)
     }
 
+    /// Title of the record this database returned, or None.
+    #[getter]
+    fn found_title(&self) -> Option<&str> {
+        self.inner.found_title.as_deref()
+    }
+
     /// Authors found in this database's record.
     #[getter]
     fn found_authors(&self) -> Vec<String> {
         self.inner.found_authors.clone()
     }
 
+    /// Title similarity (0.0–1.0) of this database's record, or None.
+    #[getter]
+    fn score(&self) -> Option<f64> {
+        self.inner.score
+    }
+
     /// URL of the paper in this database, if found.
     #[getter]
     fn paper_url(&self) -> Option<&str> {
@@ -225,12 +304,25 @@ impl PyDoiInfo {
         self.inner.valid
     }
 
+    /// Whether the DOI has a plausible ``10.NNNN/suffix`` structure.
+    #[getter]
+    fn structurally_valid(&self) -> bool {
+        self.inner.structurally_valid
+    }
+
     /// The title returned by the DOI resolver, if any.
     #[getter]
     fn title(&self) -> Option<&str> {
         self.inner.title.as_deref()
     }
 
+    /// Whether the DOI resolved to a paper whose title strongly disagrees
+    /// with the citation's.
+    #[getter]
+    fn title_mismatch(&self) -> bool {
+        self.inner.title_mismatch
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "DoiInfo(doi={:?}, valid={})",
@@ -274,6 +366,18 @@ impl PyArxivInfo {
         self.inner.title.as_deref()
     }
 
+    /// The version the citation pointed at (``v2`` -> ``2``), if any.
+    #[getter]
+    fn cited_version(&self) -> Option<u32> {
+        self.inner.cited_version
+    }
+
+    /// The latest version arXiv reports, if the arXiv search found the id.
+    #[getter]
+    fn latest_version(&self) -> Option<u32> {
+        self.inner.latest_version
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ArxivInfo(arxiv_id={:?}, valid={})",
@@ -333,11 +437,13 @@ impl PyRetractionInfo {
 ///
 /// - ``"checking"`` — ``index``, ``total``, ``title``
 /// - ``"result"`` — ``index``, ``total``, ``result`` (a ``ValidationResult``)
+/// - ``"duplicate"`` — ``index``, ``total``, ``title``; the result is shared with an earlier reference
 /// - ``"warning"`` — ``index``, ``total``, ``title``, ``failed_dbs``, ``message``
 /// - ``"retry_pass"`` — ``count``
 /// - ``"db_query_complete"`` — ``paper_index``, ``ref_index``, ``db_name``, ``status``, ``elapsed_ms``
 /// - ``"rate_limit_wait"`` — ``db_name``, waiting for rate limiter
 /// - ``"rate_limit_retry"`` — ``ref_index``, ``db_name``, ``attempt``, retrying after 429
+/// - ``"rate_limit_status"`` — ``slowdown_factors``, sent while a database is throttled
 #[pyclass(name = "ProgressEvent")]
 #[derive(Debug, Clone)]
 pub struct PyProgressEvent {
@@ -358,44 +464,51 @@ impl PyProgressEvent {
         match &self.inner {
             ProgressEvent::Checking { .. } => "checking",
             ProgressEvent::Result { .. } => "result",
+            ProgressEvent::Duplicate { .. } => "duplicate",
             ProgressEvent::Warning { .. } => "warning",
             ProgressEvent::Retrying { .. } => "retrying",
             ProgressEvent::RetryPass { .. } => "retry_pass",
             ProgressEvent::DatabaseQueryComplete { .. } => "db_query_complete",
             ProgressEvent::RateLimitWait { .. } => "rate_limit_wait",
             ProgressEvent::RateLimitRetry { .. } => "rate_limit_retry",
+            ProgressEvent::RateLimited { .. } => "rate_limited",
+            ProgressEvent::RateLimitStatus { .. } => "rate_limit_status",
+            ProgressEvent::ConcurrencyChanged { .. } => "concurrency_changed",
         }
     }
 
-    /// Index of the reference (for checking/result/warning/retrying events).
+    /// Index of the reference (for checking/result/duplicate/warning/retrying events).
     #[getter]
     fn index(&self) -> Option<usize> {
         match &self.inner {
             ProgressEvent::Checking { index, .. }
             | ProgressEvent::Result { index, .. }
+            | ProgressEvent::Duplicate { index, .. }
             | ProgressEvent::Warning { index, .. }
             | ProgressEvent::Retrying { index, .. } => Some(*index),
             _ => None,
         }
     }
 
-    /// Total number of references (for checking/result/warning/retrying events).
+    /// Total number of references (for checking/result/duplicate/warning/retrying events).
     #[getter]
     fn total(&self) -> Option<usize> {
         match &self.inner {
             ProgressEvent::Checking { total, .. }
             | ProgressEvent::Result { total, .. }
+            | ProgressEvent::Duplicate { total, .. }
             | ProgressEvent::Warning { total, .. }
             | ProgressEvent::Retrying { total, .. } => Some(*total),
             _ => None,
         }
     }
 
-    /// Reference title (for checking/warning/retrying events).
+    /// Reference title (for checking/duplicate/warning/retrying events).
     #[getter]
     fn title(&self) -> Option<&str> {
         match &self.inner {
             ProgressEvent::Checking { title, .. }
+            | ProgressEvent::Duplicate { title, .. }
             | ProgressEvent::Warning { title, .. }
             | ProgressEvent::Retrying { title, .. } => Some(title),
             _ => None,
@@ -457,11 +570,58 @@ impl PyProgressEvent {
         }
     }
 
-    /// Database name (for db_query_complete events).
+    /// Database name (for db_query_complete, rate_limited and
+    /// concurrency_changed events).
     #[getter]
     fn db_name(&self) -> Option<&str> {
         match &self.inner {
-            ProgressEvent::DatabaseQueryComplete { db_name, .. } => Some(db_name),
+            ProgressEvent::DatabaseQueryComplete { db_name, .. }
+            | ProgressEvent::RateLimited { db_name, .. }
+            | ProgressEvent::ConcurrencyChanged { db_name, .. } => Some(db_name),
+            _ => None,
+        }
+    }
+
+    /// How long the query waits before retrying, in milliseconds (for rate_limited events).
+    #[getter]
+    fn retry_after_ms(&self) -> Option<f64> {
+        match &self.inner {
+            ProgressEvent::RateLimited { retry_after, .. } => {
+                Some(retry_after.as_secs_f64() * 1000.0)
+            }
+            _ => None,
+        }
+    }
+
+    /// Which backoff this is for the query, starting at 1 (for rate_limited events).
+    #[getter]
+    fn attempt(&self) -> Option<u32> {
+        match &self.inner {
+            ProgressEvent::RateLimited { attempt, .. } => Some(*attempt),
+            _ => None,
+        }
+    }
+
+    /// Slowdown factor per database name, 1 at the base rate and 2–16 while
+    /// throttled (for rate_limit_status events).
+    #[getter]
+    fn slowdown_factors(&self) -> Option<HashMap<String, u32>> {
+        match &self.inner {
+            ProgressEvent::RateLimitStatus { limiters } => Some(
+                limiters
+                    .iter()
+                    .map(|s| (s.db_name.clone(), s.factor))
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Queries the database now runs at once (for concurrency_changed events).
+    #[getter]
+    fn concurrency(&self) -> Option<usize> {
+        match &self.inner {
+            ProgressEvent::ConcurrencyChanged { limit, .. } => Some(*limit),
             _ => None,
         }
     }
@@ -510,6 +670,14 @@ impl PyProgressEvent {
                     Status::AuthorMismatch => "author_mismatch",
                 },
             ),
+            ProgressEvent::Duplicate {
+                index,
+                total,
+                title,
+            } => format!(
+                "ProgressEvent(type='duplicate', index={}, total={}, title={:?})",
+                index, total, title,
+            ),
             ProgressEvent::Warning {
                 index,
                 total,
@@ -558,6 +726,28 @@ impl PyProgressEvent {
                 attempt,
                 backoff.as_secs_f64() * 1000.0,
             ),
+            ProgressEvent::RateLimited {
+                db_name,
+                retry_after,
+                attempt,
+            } => format!(
+                "ProgressEvent(type='rate_limited', db={:?}, retry_after_ms={:.0}, attempt={})",
+                db_name,
+                retry_after.as_secs_f64() * 1000.0,
+                attempt,
+            ),
+            ProgressEvent::ConcurrencyChanged { db_name, limit } => format!(
+                "ProgressEvent(type='concurrency_changed', db={:?}, concurrency={})",
+                db_name, limit,
+            ),
+            ProgressEvent::RateLimitStatus { limiters } => format!(
+                "ProgressEvent(type='rate_limit_status', throttled={:?})",
+                limiters
+                    .iter()
+                    .filter(|s| s.is_throttled())
+                    .map(|s| format!("{} {}x", s.db_name, s.factor))
+                    .collect::<Vec<_>>(),
+            ),
         }
     }
 }
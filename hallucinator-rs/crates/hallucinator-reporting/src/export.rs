@@ -1,7 +1,7 @@
 use std::io::Write;
 use std::path::Path;
 
-use hallucinator_core::{CheckStats, DbStatus, Status, ValidationResult};
+use hallucinator_core::{CheckStats, DbStatus, DoiInfo, FailedDb, Status, ValidationResult};
 
 use crate::types::{ExportFormat, FpReason, PaperVerdict, ReportPaper, ReportRef};
 
@@ -9,25 +9,59 @@ use crate::types::{ExportFormat, FpReason, PaperVerdict, ReportPaper, ReportRef}
 ///
 /// `ref_states` is a parallel slice to `papers` — `ref_states[i]` are the ReportRefs
 /// for `papers[i]`. This is used to include FP reason overrides in the output.
+/// A single paper is just a one-element slice; see [`export_batch`].
 pub fn export_results(
     papers: &[ReportPaper<'_>],
     ref_states: &[&[ReportRef]],
     format: ExportFormat,
+    author_mismatch_is_failure: bool,
     path: &Path,
 ) -> Result<(), String> {
+    let mut file =
+        std::fs::File::create(path).map_err(|e| format!("Failed to create file: {}", e))?;
+    export_batch(
+        papers,
+        ref_states,
+        format,
+        author_mismatch_is_failure,
+        &mut file,
+    )
+    .map_err(|e| format!("Failed to write: {}", e))?;
+    Ok(())
+}
+
+/// Write a combined report for a batch of papers as a single document.
+///
+/// Every format already holds multiple papers: JSON is an array of paper
+/// objects in a versioned envelope (loadable with `--load`), CSV tags each row with its filename,
+/// and Markdown/Text/HTML get one section per paper. When the batch has
+/// more than one paper, the human-readable formats also open with a summary
+/// of the aggregate stats. SARIF is one run whose results point at each
+/// paper's file. RIS holds only the verified references, for importing
+/// into a reference manager. With `author_mismatch_is_failure`
+/// ([`Config::author_mismatch_is_failure`](hallucinator_core::Config::author_mismatch_is_failure)),
+/// SARIF ranks author mismatches as errors, like references not found.
+///
+/// Returns the aggregate FP-adjusted [`CheckStats`] across all papers.
+pub fn export_batch<W: Write>(
+    papers: &[ReportPaper<'_>],
+    ref_states: &[&[ReportRef]],
+    format: ExportFormat,
+    author_mismatch_is_failure: bool,
+    writer: &mut W,
+) -> std::io::Result<CheckStats> {
     let content = match format {
         ExportFormat::Json => export_json(papers, ref_states),
         ExportFormat::Csv => export_csv(papers, ref_states),
         ExportFormat::Markdown => export_markdown(papers, ref_states),
         ExportFormat::Text => export_text(papers, ref_states),
         ExportFormat::Html => export_html(papers, ref_states),
+        ExportFormat::Sarif => export_sarif(papers, ref_states, author_mismatch_is_failure),
+        ExportFormat::Ris => export_ris(papers, ref_states),
     };
-
-    let mut file =
-        std::fs::File::create(path).map_err(|e| format!("Failed to create file: {}", e))?;
-    file.write_all(content.as_bytes())
-        .map_err(|e| format!("Failed to write: {}", e))?;
-    Ok(())
+    writer.write_all(content.as_bytes())?;
+    writer.flush()?;
+    Ok(aggregate_stats(papers, ref_states))
 }
 
 fn status_str(s: &Status) -> &'static str {
@@ -50,10 +84,11 @@ fn is_retracted(r: &ValidationResult) -> bool {
     r.retraction_info.as_ref().is_some_and(|ri| ri.is_retracted)
 }
 
-/// Whether a verified reference has an invalid DOI or arXiv ID.
+/// Whether a verified reference has an invalid or mismatched DOI, or an
+/// invalid arXiv ID.
 fn has_doi_arxiv_issue(r: &ValidationResult) -> bool {
     r.status == Status::Verified
-        && (r.doi_info.as_ref().is_some_and(|d| !d.valid)
+        && (r.doi_info.as_ref().is_some_and(DoiInfo::has_issue)
             || r.arxiv_info.as_ref().is_some_and(|a| !a.valid))
 }
 
@@ -152,6 +187,23 @@ fn adjusted_stats(paper: &ReportPaper<'_>, refs: &[ReportRef]) -> CheckStats {
     s
 }
 
+/// Sum the FP-adjusted stats of every paper in a batch.
+fn aggregate_stats(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> CheckStats {
+    let mut total = CheckStats::default();
+    for (pi, paper) in papers.iter().enumerate() {
+        let paper_refs = ref_states.get(pi).copied().unwrap_or(&[]);
+        let s = adjusted_stats(paper, paper_refs);
+        total.total += s.total;
+        total.verified += s.verified;
+        total.not_found += s.not_found;
+        total.author_mismatch += s.author_mismatch;
+        total.retracted += s.retracted;
+        total.skipped += s.skipped;
+        total.duplicates += s.duplicates;
+    }
+    total
+}
+
 fn json_escape(s: &str) -> String {
     let mut out = String::with_capacity(s.len());
     for c in s.chars() {
@@ -181,12 +233,91 @@ fn json_opt_str(s: &Option<String>) -> String {
     }
 }
 
+fn json_opt_u32(v: Option<u32>) -> String {
+    match v {
+        Some(v) => v.to_string(),
+        None => "null".to_string(),
+    }
+}
+
+fn json_opt_f64(v: Option<f64>) -> String {
+    match v {
+        Some(v) => format!("{:.4}", v),
+        None => "null".to_string(),
+    }
+}
+
 fn json_str_array(v: &[String]) -> String {
     let items: Vec<String> = v.iter().map(|s| json_str(s)).collect();
     format!("[{}]", items.join(", "))
 }
 
+/// `[{"name": ..., "reason": ...}, ...]` for the databases that failed.
+fn json_failed_dbs(v: &[FailedDb]) -> String {
+    let items: Vec<String> = v
+        .iter()
+        .map(|f| {
+            format!(
+                "{{\"name\": {}, \"reason\": {}}}",
+                json_str(&f.name),
+                json_str(f.reason.as_str())
+            )
+        })
+        .collect();
+    format!("[{}]", items.join(", "))
+}
+
+/// "CrossRef timed out, arXiv rate limited (429)", for the human-readable
+/// formats.
+fn describe_failed_dbs(v: &[FailedDb]) -> String {
+    v.iter()
+        .map(FailedDb::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Version of the JSON export's shape, written as its `schema_version`.
+///
+/// Bump it whenever a field of the paper or reference objects is renamed,
+/// removed or changes meaning, so readers can refuse files they would
+/// misread. Adding a field does not need a bump. The current schema:
+///
+/// ```text
+/// { "schema_version": 1,
+///   "stats": { "total", "verified", "not_found", "author_mismatch",
+///     "retracted", "skipped", "problematic_pct" },
+///   "papers": [ { "filename", "verdict", "stats": { "total", "verified",
+///       "not_found", "author_mismatch", "retracted", "skipped",
+///       "problematic_pct" },
+///     "references": [ { "index", "original_number", "title", "raw_citation",
+///       "status", "effective_status", "skip_reason"?, "fp_reason", "reason",
+///       "source", "agreeing_dbs", "ref_authors", "found_authors", "match_score", "venue",
+///       "year", "orcid"?, "paper_url", "failed_dbs", "failed_db_reasons", "doi_info", "arxiv_info", "retraction_info",
+///       "title_near_miss", "db_results" } ] } ] }
+/// ```
+pub const JSON_SCHEMA_VERSION: u32 = 1;
+
+/// JSON export: the papers wrapped in an envelope carrying
+/// [`JSON_SCHEMA_VERSION`] and the batch's aggregate stats.
 pub fn export_json(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> String {
+    let s = aggregate_stats(papers, ref_states);
+    let papers_json = json_papers(papers, ref_states);
+    format!(
+        "{{\n  \"schema_version\": {},\n  \"stats\": {{\n    \"total\": {},\n    \"verified\": {},\n    \"not_found\": {},\n    \"author_mismatch\": {},\n    \"retracted\": {},\n    \"skipped\": {},\n    \"problematic_pct\": {:.1}\n  }},\n  \"papers\": {}\n}}\n",
+        JSON_SCHEMA_VERSION,
+        s.total,
+        s.verified,
+        s.not_found,
+        s.author_mismatch,
+        s.retracted,
+        s.skipped,
+        problematic_pct(&s),
+        papers_json.trim_end().replace('\n', "\n  ")
+    )
+}
+
+/// The array of paper objects inside the JSON export.
+fn json_papers(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> String {
     let mut out = String::from("[\n");
     for (pi, paper) in papers.iter().enumerate() {
         let paper_refs = ref_states.get(pi).copied().unwrap_or(&[]);
@@ -239,10 +370,20 @@ pub fn export_json(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> S
             ));
             entry.push_str(&format!("        \"effective_status\": {},\n", effective));
             entry.push_str(&format!("        \"fp_reason\": {},\n", fp_json));
+            entry.push_str(&format!(
+                "        \"reason\": {},\n",
+                r.reason
+                    .map(|reason| json_str(reason.as_str()))
+                    .unwrap_or_else(|| "null".to_string())
+            ));
             entry.push_str(&format!(
                 "        \"source\": {},\n",
                 json_opt_str(&r.source)
             ));
+            entry.push_str(&format!(
+                "        \"agreeing_dbs\": {},\n",
+                json_str_array(&r.agreeing_dbs)
+            ));
             entry.push_str(&format!(
                 "        \"ref_authors\": {},\n",
                 json_str_array(&r.ref_authors)
@@ -251,22 +392,37 @@ pub fn export_json(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> S
                 "        \"found_authors\": {},\n",
                 json_str_array(&r.found_authors)
             ));
+            entry.push_str(&format!(
+                "        \"match_score\": {},\n",
+                json_opt_f64(r.match_score)
+            ));
+            entry.push_str(&format!("        \"venue\": {},\n", json_opt_str(&r.venue)));
+            entry.push_str(&format!("        \"year\": {},\n", json_opt_u32(r.year)));
+            if r.orcid.is_some() {
+                entry.push_str(&format!("        \"orcid\": {},\n", json_opt_str(&r.orcid)));
+            }
             entry.push_str(&format!(
                 "        \"paper_url\": {},\n",
                 json_opt_str(&r.paper_url)
             ));
             entry.push_str(&format!(
                 "        \"failed_dbs\": {},\n",
-                json_str_array(&r.failed_dbs)
+                json_str_array(&r.failed_db_names())
+            ));
+            entry.push_str(&format!(
+                "        \"failed_db_reasons\": {},\n",
+                json_failed_dbs(&r.failed_dbs)
             ));
 
             // DOI info
             if let Some(doi) = &r.doi_info {
                 entry.push_str(&format!(
-                    "        \"doi_info\": {{\"doi\": {}, \"valid\": {}, \"title\": {}}},\n",
+                    "        \"doi_info\": {{\"doi\": {}, \"valid\": {}, \"structurally_valid\": {}, \"title\": {}, \"title_mismatch\": {}}},\n",
                     json_str(&doi.doi),
                     doi.valid,
-                    json_opt_str(&doi.title)
+                    doi.structurally_valid,
+                    json_opt_str(&doi.title),
+                    doi.title_mismatch
                 ));
             } else {
                 entry.push_str("        \"doi_info\": null,\n");
@@ -275,10 +431,12 @@ pub fn export_json(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> S
             // arXiv info
             if let Some(ax) = &r.arxiv_info {
                 entry.push_str(&format!(
-                    "        \"arxiv_info\": {{\"arxiv_id\": {}, \"valid\": {}, \"title\": {}}},\n",
+                    "        \"arxiv_info\": {{\"arxiv_id\": {}, \"valid\": {}, \"title\": {}, \"cited_version\": {}, \"latest_version\": {}}},\n",
                     json_str(&ax.arxiv_id),
                     ax.valid,
-                    json_opt_str(&ax.title)
+                    json_opt_str(&ax.title),
+                    json_opt_u32(ax.cited_version),
+                    json_opt_u32(ax.latest_version),
                 ));
             } else {
                 entry.push_str("        \"arxiv_info\": null,\n");
@@ -296,6 +454,18 @@ pub fn export_json(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> S
                 entry.push_str("        \"retraction_info\": null,\n");
             }
 
+            // Strict title mode's cited vs. found title
+            if let Some(m) = &r.title_near_miss {
+                entry.push_str(&format!(
+                    "        \"title_near_miss\": {{\"cited_title\": {}, \"found_title\": {}, \"similarity\": {}}},\n",
+                    json_str(&m.cited_title),
+                    json_str(&m.found_title),
+                    json_opt_f64(Some(m.similarity))
+                ));
+            } else {
+                entry.push_str("        \"title_near_miss\": null,\n");
+            }
+
             // Per-DB results
             entry.push_str("        \"db_results\": [");
             for (di, db) in r.db_results.iter().enumerate() {
@@ -309,13 +479,35 @@ pub fn export_json(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> S
                 };
                 let elapsed_ms = db.elapsed.map(|d| d.as_millis()).unwrap_or(0);
                 entry.push_str(&format!(
-                    "{{\"db\": {}, \"status\": {}, \"elapsed_ms\": {}, \"authors\": {}, \"url\": {}}}",
+                    "{{\"db\": {}, \"status\": {}, \"elapsed_ms\": {}, \"authors\": {}, \"url\": {}",
                     json_str(&db.db_name),
                     json_str(db_status),
                     elapsed_ms,
                     json_str_array(&db.found_authors),
                     json_opt_str(&db.paper_url),
                 ));
+                if let Some(ref title) = db.found_title {
+                    entry.push_str(&format!(", \"title\": {}", json_str(title)));
+                }
+                if let Some(score) = db.score {
+                    entry.push_str(&format!(", \"score\": {:.4}", score));
+                }
+                if !db.alternates.is_empty() {
+                    let alternates: Vec<String> = db
+                        .alternates
+                        .iter()
+                        .map(|alt| {
+                            format!(
+                                "{{\"title\": {}, \"authors\": {}, \"url\": {}}}",
+                                json_str(&alt.title),
+                                json_str_array(&alt.authors),
+                                json_opt_str(&alt.paper_url),
+                            )
+                        })
+                        .collect();
+                    entry.push_str(&format!(", \"alternates\": [{}]", alternates.join(", ")));
+                }
+                entry.push('}');
                 if di + 1 < r.db_results.len() {
                     entry.push_str(", ");
                 }
@@ -344,11 +536,16 @@ pub fn export_json(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> S
                 entry.push_str("        \"source\": null,\n");
                 entry.push_str("        \"ref_authors\": [],\n");
                 entry.push_str("        \"found_authors\": [],\n");
+                entry.push_str("        \"match_score\": null,\n");
+                entry.push_str("        \"venue\": null,\n");
+                entry.push_str("        \"year\": null,\n");
                 entry.push_str("        \"paper_url\": null,\n");
                 entry.push_str("        \"failed_dbs\": [],\n");
+                entry.push_str("        \"failed_db_reasons\": [],\n");
                 entry.push_str("        \"doi_info\": null,\n");
                 entry.push_str("        \"arxiv_info\": null,\n");
                 entry.push_str("        \"retraction_info\": null,\n");
+                entry.push_str("        \"title_near_miss\": null,\n");
                 entry.push_str("        \"db_results\": []\n");
                 entry.push_str("      }");
                 entries.push(entry);
@@ -372,15 +569,239 @@ pub fn export_json(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> S
     out
 }
 
+/// SARIF rules: (id, short description, default level).
+const SARIF_RULES: &[(&str, &str, &str)] = &[
+    (
+        "hallucinated-reference",
+        "Reference not found in any database",
+        "error",
+    ),
+    (
+        "author-mismatch",
+        "Reference found, but its authors do not match",
+        "warning",
+    ),
+    (
+        "retracted-citation",
+        "Reference cites a retracted paper",
+        "error",
+    ),
+];
+
+/// The SARIF rule a reference violates, if any. FP-overridden references
+/// count as verified and produce no result.
+fn sarif_rule(
+    r: &ValidationResult,
+    fp: Option<FpReason>,
+) -> Option<&'static (&'static str, &'static str, &'static str)> {
+    if fp.is_some() {
+        return None;
+    }
+    let id = if is_retracted(r) {
+        "retracted-citation"
+    } else {
+        match r.status {
+            Status::NotFound => "hallucinated-reference",
+            Status::AuthorMismatch => "author-mismatch",
+            Status::Verified => return None,
+        }
+    };
+    SARIF_RULES.iter().find(|(rule_id, _, _)| *rule_id == id)
+}
+
+/// Percent-encode a filename for `artifactLocation.uri`, keeping path
+/// separators so relative paths still resolve against the repository.
+fn sarif_uri(path: &str) -> String {
+    let mut out = String::with_capacity(path.len());
+    for b in path.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    out
+}
+
+fn sarif_message(ref_num: usize, r: &ValidationResult, rule_id: &str) -> String {
+    let mut msg = format!("Reference [{}] \"{}\"", ref_num, r.title);
+    match rule_id {
+        "retracted-citation" => {
+            msg.push_str(" cites a retracted paper");
+            if let Some(rdoi) = r
+                .retraction_info
+                .as_ref()
+                .and_then(|ri| ri.retraction_doi.as_deref())
+            {
+                msg.push_str(&format!(" (retraction notice: https://doi.org/{})", rdoi));
+            }
+            msg.push('.');
+        }
+        "author-mismatch" => {
+            msg.push_str(" was found");
+            if let Some(src) = &r.source {
+                msg.push_str(&format!(" on {}", src));
+            }
+            msg.push_str(" but its authors do not match");
+            if !r.ref_authors.is_empty() {
+                msg.push_str(&format!("; cited: {}", r.ref_authors.join(", ")));
+            }
+            if !r.found_authors.is_empty() {
+                msg.push_str(&format!("; found: {}", r.found_authors.join(", ")));
+            }
+            msg.push('.');
+        }
+        _ => {
+            msg.push_str(" was not found in any database");
+            if !r.failed_dbs.is_empty() {
+                msg.push_str(&format!(" ({})", describe_failed_dbs(&r.failed_dbs)));
+            }
+            msg.push('.');
+        }
+    }
+    msg
+}
+
+/// Export results as a SARIF 2.1.0 log, so CI (e.g. GitHub code scanning)
+/// can annotate the checked files.
+///
+/// Each retracted, not-found or author-mismatched reference becomes one
+/// result located at its paper's file. PDFs have no line numbers, so
+/// results carry no region. Author mismatches are warnings, or errors with
+/// `author_mismatch_is_failure`.
+pub fn export_sarif(
+    papers: &[ReportPaper<'_>],
+    ref_states: &[&[ReportRef]],
+    author_mismatch_is_failure: bool,
+) -> String {
+    let level_of = |id: &str, level: &'static str| {
+        if id == "author-mismatch" && author_mismatch_is_failure {
+            "error"
+        } else {
+            level
+        }
+    };
+    let rules: Vec<String> = SARIF_RULES
+        .iter()
+        .map(|&(id, desc, level)| {
+            let level = level_of(id, level);
+            format!(
+                "            {{\n              \"id\": {},\n              \"shortDescription\": {{ \"text\": {} }},\n              \"defaultConfiguration\": {{ \"level\": {} }}\n            }}",
+                json_str(id),
+                json_str(desc),
+                json_str(level),
+            )
+        })
+        .collect();
+
+    let mut results: Vec<String> = Vec::new();
+    for (pi, paper) in papers.iter().enumerate() {
+        let paper_refs = ref_states.get(pi).copied().unwrap_or(&[]);
+        for sref in build_sorted_refs(paper, paper_refs) {
+            let Some(&(rule_id, _, level)) = sarif_rule(sref.result, sref.fp) else {
+                continue;
+            };
+            let level = level_of(rule_id, level);
+            results.push(format!(
+                "        {{\n          \"ruleId\": {},\n          \"level\": {},\n          \"message\": {{ \"text\": {} }},\n          \"locations\": [\n            {{ \"physicalLocation\": {{ \"artifactLocation\": {{ \"uri\": {} }} }} }}\n          ]\n        }}",
+                json_str(rule_id),
+                json_str(level),
+                json_str(&sarif_message(sref.ref_num, sref.result, rule_id)),
+                json_str(&sarif_uri(paper.filename)),
+            ));
+        }
+    }
+
+    format!(
+        "{{\n  \"$schema\": \"https://json.schemastore.org/sarif-2.1.0.json\",\n  \"version\": \"2.1.0\",\n  \"runs\": [\n    {{\n      \"tool\": {{\n        \"driver\": {{\n          \"name\": \"hallucinator\",\n          \"version\": {},\n          \"informationUri\": {},\n          \"rules\": [\n{}\n          ]\n        }}\n      }},\n      \"results\": [{}]\n    }}\n  ]\n}}\n",
+        json_str(env!("CARGO_PKG_VERSION")),
+        json_str(env!("CARGO_PKG_REPOSITORY")),
+        rules.join(",\n"),
+        if results.is_empty() {
+            String::new()
+        } else {
+            format!("\n{}\n      ", results.join(",\n"))
+        },
+    )
+}
+
+/// Quote a CSV field (RFC 4180) when it holds a quote, comma or line break.
 fn csv_escape(s: &str) -> String {
-    if s.contains('"') || s.contains(',') || s.contains('\n') {
+    if s.contains(['"', ',', '\n', '\r']) {
         format!("\"{}\"", s.replace('"', "\"\""))
     } else {
         s.to_string()
     }
 }
 
-fn export_csv(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> String {
+/// Export the verified references (including those marked as false
+/// positives, but not retracted ones) as RIS records for Zotero, EndNote or
+/// Mendeley. Title and authors come from the matching database when it
+/// returned them, falling back to the citation's.
+pub fn export_ris(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> String {
+    let mut out = String::new();
+    for (pi, paper) in papers.iter().enumerate() {
+        let paper_refs = ref_states.get(pi).copied().unwrap_or(&[]);
+        let mut refs = build_sorted_refs(paper, paper_refs);
+        refs.sort_by_key(|sref| sref.ref_num);
+        for sref in refs {
+            let r = sref.result;
+            if is_retracted(r) || (r.status != Status::Verified && sref.fp.is_none()) {
+                continue;
+            }
+            write_ris_record(&mut out, r);
+        }
+    }
+    out
+}
+
+fn write_ris_record(out: &mut String, r: &ValidationResult) {
+    let source = r.source.as_deref();
+    let found_title = r
+        .db_results
+        .iter()
+        .find(|d| Some(d.db_name.as_str()) == source)
+        .and_then(|d| d.found_title.as_deref());
+    let authors = if r.found_authors.is_empty() {
+        &r.ref_authors
+    } else {
+        &r.found_authors
+    };
+
+    let mut tag = |tag: &str, value: &str| {
+        let value = value.trim();
+        if !value.is_empty() {
+            out.push_str(&format!(
+                "{}  - {}\n",
+                tag,
+                value.replace(['\r', '\n'], " ")
+            ));
+        }
+    };
+    tag("TY", "JOUR");
+    tag("TI", found_title.unwrap_or(&r.title));
+    for author in authors {
+        tag("AU", author);
+    }
+    if let Some(venue) = &r.venue {
+        tag("T2", venue);
+    }
+    if let Some(year) = r.year {
+        tag("PY", &year.to_string());
+    }
+    if let Some(doi) = r.doi_info.as_ref().filter(|d| !d.title_mismatch) {
+        tag("DO", &doi.doi);
+    }
+    if let Some(url) = &r.paper_url {
+        tag("UR", url);
+    }
+    out.push_str("ER  - \n\n");
+}
+
+/// One row per reference, tagged with its paper's filename. Multi-valued
+/// fields (authors, failed databases) are joined with `"; "`.
+pub fn export_csv(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> String {
     let mut out = String::from(
         "Filename,Verdict,Ref#,Title,Status,EffectiveStatus,FpReason,Source,Retracted,Authors,FoundAuthors,PaperURL,DOI,ArxivID,FailedDBs\n",
     );
@@ -406,7 +827,7 @@ fn export_csv(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> String
                 .as_ref()
                 .map(|a| a.arxiv_id.as_str())
                 .unwrap_or("");
-            let failed = r.failed_dbs.join("; ");
+            let failed = r.failed_db_names().join("; ");
             out.push_str(&format!(
                 "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}\n",
                 csv_escape(paper.filename),
@@ -443,8 +864,10 @@ fn export_csv(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> String
     out
 }
 
+/// Make text safe inside a GitHub-flavored table cell: pipes would split
+/// the cell and line breaks would end the row.
 fn md_escape(s: &str) -> String {
-    s.replace('|', "\\|")
+    s.replace('|', "\\|").replace(['\r', '\n'], " ")
 }
 
 fn scholar_url(title: &str) -> String {
@@ -457,6 +880,15 @@ fn scholar_url(title: &str) -> String {
 fn export_markdown(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> String {
     let mut out = String::from("# Hallucinator Results\n\n");
 
+    if papers.len() > 1 {
+        let s = aggregate_stats(papers, ref_states);
+        out.push_str(&format!(
+            "**{}** papers | **{}** references | **{}** verified | **{}** not found | **{}** mismatch | **{}** retracted | **{}** skipped | **{:.1}%** problematic\n\n",
+            papers.len(), s.total, s.verified, s.not_found, s.author_mismatch, s.retracted, s.skipped,
+            problematic_pct(&s),
+        ));
+    }
+
     for (pi, paper) in papers.iter().enumerate() {
         let paper_refs = ref_states.get(pi).copied().unwrap_or(&[]);
         let s = adjusted_stats(paper, paper_refs);
@@ -491,9 +923,22 @@ fn export_markdown(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> S
 
         if !problems.is_empty() {
             out.push_str("### Problematic References\n\n");
+            out.push_str("| # | Title | Verdict | DOI/URL |\n");
+            out.push_str("|---|-------|---------|---------|\n");
+            for sref in &problems {
+                out.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    sref.ref_num,
+                    md_escape(&sref.result.title),
+                    md_verdict(sref.result),
+                    md_link_cell(sref.result),
+                ));
+            }
+            out.push_str("\n<details><summary>Details</summary>\n\n");
             for sref in &problems {
                 write_md_ref(&mut out, sref.ref_num, sref.result);
             }
+            out.push_str("</details>\n\n");
         }
 
         if !doi_arxiv_issues.is_empty() {
@@ -586,8 +1031,8 @@ fn export_markdown(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> S
     out
 }
 
-fn write_md_ref(out: &mut String, ref_num: usize, r: &ValidationResult) {
-    let status_icon = if is_retracted(r) {
+fn md_verdict(r: &ValidationResult) -> &'static str {
+    if is_retracted(r) {
         "\u{2620}\u{fe0f} RETRACTED"
     } else {
         match r.status {
@@ -595,13 +1040,26 @@ fn write_md_ref(out: &mut String, ref_num: usize, r: &ValidationResult) {
             Status::AuthorMismatch => "\u{26a0}\u{fe0f} Author Mismatch",
             Status::Verified => "\u{2713} Verified",
         }
-    };
+    }
+}
 
+/// The DOI link when the citation has one, else the matched paper URL.
+fn md_link_cell(r: &ValidationResult) -> String {
+    if let Some(doi) = &r.doi_info {
+        format!("[{}](https://doi.org/{})", md_escape(&doi.doi), doi.doi)
+    } else if let Some(url) = &r.paper_url {
+        format!("[link]({})", url)
+    } else {
+        "\u{2014}".to_string()
+    }
+}
+
+fn write_md_ref(out: &mut String, ref_num: usize, r: &ValidationResult) {
     out.push_str(&format!(
         "**[{}]** {} \u{2014} {}\n\n",
         ref_num,
         md_escape(&r.title),
-        status_icon,
+        md_verdict(r),
     ));
 
     // Author comparison for mismatches
@@ -633,6 +1091,14 @@ fn write_md_ref(out: &mut String, ref_num: usize, r: &ValidationResult) {
             "- **DOI** `{}` \u{2014} invalid/unresolvable\n",
             doi.doi
         ));
+    } else if let Some(doi) = &r.doi_info
+        && doi.title_mismatch
+    {
+        out.push_str(&format!(
+            "- **DOI** `{}` \u{2014} resolves to a different title: {}\n",
+            doi.doi,
+            md_escape(doi.title.as_deref().unwrap_or("")),
+        ));
     }
     if let Some(ax) = &r.arxiv_info
         && !ax.valid
@@ -663,7 +1129,10 @@ fn write_md_ref(out: &mut String, ref_num: usize, r: &ValidationResult) {
 
     // Failed DBs
     if !r.failed_dbs.is_empty() {
-        out.push_str(&format!("- **Timed out:** {}\n", r.failed_dbs.join(", ")));
+        out.push_str(&format!(
+            "- **Failed:** {}\n",
+            describe_failed_dbs(&r.failed_dbs)
+        ));
     }
 
     // Raw citation in details block
@@ -681,6 +1150,15 @@ fn export_text(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> Strin
     out.push_str(&"=".repeat(60));
     out.push('\n');
 
+    if papers.len() > 1 {
+        let s = aggregate_stats(papers, ref_states);
+        out.push_str(&format!(
+            "{} papers | {} total | {} verified | {} not found | {} mismatch | {} retracted | {} skipped | {:.1}% problematic\n",
+            papers.len(), s.total, s.verified, s.not_found, s.author_mismatch, s.retracted, s.skipped,
+            problematic_pct(&s),
+        ));
+    }
+
     for (pi, paper) in papers.iter().enumerate() {
         let paper_refs = ref_states.get(pi).copied().unwrap_or(&[]);
         let s = adjusted_stats(paper, paper_refs);
@@ -746,12 +1224,28 @@ fn export_text(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> Strin
 
             // DOI / arXiv
             if let Some(doi) = &r.doi_info {
-                let valid = if doi.valid { "valid" } else { "INVALID" };
+                let valid = if !doi.structurally_valid {
+                    "MALFORMED"
+                } else if doi.title_mismatch {
+                    "TITLE MISMATCH"
+                } else if doi.valid {
+                    "valid"
+                } else {
+                    "INVALID"
+                };
                 out.push_str(&format!("       DOI: {} ({})\n", doi.doi, valid));
+                if doi.title_mismatch
+                    && let Some(title) = &doi.title
+                {
+                    out.push_str(&format!("       DOI title: {}\n", title));
+                }
             }
             if let Some(ax) = &r.arxiv_info {
                 let valid = if ax.valid { "valid" } else { "INVALID" };
                 out.push_str(&format!("       arXiv: {} ({})\n", ax.arxiv_id, valid));
+                if let Some(note) = ax.version_note() {
+                    out.push_str(&format!("       arXiv version: {}\n", note));
+                }
             }
 
             // Retraction details
@@ -773,7 +1267,10 @@ fn export_text(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> Strin
 
             // Failed DBs
             if !r.failed_dbs.is_empty() {
-                out.push_str(&format!("       Timed out: {}\n", r.failed_dbs.join(", ")));
+                out.push_str(&format!(
+                    "       Failed: {}\n",
+                    describe_failed_dbs(&r.failed_dbs)
+                ));
             }
 
             // Raw citation
@@ -819,18 +1316,7 @@ fn html_escape(s: &str) -> String {
 fn export_html(papers: &[ReportPaper<'_>], ref_states: &[&[ReportRef]]) -> String {
     let mut out = String::with_capacity(16384);
 
-    // Aggregate adjusted stats across all papers
-    let mut total_stats = CheckStats::default();
-    for (pi, p) in papers.iter().enumerate() {
-        let pr = ref_states.get(pi).copied().unwrap_or(&[]);
-        let adj = adjusted_stats(p, pr);
-        total_stats.total += adj.total;
-        total_stats.verified += adj.verified;
-        total_stats.not_found += adj.not_found;
-        total_stats.author_mismatch += adj.author_mismatch;
-        total_stats.retracted += adj.retracted;
-        total_stats.skipped += adj.skipped;
-    }
+    let total_stats = aggregate_stats(papers, ref_states);
 
     out.push_str(
         r#"<!DOCTYPE html>
@@ -983,6 +1469,36 @@ a { color: var(--blue); text-decoration: none; }
 a:hover { text-decoration: underline; }
 .links { margin-top: 0.4rem; }
 .links a { margin-right: 1rem; }
+.toolbar {
+  display: flex;
+  gap: 0.5rem;
+  flex-wrap: wrap;
+  align-items: center;
+  margin-bottom: 1.5rem;
+}
+.toolbar button {
+  background: var(--surface);
+  color: var(--text);
+  border: 1px solid var(--border);
+  border-radius: 4px;
+  padding: 0.3rem 0.8rem;
+  cursor: pointer;
+  font: inherit;
+  font-size: 0.85rem;
+}
+.toolbar button.active { background: var(--card); border-color: var(--blue); }
+.toolbar input {
+  flex: 1;
+  min-width: 200px;
+  background: var(--surface);
+  color: var(--text);
+  border: 1px solid var(--border);
+  border-radius: 4px;
+  padding: 0.3rem 0.6rem;
+  font: inherit;
+  font-size: 0.85rem;
+}
+.hidden { display: none; }
 footer {
   margin-top: 3rem;
   padding-top: 1rem;
@@ -1017,6 +1533,17 @@ footer {
     ));
     out.push_str("</div>\n");
 
+    out.push_str(
+        "<div class=\"toolbar\">\n\
+<button class=\"active\" data-filter=\"all\">All</button>\n\
+<button data-filter=\"not-found\">Not Found</button>\n\
+<button data-filter=\"mismatch\">Mismatch</button>\n\
+<button data-filter=\"retracted\">Retracted</button>\n\
+<button data-filter=\"verified\">Verified</button>\n\
+<input id=\"search\" type=\"search\" placeholder=\"Search titles and citations\">\n\
+</div>\n",
+    );
+
     // Per-paper sections
     for (pi, paper) in papers.iter().enumerate() {
         let paper_refs = ref_states.get(pi).copied().unwrap_or(&[]);
@@ -1028,7 +1555,7 @@ footer {
             None => "",
         };
         out.push_str(&format!(
-            "<details open>\n<summary>{}{}</summary>\n<div class=\"paper-content\">\n",
+            "<details class=\"paper\" open>\n<summary>{}{}</summary>\n<div class=\"paper-content\">\n",
             html_escape(paper.filename),
             verdict_html,
         ));
@@ -1065,7 +1592,7 @@ footer {
                     &rs.title
                 };
                 out.push_str(&format!(
-                    "<div class=\"ref-card\" style=\"opacity:0.5\"><div class=\"ref-header\"><span class=\"ref-num\">[{}]</span><span class=\"ref-title\">{}</span><span class=\"badge\" style=\"background:var(--dim);color:#fff\">{}</span></div></div>\n",
+                    "<div class=\"ref-card\" data-verdict=\"skipped\" style=\"opacity:0.5\"><div class=\"ref-header\"><span class=\"ref-num\">[{}]</span><span class=\"ref-title\">{}</span><span class=\"badge\" style=\"background:var(--dim);color:#fff\">{}</span></div></div>\n",
                     rs.index + 1,
                     html_escape(title),
                     html_escape(reason),
@@ -1094,10 +1621,45 @@ footer {
         year, month, day, hours, minutes,
     ));
 
+    out.push_str(HTML_FILTER_SCRIPT);
     out.push_str("</body>\n</html>\n");
     out
 }
 
+/// Client-side filtering for [`export_html`]: the toolbar buttons show one
+/// verdict (matching each card's `data-verdict`), the search box matches a
+/// card's text, and papers left with no visible card are hidden.
+const HTML_FILTER_SCRIPT: &str = r#"<script>
+(function () {
+  var verdict = "all";
+  var search = document.getElementById("search");
+  function apply() {
+    var query = search.value.toLowerCase();
+    document.querySelectorAll("details.paper").forEach(function (paper) {
+      var shown = 0;
+      paper.querySelectorAll(".ref-card").forEach(function (card) {
+        var ok = (verdict === "all" || card.dataset.verdict === verdict) &&
+          card.textContent.toLowerCase().indexOf(query) !== -1;
+        card.classList.toggle("hidden", !ok);
+        if (ok) shown++;
+      });
+      paper.classList.toggle("hidden", shown === 0 && (verdict !== "all" || query !== ""));
+    });
+  }
+  document.querySelectorAll(".toolbar button").forEach(function (button) {
+    button.addEventListener("click", function () {
+      document.querySelectorAll(".toolbar button").forEach(function (b) {
+        b.classList.toggle("active", b === button);
+      });
+      verdict = button.dataset.filter;
+      apply();
+    });
+  });
+  search.addEventListener("input", apply);
+})();
+</script>
+"#;
+
 fn write_stat_card(out: &mut String, class: &str, value: usize, label: &str) {
     out.push_str(&format!(
         "<div class=\"stat-card {}\"><span class=\"number\">{}</span><span class=\"label\">{}</span></div>\n",
@@ -1119,7 +1681,10 @@ fn write_html_ref(out: &mut String, ref_num: usize, r: &ValidationResult, fp: Op
         }
     };
 
-    out.push_str("<div class=\"ref-card\">\n");
+    out.push_str(&format!(
+        "<div class=\"ref-card\" data-verdict=\"{}\">\n",
+        badge_class
+    ));
     out.push_str("<div class=\"ref-header\">\n");
     out.push_str(&format!("<span class=\"ref-num\">[{}]</span>\n", ref_num));
     out.push_str(&format!(
@@ -1167,7 +1732,14 @@ fn write_html_ref(out: &mut String, ref_num: usize, r: &ValidationResult, fp: Op
 
     // DOI / arXiv
     if let Some(doi) = &r.doi_info {
-        if doi.valid {
+        if doi.title_mismatch {
+            out.push_str(&format!(
+                "<div class=\"ref-detail\" style=\"color:var(--red)\">DOI: <a href=\"https://doi.org/{}\">{}</a> (resolves to a different title: {})</div>\n",
+                html_escape(&doi.doi),
+                html_escape(&doi.doi),
+                html_escape(doi.title.as_deref().unwrap_or("")),
+            ));
+        } else if doi.valid {
             out.push_str(&format!(
                 "<div class=\"ref-detail\">DOI: <a href=\"https://doi.org/{}\">{}</a></div>\n",
                 html_escape(&doi.doi),
@@ -1175,17 +1747,25 @@ fn write_html_ref(out: &mut String, ref_num: usize, r: &ValidationResult, fp: Op
             ));
         } else {
             out.push_str(&format!(
-                "<div class=\"ref-detail\" style=\"color:var(--red)\">DOI: {} (invalid)</div>\n",
+                "<div class=\"ref-detail\" style=\"color:var(--red)\">DOI: {} ({})</div>\n",
                 html_escape(&doi.doi),
+                if doi.structurally_valid {
+                    "invalid"
+                } else {
+                    "malformed"
+                },
             ));
         }
     }
     if let Some(ax) = &r.arxiv_info {
         if ax.valid {
             out.push_str(&format!(
-                "<div class=\"ref-detail\">arXiv: <a href=\"https://arxiv.org/abs/{}\">{}</a></div>\n",
+                "<div class=\"ref-detail\">arXiv: <a href=\"https://arxiv.org/abs/{}\">{}</a>{}</div>\n",
                 html_escape(&ax.arxiv_id),
                 html_escape(&ax.arxiv_id),
+                ax.version_note()
+                    .map(|note| format!(" ({})", note))
+                    .unwrap_or_default(),
             ));
         } else {
             out.push_str(&format!(
@@ -1226,8 +1806,8 @@ fn write_html_ref(out: &mut String, ref_num: usize, r: &ValidationResult, fp: Op
     // Failed DBs
     if !r.failed_dbs.is_empty() {
         out.push_str(&format!(
-            "<div class=\"ref-detail\">Timed out: {}</div>\n",
-            html_escape(&r.failed_dbs.join(", "))
+            "<div class=\"ref-detail\">Failed: {}</div>\n",
+            html_escape(&describe_failed_dbs(&r.failed_dbs))
         ));
     }
 
@@ -1266,7 +1846,10 @@ mod tests {
     use std::str::FromStr;
 
     use crate::types::{ExportFormat, FpReason, PaperVerdict, ReportPaper, ReportRef, SkipInfo};
-    use hallucinator_core::{CheckStats, DoiInfo, RetractionInfo, Status, ValidationResult};
+    use hallucinator_core::{
+        CheckStats, DbResult, DoiInfo, FailureReason, FlagReason, RetractionInfo, Status,
+        ValidationResult,
+    };
 
     // ── helpers ──────────────────────────────────────────────────────
 
@@ -1277,6 +1860,7 @@ mod tests {
             ref_authors: vec![],
             status,
             source: None,
+            agreeing_dbs: vec![],
             found_authors: vec![],
             paper_url: None,
             failed_dbs: vec![],
@@ -1284,6 +1868,12 @@ mod tests {
             doi_info: None,
             arxiv_info: None,
             retraction_info: None,
+            reason: None,
+            match_score: None,
+            title_near_miss: None,
+            venue: None,
+            year: None,
+            orcid: None,
         }
     }
 
@@ -1380,6 +1970,11 @@ mod tests {
         assert_eq!(csv_escape("a\nb"), "\"a\nb\"");
     }
 
+    #[test]
+    fn test_csv_escape_carriage_return() {
+        assert_eq!(csv_escape("a\r\nb"), "\"a\r\nb\"");
+    }
+
     #[test]
     fn test_csv_escape_clean() {
         assert_eq!(csv_escape("hello"), "hello");
@@ -1430,6 +2025,7 @@ mod tests {
             author_mismatch: 0,
             retracted: 0,
             skipped: 5,
+            duplicates: 0,
         };
         assert_eq!(problematic_pct(&stats), 0.0);
     }
@@ -1443,6 +2039,7 @@ mod tests {
             author_mismatch: 0,
             retracted: 0,
             skipped: 0,
+            duplicates: 0,
         };
         assert!((problematic_pct(&stats) - 20.0).abs() < f64::EPSILON);
     }
@@ -1456,6 +2053,7 @@ mod tests {
             author_mismatch: 0,
             retracted: 0,
             skipped: 0,
+            duplicates: 0,
         };
         let results: Vec<Option<ValidationResult>> = vec![
             Some(make_result("A", Status::Verified)),
@@ -1482,6 +2080,7 @@ mod tests {
             author_mismatch: 1,
             retracted: 0,
             skipped: 0,
+            duplicates: 0,
         };
         let results: Vec<Option<ValidationResult>> = vec![
             Some(make_result("A", Status::Verified)),
@@ -1506,6 +2105,7 @@ mod tests {
             author_mismatch: 0,
             retracted: 0,
             skipped: 0,
+            duplicates: 0,
         };
         let results: Vec<Option<ValidationResult>> = vec![Some(make_result("A", Status::Verified))];
         let paper = make_paper("test.pdf", &stats, &results);
@@ -1525,6 +2125,7 @@ mod tests {
             author_mismatch: 0,
             retracted: 1,
             skipped: 0,
+            duplicates: 0,
         };
         let results: Vec<Option<ValidationResult>> = vec![Some(make_retracted("A"))];
         let paper = make_paper("test.pdf", &stats, &results);
@@ -1543,6 +2144,8 @@ mod tests {
             doi: "10.bad".into(),
             valid: false,
             title: None,
+            structurally_valid: false,
+            title_mismatch: false,
         });
 
         let results: Vec<Option<ValidationResult>> = vec![
@@ -1659,7 +2262,7 @@ mod tests {
     #[test]
     fn test_export_format_all() {
         let all = ExportFormat::all();
-        assert_eq!(all.len(), 5);
+        assert_eq!(all.len(), 7);
         for fmt in all {
             assert!(!fmt.label().is_empty());
             assert!(!fmt.extension().is_empty());
@@ -1671,7 +2274,10 @@ mod tests {
     #[test]
     fn test_json_empty_papers() {
         let out = export_json(&[], &[]);
-        assert_eq!(out, "[\n]\n");
+        assert_eq!(
+            out,
+            "{\n  \"schema_version\": 1,\n  \"stats\": {\n    \"total\": 0,\n    \"verified\": 0,\n    \"not_found\": 0,\n    \"author_mismatch\": 0,\n    \"retracted\": 0,\n    \"skipped\": 0,\n    \"problematic_pct\": 0.0\n  },\n  \"papers\": [\n  ]\n}\n"
+        );
     }
 
     #[test]
@@ -1683,6 +2289,7 @@ mod tests {
             author_mismatch: 0,
             retracted: 0,
             skipped: 0,
+            duplicates: 0,
         };
         let results = vec![Some(make_result("Good Paper", Status::Verified))];
         let paper = make_paper("test.pdf", &stats, &results);
@@ -1690,8 +2297,9 @@ mod tests {
         let ref_slices: &[&[ReportRef]] = &[&refs];
         let out = export_json(&[paper], ref_slices);
         // Should be valid-ish JSON structure
-        assert!(out.starts_with("[\n"));
-        assert!(out.ends_with("]\n"));
+        assert!(out.starts_with("{\n  \"schema_version\": 1,\n  \"stats\": {\n"));
+        assert!(out.contains("  },\n  \"papers\": [\n    {\n"));
+        assert!(out.ends_with("    }\n  ]\n}\n"));
         assert!(out.contains("\"filename\": \"test.pdf\""));
         assert!(out.contains("\"verified\": 1"));
         assert!(out.contains("\"title\": \"Good Paper\""));
@@ -1707,6 +2315,7 @@ mod tests {
             author_mismatch: 0,
             retracted: 0,
             skipped: 1,
+            duplicates: 0,
         };
         let results: Vec<Option<ValidationResult>> = vec![];
         let paper = make_paper("test.pdf", &stats, &results);
@@ -1726,6 +2335,7 @@ mod tests {
             author_mismatch: 0,
             retracted: 0,
             skipped: 0,
+            duplicates: 0,
         };
         let results = vec![Some(make_result("FP Ref", Status::NotFound))];
         let paper = make_paper("test.pdf", &stats, &results);
@@ -1737,6 +2347,153 @@ mod tests {
         assert!(out.contains("\"fp_reason\": \"exists_elsewhere\""));
     }
 
+    #[test]
+    fn test_json_flag_reason() {
+        let stats = CheckStats {
+            total: 2,
+            verified: 1,
+            not_found: 1,
+            ..Default::default()
+        };
+        let mut flagged = make_result("Missing", Status::NotFound);
+        flagged.reason = Some(FlagReason::DoiDidNotResolve);
+        let results = vec![Some(flagged), Some(make_result("Real", Status::Verified))];
+        let paper = make_paper("test.pdf", &stats, &results);
+        let refs = vec![make_ref(0, "Missing"), make_ref(1, "Real")];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+        let out = export_json(&[paper], ref_slices);
+        assert!(out.contains("\"reason\": \"doi_did_not_resolve\""));
+        assert!(out.contains("\"reason\": null"));
+    }
+
+    #[test]
+    fn test_json_failed_db_reasons() {
+        let stats = CheckStats {
+            total: 1,
+            not_found: 1,
+            ..Default::default()
+        };
+        let mut missing = make_result("Missing", Status::NotFound);
+        missing.failed_dbs = vec![
+            FailedDb::new("CrossRef", FailureReason::Timeout),
+            FailedDb::new("Semantic Scholar", FailureReason::RateLimited),
+        ];
+        let results = vec![Some(missing)];
+        let paper = make_paper("test.pdf", &stats, &results);
+        let refs = vec![make_ref(0, "Missing")];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+        let out = export_json(&[paper], ref_slices);
+        assert!(out.contains("\"failed_dbs\": [\"CrossRef\", \"Semantic Scholar\"]"));
+        assert!(out.contains(
+            "\"failed_db_reasons\": [{\"name\": \"CrossRef\", \"reason\": \"timeout\"}, \
+             {\"name\": \"Semantic Scholar\", \"reason\": \"rate_limited\"}]"
+        ));
+    }
+
+    #[test]
+    fn test_json_match_score() {
+        let stats = CheckStats {
+            total: 2,
+            verified: 1,
+            not_found: 1,
+            ..Default::default()
+        };
+        let mut verified = make_result("Real", Status::Verified);
+        verified.match_score = Some(0.975);
+        let results = vec![
+            Some(verified),
+            Some(make_result("Missing", Status::NotFound)),
+        ];
+        let paper = make_paper("test.pdf", &stats, &results);
+        let refs = vec![make_ref(0, "Real"), make_ref(1, "Missing")];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+        let out = export_json(&[paper], ref_slices);
+        assert!(out.contains("\"match_score\": 0.9750"));
+        assert!(out.contains("\"match_score\": null"));
+    }
+
+    #[test]
+    fn test_sarif_results() {
+        let stats = CheckStats {
+            total: 4,
+            verified: 2,
+            not_found: 1,
+            author_mismatch: 1,
+            ..Default::default()
+        };
+        let mut retracted = make_result("Withdrawn", Status::Verified);
+        retracted.retraction_info = Some(RetractionInfo {
+            is_retracted: true,
+            retraction_doi: Some("10.1/ret".to_string()),
+            retraction_source: None,
+        });
+        let results = vec![
+            Some(make_result("Real", Status::Verified)),
+            Some(make_result("Fake \"quoted\"", Status::NotFound)),
+            Some(make_result("Mixed", Status::AuthorMismatch)),
+            Some(retracted),
+        ];
+        let paper = make_paper("papers/my paper.pdf", &stats, &results);
+        let refs = vec![
+            make_ref(0, "Real"),
+            make_ref(1, "Fake"),
+            make_ref(2, "Mixed"),
+            make_ref(3, "Withdrawn"),
+        ];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+        let out = export_sarif(&[paper], ref_slices, false);
+
+        assert!(out.contains("\"version\": \"2.1.0\""));
+        assert_eq!(out.matches("\"ruleId\"").count(), 3);
+        assert!(out.contains("\"ruleId\": \"hallucinated-reference\""));
+        assert!(out.contains("\"ruleId\": \"author-mismatch\",\n          \"level\": \"warning\""));
+        assert!(
+            out.contains("\"ruleId\": \"retracted-citation\",\n          \"level\": \"error\"")
+        );
+        assert!(
+            out.contains(
+                "Reference [2] \\\"Fake \\\"quoted\\\"\\\" was not found in any database."
+            )
+        );
+        assert!(out.contains("retraction notice: https://doi.org/10.1/ret"));
+        assert!(out.contains("\"uri\": \"papers/my%20paper.pdf\""));
+        assert!(!out.contains("\"region\""));
+    }
+
+    #[test]
+    fn test_sarif_author_mismatch_as_failure() {
+        let stats = CheckStats {
+            total: 1,
+            author_mismatch: 1,
+            ..Default::default()
+        };
+        let results = vec![Some(make_result("Mixed", Status::AuthorMismatch))];
+        let paper = make_paper("f.pdf", &stats, &results);
+        let refs = vec![make_ref(0, "Mixed")];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+        let out = export_sarif(&[paper], ref_slices, true);
+        assert!(out.contains("\"ruleId\": \"author-mismatch\",\n          \"level\": \"error\""));
+        assert!(!out.contains("\"warning\""));
+    }
+
+    #[test]
+    fn test_sarif_skips_fp_overrides() {
+        let stats = CheckStats {
+            total: 1,
+            not_found: 1,
+            ..Default::default()
+        };
+        let results = vec![Some(make_result("Fake", Status::NotFound))];
+        let paper = make_paper("f.pdf", &stats, &results);
+        let mut r = make_ref(0, "Fake");
+        r.fp_reason = Some(FpReason::ExistsElsewhere);
+        let refs = vec![r];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+        let out = export_sarif(&[paper], ref_slices, false);
+        assert!(!out.contains("\"ruleId\""));
+        assert!(out.contains("\"results\": []"));
+    }
+
     #[test]
     fn test_csv_header() {
         let out = export_csv(&[], &[]);
@@ -1756,6 +2513,7 @@ mod tests {
             author_mismatch: 0,
             retracted: 0,
             skipped: 0,
+            duplicates: 0,
         };
         let results = vec![Some(make_result("My Paper", Status::Verified))];
         let paper = make_paper("test.pdf", &stats, &results);
@@ -1768,6 +2526,39 @@ mod tests {
         assert!(lines[1].contains("My Paper"));
     }
 
+    #[test]
+    fn test_csv_row_escapes_awkward_fields() {
+        let stats = CheckStats {
+            total: 1,
+            verified: 0,
+            not_found: 1,
+            author_mismatch: 0,
+            retracted: 0,
+            skipped: 0,
+            duplicates: 0,
+        };
+        let mut result = make_result("The \"Best\" Paper,\nPart 2", Status::NotFound);
+        result.ref_authors = vec!["Doe, J.".into(), "Roe, R.".into()];
+        result.failed_dbs = vec![
+            FailedDb::new("CrossRef", FailureReason::Timeout),
+            FailedDb::new("arXiv", FailureReason::Network),
+        ];
+        let results = vec![Some(result)];
+        let paper = make_paper("a,b.pdf", &stats, &results);
+        let refs = vec![make_ref(0, "The \"Best\" Paper")];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+        let out = export_csv(&[paper], ref_slices);
+
+        let row = out.split_once('\n').unwrap().1;
+        assert!(row.starts_with("\"a,b.pdf\","), "{row}");
+        assert!(
+            row.contains(",\"The \"\"Best\"\" Paper,\nPart 2\","),
+            "{row}"
+        );
+        assert!(row.contains(",\"Doe, J.; Roe, R.\","), "{row}");
+        assert!(row.ends_with(",CrossRef; arXiv\n"), "{row}");
+    }
+
     #[test]
     fn test_markdown_structure() {
         let stats = CheckStats {
@@ -1777,6 +2568,7 @@ mod tests {
             author_mismatch: 0,
             retracted: 0,
             skipped: 0,
+            duplicates: 0,
         };
         let results = vec![
             Some(make_result("Good", Status::Verified)),
@@ -1793,6 +2585,67 @@ mod tests {
         assert!(out.contains("**1** not found"));
     }
 
+    #[test]
+    fn test_doi_title_mismatch_is_a_doi_issue() {
+        let mut r = make_result("Cited Title", Status::Verified);
+        r.doi_info = Some(DoiInfo {
+            doi: "10.1000/other".into(),
+            valid: true,
+            title: Some("An Unrelated Paper".into()),
+            structurally_valid: true,
+            title_mismatch: true,
+        });
+        assert!(has_doi_arxiv_issue(&r));
+        let mut out = String::new();
+        write_md_ref(&mut out, 1, &r);
+        assert!(out.contains("resolves to a different title: An Unrelated Paper"));
+
+        r.doi_info.as_mut().unwrap().title_mismatch = false;
+        assert!(!has_doi_arxiv_issue(&r));
+    }
+
+    #[test]
+    fn test_markdown_problem_table() {
+        let stats = CheckStats {
+            total: 3,
+            verified: 1,
+            not_found: 2,
+            author_mismatch: 0,
+            retracted: 0,
+            skipped: 0,
+            duplicates: 0,
+        };
+        let mut with_doi = make_result("Cats | Dogs", Status::NotFound);
+        with_doi.doi_info = Some(hallucinator_core::DoiInfo {
+            doi: "10.1000/xyz".to_string(),
+            valid: true,
+            title: None,
+            structurally_valid: true,
+            title_mismatch: false,
+        });
+        let results = vec![
+            Some(make_result("Good", Status::Verified)),
+            Some(with_doi),
+            Some(make_result("Bad", Status::NotFound)),
+        ];
+        let paper = make_paper("paper.pdf", &stats, &results);
+        let refs = vec![
+            make_ref(0, "Good"),
+            make_ref(1, "Cats | Dogs"),
+            make_ref(2, "Bad"),
+        ];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+        let out = export_markdown(&[paper], ref_slices);
+
+        let table_start = out.find("| # | Title | Verdict | DOI/URL |").unwrap();
+        let table = &out[table_start..out[table_start..].find("\n\n").unwrap() + table_start];
+        assert!(table.contains(
+            "| 2 | Cats \\| Dogs | \u{2717} Not Found | [10.1000/xyz](https://doi.org/10.1000/xyz) |"
+        ));
+        assert!(table.contains("| 3 | Bad | \u{2717} Not Found | \u{2014} |"));
+        assert!(!table.contains("Good"));
+    }
+
     #[test]
     fn test_text_structure() {
         let stats = CheckStats {
@@ -1802,6 +2655,7 @@ mod tests {
             author_mismatch: 0,
             retracted: 0,
             skipped: 0,
+            duplicates: 0,
         };
         let results = vec![Some(make_result("Paper", Status::Verified))];
         let paper = make_paper("f.pdf", &stats, &results);
@@ -1814,6 +2668,72 @@ mod tests {
         assert!(out.contains("1 verified"));
     }
 
+    #[test]
+    fn test_ris_records_verified_refs_in_tag_order() {
+        let stats = CheckStats::default();
+        let mut verified = make_result("Cited Title", Status::Verified);
+        verified.source = Some("DBLP".to_string());
+        verified.db_results = vec![DbResult {
+            db_name: "DBLP".to_string(),
+            status: DbStatus::Match,
+            elapsed: None,
+            found_title: Some("Found Title".to_string()),
+            found_authors: vec![],
+            paper_url: None,
+            error_message: None,
+            alternates: vec![],
+            threshold: None,
+            score: None,
+            venue: None,
+            year: None,
+        }];
+        verified.found_authors = vec!["Ada Lovelace".to_string(), "Alan Turing".to_string()];
+        verified.year = Some(2020);
+        verified.doi_info = Some(DoiInfo {
+            doi: "10.1234/x".to_string(),
+            valid: true,
+            title: None,
+            structurally_valid: true,
+            title_mismatch: false,
+        });
+        verified.paper_url = Some("https://example.org/p".to_string());
+        let results = vec![
+            Some(verified),
+            Some(make_result("Missing", Status::NotFound)),
+            Some(make_retracted("Retracted")),
+            Some(make_result("Marked Valid", Status::NotFound)),
+        ];
+        let paper = make_paper("f.pdf", &stats, &results);
+        let refs = vec![
+            make_ref(0, "Cited Title"),
+            make_ref(1, "Missing"),
+            make_ref(2, "Retracted"),
+            make_ref_fp(3, "Marked Valid", FpReason::KnownGood),
+        ];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+        let out = export_ris(&[paper], ref_slices);
+
+        let records: Vec<&str> = out.split_terminator("ER  - \n\n").collect();
+        assert_eq!(records.len(), 2);
+        assert!(out.ends_with("ER  - \n\n"));
+        assert_eq!(
+            records[0].lines().collect::<Vec<_>>(),
+            vec![
+                "TY  - JOUR",
+                "TI  - Found Title",
+                "AU  - Ada Lovelace",
+                "AU  - Alan Turing",
+                "PY  - 2020",
+                "DO  - 10.1234/x",
+                "UR  - https://example.org/p",
+            ]
+        );
+        assert_eq!(
+            records[1].lines().collect::<Vec<_>>(),
+            vec!["TY  - JOUR", "TI  - Marked Valid"]
+        );
+    }
+
     #[test]
     fn test_html_structure() {
         let stats = CheckStats {
@@ -1823,6 +2743,7 @@ mod tests {
             author_mismatch: 0,
             retracted: 0,
             skipped: 0,
+            duplicates: 0,
         };
         let results = vec![Some(make_result("Paper", Status::Verified))];
         let paper = make_paper("f.pdf", &stats, &results);
@@ -1850,4 +2771,136 @@ mod tests {
         assert!(out.contains("badge verified\">SAFE</span>"));
         assert!(out.contains("badge not-found\">?!</span>"));
     }
+
+    #[test]
+    fn test_html_filtering_is_self_contained_and_escaped() {
+        let stats = CheckStats {
+            total: 2,
+            verified: 1,
+            not_found: 1,
+            ..Default::default()
+        };
+        let results = vec![
+            Some(make_result("Bounds for <i>n</i> < 2^k", Status::NotFound)),
+            Some(make_result("Paper", Status::Verified)),
+        ];
+        let paper = make_paper("f.pdf", &stats, &results);
+        let refs = vec![
+            make_ref(0, "Bounds for <i>n</i> < 2^k"),
+            make_ref(1, "Paper"),
+        ];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+        let out = export_html(&[paper], ref_slices);
+        assert!(out.contains("data-filter=\"not-found\""));
+        assert!(out.contains("id=\"search\""));
+        assert!(out.contains("<div class=\"ref-card\" data-verdict=\"not-found\">"));
+        assert!(out.contains("<div class=\"ref-card\" data-verdict=\"verified\">"));
+        assert!(out.contains("Bounds for &lt;i&gt;n&lt;/i&gt; &lt; 2^k"));
+        assert!(!out.contains("<i>n</i>"));
+        // No external stylesheets or scripts
+        assert!(!out.contains("<link"));
+        assert!(!out.contains("<script src"));
+    }
+
+    #[test]
+    fn test_batch_aggregates_stats_and_writes_one_document() {
+        let stats_a = CheckStats {
+            total: 2,
+            verified: 1,
+            not_found: 1,
+            ..Default::default()
+        };
+        let stats_b = CheckStats {
+            total: 1,
+            verified: 1,
+            ..Default::default()
+        };
+        let results_a = vec![
+            Some(make_result("Real", Status::Verified)),
+            Some(make_result("Fake", Status::NotFound)),
+        ];
+        let results_b = vec![Some(make_result("Other", Status::Verified))];
+        let papers = [
+            make_paper("a.pdf", &stats_a, &results_a),
+            make_paper("b.pdf", &stats_b, &results_b),
+        ];
+        let refs_a = vec![make_ref(0, "Real"), make_ref(1, "Fake")];
+        let refs_b = vec![make_ref(0, "Other")];
+        let ref_slices: &[&[ReportRef]] = &[&refs_a, &refs_b];
+
+        let mut buf = Vec::new();
+        let total =
+            export_batch(&papers, ref_slices, ExportFormat::Markdown, false, &mut buf).unwrap();
+        assert_eq!(total.total, 3);
+        assert_eq!(total.verified, 2);
+        assert_eq!(total.not_found, 1);
+
+        let out = String::from_utf8(buf).unwrap();
+        assert_eq!(out.matches("# Hallucinator Results").count(), 1);
+        assert!(out.contains("**2** papers | **3** references"));
+        assert!(out.contains("## a.pdf"));
+        assert!(out.contains("## b.pdf"));
+    }
+
+    #[test]
+    fn test_batch_json_carries_aggregate_stats() {
+        let stats_a = CheckStats {
+            total: 2,
+            verified: 1,
+            not_found: 1,
+            ..Default::default()
+        };
+        let stats_b = CheckStats {
+            total: 1,
+            verified: 1,
+            ..Default::default()
+        };
+        let results_a = vec![
+            Some(make_result("Real", Status::Verified)),
+            Some(make_result("Fake", Status::NotFound)),
+        ];
+        let results_b = vec![Some(make_result("Other", Status::Verified))];
+        let papers = [
+            make_paper("a.pdf", &stats_a, &results_a),
+            make_paper("b.pdf", &stats_b, &results_b),
+        ];
+        let refs_a = vec![make_ref(0, "Real"), make_ref(1, "Fake")];
+        let refs_b = vec![make_ref(0, "Other")];
+        let ref_slices: &[&[ReportRef]] = &[&refs_a, &refs_b];
+
+        let mut buf = Vec::new();
+        export_batch(&papers, ref_slices, ExportFormat::Json, false, &mut buf).unwrap();
+        let out = String::from_utf8(buf).unwrap();
+        assert!(out.starts_with(
+            "{\n  \"schema_version\": 1,\n  \"stats\": {\n    \"total\": 3,\n    \"verified\": 2,\n    \"not_found\": 1,\n    \"author_mismatch\": 0,\n    \"retracted\": 0,\n    \"skipped\": 0,\n    \"problematic_pct\": 33.3\n  },\n"
+        ));
+        assert_eq!(out.matches("\"filename\":").count(), 2);
+    }
+
+    #[test]
+    fn test_batch_single_paper_matches_per_format_export() {
+        let stats = CheckStats {
+            total: 1,
+            verified: 1,
+            ..Default::default()
+        };
+        let results = vec![Some(make_result("Paper", Status::Verified))];
+        let paper = make_paper("f.pdf", &stats, &results);
+        let refs = vec![make_ref(0, "Paper")];
+        let ref_slices: &[&[ReportRef]] = &[&refs];
+
+        let mut buf = Vec::new();
+        export_batch(
+            std::slice::from_ref(&paper),
+            ref_slices,
+            ExportFormat::Json,
+            false,
+            &mut buf,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            export_json(&[paper], ref_slices)
+        );
+    }
 }
@@ -1,5 +1,8 @@
 pub mod export;
 pub mod types;
 
-pub use export::{export_json, export_results};
+pub use export::{
+    JSON_SCHEMA_VERSION, export_batch, export_csv, export_json, export_results, export_ris,
+    export_sarif,
+};
 pub use types::{ExportFormat, FpReason, PaperVerdict, ReportPaper, ReportRef, SkipInfo};
@@ -110,6 +110,8 @@ pub enum ExportFormat {
     Markdown,
     Text,
     Html,
+    Sarif,
+    Ris,
 }
 
 impl ExportFormat {
@@ -120,6 +122,8 @@ impl ExportFormat {
             ExportFormat::Markdown,
             ExportFormat::Text,
             ExportFormat::Html,
+            ExportFormat::Sarif,
+            ExportFormat::Ris,
         ]
     }
 
@@ -130,6 +134,8 @@ impl ExportFormat {
             Self::Markdown => "Markdown",
             Self::Text => "Plain Text",
             Self::Html => "HTML",
+            Self::Sarif => "SARIF",
+            Self::Ris => "RIS",
         }
     }
 
@@ -140,6 +146,8 @@ impl ExportFormat {
             Self::Markdown => "md",
             Self::Text => "txt",
             Self::Html => "html",
+            Self::Sarif => "sarif",
+            Self::Ris => "ris",
         }
     }
 }
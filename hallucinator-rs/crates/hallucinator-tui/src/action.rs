@@ -12,7 +12,9 @@ pub enum Action {
     GoBottom,
     CycleSort,
     CycleFilter,
+    ToggleVerifiedCollapse,
     ToggleHelp,
+    CycleTheme,
     StartSearch,
     SearchInput(char),
     SearchConfirm,
@@ -20,13 +22,19 @@ pub enum Action {
     NextMatch,
     PrevMatch,
     Retry,
-    RetryAll,
+    RetryFailed,
+    CancelPaper,
     Export,
+    ExportProblems,
     AddFiles,
+    AddPaper,
     #[allow(dead_code)] // planned feature: remove paper from queue
     RemovePaper,
     CopyToClipboard,
+    /// `y` in the reference detail view: copy the citation, links and verdict.
+    CopyDetail,
     OpenConfig,
+    OpenSummary,
     ToggleActivityPanel,
     SaveConfig,
     BuildDatabase,
@@ -1,3 +1,4 @@
+use std::collections::{HashMap, VecDeque};
 use std::io::Write;
 use std::path::PathBuf;
 use std::time::Instant;
@@ -18,9 +19,12 @@ use crate::model::paper::{FpReason, PaperFilter, PaperSortOrder, RefPhase, RefSt
 use crate::model::queue::{
     PaperPhase, PaperState, PaperVerdict, QueueFilter, SortOrder, filtered_indices,
 };
+use crate::model::summary::BatchSummary;
 use crate::theme::Theme;
 use crate::tui_event::{BackendCommand, BackendEvent};
+use crate::view::add_paper::AddPaperState;
 use crate::view::export::ExportState;
+use crate::view::{UiConfig, spinner_char};
 
 /// Which screen is currently displayed.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -31,6 +35,8 @@ pub enum Screen {
     RefDetail(usize, usize), // (paper_index, ref_index)
     Config,
     FilePicker,
+    /// Totals for the whole run.
+    Summary,
 }
 
 /// Input mode determines how keyboard input is interpreted.
@@ -77,6 +83,7 @@ pub struct FileEntry {
     pub is_pdf: bool,
     pub is_bbl: bool,
     pub is_bib: bool,
+    pub is_txt: bool,
     pub is_archive: bool,
     pub is_json: bool,
     pub is_db: bool,
@@ -109,6 +116,7 @@ impl FilePickerState {
                 is_pdf: false,
                 is_bbl: false,
                 is_bib: false,
+                is_txt: false,
                 is_archive: false,
                 is_json: false,
                 is_db: false,
@@ -136,6 +144,7 @@ impl FilePickerState {
                         is_pdf: false,
                         is_bbl: false,
                         is_bib: false,
+                        is_txt: false,
                         is_archive: false,
                         is_json: false,
                         is_db: false,
@@ -145,6 +154,7 @@ impl FilePickerState {
                     let is_pdf = ext.map(|e| e.eq_ignore_ascii_case("pdf")).unwrap_or(false);
                     let is_bbl = ext.map(|e| e.eq_ignore_ascii_case("bbl")).unwrap_or(false);
                     let is_bib = ext.map(|e| e.eq_ignore_ascii_case("bib")).unwrap_or(false);
+                    let is_txt = ext.map(|e| e.eq_ignore_ascii_case("txt")).unwrap_or(false);
                     let is_archive = hallucinator_pdf::archive::is_archive_path(&path);
                     let is_json = ext.map(|e| e.eq_ignore_ascii_case("json")).unwrap_or(false);
                     let is_db = ext
@@ -157,6 +167,7 @@ impl FilePickerState {
                         is_pdf,
                         is_bbl,
                         is_bib,
+                        is_txt,
                         is_archive,
                         is_json,
                         is_db,
@@ -164,8 +175,8 @@ impl FilePickerState {
                 }
             }
 
-            dirs.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
-            files.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+            dirs.sort_by_key(|e| e.name.to_lowercase());
+            files.sort_by_key(|e| e.name.to_lowercase());
 
             entries.extend(dirs);
             entries.extend(files);
@@ -176,12 +187,13 @@ impl FilePickerState {
         self.scroll_offset = 0;
     }
 
-    /// Toggle selection of the current entry (PDFs, .bbl, .bib files, archives, .json results, and .db/.sqlite).
+    /// Toggle selection of the current entry (PDFs, .bbl, .bib, .txt files, archives, .json results, and .db/.sqlite).
     pub fn toggle_selected(&mut self) {
         if let Some(entry) = self.entries.get(self.cursor)
             && (entry.is_pdf
                 || entry.is_bbl
                 || entry.is_bib
+                || entry.is_txt
                 || entry.is_archive
                 || entry.is_json
                 || entry.is_db)
@@ -211,6 +223,31 @@ impl FilePickerState {
     }
 }
 
+/// Settings that determine how a [`hallucinator_core::CheckContext`] is built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CheckContextKey {
+    cache_path: Option<PathBuf>,
+    cache_max_entries: usize,
+    has_crossref_mailto: bool,
+    has_s2_api_key: bool,
+    has_ncbi_api_key: bool,
+    user_agent: String,
+    proxy: Option<String>,
+    extra_ca_cert: Option<PathBuf>,
+}
+
+/// Number of recent reference completions the ETA averages over.
+const ETA_WINDOW: usize = 50;
+
+/// Completions needed before the ETA is shown; earlier rates swing wildly.
+const ETA_MIN_SAMPLES: usize = 5;
+
+/// How long a footer flash message stays visible.
+const FLASH_DURATION: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Two clicks on the same row within this long count as a double-click.
+const DOUBLE_CLICK: std::time::Duration = std::time::Duration::from_millis(400);
+
 /// Main application state.
 pub struct App {
     pub screen: Screen,
@@ -224,11 +261,19 @@ pub struct App {
     pub queue_sorted: Vec<usize>,
     pub tick: usize,
     pub theme: Theme,
+    /// Spinner glyph set and speed.
+    pub ui: UiConfig,
     pub should_quit: bool,
     pub confirm_quit: bool,
     pub batch_complete: bool,
     pub show_help: bool,
     pub detail_scroll: u16,
+    /// Scroll offset last used for each `(paper_idx, ref_idx)` detail view,
+    /// restored when drilling back into that reference.
+    pub detail_scroll_positions: HashMap<(usize, usize), u16>,
+    /// Largest useful `detail_scroll` for the wrapped detail content at the
+    /// current width; updated on every RefDetail render.
+    pub detail_max_scroll: u16,
     /// Height of the visible table area (set on resize, used for page up/down).
     pub visible_rows: usize,
 
@@ -238,7 +283,15 @@ pub struct App {
     pub queue_filter: QueueFilter,
     pub paper_filter: PaperFilter,
     pub paper_sort: PaperSortOrder,
+    /// Hide verified, non-retracted references in the Paper view.
+    pub collapse_verified: bool,
     pub activity_panel_visible: bool,
+    /// Short confirmation shown in the footer until [`FLASH_DURATION`]
+    /// has passed, e.g. after a copy.
+    flash: Option<(String, Instant)>,
+    /// Kept alive so X11/Wayland can keep serving copied text.
+    #[cfg(feature = "clipboard")]
+    clipboard: Option<arboard::Clipboard>,
     pub start_time: Option<Instant>,
     /// Frozen elapsed time (set on cancel or batch complete).
     pub frozen_elapsed: Option<std::time::Duration>,
@@ -248,6 +301,7 @@ pub struct App {
     pub activity: ActivityState,
     pub config_state: ConfigState,
     pub export_state: ExportState,
+    pub add_paper: AddPaperState,
 
     /// Wall-clock instant when the banner was first shown.
     pub banner_start: Option<Instant>,
@@ -271,10 +325,17 @@ pub struct App {
     pub file_paths: Vec<PathBuf>,
     /// Last table area rendered (for mouse click → row mapping).
     pub last_table_area: Option<Rect>,
+    /// Rows scrolled off the top of that table when it was rendered.
+    pub last_table_offset: usize,
+    /// Row and time of the previous click, to detect double-clicks.
+    last_click: Option<(usize, Instant)>,
     /// Throughput counter: refs completed since last throughput bucket push.
     throughput_since_last: u16,
     /// Tick count of last throughput push.
     last_throughput_tick: usize,
+    /// When the most recent references completed (across all papers),
+    /// oldest first, capped at [`ETA_WINDOW`] — drives the queue ETA.
+    ref_completions: VecDeque<Instant>,
     /// File picker state.
     pub file_picker: FilePickerState,
     /// Context for the file picker (add files vs select database).
@@ -285,6 +346,9 @@ pub struct App {
     pub pending_archive_extractions: Vec<PathBuf>,
     /// Name of the archive currently being extracted (shown in UI).
     pub extracting_archive: Option<String>,
+    /// Banner text while the offline DBLP database is older than
+    /// `dblp_max_age_days`, e.g. "DBLP DB built 2024-02-29 (45 days ago)".
+    pub dblp_stale_notice: Option<String>,
     /// Receiver for streaming archive extraction (PDFs arrive one at a time).
     archive_rx: Option<std::sync::mpsc::Receiver<ArchiveItem>>,
     /// Name of the archive being streamed (for display name prefix).
@@ -293,10 +357,15 @@ pub struct App {
     pub extracted_count: usize,
     /// Number of `run_batch_with_offset` tasks still running.
     inflight_batches: usize,
+    /// Checkpoint of the batch's results, rewritten as they come in (`--checkpoint`).
+    pub checkpoint: Option<crate::checkpoint::Checkpoint>,
     /// Rate limiters for the current run (shared with backend for backoff state).
     pub current_rate_limiters: Option<std::sync::Arc<hallucinator_core::RateLimiters>>,
     /// Query cache for the current run (shared with backend for cache stats).
     pub current_query_cache: Option<std::sync::Arc<hallucinator_core::QueryCache>>,
+    /// Shared cache/limiters/client, reused across batches while the settings
+    /// it was built from are unchanged.
+    check_context: Option<(CheckContextKey, hallucinator_core::CheckContext)>,
     /// Frame counter for FPS measurement.
     frame_count: u32,
     /// Last time FPS was sampled.
@@ -324,24 +393,32 @@ impl App {
             queue_sorted,
             tick: 0,
             theme,
+            ui: UiConfig::default(),
             should_quit: false,
             confirm_quit: false,
             batch_complete: false,
             show_help: false,
             detail_scroll: 0,
+            detail_scroll_positions: HashMap::new(),
+            detail_max_scroll: u16::MAX,
             visible_rows: 20,
             input_mode: InputMode::Normal,
             search_query: String::new(),
             queue_filter: QueueFilter::All,
             paper_filter: PaperFilter::All,
             paper_sort: PaperSortOrder::Verdict,
+            collapse_verified: false,
             activity_panel_visible: true,
+            flash: None,
+            #[cfg(feature = "clipboard")]
+            clipboard: None,
             start_time: None,
             frozen_elapsed: None,
             single_paper_mode: false,
             activity: ActivityState::default(),
             config_state: ConfigState::default(),
             export_state: ExportState::default(),
+            add_paper: AddPaperState::default(),
             banner_start: None, // set in main.rs after config is applied
             pending_bell: false,
             tip_index: 0,
@@ -351,19 +428,25 @@ impl App {
             backend_cmd_tx: None,
             file_paths: Vec::new(),
             last_table_area: None,
+            last_table_offset: 0,
+            last_click: None,
             throughput_since_last: 0,
             last_throughput_tick: 0,
+            ref_completions: VecDeque::new(),
             file_picker: FilePickerState::new(),
             file_picker_context: FilePickerContext::AddFiles,
             temp_dir: None,
             pending_archive_extractions: Vec::new(),
             extracting_archive: None,
+            dblp_stale_notice: None,
             archive_rx: None,
             archive_streaming_name: None,
             extracted_count: 0,
             inflight_batches: 0,
+            checkpoint: None,
             current_rate_limiters: None,
             current_query_cache: None,
+            check_context: None,
             frame_count: 0,
             last_fps_instant: Instant::now(),
             measured_fps: 0.0,
@@ -424,17 +507,19 @@ impl App {
         let mut indices: Vec<usize> = (0..refs.len()).collect();
 
         if self.paper_filter == PaperFilter::ProblemsOnly {
-            indices.retain(|&i| {
-                refs[i].result.as_ref().is_some_and(|r| {
-                    r.status != hallucinator_core::Status::Verified
-                        || r.retraction_info.as_ref().is_some_and(|ri| ri.is_retracted)
-                })
-            });
+            indices.retain(|&i| is_problem(&refs[i]));
+        }
+
+        if self.collapse_verified {
+            indices.retain(|&i| !is_clean_verified(&refs[i]));
         }
 
         if !self.search_query.is_empty() {
             let query = self.search_query.to_lowercase();
-            indices.retain(|&i| refs[i].title.to_lowercase().contains(&query));
+            indices.retain(|&i| {
+                refs[i].title.to_lowercase().contains(&query)
+                    || refs[i].raw_citation.to_lowercase().contains(&query)
+            });
         }
 
         match self.paper_sort {
@@ -453,11 +538,72 @@ impl App {
                     sa.cmp(sb).then_with(|| a.cmp(&b))
                 });
             }
+            PaperSortOrder::MatchScore => {
+                let score = |i: usize| refs[i].result.as_ref().and_then(|r| r.match_score);
+                indices.sort_by(|&a, &b| {
+                    match (score(a), score(b)) {
+                        (Some(sa), Some(sb)) => sa.total_cmp(&sb),
+                        (Some(_), None) => std::cmp::Ordering::Less,
+                        (None, Some(_)) => std::cmp::Ordering::Greater,
+                        (None, None) => std::cmp::Ordering::Equal,
+                    }
+                    .then_with(|| a.cmp(&b))
+                });
+            }
         }
 
         indices
     }
 
+    /// Current spinner glyph. With `ui.tick_ms` set, the frame follows
+    /// elapsed time rather than the redraw rate.
+    pub fn spinner(&self) -> char {
+        let frame = match self.ui.tick_ms {
+            Some(ms) => {
+                let frame_ms = 1000 / self.config_state.fps.max(1) as u64;
+                (self.tick as u64 * frame_ms / ms.max(1)) as usize
+            }
+            None => self.tick,
+        };
+        spinner_char(self.ui.spinner, frame)
+    }
+
+    /// Number of references hidden from the Paper view by `collapse_verified`.
+    pub fn hidden_verified_count(&self, paper_index: usize) -> usize {
+        if !self.collapse_verified {
+            return 0;
+        }
+        self.ref_states[paper_index]
+            .iter()
+            .filter(|rs| is_clean_verified(rs))
+            .count()
+    }
+
+    /// Number of references the paper filter hides in a paper's view.
+    pub fn filtered_out_count(&self, paper_index: usize) -> usize {
+        match self.paper_filter {
+            PaperFilter::All => 0,
+            PaperFilter::ProblemsOnly => self.ref_states[paper_index]
+                .iter()
+                .filter(|rs| !is_problem(rs))
+                .count(),
+        }
+    }
+
+    /// Apply `change` to the paper view's filtering, keeping the cursor on
+    /// the same reference when it stays visible.
+    fn refilter_paper(&mut self, paper_index: usize, change: impl FnOnce(&mut Self)) {
+        let selected = self
+            .paper_ref_indices(paper_index)
+            .get(self.paper_cursor)
+            .copied();
+        change(self);
+        let indices = self.paper_ref_indices(paper_index);
+        self.paper_cursor = selected
+            .and_then(|ri| indices.iter().position(|&i| i == ri))
+            .unwrap_or_else(|| self.paper_cursor.min(indices.len().saturating_sub(1)));
+    }
+
     /// Get the paper index for the currently viewed paper (if any).
     fn current_paper_index(&self) -> Option<usize> {
         match self.screen {
@@ -487,22 +633,29 @@ impl App {
                 .map(|i| self.paper_export_stem(i))
                 .unwrap_or_else(|| "hallucinator-results".to_string()),
             crate::view::export::ExportScope::AllPapers => "hallucinator-results".to_string(),
+            crate::view::export::ExportScope::Problems => "hallucinator-problems".to_string(),
         }
     }
 
     /// Send a start command to the backend if not already started.
+    /// The queued files from position `from` on, with their names in the
+    /// queue, leaving out the placeholders of loaded results.
+    fn files_to_check(&self, from: usize) -> Vec<(PathBuf, String)> {
+        self.file_paths
+            .iter()
+            .zip(&self.papers)
+            .skip(from)
+            .filter(|(path, _)| path.as_os_str() != "")
+            .map(|(path, paper)| (path.clone(), paper.filename.clone()))
+            .collect()
+    }
+
     pub fn start_processing(&mut self) {
         if self.processing_started {
             return;
         }
 
-        // Filter out placeholder paths (from loaded results)
-        let real_files: Vec<PathBuf> = self
-            .file_paths
-            .iter()
-            .filter(|p| p.as_os_str() != "")
-            .cloned()
-            .collect();
+        let real_files = self.files_to_check(0);
 
         if real_files.is_empty() {
             return;
@@ -516,6 +669,7 @@ impl App {
         self.activity = ActivityState::default();
         self.throughput_since_last = 0;
         self.last_throughput_tick = self.tick;
+        self.ref_completions.clear();
 
         // Reset all paper/ref state to avoid double-counting on restart
         for paper in &mut self.papers {
@@ -529,20 +683,115 @@ impl App {
             rs.clear();
         }
 
-        if let Some(tx) = &self.backend_cmd_tx {
-            let config = self.build_config();
-            // Keep references to rate limiters and cache for the activity panel
-            self.current_rate_limiters = Some(config.rate_limiters.clone());
-            self.current_query_cache = config.query_cache.clone();
+        if let Some(tx) = self.backend_cmd_tx.clone() {
+            let (config, context) = self.build_config_with_context();
             let _ = tx.send(BackendCommand::ProcessFiles {
                 files: real_files,
                 starting_index: 0,
                 config: Box::new(config),
+                context,
             });
             self.inflight_batches += 1;
         }
     }
 
+    /// Write an export report covering `paper_indices` in `format`.
+    pub fn write_report<W: Write>(
+        &self,
+        paper_indices: &[usize],
+        format: hallucinator_reporting::ExportFormat,
+        writer: &mut W,
+    ) -> std::io::Result<hallucinator_core::CheckStats> {
+        self.write_report_filtered(paper_indices, format, writer, false)
+    }
+
+    /// Like [`write_report`](Self::write_report), but with `problems_only`
+    /// every reference that is not an unflagged problem is left out, and the
+    /// counts cover only those that remain.
+    fn write_report_filtered<W: Write>(
+        &self,
+        paper_indices: &[usize],
+        format: hallucinator_reporting::ExportFormat,
+        writer: &mut W,
+        problems_only: bool,
+    ) -> std::io::Result<hallucinator_core::CheckStats> {
+        let keep = |rs: &RefState| !problems_only || is_exported_problem(rs);
+        // Build full results from ref_states for export
+        let results_vecs: Vec<Vec<Option<hallucinator_core::ValidationResult>>> = paper_indices
+            .iter()
+            .map(|&i| {
+                self.ref_states
+                    .get(i)
+                    .map(|refs| {
+                        refs.iter()
+                            .map(|rs| rs.result.clone().filter(|_| keep(rs)))
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            })
+            .collect();
+        let problem_stats: Vec<hallucinator_core::CheckStats> = if problems_only {
+            results_vecs
+                .iter()
+                .map(|results| {
+                    let kept: Vec<_> = results.iter().flatten().cloned().collect();
+                    hallucinator_core::CheckStats::from_results(&kept, 0)
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let report_papers: Vec<hallucinator_reporting::ReportPaper<'_>> = paper_indices
+            .iter()
+            .zip(results_vecs.iter())
+            .enumerate()
+            .filter_map(|(k, (&i, results))| {
+                let paper = self.papers.get(i)?;
+                Some(hallucinator_reporting::ReportPaper {
+                    filename: &paper.filename,
+                    stats: problem_stats.get(k).unwrap_or(&paper.stats),
+                    results,
+                    verdict: paper.verdict,
+                })
+            })
+            .collect();
+        let report_refs: Vec<Vec<hallucinator_reporting::ReportRef>> = paper_indices
+            .iter()
+            .map(|&i| {
+                self.ref_states
+                    .get(i)
+                    .map(|refs| {
+                        refs.iter()
+                            .map(|rs| hallucinator_reporting::ReportRef {
+                                index: rs.index,
+                                title: rs.title.clone(),
+                                skip_info: if let RefPhase::Skipped(reason) = &rs.phase
+                                    && !problems_only
+                                {
+                                    Some(hallucinator_reporting::SkipInfo {
+                                        reason: reason.clone(),
+                                    })
+                                } else {
+                                    None
+                                },
+                                fp_reason: rs.fp_reason,
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            })
+            .collect();
+        let ref_slices: Vec<&[hallucinator_reporting::ReportRef]> =
+            report_refs.iter().map(|v| v.as_slice()).collect();
+        hallucinator_reporting::export_batch(
+            &report_papers,
+            &ref_slices,
+            format,
+            self.config_state.author_mismatch_is_failure,
+            writer,
+        )
+    }
+
     /// Build a `hallucinator_core::Config` from the current ConfigState.
     fn build_config(&self) -> hallucinator_core::Config {
         let disabled_dbs: Vec<String> = self
@@ -564,6 +813,16 @@ impl App {
             } else {
                 Some(self.config_state.s2_api_key.clone())
             },
+            ncbi_api_key: if self.config_state.ncbi_api_key.is_empty() {
+                None
+            } else {
+                Some(self.config_state.ncbi_api_key.clone())
+            },
+            core_api_key: if self.config_state.core_api_key.is_empty() {
+                None
+            } else {
+                Some(self.config_state.core_api_key.clone())
+            },
             dblp_offline_path: if self.config_state.dblp_offline_path.is_empty() {
                 None
             } else {
@@ -572,6 +831,7 @@ impl App {
                 ))
             },
             dblp_offline_db: None, // Populated from main.rs
+            dblp_max_age_days: self.config_state.dblp_max_age_days,
             acl_offline_path: if self.config_state.acl_offline_path.is_empty() {
                 None
             } else {
@@ -580,36 +840,131 @@ impl App {
                 ))
             },
             acl_offline_db: None, // Populated from main.rs
+            local_bib_path: if self.config_state.local_bib_path.is_empty() {
+                None
+            } else {
+                Some(std::path::PathBuf::from(&self.config_state.local_bib_path))
+            },
+            local_bib: None,
+            retraction_db_path: if self.config_state.retraction_db_path.is_empty() {
+                None
+            } else {
+                Some(std::path::PathBuf::from(
+                    &self.config_state.retraction_db_path,
+                ))
+            },
+            retraction_db: None,
+            retraction_max_age_days: self.config_state.retraction_max_age_days,
             num_workers: self.config_state.num_workers,
+            max_concurrent_papers: self.config_state.max_concurrent_papers,
+            dedup: self.config_state.dedup,
+            adaptive_concurrency: self.config_state.adaptive_concurrency,
             max_rate_limit_retries: self.config_state.max_rate_limit_retries,
-            rate_limiters: std::sync::Arc::new(hallucinator_core::RateLimiters::new(
-                !self.config_state.crossref_mailto.is_empty(),
-                !self.config_state.s2_api_key.is_empty(),
-            )),
+            // Batches run on the pool directly; failed references are
+            // retried on request instead.
+            max_retry_passes: 0,
+            max_references: (self.config_state.max_references > 0)
+                .then_some(self.config_state.max_references),
+            run_deadline: None,
+            // Cache and rate limiters come from the shared CheckContext
+            // (see `build_config_with_context`).
+            rate_limiters: std::sync::Arc::new(hallucinator_core::RateLimiters::default()),
             db_timeout_secs: self.config_state.db_timeout_secs,
             db_timeout_short_secs: self.config_state.db_timeout_short_secs,
+            db_timeouts: self
+                .config_state
+                .db_timeouts
+                .iter()
+                .map(|(name, secs)| (name.clone(), *secs))
+                .collect(),
             disabled_dbs,
+            only_dbs: self.config_state.only_dbs.clone(),
+            per_db_threshold: self
+                .config_state
+                .per_db_threshold
+                .iter()
+                .map(|(name, t)| (name.clone(), *t))
+                .collect(),
+            author_match_min_overlap: self.config_state.author_min_overlap,
+            require_first_author_match: self.config_state.require_first_author,
+            author_match_threshold: self.config_state.author_match_threshold,
+            min_match_score: self.config_state.min_match_score,
+            consensus_mode: self.config_state.consensus_mode,
+            consensus_min: self.config_state.consensus_min,
+            strict_title: self.config_state.strict_title,
+            orcid_check: self.config_state.orcid_check,
+            // Only ranks exit statuses, which the TUI has none of
+            author_mismatch_is_failure: self.config_state.author_mismatch_is_failure,
             check_openalex_authors: false,
             crossref_mailto: if self.config_state.crossref_mailto.is_empty() {
                 None
             } else {
                 Some(self.config_state.crossref_mailto.clone())
             },
+            user_agent: if self.config_state.user_agent.is_empty() {
+                None
+            } else {
+                Some(self.config_state.user_agent.clone())
+            },
+            proxy: if self.config_state.proxy.is_empty() {
+                None
+            } else {
+                Some(self.config_state.proxy.clone())
+            },
+            extra_ca_cert: if self.config_state.extra_ca_cert.is_empty() {
+                None
+            } else {
+                Some(PathBuf::from(&self.config_state.extra_ca_cert))
+            },
             cache_path: if self.config_state.cache_path.is_empty() {
                 None
             } else {
                 Some(std::path::PathBuf::from(&self.config_state.cache_path))
             },
-            query_cache: Some(hallucinator_core::build_query_cache(
-                if self.config_state.cache_path.is_empty() {
-                    None
-                } else {
-                    Some(std::path::Path::new(&self.config_state.cache_path))
-                },
-            )),
+            cache_max_entries: self.config_state.cache_max_entries,
+            query_cache: None,
         }
     }
 
+    /// Build a config together with the shared [`CheckContext`] it should run with.
+    ///
+    /// The context (query cache, rate limiters, HTTP client) is reused across
+    /// batches and retries so cache hits and rate-limit backoff carry over. It
+    /// is rebuilt only when a setting it depends on changes.
+    ///
+    /// [`CheckContext`]: hallucinator_core::CheckContext
+    pub fn build_config_with_context(
+        &mut self,
+    ) -> (hallucinator_core::Config, hallucinator_core::CheckContext) {
+        let mut config = self.build_config();
+        let key = CheckContextKey {
+            cache_path: config.cache_path.clone(),
+            cache_max_entries: config.cache_max_entries,
+            has_crossref_mailto: config.crossref_mailto.is_some(),
+            has_s2_api_key: config.s2_api_key.is_some(),
+            has_ncbi_api_key: config.ncbi_api_key.is_some(),
+            user_agent: config.user_agent(),
+            proxy: config.proxy.clone(),
+            extra_ca_cert: config.extra_ca_cert.clone(),
+        };
+        let context = match &self.check_context {
+            Some((k, ctx)) if *k == key => ctx.clone(),
+            _ => {
+                if let Err(e) = hallucinator_core::context::try_build_http_client(&config) {
+                    self.activity.log_warn(e);
+                }
+                let ctx = hallucinator_core::CheckContext::new(&config);
+                self.check_context = Some((key, ctx.clone()));
+                ctx
+            }
+        };
+        context.apply_to(&mut config);
+        // Keep references to rate limiters and cache for the activity panel
+        self.current_rate_limiters = Some(context.rate_limiters.clone());
+        self.current_query_cache = context.cache.clone();
+        (config, context)
+    }
+
     /// Add files from file picker to the paper queue.
     /// PDFs are added directly. Archives are queued for deferred extraction
     /// (one per tick) so the UI can show progress. JSON result files are loaded
@@ -675,6 +1030,75 @@ impl App {
         self.recompute_sorted_indices();
     }
 
+    /// Handle Enter in the add-path prompt.
+    ///
+    /// An empty path or a directory opens the file picker there; an existing
+    /// file is appended to the queue and checked right away. A path that does
+    /// not exist keeps the prompt open with an error.
+    fn submit_add_paper(&mut self) {
+        let input = self.add_paper.buffer.trim();
+        let path = match input.strip_prefix("~/").zip(dirs::home_dir()) {
+            Some((rest, home)) => home.join(rest),
+            None => PathBuf::from(input),
+        };
+
+        if input.is_empty() || path.is_dir() {
+            if path.is_dir() {
+                self.file_picker.current_dir = path.canonicalize().unwrap_or(path);
+                self.file_picker.cursor = 0;
+                self.file_picker.scroll_offset = 0;
+                self.file_picker.refresh_entries();
+            }
+            self.add_paper = AddPaperState::default();
+            self.input_mode = InputMode::Normal;
+            self.file_picker_context = FilePickerContext::AddFiles;
+            self.screen = Screen::FilePicker;
+            return;
+        }
+
+        if !path.is_file() {
+            self.add_paper.error = Some(format!("No such file: {}", path.display()));
+            return;
+        }
+
+        self.add_paper = AddPaperState::default();
+        self.input_mode = InputMode::Normal;
+
+        let first_new = self.papers.len();
+        self.file_picker.selected = vec![path];
+        self.add_files_from_picker();
+
+        // Leave single-paper mode so the queue shows the new entry.
+        self.single_paper_mode = false;
+        self.screen = Screen::Queue;
+        if let Some(row) = self.queue_sorted.iter().position(|&i| i == first_new) {
+            self.queue_cursor = row;
+        }
+
+        if !self.processing_started {
+            self.start_processing();
+            return;
+        }
+
+        let new_files = self.files_to_check(first_new);
+        if new_files.is_empty() {
+            return;
+        }
+        if let Some(tx) = self.backend_cmd_tx.clone() {
+            let (config, context) = self.build_config_with_context();
+            let _ = tx.send(BackendCommand::ProcessFiles {
+                files: new_files,
+                starting_index: first_new,
+                config: Box::new(config),
+                context,
+            });
+            self.inflight_batches += 1;
+            self.batch_complete = false;
+            self.frozen_elapsed = None;
+            self.start_time.get_or_insert_with(Instant::now);
+        }
+    }
+
     /// Start streaming extraction for the next pending archive.
     /// Spawns a background thread that extracts PDFs one-by-one,
     /// sending them through a channel that the tick handler drains.
@@ -738,7 +1162,7 @@ impl App {
 
         let archive_name = self.archive_streaming_name.clone().unwrap_or_default();
         let mut finished = false;
-        let mut new_pdfs: Vec<PathBuf> = Vec::new();
+        let mut new_pdfs = 0;
 
         loop {
             match rx.try_recv() {
@@ -747,7 +1171,7 @@ impl App {
                     let display_name = format!("{}/{}", archive_name, pdf.filename);
                     self.papers.push(PaperState::new(display_name));
                     self.ref_states.push(Vec::new());
-                    new_pdfs.push(pdf.path.clone());
+                    new_pdfs += 1;
                     self.file_paths.push(pdf.path);
                 }
                 Ok(ArchiveItem::Warning(msg)) => {
@@ -778,19 +1202,20 @@ impl App {
             }
         }
 
-        let got_new = !new_pdfs.is_empty();
+        let got_new = new_pdfs > 0;
 
         // If processing is already started, send newly extracted PDFs to backend
         if self.processing_started
             && got_new
-            && let Some(tx) = &self.backend_cmd_tx
+            && let Some(tx) = self.backend_cmd_tx.clone()
         {
-            let starting_index = self.file_paths.len() - new_pdfs.len();
-            let config = self.build_config();
+            let starting_index = self.file_paths.len() - new_pdfs;
+            let (config, context) = self.build_config_with_context();
             let _ = tx.send(BackendCommand::ProcessFiles {
-                files: new_pdfs,
+                files: self.files_to_check(starting_index),
                 starting_index,
                 config: Box::new(config),
+                context,
             });
             self.inflight_batches += 1;
         }
@@ -886,13 +1311,14 @@ impl App {
                     self.export_state.active = false;
                 }
                 Action::MoveDown => {
-                    self.export_state.cursor = (self.export_state.cursor + 1).min(3);
+                    self.export_state.cursor =
+                        (self.export_state.cursor + 1).min(crate::view::export::CONFIRM_ROW);
                 }
                 Action::MoveUp => {
                     self.export_state.cursor = self.export_state.cursor.saturating_sub(1);
                 }
                 Action::DrillIn => match self.export_state.cursor {
-                    0 => {
+                    crate::view::export::FORMAT_ROW => {
                         let formats = crate::view::export::ExportFormat::all();
                         let idx = formats
                             .iter()
@@ -900,32 +1326,37 @@ impl App {
                             .unwrap_or(0);
                         self.export_state.format = formats[(idx + 1) % formats.len()];
                     }
-                    1 => {
+                    crate::view::export::SCOPE_ROW => {
                         self.export_state.scope = match self.export_state.scope {
                             crate::view::export::ExportScope::ThisPaper => {
                                 crate::view::export::ExportScope::AllPapers
                             }
                             crate::view::export::ExportScope::AllPapers => {
+                                crate::view::export::ExportScope::Problems
+                            }
+                            crate::view::export::ExportScope::Problems => {
                                 crate::view::export::ExportScope::ThisPaper
                             }
                         };
                         self.export_state.output_path =
                             self.export_default_path(self.export_state.scope);
                     }
-                    2 => {
+                    crate::view::export::PATH_ROW => {
                         // Start editing the output path
                         self.export_state.editing_path = true;
                         self.export_state.edit_buffer = self.export_state.output_path.clone();
                         self.input_mode = InputMode::TextInput;
                     }
-                    3 => {
+                    crate::view::export::CONFIRM_ROW => {
                         let path = format!(
                             "{}.{}",
                             self.export_state.output_path,
                             self.export_state.format.extension()
                         );
-                        let paper_indices = match self.export_state.scope {
-                            crate::view::export::ExportScope::AllPapers => {
+                        let scope = self.export_state.scope;
+                        let paper_indices = match scope {
+                            crate::view::export::ExportScope::AllPapers
+                            | crate::view::export::ExportScope::Problems => {
                                 (0..self.papers.len()).collect::<Vec<_>>()
                             }
                             crate::view::export::ExportScope::ThisPaper => {
@@ -940,69 +1371,36 @@ impl App {
                                     .unwrap_or_else(|| (0..self.papers.len()).collect())
                             }
                         };
-                        // Build full results from ref_states for export
-                        let results_vecs: Vec<Vec<Option<hallucinator_core::ValidationResult>>> =
-                            paper_indices
-                                .iter()
-                                .map(|&i| {
-                                    self.ref_states
-                                        .get(i)
-                                        .map(|refs| {
-                                            refs.iter().map(|rs| rs.result.clone()).collect()
-                                        })
-                                        .unwrap_or_default()
-                                })
-                                .collect();
-                        let report_papers: Vec<hallucinator_reporting::ReportPaper<'_>> =
-                            paper_indices
-                                .iter()
-                                .zip(results_vecs.iter())
-                                .filter_map(|(&i, results)| {
-                                    let paper = self.papers.get(i)?;
-                                    Some(hallucinator_reporting::ReportPaper {
-                                        filename: &paper.filename,
-                                        stats: &paper.stats,
-                                        results,
-                                        verdict: paper.verdict,
-                                    })
-                                })
-                                .collect();
-                        let report_refs: Vec<Vec<hallucinator_reporting::ReportRef>> =
-                            paper_indices
-                                .iter()
-                                .map(|&i| {
-                                    self.ref_states
-                                        .get(i)
-                                        .map(|refs| {
-                                            refs.iter()
-                                                .map(|rs| hallucinator_reporting::ReportRef {
-                                                    index: rs.index,
-                                                    title: rs.title.clone(),
-                                                    skip_info: if let RefPhase::Skipped(reason) =
-                                                        &rs.phase
-                                                    {
-                                                        Some(hallucinator_reporting::SkipInfo {
-                                                            reason: reason.clone(),
-                                                        })
-                                                    } else {
-                                                        None
-                                                    },
-                                                    fp_reason: rs.fp_reason,
-                                                })
-                                                .collect()
-                                        })
-                                        .unwrap_or_default()
-                                })
-                                .collect();
-                        let ref_slices: Vec<&[hallucinator_reporting::ReportRef]> =
-                            report_refs.iter().map(|v| v.as_slice()).collect();
-                        match hallucinator_reporting::export_results(
-                            &report_papers,
-                            &ref_slices,
-                            self.export_state.format,
-                            std::path::Path::new(&path),
-                        ) {
-                            Ok(()) => {
+                        let problems_only = scope == crate::view::export::ExportScope::Problems;
+                        let written = std::fs::File::create(&path).and_then(|mut file| {
+                            self.write_report_filtered(
+                                &paper_indices,
+                                self.export_state.format,
+                                &mut file,
+                                problems_only,
+                            )
+                        });
+                        match written {
+                            Ok(_) if problems_only => {
+                                let count = self
+                                    .ref_states
+                                    .iter()
+                                    .flatten()
+                                    .filter(|rs| is_exported_problem(rs))
+                                    .count();
+                                let msg = if count == 0 {
+                                    format!(
+                                        "No problem references; wrote an empty report to {}",
+                                        path
+                                    )
+                                } else {
+                                    format!("Wrote {} problem references to {}", count, path)
+                                };
+                                self.export_state.message = Some(msg.clone());
+                                self.export_state.active = false;
+                                self.flash = Some((msg, Instant::now()));
+                            }
+                            Ok(_) => {
                                 self.export_state.message = Some(format!("Saved to {}", path));
                             }
                             Err(e) => {
@@ -1020,6 +1418,36 @@ impl App {
             return false;
         }
 
+        // Add-path prompt intercepts
+        if self.add_paper.active {
+            match action {
+                Action::Quit => {
+                    self.should_quit = true;
+                    return true;
+                }
+                Action::SearchCancel => {
+                    self.add_paper = AddPaperState::default();
+                    self.input_mode = InputMode::Normal;
+                }
+                Action::SearchConfirm => {
+                    self.submit_add_paper();
+                }
+                Action::SearchInput(ch) => {
+                    if ch == '\x08' {
+                        self.add_paper.buffer.pop();
+                    } else {
+                        self.add_paper.buffer.push(ch);
+                    }
+                    self.add_paper.error = None;
+                }
+                Action::Tick => {
+                    self.tick = self.tick.wrapping_add(1);
+                }
+                _ => {}
+            }
+            return false;
+        }
+
         // Help overlay
         if self.show_help {
             match action {
@@ -1237,7 +1665,7 @@ impl App {
                         }
                     }
                 }
-                Action::OpenConfig => {
+                Action::OpenConfig | Action::ToggleVerifiedCollapse => {
                     self.config_state.prev_screen = Some(self.screen.clone());
                     self.screen = Screen::Config;
                 }
@@ -1262,9 +1690,14 @@ impl App {
             Action::ToggleHelp => {
                 self.show_help = true;
             }
+            Action::CycleTheme => {
+                self.cycle_theme();
+            }
             Action::NavigateBack => match &self.screen {
-                Screen::RefDetail(paper_idx, _) => {
+                Screen::RefDetail(paper_idx, ref_idx) => {
                     let paper_idx = *paper_idx;
+                    self.detail_scroll_positions
+                        .insert((paper_idx, *ref_idx), self.detail_scroll);
                     self.screen = Screen::Paper(paper_idx);
                 }
                 Screen::Paper(paper_idx) => {
@@ -1272,6 +1705,8 @@ impl App {
                         let paper_idx = *paper_idx;
                         self.screen = Screen::Queue;
                         self.paper_cursor = 0;
+                        self.search_query.clear();
+                        self.recompute_sorted_indices();
                         // Restore cursor to the same paper even if sort order changed
                         self.queue_cursor = self
                             .queue_sorted
@@ -1307,6 +1742,7 @@ impl App {
                         }
                     }
                 }
+                Screen::Summary => self.screen = Screen::Queue,
                 Screen::Banner | Screen::FilePicker => {}
             },
             Action::DrillIn => match &self.screen {
@@ -1315,6 +1751,8 @@ impl App {
                         let paper_idx = self.queue_sorted[self.queue_cursor];
                         self.screen = Screen::Paper(paper_idx);
                         self.paper_cursor = 0;
+                        // A filename search means nothing among references.
+                        self.search_query.clear();
                     }
                 }
                 Screen::Paper(idx) => {
@@ -1322,7 +1760,13 @@ impl App {
                     let indices = self.paper_ref_indices(idx);
                     if self.paper_cursor < indices.len() {
                         let ref_idx = indices[self.paper_cursor];
-                        self.detail_scroll = 0;
+                        self.detail_scroll = self
+                            .detail_scroll_positions
+                            .get(&(idx, ref_idx))
+                            .copied()
+                            .unwrap_or(0);
+                        // Unknown until the new reference is rendered.
+                        self.detail_max_scroll = u16::MAX;
                         self.screen = Screen::RefDetail(idx, ref_idx);
                     }
                 }
@@ -1330,7 +1774,7 @@ impl App {
                     // Enter on config: start editing the current field
                     self.handle_config_enter();
                 }
-                Screen::RefDetail(..) | Screen::Banner | Screen::FilePicker => {}
+                Screen::RefDetail(..) | Screen::Banner | Screen::FilePicker | Screen::Summary => {}
             },
             Action::MoveDown => match &self.screen {
                 Screen::Queue => {
@@ -1345,7 +1789,10 @@ impl App {
                     }
                 }
                 Screen::RefDetail(..) => {
-                    self.detail_scroll = self.detail_scroll.saturating_add(1);
+                    self.detail_scroll = self
+                        .detail_scroll
+                        .saturating_add(1)
+                        .min(self.detail_max_scroll);
                 }
                 Screen::Config => {
                     let max = self.config_section_item_count().saturating_sub(1);
@@ -1353,7 +1800,7 @@ impl App {
                         self.config_state.item_cursor += 1;
                     }
                 }
-                Screen::Banner | Screen::FilePicker => {}
+                Screen::Banner | Screen::FilePicker | Screen::Summary => {}
             },
             Action::MoveUp => match &self.screen {
                 Screen::Queue => {
@@ -1368,7 +1815,7 @@ impl App {
                 Screen::Config => {
                     self.config_state.item_cursor = self.config_state.item_cursor.saturating_sub(1);
                 }
-                Screen::Banner | Screen::FilePicker => {}
+                Screen::Banner | Screen::FilePicker | Screen::Summary => {}
             },
             Action::PageDown => {
                 let page = self.visible_rows.max(1);
@@ -1382,9 +1829,12 @@ impl App {
                         self.paper_cursor = (self.paper_cursor + page).min(max);
                     }
                     Screen::RefDetail(..) => {
-                        self.detail_scroll = self.detail_scroll.saturating_add(page as u16);
+                        self.detail_scroll = self
+                            .detail_scroll
+                            .saturating_add(page as u16)
+                            .min(self.detail_max_scroll);
                     }
-                    Screen::Config | Screen::Banner | Screen::FilePicker => {}
+                    Screen::Config | Screen::Banner | Screen::FilePicker | Screen::Summary => {}
                 }
             }
             Action::PageUp => {
@@ -1399,7 +1849,7 @@ impl App {
                     Screen::RefDetail(..) => {
                         self.detail_scroll = self.detail_scroll.saturating_sub(page as u16);
                     }
-                    Screen::Config | Screen::Banner | Screen::FilePicker => {}
+                    Screen::Config | Screen::Banner | Screen::FilePicker | Screen::Summary => {}
                 }
             }
             Action::GoTop => match &self.screen {
@@ -1407,7 +1857,7 @@ impl App {
                 Screen::Paper(_) => self.paper_cursor = 0,
                 Screen::RefDetail(..) => self.detail_scroll = 0,
                 Screen::Config => self.config_state.item_cursor = 0,
-                Screen::Banner | Screen::FilePicker => {}
+                Screen::Banner | Screen::FilePicker | Screen::Summary => {}
             },
             Action::GoBottom => match &self.screen {
                 Screen::Queue => {
@@ -1417,13 +1867,13 @@ impl App {
                     self.paper_cursor = self.paper_ref_indices(*idx).len().saturating_sub(1);
                 }
                 Screen::RefDetail(..) => {
-                    self.detail_scroll = u16::MAX;
+                    self.detail_scroll = self.detail_max_scroll;
                 }
                 Screen::Config => {
                     self.config_state.item_cursor =
                         self.config_section_item_count().saturating_sub(1);
                 }
-                Screen::Banner | Screen::FilePicker => {}
+                Screen::Banner | Screen::FilePicker | Screen::Summary => {}
             },
             Action::CycleSort => match &self.screen {
                 Screen::Queue => {
@@ -1441,15 +1891,33 @@ impl App {
                     self.recompute_sorted_indices();
                     self.queue_cursor = 0;
                 }
-                Screen::Paper(_) => {
-                    self.paper_filter = self.paper_filter.next();
-                    self.paper_cursor = 0;
+                Screen::Paper(idx) => {
+                    let idx = *idx;
+                    self.refilter_paper(idx, |app| app.paper_filter = app.paper_filter.next());
                 }
                 _ => {}
             },
+            Action::ToggleVerifiedCollapse => match &self.screen {
+                Screen::Paper(idx) => {
+                    let idx = *idx;
+                    self.refilter_paper(idx, |app| app.collapse_verified = !app.collapse_verified);
+                }
+                // Outside the Paper view `c` keeps its old meaning.
+                _ => {
+                    self.config_state.prev_screen = Some(self.screen.clone());
+                    self.screen = Screen::Config;
+                }
+            },
             Action::StartSearch => {
                 self.input_mode = InputMode::Search;
-                self.search_query.clear();
+                match self.screen {
+                    Screen::Queue => {
+                        self.search_query.clear();
+                        self.recompute_sorted_indices();
+                    }
+                    Screen::Paper(idx) => self.refilter_paper(idx, |app| app.search_query.clear()),
+                    _ => self.search_query.clear(),
+                }
             }
             Action::SearchInput(c) => {
                 if self.config_state.editing {
@@ -1460,14 +1928,21 @@ impl App {
                         self.config_state.edit_buffer.push(c);
                     }
                 } else {
-                    if c == '\x08' {
-                        self.search_query.pop();
-                    } else {
-                        self.search_query.push(c);
-                    }
-                    if self.screen == Screen::Queue {
-                        self.recompute_sorted_indices();
-                        self.queue_cursor = 0;
+                    let edit = |app: &mut Self| {
+                        if c == '\x08' {
+                            app.search_query.pop();
+                        } else {
+                            app.search_query.push(c);
+                        }
+                    };
+                    match self.screen {
+                        Screen::Queue => {
+                            edit(self);
+                            self.recompute_sorted_indices();
+                            self.queue_cursor = 0;
+                        }
+                        Screen::Paper(idx) => self.refilter_paper(idx, edit),
+                        _ => edit(self),
                     }
                 }
             }
@@ -1485,9 +1960,15 @@ impl App {
                     self.input_mode = InputMode::Normal;
                 } else {
                     self.input_mode = InputMode::Normal;
-                    self.search_query.clear();
-                    if self.screen == Screen::Queue {
-                        self.recompute_sorted_indices();
+                    match self.screen {
+                        Screen::Queue => {
+                            self.search_query.clear();
+                            self.recompute_sorted_indices();
+                        }
+                        Screen::Paper(idx) => {
+                            self.refilter_paper(idx, |app| app.search_query.clear())
+                        }
+                        _ => self.search_query.clear(),
                     }
                 }
             }
@@ -1499,12 +1980,32 @@ impl App {
                 self.config_state.prev_screen = Some(self.screen.clone());
                 self.screen = Screen::Config;
             }
+            Action::OpenSummary => {
+                if matches!(
+                    self.screen,
+                    Screen::Queue | Screen::Paper(_) | Screen::RefDetail(..)
+                ) {
+                    self.screen = Screen::Summary;
+                }
+            }
             Action::Export => {
                 self.export_state.active = true;
-                self.export_state.cursor = 0;
+                self.export_state.cursor = crate::view::export::FORMAT_ROW;
                 self.export_state.message = None;
                 self.export_state.output_path = self.export_default_path(self.export_state.scope);
             }
+            Action::ExportProblems => {
+                if self.screen == Screen::Queue {
+                    // Land on Export so Enter writes straight away; the
+                    // modal doubles as the prompt for format and path.
+                    self.export_state.active = true;
+                    self.export_state.scope = crate::view::export::ExportScope::Problems;
+                    self.export_state.cursor = crate::view::export::CONFIRM_ROW;
+                    self.export_state.message = None;
+                    self.export_state.output_path =
+                        self.export_default_path(self.export_state.scope);
+                }
+            }
             Action::StartProcessing => {
                 if self.screen == Screen::Queue {
                     if !self.processing_started {
@@ -1523,13 +2024,11 @@ impl App {
             }
             Action::ToggleSafe => {
                 match &self.screen {
-                    Screen::Queue => {
+                    Screen::Queue if self.queue_cursor < self.queue_sorted.len() => {
                         // Space on queue: cycle paper verdict (None → Safe → Questionable → None)
-                        if self.queue_cursor < self.queue_sorted.len() {
-                            let paper_idx = self.queue_sorted[self.queue_cursor];
-                            if let Some(paper) = self.papers.get_mut(paper_idx) {
-                                paper.verdict = PaperVerdict::cycle(paper.verdict);
-                            }
+                        let paper_idx = self.queue_sorted[self.queue_cursor];
+                        if let Some(paper) = self.papers.get_mut(paper_idx) {
+                            paper.verdict = PaperVerdict::cycle(paper.verdict);
                         }
                     }
                     Screen::Paper(idx) => {
@@ -1608,10 +2107,26 @@ impl App {
                     self.screen = Screen::FilePicker;
                 }
             }
+            Action::AddPaper => {
+                if self.screen != Screen::Config {
+                    self.add_paper = AddPaperState {
+                        active: true,
+                        ..AddPaperState::default()
+                    };
+                    self.input_mode = InputMode::TextInput;
+                }
+            }
             Action::CopyToClipboard => {
                 if let Some(text) = self.get_copyable_text() {
-                    osc52_copy(&text);
-                    self.activity.log("Copied to clipboard".to_string());
+                    self.copy_to_clipboard(&text);
+                }
+            }
+            Action::CopyDetail => {
+                if let Screen::RefDetail(paper_idx, ref_idx) = self.screen
+                    && let Some(rs) = self.ref_states.get(paper_idx).and_then(|r| r.get(ref_idx))
+                {
+                    let text = detail_clipboard_text(rs);
+                    self.copy_to_clipboard(&text);
                 }
             }
             Action::SaveConfig => {
@@ -1630,8 +2145,13 @@ impl App {
             Action::Retry => {
                 self.handle_retry_single();
             }
-            Action::RetryAll => {
-                self.handle_retry_all();
+            Action::RetryFailed => {
+                self.handle_retry_failed();
+            }
+            Action::CancelPaper => {
+                if self.screen == Screen::Queue && self.queue_cursor < self.queue_sorted.len() {
+                    self.cancel_paper(self.queue_sorted[self.queue_cursor]);
+                }
             }
             Action::RemovePaper => {
                 // Placeholder for future implementation
@@ -1668,31 +2188,43 @@ impl App {
         false
     }
 
-    /// Handle mouse click → row selection.
+    /// Handle mouse click → row selection; a double-click drills in.
     fn handle_click(&mut self, _x: u16, y: u16) {
-        if let Some(table_area) = self.last_table_area
-            && y >= table_area.y
-            && y < table_area.y + table_area.height
-        {
-            // Account for border (1) + header row (1) = offset 2 from table_area.y
-            let row_offset = 2u16;
-            if y >= table_area.y + row_offset {
-                let clicked_row = (y - table_area.y - row_offset) as usize;
-                match &self.screen {
-                    Screen::Queue => {
-                        if clicked_row < self.queue_sorted.len() {
-                            self.queue_cursor = clicked_row;
-                        }
-                    }
-                    Screen::Paper(idx) => {
-                        let indices = self.paper_ref_indices(*idx);
-                        if clicked_row < indices.len() {
-                            self.paper_cursor = clicked_row;
-                        }
-                    }
-                    _ => {}
-                }
+        let Some(table_area) = self.last_table_area else {
+            return;
+        };
+        // Account for border (1) + header row (1) = offset 2 from table_area.y,
+        // and the bottom border
+        let row_offset = 2u16;
+        if y < table_area.y + row_offset || y + 1 >= table_area.y + table_area.height {
+            return;
+        }
+        let clicked_row = self.last_table_offset + (y - table_area.y - row_offset) as usize;
+        let selected = match &self.screen {
+            Screen::Queue if clicked_row < self.queue_sorted.len() => {
+                self.queue_cursor = clicked_row;
+                true
+            }
+            Screen::Paper(idx) if clicked_row < self.paper_ref_indices(*idx).len() => {
+                self.paper_cursor = clicked_row;
+                true
             }
+            _ => false,
+        };
+        if !selected {
+            self.last_click = None;
+            return;
+        }
+
+        let now = Instant::now();
+        let double = self
+            .last_click
+            .is_some_and(|(row, at)| row == clicked_row && now.duration_since(at) <= DOUBLE_CLICK);
+        if double {
+            self.last_click = None;
+            self.update(Action::DrillIn);
+        } else {
+            self.last_click = Some((clicked_row, now));
         }
     }
 
@@ -1702,7 +2234,7 @@ impl App {
         match self.config_state.section {
             ConfigSection::ApiKeys => 3,
             ConfigSection::Databases => 4 + self.config_state.disabled_dbs.len(), // DBLP + ACL + cache_path + clear_cache + toggles
-            ConfigSection::Concurrency => 5,
+            ConfigSection::Concurrency => 7,
             ConfigSection::Display => 2, // theme + fps
         }
     }
@@ -1729,6 +2261,8 @@ impl App {
                     2 => self.config_state.db_timeout_secs.to_string(),
                     3 => self.config_state.db_timeout_short_secs.to_string(),
                     4 => self.config_state.max_archive_size_mb.to_string(),
+                    5 => self.config_state.max_references.to_string(),
+                    6 => self.config_state.max_concurrent_papers.to_string(),
                     _ => return,
                 };
                 self.config_state.editing = true;
@@ -1737,7 +2271,7 @@ impl App {
             }
             ConfigSection::Display => match self.config_state.item_cursor {
                 0 => {
-                    // Cycle theme: hacker → modern → t800 → hacker
+                    // Cycle theme: hacker → modern → gnr → accessible → hacker
                     self.cycle_theme();
                 }
                 1 => {
@@ -1779,20 +2313,16 @@ impl App {
     fn handle_config_space(&mut self) {
         use crate::model::config::ConfigSection;
         match self.config_state.section {
-            ConfigSection::Databases => {
-                // Items 4+ are DB toggles (0-1: paths, 2: cache path, 3: clear cache)
-                if self.config_state.item_cursor >= 4 {
-                    let toggle_idx = self.config_state.item_cursor - 4;
-                    if let Some((_, enabled)) = self.config_state.disabled_dbs.get_mut(toggle_idx) {
-                        *enabled = !*enabled;
-                        self.config_state.dirty = true;
-                    }
+            // Items 4+ are DB toggles (0-1: paths, 2: cache path, 3: clear cache)
+            ConfigSection::Databases if self.config_state.item_cursor >= 4 => {
+                let toggle_idx = self.config_state.item_cursor - 4;
+                if let Some((_, enabled)) = self.config_state.disabled_dbs.get_mut(toggle_idx) {
+                    *enabled = !*enabled;
+                    self.config_state.dirty = true;
                 }
             }
-            ConfigSection::Display => {
-                if self.config_state.item_cursor == 0 {
-                    self.cycle_theme();
-                }
+            ConfigSection::Display if self.config_state.item_cursor == 0 => {
+                self.cycle_theme();
             }
             _ => {}
         }
@@ -1835,6 +2365,16 @@ impl App {
                         self.config_state.max_archive_size_mb = v;
                     }
                 }
+                5 => {
+                    if let Ok(v) = buf.parse::<usize>() {
+                        self.config_state.max_references = v;
+                    }
+                }
+                6 => {
+                    if let Ok(v) = buf.parse::<usize>() {
+                        self.config_state.max_concurrent_papers = v;
+                    }
+                }
                 _ => {}
             },
             ConfigSection::Databases => match self.config_state.item_cursor {
@@ -1899,6 +2439,7 @@ impl App {
                     path,
                     std::time::Duration::from_secs(1),
                     std::time::Duration::from_secs(1),
+                    0,
                 ) {
                     Ok(cache) => {
                         cache.clear();
@@ -1985,22 +2526,105 @@ impl App {
         }
     }
 
+    /// Cancel one paper of the running batch. Its references still being
+    /// checked go back to pending, and whatever the backend sends for it
+    /// afterwards is ignored.
+    fn cancel_paper(&mut self, paper_index: usize) {
+        if !self.processing_started || self.batch_complete {
+            return;
+        }
+        let Some(paper) = self.papers.get_mut(paper_index) else {
+            return;
+        };
+        if paper.phase.is_terminal() {
+            return;
+        }
+        paper.phase = PaperPhase::Cancelled;
+        paper.extraction_progress = None;
+        self.activity.log(format!("Cancelled {}", paper.filename));
+
+        if let Some(refs) = self.ref_states.get_mut(paper_index) {
+            for rs in refs
+                .iter_mut()
+                .filter(|rs| matches!(rs.phase, RefPhase::Checking | RefPhase::Retrying))
+            {
+                rs.phase = RefPhase::Pending;
+                let title = rs.title.clone();
+                self.activity
+                    .active_queries
+                    .retain(|q| q.ref_title != title);
+            }
+        }
+
+        if let Some(tx) = &self.backend_cmd_tx {
+            let _ = tx.send(BackendCommand::CancelPaper { paper_index });
+        }
+    }
+
+    /// Whether `event` is about a paper the user cancelled. Database
+    /// completions and rate-limit news still count towards the activity
+    /// panel, so only the paper's own progress is dropped.
+    fn is_for_cancelled_paper(&self, event: &BackendEvent) -> bool {
+        let paper_index = match event {
+            BackendEvent::ExtractionStarted { paper_index }
+            | BackendEvent::ExtractionProgress { paper_index, .. }
+            | BackendEvent::ExtractionComplete { paper_index, .. }
+            | BackendEvent::ExtractionFailed { paper_index, .. }
+            | BackendEvent::PaperComplete { paper_index } => *paper_index,
+            BackendEvent::Progress { paper_index, event } => match **event {
+                ProgressEvent::DatabaseQueryComplete { .. }
+                | ProgressEvent::RateLimited { .. }
+                | ProgressEvent::RateLimitStatus { .. }
+                | ProgressEvent::ConcurrencyChanged { .. } => return false,
+                _ => *paper_index,
+            },
+            _ => return false,
+        };
+        self.papers
+            .get(paper_index)
+            .is_some_and(|p| p.phase == PaperPhase::Cancelled)
+    }
+
     /// Process a backend event and update model state.
     pub fn handle_backend_event(&mut self, event: BackendEvent) {
+        if self.is_for_cancelled_paper(&event) {
+            return;
+        }
+        let checkpoint_due = match &event {
+            BackendEvent::PaperComplete { .. }
+            | BackendEvent::ExtractionFailed { .. }
+            | BackendEvent::BatchComplete => Some(true),
+            BackendEvent::Progress { event, .. }
+                if matches!(**event, ProgressEvent::Result { .. }) =>
+            {
+                Some(false)
+            }
+            _ => None,
+        };
         match event {
             BackendEvent::ExtractionStarted { paper_index } => {
                 if let Some(paper) = self.papers.get_mut(paper_index) {
                     paper.phase = PaperPhase::Extracting;
+                    paper.extraction_progress = None;
                 }
             }
-            BackendEvent::ExtractionComplete {
+            BackendEvent::ExtractionProgress {
                 paper_index,
-                ref_count,
-                references,
-                skip_stats: _,
+                progress,
+            } => {
+                if let Some(paper) = self.papers.get_mut(paper_index) {
+                    paper.extraction_progress = Some(progress);
+                }
+            }
+            BackendEvent::ExtractionComplete {
+                paper_index,
+                ref_count,
+                references,
+                skip_stats,
             } => {
                 if let Some(paper) = self.papers.get_mut(paper_index) {
                     paper.total_refs = ref_count;
+                    paper.skip_stats = Some(skip_stats);
                     let skipped = references
                         .iter()
                         .filter(|r| r.skip_reason.is_some())
@@ -2010,6 +2634,7 @@ impl App {
                     // Allocate result slots for ALL refs (including skipped) so
                     // that remapped indices from the backend fit.
                     paper.init_results(references.len());
+                    paper.extraction_progress = None;
                     paper.phase = PaperPhase::Checking;
                 }
                 if paper_index < self.ref_states.len() {
@@ -2031,14 +2656,23 @@ impl App {
                                 authors: r.authors,
                                 doi: r.doi,
                                 arxiv_id: r.arxiv_id,
+                                arxiv_version: r.arxiv_version,
                             }
                         })
                         .collect();
                 }
             }
-            BackendEvent::ExtractionFailed { paper_index, error } => {
+            BackendEvent::ExtractionFailed {
+                paper_index,
+                error,
+                encrypted,
+            } => {
                 if let Some(paper) = self.papers.get_mut(paper_index) {
-                    paper.phase = PaperPhase::ExtractionFailed;
+                    paper.phase = if encrypted {
+                        PaperPhase::Encrypted
+                    } else {
+                        PaperPhase::ExtractionFailed
+                    };
                     paper.error = Some(error);
                 }
             }
@@ -2047,7 +2681,10 @@ impl App {
             }
             BackendEvent::PaperComplete { paper_index } => {
                 if let Some(paper) = self.papers.get_mut(paper_index)
-                    && paper.phase != PaperPhase::ExtractionFailed
+                    && !matches!(
+                        paper.phase,
+                        PaperPhase::ExtractionFailed | PaperPhase::Encrypted
+                    )
                 {
                     paper.phase = PaperPhase::Complete;
                 }
@@ -2093,6 +2730,7 @@ impl App {
                     self.config_state.dblp_build_status =
                         Some(format!("Build complete! (total {:.0?})", elapsed));
                     self.config_state.dblp_offline_path = db_path.display().to_string();
+                    self.dblp_stale_notice = None;
                     self.activity
                         .log(format!("DBLP database built: {}", db_path.display()));
                 } else {
@@ -2139,6 +2777,35 @@ impl App {
                 }
             }
         }
+        if let Some(force) = checkpoint_due {
+            self.save_checkpoint(force);
+        }
+    }
+
+    /// Rewrite the checkpoint with every paper's results so far. Results
+    /// alone only trigger a write every few seconds; a finished paper or
+    /// batch always does. The first failed write is logged, later ones not.
+    fn save_checkpoint(&mut self, force: bool) {
+        let Some(mut checkpoint) = self.checkpoint.take() else {
+            return;
+        };
+        if checkpoint.is_due(force) {
+            let indices: Vec<usize> = (0..self.papers.len()).collect();
+            let written = checkpoint.write(|file| {
+                self.write_report(&indices, hallucinator_reporting::ExportFormat::Json, file)
+                    .map(|_| ())
+            });
+            if let Err(e) = written
+                && checkpoint.mark_failed()
+            {
+                self.activity.log_warn(format!(
+                    "Could not write checkpoint {}: {}",
+                    checkpoint.path().display(),
+                    e
+                ));
+            }
+        }
+        self.checkpoint = Some(checkpoint);
     }
 
     fn handle_progress(&mut self, paper_index: usize, event: ProgressEvent) {
@@ -2168,9 +2835,13 @@ impl App {
             ProgressEvent::Result { index, result, .. } => {
                 let result = *result;
                 if let Some(paper) = self.papers.get_mut(paper_index) {
-                    // Track retry progress
+                    // Track retry progress; a paper retried after its run is
+                    // done again once the last result is in
                     if paper.phase == PaperPhase::Retrying {
                         paper.retry_done += 1;
+                        if paper.retry_done >= paper.retry_total {
+                            paper.phase = PaperPhase::Complete;
+                        }
                     }
                     let is_retracted = result
                         .retraction_info
@@ -2191,8 +2862,28 @@ impl App {
                 }
                 self.activity.total_completed += 1;
                 self.throughput_since_last += 1;
+                if self.ref_completions.len() == ETA_WINDOW {
+                    self.ref_completions.pop_front();
+                }
+                self.ref_completions.push_back(Instant::now());
+            }
+            ProgressEvent::Duplicate { .. } => {
+                // The shared result follows as an ordinary Result
+                if let Some(paper) = self.papers.get_mut(paper_index) {
+                    paper.stats.duplicates += 1;
+                }
+            }
+            ProgressEvent::Warning {
+                failed_dbs,
+                message,
+                ..
+            } => {
+                // Per-ref timeouts already show up on the ref itself; only
+                // paper-level warnings (like the reference cap) go to the log.
+                if failed_dbs.is_empty() {
+                    self.activity.log_warn(message);
+                }
             }
-            ProgressEvent::Warning { .. } => {}
             ProgressEvent::Retrying {
                 title, failed_dbs, ..
             } => {
@@ -2252,6 +2943,19 @@ impl App {
                     }
                 }
             }
+            ProgressEvent::RateLimited {
+                db_name,
+                retry_after,
+                ..
+            } => {
+                self.activity.record_throttle(&db_name, retry_after);
+            }
+            ProgressEvent::RateLimitStatus { limiters } => {
+                self.activity.record_rate_limits(&limiters);
+            }
+            ProgressEvent::ConcurrencyChanged { db_name, limit } => {
+                self.activity.record_concurrency(&db_name, limit);
+            }
             ProgressEvent::RateLimitWait { .. } | ProgressEvent::RateLimitRetry { .. } => {
                 // Rate limit events are handled internally by the pool;
                 // no TUI action needed (activity panel could log these in the future).
@@ -2259,6 +2963,20 @@ impl App {
         }
     }
 
+    /// Totals across all papers for the summary screen.
+    pub fn summary(&self) -> BatchSummary {
+        let cache = self
+            .current_query_cache
+            .as_ref()
+            .map(|cache| (cache.hits(), cache.hits() + cache.misses()));
+        BatchSummary::new(
+            &self.papers,
+            &self.activity.db_health,
+            cache,
+            self.elapsed(),
+        )
+    }
+
     /// Elapsed processing time. Returns zero before processing starts,
     /// frozen value after cancel/complete, or live value during processing.
     /// Record that a frame was drawn; updates the measured FPS counter.
@@ -2283,6 +3001,39 @@ impl App {
         }
     }
 
+    /// Estimated time remaining for the batch and the current rate in
+    /// refs/sec, or `None` until enough references have completed.
+    ///
+    /// Papers whose references have not been extracted yet are assumed to
+    /// hold as many references as the average extracted paper.
+    pub fn eta(&self) -> Option<(std::time::Duration, f64)> {
+        let rate = completion_rate(&self.ref_completions)?;
+
+        let mut remaining = 0usize;
+        let mut unknown_papers = 0usize;
+        let (mut known_refs, mut known_papers) = (0usize, 0usize);
+        for paper in &self.papers {
+            if paper.total_refs > 0 {
+                known_refs += paper.total_refs;
+                known_papers += 1;
+            }
+            if paper.phase.is_terminal() {
+                continue;
+            }
+            if paper.total_refs > 0 {
+                remaining += paper.total_refs.saturating_sub(paper.completed_count());
+            } else {
+                unknown_papers += 1;
+            }
+        }
+        remaining += (unknown_papers * known_refs)
+            .checked_div(known_papers)
+            .unwrap_or(0);
+
+        let secs = remaining as f64 / rate;
+        Some((std::time::Duration::from_secs_f64(secs), rate))
+    }
+
     /// Build the global stats line shown in the logo bar.
     fn build_stats_line(&self) -> Line<'static> {
         let theme = &self.theme;
@@ -2333,15 +3084,16 @@ impl App {
         Line::from(spans)
     }
 
-    /// Cycle theme: hacker → modern → gnr → hacker.
+    /// Cycle theme: hacker → modern → gnr → accessible → hacker.
     fn cycle_theme(&mut self) {
-        let (name, theme) = match self.config_state.theme_name.as_str() {
-            "hacker" => ("modern", Theme::modern()),
-            "modern" => ("gnr", Theme::t800()),
-            _ => ("hacker", Theme::hacker()),
+        let name = match self.config_state.theme_name.as_str() {
+            "hacker" => "modern",
+            "modern" => "gnr",
+            "gnr" | "t800" => "accessible",
+            _ => "hacker",
         };
         self.config_state.theme_name = name.to_string();
-        self.theme = theme;
+        self.theme = Theme::from_name(name);
         self.config_state.dirty = true;
     }
 
@@ -2379,6 +3131,40 @@ impl App {
         }
     }
 
+    /// The footer flash message, while it is still fresh.
+    pub fn flash_message(&self) -> Option<&str> {
+        self.flash
+            .as_ref()
+            .filter(|(_, at)| at.elapsed() < FLASH_DURATION)
+            .map(|(msg, _)| msg.as_str())
+    }
+
+    /// Put `text` on the OS clipboard when built with the `clipboard`
+    /// feature, falling back to OSC 52 for remote or display-less sessions,
+    /// and flash where it went. OSC 52 gets no answer from the terminal, so
+    /// it only says the text was sent.
+    fn copy_to_clipboard(&mut self, text: &str) {
+        let msg = self.copy_text(text);
+        self.activity.log(msg.to_string());
+        self.flash = Some((msg.to_string(), Instant::now()));
+    }
+
+    fn copy_text(&mut self, text: &str) -> &'static str {
+        #[cfg(feature = "clipboard")]
+        {
+            if self.clipboard.is_none() {
+                self.clipboard = arboard::Clipboard::new().ok();
+            }
+            if let Some(clipboard) = self.clipboard.as_mut()
+                && clipboard.set_text(text).is_ok()
+            {
+                return "Copied to clipboard";
+            }
+        }
+        osc52_copy(text);
+        "Sent to terminal clipboard"
+    }
+
     /// Handle Ctrl+r: retry the currently selected reference.
     fn handle_retry_single(&mut self) {
         let (paper_idx, ref_idx) = match &self.screen {
@@ -2406,7 +3192,7 @@ impl App {
                     self.activity.log("Already verified".to_string());
                     return;
                 }
-                r.failed_dbs.clone()
+                r.failed_db_names()
             }
             None => {
                 self.activity.log("No result to retry".to_string());
@@ -2429,54 +3215,80 @@ impl App {
         self.activity
             .log(format!("Retrying ref #{}...", ref_idx + 1));
 
-        if let Some(tx) = &self.backend_cmd_tx {
-            let config = self.build_config();
+        if let Some(tx) = self.backend_cmd_tx.clone() {
+            let (config, context) = self.build_config_with_context();
             let _ = tx.send(BackendCommand::RetryReferences {
                 paper_index: paper_idx,
                 refs_to_retry: vec![(ref_idx, reference, failed_dbs)],
                 config: Box::new(config),
+                context,
             });
         }
     }
 
-    /// Handle R: retry all failed/not-found references for the current paper.
-    fn handle_retry_all(&mut self) {
-        let paper_idx = match &self.screen {
-            Screen::Paper(idx) => *idx,
-            Screen::RefDetail(idx, _) => *idx,
-            Screen::Queue => {
-                if self.queue_cursor < self.queue_sorted.len() {
-                    self.queue_sorted[self.queue_cursor]
-                } else {
-                    return;
-                }
-            }
+    /// Handle R: retry the failed references of the current paper, or on
+    /// the queue of every finished paper. Papers still being checked (or
+    /// cancelled) are left alone.
+    fn handle_retry_failed(&mut self) {
+        let papers: Vec<usize> = match &self.screen {
+            Screen::Paper(idx) | Screen::RefDetail(idx, _) => vec![*idx],
+            Screen::Queue => (0..self.papers.len()).collect(),
             _ => return,
         };
 
-        let refs = match self.ref_states.get(paper_idx) {
-            Some(r) => r,
-            None => return,
-        };
-
-        // Collect retryable refs: NotFound with failed_dbs, or NotFound for full re-check
-        let mut to_retry: Vec<(usize, hallucinator_core::Reference, Vec<String>)> = Vec::new();
-        for (i, rs) in refs.iter().enumerate() {
-            if let Some(result) = &rs.result
-                && result.status == hallucinator_core::Status::NotFound
+        let mut retried = 0;
+        for paper_idx in papers {
+            if self
+                .papers
+                .get(paper_idx)
+                .is_none_or(|p| p.phase != PaperPhase::Complete)
             {
-                to_retry.push((i, rs.to_reference(), result.failed_dbs.clone()));
+                continue;
+            }
+            let to_retry = self.failed_refs(paper_idx);
+            if to_retry.is_empty() {
+                continue;
             }
+            retried += to_retry.len();
+            self.submit_retry(paper_idx, to_retry);
         }
 
-        if to_retry.is_empty() {
+        if retried == 0 {
             self.activity.log("No references to retry".to_string());
-            return;
+        } else {
+            self.activity
+                .log(format!("Retrying {} references...", retried));
         }
+    }
 
-        let count = to_retry.len();
+    /// References of a paper worth another try: those not found, and those
+    /// where some database failed. Each comes with its failed databases; an
+    /// empty list re-checks against all of them.
+    fn failed_refs(
+        &self,
+        paper_idx: usize,
+    ) -> Vec<(usize, hallucinator_core::Reference, Vec<String>)> {
+        let Some(refs) = self.ref_states.get(paper_idx) else {
+            return Vec::new();
+        };
+        refs.iter()
+            .enumerate()
+            .filter_map(|(i, rs)| {
+                let result = rs.result.as_ref()?;
+                (result.status == hallucinator_core::Status::NotFound
+                    || !result.failed_dbs.is_empty())
+                .then(|| (i, rs.to_reference(), result.failed_db_names()))
+            })
+            .collect()
+    }
 
-        // Mark all as retrying
+    /// Send `to_retry` to the backend, showing the paper as retrying until
+    /// every result is back.
+    fn submit_retry(
+        &mut self,
+        paper_idx: usize,
+        to_retry: Vec<(usize, hallucinator_core::Reference, Vec<String>)>,
+    ) {
         if let Some(refs) = self.ref_states.get_mut(paper_idx) {
             for &(ref_idx, _, _) in &to_retry {
                 if let Some(rs) = refs.get_mut(ref_idx) {
@@ -2484,16 +3296,19 @@ impl App {
                 }
             }
         }
+        if let Some(paper) = self.papers.get_mut(paper_idx) {
+            paper.phase = PaperPhase::Retrying;
+            paper.retry_total = to_retry.len();
+            paper.retry_done = 0;
+        }
 
-        self.activity
-            .log(format!("Retrying {} references...", count));
-
-        if let Some(tx) = &self.backend_cmd_tx {
-            let config = self.build_config();
+        if let Some(tx) = self.backend_cmd_tx.clone() {
+            let (config, context) = self.build_config_with_context();
             let _ = tx.send(BackendCommand::RetryReferences {
                 paper_index: paper_idx,
                 refs_to_retry: to_retry,
                 config: Box::new(config),
+                context,
             });
         }
     }
@@ -2587,6 +3402,7 @@ impl App {
                 crate::view::detail::render_in(f, self, paper_idx, ref_idx, main_area, footer_area)
             }
             Screen::Config => crate::view::config::render_in(f, self, main_area, footer_area),
+            Screen::Summary => crate::view::summary::render_in(f, self, main_area, footer_area),
             Screen::Banner | Screen::FilePicker => unreachable!(),
         }
 
@@ -2594,6 +3410,10 @@ impl App {
             crate::view::export::render(f, self);
         }
 
+        if self.add_paper.active {
+            crate::view::add_paper::render(f, self);
+        }
+
         if self.show_help {
             crate::view::help::render(f, &self.theme);
         }
@@ -2604,6 +3424,46 @@ impl App {
     }
 }
 
+/// References per second over a window of completion times, once it holds
+/// at least [`ETA_MIN_SAMPLES`] entries spread over a measurable span.
+fn completion_rate(completions: &VecDeque<Instant>) -> Option<f64> {
+    if completions.len() < ETA_MIN_SAMPLES {
+        return None;
+    }
+    let span = completions
+        .back()?
+        .duration_since(*completions.front()?)
+        .as_secs_f64();
+    (span > 0.0).then(|| (completions.len() - 1) as f64 / span)
+}
+
+/// What `y` copies from the reference detail view: the citation, then the
+/// DOI and paper links and the verdict, one per line.
+fn detail_clipboard_text(rs: &RefState) -> String {
+    let citation = rs
+        .result
+        .as_ref()
+        .map(|r| r.raw_citation.as_str())
+        .filter(|c| !c.is_empty())
+        .or(Some(rs.raw_citation.as_str()).filter(|c| !c.is_empty()))
+        .unwrap_or(&rs.title);
+    let mut lines = vec![citation.to_string()];
+
+    let doi = rs
+        .result
+        .as_ref()
+        .and_then(|r| r.doi_info.as_ref().map(|d| d.doi.clone()))
+        .or_else(|| rs.doi.clone());
+    if let Some(doi) = doi {
+        lines.push(format!("DOI: https://doi.org/{}", doi));
+    }
+    if let Some(url) = rs.result.as_ref().and_then(|r| r.paper_url.as_deref()) {
+        lines.push(format!("URL: {}", url));
+    }
+    lines.push(format!("Verdict: {}", rs.verdict_label()));
+    lines.join("\n")
+}
+
 /// Copy text to the system clipboard via OSC 52 escape sequence.
 /// Works in Ghostty, iTerm2, kitty, WezTerm, and most modern terminals.
 fn osc52_copy(text: &str) {
@@ -2666,6 +3526,22 @@ fn format_dblp_progress(
                 )
             }
         }
+        hallucinator_dblp::BuildProgress::Verifying {
+            bytes_checked,
+            bytes_total,
+        } => {
+            let pct = if *bytes_total > 0 {
+                (*bytes_checked as f64 / *bytes_total as f64 * 100.0) as u32
+            } else {
+                0
+            };
+            format!(
+                "Verifying download... {} / {} ({}%)",
+                format_bytes(*bytes_checked),
+                format_bytes(*bytes_total),
+                pct
+            )
+        }
         hallucinator_dblp::BuildProgress::Parsing {
             records_inserted,
             bytes_read,
@@ -2871,6 +3747,25 @@ fn format_bytes(bytes: u64) -> String {
     }
 }
 
+/// Checked and not cleanly verified: the rows `PaperFilter::ProblemsOnly`
+/// keeps.
+fn is_problem(rs: &RefState) -> bool {
+    rs.result.is_some() && !is_clean_verified(rs)
+}
+
+/// A problem the user has not marked safe, as written by the problems export.
+fn is_exported_problem(rs: &RefState) -> bool {
+    is_problem(rs) && rs.fp_reason.is_none()
+}
+
+/// Verified and not retracted: the rows `collapse_verified` hides.
+fn is_clean_verified(rs: &RefState) -> bool {
+    rs.result.as_ref().is_some_and(|r| {
+        r.status == hallucinator_core::Status::Verified
+            && !r.retraction_info.as_ref().is_some_and(|ri| ri.is_retracted)
+    })
+}
+
 fn verdict_sort_key(rs: &RefState) -> u8 {
     if matches!(rs.phase, RefPhase::Skipped(_)) {
         return 5; // sort skipped refs last
@@ -2982,6 +3877,7 @@ mod tests {
     use super::*;
     use crate::action::Action;
     use crate::model::config::ConfigSection;
+    use hallucinator_core::{FailedDb, FailureReason};
 
     /// Create a minimal App for testing (no backend, no files).
     fn test_app() -> App {
@@ -3001,6 +3897,90 @@ mod tests {
         assert_eq!(app.file_picker_context, FilePickerContext::AddFiles);
     }
 
+    // ── Theme cycling ──────────────────────────────────────────────
+
+    #[test]
+    fn cycle_theme_key_walks_all_themes() {
+        let mut app = test_app();
+        dismiss_banner(&mut app);
+        let mut seen = vec![];
+        for _ in 0..4 {
+            app.update(Action::CycleTheme);
+            seen.push(app.config_state.theme_name.clone());
+        }
+        assert_eq!(seen, ["modern", "gnr", "accessible", "hacker"]);
+        assert!(app.config_state.dirty);
+    }
+
+    // ── Queue ETA ──────────────────────────────────────────────────
+
+    #[test]
+    fn eta_waits_for_enough_completions() {
+        let mut app = test_app();
+        let start = Instant::now();
+        app.ref_completions = (0..ETA_MIN_SAMPLES as u64 - 1)
+            .map(|i| start + std::time::Duration::from_secs(i))
+            .collect();
+        assert!(app.eta().is_none());
+    }
+
+    #[test]
+    fn eta_extrapolates_unextracted_papers() {
+        let mut app = App::new(
+            vec!["a.pdf".to_string(), "b.pdf".to_string()],
+            Theme::hacker(),
+        );
+        app.papers[0].total_refs = 10;
+        let start = Instant::now();
+        // Five completions one second apart: 1 ref/s.
+        app.ref_completions = (0..5)
+            .map(|i| start + std::time::Duration::from_secs(i))
+            .collect();
+
+        let (remaining, rate) = app.eta().unwrap();
+        assert!((rate - 1.0).abs() < 1e-9);
+        // 10 refs left in a.pdf, and b.pdf is assumed to hold as many.
+        assert_eq!(remaining.as_secs(), 20);
+    }
+
+    // ── Copying reference details ──────────────────────────────────
+
+    #[test]
+    fn detail_clipboard_text_has_citation_links_and_verdict() {
+        let mut rs = ref_with_status(0, Some(hallucinator_core::Status::Verified));
+        rs.doi = Some("10.1000/xyz".to_string());
+        let result = rs.result.as_mut().unwrap();
+        result.raw_citation = "A. Author. A paper. 2020.".to_string();
+        result.paper_url = Some("https://example.org/paper".to_string());
+
+        let text = detail_clipboard_text(&rs);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "A. Author. A paper. 2020.");
+        assert_eq!(lines[1], "DOI: https://doi.org/10.1000/xyz");
+        assert_eq!(lines[2], "URL: https://example.org/paper");
+        assert_eq!(lines[3], format!("Verdict: {}", rs.verdict_label()));
+    }
+
+    #[test]
+    fn detail_clipboard_text_falls_back_to_title() {
+        let rs = ref_with_status(3, None);
+        let text = detail_clipboard_text(&rs);
+        assert!(text.starts_with("Ref 3\n"));
+        assert!(!text.contains("DOI:"));
+    }
+
+    #[test]
+    fn flash_message_expires() {
+        let mut app = test_app();
+        app.flash = Some(("Copied to clipboard".to_string(), Instant::now()));
+        assert_eq!(app.flash_message(), Some("Copied to clipboard"));
+        app.flash = Some((
+            "Copied to clipboard".to_string(),
+            Instant::now() - FLASH_DURATION,
+        ));
+        assert_eq!(app.flash_message(), None);
+    }
+
     // ── AddFiles from Queue opens picker in AddFiles mode ──────────
 
     #[test]
@@ -3075,6 +4055,130 @@ mod tests {
         assert_eq!(app.screen, Screen::Config);
     }
 
+    // ── AddPaper prompt ─────────────────────────────────────────────
+
+    fn type_path(app: &mut App, path: &str) {
+        for ch in path.chars() {
+            app.update(Action::SearchInput(ch));
+        }
+    }
+
+    #[test]
+    fn add_paper_opens_prompt_from_empty_queue() {
+        let mut app = test_app();
+        dismiss_banner(&mut app);
+        app.update(Action::AddPaper);
+        assert!(app.add_paper.active);
+        assert_eq!(app.input_mode, InputMode::TextInput);
+    }
+
+    #[test]
+    fn add_paper_rejects_missing_path() {
+        let mut app = test_app();
+        dismiss_banner(&mut app);
+        app.update(Action::AddPaper);
+        type_path(&mut app, "/no/such/paper.pdf");
+        app.update(Action::SearchConfirm);
+
+        assert!(app.add_paper.active);
+        assert!(
+            app.add_paper
+                .error
+                .as_deref()
+                .unwrap()
+                .contains("No such file")
+        );
+        assert!(app.papers.is_empty());
+    }
+
+    #[test]
+    fn add_paper_with_empty_path_opens_picker() {
+        let mut app = test_app();
+        dismiss_banner(&mut app);
+        app.update(Action::AddPaper);
+        app.update(Action::SearchConfirm);
+
+        assert!(!app.add_paper.active);
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.screen, Screen::FilePicker);
+        assert_eq!(app.file_picker_context, FilePickerContext::AddFiles);
+    }
+
+    #[test]
+    fn archive_members_are_checked_under_their_queue_name() {
+        let mut app = App::new(
+            vec!["papers.zip/a.pdf".to_string(), "loaded.pdf".to_string()],
+            Theme::hacker(),
+        );
+        app.file_paths = vec![PathBuf::from("/tmp/x/a.pdf"), PathBuf::new()];
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        app.backend_cmd_tx = Some(tx);
+
+        app.start_processing();
+        match rx.try_recv() {
+            Ok(BackendCommand::ProcessFiles { files, .. }) => assert_eq!(
+                files,
+                vec![(
+                    PathBuf::from("/tmp/x/a.pdf"),
+                    "papers.zip/a.pdf".to_string()
+                )]
+            ),
+            _ => panic!("expected ProcessFiles"),
+        }
+    }
+
+    #[test]
+    fn add_paper_enqueues_and_starts_checking() {
+        let dir = tempfile::tempdir().unwrap();
+        let pdf = dir.path().join("paper.pdf");
+        std::fs::write(&pdf, b"%PDF-1.4").unwrap();
+
+        let mut app = test_app();
+        dismiss_banner(&mut app);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        app.backend_cmd_tx = Some(tx);
+
+        app.update(Action::AddPaper);
+        type_path(&mut app, &pdf.display().to_string());
+        app.update(Action::SearchConfirm);
+
+        assert!(!app.add_paper.active);
+        assert_eq!(app.papers.len(), 1);
+        assert_eq!(app.papers[0].filename, "paper.pdf");
+        assert!(app.processing_started);
+        match rx.try_recv() {
+            Ok(BackendCommand::ProcessFiles {
+                files,
+                starting_index,
+                ..
+            }) => {
+                assert_eq!(files, vec![(pdf.clone(), "paper.pdf".to_string())]);
+                assert_eq!(starting_index, 0);
+            }
+            _ => panic!("expected ProcessFiles"),
+        }
+
+        // A second paper added mid-run is sent as its own batch.
+        let second = dir.path().join("second.pdf");
+        std::fs::write(&second, b"%PDF-1.4").unwrap();
+        app.update(Action::AddPaper);
+        type_path(&mut app, &second.display().to_string());
+        app.update(Action::SearchConfirm);
+
+        assert_eq!(app.papers.len(), 2);
+        match rx.try_recv() {
+            Ok(BackendCommand::ProcessFiles {
+                files,
+                starting_index,
+                ..
+            }) => {
+                assert_eq!(files, vec![(second, "second.pdf".to_string())]);
+                assert_eq!(starting_index, 1);
+            }
+            _ => panic!("expected ProcessFiles"),
+        }
+    }
+
     // ── Esc in db picker with no selection returns to Config unchanged ──
 
     #[test]
@@ -3143,6 +4247,7 @@ mod tests {
             is_pdf: true,
             is_bbl: false,
             is_bib: false,
+            is_txt: false,
             is_archive: false,
             is_json: false,
             is_db: false,
@@ -3169,6 +4274,7 @@ mod tests {
             is_pdf: false,
             is_bbl: false,
             is_bib: false,
+            is_txt: false,
             is_archive: false,
             is_json: false,
             is_db: true,
@@ -3197,6 +4303,7 @@ mod tests {
             is_pdf: false,
             is_bbl: false,
             is_bib: false,
+            is_txt: false,
             is_archive: false,
             is_json: false,
             is_db: true,
@@ -3229,6 +4336,7 @@ mod tests {
             is_pdf: false,
             is_bbl: false,
             is_bib: false,
+            is_txt: false,
             is_archive: false,
             is_json: false,
             is_db: true, // pretend it's a db
@@ -3260,6 +4368,7 @@ mod tests {
             is_pdf: false,
             is_bbl: false,
             is_bib: false,
+            is_txt: false,
             is_archive: false,
             is_json: false,
             is_db: false,
@@ -3288,6 +4397,7 @@ mod tests {
             is_pdf: true,
             is_bbl: false,
             is_bib: false,
+            is_txt: false,
             is_archive: false,
             is_json: false,
             is_db: false,
@@ -3386,6 +4496,7 @@ mod tests {
             is_pdf: false,
             is_bbl: false,
             is_bib: false,
+            is_txt: false,
             is_archive: false,
             is_json: false,
             is_db: true,
@@ -3415,6 +4526,7 @@ mod tests {
             is_pdf: true,
             is_bbl: false,
             is_bib: false,
+            is_txt: false,
             is_archive: false,
             is_json: false,
             is_db: false,
@@ -3561,6 +4673,7 @@ mod tests {
             is_pdf: false,
             is_bbl: false,
             is_bib: false,
+            is_txt: false,
             is_archive: false,
             is_json: false,
             is_db: true,
@@ -3572,4 +4685,713 @@ mod tests {
         assert_eq!(app.screen, Screen::Config);
         assert!(app.config_state.dirty);
     }
+
+    // ── Collapse verified refs in the Paper view ────────────────────
+
+    fn ref_with_status(index: usize, status: Option<hallucinator_core::Status>) -> RefState {
+        RefState {
+            index,
+            title: format!("Ref {}", index),
+            phase: if status.is_some() {
+                RefPhase::Done
+            } else {
+                RefPhase::Pending
+            },
+            result: status.map(|status| hallucinator_core::ValidationResult {
+                title: format!("Ref {}", index),
+                raw_citation: String::new(),
+                ref_authors: vec![],
+                status,
+                source: None,
+                agreeing_dbs: vec![],
+                found_authors: vec![],
+                paper_url: None,
+                failed_dbs: vec![],
+                db_results: vec![],
+                doi_info: None,
+                arxiv_info: None,
+                retraction_info: None,
+                reason: None,
+                match_score: None,
+                title_near_miss: None,
+                venue: None,
+                year: None,
+                orcid: None,
+            }),
+            fp_reason: None,
+            raw_citation: String::new(),
+            authors: vec![],
+            doi: None,
+            arxiv_id: None,
+            arxiv_version: None,
+        }
+    }
+
+    /// One paper with refs: verified, not found, verified, pending.
+    fn app_with_paper() -> App {
+        use hallucinator_core::Status;
+        let mut app = App::new(vec!["paper.pdf".to_string()], Theme::hacker());
+        app.ref_states[0] = vec![
+            ref_with_status(0, Some(Status::Verified)),
+            ref_with_status(1, Some(Status::NotFound)),
+            ref_with_status(2, Some(Status::Verified)),
+            ref_with_status(3, None),
+        ];
+        app.paper_sort = PaperSortOrder::RefNumber;
+        app.screen = Screen::Paper(0);
+        app
+    }
+
+    #[test]
+    fn match_score_sort_puts_weakest_matches_first() {
+        let mut app = app_with_paper();
+        for (i, score) in [(0, 0.97), (2, 0.88)] {
+            app.ref_states[0][i].result.as_mut().unwrap().match_score = Some(score);
+        }
+        app.paper_sort = PaperSortOrder::MatchScore;
+        assert_eq!(app.paper_ref_indices(0), vec![2, 0, 1, 3]);
+        assert_eq!(app.paper_sort.next(), PaperSortOrder::RefNumber);
+    }
+
+    #[test]
+    fn export_problems_writes_only_unflagged_problems() {
+        use hallucinator_core::Status;
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().join("problems").to_string_lossy().to_string();
+
+        let mut app = app_with_paper();
+        app.ref_states[0].push(ref_with_status(4, Some(Status::AuthorMismatch)));
+        app.ref_states[0][4].fp_reason = Some(FpReason::BrokenParse);
+        app.screen = Screen::Queue;
+        app.update(Action::ExportProblems);
+        assert!(app.export_state.active);
+        assert_eq!(app.export_state.cursor, crate::view::export::CONFIRM_ROW);
+        app.export_state.format = crate::view::export::ExportFormat::Markdown;
+        app.export_state.output_path = base.clone();
+        app.update(Action::DrillIn);
+
+        let path = format!("{}.md", base);
+        assert!(!app.export_state.active);
+        assert_eq!(
+            app.flash_message(),
+            Some(format!("Wrote 1 problem references to {}", path).as_str())
+        );
+        let report = std::fs::read_to_string(&path).unwrap();
+        assert!(report.contains("Ref 1"));
+        assert!(!report.contains("Ref 0"));
+        assert!(!report.contains("Ref 4"));
+
+        // Nothing left to report still writes the (empty) file
+        app.ref_states[0][1].fp_reason = Some(FpReason::BrokenParse);
+        app.update(Action::ExportProblems);
+        app.export_state.output_path = base;
+        app.update(Action::DrillIn);
+        assert_eq!(
+            app.flash_message(),
+            Some(format!("No problem references; wrote an empty report to {}", path).as_str())
+        );
+    }
+
+    #[test]
+    fn problems_report_counts_only_the_problems() {
+        let mut app = app_with_paper();
+        app.papers[0].stats = hallucinator_core::CheckStats {
+            total: 4,
+            verified: 2,
+            not_found: 1,
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        app.write_report_filtered(
+            &[0],
+            crate::view::export::ExportFormat::Json,
+            &mut out,
+            true,
+        )
+        .unwrap();
+
+        let report: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        let stats = &report["papers"][0]["stats"];
+        assert_eq!(stats["total"], 1);
+        assert_eq!(stats["verified"], 0);
+        assert_eq!(stats["not_found"], 1);
+    }
+
+    #[test]
+    fn checkpoint_resumes_checked_references_by_position() {
+        use crate::checkpoint::{Checkpoint, ResumeState};
+        use hallucinator_core::Status;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("batch.json");
+
+        let mut app = app_with_paper();
+        app.checkpoint = Some(Checkpoint::new(path.clone()));
+        app.handle_backend_event(BackendEvent::PaperComplete { paper_index: 0 });
+
+        let resume = ResumeState::load(&path).unwrap();
+        assert_eq!(resume.len(), 3);
+        let refs = &app.ref_states[0];
+        let prior = resume.prior_result("paper.pdf", &refs[1].to_reference());
+        assert_eq!(prior.map(|r| r.status), Some(Status::NotFound));
+        // Unchecked, from another paper, or retitled since: checked again
+        assert!(
+            resume
+                .prior_result("paper.pdf", &refs[3].to_reference())
+                .is_none()
+        );
+        assert!(
+            resume
+                .prior_result("other.pdf", &refs[1].to_reference())
+                .is_none()
+        );
+        let mut moved = refs[2].to_reference();
+        moved.title = Some("Ref 1".to_string());
+        assert!(resume.prior_result("paper.pdf", &moved).is_none());
+
+        // A checkpoint that does not exist yet resumes nothing
+        assert!(
+            ResumeState::load(&dir.path().join("new.json"))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn click_selects_row_past_scroll_offset() {
+        let mut app = app_with_paper();
+        app.last_table_area = Some(Rect::new(0, 5, 80, 10));
+        app.last_table_offset = 1;
+        // Border + header, then the first visible row is reference 1
+        app.update(Action::ClickAt(3, 8));
+        assert_eq!(app.paper_cursor, 2);
+        // Header and bottom border select nothing
+        app.update(Action::ClickAt(3, 6));
+        app.update(Action::ClickAt(3, 14));
+        assert_eq!(app.paper_cursor, 2);
+        assert_eq!(app.screen, Screen::Paper(0));
+    }
+
+    #[test]
+    fn double_click_drills_in() {
+        let mut app = app_with_paper();
+        app.last_table_area = Some(Rect::new(0, 0, 80, 10));
+        app.update(Action::ClickAt(3, 3));
+        assert_eq!(app.screen, Screen::Paper(0));
+        app.update(Action::ClickAt(3, 3));
+        assert_eq!(app.paper_cursor, 1);
+        assert_eq!(app.screen, Screen::RefDetail(0, 1));
+    }
+
+    #[test]
+    fn clicks_on_different_rows_are_not_a_double_click() {
+        let mut app = app_with_paper();
+        app.last_table_area = Some(Rect::new(0, 0, 80, 10));
+        app.update(Action::ClickAt(3, 2));
+        app.update(Action::ClickAt(3, 3));
+        assert_eq!(app.paper_cursor, 1);
+        assert_eq!(app.screen, Screen::Paper(0));
+    }
+
+    #[test]
+    fn rate_limited_event_shows_throttle_notice() {
+        let mut app = app_with_paper();
+        assert!(app.activity.throttle_notice().is_none());
+
+        app.handle_backend_event(BackendEvent::Progress {
+            paper_index: 0,
+            event: Box::new(ProgressEvent::RateLimited {
+                db_name: "CrossRef".to_string(),
+                retry_after: std::time::Duration::from_secs(8),
+                attempt: 1,
+            }),
+        });
+
+        let notice = app.activity.throttle_notice().unwrap();
+        assert!(
+            notice.starts_with("CrossRef throttled, waiting"),
+            "{notice}"
+        );
+        assert!(
+            app.activity.messages.back().unwrap().1,
+            "logged as a warning"
+        );
+    }
+
+    #[test]
+    fn rate_limit_status_shows_slowed_databases() {
+        let mut app = app_with_paper();
+        let state = |db_name: &str, factor| hallucinator_core::RateLimitState {
+            db_name: db_name.to_string(),
+            factor,
+            since_last_429: Some(std::time::Duration::from_secs(3)),
+        };
+        let status = |limiters| BackendEvent::Progress {
+            paper_index: 0,
+            event: Box::new(ProgressEvent::RateLimitStatus { limiters }),
+        };
+
+        app.handle_backend_event(status(vec![
+            state("CrossRef", 2),
+            state("DBLP", 1),
+            state("arXiv", 4),
+        ]));
+        assert_eq!(
+            app.activity.slowdown_notice().as_deref(),
+            Some("throttled: CrossRef 2x, arXiv 4x")
+        );
+
+        app.handle_backend_event(status(vec![state("CrossRef", 1), state("arXiv", 1)]));
+        assert_eq!(app.activity.slowdown_notice(), None);
+    }
+
+    #[test]
+    fn cancel_paper_stops_only_the_selected_paper() {
+        let mut app = App::new(
+            vec!["big.pdf".to_string(), "small.pdf".to_string()],
+            Theme::hacker(),
+        );
+        dismiss_banner(&mut app);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        app.backend_cmd_tx = Some(tx);
+        app.processing_started = true;
+        app.recompute_sorted_indices();
+
+        let reference = |title: &str| hallucinator_core::Reference {
+            raw_citation: title.to_string(),
+            title: Some(title.to_string()),
+            authors: vec![],
+            doi: None,
+            arxiv_id: None,
+            arxiv_version: None,
+            original_number: 1,
+            skip_reason: None,
+            doi_only: false,
+        };
+        let checking = |paper_index, title: &str| BackendEvent::Progress {
+            paper_index,
+            event: Box::new(ProgressEvent::Checking {
+                index: 0,
+                total: 1,
+                title: title.to_string(),
+            }),
+        };
+        for (paper_index, title) in [(0, "Huge Survey"), (1, "Short Note")] {
+            app.handle_backend_event(BackendEvent::ExtractionComplete {
+                paper_index,
+                ref_count: 1,
+                references: vec![reference(title)],
+                skip_stats: Default::default(),
+            });
+            app.handle_backend_event(checking(paper_index, title));
+        }
+
+        app.queue_cursor = app.queue_sorted.iter().position(|&i| i == 0).unwrap();
+        app.update(Action::CancelPaper);
+
+        assert_eq!(app.papers[0].phase, PaperPhase::Cancelled);
+        assert_eq!(app.ref_states[0][0].phase, RefPhase::Pending);
+        let titles: Vec<&str> = app
+            .activity
+            .active_queries
+            .iter()
+            .map(|q| q.ref_title.as_str())
+            .collect();
+        assert_eq!(titles, ["Short Note"]);
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(BackendCommand::CancelPaper { paper_index: 0 })
+        ));
+
+        // Stragglers from the backend leave the cancelled paper alone
+        app.handle_backend_event(checking(0, "Huge Survey"));
+        app.handle_backend_event(BackendEvent::PaperComplete { paper_index: 0 });
+        assert_eq!(app.papers[0].phase, PaperPhase::Cancelled);
+        assert_eq!(app.ref_states[0][0].phase, RefPhase::Pending);
+
+        assert_eq!(app.papers[1].phase, PaperPhase::Checking);
+        app.handle_backend_event(BackendEvent::PaperComplete { paper_index: 1 });
+        assert_eq!(app.papers[1].phase, PaperPhase::Complete);
+
+        // A finished paper cannot be cancelled
+        app.queue_cursor = app.queue_sorted.iter().position(|&i| i == 1).unwrap();
+        app.update(Action::CancelPaper);
+        assert_eq!(app.papers[1].phase, PaperPhase::Complete);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn encrypted_pdf_is_shown_as_skipped() {
+        let mut app = App::new(
+            vec!["locked.pdf".to_string(), "broken.pdf".to_string()],
+            Theme::hacker(),
+        );
+        app.handle_backend_event(BackendEvent::ExtractionFailed {
+            paper_index: 0,
+            error: "PDF is encrypted".to_string(),
+            encrypted: true,
+        });
+        app.handle_backend_event(BackendEvent::ExtractionFailed {
+            paper_index: 1,
+            error: "failed to open PDF".to_string(),
+            encrypted: false,
+        });
+        app.handle_backend_event(BackendEvent::PaperComplete { paper_index: 0 });
+        assert_eq!(app.papers[0].phase, PaperPhase::Encrypted);
+        assert_eq!(app.papers[0].phase.label(), "Encrypted \u{2014} skipped");
+        assert!(app.papers[0].phase.is_terminal());
+        assert_eq!(app.papers[1].phase, PaperPhase::ExtractionFailed);
+    }
+
+    #[test]
+    fn retry_failed_resubmits_only_failed_refs_of_finished_papers() {
+        use hallucinator_core::Status;
+        let mut app = App::new(
+            vec!["done.pdf".to_string(), "running.pdf".to_string()],
+            Theme::hacker(),
+        );
+        dismiss_banner(&mut app);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        app.backend_cmd_tx = Some(tx);
+
+        let mut timed_out = ref_with_status(2, Some(Status::AuthorMismatch));
+        timed_out.result.as_mut().unwrap().failed_dbs =
+            vec![FailedDb::new("DBLP", FailureReason::Timeout)];
+        app.ref_states[0] = vec![
+            ref_with_status(0, Some(Status::Verified)),
+            ref_with_status(1, Some(Status::NotFound)),
+            timed_out,
+        ];
+        app.ref_states[1] = vec![ref_with_status(0, Some(Status::NotFound))];
+        for (paper, refs) in app.papers.iter_mut().zip(&app.ref_states) {
+            paper.init_results(refs.len());
+            for (i, rs) in refs.iter().enumerate() {
+                paper.record_status(i, rs.result.as_ref().unwrap().status.clone(), false);
+            }
+        }
+        app.papers[0].phase = PaperPhase::Complete;
+        app.papers[1].phase = PaperPhase::Checking;
+
+        app.update(Action::RetryFailed);
+
+        match rx.try_recv() {
+            Ok(BackendCommand::RetryReferences {
+                paper_index,
+                refs_to_retry,
+                ..
+            }) => {
+                assert_eq!(paper_index, 0);
+                let retried: Vec<(usize, Vec<String>)> = refs_to_retry
+                    .into_iter()
+                    .map(|(i, _, failed)| (i, failed))
+                    .collect();
+                assert_eq!(retried, [(1, vec![]), (2, vec!["DBLP".to_string()])]);
+            }
+            _ => panic!("expected RetryReferences"),
+        }
+        assert!(rx.try_recv().is_err(), "the running paper is left alone");
+        assert_eq!(app.papers[0].phase, PaperPhase::Retrying);
+        assert_eq!(app.ref_states[0][1].phase, RefPhase::Retrying);
+
+        let found = ref_with_status(1, Some(Status::Verified)).result.unwrap();
+        for index in [1, 2] {
+            app.handle_backend_event(BackendEvent::Progress {
+                paper_index: 0,
+                event: Box::new(ProgressEvent::Result {
+                    index,
+                    total: 2,
+                    result: Box::new(found.clone()),
+                }),
+            });
+        }
+
+        let stats = &app.papers[0].stats;
+        assert_eq!(
+            (stats.verified, stats.not_found, stats.author_mismatch),
+            (3, 0, 0)
+        );
+        assert_eq!(app.papers[0].phase, PaperPhase::Complete);
+    }
+
+    #[test]
+    fn summary_totals_papers_and_ranks_problems() {
+        use hallucinator_core::Status;
+        let mut app = App::new(
+            vec![
+                "clean.pdf".to_string(),
+                "bad.pdf".to_string(),
+                "worse.pdf".to_string(),
+            ],
+            Theme::hacker(),
+        );
+        dismiss_banner(&mut app);
+        let statuses = [
+            vec![Status::Verified, Status::Verified],
+            vec![Status::Verified, Status::NotFound],
+            vec![Status::NotFound, Status::AuthorMismatch, Status::Verified],
+        ];
+        for (paper, statuses) in app.papers.iter_mut().zip(&statuses) {
+            paper.total_refs = statuses.len();
+            paper.init_results(statuses.len());
+            for (i, status) in statuses.iter().enumerate() {
+                paper.record_status(i, status.clone(), false);
+            }
+            paper.phase = PaperPhase::Complete;
+        }
+        for (db, failed) in [("CrossRef", 1), ("DBLP", 3), ("arXiv", 0)] {
+            for i in 0..4 {
+                app.activity
+                    .record_db_complete(db, i >= failed, false, 100.0);
+            }
+        }
+
+        app.update(Action::OpenSummary);
+        assert_eq!(app.screen, Screen::Summary);
+
+        let summary = app.summary();
+        assert_eq!((summary.papers, summary.papers_done), (3, 3));
+        assert_eq!(summary.checked, 7);
+        assert_eq!(
+            (summary.verified, summary.not_found, summary.author_mismatch),
+            (4, 2, 1)
+        );
+        assert!((summary.verified_pct() - 400.0 / 7.0).abs() < 1e-9);
+        let worst: Vec<(usize, usize)> = summary
+            .worst_papers
+            .iter()
+            .map(|&(i, problems, _)| (i, problems))
+            .collect();
+        assert_eq!(worst, [(2, 2), (1, 1)]);
+        assert_eq!(
+            summary.failing_dbs,
+            [("DBLP".to_string(), 3, 4), ("CrossRef".to_string(), 1, 4)]
+        );
+        assert_eq!(summary.cache_hit_pct(), None);
+
+        app.update(Action::NavigateBack);
+        assert_eq!(app.screen, Screen::Queue);
+    }
+
+    #[test]
+    fn collapse_verified_hides_clean_refs() {
+        let mut app = app_with_paper();
+
+        app.update(Action::ToggleVerifiedCollapse);
+
+        assert!(app.collapse_verified);
+        assert_eq!(app.paper_ref_indices(0), vec![1, 3]);
+        assert_eq!(app.hidden_verified_count(0), 2);
+
+        app.update(Action::ToggleVerifiedCollapse);
+
+        assert_eq!(app.paper_ref_indices(0), vec![0, 1, 2, 3]);
+        assert_eq!(app.hidden_verified_count(0), 0);
+    }
+
+    #[test]
+    fn collapse_verified_keeps_cursor_on_visible_ref() {
+        let mut app = app_with_paper();
+        app.paper_cursor = 3; // pending ref, stays visible
+
+        app.update(Action::ToggleVerifiedCollapse);
+        assert_eq!(app.paper_cursor, 1);
+
+        app.update(Action::GoBottom);
+        assert_eq!(app.paper_cursor, 1, "bottom is the last visible ref");
+    }
+
+    #[test]
+    fn collapse_verified_clamps_cursor_when_ref_hidden() {
+        let mut app = app_with_paper();
+        app.paper_cursor = 2; // verified, gets hidden
+
+        app.update(Action::ToggleVerifiedCollapse);
+
+        assert_eq!(app.paper_cursor, 1);
+    }
+
+    #[test]
+    fn problems_filter_shows_only_flagged_refs() {
+        let mut app = app_with_paper();
+
+        app.update(Action::CycleFilter);
+
+        assert_eq!(app.paper_filter, PaperFilter::ProblemsOnly);
+        assert_eq!(app.paper_ref_indices(0), vec![1]);
+        assert_eq!(app.filtered_out_count(0), 3);
+
+        app.update(Action::CycleFilter);
+
+        assert_eq!(app.paper_ref_indices(0), vec![0, 1, 2, 3]);
+        assert_eq!(app.filtered_out_count(0), 0);
+    }
+
+    #[test]
+    fn problems_filter_keeps_cursor_and_bounds_navigation() {
+        let mut app = app_with_paper();
+        app.paper_cursor = 1; // the not-found ref
+
+        app.update(Action::CycleFilter);
+        assert_eq!(app.paper_cursor, 0);
+
+        app.update(Action::MoveDown);
+        assert_eq!(app.paper_cursor, 0, "only one ref is visible");
+
+        app.update(Action::CycleFilter);
+        assert_eq!(app.paper_cursor, 1, "cursor returns to the same ref");
+    }
+
+    #[test]
+    fn paper_search_matches_title_and_raw_citation() {
+        let mut app = app_with_paper();
+        app.ref_states[0][2].raw_citation = "J. Smith. Neural nets. NeurIPS, 2020.".to_string();
+        app.paper_cursor = 2;
+
+        app.update(Action::StartSearch);
+        for c in "NEURIPS".chars() {
+            app.update(Action::SearchInput(c));
+        }
+        assert_eq!(app.paper_ref_indices(0), vec![2]);
+        assert_eq!(app.paper_cursor, 0, "cursor follows the matching ref");
+
+        app.update(Action::SearchCancel);
+        assert!(app.search_query.is_empty());
+        assert_eq!(app.paper_ref_indices(0), vec![0, 1, 2, 3]);
+        assert_eq!(app.paper_cursor, 2);
+    }
+
+    #[test]
+    fn queue_search_does_not_leak_into_paper_view() {
+        let mut app = app_with_paper();
+        app.screen = Screen::Queue;
+        app.recompute_sorted_indices();
+
+        app.update(Action::StartSearch);
+        for c in "paper".chars() {
+            app.update(Action::SearchInput(c));
+        }
+        app.update(Action::SearchConfirm);
+        assert_eq!(app.queue_sorted, vec![0]);
+
+        app.update(Action::DrillIn);
+        assert_eq!(app.screen, Screen::Paper(0));
+        assert_eq!(app.paper_ref_indices(0), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn collapse_key_outside_paper_opens_config() {
+        let mut app = test_app();
+        dismiss_banner(&mut app);
+
+        app.update(Action::ToggleVerifiedCollapse);
+
+        assert_eq!(app.screen, Screen::Config);
+        assert!(!app.collapse_verified);
+    }
+
+    // ── Detail scroll persistence ───────────────────────────────────
+
+    #[test]
+    fn detail_scroll_restored_per_reference() {
+        let mut app = app_with_paper();
+
+        // Scroll into ref 0, then back out.
+        app.update(Action::DrillIn);
+        assert_eq!(app.screen, Screen::RefDetail(0, 0));
+        app.update(Action::MoveDown);
+        app.update(Action::MoveDown);
+        app.update(Action::NavigateBack);
+
+        // First visit to ref 1 starts at the top.
+        app.update(Action::MoveDown);
+        app.update(Action::DrillIn);
+        assert_eq!(app.screen, Screen::RefDetail(0, 1));
+        assert_eq!(app.detail_scroll, 0);
+        app.update(Action::NavigateBack);
+
+        // Returning to ref 0 restores its offset.
+        app.update(Action::MoveUp);
+        app.update(Action::DrillIn);
+        assert_eq!(app.screen, Screen::RefDetail(0, 0));
+        assert_eq!(app.detail_scroll, 2);
+    }
+
+    #[test]
+    fn detail_scroll_clamped_to_rendered_height() {
+        let mut app = app_with_paper();
+        app.update(Action::DrillIn);
+        // As measured by the last render of the wrapped content.
+        app.detail_max_scroll = 3;
+
+        app.update(Action::GoBottom);
+        assert_eq!(app.detail_scroll, 3);
+
+        app.update(Action::MoveDown);
+        app.update(Action::PageDown);
+        assert_eq!(app.detail_scroll, 3);
+
+        app.update(Action::MoveUp);
+        assert_eq!(app.detail_scroll, 2);
+    }
+
+    // ── Extraction skip stats ───────────────────────────────────────
+
+    #[test]
+    fn extraction_complete_keeps_skip_summary() {
+        let mut app = App::new(vec!["paper.pdf".to_string()], Theme::hacker());
+
+        app.handle_backend_event(BackendEvent::ExtractionComplete {
+            paper_index: 0,
+            ref_count: 0,
+            references: vec![],
+            skip_stats: hallucinator_pdf::SkipStats {
+                url_only: 3,
+                short_title: 1,
+                no_title: 2,
+                ..Default::default()
+            },
+        });
+
+        assert_eq!(
+            app.papers[0].skip_summary().as_deref(),
+            Some("(3 url-only, 1 short, 2 no-title skipped)")
+        );
+    }
+
+    #[test]
+    fn skip_summary_omits_zero_counts() {
+        let mut app = App::new(vec!["paper.pdf".to_string()], Theme::hacker());
+        assert_eq!(app.papers[0].skip_summary(), None);
+
+        app.papers[0].skip_stats = Some(hallucinator_pdf::SkipStats {
+            short_title: 4,
+            ..Default::default()
+        });
+        assert_eq!(
+            app.papers[0].skip_summary().as_deref(),
+            Some("(4 short skipped)")
+        );
+    }
+
+    #[test]
+    fn spinner_tick_ms_slows_frames_independently_of_fps() {
+        let mut app = test_app();
+        app.ui.spinner = crate::view::SpinnerStyle::Ascii;
+        assert_eq!(app.spinner(), '|');
+        app.tick = 1;
+        assert_eq!(app.spinner(), '/');
+
+        // 30 fps ≈ 33ms per tick; at 100ms per frame the spinner holds for 3 ticks.
+        app.ui.tick_ms = Some(100);
+        app.tick = 2;
+        assert_eq!(app.spinner(), '|');
+        app.tick = 4;
+        assert_eq!(app.spinner(), '/');
+    }
+
+    #[test]
+    fn spinner_style_from_name() {
+        use crate::view::SpinnerStyle;
+        assert_eq!(SpinnerStyle::from_name("ASCII"), SpinnerStyle::Ascii);
+        assert_eq!(SpinnerStyle::from_name("braille"), SpinnerStyle::Braille);
+    }
 }
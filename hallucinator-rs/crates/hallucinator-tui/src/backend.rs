@@ -4,10 +4,13 @@ use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 
+use hallucinator_core::checker::{cap_references, reference_cap_warning, report_rate_limits};
 use hallucinator_core::pool::{RefJob, ValidationPool};
-use hallucinator_core::{Config, ProgressEvent};
+use hallucinator_core::{CheckContext, Config, ProgressEvent};
 use hallucinator_pdf::ExtractionResult;
 
+use crate::checkpoint::ResumeState;
+use crate::persistence::ResultLog;
 use crate::tui_event::BackendEvent;
 
 /// Remap progress event indices from the filtered (checkable-only) vec back to
@@ -66,38 +69,92 @@ fn remap_progress_index(event: ProgressEvent, index_map: &[usize]) -> ProgressEv
 /// Run batch validation with paper indices starting at `offset`.
 ///
 /// Creates a single global `ValidationPool` shared by all papers.
-/// Each paper gets its own task for extraction + job submission, so all
-/// papers can feed refs into the pool concurrently. The `num_workers`
-/// setting controls the total number of concurrent reference validations.
+/// Each paper gets its own task for extraction + job submission, so up to
+/// `max_concurrent_papers` papers feed refs into the pool concurrently; the
+/// rest wait their turn in order. The `num_workers` setting controls the
+/// total number of concurrent reference validations.
+/// Each paper comes with its own token, a child of `cancel`, so a single
+/// paper can be abandoned while the rest of the batch carries on.
+/// With a `log`, each result is also appended to it as it arrives.
+/// With `resume`, references that already have a verdict there are not
+/// checked again; their earlier results are reported as if just checked.
+/// Both are keyed by the paper's name in the queue, given with its path
+/// (an archive member is named `{archive}/{pdf}`).
+#[allow(clippy::too_many_arguments)]
 pub async fn run_batch_with_offset(
-    pdfs: Vec<PathBuf>,
+    papers: Vec<(PathBuf, String, CancellationToken)>,
     config: Config,
+    options: hallucinator_pdf::ExtractionOptions,
+    context: CheckContext,
     tx: mpsc::UnboundedSender<BackendEvent>,
     cancel: CancellationToken,
     offset: usize,
+    log: Option<Arc<ResultLog>>,
+    resume: Option<Arc<ResumeState>>,
 ) {
     let num_workers = config.num_workers.max(1);
+    let max_references = config.max_references;
+    let paper_slots = Arc::new(tokio::sync::Semaphore::new(
+        match config.max_concurrent_papers {
+            0 => tokio::sync::Semaphore::MAX_PERMITS,
+            n => n,
+        },
+    ));
     let config = Arc::new(config);
 
+    // Rate-limit status concerns the whole batch; it is filed under the
+    // batch's first paper.
+    let status_tx = tx.clone();
+    let rate_limit_status = tokio::spawn(report_rate_limits(
+        context.rate_limiters.clone(),
+        Arc::new(move |event| {
+            let _ = status_tx.send(BackendEvent::Progress {
+                paper_index: offset,
+                event: Box::new(event),
+            });
+        }),
+    ));
+
     // Create ONE global validation pool for all papers
-    let pool = ValidationPool::new(config.clone(), cancel.clone(), num_workers);
+    let pool = ValidationPool::with_context(config.clone(), context, cancel.clone(), num_workers);
     let pool_tx = pool.sender();
 
     // Spawn one task per paper. Extraction is fast (CPU-bound via spawn_blocking),
     // and each task then submits refs to the shared pool and awaits results.
-    // The ValidationPool is the sole concurrency bottleneck.
+    // A paper takes one of the paper slots before its task starts and holds
+    // it from extraction until its last result, so papers start in queue
+    // order as earlier ones finish.
     let mut handles = Vec::new();
-    for (i, pdf_path) in pdfs.into_iter().enumerate() {
+    for (i, (pdf_path, name, paper_cancel)) in papers.into_iter().enumerate() {
+        let slot = tokio::select! {
+            slot = paper_slots.clone().acquire_owned() => slot,
+            _ = cancel.cancelled() => break,
+        };
         let paper_index = offset + i;
         let pool_tx = pool_tx.clone();
         let tx = tx.clone();
-        let cancel = cancel.clone();
+        let log = log.clone();
+        let resume = resume.clone();
+        let options = options.clone();
 
         handles.push(tokio::spawn(async move {
-            if cancel.is_cancelled() {
+            let _slot = slot;
+            if paper_cancel.is_cancelled() {
                 return;
             }
-            process_single_paper(paper_index, &pdf_path, &pool_tx, &tx, &cancel).await;
+            process_single_paper(
+                paper_index,
+                &pdf_path,
+                name.into(),
+                options,
+                max_references,
+                &pool_tx,
+                &tx,
+                &paper_cancel,
+                log,
+                resume,
+            )
+            .await;
         }));
     }
 
@@ -112,55 +169,68 @@ pub async fn run_batch_with_offset(
     // All papers have been extracted and all jobs submitted.
     // Shut down the pool and wait for remaining validations to finish.
     pool.shutdown().await;
+    rate_limit_status.abort();
 
     let _ = tx.send(BackendEvent::BatchComplete);
 }
 
 /// Process a single paper: extract references, submit to shared pool, collect results.
+#[allow(clippy::too_many_arguments)]
 async fn process_single_paper(
     paper_index: usize,
     pdf_path: &std::path::Path,
+    filename: Arc<str>,
+    options: hallucinator_pdf::ExtractionOptions,
+    max_references: Option<usize>,
     pool_tx: &async_channel::Sender<RefJob>,
     tx: &mpsc::UnboundedSender<BackendEvent>,
     cancel: &CancellationToken,
+    log: Option<Arc<ResultLog>>,
+    resume: Option<Arc<ResumeState>>,
 ) {
     // Signal extraction start
     let _ = tx.send(BackendEvent::ExtractionStarted { paper_index });
 
     // Extract references (blocking call)
     let path = pdf_path.to_path_buf();
-    let is_bbl = path
-        .extension()
-        .is_some_and(|e| e.eq_ignore_ascii_case("bbl"));
-    let is_bib = path
-        .extension()
-        .is_some_and(|e| e.eq_ignore_ascii_case("bib"));
-
-    let extraction: Result<ExtractionResult, String> = tokio::task::spawn_blocking(move || {
-        if is_bbl {
-            hallucinator_bbl::extract_references_from_bbl(&path)
-                .map_err(|e| format!("BBL extraction failed: {}", e))
-        } else if is_bib {
-            hallucinator_bbl::extract_references_from_bib(&path)
-                .map_err(|e| format!("BIB extraction failed: {}", e))
-        } else {
-            hallucinator_pdf::extract_references(&path)
-                .map_err(|e| format!("PDF extraction failed: {}", e))
-        }
-    })
-    .await
-    .unwrap_or_else(|e| Err(format!("Task join error: {}", e)));
+    let tx_extract = tx.clone();
+    let extraction: Result<ExtractionResult, (String, bool)> =
+        tokio::task::spawn_blocking(move || {
+            let extractor = hallucinator_pdf::PdfExtractor::new().with_options(options);
+            hallucinator_core::input::extract_references_from_path(&path, &extractor, &|progress| {
+                let _ = tx_extract.send(BackendEvent::ExtractionProgress {
+                    paper_index,
+                    progress,
+                });
+            })
+            .map_err(|e| {
+                let encrypted = matches!(
+                    e,
+                    hallucinator_core::CoreError::Pdf(hallucinator_pdf::PdfError::Encrypted)
+                );
+                (e.to_string(), encrypted)
+            })
+        })
+        .await
+        .unwrap_or_else(|e| Err((format!("Task join error: {}", e), false)));
 
     let extraction = match extraction {
         Ok(ext) => ext,
-        Err(error) => {
-            let _ = tx.send(BackendEvent::ExtractionFailed { paper_index, error });
+        Err((error, encrypted)) => {
+            let _ = tx.send(BackendEvent::ExtractionFailed {
+                paper_index,
+                error,
+                encrypted,
+            });
             return;
         }
     };
 
     let skip_stats = extraction.skip_stats.clone();
-    let all_refs = extraction.references;
+    let ocr_used = extraction.ocr_used;
+    let ocr_error = extraction.ocr_error.clone();
+    let mut all_refs = extraction.references;
+    let capped = cap_references(&mut all_refs, max_references);
 
     // Count only non-skipped refs for the ref_count (used for stats/progress)
     let checkable_count = all_refs.iter().filter(|r| r.skip_reason.is_none()).count();
@@ -171,6 +241,41 @@ async fn process_single_paper(
         references: all_refs.clone(),
         skip_stats,
     });
+    if ocr_used {
+        let _ = tx.send(BackendEvent::Progress {
+            paper_index,
+            event: Box::new(ProgressEvent::Warning {
+                index: 0,
+                total: checkable_count,
+                title: String::new(),
+                failed_dbs: vec![],
+                message: "No text layer found; references were read by OCR and may contain recognition errors".to_string(),
+            }),
+        });
+    }
+    if let Some(e) = ocr_error {
+        let _ = tx.send(BackendEvent::Progress {
+            paper_index,
+            event: Box::new(ProgressEvent::Warning {
+                index: 0,
+                total: checkable_count,
+                title: String::new(),
+                failed_dbs: vec![],
+                message: format!(
+                    "Little embedded text found and OCR failed ({}); references may be incomplete",
+                    e
+                ),
+            }),
+        });
+    }
+    if let Some(max) = max_references
+        && capped > 0
+    {
+        let _ = tx.send(BackendEvent::Progress {
+            paper_index,
+            event: Box::new(reference_cap_warning(max, capped)),
+        });
+    }
 
     // Build a mapping from filtered (checkable) index → original all_refs index,
     // so that progress events use indices into the full ref_states array.
@@ -203,13 +308,49 @@ async fn process_single_paper(
             break;
         }
 
+        if let Some(result) = resume
+            .as_ref()
+            .and_then(|r| r.prior_result(&filename, reference))
+        {
+            let _ = tx.send(BackendEvent::Progress {
+                paper_index,
+                event: Box::new(ProgressEvent::Result {
+                    index: index_map[i],
+                    total,
+                    result: Box::new(result),
+                }),
+            });
+            continue;
+        }
+
         let (result_tx, result_rx) = tokio::sync::oneshot::channel();
 
         // Build per-ref progress callback that remaps indices and tags with paper_index
         let tx_progress = tx.clone();
         let index_map = Arc::clone(&index_map);
+        let log = log.clone();
+        let filename = Arc::clone(&filename);
         let progress_cb = move |event: ProgressEvent| {
             let event = remap_progress_index(event, &index_map);
+            if let (
+                Some(log),
+                ProgressEvent::Result {
+                    index,
+                    total,
+                    result,
+                },
+            ) = (&log, &event)
+            {
+                log_result(
+                    log,
+                    &filename,
+                    *index,
+                    *total,
+                    result,
+                    paper_index,
+                    &tx_progress,
+                );
+            }
             let _ = tx_progress.send(BackendEvent::Progress {
                 paper_index,
                 event: Box::new(event),
@@ -222,13 +363,15 @@ async fn process_single_paper(
             ref_index: i,
             total,
             progress: Arc::new(progress_cb),
+            cancel: Some(cancel.clone()),
         };
 
         let _ = pool_tx.send(job).await;
         receivers.push((i, result_rx));
     }
 
-    // Await all receivers (results are already sent via Progress events)
+    // Await all receivers (results are already sent via Progress events);
+    // the jobs of a cancelled paper close theirs without a result
     for (_i, rx) in receivers {
         let _ = rx.await;
     }
@@ -236,21 +379,56 @@ async fn process_single_paper(
     let _ = tx.send(BackendEvent::PaperComplete { paper_index });
 }
 
+/// Append a result to the log; the first failed write (e.g. a full disk) is
+/// reported as a paper-level warning; later failures are not reported again.
+fn log_result(
+    log: &ResultLog,
+    filename: &str,
+    index: usize,
+    total: usize,
+    result: &hallucinator_core::ValidationResult,
+    paper_index: usize,
+    tx: &mpsc::UnboundedSender<BackendEvent>,
+) {
+    if let Err(e) = log.append(filename, index, result)
+        && log.mark_failed()
+    {
+        let _ = tx.send(BackendEvent::Progress {
+            paper_index,
+            event: Box::new(ProgressEvent::Warning {
+                index,
+                total,
+                title: result.title.clone(),
+                failed_dbs: vec![],
+                message: format!(
+                    "Could not write results log {}: {}",
+                    log.path().display(),
+                    e
+                ),
+            }),
+        });
+    }
+}
+
 /// Retry specific references for a paper, re-checking against failed (or all) databases.
 pub async fn retry_references(
     paper_index: usize,
     refs_to_retry: Vec<(usize, hallucinator_core::Reference, Vec<String>)>,
-    config: Config,
+    mut config: Config,
+    context: CheckContext,
     tx: mpsc::UnboundedSender<BackendEvent>,
 ) {
-    let client = reqwest::Client::builder()
-        .pool_max_idle_per_host(2)
-        .pool_idle_timeout(std::time::Duration::from_secs(30))
-        .build()
-        .unwrap_or_else(|_| reqwest::Client::new());
+    context.apply_to(&mut config);
+    let client = context.client;
+    let total = refs_to_retry.len();
+    if let Some(warning) = hallucinator_core::checker::load_local_bib(&mut config, total) {
+        let _ = tx.send(BackendEvent::Progress {
+            paper_index,
+            event: Box::new(warning),
+        });
+    }
     let config = Arc::new(config);
     let semaphore = Arc::new(tokio::sync::Semaphore::new(config.num_workers.max(1)));
-    let total = refs_to_retry.len();
 
     let mut handles = Vec::new();
 
@@ -273,11 +451,24 @@ pub async fn retry_references(
                 }),
             });
 
+            let rate_limited_tx = tx.clone();
+            let on_rate_limited: hallucinator_core::orchestrator::OnRateLimited =
+                Arc::new(move |event| {
+                    let _ = rate_limited_tx.send(BackendEvent::Progress {
+                        paper_index,
+                        event: Box::new(event),
+                    });
+                });
+
             let result = if failed_dbs.is_empty() {
                 // Full re-check against all databases
                 hallucinator_core::checker::check_single_reference(
-                    &reference, &config, &client, true, // longer timeout
+                    &reference,
+                    &config,
+                    &client,
+                    true, // longer timeout
                     None,
+                    Some(&on_rate_limited),
                 )
                 .await
             } else {
@@ -288,6 +479,7 @@ pub async fn retry_references(
                     &client,
                     &failed_dbs,
                     None,
+                    Some(&on_rate_limited),
                 )
                 .await
             };
@@ -310,6 +502,22 @@ pub async fn retry_references(
     }
 }
 
+/// Load the Retraction Watch data at `config.retraction_db_path` unless
+/// `config` already carries it. A file that cannot be read, or stale data,
+/// is reported to the activity log under `paper_index`.
+pub fn load_retraction_db(
+    config: &mut Config,
+    paper_index: usize,
+    tx: &mpsc::UnboundedSender<BackendEvent>,
+) {
+    if let Some(warning) = hallucinator_core::checker::load_retraction_db(config, 0) {
+        let _ = tx.send(BackendEvent::Progress {
+            paper_index,
+            event: Box::new(warning),
+        });
+    }
+}
+
 /// Open offline DBLP database if a path is configured, returning the Arc<Mutex<..>> handle.
 pub fn open_dblp_db(
     path: &std::path::Path,
@@ -324,6 +532,25 @@ pub fn open_dblp_db(
     Ok(Arc::new(Mutex::new(db)))
 }
 
+/// For an opened offline DBLP database at `path` that is at least
+/// `max_age_days` old: the activity-log warning and the short banner text.
+pub fn dblp_staleness(
+    db: &Mutex<hallucinator_dblp::DblpDatabase>,
+    path: &std::path::Path,
+    max_age_days: u64,
+) -> Option<(String, String)> {
+    let staleness = db.lock().ok()?.check_staleness(max_age_days).ok()?;
+    if !staleness.is_stale {
+        return None;
+    }
+    let message = hallucinator_core::checker::dblp_stale_message(path, &staleness);
+    let banner = match (staleness.build_day(), staleness.age_days) {
+        (Some(day), Some(days)) => format!("DBLP DB built {} ({} days ago)", day, days),
+        _ => "DBLP DB has no build date".to_string(),
+    };
+    Some((message, banner))
+}
+
 /// Open offline ACL Anthology database if a path is configured, returning the Arc<Mutex<..>> handle.
 pub fn open_acl_db(
     path: &std::path::Path,
@@ -0,0 +1,113 @@
+//! Batch checkpoints, so a long batch can pick up where it stopped after a
+//! crash or an early quit.
+//!
+//! The checkpoint is a JSON export of the batch (the format `--load` reads),
+//! rewritten as results come in. Resuming from it keys earlier verdicts by
+//! paper filename and reference position, not by title, so two references
+//! with the same title in one paper are never confused.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use hallucinator_core::{Reference, ValidationResult};
+
+/// Least time between two checkpoint writes triggered by single results.
+const WRITE_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Where the checkpoint goes and when it was last written.
+pub struct Checkpoint {
+    path: PathBuf,
+    last_write: Option<Instant>,
+    failed: bool,
+}
+
+impl Checkpoint {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            last_write: None,
+            failed: false,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Whether a write is due: always when `force`d (a paper or the batch
+    /// finished), otherwise at most once per [`WRITE_INTERVAL`].
+    pub fn is_due(&self, force: bool) -> bool {
+        force
+            || self
+                .last_write
+                .is_none_or(|t| t.elapsed() >= WRITE_INTERVAL)
+    }
+
+    /// Replace the checkpoint with whatever `write` produces. The new content
+    /// goes to a temporary file that is then renamed over the old one, so a
+    /// crash mid-write leaves the previous checkpoint intact.
+    pub fn write(
+        &mut self,
+        write: impl FnOnce(&mut std::fs::File) -> std::io::Result<()>,
+    ) -> std::io::Result<()> {
+        self.last_write = Some(Instant::now());
+        let mut tmp_name = self.path.as_os_str().to_owned();
+        tmp_name.push(".tmp");
+        let tmp = PathBuf::from(tmp_name);
+        let mut file = std::fs::File::create(&tmp)?;
+        write(&mut file)?;
+        file.sync_all()?;
+        std::fs::rename(&tmp, &self.path)
+    }
+
+    /// Record a write failure; true only the first time, so callers warn once.
+    pub fn mark_failed(&mut self) -> bool {
+        !std::mem::replace(&mut self.failed, true)
+    }
+}
+
+/// Verdicts from an earlier run of the batch, read back from its checkpoint.
+#[derive(Default)]
+pub struct ResumeState {
+    /// Paper filename → reference position → (title, verdict).
+    papers: HashMap<String, HashMap<usize, (String, ValidationResult)>>,
+}
+
+impl ResumeState {
+    /// Read the checkpoint at `path`. A missing file is an empty state, so the
+    /// same `--resume` command line starts a batch and later resumes it.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let mut state = Self::default();
+        for (paper, refs) in crate::load::load_results_file(path)? {
+            let done = state.papers.entry(paper.filename).or_default();
+            for rs in refs {
+                if let Some(result) = rs.result {
+                    done.insert(rs.index, (rs.title, result));
+                }
+            }
+        }
+        Ok(state)
+    }
+
+    /// Number of references with a verdict.
+    pub fn len(&self) -> usize {
+        self.papers.values().map(HashMap::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The earlier verdict for `reference` in the paper `filename`, if it was
+    /// checked. The title must still match, so a paper whose file changed
+    /// since the checkpoint is checked again rather than given stale results.
+    pub fn prior_result(&self, filename: &str, reference: &Reference) -> Option<ValidationResult> {
+        let index = reference.original_number.saturating_sub(1);
+        let (title, result) = self.papers.get(filename)?.get(&index)?;
+        (title == reference.title.as_deref().unwrap_or_default()).then(|| result.clone())
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
@@ -18,30 +19,79 @@ pub struct ConfigFile {
 pub struct ApiKeysConfig {
     pub openalex_key: Option<String>,
     pub s2_api_key: Option<String>,
+    pub ncbi_api_key: Option<String>,
+    pub core_api_key: Option<String>,
     pub crossref_mailto: Option<String>,
+    pub user_agent: Option<String>,
+    /// Proxy URL for API requests; unset falls back to `HTTPS_PROXY` etc.
+    pub proxy: Option<String>,
+    /// PEM file with extra root certificates to trust.
+    pub extra_ca_cert: Option<String>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DatabasesConfig {
     pub dblp_offline_path: Option<String>,
+    /// Warn when the offline DBLP database is at least this many days old.
+    pub dblp_max_age_days: Option<u64>,
     pub acl_offline_path: Option<String>,
+    /// Local `.bib` library to check references against before the public databases.
+    pub local_bib_path: Option<String>,
+    /// Retraction Watch CSV to look matched DOIs up in.
+    pub retraction_db_path: Option<String>,
+    /// Warn when the Retraction Watch CSV is at least this many days old.
+    pub retraction_max_age_days: Option<u64>,
     pub cache_path: Option<String>,
     pub disabled: Option<Vec<String>>,
+    /// Title-similarity threshold per database name, e.g. `{ DBLP = 0.97 }`.
+    pub thresholds: Option<BTreeMap<String, f64>>,
+    /// Minimum fraction of cited authors a match must share (0.0 = any one).
+    pub author_min_overlap: Option<f32>,
+    /// Reject matches whose first author differs from the citation's.
+    pub require_first_author: Option<bool>,
+    /// Surname similarity for two author names to match (1.0 = exact).
+    pub author_match_threshold: Option<f64>,
+    /// Title similarity a match must reach to count (0.0 = no minimum).
+    pub min_match_score: Option<f64>,
+    /// Query every database and verify only when several agree on the title.
+    pub consensus_mode: Option<bool>,
+    /// Databases that must agree on the title in consensus mode.
+    pub consensus_min: Option<usize>,
+    /// Flag matches whose title is close to, but not the same as, the cited one.
+    pub strict_title: Option<bool>,
+    /// Settle author mismatches by who claims the matched work on ORCID.
+    pub orcid_check: Option<bool>,
+    /// Rank author mismatches with references not found in exports.
+    pub author_mismatch_is_failure: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ConcurrencyConfig {
+    /// 0 = unlimited.
+    pub max_concurrent_papers: Option<usize>,
     pub num_workers: Option<usize>,
     pub db_timeout_secs: Option<u64>,
     pub db_timeout_short_secs: Option<u64>,
+    /// Timeout in seconds per database name, overriding `db_timeout_secs`.
+    pub db_timeouts: Option<BTreeMap<String, u64>>,
     pub max_rate_limit_retries: Option<u32>,
     pub max_archive_size_mb: Option<u32>,
+    /// 0 = unlimited.
+    pub max_references: Option<usize>,
+    /// Look up references with the same title once and share the result.
+    pub dedup: Option<bool>,
+    /// Adjust how many queries each database runs at once from its 429s.
+    pub adaptive_concurrency: Option<bool>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DisplayConfig {
     pub theme: Option<String>,
     pub fps: Option<u32>,
+    /// "braille" or "ascii"; unset auto-detects from the locale.
+    pub spinner: Option<String>,
+    /// Milliseconds per spinner frame; 0 = one frame per redraw.
+    pub tick_ms: Option<u64>,
 }
 
 /// Platform config directory path: `<config_dir>/hallucinator/config.toml`.
@@ -82,6 +132,16 @@ fn merge(base: ConfigFile, overlay: ConfigFile) -> ConfigFile {
                 .as_ref()
                 .and_then(|a| a.s2_api_key.clone())
                 .or_else(|| base.api_keys.as_ref().and_then(|a| a.s2_api_key.clone())),
+            ncbi_api_key: overlay
+                .api_keys
+                .as_ref()
+                .and_then(|a| a.ncbi_api_key.clone())
+                .or_else(|| base.api_keys.as_ref().and_then(|a| a.ncbi_api_key.clone())),
+            core_api_key: overlay
+                .api_keys
+                .as_ref()
+                .and_then(|a| a.core_api_key.clone())
+                .or_else(|| base.api_keys.as_ref().and_then(|a| a.core_api_key.clone())),
             crossref_mailto: overlay
                 .api_keys
                 .as_ref()
@@ -91,6 +151,21 @@ fn merge(base: ConfigFile, overlay: ConfigFile) -> ConfigFile {
                         .as_ref()
                         .and_then(|a| a.crossref_mailto.clone())
                 }),
+            user_agent: overlay
+                .api_keys
+                .as_ref()
+                .and_then(|a| a.user_agent.clone())
+                .or_else(|| base.api_keys.as_ref().and_then(|a| a.user_agent.clone())),
+            proxy: overlay
+                .api_keys
+                .as_ref()
+                .and_then(|a| a.proxy.clone())
+                .or_else(|| base.api_keys.as_ref().and_then(|a| a.proxy.clone())),
+            extra_ca_cert: overlay
+                .api_keys
+                .as_ref()
+                .and_then(|a| a.extra_ca_cert.clone())
+                .or_else(|| base.api_keys.as_ref().and_then(|a| a.extra_ca_cert.clone())),
         }),
         databases: Some(DatabasesConfig {
             dblp_offline_path: overlay
@@ -111,6 +186,38 @@ fn merge(base: ConfigFile, overlay: ConfigFile) -> ConfigFile {
                         .as_ref()
                         .and_then(|d| d.acl_offline_path.clone())
                 }),
+            local_bib_path: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.local_bib_path.clone())
+                .or_else(|| {
+                    base.databases
+                        .as_ref()
+                        .and_then(|d| d.local_bib_path.clone())
+                }),
+            retraction_db_path: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.retraction_db_path.clone())
+                .or_else(|| {
+                    base.databases
+                        .as_ref()
+                        .and_then(|d| d.retraction_db_path.clone())
+                }),
+            dblp_max_age_days: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.dblp_max_age_days)
+                .or_else(|| base.databases.as_ref().and_then(|d| d.dblp_max_age_days)),
+            retraction_max_age_days: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.retraction_max_age_days)
+                .or_else(|| {
+                    base.databases
+                        .as_ref()
+                        .and_then(|d| d.retraction_max_age_days)
+                }),
             cache_path: overlay
                 .databases
                 .as_ref()
@@ -121,8 +228,75 @@ fn merge(base: ConfigFile, overlay: ConfigFile) -> ConfigFile {
                 .as_ref()
                 .and_then(|d| d.disabled.clone())
                 .or_else(|| base.databases.as_ref().and_then(|d| d.disabled.clone())),
+            thresholds: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.thresholds.clone())
+                .or_else(|| base.databases.as_ref().and_then(|d| d.thresholds.clone())),
+            author_min_overlap: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.author_min_overlap)
+                .or_else(|| base.databases.as_ref().and_then(|d| d.author_min_overlap)),
+            require_first_author: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.require_first_author)
+                .or_else(|| base.databases.as_ref().and_then(|d| d.require_first_author)),
+            author_match_threshold: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.author_match_threshold)
+                .or_else(|| {
+                    base.databases
+                        .as_ref()
+                        .and_then(|d| d.author_match_threshold)
+                }),
+            min_match_score: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.min_match_score)
+                .or_else(|| base.databases.as_ref().and_then(|d| d.min_match_score)),
+            consensus_mode: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.consensus_mode)
+                .or_else(|| base.databases.as_ref().and_then(|d| d.consensus_mode)),
+            consensus_min: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.consensus_min)
+                .or_else(|| base.databases.as_ref().and_then(|d| d.consensus_min)),
+            strict_title: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.strict_title)
+                .or_else(|| base.databases.as_ref().and_then(|d| d.strict_title)),
+            orcid_check: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.orcid_check)
+                .or_else(|| base.databases.as_ref().and_then(|d| d.orcid_check)),
+            author_mismatch_is_failure: overlay
+                .databases
+                .as_ref()
+                .and_then(|d| d.author_mismatch_is_failure)
+                .or_else(|| {
+                    base.databases
+                        .as_ref()
+                        .and_then(|d| d.author_mismatch_is_failure)
+                }),
         }),
         concurrency: Some(ConcurrencyConfig {
+            max_concurrent_papers: overlay
+                .concurrency
+                .as_ref()
+                .and_then(|c| c.max_concurrent_papers)
+                .or_else(|| {
+                    base.concurrency
+                        .as_ref()
+                        .and_then(|c| c.max_concurrent_papers)
+                }),
             num_workers: overlay
                 .concurrency
                 .as_ref()
@@ -142,6 +316,15 @@ fn merge(base: ConfigFile, overlay: ConfigFile) -> ConfigFile {
                         .as_ref()
                         .and_then(|c| c.db_timeout_short_secs)
                 }),
+            db_timeouts: overlay
+                .concurrency
+                .as_ref()
+                .and_then(|c| c.db_timeouts.clone())
+                .or_else(|| {
+                    base.concurrency
+                        .as_ref()
+                        .and_then(|c| c.db_timeouts.clone())
+                }),
             max_rate_limit_retries: overlay
                 .concurrency
                 .as_ref()
@@ -160,6 +343,25 @@ fn merge(base: ConfigFile, overlay: ConfigFile) -> ConfigFile {
                         .as_ref()
                         .and_then(|c| c.max_archive_size_mb)
                 }),
+            max_references: overlay
+                .concurrency
+                .as_ref()
+                .and_then(|c| c.max_references)
+                .or_else(|| base.concurrency.as_ref().and_then(|c| c.max_references)),
+            dedup: overlay
+                .concurrency
+                .as_ref()
+                .and_then(|c| c.dedup)
+                .or_else(|| base.concurrency.as_ref().and_then(|c| c.dedup)),
+            adaptive_concurrency: overlay
+                .concurrency
+                .as_ref()
+                .and_then(|c| c.adaptive_concurrency)
+                .or_else(|| {
+                    base.concurrency
+                        .as_ref()
+                        .and_then(|c| c.adaptive_concurrency)
+                }),
         }),
         display: Some(DisplayConfig {
             theme: overlay
@@ -172,6 +374,16 @@ fn merge(base: ConfigFile, overlay: ConfigFile) -> ConfigFile {
                 .as_ref()
                 .and_then(|d| d.fps)
                 .or_else(|| base.display.as_ref().and_then(|d| d.fps)),
+            spinner: overlay
+                .display
+                .as_ref()
+                .and_then(|d| d.spinner.clone())
+                .or_else(|| base.display.as_ref().and_then(|d| d.spinner.clone())),
+            tick_ms: overlay
+                .display
+                .as_ref()
+                .and_then(|d| d.tick_ms)
+                .or_else(|| base.display.as_ref().and_then(|d| d.tick_ms)),
         }),
     }
 }
@@ -203,11 +415,36 @@ pub fn apply_to_config_state(file_cfg: &ConfigFile, state: &mut ConfigState) {
         {
             state.s2_api_key = key.clone();
         }
+        if let Some(ref key) = api.ncbi_api_key
+            && !key.is_empty()
+        {
+            state.ncbi_api_key = key.clone();
+        }
+        if let Some(ref key) = api.core_api_key
+            && !key.is_empty()
+        {
+            state.core_api_key = key.clone();
+        }
         if let Some(ref email) = api.crossref_mailto
             && !email.is_empty()
         {
             state.crossref_mailto = email.clone();
         }
+        if let Some(ref ua) = api.user_agent
+            && !ua.is_empty()
+        {
+            state.user_agent = ua.clone();
+        }
+        if let Some(ref proxy) = api.proxy
+            && !proxy.is_empty()
+        {
+            state.proxy = proxy.clone();
+        }
+        if let Some(ref path) = api.extra_ca_cert
+            && !path.is_empty()
+        {
+            state.extra_ca_cert = path.clone();
+        }
     }
     if let Some(db) = &file_cfg.databases {
         if let Some(ref path) = db.dblp_offline_path
@@ -215,11 +452,27 @@ pub fn apply_to_config_state(file_cfg: &ConfigFile, state: &mut ConfigState) {
         {
             state.dblp_offline_path = path.clone();
         }
+        if let Some(v) = db.dblp_max_age_days {
+            state.dblp_max_age_days = v;
+        }
         if let Some(ref path) = db.acl_offline_path
             && !path.is_empty()
         {
             state.acl_offline_path = path.clone();
         }
+        if let Some(ref path) = db.local_bib_path
+            && !path.is_empty()
+        {
+            state.local_bib_path = path.clone();
+        }
+        if let Some(ref path) = db.retraction_db_path
+            && !path.is_empty()
+        {
+            state.retraction_db_path = path.clone();
+        }
+        if let Some(v) = db.retraction_max_age_days {
+            state.retraction_max_age_days = v;
+        }
         if let Some(ref path) = db.cache_path
             && !path.is_empty()
         {
@@ -232,8 +485,41 @@ pub fn apply_to_config_state(file_cfg: &ConfigFile, state: &mut ConfigState) {
                 }
             }
         }
+        if let Some(ref thresholds) = db.thresholds {
+            state.per_db_threshold = thresholds.clone();
+        }
+        if let Some(v) = db.author_min_overlap {
+            state.author_min_overlap = v.clamp(0.0, 1.0);
+        }
+        if let Some(v) = db.require_first_author {
+            state.require_first_author = v;
+        }
+        if let Some(v) = db.author_match_threshold {
+            state.author_match_threshold = v.clamp(0.0, 1.0);
+        }
+        if let Some(v) = db.min_match_score {
+            state.min_match_score = v.clamp(0.0, 1.0);
+        }
+        if let Some(v) = db.consensus_mode {
+            state.consensus_mode = v;
+        }
+        if let Some(v) = db.consensus_min {
+            state.consensus_min = v.max(1);
+        }
+        if let Some(v) = db.strict_title {
+            state.strict_title = v;
+        }
+        if let Some(v) = db.orcid_check {
+            state.orcid_check = v;
+        }
+        if let Some(v) = db.author_mismatch_is_failure {
+            state.author_mismatch_is_failure = v;
+        }
     }
     if let Some(conc) = &file_cfg.concurrency {
+        if let Some(v) = conc.max_concurrent_papers {
+            state.max_concurrent_papers = v;
+        }
         if let Some(v) = conc.num_workers {
             state.num_workers = v.max(1);
         }
@@ -243,12 +529,27 @@ pub fn apply_to_config_state(file_cfg: &ConfigFile, state: &mut ConfigState) {
         if let Some(v) = conc.db_timeout_short_secs {
             state.db_timeout_short_secs = v.max(1);
         }
+        if let Some(ref timeouts) = conc.db_timeouts {
+            state.db_timeouts = timeouts
+                .iter()
+                .map(|(name, &secs)| (name.clone(), secs.max(1)))
+                .collect();
+        }
         if let Some(v) = conc.max_rate_limit_retries {
             state.max_rate_limit_retries = v;
         }
         if let Some(v) = conc.max_archive_size_mb {
             state.max_archive_size_mb = v;
         }
+        if let Some(v) = conc.max_references {
+            state.max_references = v;
+        }
+        if let Some(v) = conc.dedup {
+            state.dedup = v;
+        }
+        if let Some(v) = conc.adaptive_concurrency {
+            state.adaptive_concurrency = v;
+        }
     }
     if let Some(disp) = &file_cfg.display {
         if let Some(ref theme) = disp.theme
@@ -259,9 +560,91 @@ pub fn apply_to_config_state(file_cfg: &ConfigFile, state: &mut ConfigState) {
         if let Some(fps) = disp.fps {
             state.fps = fps.clamp(1, 120);
         }
+        if let Some(ref spinner) = disp.spinner {
+            state.spinner = spinner.clone();
+        }
+        if let Some(ms) = disp.tick_ms {
+            state.tick_ms = ms;
+        }
     }
 }
 
+/// Apply settings from the environment onto a `ConfigState`, over the
+/// config file's. Settings the TUI has no field for are left to it.
+pub fn apply_env_to_config_state(env: &hallucinator_core::EnvConfig, state: &mut ConfigState) {
+    fn set<T: Clone>(target: &mut T, value: &Option<T>) {
+        if let Some(value) = value {
+            *target = value.clone();
+        }
+    }
+    fn set_path(target: &mut String, value: &Option<PathBuf>) {
+        if let Some(path) = value {
+            *target = path.display().to_string();
+        }
+    }
+    set(&mut state.openalex_key, &env.openalex_key);
+    set(&mut state.s2_api_key, &env.s2_api_key);
+    set(&mut state.ncbi_api_key, &env.ncbi_api_key);
+    set(&mut state.core_api_key, &env.core_api_key);
+    set(&mut state.crossref_mailto, &env.crossref_mailto);
+    set(&mut state.user_agent, &env.user_agent);
+    set(&mut state.proxy, &env.proxy);
+    set_path(&mut state.extra_ca_cert, &env.extra_ca_cert);
+    set_path(&mut state.dblp_offline_path, &env.dblp_offline_path);
+    set(&mut state.dblp_max_age_days, &env.dblp_max_age_days);
+    set_path(&mut state.acl_offline_path, &env.acl_offline_path);
+    set_path(&mut state.local_bib_path, &env.local_bib_path);
+    set_path(&mut state.retraction_db_path, &env.retraction_db_path);
+    set(
+        &mut state.retraction_max_age_days,
+        &env.retraction_max_age_days,
+    );
+    set_path(&mut state.cache_path, &env.cache_path);
+    set(&mut state.cache_max_entries, &env.cache_max_entries);
+    // The variable lists every disabled database, replacing the file's list
+    if let Some(ref disabled) = env.disabled_dbs {
+        for (name, enabled) in &mut state.disabled_dbs {
+            *enabled = !disabled.iter().any(|d| d.eq_ignore_ascii_case(name));
+        }
+    }
+    set(&mut state.only_dbs, &env.only_dbs);
+    if let Some(ref thresholds) = env.per_db_threshold {
+        state.per_db_threshold.extend(thresholds.clone());
+    }
+    set(&mut state.author_min_overlap, &env.author_match_min_overlap);
+    set(
+        &mut state.require_first_author,
+        &env.require_first_author_match,
+    );
+    set(
+        &mut state.author_match_threshold,
+        &env.author_match_threshold,
+    );
+    set(&mut state.min_match_score, &env.min_match_score);
+    set(&mut state.consensus_mode, &env.consensus_mode);
+    set(&mut state.consensus_min, &env.consensus_min);
+    set(&mut state.strict_title, &env.strict_title);
+    set(&mut state.orcid_check, &env.orcid_check);
+    set(
+        &mut state.author_mismatch_is_failure,
+        &env.author_mismatch_is_failure,
+    );
+    set(&mut state.num_workers, &env.num_workers);
+    set(&mut state.max_concurrent_papers, &env.max_concurrent_papers);
+    set(&mut state.dedup, &env.dedup);
+    set(&mut state.adaptive_concurrency, &env.adaptive_concurrency);
+    set(&mut state.db_timeout_secs, &env.db_timeout_secs);
+    set(&mut state.db_timeout_short_secs, &env.db_timeout_short_secs);
+    if let Some(ref timeouts) = env.db_timeouts {
+        state.db_timeouts.extend(timeouts.clone());
+    }
+    set(
+        &mut state.max_rate_limit_retries,
+        &env.max_rate_limit_retries,
+    );
+    set(&mut state.max_references, &env.max_references);
+}
+
 /// Convert a `ConfigState` into a `ConfigFile` for saving.
 pub fn from_config_state(state: &ConfigState) -> ConfigFile {
     let disabled: Vec<String> = state
@@ -283,11 +666,36 @@ pub fn from_config_state(state: &ConfigState) -> ConfigFile {
             } else {
                 Some(state.s2_api_key.clone())
             },
+            ncbi_api_key: if state.ncbi_api_key.is_empty() {
+                None
+            } else {
+                Some(state.ncbi_api_key.clone())
+            },
+            core_api_key: if state.core_api_key.is_empty() {
+                None
+            } else {
+                Some(state.core_api_key.clone())
+            },
             crossref_mailto: if state.crossref_mailto.is_empty() {
                 None
             } else {
                 Some(state.crossref_mailto.clone())
             },
+            user_agent: if state.user_agent.is_empty() {
+                None
+            } else {
+                Some(state.user_agent.clone())
+            },
+            proxy: if state.proxy.is_empty() {
+                None
+            } else {
+                Some(state.proxy.clone())
+            },
+            extra_ca_cert: if state.extra_ca_cert.is_empty() {
+                None
+            } else {
+                Some(state.extra_ca_cert.clone())
+            },
         }),
         databases: Some(DatabasesConfig {
             dblp_offline_path: if state.dblp_offline_path.is_empty() {
@@ -295,11 +703,23 @@ pub fn from_config_state(state: &ConfigState) -> ConfigFile {
             } else {
                 Some(state.dblp_offline_path.clone())
             },
+            dblp_max_age_days: Some(state.dblp_max_age_days),
             acl_offline_path: if state.acl_offline_path.is_empty() {
                 None
             } else {
                 Some(state.acl_offline_path.clone())
             },
+            local_bib_path: if state.local_bib_path.is_empty() {
+                None
+            } else {
+                Some(state.local_bib_path.clone())
+            },
+            retraction_db_path: if state.retraction_db_path.is_empty() {
+                None
+            } else {
+                Some(state.retraction_db_path.clone())
+            },
+            retraction_max_age_days: Some(state.retraction_max_age_days),
             cache_path: if state.cache_path.is_empty() {
                 None
             } else {
@@ -310,17 +730,46 @@ pub fn from_config_state(state: &ConfigState) -> ConfigFile {
             } else {
                 Some(disabled)
             },
+            thresholds: if state.per_db_threshold.is_empty() {
+                None
+            } else {
+                Some(state.per_db_threshold.clone())
+            },
+            author_min_overlap: Some(state.author_min_overlap),
+            require_first_author: Some(state.require_first_author),
+            author_match_threshold: Some(state.author_match_threshold),
+            min_match_score: Some(state.min_match_score),
+            consensus_mode: Some(state.consensus_mode),
+            consensus_min: Some(state.consensus_min),
+            strict_title: Some(state.strict_title),
+            orcid_check: Some(state.orcid_check),
+            author_mismatch_is_failure: Some(state.author_mismatch_is_failure),
         }),
         concurrency: Some(ConcurrencyConfig {
+            max_concurrent_papers: Some(state.max_concurrent_papers),
             num_workers: Some(state.num_workers),
             db_timeout_secs: Some(state.db_timeout_secs),
             db_timeout_short_secs: Some(state.db_timeout_short_secs),
+            db_timeouts: if state.db_timeouts.is_empty() {
+                None
+            } else {
+                Some(state.db_timeouts.clone())
+            },
             max_rate_limit_retries: Some(state.max_rate_limit_retries),
             max_archive_size_mb: Some(state.max_archive_size_mb),
+            max_references: Some(state.max_references),
+            dedup: Some(state.dedup),
+            adaptive_concurrency: Some(state.adaptive_concurrency),
         }),
         display: Some(DisplayConfig {
             theme: Some(state.theme_name.clone()),
             fps: Some(state.fps),
+            spinner: if state.spinner.is_empty() {
+                None
+            } else {
+                Some(state.spinner.clone())
+            },
+            tick_ms: Some(state.tick_ms),
         }),
     }
 }
@@ -418,8 +867,10 @@ mod tests {
             }),
             ..Default::default()
         };
-        let mut state = ConfigState::default();
-        state.cache_path = "existing.db".to_string();
+        let mut state = ConfigState {
+            cache_path: "existing.db".to_string(),
+            ..Default::default()
+        };
 
         apply_to_config_state(&file_cfg, &mut state);
         assert_eq!(state.cache_path, "existing.db"); // not overwritten
@@ -428,8 +879,10 @@ mod tests {
     #[test]
     fn apply_none_cache_path_does_not_overwrite() {
         let file_cfg = ConfigFile::default(); // no databases section
-        let mut state = ConfigState::default();
-        state.cache_path = "existing.db".to_string();
+        let mut state = ConfigState {
+            cache_path: "existing.db".to_string(),
+            ..Default::default()
+        };
 
         apply_to_config_state(&file_cfg, &mut state);
         assert_eq!(state.cache_path, "existing.db"); // preserved
@@ -437,8 +890,10 @@ mod tests {
 
     #[test]
     fn from_config_state_cache_path() {
-        let mut state = ConfigState::default();
-        state.cache_path = "/tmp/cache.db".to_string();
+        let state = ConfigState {
+            cache_path: "/tmp/cache.db".to_string(),
+            ..Default::default()
+        };
         let file_cfg = from_config_state(&state);
         assert_eq!(
             file_cfg.databases.unwrap().cache_path.unwrap(),
@@ -456,9 +911,15 @@ mod tests {
     #[test]
     fn full_round_trip_config_state_toml_config_state() {
         // ConfigState → ConfigFile → TOML → ConfigFile → ConfigState
-        let mut state = ConfigState::default();
-        state.cache_path = "/data/hallucinator_cache.db".to_string();
-        state.openalex_key = "test-key".to_string();
+        let state = ConfigState {
+            cache_path: "/data/hallucinator_cache.db".to_string(),
+            openalex_key: "test-key".to_string(),
+            crossref_mailto: "lab@example.org".to_string(),
+            local_bib_path: "/home/me/library.bib".to_string(),
+            retraction_db_path: "/data/retraction_watch.csv".to_string(),
+            retraction_max_age_days: 14,
+            ..Default::default()
+        };
 
         let file_cfg = from_config_state(&state);
         let toml_str = toml::to_string_pretty(&file_cfg).unwrap();
@@ -469,5 +930,200 @@ mod tests {
 
         assert_eq!(state2.cache_path, "/data/hallucinator_cache.db");
         assert_eq!(state2.openalex_key, "test-key");
+        assert_eq!(state2.crossref_mailto, "lab@example.org");
+        assert_eq!(state2.local_bib_path, "/home/me/library.bib");
+        assert_eq!(state2.retraction_db_path, "/data/retraction_watch.csv");
+        assert_eq!(state2.retraction_max_age_days, 14);
+    }
+
+    #[test]
+    fn thresholds_parse_and_apply() {
+        let toml_str = "[databases]\nthresholds = { DBLP = 0.97, SSRN = 0.9 }\n";
+        let parsed: ConfigFile = toml::from_str(toml_str).unwrap();
+        let mut state = ConfigState::default();
+        apply_to_config_state(&parsed, &mut state);
+        assert_eq!(state.per_db_threshold.get("DBLP"), Some(&0.97));
+        assert_eq!(state.per_db_threshold.get("SSRN"), Some(&0.9));
+
+        let saved = from_config_state(&state);
+        assert_eq!(saved.databases.unwrap().thresholds.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn max_concurrent_papers_parse_and_apply() {
+        let mut state = ConfigState::default();
+        assert_eq!(
+            state.max_concurrent_papers,
+            hallucinator_core::pool::DEFAULT_MAX_CONCURRENT_PAPERS
+        );
+
+        let parsed: ConfigFile =
+            toml::from_str("[concurrency]\nmax_concurrent_papers = 0\n").unwrap();
+        apply_to_config_state(&parsed, &mut state);
+        assert_eq!(state.max_concurrent_papers, 0);
+
+        let saved = from_config_state(&state);
+        assert_eq!(saved.concurrency.unwrap().max_concurrent_papers, Some(0));
+    }
+
+    #[test]
+    fn dedup_parse_and_apply() {
+        let mut state = ConfigState::default();
+        assert!(!state.dedup);
+
+        let parsed: ConfigFile = toml::from_str("[concurrency]\ndedup = true\n").unwrap();
+        apply_to_config_state(&parsed, &mut state);
+        assert!(state.dedup);
+
+        let saved = from_config_state(&state);
+        assert_eq!(saved.concurrency.unwrap().dedup, Some(true));
+    }
+
+    #[test]
+    fn adaptive_concurrency_parse_and_apply() {
+        let mut state = ConfigState::default();
+        assert!(!state.adaptive_concurrency);
+
+        let parsed: ConfigFile =
+            toml::from_str("[concurrency]\nadaptive_concurrency = true\n").unwrap();
+        apply_to_config_state(&parsed, &mut state);
+        assert!(state.adaptive_concurrency);
+
+        let saved = from_config_state(&state);
+        assert_eq!(saved.concurrency.unwrap().adaptive_concurrency, Some(true));
+    }
+
+    #[test]
+    fn db_timeouts_parse_and_apply() {
+        let toml_str = "[concurrency]\ndb_timeout_secs = 10\n\
+                        db_timeouts = { \"Europe PMC\" = 30, DBLP = 0 }\n";
+        let parsed: ConfigFile = toml::from_str(toml_str).unwrap();
+        let mut state = ConfigState::default();
+        apply_to_config_state(&parsed, &mut state);
+        assert_eq!(state.db_timeouts.get("Europe PMC"), Some(&30));
+        assert_eq!(state.db_timeouts.get("DBLP"), Some(&1));
+
+        let saved = toml::to_string_pretty(&from_config_state(&state)).unwrap();
+        let reparsed: ConfigFile = toml::from_str(&saved).unwrap();
+        let timeouts = reparsed.concurrency.unwrap().db_timeouts.unwrap();
+        assert_eq!(timeouts.get("Europe PMC"), Some(&30));
+    }
+
+    #[test]
+    fn author_policy_parse_and_apply() {
+        let toml_str = "[databases]\nauthor_min_overlap = 0.5\nrequire_first_author = true\n\
+                        author_match_threshold = 0.85\nmin_match_score = 0.95\n";
+        let parsed: ConfigFile = toml::from_str(toml_str).unwrap();
+        let mut state = ConfigState::default();
+        apply_to_config_state(&parsed, &mut state);
+        assert_eq!(state.author_min_overlap, 0.5);
+        assert!(state.require_first_author);
+        assert_eq!(state.author_match_threshold, 0.85);
+        assert_eq!(state.min_match_score, 0.95);
+
+        let saved = from_config_state(&state).databases.unwrap();
+        assert_eq!(saved.author_min_overlap, Some(0.5));
+        assert_eq!(saved.require_first_author, Some(true));
+        assert_eq!(saved.author_match_threshold, Some(0.85));
+        assert_eq!(saved.min_match_score, Some(0.95));
+    }
+
+    #[test]
+    fn consensus_parse_and_apply() {
+        let toml_str = "[databases]\nconsensus_mode = true\nconsensus_min = 0\n";
+        let parsed: ConfigFile = toml::from_str(toml_str).unwrap();
+        let mut state = ConfigState::default();
+        apply_to_config_state(&parsed, &mut state);
+        assert!(state.consensus_mode);
+        // At least one database always has to match
+        assert_eq!(state.consensus_min, 1);
+
+        let saved = from_config_state(&state).databases.unwrap();
+        assert_eq!(saved.consensus_mode, Some(true));
+        assert_eq!(saved.consensus_min, Some(1));
+    }
+
+    #[test]
+    fn strict_title_parse_and_apply() {
+        let mut state = ConfigState::default();
+        assert!(!state.strict_title);
+
+        let parsed: ConfigFile = toml::from_str("[databases]\nstrict_title = true\n").unwrap();
+        apply_to_config_state(&parsed, &mut state);
+        assert!(state.strict_title);
+
+        let saved = from_config_state(&state).databases.unwrap();
+        assert_eq!(saved.strict_title, Some(true));
+    }
+
+    #[test]
+    fn orcid_check_parse_and_apply() {
+        let mut state = ConfigState::default();
+        assert!(!state.orcid_check);
+
+        let parsed: ConfigFile = toml::from_str("[databases]\norcid_check = true\n").unwrap();
+        apply_to_config_state(&parsed, &mut state);
+        assert!(state.orcid_check);
+
+        let saved = from_config_state(&state).databases.unwrap();
+        assert_eq!(saved.orcid_check, Some(true));
+    }
+
+    #[test]
+    fn author_mismatch_is_failure_parse_and_apply() {
+        let mut state = ConfigState::default();
+        assert!(!state.author_mismatch_is_failure);
+
+        let parsed: ConfigFile =
+            toml::from_str("[databases]\nauthor_mismatch_is_failure = true\n").unwrap();
+        apply_to_config_state(&parsed, &mut state);
+        assert!(state.author_mismatch_is_failure);
+
+        let saved = from_config_state(&state).databases.unwrap();
+        assert_eq!(saved.author_mismatch_is_failure, Some(true));
+    }
+
+    #[test]
+    fn dblp_max_age_days_parse_and_apply() {
+        let mut state = ConfigState::default();
+        assert_eq!(
+            state.dblp_max_age_days,
+            hallucinator_dblp::DEFAULT_MAX_AGE_DAYS
+        );
+
+        let parsed: ConfigFile = toml::from_str("[databases]\ndblp_max_age_days = 90\n").unwrap();
+        apply_to_config_state(&parsed, &mut state);
+        assert_eq!(state.dblp_max_age_days, 90);
+
+        let saved = from_config_state(&state).databases.unwrap();
+        assert_eq!(saved.dblp_max_age_days, Some(90));
+    }
+
+    #[test]
+    fn env_overrides_file_settings() {
+        let parsed: ConfigFile = toml::from_str(
+            "[databases]\ncache_path = \"file.db\"\ndisabled = [\"OpenAlex\"]\n\n[concurrency]\nnum_workers = 2\n",
+        )
+        .unwrap();
+        let mut state = ConfigState::default();
+        apply_to_config_state(&parsed, &mut state);
+
+        let env = hallucinator_core::EnvConfig {
+            cache_path: Some(PathBuf::from("env.db")),
+            disabled_dbs: Some(vec!["pubmed".to_string()]),
+            strict_title: Some(true),
+            ..Default::default()
+        };
+        apply_env_to_config_state(&env, &mut state);
+        assert_eq!(state.cache_path, "env.db");
+        assert_eq!(state.num_workers, 2);
+        assert!(state.strict_title);
+        let disabled: Vec<&str> = state
+            .disabled_dbs
+            .iter()
+            .filter(|(_, enabled)| !enabled)
+            .map(|(name, _)| name.as_str())
+            .collect();
+        assert_eq!(disabled, vec!["PubMed"]);
     }
 }
@@ -0,0 +1,260 @@
+//! Headless batch mode: run without touching the terminal and print a plain
+//! summary, for scripts, CI, and pipes where raw mode is unavailable. The
+//! exit status follows the CLI's `check`.
+
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+use crate::app::App;
+use crate::backend;
+use crate::checkpoint::{Checkpoint, ResumeState};
+use crate::load;
+use crate::model::config::ConfigState;
+use crate::model::summary::BatchSummary;
+use crate::persistence::ResultLog;
+use crate::theme::Theme;
+use crate::tui_event::BackendEvent;
+
+/// Offline databases opened at startup, handed to the batch as-is.
+pub struct OfflineDbs {
+    pub dblp: Option<Arc<Mutex<hallucinator_dblp::DblpDatabase>>>,
+    pub acl: Option<Arc<Mutex<hallucinator_acl::AclDatabase>>>,
+}
+
+/// Check `files` (and/or show results from `load`) and write a summary to
+/// stdout. Per-paper progress goes to stderr. Returns the exit status.
+#[allow(clippy::too_many_arguments)]
+pub async fn run(
+    files: Vec<PathBuf>,
+    load: Option<PathBuf>,
+    config_state: ConfigState,
+    dbs: OfflineDbs,
+    check_openalex_authors: bool,
+    pdf_password: Option<String>,
+    log: Option<Arc<ResultLog>>,
+    checkpoint: Option<PathBuf>,
+    resume: Option<Arc<ResumeState>>,
+) -> anyhow::Result<i32> {
+    let filenames: Vec<String> = files
+        .iter()
+        .map(|p| {
+            p.file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| p.display().to_string())
+        })
+        .collect();
+    let mut app = App::new(filenames.clone(), Theme::hacker());
+    app.file_paths = files.clone();
+    app.config_state = config_state;
+    app.checkpoint = checkpoint.map(Checkpoint::new);
+    if let Some(resume) = resume.as_ref().filter(|r| !r.is_empty()) {
+        eprintln!("Resuming: {} references already checked", resume.len());
+    }
+
+    if let Some(ref load_path) = load {
+        let loaded = load::load_results_file(load_path)
+            .map_err(|e| anyhow::anyhow!("Failed to load results: {}", e))?;
+        for (paper, refs) in loaded {
+            app.papers.push(paper);
+            app.ref_states.push(refs);
+            app.file_paths.push(PathBuf::new()); // placeholder
+        }
+    }
+
+    if !files.is_empty() {
+        // Count only the files checked here, not papers shown from --load.
+        let total = files.len();
+        let (mut config, context) = app.build_config_with_context();
+        config.dblp_offline_db = dbs.dblp;
+        config.acl_offline_db = dbs.acl;
+        config.check_openalex_authors = check_openalex_authors;
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        backend::load_retraction_db(&mut config, 0, &tx);
+        let cancel = CancellationToken::new();
+        let cancel_for_signal = cancel.clone();
+        tokio::spawn(async move {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                cancel_for_signal.cancel();
+            }
+        });
+        let papers = files
+            .into_iter()
+            .zip(filenames)
+            .map(|(path, name)| (path, name, cancel.child_token()))
+            .collect();
+        let options = hallucinator_pdf::ExtractionOptions {
+            password: pdf_password,
+            ..Default::default()
+        };
+        tokio::spawn(backend::run_batch_with_offset(
+            papers, config, options, context, tx, cancel, 0, log, resume,
+        ));
+
+        let mut done = 0;
+        while let Some(event) = rx.recv().await {
+            let batch_complete = matches!(event, BackendEvent::BatchComplete);
+            match &event {
+                BackendEvent::ExtractionFailed {
+                    paper_index,
+                    encrypted: true,
+                    ..
+                } => {
+                    done += 1;
+                    eprintln!(
+                        "[{}/{}] {}: encrypted, skipped (pass --pdf-password)",
+                        done, total, app.papers[*paper_index].filename
+                    );
+                }
+                BackendEvent::ExtractionFailed {
+                    paper_index, error, ..
+                } => {
+                    done += 1;
+                    eprintln!(
+                        "[{}/{}] {}: extraction failed: {}",
+                        done, total, app.papers[*paper_index].filename, error
+                    );
+                }
+                BackendEvent::PaperComplete { paper_index }
+                    if app.papers[*paper_index].error.is_none() =>
+                {
+                    done += 1;
+                    eprintln!("[{}/{}] {}", done, total, app.papers[*paper_index].filename);
+                }
+                BackendEvent::Progress { paper_index, event } => {
+                    if let hallucinator_core::ProgressEvent::Warning {
+                        failed_dbs,
+                        message,
+                        ..
+                    } = event.as_ref()
+                        && failed_dbs.is_empty()
+                    {
+                        eprintln!("{}: {}", app.papers[*paper_index].filename, message);
+                    }
+                }
+                _ => {}
+            }
+            app.handle_backend_event(event);
+            if batch_complete {
+                break;
+            }
+        }
+    }
+
+    let summary = app.summary();
+    let failed = app.papers.iter().filter(|p| p.error.is_some()).count();
+    let (code, reason) = exit_status(
+        &summary,
+        failed,
+        app.config_state.author_mismatch_is_failure,
+    );
+    let mut stdout = io::stdout().lock();
+    print_summary(&mut stdout, &app, &summary)?;
+    writeln!(stdout, "Exit status {}: {}", code, reason)?;
+    stdout.flush()?;
+    Ok(code)
+}
+
+/// One line per paper, then the totals, as in the CLI's batch summary.
+fn print_summary(w: &mut impl Write, app: &App, summary: &BatchSummary) -> io::Result<()> {
+    let sep = "=".repeat(60);
+    writeln!(w, "{}", sep)?;
+    writeln!(w, "SUMMARY ({} papers)", summary.papers)?;
+    writeln!(w, "{}", sep)?;
+    for paper in &app.papers {
+        match &paper.error {
+            Some(error) => writeln!(w, "  {}  could not be checked: {}", paper.filename, error)?,
+            None => writeln!(
+                w,
+                "  {}  {} verified, {} not found, {} author mismatches, {} retracted",
+                paper.filename,
+                paper.stats.verified,
+                paper.stats.not_found,
+                paper.stats.author_mismatch,
+                paper.stats.retracted
+            )?,
+        }
+    }
+    writeln!(w)?;
+    writeln!(w, "  References analyzed: {}", summary.checked)?;
+    writeln!(w, "  Verified: {}", summary.verified)?;
+    writeln!(w, "  Author mismatches: {}", summary.author_mismatch)?;
+    writeln!(
+        w,
+        "  Not found (potential hallucinations): {}",
+        summary.not_found
+    )?;
+    writeln!(w, "  Retracted papers: {}", summary.retracted)?;
+    writeln!(w, "  Skipped: {}", summary.skipped)?;
+    writeln!(w)?;
+    Ok(())
+}
+
+/// The CLI's exit status for these totals, with the finding that set it:
+/// 3 for a retracted citation, 2 for an author mismatch (1 with
+/// `author_mismatch_is_failure`), 1 for a reference not found or a paper that
+/// could not be checked, else 0.
+fn exit_status(
+    summary: &BatchSummary,
+    failed_papers: usize,
+    author_mismatch_is_failure: bool,
+) -> (i32, String) {
+    if summary.retracted > 0 {
+        (3, format!("{} retracted paper(s) cited", summary.retracted))
+    } else if summary.author_mismatch > 0 && !author_mismatch_is_failure {
+        (
+            2,
+            format!("{} author mismatch(es)", summary.author_mismatch),
+        )
+    } else if summary.not_found + summary.author_mismatch > 0 {
+        let mut reason = format!("{} reference(s) not found", summary.not_found);
+        if summary.author_mismatch > 0 {
+            reason.push_str(&format!(
+                ", {} author mismatch(es) (author_mismatch_is_failure)",
+                summary.author_mismatch
+            ));
+        }
+        (1, reason)
+    } else if failed_papers > 0 {
+        (
+            1,
+            format!("{} paper(s) could not be checked", failed_papers),
+        )
+    } else {
+        (0, "every checked reference was verified".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn summary(not_found: usize, author_mismatch: usize, retracted: usize) -> BatchSummary {
+        let mut summary = BatchSummary::new(&[], std::iter::empty(), None, Duration::ZERO);
+        summary.not_found = not_found;
+        summary.author_mismatch = author_mismatch;
+        summary.retracted = retracted;
+        summary
+    }
+
+    #[test]
+    fn exit_status_follows_the_most_severe_finding() {
+        assert_eq!(exit_status(&summary(0, 0, 0), 0, false).0, 0);
+        assert_eq!(exit_status(&summary(2, 0, 0), 0, false).0, 1);
+        assert_eq!(exit_status(&summary(2, 1, 0), 0, false).0, 2);
+        assert_eq!(exit_status(&summary(2, 1, 1), 0, false).0, 3);
+        assert_eq!(exit_status(&summary(0, 0, 0), 1, false).0, 1);
+    }
+
+    #[test]
+    fn author_mismatch_is_failure_exits_with_one() {
+        let (code, reason) = exit_status(&summary(0, 1, 0), 0, true);
+        assert_eq!(code, 1);
+        assert!(reason.contains("1 author mismatch"));
+    }
+}
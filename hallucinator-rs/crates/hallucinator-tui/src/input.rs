@@ -46,23 +46,29 @@ fn map_key_normal(key: &KeyEvent) -> Action {
         KeyCode::Char('G') => Action::GoBottom,
         KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => Action::SaveConfig,
         KeyCode::Char('s') => Action::CycleSort,
+        KeyCode::Char('S') => Action::OpenSummary,
         KeyCode::Char('f') => Action::CycleFilter,
         KeyCode::Char('/') => Action::StartSearch,
         KeyCode::Char('n') => Action::NextMatch,
         KeyCode::Char('N') => Action::PrevMatch,
         KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => Action::Retry,
         KeyCode::Char('r') => Action::StartProcessing,
-        KeyCode::Char('R') => Action::RetryAll,
+        KeyCode::Char('R') => Action::RetryFailed,
+        KeyCode::Char('x') => Action::CancelPaper,
         KeyCode::Char('e') => Action::Export,
-        KeyCode::Char('o') | KeyCode::Char('a') => Action::AddFiles,
+        KeyCode::Char('E') => Action::ExportProblems,
+        KeyCode::Char('o') => Action::AddFiles,
+        KeyCode::Char('a') => Action::AddPaper,
         KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => Action::PageDown,
         KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => Action::PageUp,
         KeyCode::Char('y') => Action::CopyToClipboard,
-        KeyCode::Char(',') | KeyCode::Char('c') => Action::OpenConfig,
+        KeyCode::Char(',') => Action::OpenConfig,
+        KeyCode::Char('c') => Action::ToggleVerifiedCollapse,
         KeyCode::Char(' ') => Action::ToggleSafe,
         KeyCode::Tab => Action::ToggleActivityPanel,
         KeyCode::Char('b') => Action::BuildDatabase,
         KeyCode::Char('?') => Action::ToggleHelp,
+        KeyCode::Char('t') => Action::CycleTheme,
         KeyCode::PageDown => Action::PageDown,
         KeyCode::PageUp => Action::PageUp,
         KeyCode::Home => Action::GoTop,
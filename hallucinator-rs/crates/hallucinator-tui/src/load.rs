@@ -4,7 +4,8 @@ use std::time::Duration;
 use serde::Deserialize;
 
 use hallucinator_core::{
-    ArxivInfo, DbResult, DbStatus, DoiInfo, RetractionInfo, Status, ValidationResult,
+    ArxivInfo, DbResult, DbStatus, DoiInfo, FailedDb, FailureReason, FlagReason, MatchCandidate,
+    RetractionInfo, Status, TitleNearMiss, ValidationResult,
 };
 
 use crate::model::paper::{FpReason, RefPhase, RefState};
@@ -16,6 +17,13 @@ use crate::model::queue::{PaperPhase, PaperState, PaperVerdict};
 // export format and the simplified persistence format.
 // ---------------------------------------------------------------------------
 
+/// The versioned envelope around the papers, written since schema version 1.
+#[derive(Deserialize)]
+struct LoadedExport {
+    schema_version: u32,
+    papers: Vec<LoadedFile>,
+}
+
 #[derive(Deserialize)]
 struct LoadedFile {
     filename: String,
@@ -40,16 +48,32 @@ struct LoadedRef {
     raw_citation: Option<String>,
     status: String,
     source: Option<String>,
+    /// Absent in exports written before consensus votes were kept apart.
+    #[serde(default)]
+    agreeing_dbs: Vec<String>,
     ref_authors: Option<Vec<String>>,
     found_authors: Option<Vec<String>>,
     paper_url: Option<String>,
     failed_dbs: Option<Vec<String>>,
+    /// Why each of `failed_dbs` failed; absent in older exports.
+    failed_db_reasons: Option<Vec<LoadedFailedDb>>,
     /// Simplified persistence format field (rich format uses retraction_info).
     retracted: Option<bool>,
     doi_info: Option<LoadedDoiInfo>,
     arxiv_info: Option<LoadedArxivInfo>,
     retraction_info: Option<LoadedRetractionInfo>,
+    /// Absent in exports written before strict title mode existed.
+    title_near_miss: Option<LoadedTitleNearMiss>,
     db_results: Option<Vec<LoadedDbResult>>,
+    /// Why the reference was flagged; absent in older exports.
+    reason: Option<String>,
+    /// Absent in exports written before match scores were recorded.
+    match_score: Option<f64>,
+    /// Absent in exports written before venues and years were recorded.
+    venue: Option<String>,
+    year: Option<u32>,
+    /// Absent in exports written before the ORCID check existed.
+    orcid: Option<String>,
     /// FP reason string (new format).
     fp_reason: Option<String>,
     /// Legacy boolean field — if true and no fp_reason, maps to KnownGood.
@@ -63,6 +87,11 @@ struct LoadedDoiInfo {
     doi: String,
     valid: bool,
     title: Option<String>,
+    /// Absent in exports written before structural checks existed.
+    structurally_valid: Option<bool>,
+    /// Absent in exports written before DOI titles were compared.
+    #[serde(default)]
+    title_mismatch: bool,
 }
 
 #[derive(Deserialize)]
@@ -70,6 +99,9 @@ struct LoadedArxivInfo {
     arxiv_id: String,
     valid: bool,
     title: Option<String>,
+    /// Absent in exports written before arXiv versions were tracked.
+    cited_version: Option<u32>,
+    latest_version: Option<u32>,
 }
 
 #[derive(Deserialize)]
@@ -79,11 +111,34 @@ struct LoadedRetractionInfo {
     retraction_source: Option<String>,
 }
 
+#[derive(Deserialize)]
+struct LoadedFailedDb {
+    name: String,
+    reason: String,
+}
+
+#[derive(Deserialize)]
+struct LoadedTitleNearMiss {
+    cited_title: String,
+    found_title: String,
+    similarity: f64,
+}
+
 #[derive(Deserialize)]
 struct LoadedDbResult {
     db: String,
     status: String,
     elapsed_ms: Option<u64>,
+    title: Option<String>,
+    authors: Option<Vec<String>>,
+    url: Option<String>,
+    alternates: Option<Vec<LoadedMatchCandidate>>,
+    score: Option<f64>,
+}
+
+#[derive(Deserialize)]
+struct LoadedMatchCandidate {
+    title: String,
     authors: Option<Vec<String>>,
     url: Option<String>,
 }
@@ -169,6 +224,7 @@ fn convert_loaded(loaded: LoadedFile) -> (PaperState, Vec<RefState>) {
                 authors,
                 doi: None,
                 arxiv_id: None,
+                arxiv_version: None,
             });
             continue;
         }
@@ -190,6 +246,7 @@ fn convert_loaded(loaded: LoadedFile) -> (PaperState, Vec<RefState>) {
                     authors,
                     doi,
                     arxiv_id,
+                    arxiv_version: None,
                 });
                 continue;
             }
@@ -200,6 +257,10 @@ fn convert_loaded(loaded: LoadedFile) -> (PaperState, Vec<RefState>) {
             doi: d.doi.clone(),
             valid: d.valid,
             title: d.title.clone(),
+            structurally_valid: d
+                .structurally_valid
+                .unwrap_or_else(|| hallucinator_core::doi::looks_valid(&d.doi)),
+            title_mismatch: d.title_mismatch,
         });
 
         // Build arXiv info
@@ -207,6 +268,8 @@ fn convert_loaded(loaded: LoadedFile) -> (PaperState, Vec<RefState>) {
             arxiv_id: a.arxiv_id.clone(),
             valid: a.valid,
             title: a.title.clone(),
+            cited_version: a.cited_version,
+            latest_version: a.latest_version,
         });
 
         // Build retraction info — prefer rich retraction_info, fall back to bool flag
@@ -236,9 +299,26 @@ fn convert_loaded(loaded: LoadedFile) -> (PaperState, Vec<RefState>) {
                         db_name: db.db.clone(),
                         status: convert_db_status(&db.status),
                         elapsed: db.elapsed_ms.map(Duration::from_millis),
+                        found_title: db.title.clone(),
                         found_authors: db.authors.clone().unwrap_or_default(),
                         paper_url: db.url.clone(),
                         error_message: None,
+                        alternates: db
+                            .alternates
+                            .iter()
+                            .flatten()
+                            .map(|alt| MatchCandidate {
+                                title: alt.title.clone(),
+                                authors: alt.authors.clone().unwrap_or_default(),
+                                paper_url: alt.url.clone(),
+                                venue: None,
+                                year: None,
+                            })
+                            .collect(),
+                        threshold: None,
+                        score: db.score,
+                        venue: None,
+                        year: None,
                     })
                     .collect()
             })
@@ -251,19 +331,51 @@ fn convert_loaded(loaded: LoadedFile) -> (PaperState, Vec<RefState>) {
             .filter(|s| !s.is_empty())
             .cloned();
 
+        // Older exports predate the field; derive it the way the checker does.
+        let reason = loaded_ref
+            .reason
+            .as_deref()
+            .and_then(|r| r.parse().ok())
+            .or_else(|| FlagReason::classify(&status, doi_info.as_ref()));
+
         let result = ValidationResult {
             title: title.clone(),
             raw_citation: loaded_ref.raw_citation.clone().unwrap_or_default(),
             ref_authors: loaded_ref.ref_authors.clone().unwrap_or_default(),
             status,
             source,
+            agreeing_dbs: loaded_ref.agreeing_dbs.clone(),
             found_authors: loaded_ref.found_authors.clone().unwrap_or_default(),
             paper_url: loaded_ref.paper_url.clone(),
-            failed_dbs: loaded_ref.failed_dbs.clone().unwrap_or_default(),
+            failed_dbs: match &loaded_ref.failed_db_reasons {
+                Some(reasons) => reasons
+                    .iter()
+                    .map(|f| {
+                        let reason = f.reason.parse().unwrap_or(FailureReason::Other);
+                        FailedDb::new(f.name.clone(), reason)
+                    })
+                    .collect(),
+                None => loaded_ref
+                    .failed_dbs
+                    .iter()
+                    .flatten()
+                    .map(|name| FailedDb::new(name.clone(), FailureReason::Other))
+                    .collect(),
+            },
             db_results,
             doi_info: doi_info.clone(),
             arxiv_info: arxiv_info.clone(),
             retraction_info,
+            reason,
+            match_score: loaded_ref.match_score,
+            title_near_miss: loaded_ref.title_near_miss.as_ref().map(|m| TitleNearMiss {
+                cited_title: m.cited_title.clone(),
+                found_title: m.found_title.clone(),
+                similarity: m.similarity,
+            }),
+            venue: loaded_ref.venue.clone(),
+            year: loaded_ref.year,
+            orcid: loaded_ref.orcid.clone(),
         };
 
         let is_retracted = result
@@ -286,6 +398,7 @@ fn convert_loaded(loaded: LoadedFile) -> (PaperState, Vec<RefState>) {
             authors: ref_authors,
             doi: ref_doi,
             arxiv_id: ref_arxiv,
+            arxiv_version: None,
         });
     }
 
@@ -314,21 +427,34 @@ fn convert_loaded(loaded: LoadedFile) -> (PaperState, Vec<RefState>) {
 
 /// Load previously saved results from a JSON file.
 ///
-/// Handles both formats:
-/// - **Export format**: JSON array of paper objects (from TUI export or `--load`)
-/// - **Persistence format**: Single JSON object (from auto-save in `~/.cache/hallucinator/runs/`)
+/// Handles these formats:
+/// - **Export format**: `{"schema_version": N, "papers": [...]}`, written by
+///   the TUI export and the auto-save in `~/.cache/hallucinator/runs/`.
+///   Files from a newer schema than this build knows are refused.
+/// - **Legacy export format**: a bare JSON array of paper objects
+/// - **Legacy persistence format**: a single paper object
 pub fn load_results_file(path: &Path) -> Result<Vec<(PaperState, Vec<RefState>)>, String> {
     let content = std::fs::read_to_string(path)
         .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
 
     let loaded_files: Vec<LoadedFile> =
-        if let Ok(arr) = serde_json::from_str::<Vec<LoadedFile>>(&content) {
+        if let Ok(export) = serde_json::from_str::<LoadedExport>(&content) {
+            if export.schema_version > hallucinator_reporting::JSON_SCHEMA_VERSION {
+                return Err(format!(
+                    "{} uses results schema version {}; this build reads up to version {}",
+                    path.display(),
+                    export.schema_version,
+                    hallucinator_reporting::JSON_SCHEMA_VERSION
+                ));
+            }
+            export.papers
+        } else if let Ok(arr) = serde_json::from_str::<Vec<LoadedFile>>(&content) {
             arr
         } else if let Ok(single) = serde_json::from_str::<LoadedFile>(&content) {
             vec![single]
         } else {
             return Err(
-                "Invalid JSON: expected export format (array) or persistence format (object)"
+                "Invalid JSON: expected an export (versioned object or array) or a single paper"
                     .to_string(),
             );
         };
@@ -339,3 +465,153 @@ pub fn load_results_file(path: &Path) -> Result<Vec<(PaperState, Vec<RefState>)>
 
     Ok(loaded_files.into_iter().map(convert_loaded).collect())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hallucinator_core::CheckStats;
+    use hallucinator_reporting::{ReportPaper, ReportRef, SkipInfo};
+
+    fn result(title: &str, status: Status) -> ValidationResult {
+        ValidationResult {
+            title: title.to_string(),
+            raw_citation: format!("[1] {title}"),
+            ref_authors: vec!["A. Author".to_string()],
+            status,
+            source: None,
+            agreeing_dbs: vec![],
+            found_authors: vec![],
+            paper_url: None,
+            failed_dbs: vec![],
+            db_results: vec![],
+            doi_info: None,
+            arxiv_info: None,
+            retraction_info: None,
+            reason: None,
+            match_score: None,
+            title_near_miss: None,
+            venue: None,
+            year: None,
+            orcid: None,
+        }
+    }
+
+    fn report_ref(index: usize, title: &str) -> ReportRef {
+        ReportRef {
+            index,
+            title: title.to_string(),
+            skip_info: None,
+            fp_reason: None,
+        }
+    }
+
+    fn write_temp(content: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        std::io::Write::write_all(&mut file, content.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn exported_json_loads_back() {
+        let mut verified = result("Real Paper", Status::Verified);
+        verified.source = Some("CrossRef".to_string());
+        verified.match_score = Some(0.98);
+        verified.agreeing_dbs = vec!["CrossRef".to_string(), "DBLP".to_string()];
+        let near_miss = TitleNearMiss {
+            cited_title: "Real Paper".to_string(),
+            found_title: "Real Papers".to_string(),
+            similarity: 0.95,
+        };
+        verified.title_near_miss = Some(near_miss.clone());
+        verified.venue = Some("Nature".to_string());
+        verified.year = Some(2021);
+        let mut missing = result("Made Up", Status::NotFound);
+        missing.failed_dbs = vec![FailedDb::new("DBLP", FailureReason::RateLimited)];
+        let results = vec![Some(verified), Some(missing), None];
+        let stats = CheckStats {
+            total: 3,
+            verified: 1,
+            not_found: 1,
+            skipped: 1,
+            ..Default::default()
+        };
+        let paper = ReportPaper {
+            filename: "paper.pdf",
+            stats: &stats,
+            results: &results,
+            verdict: Some(PaperVerdict::Questionable),
+        };
+        let refs = vec![
+            report_ref(0, "Real Paper"),
+            ReportRef {
+                fp_reason: Some(FpReason::KnownGood),
+                ..report_ref(1, "Made Up")
+            },
+            ReportRef {
+                skip_info: Some(SkipInfo {
+                    reason: "short_title".to_string(),
+                }),
+                ..report_ref(2, "Short")
+            },
+        ];
+
+        let json = hallucinator_reporting::export_json(&[paper], &[&refs]);
+        let file = write_temp(&json);
+        let loaded = load_results_file(file.path()).unwrap();
+
+        assert_eq!(loaded.len(), 1);
+        let (paper, ref_states) = &loaded[0];
+        assert_eq!(paper.filename, "paper.pdf");
+        assert_eq!(paper.phase, PaperPhase::Complete);
+        assert_eq!(paper.verdict, Some(PaperVerdict::Questionable));
+        assert_eq!((paper.stats.verified, paper.stats.not_found), (1, 1));
+
+        let titles: Vec<&str> = ref_states.iter().map(|rs| rs.title.as_str()).collect();
+        assert_eq!(titles, ["Real Paper", "Made Up", "Short"]);
+        let real = ref_states[0].result.as_ref().unwrap();
+        assert_eq!(real.status, Status::Verified);
+        assert_eq!(real.source.as_deref(), Some("CrossRef"));
+        assert_eq!(real.match_score, Some(0.98));
+        assert_eq!(real.agreeing_dbs, ["CrossRef", "DBLP"]);
+        assert_eq!(real.title_near_miss, Some(near_miss));
+        assert_eq!(
+            (real.venue.as_deref(), real.year),
+            (Some("Nature"), Some(2021))
+        );
+        let made_up = ref_states[1].result.as_ref().unwrap();
+        assert_eq!(
+            made_up.failed_dbs,
+            [FailedDb::new("DBLP", FailureReason::RateLimited)]
+        );
+        assert_eq!(ref_states[1].fp_reason, Some(FpReason::KnownGood));
+        assert_eq!(
+            ref_states[2].phase,
+            RefPhase::Skipped("short_title".to_string())
+        );
+    }
+
+    #[test]
+    fn newer_schema_is_refused() {
+        let json = format!(
+            "{{\"schema_version\": {}, \"papers\": []}}",
+            hallucinator_reporting::JSON_SCHEMA_VERSION + 1
+        );
+        let file = write_temp(&json);
+        let err = load_results_file(file.path()).unwrap_err();
+        assert!(err.contains("schema version"), "{err}");
+    }
+
+    #[test]
+    fn legacy_array_still_loads() {
+        let file = write_temp(
+            r#"[{"filename": "old.pdf", "verdict": null,
+                 "references": [{"index": 0, "title": "T", "status": "verified"}]}]"#,
+        );
+        let loaded = load_results_file(file.path()).unwrap();
+        assert_eq!(loaded[0].0.filename, "old.pdf");
+        assert_eq!(
+            loaded[0].1[0].result.as_ref().unwrap().status,
+            Status::Verified
+        );
+    }
+}
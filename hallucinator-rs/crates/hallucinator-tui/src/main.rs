@@ -1,7 +1,8 @@
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
-use std::io;
+use std::collections::HashMap;
+use std::io::{self, IsTerminal};
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
@@ -9,11 +10,6 @@ use std::time::Duration;
 use clap::{Parser, Subcommand};
 use ratatui::Terminal;
 use ratatui::crossterm::event;
-use ratatui::crossterm::event::{DisableMouseCapture, EnableMouseCapture};
-use ratatui::crossterm::execute;
-use ratatui::crossterm::terminal::{
-    EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
-};
 use ratatui::prelude::CrosstermBackend;
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
@@ -21,11 +17,14 @@ use tokio_util::sync::CancellationToken;
 mod action;
 mod app;
 mod backend;
+mod checkpoint;
 mod config_file;
+mod headless;
 mod input;
 mod load;
 mod model;
 mod persistence;
+mod term;
 mod theme;
 mod tui_event;
 mod view;
@@ -39,7 +38,7 @@ struct Cli {
     #[command(subcommand)]
     command: Option<Command>,
 
-    /// PDF, .bbl, or .bib files to check
+    /// PDF, .bbl, .bib, or .txt files to check
     file_paths: Vec<PathBuf>,
 
     /// OpenAlex API key
@@ -50,6 +49,14 @@ struct Cli {
     #[arg(long)]
     s2_api_key: Option<String>,
 
+    /// NCBI API key for PubMed (raises its rate limit)
+    #[arg(long)]
+    ncbi_api_key: Option<String>,
+
+    /// CORE (core.ac.uk) API key; enables the CORE database
+    #[arg(long)]
+    core_api_key: Option<String>,
+
     /// Path to offline DBLP database
     #[arg(long)]
     dblp_offline: Option<PathBuf>,
@@ -58,15 +65,40 @@ struct Cli {
     #[arg(long)]
     acl_offline: Option<PathBuf>,
 
+    /// Local .bib library (e.g. a Zotero export) to check references against
+    #[arg(long)]
+    local_bib: Option<PathBuf>,
+
+    /// Retraction Watch CSV to look matched DOIs up in before asking CrossRef
+    #[arg(long)]
+    retraction_db: Option<PathBuf>,
+
+    /// Proxy URL for API requests (default: HTTPS_PROXY/HTTP_PROXY from the environment)
+    #[arg(long)]
+    proxy: Option<String>,
+
+    /// PEM file with extra root certificates to trust (e.g. a corporate CA)
+    #[arg(long)]
+    extra_ca_cert: Option<PathBuf>,
+
     /// Comma-separated list of databases to disable
     #[arg(long, value_delimiter = ',')]
     disable_dbs: Vec<String>,
 
+    /// Comma-separated list of the only databases to query (overrides --disable-dbs)
+    #[arg(long, value_delimiter = ',')]
+    only_dbs: Vec<String>,
+
     /// Flag author mismatches from OpenAlex (default: skipped)
     #[arg(long)]
     check_openalex_authors: bool,
 
-    /// Color theme: hacker (default), modern, or gnr
+    /// Password for encrypted PDFs (or set HALLUCINATOR_PDF_PASSWORD);
+    /// without it they are skipped
+    #[arg(long, value_name = "PASSWORD")]
+    pdf_password: Option<String>,
+
+    /// Color theme: hacker (default), modern, gnr, or accessible (color-blind friendly)
     #[arg(long)]
     theme: Option<String>,
 
@@ -74,13 +106,41 @@ struct Cli {
     #[arg(long)]
     load: Option<PathBuf>,
 
-    /// Enable mouse support (click to select rows, scroll)
+    /// Append each reference's verdict to this JSONL file as soon as it is checked
+    #[arg(long, value_name = "PATH")]
+    log: Option<PathBuf>,
+
+    /// Keep a checkpoint of the batch's results in this file, rewritten as
+    /// references are checked (the same JSON format --load reads)
+    #[arg(long, value_name = "PATH")]
+    checkpoint: Option<PathBuf>,
+
+    /// Resume a batch from its checkpoint: references already checked there
+    /// are not checked again, and the checkpoint keeps being updated (a
+    /// missing file starts a new one)
+    #[arg(long, value_name = "PATH")]
+    resume: Option<PathBuf>,
+
+    /// Enable mouse support (click to select rows, double-click to open, scroll)
     #[arg(long)]
     mouse: bool,
 
+    /// Run without the UI and print a summary, exiting with the CLI's status
+    /// codes (automatic when stdout isn't a terminal)
+    #[arg(long)]
+    headless: bool,
+
+    /// Render inline instead of on the alternate screen
+    #[arg(long)]
+    no_altscreen: bool,
+
     /// Target frames per second (default: 30)
     #[arg(long)]
     fps: Option<u32>,
+
+    /// Milliseconds per spinner frame (default: one frame per redraw)
+    #[arg(long)]
+    tick_ms: Option<u64>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -133,36 +193,8 @@ async fn main() -> anyhow::Result<()> {
     config_file::apply_to_config_state(&file_config, &mut config_state);
 
     // Apply env vars (override file config)
-    if let Ok(key) = std::env::var("OPENALEX_KEY")
-        && !key.is_empty()
-    {
-        config_state.openalex_key = key;
-    }
-    if let Ok(key) = std::env::var("S2_API_KEY")
-        && !key.is_empty()
-    {
-        config_state.s2_api_key = key;
-    }
-    if let Ok(path) = std::env::var("DBLP_OFFLINE_PATH")
-        && !path.is_empty()
-    {
-        config_state.dblp_offline_path = path;
-    }
-    if let Ok(path) = std::env::var("ACL_OFFLINE_PATH")
-        && !path.is_empty()
-    {
-        config_state.acl_offline_path = path;
-    }
-    if let Ok(v) = std::env::var("DB_TIMEOUT")
-        && let Ok(secs) = v.parse::<u64>()
-    {
-        config_state.db_timeout_secs = secs;
-    }
-    if let Ok(v) = std::env::var("DB_TIMEOUT_SHORT")
-        && let Ok(secs) = v.parse::<u64>()
-    {
-        config_state.db_timeout_short_secs = secs;
-    }
+    let env = hallucinator_core::EnvConfig::read().map_err(|e| anyhow::anyhow!(e))?;
+    config_file::apply_env_to_config_state(&env, &mut config_state);
 
     // Apply CLI args (highest priority)
     if let Some(key) = cli.openalex_key {
@@ -171,18 +203,39 @@ async fn main() -> anyhow::Result<()> {
     if let Some(key) = cli.s2_api_key {
         config_state.s2_api_key = key;
     }
+    if let Some(key) = cli.ncbi_api_key {
+        config_state.ncbi_api_key = key;
+    }
+    if let Some(key) = cli.core_api_key {
+        config_state.core_api_key = key;
+    }
     if let Some(ref path) = cli.dblp_offline {
         config_state.dblp_offline_path = path.display().to_string();
     }
     if let Some(ref path) = cli.acl_offline {
         config_state.acl_offline_path = path.display().to_string();
     }
+    if let Some(ref path) = cli.local_bib {
+        config_state.local_bib_path = path.display().to_string();
+    }
+    if let Some(ref path) = cli.retraction_db {
+        config_state.retraction_db_path = path.display().to_string();
+    }
+    if let Some(proxy) = cli.proxy {
+        config_state.proxy = proxy;
+    }
+    if let Some(ref path) = cli.extra_ca_cert {
+        config_state.extra_ca_cert = path.display().to_string();
+    }
     if let Some(ref theme) = cli.theme {
         config_state.theme_name = theme.clone();
     }
     if let Some(fps) = cli.fps {
         config_state.fps = fps.clamp(1, 120);
     }
+    if let Some(ms) = cli.tick_ms {
+        config_state.tick_ms = ms;
+    }
 
     // Mark disabled DBs from CLI args
     for (name, enabled) in &mut config_state.disabled_dbs {
@@ -190,6 +243,9 @@ async fn main() -> anyhow::Result<()> {
             *enabled = false;
         }
     }
+    if !cli.only_dbs.is_empty() {
+        config_state.only_dbs = cli.only_dbs.clone();
+    }
 
     // Auto-detect default DBLP DB if no explicit path configured
     // Check CWD first (default update-dblp location), then platform data dir
@@ -235,11 +291,18 @@ async fn main() -> anyhow::Result<()> {
     // Open DBLP database if configured (fall back to None if file missing or corrupt)
     let mut startup_warnings: Vec<String> = Vec::new();
     let mut startup_info: Vec<String> = Vec::new();
+    let mut dblp_stale_notice: Option<String> = None;
     let dblp_offline_db: Option<Arc<Mutex<hallucinator_dblp::DblpDatabase>>> =
         if let Some(ref path) = dblp_offline_path {
             match backend::open_dblp_db(path) {
                 Ok(db) => {
                     startup_info.push(format!("DBLP offline DB loaded: {}", path.display()));
+                    if let Some((message, banner)) =
+                        backend::dblp_staleness(&db, path, config_state.dblp_max_age_days)
+                    {
+                        startup_warnings.push(message);
+                        dblp_stale_notice = Some(banner);
+                    }
                     Some(db)
                 }
                 Err(e) => {
@@ -275,12 +338,30 @@ async fn main() -> anyhow::Result<()> {
             None
         };
 
-    // Select theme
-    let theme = match config_state.theme_name.as_str() {
-        "modern" => theme::Theme::modern(),
-        "gnr" | "t800" => theme::Theme::t800(),
-        _ => theme::Theme::hacker(),
+    let pdf_password = cli
+        .pdf_password
+        .clone()
+        .or_else(|| std::env::var("HALLUCINATOR_PDF_PASSWORD").ok());
+
+    // Open the results log up front so a bad path fails before any work starts
+    let result_log: Option<Arc<persistence::ResultLog>> = match cli.log {
+        Some(ref path) => Some(Arc::new(persistence::ResultLog::open(path).map_err(
+            |e| anyhow::anyhow!("Cannot open results log {}: {}", path.display(), e),
+        )?)),
+        None => None,
+    };
+
+    // Likewise read the checkpoint to resume from before any work starts
+    let resume: Option<Arc<checkpoint::ResumeState>> = match cli.resume {
+        Some(ref path) => Some(Arc::new(checkpoint::ResumeState::load(path).map_err(
+            |e| anyhow::anyhow!("Cannot resume from {}: {}", path.display(), e),
+        )?)),
+        None => None,
     };
+    let checkpoint_path = cli.checkpoint.clone().or_else(|| cli.resume.clone());
+
+    // Select theme
+    let theme = theme::Theme::from_name(&config_state.theme_name);
 
     // Build filenames for display
     let filenames: Vec<String> = cli
@@ -293,6 +374,26 @@ async fn main() -> anyhow::Result<()> {
         })
         .collect();
 
+    // Without a TTY there's no raw mode to enter; run the batch and print a summary.
+    if cli.headless || !io::stdout().is_terminal() {
+        let code = headless::run(
+            cli.file_paths,
+            cli.load,
+            config_state,
+            headless::OfflineDbs {
+                dblp: dblp_offline_db,
+                acl: acl_offline_db,
+            },
+            cli.check_openalex_authors,
+            pdf_password,
+            result_log,
+            checkpoint_path,
+            resume,
+        )
+        .await?;
+        std::process::exit(code);
+    }
+
     // Set Windows timer resolution to 1ms for accurate frame pacing.
     // Without this, timers round up to the default 15.6ms granularity,
     // causing ~22 FPS instead of the target 30.
@@ -306,28 +407,17 @@ async fn main() -> anyhow::Result<()> {
     }
 
     // Initialize terminal
-    let mouse_enabled = cli.mouse;
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    if mouse_enabled {
-        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    } else {
-        execute!(stdout, EnterAlternateScreen)?;
-    }
-
     // Install panic hook that restores terminal before printing panic
     let original_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |panic_info| {
-        let _ = disable_raw_mode();
-        if mouse_enabled {
-            let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture);
-        } else {
-            let _ = execute!(io::stdout(), LeaveAlternateScreen);
-        }
+        let _ = term::restore();
         original_hook(panic_info);
     }));
+    if !term::enter(!cli.no_altscreen, cli.mouse)? && !cli.no_altscreen {
+        startup_info.push("Alternate screen unsupported, rendering inline".to_string());
+    }
 
-    let backend_terminal = CrosstermBackend::new(stdout);
+    let backend_terminal = CrosstermBackend::new(io::stdout());
     let mut terminal = Terminal::new(backend_terminal)?;
 
     // Drain any stray input events (e.g. Enter keypress from launching the command)
@@ -341,7 +431,21 @@ async fn main() -> anyhow::Result<()> {
     app.file_paths = cli.file_paths.clone();
 
     // Apply the fully-resolved config state
+    app.ui = view::UiConfig {
+        spinner: view::SpinnerStyle::from_name(&config_state.spinner),
+        tick_ms: (config_state.tick_ms > 0).then_some(config_state.tick_ms),
+    };
     app.config_state = config_state;
+    app.dblp_stale_notice = dblp_stale_notice;
+    app.checkpoint = checkpoint_path.map(checkpoint::Checkpoint::new);
+    if let (Some(path), Some(resume)) = (&cli.resume, &resume) {
+        app.activity.log(format!(
+            "Resuming from {}: {} reference{} already checked",
+            path.display(),
+            resume.len(),
+            if resume.len() == 1 { "" } else { "s" }
+        ));
+    }
 
     // Record banner start time for Instant-based auto-dismiss
     app.banner_start = Some(std::time::Instant::now());
@@ -440,10 +544,18 @@ async fn main() -> anyhow::Result<()> {
     let mut cached_dblp_db = dblp_offline_db.clone();
     let mut cached_acl_path = acl_offline_path.clone();
     let mut cached_acl_db = acl_offline_db.clone();
+    let mut cached_retraction_path: Option<PathBuf> = None;
+    let mut cached_retraction_db = None;
     let check_openalex_authors = cli.check_openalex_authors;
+    let extraction_options = hallucinator_pdf::ExtractionOptions {
+        password: pdf_password,
+        ..Default::default()
+    };
     tokio::spawn(async move {
         // Per-batch cancel token — cancelled when user requests stop
         let mut batch_cancel = CancellationToken::new();
+        // Per-paper children of the batch token, by queue index
+        let mut paper_cancels: HashMap<usize, CancellationToken> = HashMap::new();
 
         while let Some(cmd) = cmd_rx.recv().await {
             match cmd {
@@ -451,6 +563,7 @@ async fn main() -> anyhow::Result<()> {
                     files,
                     starting_index,
                     mut config,
+                    context,
                 } => {
                     // Fresh token for this batch
                     batch_cancel = CancellationToken::new();
@@ -481,18 +594,52 @@ async fn main() -> anyhow::Result<()> {
                     config.acl_offline_db = cached_acl_db.clone();
                     config.check_openalex_authors = check_openalex_authors;
 
+                    // Load the Retraction Watch data once per path
+                    if config.retraction_db_path != cached_retraction_path {
+                        cached_retraction_path = config.retraction_db_path.clone();
+                        cached_retraction_db = None;
+                    }
+                    config.retraction_db = cached_retraction_db.clone();
+                    backend::load_retraction_db(&mut config, starting_index, &event_tx_for_backend);
+                    cached_retraction_db = config.retraction_db.clone();
+
+                    paper_cancels.retain(|_, token| !token.is_cancelled());
+                    let papers = files
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, (path, name))| {
+                            let token = batch_cancel.child_token();
+                            paper_cancels.insert(starting_index + i, token.clone());
+                            (path, name, token)
+                        })
+                        .collect();
+
                     let tx = event_tx_for_backend.clone();
                     let cancel = batch_cancel.clone();
+                    let log = result_log.clone();
+                    let resume = resume.clone();
+                    let options = extraction_options.clone();
                     // Spawn batch as a separate task so we can still receive commands
                     tokio::spawn(async move {
-                        backend::run_batch_with_offset(files, *config, tx, cancel, starting_index)
-                            .await;
+                        backend::run_batch_with_offset(
+                            papers,
+                            *config,
+                            options,
+                            context,
+                            tx,
+                            cancel,
+                            starting_index,
+                            log,
+                            resume,
+                        )
+                        .await;
                     });
                 }
                 tui_event::BackendCommand::RetryReferences {
                     paper_index,
                     refs_to_retry,
                     mut config,
+                    context,
                 } => {
                     // Inject cached DB handles
                     config.dblp_offline_path = cached_dblp_path.clone();
@@ -500,15 +647,25 @@ async fn main() -> anyhow::Result<()> {
                     config.acl_offline_path = cached_acl_path.clone();
                     config.acl_offline_db = cached_acl_db.clone();
                     config.check_openalex_authors = check_openalex_authors;
+                    if config.retraction_db_path == cached_retraction_path {
+                        config.retraction_db = cached_retraction_db.clone();
+                    }
+                    backend::load_retraction_db(&mut config, paper_index, &event_tx_for_backend);
 
                     let tx = event_tx_for_backend.clone();
                     tokio::spawn(async move {
-                        backend::retry_references(paper_index, refs_to_retry, *config, tx).await;
+                        backend::retry_references(paper_index, refs_to_retry, *config, context, tx)
+                            .await;
                     });
                 }
                 tui_event::BackendCommand::CancelProcessing => {
                     batch_cancel.cancel();
                 }
+                tui_event::BackendCommand::CancelPaper { paper_index } => {
+                    if let Some(token) = paper_cancels.remove(&paper_index) {
+                        token.cancel();
+                    }
+                }
                 tui_event::BackendCommand::BuildDblp { db_path } => {
                     // Invalidate cached handle so the next ProcessFiles re-opens
                     // the DB even if the path hasn't changed (e.g. rebuilding
@@ -611,6 +768,11 @@ async fn main() -> anyhow::Result<()> {
                         }
                         _ => input::map_event(&evt, &app.input_mode),
                     }
+                } else if matches!(app.screen, Screen::RefDetail(..)) {
+                    match input::map_event(&evt, &app.input_mode) {
+                        action::Action::CopyToClipboard => action::Action::CopyDetail,
+                        action => action,
+                    }
                 } else {
                     input::map_event(&evt, &app.input_mode)
                 };
@@ -685,16 +847,7 @@ async fn main() -> anyhow::Result<()> {
     }
 
     // Restore terminal
-    disable_raw_mode()?;
-    if mouse_enabled {
-        execute!(
-            terminal.backend_mut(),
-            LeaveAlternateScreen,
-            DisableMouseCapture
-        )?;
-    } else {
-        execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
-    }
+    term::restore()?;
 
     Ok(())
 }
@@ -771,6 +924,24 @@ async fn update_dblp(db_path: &PathBuf) -> anyhow::Result<()> {
                 dl_bar.set_message("Downloading dblp.xml.gz");
             }
         }
+        hallucinator_dblp::BuildProgress::Verifying {
+            bytes_checked,
+            bytes_total,
+        } => {
+            if !dl_bar.is_finished() {
+                dl_bar.finish_with_message(format!(
+                    "Downloaded {} in {:.0?}",
+                    HumanBytes(dl_bar.position()),
+                    dl_bar.elapsed()
+                ));
+            }
+            parse_bar.set_draw_target(indicatif::ProgressDrawTarget::stderr());
+            parse_bar.set_message(format!(
+                "Verifying checksum... {}/{}",
+                HumanBytes(bytes_checked),
+                HumanBytes(bytes_total)
+            ));
+        }
         hallucinator_dblp::BuildProgress::Parsing {
             records_inserted,
             bytes_read,
@@ -1,4 +1,8 @@
 use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use hallucinator_core::RateLimitState;
+use hallucinator_core::checker::RATE_LIMIT_STATUS_INTERVAL;
 
 /// Health status of a database backend.
 #[derive(Debug, Clone)]
@@ -10,6 +14,8 @@ pub struct DbHealth {
     pub avg_response_ms: f64,
     /// Number of queries currently in flight for this DB.
     pub in_flight: usize,
+    /// Queries the DB may run at once, with adaptive concurrency (else 1).
+    pub concurrency: usize,
 }
 
 impl DbHealth {
@@ -21,6 +27,7 @@ impl DbHealth {
             hits: 0,
             avg_response_ms: 0.0,
             in_flight: 0,
+            concurrency: 1,
         }
     }
 
@@ -78,6 +85,12 @@ pub struct ActivityState {
     pub messages: VecDeque<(String, bool)>,
     /// Whether the DBLP timeout warning has already been shown.
     pub dblp_timeout_warned: bool,
+    /// Most recent 429 backoff: database name and when its wait ends.
+    pub throttled: Option<(String, Instant)>,
+    /// Slowed-down databases and their slowdown factors, from the latest
+    /// rate-limit status, and when it arrived.
+    pub slowdowns: Vec<(String, u32)>,
+    pub slowdowns_at: Option<Instant>,
 }
 
 impl Default for ActivityState {
@@ -89,6 +102,9 @@ impl Default for ActivityState {
             total_completed: 0,
             messages: VecDeque::new(),
             dblp_timeout_warned: false,
+            throttled: None,
+            slowdowns: Vec::new(),
+            slowdowns_at: None,
         }
     }
 }
@@ -140,6 +156,62 @@ impl ActivityState {
         health.record(success, is_match, elapsed_ms);
     }
 
+    /// Record the adaptive concurrency window of `db_name`.
+    pub fn record_concurrency(&mut self, db_name: &str, limit: usize) {
+        self.db_health
+            .entry(db_name.to_string())
+            .or_insert_with(DbHealth::new)
+            .concurrency = limit;
+    }
+
+    /// Record that `db_name` answered 429 and is being waited out for `wait`.
+    pub fn record_throttle(&mut self, db_name: &str, wait: Duration) {
+        self.log_warn(format!(
+            "{} throttled (429), waiting {}s",
+            db_name,
+            wait.as_secs_f64().ceil() as u64
+        ));
+        self.throttled = Some((db_name.to_string(), Instant::now() + wait));
+    }
+
+    /// Footer notice while a backoff is in progress, e.g.
+    /// "CrossRef throttled, waiting 8s".
+    pub fn throttle_notice(&self) -> Option<String> {
+        let (db_name, until) = self.throttled.as_ref()?;
+        let left = until.checked_duration_since(Instant::now())?;
+        Some(format!(
+            "{} throttled, waiting {}s",
+            db_name,
+            left.as_secs_f64().ceil().max(1.0) as u64
+        ))
+    }
+
+    /// Keep the throttled databases from a rate-limit status event.
+    pub fn record_rate_limits(&mut self, limiters: &[RateLimitState]) {
+        self.slowdowns = limiters
+            .iter()
+            .filter(|s| s.is_throttled())
+            .map(|s| (s.db_name.clone(), s.factor))
+            .collect();
+        self.slowdowns_at = Some(Instant::now());
+    }
+
+    /// Footer indicator while databases run slowed down, e.g.
+    /// "throttled: arXiv 4x, CrossRef 2x". Status events stop with the run
+    /// that sent them, so a stale one is ignored.
+    pub fn slowdown_notice(&self) -> Option<String> {
+        let at = self.slowdowns_at?;
+        if self.slowdowns.is_empty() || at.elapsed() > RATE_LIMIT_STATUS_INTERVAL * 3 {
+            return None;
+        }
+        let dbs: Vec<String> = self
+            .slowdowns
+            .iter()
+            .map(|(name, factor)| format!("{} {}x", name, factor))
+            .collect();
+        Some(format!("throttled: {}", dbs.join(", ")))
+    }
+
     pub fn push_throughput(&mut self, count: u16) {
         if self.throughput_buckets.len() >= 60 {
             self.throughput_buckets.pop_front();
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 /// Configuration sections for the config screen.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ConfigSection {
@@ -60,20 +62,68 @@ pub struct ConfigState {
     // Editable fields
     pub openalex_key: String,
     pub s2_api_key: String,
+    /// NCBI API key for PubMed (empty = keyless rate).
+    pub ncbi_api_key: String,
+    /// CORE API key (empty = CORE not queried).
+    pub core_api_key: String,
     pub crossref_mailto: String,
+    /// Custom User-Agent for API requests (empty = default `hallucinator/<version>`).
+    pub user_agent: String,
+    pub proxy: String,
+    pub extra_ca_cert: String,
     pub disabled_dbs: Vec<(String, bool)>, // (name, enabled)
+    /// Databases to query exclusively, from `--only-dbs`; overrides the
+    /// toggles above when non-empty. Not saved to the config file.
+    pub only_dbs: Vec<String>,
+    /// Title-similarity threshold overrides by database name.
+    pub per_db_threshold: BTreeMap<String, f64>,
+    /// Minimum fraction of cited authors a match must share.
+    pub author_min_overlap: f32,
+    pub require_first_author: bool,
+    /// Surname similarity for two author names to match.
+    pub author_match_threshold: f64,
+    /// Title similarity a match must reach to count.
+    pub min_match_score: f64,
+    /// Verify only when `consensus_min` databases agree on the title.
+    pub consensus_mode: bool,
+    pub consensus_min: usize,
+    /// Flag matches whose title is close to, but not the same as, the cited one.
+    pub strict_title: bool,
+    /// Settle author mismatches by who claims the work on ORCID.
+    pub orcid_check: bool,
+    /// Rank author mismatches with references not found in exports.
+    pub author_mismatch_is_failure: bool,
     pub dblp_offline_path: String,
+    /// Warn when the offline DBLP database is at least this many days old.
+    pub dblp_max_age_days: u64,
     pub acl_offline_path: String,
+    pub local_bib_path: String,
+    pub retraction_db_path: String,
+    pub retraction_max_age_days: u64,
     pub cache_path: String,
+    /// Most query cache entries kept in memory; 0 = unbounded.
+    pub cache_max_entries: usize,
     /// Inline status message for cache clear operation.
     pub cache_clear_status: Option<String>,
     pub num_workers: usize,
+    /// Papers checked at once; 0 = unlimited.
+    pub max_concurrent_papers: usize,
+    /// Look up references with the same title once per batch.
+    pub dedup: bool,
+    /// Let each database run more queries at once while it allows them.
+    pub adaptive_concurrency: bool,
     pub max_rate_limit_retries: u32,
     pub db_timeout_secs: u64,
     pub db_timeout_short_secs: u64,
+    /// Timeout overrides in seconds by database name.
+    pub db_timeouts: BTreeMap<String, u64>,
     pub max_archive_size_mb: u32, // 0 = unlimited
+    pub max_references: usize,    // 0 = unlimited
     pub theme_name: String,
     pub fps: u32,
+    /// "braille", "ascii", or empty for auto-detect.
+    pub spinner: String,
+    pub tick_ms: u64, // 0 = one spinner frame per redraw
 }
 
 impl Default for ConfigState {
@@ -86,8 +136,11 @@ impl Default for ConfigState {
             ("ACL Anthology".to_string(), true),
             ("Europe PMC".to_string(), true),
             ("PubMed".to_string(), true),
+            ("DataCite".to_string(), true),
             ("OpenAlex".to_string(), true),
             ("DOI".to_string(), true),
+            ("Unpaywall".to_string(), true),
+            ("CORE".to_string(), true),
         ];
 
         Self {
@@ -108,19 +161,47 @@ impl Default for ConfigState {
             acl_parse_started: None,
             openalex_key: String::new(),
             s2_api_key: String::new(),
+            ncbi_api_key: String::new(),
+            core_api_key: String::new(),
             crossref_mailto: String::new(),
+            user_agent: String::new(),
+            proxy: String::new(),
+            extra_ca_cert: String::new(),
             disabled_dbs: all_dbs,
+            only_dbs: vec![],
+            per_db_threshold: BTreeMap::new(),
+            author_min_overlap: 0.0,
+            require_first_author: false,
+            author_match_threshold: hallucinator_core::authors::DEFAULT_AUTHOR_MATCH_THRESHOLD,
+            min_match_score: 0.0,
+            consensus_mode: false,
+            consensus_min: hallucinator_core::orchestrator::DEFAULT_CONSENSUS_MIN,
+            strict_title: false,
+            orcid_check: false,
+            author_mismatch_is_failure: false,
             dblp_offline_path: String::new(),
+            dblp_max_age_days: hallucinator_dblp::DEFAULT_MAX_AGE_DAYS,
             acl_offline_path: String::new(),
+            local_bib_path: String::new(),
+            retraction_db_path: String::new(),
+            retraction_max_age_days: hallucinator_core::retraction_watch::DEFAULT_MAX_AGE_DAYS,
             cache_path: String::new(),
+            cache_max_entries: hallucinator_core::cache::DEFAULT_MAX_ENTRIES,
             cache_clear_status: None,
             num_workers: 4,
+            max_concurrent_papers: hallucinator_core::pool::DEFAULT_MAX_CONCURRENT_PAPERS,
+            dedup: false,
+            adaptive_concurrency: false,
             max_rate_limit_retries: 3,
             db_timeout_secs: 10,
             db_timeout_short_secs: 5,
+            db_timeouts: BTreeMap::new(),
             max_archive_size_mb: 0, // unlimited
+            max_references: 0,      // unlimited
             theme_name: "hacker".to_string(),
             fps: 30,
+            spinner: String::new(),
+            tick_ms: 0,
         }
     }
 }
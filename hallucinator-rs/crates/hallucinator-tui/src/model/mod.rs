@@ -2,3 +2,4 @@ pub mod activity;
 pub mod config;
 pub mod paper;
 pub mod queue;
+pub mod summary;
@@ -31,6 +31,8 @@ pub struct RefState {
     pub doi: Option<String>,
     /// arXiv ID extracted during parsing.
     pub arxiv_id: Option<String>,
+    /// arXiv version the citation pointed at.
+    pub arxiv_version: Option<u32>,
 }
 
 impl RefState {
@@ -52,8 +54,10 @@ impl RefState {
             authors: self.authors.clone(),
             doi: self.doi.clone(),
             arxiv_id: self.arxiv_id.clone(),
+            arxiv_version: self.arxiv_version,
             original_number: self.index + 1,
             skip_reason,
+            doi_only: false,
         }
     }
 
@@ -71,6 +75,8 @@ impl RefState {
                 "url_only" => "(skipped: URL-only)".to_string(),
                 "short_title" => "(skipped: short title)".to_string(),
                 "no_title" => "(skipped: no title)".to_string(),
+                "unresolved_footnote" => "(skipped: ibid./unresolved note)".to_string(),
+                "max_references" => "(skipped: reference cap)".to_string(),
                 other => format!("(skipped: {})", other),
             };
         }
@@ -113,6 +119,8 @@ pub enum PaperSortOrder {
     RefNumber,
     Verdict,
     Source,
+    /// Weakest title match first; references without a score go last.
+    MatchScore,
 }
 
 impl PaperSortOrder {
@@ -120,7 +128,8 @@ impl PaperSortOrder {
         match self {
             Self::RefNumber => Self::Verdict,
             Self::Verdict => Self::Source,
-            Self::Source => Self::RefNumber,
+            Self::Source => Self::MatchScore,
+            Self::MatchScore => Self::RefNumber,
         }
     }
 
@@ -129,6 +138,7 @@ impl PaperSortOrder {
             Self::RefNumber => "ref#",
             Self::Verdict => "verdict",
             Self::Source => "source",
+            Self::MatchScore => "score",
         }
     }
 }
@@ -1,4 +1,5 @@
 use hallucinator_core::{CheckStats, Status};
+use hallucinator_pdf::{ExtractionProgress, SkipStats};
 
 pub use hallucinator_reporting::PaperVerdict;
 
@@ -16,9 +17,12 @@ pub enum PaperPhase {
     Queued,
     Extracting,
     ExtractionFailed,
+    /// The PDF is password-protected and was skipped.
+    Encrypted,
     Checking,
     Retrying,
     Complete,
+    Cancelled,
 }
 
 impl PaperPhase {
@@ -27,14 +31,19 @@ impl PaperPhase {
             Self::Queued => "Queued",
             Self::Extracting => "Extracting...",
             Self::ExtractionFailed => "Failed",
+            Self::Encrypted => "Encrypted \u{2014} skipped",
             Self::Checking => "Checking...",
             Self::Retrying => "Retrying...",
             Self::Complete => "Done",
+            Self::Cancelled => "Cancelled",
         }
     }
 
     pub fn is_terminal(&self) -> bool {
-        matches!(self, Self::Complete | Self::ExtractionFailed)
+        matches!(
+            self,
+            Self::Complete | Self::ExtractionFailed | Self::Encrypted | Self::Cancelled
+        )
     }
 }
 
@@ -54,6 +63,10 @@ pub struct PaperState {
     pub retry_done: usize,
     /// User-assigned verdict for the entire paper.
     pub verdict: Option<PaperVerdict>,
+    /// Latest extraction sub-step, while the paper is being extracted.
+    pub extraction_progress: Option<ExtractionProgress>,
+    /// Why extraction dropped references; `None` for loaded results.
+    pub skip_stats: Option<SkipStats>,
 }
 
 impl PaperState {
@@ -68,6 +81,29 @@ impl PaperState {
             retry_total: 0,
             retry_done: 0,
             verdict: None,
+            extraction_progress: None,
+            skip_stats: None,
+        }
+    }
+
+    /// Short summary of references dropped during extraction, e.g.
+    /// "(3 url-only, 1 short, 2 no-title skipped)". `None` when nothing was dropped.
+    pub fn skip_summary(&self) -> Option<String> {
+        let s = self.skip_stats.as_ref()?;
+        let parts: Vec<String> = [
+            (s.url_only, "url-only"),
+            (s.short_title, "short"),
+            (s.no_title, "no-title"),
+            (s.unresolved_footnote, "unresolved-note"),
+        ]
+        .iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, label)| format!("{} {}", n, label))
+        .collect();
+        if parts.is_empty() {
+            None
+        } else {
+            Some(format!("({} skipped)", parts.join(", ")))
         }
     }
 
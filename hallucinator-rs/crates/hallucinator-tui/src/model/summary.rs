@@ -0,0 +1,102 @@
+use std::time::Duration;
+
+use super::activity::DbHealth;
+use super::queue::PaperState;
+
+/// How many papers and databases the summary ranks.
+pub const SUMMARY_TOP_N: usize = 5;
+
+/// Totals across every paper of a run, for the summary screen.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchSummary {
+    pub papers: usize,
+    pub papers_done: usize,
+    /// References with a result.
+    pub checked: usize,
+    pub verified: usize,
+    pub not_found: usize,
+    pub author_mismatch: usize,
+    pub retracted: usize,
+    pub skipped: usize,
+    /// References that took another reference's lookup (dedup).
+    pub duplicates: usize,
+    /// Papers with the most problems first: (queue index, problems, % of refs).
+    pub worst_papers: Vec<(usize, usize, f64)>,
+    /// Databases with the most failed queries first: (name, failed, total).
+    pub failing_dbs: Vec<(String, usize, usize)>,
+    /// Query cache hits and lookups, when a cache is in use.
+    pub cache: Option<(u64, u64)>,
+    pub wall_time: Duration,
+}
+
+impl BatchSummary {
+    pub fn new<'a>(
+        papers: &[PaperState],
+        db_health: impl IntoIterator<Item = (&'a String, &'a DbHealth)>,
+        cache: Option<(u64, u64)>,
+        wall_time: Duration,
+    ) -> Self {
+        let mut summary = Self {
+            papers: papers.len(),
+            papers_done: papers.iter().filter(|p| p.phase.is_terminal()).count(),
+            checked: 0,
+            verified: 0,
+            not_found: 0,
+            author_mismatch: 0,
+            retracted: 0,
+            skipped: 0,
+            duplicates: 0,
+            worst_papers: Vec::new(),
+            failing_dbs: Vec::new(),
+            cache,
+            wall_time,
+        };
+        for paper in papers {
+            summary.checked += paper.completed_count();
+            summary.verified += paper.stats.verified;
+            summary.not_found += paper.stats.not_found;
+            summary.author_mismatch += paper.stats.author_mismatch;
+            summary.retracted += paper.stats.retracted;
+            summary.skipped += paper.stats.skipped;
+            summary.duplicates += paper.stats.duplicates;
+        }
+
+        let mut worst: Vec<(usize, usize, f64)> = papers
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| p.problems() > 0)
+            .map(|(i, p)| (i, p.problems(), p.problematic_pct()))
+            .collect();
+        worst.sort_by(|a, b| b.1.cmp(&a.1).then(b.2.total_cmp(&a.2)).then(a.0.cmp(&b.0)));
+        worst.truncate(SUMMARY_TOP_N);
+        summary.worst_papers = worst;
+
+        let mut failing: Vec<(String, usize, usize)> = db_health
+            .into_iter()
+            .filter(|(_, h)| h.failed > 0)
+            .map(|(name, h)| (name.clone(), h.failed, h.total_queries))
+            .collect();
+        failing.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        failing.truncate(SUMMARY_TOP_N);
+        summary.failing_dbs = failing;
+
+        summary
+    }
+
+    /// Share of checked references that were verified (0.0 - 100.0).
+    pub fn verified_pct(&self) -> f64 {
+        if self.checked == 0 {
+            0.0
+        } else {
+            self.verified as f64 / self.checked as f64 * 100.0
+        }
+    }
+
+    /// Share of cache lookups that hit (0.0 - 100.0), if any were made.
+    pub fn cache_hit_pct(&self) -> Option<f64> {
+        match self.cache {
+            Some((hits, lookups)) if lookups > 0 => Some(hits as f64 / lookups as f64 * 100.0),
+            _ => None,
+        }
+    }
+}
@@ -1,5 +1,10 @@
+use std::fs::{File, OpenOptions};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use hallucinator_core::{Status, ValidationResult};
 
 use crate::model::paper::{RefPhase, RefState};
 use crate::model::queue::PaperState;
@@ -57,3 +62,127 @@ pub fn save_paper_results(
         let _ = file.write_all(json.as_bytes());
     }
 }
+
+/// Append-only JSONL log of reference verdicts, written as each result
+/// arrives so a crash or early quit keeps the work completed so far.
+pub struct ResultLog {
+    path: PathBuf,
+    file: Mutex<File>,
+    failed: AtomicBool,
+}
+
+impl ResultLog {
+    /// Open (or create) the log at `path`, appending to any existing content.
+    pub fn open(path: &Path) -> std::io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            file: Mutex::new(file),
+            failed: AtomicBool::new(false),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Write one line for `result` and flush it to disk.
+    ///
+    /// `index` is the reference's position in the paper's full reference list.
+    pub fn append(
+        &self,
+        paper: &str,
+        index: usize,
+        result: &ValidationResult,
+    ) -> std::io::Result<()> {
+        let mut line = result_log_line(paper, index, result).to_string();
+        line.push('\n');
+        let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        file.write_all(line.as_bytes())?;
+        file.flush()
+    }
+
+    /// Record a write failure; true only the first time, so callers warn once
+    /// rather than for every remaining reference.
+    pub fn mark_failed(&self) -> bool {
+        !self.failed.swap(true, Ordering::Relaxed)
+    }
+}
+
+fn result_log_line(paper: &str, index: usize, result: &ValidationResult) -> serde_json::Value {
+    serde_json::json!({
+        "timestamp": chrono::Local::now().to_rfc3339(),
+        "paper": paper,
+        "ref": index + 1,
+        "title": result.title,
+        "status": match result.status {
+            Status::Verified => "verified",
+            Status::NotFound => "not_found",
+            Status::AuthorMismatch => "author_mismatch",
+        },
+        "reason": result.reason.map(|r| r.as_str()),
+        "source": result.source,
+        "failed_dbs": result.failed_db_names(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(title: &str, status: Status) -> ValidationResult {
+        ValidationResult {
+            title: title.to_string(),
+            raw_citation: String::new(),
+            ref_authors: vec![],
+            status,
+            source: Some("CrossRef".to_string()),
+            agreeing_dbs: vec![],
+            found_authors: vec![],
+            paper_url: None,
+            failed_dbs: vec![],
+            db_results: vec![],
+            doi_info: None,
+            arxiv_info: None,
+            retraction_info: None,
+            reason: None,
+            match_score: None,
+            title_near_miss: None,
+            venue: None,
+            year: None,
+            orcid: None,
+        }
+    }
+
+    #[test]
+    fn result_log_appends_one_line_per_result() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("results.jsonl");
+        std::fs::write(&path, "earlier\n").unwrap();
+
+        let log = ResultLog::open(&path).unwrap();
+        log.append("a.pdf", 0, &result("First", Status::Verified))
+            .unwrap();
+        log.append("a.pdf", 4, &result("Second", Status::NotFound))
+            .unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "earlier");
+        let second: serde_json::Value = serde_json::from_str(lines[2]).unwrap();
+        assert_eq!(second["paper"], "a.pdf");
+        assert_eq!(second["ref"], 5);
+        assert_eq!(second["title"], "Second");
+        assert_eq!(second["status"], "not_found");
+        assert_eq!(second["source"], "CrossRef");
+    }
+
+    #[test]
+    fn result_log_warns_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = ResultLog::open(&dir.path().join("results.jsonl")).unwrap();
+        assert!(log.mark_failed());
+        assert!(!log.mark_failed());
+    }
+}
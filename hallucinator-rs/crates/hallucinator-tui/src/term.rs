@@ -0,0 +1,85 @@
+//! Terminal setup and teardown.
+//!
+//! Tracks which modes were actually entered so restoring (from normal exit or
+//! the panic hook) only undoes those, and only once.
+
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use ratatui::crossterm::cursor::{MoveTo, Show};
+use ratatui::crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+use ratatui::crossterm::execute;
+use ratatui::crossterm::terminal::{
+    Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
+};
+
+static RAW_MODE: AtomicBool = AtomicBool::new(false);
+static ALT_SCREEN: AtomicBool = AtomicBool::new(false);
+static MOUSE: AtomicBool = AtomicBool::new(false);
+
+/// Enter raw mode and, when requested and supported, the alternate screen.
+///
+/// If the alternate screen is unavailable the UI renders inline over the
+/// normal screen, which [`restore`] clears on exit. Returns whether the
+/// alternate screen is in use.
+pub fn enter(alt_screen: bool, mouse: bool) -> io::Result<bool> {
+    enable_raw_mode()?;
+    RAW_MODE.store(true, Ordering::SeqCst);
+
+    let mut stdout = io::stdout();
+    let use_alt = alt_screen
+        && term_supports_alt_screen(std::env::var("TERM").ok().as_deref())
+        && execute!(stdout, EnterAlternateScreen).is_ok();
+    ALT_SCREEN.store(use_alt, Ordering::SeqCst);
+    if !use_alt {
+        execute!(stdout, Clear(ClearType::All), MoveTo(0, 0))?;
+    }
+
+    if mouse {
+        execute!(stdout, EnableMouseCapture)?;
+        MOUSE.store(true, Ordering::SeqCst);
+    }
+    Ok(use_alt)
+}
+
+/// Undo whatever [`enter`] set up. Safe to call more than once; later calls
+/// emit nothing.
+pub fn restore() -> io::Result<()> {
+    let mut stdout = io::stdout();
+    if MOUSE.swap(false, Ordering::SeqCst) {
+        let _ = execute!(stdout, DisableMouseCapture);
+    }
+    let was_raw = RAW_MODE.swap(false, Ordering::SeqCst);
+    if ALT_SCREEN.swap(false, Ordering::SeqCst) {
+        execute!(stdout, LeaveAlternateScreen)?;
+    } else if was_raw {
+        // Inline mode: wipe the UI so it doesn't linger in scrollback.
+        execute!(stdout, Clear(ClearType::All), MoveTo(0, 0), Show)?;
+    }
+    if was_raw {
+        disable_raw_mode()?;
+    }
+    Ok(())
+}
+
+/// Whether a `TERM` value is known to handle the alternate screen.
+///
+/// Windows consoles don't set `TERM` but support it.
+fn term_supports_alt_screen(term: Option<&str>) -> bool {
+    match term {
+        None | Some("") => cfg!(windows),
+        Some(t) => t != "dumb",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dumb_or_missing_term_has_no_alt_screen() {
+        assert!(!term_supports_alt_screen(Some("dumb")));
+        assert!(term_supports_alt_screen(Some("xterm-256color")));
+        assert_eq!(term_supports_alt_screen(None), cfg!(windows));
+    }
+}
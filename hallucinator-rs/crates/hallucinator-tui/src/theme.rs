@@ -97,6 +97,42 @@ impl Theme {
         }
     }
 
+    /// Color-blind-friendly theme built on the Okabe–Ito palette: statuses
+    /// differ in blue/orange/purple/yellow hue and lightness, never only in
+    /// red versus green.
+    pub fn accessible() -> Self {
+        Self {
+            verified: Color::Rgb(86, 180, 233),         // sky blue
+            not_found: Color::Rgb(230, 159, 0),         // orange
+            author_mismatch: Color::Rgb(204, 121, 167), // reddish purple
+            retracted: Color::Rgb(240, 228, 66),        // yellow
+
+            header_fg: Color::White,
+            header_bg: Color::Rgb(0, 114, 178),
+            border: Color::Rgb(90, 90, 90),
+            text: Color::White,
+            dim: Color::Rgb(140, 140, 140),
+            highlight_bg: Color::Rgb(20, 50, 80),
+            active: Color::Rgb(86, 180, 233),
+            queued: Color::Rgb(100, 100, 100),
+            spinner: Color::Rgb(86, 180, 233),
+            footer_fg: Color::Rgb(140, 140, 140),
+            footer_bg: Color::Reset,
+            is_t800: false,
+        }
+    }
+
+    /// Theme for a `--theme` / config file name; unknown names get the
+    /// default hacker theme.
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "modern" => Self::modern(),
+            "gnr" | "t800" => Self::t800(),
+            "accessible" | "deuteranopia" => Self::accessible(),
+            _ => Self::hacker(),
+        }
+    }
+
     /// Whether this is the T-800 theme (used for theme-specific rendering).
     pub fn is_t800(&self) -> bool {
         self.is_t800
@@ -115,9 +151,11 @@ impl Theme {
             PaperPhase::Queued => self.queued,
             PaperPhase::Extracting => self.active,
             PaperPhase::ExtractionFailed => self.not_found,
+            PaperPhase::Encrypted => self.author_mismatch,
             PaperPhase::Checking => self.active,
             PaperPhase::Retrying => self.author_mismatch,
             PaperPhase::Complete => self.verified,
+            PaperPhase::Cancelled => self.dim,
         }
     }
 
@@ -148,6 +186,13 @@ impl Theme {
             .add_modifier(Modifier::BOLD)
     }
 
+    /// Style for search matches inside table cells.
+    pub fn search_match_style(&self) -> Style {
+        Style::default()
+            .fg(self.active)
+            .add_modifier(Modifier::REVERSED | Modifier::BOLD)
+    }
+
     pub fn border_style(&self) -> Style {
         Style::default().fg(self.border)
     }
@@ -1,15 +1,19 @@
 use std::path::PathBuf;
 
 use hallucinator_core::{ProgressEvent, Reference};
-use hallucinator_pdf::SkipStats;
+use hallucinator_pdf::{ExtractionProgress, SkipStats};
 
 /// Commands sent from the TUI to the backend.
 pub enum BackendCommand {
     /// Start processing files. `starting_index` is the offset into the app's paper list.
+    /// Each file comes with its name in the queue, which keys its results in
+    /// the checkpoint and the results log.
     ProcessFiles {
-        files: Vec<PathBuf>,
+        files: Vec<(PathBuf, String)>,
         starting_index: usize,
         config: Box<hallucinator_core::Config>,
+        /// Shared cache, rate limiters and HTTP client for the run.
+        context: hallucinator_core::CheckContext,
     },
     /// Retry specific references for a paper.
     /// Each tuple is (ref_index, Reference, failed_dbs). If failed_dbs is empty,
@@ -18,9 +22,12 @@ pub enum BackendCommand {
         paper_index: usize,
         refs_to_retry: Vec<(usize, Reference, Vec<String>)>,
         config: Box<hallucinator_core::Config>,
+        context: hallucinator_core::CheckContext,
     },
     /// Cancel the current batch.
     CancelProcessing,
+    /// Cancel one paper, leaving the rest of its batch running.
+    CancelPaper { paper_index: usize },
     /// Build/update the offline DBLP database.
     BuildDblp { db_path: PathBuf },
     /// Build/update the offline ACL Anthology database.
@@ -33,6 +40,11 @@ pub enum BackendCommand {
 pub enum BackendEvent {
     /// PDF text extraction started for paper at queue index.
     ExtractionStarted { paper_index: usize },
+    /// Sub-step progress during extraction (page text, locating, segmenting, parsing).
+    ExtractionProgress {
+        paper_index: usize,
+        progress: ExtractionProgress,
+    },
     /// PDF extraction completed — references parsed.
     ExtractionComplete {
         paper_index: usize,
@@ -40,8 +52,13 @@ pub enum BackendEvent {
         references: Vec<Reference>,
         skip_stats: SkipStats,
     },
-    /// PDF extraction failed.
-    ExtractionFailed { paper_index: usize, error: String },
+    /// PDF extraction failed. `encrypted` means the PDF is password-protected
+    /// and no (or a wrong) password was given.
+    ExtractionFailed {
+        paper_index: usize,
+        error: String,
+        encrypted: bool,
+    },
     /// Progress event from check_references (checking/result/warning/retry).
     Progress {
         paper_index: usize,
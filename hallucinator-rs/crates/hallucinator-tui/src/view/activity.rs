@@ -127,6 +127,14 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
             ),
             Span::styled(format!("{:>6} ", avg), Style::default().fg(avg_color)),
             Span::styled(bar_label, Style::default().fg(bar_color)),
+            Span::styled(
+                if health.concurrency > 1 {
+                    format!(" \u{00D7}{}", health.concurrency)
+                } else {
+                    String::new()
+                },
+                Style::default().fg(theme.dim),
+            ),
         ]));
     }
 
@@ -170,6 +178,13 @@ pub fn render(f: &mut Frame, area: Rect, app: &App) {
                 ),
                 Style::default().fg(theme.dim),
             ),
+            Span::styled(
+                match cache.evictions() {
+                    0 => String::new(),
+                    n => format!("{} evicted", n),
+                },
+                Style::default().fg(theme.dim),
+            ),
         ]));
     }
 
@@ -0,0 +1,73 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::App;
+
+/// State for the add-path prompt.
+#[derive(Debug, Clone, Default)]
+pub struct AddPaperState {
+    pub active: bool,
+    pub buffer: String,
+    /// Why the last submitted path was rejected.
+    pub error: Option<String>,
+}
+
+/// Render the add-path prompt as a centered popup.
+pub fn render(f: &mut Frame, app: &App) {
+    let theme = &app.theme;
+    let prompt = &app.add_paper;
+    let area = f.area();
+    let popup = centered_rect(60, 8, area);
+
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("  Path: ", Style::default().fg(theme.text)),
+            Span::styled(
+                format!("{}\u{2588}", prompt.buffer),
+                Style::default().fg(theme.active),
+            ),
+        ]),
+        Line::from(""),
+    ];
+
+    match &prompt.error {
+        Some(err) => lines.push(Line::from(Span::styled(
+            format!("  {}", err),
+            Style::default()
+                .fg(theme.not_found)
+                .add_modifier(Modifier::BOLD),
+        ))),
+        None => lines.push(Line::from(Span::styled(
+            "  PDF, .bbl, .bib, .txt, archive or results .json",
+            Style::default().fg(theme.dim),
+        ))),
+    }
+
+    lines.push(Line::from(Span::styled(
+        "  Enter:add  (empty or a directory: browse)  Esc:cancel",
+        Style::default().fg(theme.dim),
+    )));
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.active))
+            .title(" Add Paper "),
+    );
+
+    f.render_widget(Clear, popup);
+    f.render_widget(paragraph, popup);
+}
+
+fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    let vertical = Layout::vertical([Constraint::Length(height)])
+        .flex(Flex::Center)
+        .split(area);
+    Layout::horizontal([Constraint::Length(width)])
+        .flex(Flex::Center)
+        .split(vertical[0])[0]
+}
@@ -342,6 +342,16 @@ fn render_concurrency(lines: &mut Vec<Line>, config: &ConfigState, theme: &Theme
     } else {
         format!("{}", config.max_archive_size_mb)
     };
+    let ref_limit = if config.max_references == 0 {
+        "unlimited".to_string()
+    } else {
+        config.max_references.to_string()
+    };
+    let paper_limit = if config.max_concurrent_papers == 0 {
+        "unlimited".to_string()
+    } else {
+        config.max_concurrent_papers.to_string()
+    };
     let items = [
         ("Ref Workers", config.num_workers.to_string()),
         (
@@ -354,6 +364,8 @@ fn render_concurrency(lines: &mut Vec<Line>, config: &ConfigState, theme: &Theme
             config.db_timeout_short_secs.to_string(),
         ),
         ("Archive Size Limit (MB)", archive_limit),
+        ("Max Refs per Paper", ref_limit),
+        ("Concurrent Papers", paper_limit),
     ];
     for (i, (label, value)) in items.iter().enumerate() {
         let cursor = if config.item_cursor == i { "> " } else { "  " };
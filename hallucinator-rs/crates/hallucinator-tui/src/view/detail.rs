@@ -4,6 +4,7 @@ use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 
+use hallucinator_core::authors::author_overlap_at;
 use hallucinator_core::{DbStatus, Status};
 
 use crate::app::App;
@@ -15,7 +16,7 @@ use crate::view::truncate;
 /// `footer_area` is a full-width row below the main content + activity panel.
 pub fn render_in(
     f: &mut Frame,
-    app: &App,
+    app: &mut App,
     paper_index: usize,
     ref_index: usize,
     area: Rect,
@@ -24,6 +25,7 @@ pub fn render_in(
     let theme = &app.theme;
     let paper = &app.papers[paper_index];
     let refs = &app.ref_states[paper_index];
+    let surname_threshold = app.config_state.author_match_threshold;
     let rs = &refs[ref_index];
 
     let chunks = Layout::vertical([
@@ -72,6 +74,8 @@ pub fn render_in(
             "url_only" => "URL-only (non-academic URL)",
             "short_title" => "Short title (fewer than minimum words)",
             "no_title" => "No title could be extracted",
+            "unresolved_footnote" => "Footnote without a title of its own (ibid., op. cit.)",
+            "max_references" => "Beyond the per-paper reference limit",
             other => other,
         };
         lines.push(Line::from(Span::styled(
@@ -132,6 +136,53 @@ pub fn render_in(
         if let Some(source) = &result.source {
             labeled_line(&mut lines, "Source", source, theme);
         }
+        // A consensus verdict names every database that agreed
+        if result.agreeing_dbs.len() > 1 {
+            labeled_line(
+                &mut lines,
+                "Agreeing",
+                &result.agreeing_dbs.join(", "),
+                theme,
+            );
+        }
+        if let Some(score) = result.match_score {
+            labeled_line(&mut lines, "Match Score", &format!("{:.2}", score), theme);
+        }
+        // Venue and year of the matched record, to tell the cited work from
+        // a same-titled editorial or erratum
+        let published = match (&result.venue, result.year) {
+            (Some(venue), Some(year)) => Some(format!("{}, {}", venue, year)),
+            (Some(venue), None) => Some(venue.clone()),
+            (None, Some(year)) => Some(year.to_string()),
+            (None, None) => None,
+        };
+        if let Some(published) = published {
+            labeled_line(&mut lines, "Published", &published, theme);
+        }
+        // A cited author who claims the matched work on ORCID
+        if let Some(orcid) = &result.orcid {
+            labeled_line(&mut lines, "ORCID", orcid, theme);
+        }
+        if let Some(reason) = result.reason {
+            labeled_line(&mut lines, "Reason", reason.description(), theme);
+        }
+        // Strict title mode: the match is a near miss, show both titles
+        if let Some(near_miss) = &result.title_near_miss {
+            lines.push(Line::from(vec![
+                Span::styled("  Title Check:   ", Style::default().fg(theme.dim)),
+                Span::styled(
+                    format!(
+                        "\u{26A0} different title (similarity {:.2})",
+                        near_miss.similarity
+                    ),
+                    Style::default()
+                        .fg(theme.author_mismatch)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]));
+            labeled_line(&mut lines, "Cited Title", &near_miss.cited_title, theme);
+            labeled_line(&mut lines, "Found Title", &near_miss.found_title, theme);
+        }
         // Author comparison for mismatches: always show both rows
         if result.status == Status::AuthorMismatch {
             // PDF Authors (what was extracted from the paper)
@@ -146,26 +197,17 @@ pub fn render_in(
 
             // DB Authors (what the database returned) — always show, even if empty
             if !result.found_authors.is_empty() {
-                let overlap = if !result.ref_authors.is_empty() {
-                    let ref_set: std::collections::HashSet<_> = result
-                        .ref_authors
-                        .iter()
-                        .map(|a| a.to_lowercase())
-                        .collect();
-                    let found_set: std::collections::HashSet<_> = result
-                        .found_authors
-                        .iter()
-                        .map(|a| a.to_lowercase())
-                        .collect();
-                    let overlap_count = ref_set.intersection(&found_set).count();
-                    format!(" ({}/{})", overlap_count, result.ref_authors.len())
-                } else {
-                    String::new()
-                };
                 labeled_line(
                     &mut lines,
                     "DB Authors",
-                    &format!("{}{}", result.found_authors.join(", "), overlap),
+                    &result.found_authors.join(", "),
+                    theme,
+                );
+                author_overlap_line(
+                    &mut lines,
+                    &result.ref_authors,
+                    &result.found_authors,
+                    surname_threshold,
                     theme,
                 );
             } else {
@@ -187,6 +229,37 @@ pub fn render_in(
                 &result.found_authors.join(", "),
                 theme,
             );
+            author_overlap_line(
+                &mut lines,
+                &result.ref_authors,
+                &result.found_authors,
+                surname_threshold,
+                theme,
+            );
+        }
+
+        // Other records that matched the title just as well
+        let alternates = result.ambiguous_matches();
+        if !alternates.is_empty() {
+            lines.push(Line::from(vec![
+                Span::styled("  Confidence:    ", Style::default().fg(theme.dim)),
+                Span::styled(
+                    format!("low ({} equally good title matches)", alternates.len() + 1),
+                    Style::default().fg(theme.author_mismatch),
+                ),
+            ]));
+
+            lines.push(Line::from(""));
+            section_header(&mut lines, "OTHER MATCHES", theme);
+            for alt in alternates {
+                labeled_line(&mut lines, "Title", &alt.title, theme);
+                if !alt.authors.is_empty() {
+                    labeled_line(&mut lines, "Authors", &alt.authors.join(", "), theme);
+                }
+                if let Some(url) = &alt.paper_url {
+                    labeled_line(&mut lines, "URL", url, theme);
+                }
+            }
         }
 
         // DATABASE RESULTS section (per-DB table)
@@ -223,13 +296,16 @@ pub fn render_in(
                 };
 
                 let time_str = match db_result.elapsed {
-                    Some(d) => format!("{:.1}s", d.as_secs_f64()),
+                    Some(d) => seconds(d),
                     None => "\u{2014}".to_string(),
                 };
 
                 let mut notes = String::new();
-                if db_result.status == DbStatus::Match
-                    && result.source.as_deref() == Some(&db_result.db_name)
+                if result.agreeing_dbs.len() > 1 && result.agreeing_dbs.contains(&db_result.db_name)
+                {
+                    notes = "\u{2190} agrees".to_string();
+                } else if db_result.status == DbStatus::Match
+                    && result.source.as_deref() == Some(db_result.db_name.as_str())
                 {
                     notes = "\u{2190} verified (early exit)".to_string();
                 } else if db_result.status == DbStatus::Skipped {
@@ -259,6 +335,32 @@ pub fn render_in(
                     )));
                 }
             }
+
+            // Where the time went: the slowest query held up the verdict
+            if let (Some(waited), Some(slowest)) = (result.query_time(), result.slowest_db()) {
+                lines.push(Line::from(""));
+                labeled_line(
+                    &mut lines,
+                    "Waited",
+                    &format!("{} (slowest: {})", seconds(waited), slowest.db_name),
+                    theme,
+                );
+                if let Some(verdict) = result.verdict_db() {
+                    let mut text = match verdict.elapsed {
+                        Some(d) => format!("{} in {}", verdict.db_name, seconds(d)),
+                        None => verdict.db_name.clone(),
+                    };
+                    let timed = result
+                        .db_results
+                        .iter()
+                        .filter(|r| r.elapsed.is_some())
+                        .count();
+                    if timed > 1 && verdict.db_name == slowest.db_name {
+                        text.push_str(", the slowest database");
+                    }
+                    labeled_line(&mut lines, "Verdict from", &text, theme);
+                }
+            }
         }
 
         // IDENTIFIERS section
@@ -270,16 +372,33 @@ pub fn render_in(
             section_header(&mut lines, "IDENTIFIERS", theme);
 
             if let Some(doi) = &result.doi_info {
-                let validity = if doi.valid { "valid" } else { "invalid" };
+                let validity = if !doi.structurally_valid {
+                    "malformed"
+                } else if doi.title_mismatch {
+                    "resolves to a different title"
+                } else if doi.valid {
+                    "valid"
+                } else {
+                    "invalid"
+                };
                 labeled_line(
                     &mut lines,
                     "DOI",
                     &format!("{} ({})", doi.doi, validity),
                     theme,
                 );
+                if doi.title_mismatch
+                    && let Some(title) = &doi.title
+                {
+                    labeled_line(&mut lines, "DOI title", title, theme);
+                }
             }
             if let Some(arxiv) = &result.arxiv_info {
-                let validity = if arxiv.valid { "valid" } else { "invalid" };
+                let validity = match arxiv.version_note() {
+                    Some(note) if arxiv.valid => format!("valid, {}", note),
+                    _ if arxiv.valid => "valid".to_string(),
+                    _ => "invalid".to_string(),
+                };
                 labeled_line(
                     &mut lines,
                     "arXiv",
@@ -347,17 +466,42 @@ pub fn render_in(
                     "  \u{255A}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{2550}\u{255D}",
                     Style::default().fg(theme.retracted),
                 )));
+        } else if let Some(notice) = &result.retraction_info {
+            // An editorial notice short of a retraction
+            lines.push(Line::from(""));
+            section_header(&mut lines, "EDITORIAL NOTICE", theme);
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  {}",
+                    notice.retraction_source.as_deref().unwrap_or("Notice")
+                ),
+                Style::default().fg(theme.author_mismatch),
+            )));
+            if let Some(doi) = &notice.retraction_doi {
+                url_line(
+                    &mut lines,
+                    "Notice",
+                    &format!("https://doi.org/{}", doi),
+                    theme,
+                );
+            }
         }
 
         // FAILED DATABASES section
         if !result.failed_dbs.is_empty() {
             lines.push(Line::from(""));
             section_header(&mut lines, "FAILED DATABASES", theme);
-            for db in &result.failed_dbs {
-                lines.push(Line::from(Span::styled(
-                    format!("  - {db}"),
-                    Style::default().fg(theme.not_found),
-                )));
+            for failed in &result.failed_dbs {
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("  - {}", failed.name),
+                        Style::default().fg(theme.not_found),
+                    ),
+                    Span::styled(
+                        format!("  {}", failed.reason.description()),
+                        Style::default().fg(theme.dim),
+                    ),
+                ]));
             }
         }
     } else if matches!(rs.phase, RefPhase::Skipped(_)) {
@@ -383,13 +527,24 @@ pub fn render_in(
                 .borders(Borders::ALL)
                 .border_style(theme.border_style()),
         )
-        .wrap(Wrap { trim: false })
-        .scroll((app.detail_scroll, 0));
+        .wrap(Wrap { trim: false });
+
+    // Clamp to the wrapped height at the current width, so `G`/End and
+    // resizes land on the last real line instead of blank space.
+    let content_area = chunks[1];
+    let max_scroll = content
+        .line_count(content_area.width.saturating_sub(2))
+        .saturating_sub(content_area.height as usize);
+    let max_scroll = u16::try_from(max_scroll).unwrap_or(u16::MAX);
+    let scroll = app.detail_scroll.min(max_scroll);
 
-    f.render_widget(content, chunks[1]);
+    f.render_widget(content.scroll((scroll, 0)), content_area);
 
     // --- Footer ---
-    render_footer(f, footer_area, theme);
+    render_footer(f, footer_area, theme, app.flash_message());
+
+    app.detail_scroll = scroll;
+    app.detail_max_scroll = max_scroll;
 }
 
 fn section_header<'a>(lines: &mut Vec<Line<'a>>, title: &'a str, theme: &Theme) {
@@ -401,6 +556,11 @@ fn section_header<'a>(lines: &mut Vec<Line<'a>>, title: &'a str, theme: &Theme)
     )));
 }
 
+/// A query time as the database table shows it, e.g. "1.2s".
+fn seconds(d: std::time::Duration) -> String {
+    format!("{:.1}s", d.as_secs_f64())
+}
+
 fn labeled_line<'a>(lines: &mut Vec<Line<'a>>, label: &'a str, value: &str, theme: &Theme) {
     lines.push(Line::from(vec![
         Span::styled(format!("  {label:<16}"), Style::default().fg(theme.dim)),
@@ -408,6 +568,41 @@ fn labeled_line<'a>(lines: &mut Vec<Line<'a>>, label: &'a str, value: &str, them
     ]));
 }
 
+/// The author overlap the checker's author policy judged, e.g.
+/// "2/4 cited authors (50%), first author matched".
+fn author_overlap_line(
+    lines: &mut Vec<Line<'_>>,
+    cited: &[String],
+    found: &[String],
+    surname_threshold: f64,
+    theme: &Theme,
+) {
+    if cited.is_empty() {
+        return;
+    }
+    let overlap = author_overlap_at(cited, found, surname_threshold);
+    if overlap.cited == 0 {
+        return;
+    }
+    let first = if overlap.first_author {
+        "first author matched"
+    } else {
+        "first author not matched"
+    };
+    labeled_line(
+        lines,
+        "Author overlap",
+        &format!(
+            "{}/{} cited authors ({:.0}%), {}",
+            overlap.matched,
+            overlap.cited,
+            overlap.fraction() * 100.0,
+            first
+        ),
+        theme,
+    );
+}
+
 /// Render a link: label on one line, URL on the next (for terminal click detection).
 fn url_line(lines: &mut Vec<Line<'_>>, label: &str, url: &str, theme: &Theme) {
     lines.push(Line::from(vec![Span::styled(
@@ -422,11 +617,18 @@ fn url_line(lines: &mut Vec<Line<'_>>, label: &str, url: &str, theme: &Theme) {
     )));
 }
 
-fn render_footer(f: &mut Frame, area: Rect, theme: &Theme) {
-    let footer = Line::from(Span::styled(
-        " j/k:scroll  Space:cycle FP reason  Ctrl+r:retry  y:copy ref  e:export  Esc:back  ?:help",
+fn render_footer(f: &mut Frame, area: Rect, theme: &Theme, flash: Option<&str>) {
+    let mut spans = vec![Span::styled(
+        " j/k:scroll  Space:cycle FP reason  Ctrl+r:retry  y:copy details  e:export  Esc:back  ?:help",
         theme.footer_style(),
-    ));
+    )];
+    if let Some(msg) = flash {
+        spans.push(Span::styled(
+            format!("  {msg}"),
+            theme.footer_style().add_modifier(Modifier::BOLD),
+        ));
+    }
+    let footer = Line::from(spans);
     f.render_widget(Paragraph::new(footer), area);
 }
 
@@ -8,11 +8,20 @@ use crate::app::App;
 
 pub use hallucinator_reporting::ExportFormat;
 
+/// Rows of the export modal, as held in [`ExportState::cursor`].
+pub const FORMAT_ROW: usize = 0;
+pub const SCOPE_ROW: usize = 1;
+pub const PATH_ROW: usize = 2;
+pub const CONFIRM_ROW: usize = 3;
+
 /// Scope of export.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ExportScope {
     ThisPaper,
     AllPapers,
+    /// Only the not-found, author-mismatch and retracted references of
+    /// every paper, leaving out those marked safe.
+    Problems,
 }
 
 impl ExportScope {
@@ -20,6 +29,7 @@ impl ExportScope {
         match self {
             Self::ThisPaper => "This paper",
             Self::AllPapers => "All papers",
+            Self::Problems => "Problems, all papers",
         }
     }
 }
@@ -31,7 +41,7 @@ pub struct ExportState {
     pub format: ExportFormat,
     pub scope: ExportScope,
     pub output_path: String,
-    pub cursor: usize, // 0=format, 1=scope, 2=path, 3=confirm
+    pub cursor: usize, // one of FORMAT_ROW..=CONFIRM_ROW
     pub editing_path: bool,
     pub edit_buffer: String,
     pub message: Option<String>,
@@ -71,7 +81,11 @@ pub fn render(f: &mut Frame, app: &App) {
     ];
 
     // Format
-    let fmt_indicator = if export.cursor == 0 { "> " } else { "  " };
+    let fmt_indicator = if export.cursor == FORMAT_ROW {
+        "> "
+    } else {
+        "  "
+    };
     lines.push(Line::from(vec![
         Span::styled(
             format!("  {}Format:  ", fmt_indicator),
@@ -81,7 +95,11 @@ pub fn render(f: &mut Frame, app: &App) {
     ]));
 
     // Scope
-    let scope_indicator = if export.cursor == 1 { "> " } else { "  " };
+    let scope_indicator = if export.cursor == SCOPE_ROW {
+        "> "
+    } else {
+        "  "
+    };
     lines.push(Line::from(vec![
         Span::styled(
             format!("  {}Scope:   ", scope_indicator),
@@ -91,7 +109,11 @@ pub fn render(f: &mut Frame, app: &App) {
     ]));
 
     // Output path
-    let path_indicator = if export.cursor == 2 { "> " } else { "  " };
+    let path_indicator = if export.cursor == PATH_ROW {
+        "> "
+    } else {
+        "  "
+    };
     let path_display = if export.editing_path {
         format!("{}\u{2588}", export.edit_buffer)
     } else {
@@ -117,7 +139,7 @@ pub fn render(f: &mut Frame, app: &App) {
     lines.push(Line::from(""));
 
     // Confirm button
-    let confirm_style = if export.cursor == 3 {
+    let confirm_style = if export.cursor == CONFIRM_ROW {
         Style::default()
             .fg(theme.header_fg)
             .bg(theme.active)
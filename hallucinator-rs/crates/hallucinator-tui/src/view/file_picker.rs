@@ -5,7 +5,6 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
 
 use crate::app::{App, FilePickerContext};
-use crate::view::spinner_char;
 
 /// Render the file picker screen into the given area.
 pub fn render_in(f: &mut Frame, app: &App, area: Rect) {
@@ -37,7 +36,7 @@ pub fn render_in(f: &mut Frame, app: &App, area: Rect) {
             let db_name = if *config_item == 0 { "DBLP" } else { "ACL" };
             format!(" > Select {} Database (.db / .sqlite)", db_name)
         } else {
-            " > Select PDFs / .bbl / .bib / Archives / Results (.json)".to_string()
+            " > Select PDFs / .bbl / .bib / .txt / Archives / Results (.json)".to_string()
         };
     let header = Line::from(vec![
         Span::styled(" Files ", theme.header_style()),
@@ -92,6 +91,7 @@ pub fn render_in(f: &mut Frame, app: &App, area: Rect) {
             } else if entry.is_pdf
                 || entry.is_bbl
                 || entry.is_bib
+                || entry.is_txt
                 || entry.is_archive
                 || entry.is_json
             {
@@ -175,7 +175,7 @@ pub fn render_in(f: &mut Frame, app: &App, area: Rect) {
                 Style::default().fg(theme.dim),
             )),
             Line::from(Span::styled(
-                "  Navigate to PDFs, .bbl, .bib, archives, or .json results and press Space to select",
+                "  Navigate to PDFs, .bbl, .bib, .txt, archives, or .json results and press Space to select",
                 Style::default().fg(theme.dim),
             )),
         ]
@@ -226,25 +226,25 @@ pub fn render_in(f: &mut Frame, app: &App, area: Rect) {
             if count_part.is_empty() {
                 format!(
                     " {} Extracting {} ({} more queued)...",
-                    spinner_char(app.tick),
+                    app.spinner(),
                     archive_name,
                     remaining - 1,
                 )
             } else {
                 format!(
                     " {} Extracting {} ({}, {} more queued)...",
-                    spinner_char(app.tick),
+                    app.spinner(),
                     archive_name,
                     count_part,
                     remaining - 1,
                 )
             }
         } else if count_part.is_empty() {
-            format!(" {} Extracting {}...", spinner_char(app.tick), archive_name,)
+            format!(" {} Extracting {}...", app.spinner(), archive_name,)
         } else {
             format!(
                 " {} Extracting {} ({})...",
-                spinner_char(app.tick),
+                app.spinner(),
                 archive_name,
                 count_part,
             )
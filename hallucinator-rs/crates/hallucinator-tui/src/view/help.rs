@@ -34,7 +34,8 @@ pub fn render(f: &mut Frame, theme: &Theme) {
         // Sorting & Filtering
         section_header("Sorting & Filtering", theme),
         key_line("s", "Cycle sort order", theme),
-        key_line("f", "Cycle filter", theme),
+        key_line("f", "Cycle filter (Paper view: problem refs only)", theme),
+        key_line("c", "Collapse/expand verified refs (Paper view)", theme),
         key_line("/", "Start search", theme),
         key_line("n / N", "Next / previous match", theme),
         Line::from(""),
@@ -43,21 +44,29 @@ pub fn render(f: &mut Frame, theme: &Theme) {
         key_line("r", "Start/stop processing", theme),
         key_line("Space", "Paper verdict / cycle FP reason", theme),
         key_line("Ctrl+r", "Retry failed reference", theme),
-        key_line("R", "Retry all failed references", theme),
+        key_line("R", "Retry failed refs (Queue: all finished papers)", theme),
+        key_line("x", "Cancel the selected paper (Queue)", theme),
         key_line("e", "Export results", theme),
-        key_line("o / a", "Open file picker (add files)", theme),
+        key_line("E", "Export problem refs of all papers (Queue)", theme),
+        key_line("a", "Add a paper by path", theme),
+        key_line("o", "Open file picker (add files)", theme),
         key_line("o", "Browse for database file (Config > Databases)", theme),
-        key_line("y", "Copy reference to clipboard (OSC 52)", theme),
+        key_line("y", "Copy reference details to clipboard", theme),
         key_line("Tab", "Toggle activity panel", theme),
         key_line(",", "Open config", theme),
+        key_line("S", "Run summary", theme),
         Line::from(""),
         // Global
         section_header("Global", theme),
         key_line("?", "Toggle this help", theme),
+        key_line("t", "Cycle color theme", theme),
         key_line("q", "Quit", theme),
         key_line("Ctrl+c", "Force quit", theme),
         Line::from(""),
-        section_header("Mouse", theme),
+        section_header("Mouse (--mouse)", theme),
+        key_line("Click", "Select row", theme),
+        key_line("Double-click", "Open paper / reference", theme),
+        key_line("Wheel", "Move up / down", theme),
         key_line("Shift+click", "Select text / click links (terminal)", theme),
         Line::from(""),
         section_header("Loading Results", theme),
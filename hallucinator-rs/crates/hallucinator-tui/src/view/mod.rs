@@ -1,4 +1,5 @@
 pub mod activity;
+pub mod add_paper;
 pub mod banner;
 pub mod config;
 pub mod detail;
@@ -8,16 +9,81 @@ pub mod help;
 pub mod paper;
 pub mod queue;
 pub mod quit_confirm;
+pub mod summary;
 
-/// Spinner frames for animated progress indication.
-const SPINNER_FRAMES: &[char] = &[
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+
+/// Braille spinner frames for animated progress indication.
+const BRAILLE_SPINNER: &[char] = &[
     '\u{280B}', '\u{2819}', '\u{2839}', '\u{2838}', '\u{283C}', '\u{2834}', '\u{2826}', '\u{2827}',
     '\u{2807}', '\u{280F}',
 ];
 
-/// Get the current spinner character based on a tick counter.
-pub fn spinner_char(tick: usize) -> char {
-    SPINNER_FRAMES[tick % SPINNER_FRAMES.len()]
+/// Plain ASCII spinner frames for terminals that can't render Braille.
+const ASCII_SPINNER: &[char] = &['|', '/', '-', '\\'];
+
+/// Which glyph set the spinners use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpinnerStyle {
+    #[default]
+    Braille,
+    Ascii,
+}
+
+impl SpinnerStyle {
+    /// Parse a configured spinner name. Empty or `"auto"` picks a set based on
+    /// whether the locale advertises UTF-8.
+    pub fn from_name(name: &str) -> Self {
+        match name.to_ascii_lowercase().as_str() {
+            "braille" => SpinnerStyle::Braille,
+            "ascii" => SpinnerStyle::Ascii,
+            _ => Self::detect(),
+        }
+    }
+
+    /// Pick Braille when the locale is UTF-8, ASCII otherwise.
+    pub fn detect() -> Self {
+        // Windows terminals don't set LANG but render Braille fine.
+        if cfg!(windows) {
+            return SpinnerStyle::Braille;
+        }
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|v| !v.is_empty());
+        if locale.as_deref().is_some_and(locale_is_utf8) {
+            SpinnerStyle::Braille
+        } else {
+            SpinnerStyle::Ascii
+        }
+    }
+
+    pub fn frames(self) -> &'static [char] {
+        match self {
+            SpinnerStyle::Braille => BRAILLE_SPINNER,
+            SpinnerStyle::Ascii => ASCII_SPINNER,
+        }
+    }
+}
+
+fn locale_is_utf8(locale: &str) -> bool {
+    let lower = locale.to_ascii_lowercase();
+    lower.contains("utf-8") || lower.contains("utf8")
+}
+
+/// Spinner appearance and speed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UiConfig {
+    pub spinner: SpinnerStyle,
+    /// Milliseconds per spinner frame; `None` advances one frame per UI tick.
+    pub tick_ms: Option<u64>,
+}
+
+/// Get the spinner character for the given frame number.
+pub fn spinner_char(style: SpinnerStyle, frame: usize) -> char {
+    let frames = style.frames();
+    frames[frame % frames.len()]
 }
 
 /// Truncate a string to fit in `max_width` columns, appending "\u{2026}" if truncated.
@@ -32,3 +98,85 @@ pub fn truncate(s: &str, max_width: usize) -> String {
     truncated.push('\u{2026}');
     truncated
 }
+
+/// Split `text` into spans, styling case-insensitive occurrences of `query`
+/// with `match_style` and the rest with `style`.
+pub fn highlight_matches(
+    text: &str,
+    query: &str,
+    style: Style,
+    match_style: Style,
+) -> Line<'static> {
+    let fold = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let needle: Vec<char> = query.chars().map(fold).collect();
+    if needle.is_empty() {
+        return Line::from(Span::styled(text.to_string(), style));
+    }
+    let chars: Vec<(usize, char)> = text.char_indices().map(|(i, c)| (i, fold(c))).collect();
+    let byte_at = |i: usize| chars.get(i).map_or(text.len(), |&(b, _)| b);
+
+    let mut spans = Vec::new();
+    let mut plain_from = 0;
+    let mut i = 0;
+    while i + needle.len() <= chars.len() {
+        if chars[i..i + needle.len()]
+            .iter()
+            .map(|&(_, c)| c)
+            .eq(needle.iter().copied())
+        {
+            if plain_from < i {
+                spans.push(Span::styled(
+                    text[byte_at(plain_from)..byte_at(i)].to_string(),
+                    style,
+                ));
+            }
+            let end = i + needle.len();
+            spans.push(Span::styled(
+                text[byte_at(i)..byte_at(end)].to_string(),
+                match_style,
+            ));
+            plain_from = end;
+            i = end;
+        } else {
+            i += 1;
+        }
+    }
+    if plain_from < chars.len() {
+        spans.push(Span::styled(text[byte_at(plain_from)..].to_string(), style));
+    }
+    Line::from(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Modifier;
+
+    #[test]
+    fn highlight_matches_is_case_insensitive() {
+        let hl = Style::default().add_modifier(Modifier::REVERSED);
+        let line = highlight_matches("Deep Learning for deep nets", "DEEP", Style::default(), hl);
+        let parts: Vec<(&str, bool)> = line
+            .spans
+            .iter()
+            .map(|s| (s.content.as_ref(), s.style == hl))
+            .collect();
+        assert_eq!(
+            parts,
+            [
+                ("Deep", true),
+                (" Learning for ", false),
+                ("deep", true),
+                (" nets", false)
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_matches_handles_multibyte_text() {
+        let hl = Style::default().add_modifier(Modifier::REVERSED);
+        let line = highlight_matches("Über café", "CAF", Style::default(), hl);
+        let text: Vec<&str> = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(text, ["Über ", "caf", "é"]);
+    }
+}
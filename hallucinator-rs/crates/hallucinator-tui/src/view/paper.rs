@@ -6,8 +6,9 @@ use ratatui::widgets::{Block, Borders, Cell, Gauge, Paragraph, Row, Table, Table
 
 use crate::app::{App, InputMode};
 use crate::model::paper::{PaperFilter, RefPhase};
+use crate::model::queue::PaperPhase;
 use crate::theme::Theme;
-use crate::view::{spinner_char, truncate};
+use crate::view::{highlight_matches, truncate};
 
 /// Render the Paper detail screen into the given area.
 /// `footer_area` is a full-width row below the main content + activity panel.
@@ -34,7 +35,7 @@ pub fn render_in(f: &mut Frame, app: &mut App, paper_index: usize, area: Rect, f
 
     render_breadcrumb(f, chunks[ci], &paper.filename, theme);
     ci += 1;
-    render_progress(f, chunks[ci], paper, app.tick, theme);
+    render_progress(f, chunks[ci], paper, app.spinner(), theme);
     ci += 1;
 
     if has_search {
@@ -43,7 +44,7 @@ pub fn render_in(f: &mut Frame, app: &mut App, paper_index: usize, area: Rect, f
     }
 
     let table_area = chunks[ci];
-    render_ref_table(f, table_area, app, paper_index);
+    app.last_table_offset = render_ref_table(f, table_area, app, paper_index);
     app.last_table_area = Some(table_area);
     ci += 1;
 
@@ -51,8 +52,7 @@ pub fn render_in(f: &mut Frame, app: &mut App, paper_index: usize, area: Rect, f
         render_preview(f, chunks[ci], app, paper_index);
     }
 
-    let paper = &app.papers[paper_index];
-    render_footer(f, footer_area, app, paper, theme);
+    render_footer(f, footer_area, app, paper_index, theme);
 }
 
 fn render_breadcrumb(f: &mut Frame, area: Rect, filename: &str, theme: &Theme) {
@@ -71,9 +71,30 @@ fn render_progress(
     f: &mut Frame,
     area: Rect,
     paper: &crate::model::queue::PaperState,
-    tick: usize,
+    spinner: char,
     theme: &Theme,
 ) {
+    if paper.phase == PaperPhase::Extracting {
+        let (ratio, label) = match &paper.extraction_progress {
+            Some(progress) => (
+                progress.fraction().clamp(0.0, 1.0),
+                format!("{} Extracting: {}", spinner, progress.label()),
+            ),
+            None => (0.0, format!("{} Extracting...", spinner)),
+        };
+        let gauge = Gauge::default()
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(theme.border_style()),
+            )
+            .gauge_style(Style::default().fg(theme.active))
+            .ratio(ratio)
+            .label(label);
+        f.render_widget(gauge, area);
+        return;
+    }
+
     let done = paper.completed_count();
     let total = paper.total_refs;
     let ratio = if total > 0 {
@@ -85,7 +106,7 @@ fn render_progress(
     let label = if done >= total && total > 0 {
         format!("\u{2713} {} / {} refs", done, total)
     } else {
-        format!("{} {} / {} refs", spinner_char(tick), done, total)
+        format!("{} {} / {} refs", spinner, done, total)
     };
 
     let gauge = Gauge::default()
@@ -120,7 +141,8 @@ fn render_search_bar(f: &mut Frame, area: Rect, app: &App, theme: &Theme) {
     f.render_widget(Paragraph::new(line), area);
 }
 
-fn render_ref_table(f: &mut Frame, area: Rect, app: &App, paper_index: usize) {
+/// Render the reference table, returning how many rows it scrolled past.
+fn render_ref_table(f: &mut Frame, area: Rect, app: &App, paper_index: usize) -> usize {
     let theme = &app.theme;
     let wide = area.width >= 80;
 
@@ -137,14 +159,14 @@ fn render_ref_table(f: &mut Frame, area: Rect, app: &App, paper_index: usize) {
     let refs = &app.ref_states[paper_index];
     let indices = app.paper_ref_indices(paper_index);
 
-    let rows: Vec<Row> = indices
+    let mut rows: Vec<Row> = indices
         .iter()
         .map(|&ri| {
             let rs = &refs[ri];
             let num = format!("{}", rs.index + 1);
             let title_display = match &rs.phase {
                 RefPhase::Checking | RefPhase::Retrying => {
-                    format!("{} {}", spinner_char(app.tick), rs.title)
+                    format!("{} {}", app.spinner(), rs.title)
                 }
                 _ => rs.title.clone(),
             };
@@ -175,7 +197,13 @@ fn render_ref_table(f: &mut Frame, area: Rect, app: &App, paper_index: usize) {
 
             let mut cells = vec![
                 Cell::from(num).style(phase_style),
-                Cell::from(title_text).style(phase_style),
+                Cell::from(highlight_matches(
+                    &title_text,
+                    &app.search_query,
+                    Style::default(),
+                    theme.search_match_style(),
+                ))
+                .style(phase_style),
                 Cell::from(verdict).style(verdict_style),
             ];
 
@@ -187,6 +215,20 @@ fn render_ref_table(f: &mut Frame, area: Rect, app: &App, paper_index: usize) {
         })
         .collect();
 
+    // Summary row for collapsed verified refs; sits past the cursor range so
+    // it can't be selected.
+    let hidden = app.hidden_verified_count(paper_index);
+    if hidden > 0 {
+        rows.push(Row::new(vec![
+            Cell::from(""),
+            Cell::from(format!("+{} verified hidden (c to show)", hidden)).style(
+                Style::default()
+                    .fg(theme.dim)
+                    .add_modifier(Modifier::ITALIC),
+            ),
+        ]));
+    }
+
     let widths = if wide {
         vec![
             Constraint::Length(4),
@@ -217,6 +259,7 @@ fn render_ref_table(f: &mut Frame, area: Rect, app: &App, paper_index: usize) {
     let mut state = TableState::default();
     state.select(Some(app.paper_cursor));
     f.render_stateful_widget(table, area, &mut state);
+    state.offset()
 }
 
 fn render_preview(f: &mut Frame, area: Rect, app: &App, paper_index: usize) {
@@ -257,13 +300,8 @@ fn render_preview(f: &mut Frame, area: Rect, app: &App, paper_index: usize) {
     f.render_widget(preview, area);
 }
 
-fn render_footer(
-    f: &mut Frame,
-    area: Rect,
-    app: &App,
-    paper: &crate::model::queue::PaperState,
-    theme: &Theme,
-) {
+fn render_footer(f: &mut Frame, area: Rect, app: &App, paper_index: usize, theme: &Theme) {
+    let paper = &app.papers[paper_index];
     let mut spans = vec![Span::styled(
         format!(
             " V:{} M:{} NF:{} R:{} ",
@@ -275,16 +313,46 @@ fn render_footer(
         Style::default().fg(theme.text),
     )];
 
+    // References dropped during extraction
+    if let Some(summary) = paper.skip_summary() {
+        spans.push(Span::styled(
+            format!("{} ", summary),
+            Style::default().fg(theme.dim),
+        ));
+    }
+
+    // Backoff in progress
+    if let Some(notice) = app.activity.throttle_notice() {
+        spans.push(Span::styled(
+            format!("[{}] ", notice),
+            Style::default()
+                .fg(theme.author_mismatch)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    if let Some(notice) = app.activity.slowdown_notice() {
+        spans.push(Span::styled(
+            format!("[{}] ", notice),
+            Style::default().fg(theme.author_mismatch),
+        ));
+    }
+
     // Filter indicator
     if app.paper_filter != PaperFilter::All {
         spans.push(Span::styled(
-            format!("[filter: {}] ", app.paper_filter.label()),
-            Style::default().fg(theme.active),
+            format!(
+                "[filter: {}, {} hidden \u{2014} f to show all] ",
+                app.paper_filter.label(),
+                app.filtered_out_count(paper_index)
+            ),
+            Style::default()
+                .fg(theme.active)
+                .add_modifier(Modifier::BOLD),
         ));
     }
 
     spans.push(Span::styled(
-        " | Space:FP reason  Enter:detail  Ctrl+r:retry  R:retry all  s:sort  f:filter  c:config  e:export  Esc:back",
+        " | Space:FP reason  Enter:detail  Ctrl+r:retry  R:retry all  s:sort  f:filter  c:collapse verified  ,:config  e:export  Esc:back",
         theme.footer_style(),
     ));
 
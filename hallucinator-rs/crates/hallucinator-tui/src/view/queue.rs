@@ -7,7 +7,7 @@ use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState};
 use crate::app::{App, InputMode};
 use crate::model::queue::{PaperPhase, PaperVerdict};
 use crate::theme::Theme;
-use crate::view::{spinner_char, truncate};
+use crate::view::{highlight_matches, truncate};
 
 /// Render the Queue screen into the given area.
 /// `footer_area` is a full-width row below the main content + activity panel.
@@ -40,7 +40,7 @@ pub fn render_in(f: &mut Frame, app: &mut App, area: Rect, footer_area: Rect) {
     }
 
     let table_area = chunks[chunk_idx];
-    render_table(f, table_area, app);
+    app.last_table_offset = render_table(f, table_area, app);
     app.last_table_area = Some(table_area);
 
     render_footer(f, footer_area, app);
@@ -63,6 +63,16 @@ fn render_header(f: &mut Frame, area: Rect, app: &App, theme: &Theme) {
         ));
     }
 
+    // Stale offline DBLP: recent papers may wrongly show as not found
+    if let Some(notice) = &app.dblp_stale_notice {
+        spans.push(Span::styled(
+            format!("  \u{26A0} {}, rebuild with update-dblp", notice),
+            Style::default()
+                .fg(theme.author_mismatch)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
     let header = Paragraph::new(Line::from(spans));
     f.render_widget(header, area);
 }
@@ -100,12 +110,12 @@ fn render_progress_bar(f: &mut Frame, area: Rect, app: &App, theme: &Theme) {
         let label = if app.extracted_count > 0 {
             format!(
                 " {} Extracting {} ({} extracted)...",
-                spinner_char(app.tick),
+                app.spinner(),
                 archive_name,
                 app.extracted_count,
             )
         } else {
-            format!(" {} Extracting {}...", spinner_char(app.tick), archive_name,)
+            format!(" {} Extracting {}...", app.spinner(), archive_name,)
         };
         spans.push(Span::styled(
             label,
@@ -137,7 +147,8 @@ fn render_search_bar(f: &mut Frame, area: Rect, app: &App, theme: &Theme) {
     f.render_widget(Paragraph::new(line), area);
 }
 
-fn render_table(f: &mut Frame, area: Rect, app: &App) {
+/// Render the paper table, returning how many rows it scrolled past.
+fn render_table(f: &mut Frame, area: Rect, app: &App) -> usize {
     let theme = &app.theme;
     let wide = area.width >= 80;
 
@@ -170,7 +181,12 @@ fn render_table(f: &mut Frame, area: Rect, app: &App) {
                 None => "",
             };
             let raw_name = format!("{}{}", verdict_badge, paper.filename);
-            let name = truncate(&raw_name, (area.width as usize).saturating_sub(40));
+            let name = highlight_matches(
+                &truncate(&raw_name, (area.width as usize).saturating_sub(40)),
+                &app.search_query,
+                Style::default(),
+                theme.search_match_style(),
+            );
 
             let name_style = match paper.verdict {
                 Some(PaperVerdict::Safe) => Style::default().fg(theme.verified),
@@ -185,16 +201,24 @@ fn render_table(f: &mut Frame, area: Rect, app: &App) {
                     if paper.retry_total > 0 {
                         format!(
                             "{} Retrying {}/{}",
-                            spinner_char(app.tick),
+                            app.spinner(),
                             paper.retry_done,
                             paper.retry_total
                         )
                     } else {
-                        format!("{} Retrying...", spinner_char(app.tick))
+                        format!("{} Retrying...", app.spinner())
                     }
                 }
-                PaperPhase::Checking | PaperPhase::Extracting => {
-                    format!("{} {}", spinner_char(app.tick), paper.phase.label())
+                PaperPhase::Extracting => match &paper.extraction_progress {
+                    Some(progress) => format!(
+                        "{} Extracting {:.0}%",
+                        app.spinner(),
+                        progress.fraction() * 100.0
+                    ),
+                    None => format!("{} {}", app.spinner(), paper.phase.label()),
+                },
+                PaperPhase::Checking => {
+                    format!("{} {}", app.spinner(), paper.phase.label())
                 }
                 _ => paper.phase.label().to_string(),
             };
@@ -295,6 +319,7 @@ fn render_table(f: &mut Frame, area: Rect, app: &App) {
     let mut state = TableState::default();
     state.select(Some(app.queue_cursor));
     f.render_stateful_widget(table, area, &mut state);
+    state.offset()
 }
 
 fn render_footer(f: &mut Frame, area: Rect, app: &App) {
@@ -312,7 +337,7 @@ fn render_footer(f: &mut Frame, area: Rect, app: &App) {
                 .add_modifier(Modifier::BOLD),
         ));
         spans.push(Span::styled(
-            " Space:mark  Enter:open  o:add  c:config  e:export  ?:help  q:quit",
+            " Space:mark  Enter:open  a:add  c:config  e:export  ?:help  q:quit",
             theme.footer_style(),
         ));
     } else if app.processing_started && !app.batch_complete {
@@ -324,15 +349,45 @@ fn render_footer(f: &mut Frame, area: Rect, app: &App) {
                 .add_modifier(Modifier::BOLD),
         ));
         spans.push(Span::styled(
-            " Space:mark  Enter:open  s:sort  f:filter  c:config  e:export  ?:help",
+            " Space:mark  Enter:open  x:cancel  s:sort  f:filter  c:config  e:export  ?:help",
             theme.footer_style(),
         ));
+        let eta = match app.eta() {
+            Some((remaining, rate)) => format!(
+                "  ETA {}:{:02}  {:.1} refs/s",
+                remaining.as_secs() / 60,
+                remaining.as_secs() % 60,
+                rate
+            ),
+            None => "  ETA \u{2014}".to_string(),
+        };
+        spans.push(Span::styled(eta, Style::default().fg(theme.dim)));
+        if let Some(notice) = app.activity.throttle_notice() {
+            spans.push(Span::styled(
+                format!("  {} ", notice),
+                Style::default()
+                    .fg(theme.author_mismatch)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        if let Some(notice) = app.activity.slowdown_notice() {
+            spans.push(Span::styled(
+                format!("  {} ", notice),
+                Style::default().fg(theme.author_mismatch),
+            ));
+        }
     } else {
         spans.push(Span::styled(
-            " Space:mark  Enter:open  s:sort  f:filter  o:add  c:config  e:export  ?:help  q:quit",
+            " Space:mark  Enter:open  s:sort  f:filter  S:summary  a:add  c:config  e/E:export all/problems  ?:help  q:quit",
             theme.footer_style(),
         ));
     }
+    if let Some(msg) = app.flash_message() {
+        spans.push(Span::styled(
+            format!("  {msg}"),
+            theme.footer_style().add_modifier(Modifier::BOLD),
+        ));
+    }
 
     let footer = Line::from(spans);
     f.render_widget(Paragraph::new(footer), area);
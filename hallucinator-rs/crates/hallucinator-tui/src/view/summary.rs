@@ -0,0 +1,175 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph};
+
+use crate::app::App;
+use crate::theme::Theme;
+use crate::view::truncate;
+
+/// Render the run Summary screen into the given area.
+/// `footer_area` is a full-width row below the main content + activity panel.
+pub fn render_in(f: &mut Frame, app: &App, area: Rect, footer_area: Rect) {
+    let theme = &app.theme;
+    let summary = app.summary();
+
+    let chunks = Layout::vertical([
+        Constraint::Length(1), // breadcrumb
+        Constraint::Min(5),    // content
+    ])
+    .split(area);
+
+    let breadcrumb = Line::from(vec![
+        Span::styled(" Summary ", theme.header_style()),
+        Span::styled(
+            format!(" {}/{} papers done", summary.papers_done, summary.papers),
+            Style::default().fg(theme.dim),
+        ),
+    ]);
+    f.render_widget(Paragraph::new(breadcrumb), chunks[0]);
+
+    let mut lines: Vec<Line> = Vec::new();
+
+    section_header(&mut lines, "REFERENCES", theme);
+    count_line(
+        &mut lines,
+        "Checked",
+        format!("{}", summary.checked),
+        theme.text,
+        theme,
+    );
+    count_line(
+        &mut lines,
+        "Verified",
+        format!("{} ({:.1}%)", summary.verified, summary.verified_pct()),
+        theme.verified,
+        theme,
+    );
+    count_line(
+        &mut lines,
+        "Not found",
+        format!("{}", summary.not_found),
+        theme.not_found,
+        theme,
+    );
+    count_line(
+        &mut lines,
+        "Author mismatch",
+        format!("{}", summary.author_mismatch),
+        theme.author_mismatch,
+        theme,
+    );
+    count_line(
+        &mut lines,
+        "Retracted",
+        format!("{}", summary.retracted),
+        theme.retracted,
+        theme,
+    );
+    count_line(
+        &mut lines,
+        "Skipped",
+        format!("{}", summary.skipped),
+        theme.dim,
+        theme,
+    );
+    lines.push(Line::from(""));
+
+    section_header(&mut lines, "MOST PROBLEMATIC PAPERS", theme);
+    if summary.worst_papers.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No problems found",
+            Style::default().fg(theme.verified),
+        )));
+    }
+    for &(paper_index, problems, pct) in &summary.worst_papers {
+        let name = truncate(&app.papers[paper_index].filename, 40);
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {name:<42}"), Style::default().fg(theme.text)),
+            Span::styled(
+                format!("{problems:>4} problems ({pct:.0}%)"),
+                Style::default().fg(theme.not_found),
+            ),
+        ]));
+    }
+    lines.push(Line::from(""));
+
+    section_header(&mut lines, "FAILING DATABASES", theme);
+    if summary.failing_dbs.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No failed queries",
+            Style::default().fg(theme.verified),
+        )));
+    }
+    for (db_name, failed, total) in &summary.failing_dbs {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {db_name:<18}"), Style::default().fg(theme.text)),
+            Span::styled(
+                format!("{failed:>4} of {total} queries failed"),
+                Style::default().fg(theme.author_mismatch),
+            ),
+        ]));
+    }
+    lines.push(Line::from(""));
+
+    section_header(&mut lines, "RUN", theme);
+    let cache = match (summary.cache, summary.cache_hit_pct()) {
+        (Some((hits, lookups)), Some(pct)) => format!("{pct:.1}% ({hits}/{lookups} lookups)"),
+        (Some(_), None) => "no lookups".to_string(),
+        (None, _) => "off".to_string(),
+    };
+    count_line(&mut lines, "Cache hit rate", cache, theme.text, theme);
+    if summary.duplicates > 0 {
+        count_line(
+            &mut lines,
+            "Lookups shared",
+            format!("{} duplicate titles", summary.duplicates),
+            theme.text,
+            theme,
+        );
+    }
+    let secs = summary.wall_time.as_secs();
+    count_line(
+        &mut lines,
+        "Wall time",
+        format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60),
+        theme.text,
+        theme,
+    );
+
+    let content = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.border_style()),
+    );
+    f.render_widget(content, chunks[1]);
+
+    let footer = Line::from(Span::styled(
+        " Esc:back  e:export  ?:help",
+        theme.footer_style(),
+    ));
+    f.render_widget(Paragraph::new(footer), footer_area);
+}
+
+fn section_header<'a>(lines: &mut Vec<Line<'a>>, title: &'a str, theme: &Theme) {
+    lines.push(Line::from(Span::styled(
+        format!("  {title}"),
+        Style::default()
+            .fg(theme.active)
+            .add_modifier(Modifier::BOLD),
+    )));
+}
+
+fn count_line<'a>(
+    lines: &mut Vec<Line<'a>>,
+    label: &'a str,
+    value: String,
+    color: ratatui::style::Color,
+    theme: &Theme,
+) {
+    lines.push(Line::from(vec![
+        Span::styled(format!("  {label:<18}"), Style::default().fg(theme.dim)),
+        Span::styled(value, Style::default().fg(color)),
+    ]));
+}
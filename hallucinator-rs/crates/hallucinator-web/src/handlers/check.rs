@@ -0,0 +1,179 @@
+use axum::Json;
+use axum::extract::{Multipart, Query, State};
+use axum::http::StatusCode;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use std::convert::Infallible;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_util::sync::CancellationToken;
+
+use hallucinator_core::{CheckStats, Config, Reference, ValidationResult};
+use hallucinator_pdf::{ExtractionResult, PdfError};
+
+use crate::handlers::stream::{build_config, send_progress_event};
+use crate::models::{
+    CheckQuery, CheckResponse, ResultJson, StatsJson, StreamQuery, StreamTicket, sse_event,
+};
+use crate::state::{AppState, PendingCheck};
+use crate::upload::{self, FileType};
+
+/// `POST /check`: validate the references of one uploaded PDF and answer
+/// with all results at once.
+///
+/// Takes the same multipart fields as `/analyze/stream`. Archives are
+/// rejected here; use the streaming endpoint for those. With `?stream=true`
+/// the references are only extracted, and the answer (202) holds the id to
+/// run the check from with `GET /check/stream`.
+pub async fn check(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<CheckQuery>,
+    multipart: Multipart,
+) -> Response {
+    let (refs, config) = match extract_upload(&state, multipart).await {
+        Ok(upload) => upload,
+        Err(e) => return error_response(e),
+    };
+    if query.stream {
+        let id = state.pending_checks.insert(refs, config);
+        let ticket = StreamTicket {
+            stream_url: format!("/check/stream?id={}", id),
+            id,
+        };
+        return (StatusCode::ACCEPTED, Json(ticket)).into_response();
+    }
+
+    let submitted = refs.len();
+    // A dropped request (client gone) cancels the run.
+    let cancel = CancellationToken::new();
+    let _guard = cancel.clone().drop_guard();
+    let results = hallucinator_core::check_references_with_context(
+        refs,
+        config,
+        state.check_context.clone(),
+        |_| {},
+        cancel,
+    )
+    .await;
+    Json(check_response(&results, submitted)).into_response()
+}
+
+/// `GET /check/stream?id=...`: run the check uploaded with
+/// `POST /check?stream=true`, streaming its progress as Server-Sent Events.
+///
+/// Each `checking` and `result` event carries the reference's `index` and
+/// the run's `total`, for a progress bar; a final `complete` event holds the
+/// body `POST /check` answers with. Closing the connection cancels the run.
+pub async fn check_stream(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<StreamQuery>,
+) -> Response {
+    let Some(pending) = state.pending_checks.take(&query.id) else {
+        return error_response((
+            StatusCode::NOT_FOUND,
+            format!(
+                "No upload {} waiting to be checked; upload it with POST /check?stream=true",
+                query.id
+            ),
+        ));
+    };
+    let (tx, rx) = mpsc::channel::<Result<Event, Infallible>>(64);
+    tokio::spawn(stream_check(state, pending, tx));
+    Sse::new(ReceiverStream::new(rx))
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+async fn stream_check(
+    state: Arc<AppState>,
+    pending: PendingCheck,
+    tx: mpsc::Sender<Result<Event, Infallible>>,
+) {
+    let submitted = pending.references.len();
+    let cancel = CancellationToken::new();
+    let tx_progress = tx.clone();
+    let run = hallucinator_core::check_references_with_context(
+        pending.references,
+        pending.config,
+        state.check_context.clone(),
+        move |event| send_progress_event(&tx_progress, &event, None),
+        cancel.clone(),
+    );
+
+    // Stop querying the databases once the client is gone
+    let results = tokio::select! {
+        results = run => results,
+        _ = tx.closed() => {
+            cancel.cancel();
+            return;
+        }
+    };
+    let complete = sse_event("complete", &check_response(&results, submitted));
+    let _ = tx.send(Ok(complete)).await;
+}
+
+/// Read the uploaded PDF's references, with the request's config.
+async fn extract_upload(
+    state: &AppState,
+    multipart: Multipart,
+) -> Result<(Vec<Reference>, Config), (StatusCode, String)> {
+    let fields = upload::parse_multipart(multipart)
+        .await
+        .map_err(|e| (e.status, e.message))?;
+    if !matches!(fields.file.file_type, FileType::Pdf) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "/check takes a single PDF; use /analyze/stream for archives".to_string(),
+        ));
+    }
+
+    let temp_dir = tempfile::tempdir().map_err(internal)?;
+    let pdf_path = temp_dir.path().join("upload.pdf");
+    std::fs::write(&pdf_path, &fields.file.data).map_err(internal)?;
+
+    let extraction = extract_blocking(pdf_path).await.map_err(|e| match e {
+        ExtractError::Pdf(PdfError::NoReferencesSection) => (
+            StatusCode::BAD_REQUEST,
+            format!("No references section found in {}", fields.file.filename),
+        ),
+        ExtractError::Pdf(e) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("{}: PDF extraction failed: {}", fields.file.filename, e),
+        ),
+        ExtractError::Join(e) => internal(e),
+    })?;
+    drop(temp_dir);
+
+    Ok((extraction.references, build_config(state, &fields)))
+}
+
+fn check_response(results: &[ValidationResult], submitted: usize) -> CheckResponse {
+    let stats = CheckStats::from_results(results, submitted.saturating_sub(results.len()));
+    CheckResponse {
+        results: results.iter().map(ResultJson::from).collect(),
+        stats: StatsJson::from(&stats),
+    }
+}
+
+fn error_response((status, message): (StatusCode, String)) -> Response {
+    (status, Json(serde_json::json!({ "error": message }))).into_response()
+}
+
+enum ExtractError {
+    Pdf(PdfError),
+    Join(tokio::task::JoinError),
+}
+
+/// Extract references on the blocking pool (MuPDF is not async), keeping
+/// the [`PdfError`] so the caller can pick the status code.
+async fn extract_blocking(path: std::path::PathBuf) -> Result<ExtractionResult, ExtractError> {
+    tokio::task::spawn_blocking(move || hallucinator_pdf::extract_references(&path))
+        .await
+        .map_err(ExtractError::Join)?
+        .map_err(ExtractError::Pdf)
+}
+
+fn internal(e: impl std::fmt::Display) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+}
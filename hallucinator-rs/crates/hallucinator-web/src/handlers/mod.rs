@@ -1,3 +1,4 @@
+pub mod check;
 pub mod index;
 pub mod retry;
 pub mod stream;
@@ -29,16 +29,15 @@ pub async fn retry(
             .into_response();
     }
 
+    let base = &state.config;
     let config = Config {
-        openalex_key: req.openalex_key.clone(),
-        s2_api_key: req.s2_api_key.clone(),
-        dblp_offline_path: state.dblp_offline_path.clone(),
-        dblp_offline_db: state.dblp_offline_db.clone(),
-        check_openalex_authors: req.check_openalex_authors,
-        ..Config::default()
+        openalex_key: req.openalex_key.clone().or_else(|| base.openalex_key.clone()),
+        s2_api_key: req.s2_api_key.clone().or_else(|| base.s2_api_key.clone()),
+        check_openalex_authors: req.check_openalex_authors || base.check_openalex_authors,
+        ..base.clone()
     };
 
-    let client = reqwest::Client::new();
+    let client = hallucinator_core::context::build_http_client(&config);
 
     let result = hallucinator_core::query_all_databases(
         &req.title,
@@ -48,6 +47,7 @@ pub async fn retry(
         true, // longer timeout for retries
         Some(&req.failed_dbs),
         None,
+        None,
     )
     .await;
 
@@ -70,7 +70,7 @@ pub async fn retry(
         found_authors: result.found_authors,
         paper_url: result.paper_url,
         error_type,
-        failed_dbs: result.failed_dbs,
+        failed_dbs: result.failed_dbs.into_iter().map(|f| f.name).collect(),
     })
     .into_response()
 }
@@ -339,21 +339,31 @@ async fn extract_pdf_blocking(path: &std::path::Path) -> Result<ExtractionResult
     .map_err(|e| format!("Task join error: {}", e))?
 }
 
-/// Build a Config from AppState and FormFields.
-fn build_config(state: &AppState, fields: &FormFields) -> Config {
+/// Build a Config from AppState and FormFields: the form's keys and
+/// database choices over the server's settings.
+pub(crate) fn build_config(state: &AppState, fields: &FormFields) -> Config {
+    let base = &state.config;
     Config {
-        openalex_key: fields.openalex_key.clone(),
-        s2_api_key: fields.s2_api_key.clone(),
-        dblp_offline_path: state.dblp_offline_path.clone(),
-        dblp_offline_db: state.dblp_offline_db.clone(),
-        disabled_dbs: fields.disabled_dbs.clone(),
-        check_openalex_authors: fields.check_openalex_authors,
-        ..Config::default()
+        openalex_key: fields
+            .openalex_key
+            .clone()
+            .or_else(|| base.openalex_key.clone()),
+        s2_api_key: fields
+            .s2_api_key
+            .clone()
+            .or_else(|| base.s2_api_key.clone()),
+        disabled_dbs: if fields.disabled_dbs.is_empty() {
+            base.disabled_dbs.clone()
+        } else {
+            fields.disabled_dbs.clone()
+        },
+        check_openalex_authors: fields.check_openalex_authors || base.check_openalex_authors,
+        ..base.clone()
     }
 }
 
 /// Send a progress event from the sync callback via try_send (non-blocking).
-fn send_progress_event(
+pub(crate) fn send_progress_event(
     tx: &mpsc::Sender<Result<Event, Infallible>>,
     event: &ProgressEvent,
     filename: Option<&str>,
@@ -406,7 +416,13 @@ fn send_progress_event(
             sse_event("retry_pass", &RetryPassEvent { count: *count })
         }
         ProgressEvent::Retrying { .. }
-        | ProgressEvent::DatabaseQueryComplete { .. } => {
+        | ProgressEvent::Duplicate { .. }
+        | ProgressEvent::DatabaseQueryComplete { .. }
+        | ProgressEvent::RateLimitWait { .. }
+        | ProgressEvent::RateLimitRetry { .. }
+        | ProgressEvent::RateLimited { .. }
+        | ProgressEvent::RateLimitStatus { .. }
+        | ProgressEvent::ConcurrencyChanged { .. } => {
             // Not sent via SSE (detail only needed in TUI)
             return;
         }
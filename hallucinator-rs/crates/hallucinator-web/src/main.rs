@@ -13,18 +13,19 @@ use state::AppState;
 async fn main() -> anyhow::Result<()> {
     dotenvy::dotenv().ok();
 
+    // Server-wide settings (API keys, databases, timeouts, ...) from the
+    // environment
+    let mut config = hallucinator_core::Config::from_env().map_err(|e| anyhow::anyhow!(e))?;
+
     // Load DBLP offline database if configured
-    let dblp_offline_path = std::env::var("DBLP_OFFLINE_PATH").ok();
-    let mut dblp_offline_db = None;
     let mut dblp_offline_path_display = String::new();
 
-    if let Some(ref path_str) = dblp_offline_path {
-        let path = std::path::PathBuf::from(path_str);
+    if let Some(path) = config.dblp_offline_path.clone() {
+        let path_str = path.display().to_string();
         if path.exists() {
             match hallucinator_dblp::DblpDatabase::open(&path) {
                 Ok(db) => {
-                    // Check staleness (30 days)
-                    if let Ok(staleness) = db.check_staleness(30)
+                    if let Ok(staleness) = db.check_staleness(config.dblp_max_age_days)
                         && staleness.is_stale
                     {
                         eprintln!(
@@ -32,9 +33,9 @@ async fn main() -> anyhow::Result<()> {
                             staleness.age_days.unwrap_or(0)
                         );
                     }
-                    dblp_offline_path_display = path_str.clone();
-                    dblp_offline_db = Some(Arc::new(Mutex::new(db)));
                     println!("DBLP offline database loaded: {}", path_str);
+                    dblp_offline_path_display = path_str;
+                    config.dblp_offline_db = Some(Arc::new(Mutex::new(db)));
                 }
                 Err(e) => {
                     eprintln!(
@@ -48,11 +49,51 @@ async fn main() -> anyhow::Result<()> {
         }
     }
 
+    // Load ACL Anthology offline database if configured
+    if let Some(path) = config.acl_offline_path.clone() {
+        let path_str = path.display().to_string();
+        if path.exists() {
+            match hallucinator_acl::AclDatabase::open(&path) {
+                Ok(db) => {
+                    if let Ok(staleness) = db.check_staleness(30)
+                        && staleness.is_stale
+                    {
+                        eprintln!(
+                            "Warning: ACL offline database is {} days old. Consider updating with: hallucinator-cli update-acl <path>",
+                            staleness.age_days.unwrap_or(0)
+                        );
+                    }
+                    println!("ACL offline database loaded: {}", path_str);
+                    config.acl_offline_db = Some(Arc::new(Mutex::new(db)));
+                }
+                Err(e) => {
+                    eprintln!(
+                        "Warning: Failed to open ACL database at {}: {}",
+                        path_str, e
+                    );
+                }
+            }
+        } else {
+            eprintln!("Warning: ACL database file not found at {}", path_str);
+        }
+    }
+
+    // Upload cap for POST /check (MB)
+    let max_upload_mb = match std::env::var("HALLUCINATOR_MAX_UPLOAD_MB") {
+        Ok(v) => v
+            .parse::<usize>()
+            .map_err(|_| anyhow::anyhow!("Invalid HALLUCINATOR_MAX_UPLOAD_MB: {}", v))?,
+        Err(_) => 50,
+    };
+
     let state = Arc::new(AppState {
-        dblp_offline_path: dblp_offline_path.map(std::path::PathBuf::from),
-        dblp_offline_db,
+        check_context: hallucinator_core::CheckContext::new(&config),
+        config,
         dblp_offline_path_display,
+        max_upload_bytes: max_upload_mb * 1024 * 1024,
+        pending_checks: state::PendingChecks::default(),
     });
+    let check_limit = axum::extract::DefaultBodyLimit::max(state.max_upload_bytes);
 
     // Allow large file uploads (500MB)
     let body_limit = axum::extract::DefaultBodyLimit::max(500 * 1024 * 1024);
@@ -63,6 +104,14 @@ async fn main() -> anyhow::Result<()> {
             "/analyze/stream",
             axum::routing::post(handlers::stream::stream),
         )
+        .route(
+            "/check",
+            axum::routing::post(handlers::check::check).layer(check_limit),
+        )
+        .route(
+            "/check/stream",
+            axum::routing::get(handlers::check::check_stream),
+        )
         .route("/retry", axum::routing::post(handlers::retry::retry))
         .route("/static/logo.png", axum::routing::get(template::serve_logo))
         .layer(body_limit)
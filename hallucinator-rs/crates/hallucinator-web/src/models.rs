@@ -1,5 +1,5 @@
 use axum::response::sse::Event;
-use hallucinator_core::{SkipStats, Status, ValidationResult};
+use hallucinator_core::{CheckStats, SkipStats, Status, ValidationResult};
 use serde::{Deserialize, Serialize};
 
 // ── Result JSON (matches Python's per-reference JSON shape) ─────────────
@@ -37,6 +37,10 @@ pub struct ArxivInfoJson {
     pub status: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub arxiv_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cited_version: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_version: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -64,7 +68,14 @@ impl From<&ValidationResult> for ResultJson {
 
         let doi_info = r.doi_info.as_ref().map(|d| DoiInfoJson {
             doi: d.doi.clone(),
-            status: if d.valid { "verified" } else { "invalid" }.to_string(),
+            status: if d.title_mismatch {
+                "title_mismatch"
+            } else if d.valid {
+                "verified"
+            } else {
+                "invalid"
+            }
+            .to_string(),
             doi_title: d.title.clone(),
         });
 
@@ -72,6 +83,8 @@ impl From<&ValidationResult> for ResultJson {
             arxiv_id: a.arxiv_id.clone(),
             status: if a.valid { "verified" } else { "invalid" }.to_string(),
             arxiv_title: a.title.clone(),
+            cited_version: a.cited_version,
+            latest_version: a.latest_version,
         });
 
         let retraction_info = r.retraction_info.as_ref().map(|ri| RetractionInfoJson {
@@ -89,7 +102,7 @@ impl From<&ValidationResult> for ResultJson {
             found_authors: r.found_authors.clone(),
             paper_url: r.paper_url.clone(),
             error_type,
-            failed_dbs: r.failed_dbs.clone(),
+            failed_dbs: r.failed_db_names(),
             doi_info,
             arxiv_info,
             retraction_info,
@@ -141,6 +154,60 @@ impl SummaryJson {
     }
 }
 
+// ── Check DTOs ──────────────────────────────────────────────────────────
+
+/// Response body of `POST /check`.
+#[derive(Serialize)]
+pub struct CheckResponse {
+    pub results: Vec<ResultJson>,
+    pub stats: StatsJson,
+}
+
+/// Query string of `POST /check`.
+#[derive(Deserialize, Default)]
+pub struct CheckQuery {
+    /// Answer with a [`StreamTicket`] instead of running the check.
+    #[serde(default)]
+    pub stream: bool,
+}
+
+/// Response body of `POST /check?stream=true`: where to stream the upload's
+/// check from.
+#[derive(Serialize)]
+pub struct StreamTicket {
+    pub id: String,
+    pub stream_url: String,
+}
+
+/// Query string of `GET /check/stream`.
+#[derive(Deserialize)]
+pub struct StreamQuery {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StatsJson {
+    pub total: usize,
+    pub verified: usize,
+    pub not_found: usize,
+    pub author_mismatch: usize,
+    pub retracted: usize,
+    pub skipped: usize,
+}
+
+impl From<&CheckStats> for StatsJson {
+    fn from(s: &CheckStats) -> Self {
+        StatsJson {
+            total: s.total,
+            verified: s.verified,
+            not_found: s.not_found,
+            author_mismatch: s.author_mismatch,
+            retracted: s.retracted,
+            skipped: s.skipped,
+        }
+    }
+}
+
 // ── SSE Event Structs ───────────────────────────────────────────────────
 
 #[derive(Serialize)]
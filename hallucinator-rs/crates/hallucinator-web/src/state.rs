@@ -1,10 +1,76 @@
-use hallucinator_dblp::DblpDatabase;
-use std::path::PathBuf;
-use std::sync::{Arc, Mutex};
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use hallucinator_core::{CheckContext, Config, Reference};
 
 /// Shared application state accessible from all handlers.
 pub struct AppState {
-    pub dblp_offline_path: Option<PathBuf>,
-    pub dblp_offline_db: Option<Arc<Mutex<DblpDatabase>>>,
+    /// Server-wide settings from the environment, with the offline DBLP
+    /// database opened; each request's form fields are applied on top.
+    pub config: Config,
     pub dblp_offline_path_display: String,
+    /// HTTP client, query cache and rate limiters shared by `/check` requests.
+    pub check_context: CheckContext,
+    /// Largest accepted `/check` upload, in bytes.
+    pub max_upload_bytes: usize,
+    /// Uploads from `POST /check?stream=true` waiting for their
+    /// `GET /check/stream`.
+    pub pending_checks: PendingChecks,
+}
+
+/// How long an upload waits for its `GET /check/stream` before it is dropped.
+pub const PENDING_CHECK_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// A check whose references have been extracted but not yet run.
+pub struct PendingCheck {
+    pub references: Vec<Reference>,
+    pub config: Config,
+    uploaded: Instant,
+}
+
+/// Checks uploaded for streaming, by id. Each id is streamed once.
+#[derive(Default)]
+pub struct PendingChecks {
+    checks: Mutex<HashMap<String, PendingCheck>>,
+}
+
+impl PendingChecks {
+    /// Keep `references` for a later stream and return its id. Uploads older
+    /// than [`PENDING_CHECK_TTL`] are dropped on the way.
+    pub fn insert(&self, references: Vec<Reference>, config: Config) -> String {
+        let id = new_id();
+        let mut checks = self.checks.lock().unwrap_or_else(|e| e.into_inner());
+        checks.retain(|_, check| check.uploaded.elapsed() < PENDING_CHECK_TTL);
+        checks.insert(
+            id.clone(),
+            PendingCheck {
+                references,
+                config,
+                uploaded: Instant::now(),
+            },
+        );
+        id
+    }
+
+    /// Take the check uploaded as `id`, unless it was already streamed or
+    /// has expired.
+    pub fn take(&self, id: &str) -> Option<PendingCheck> {
+        let mut checks = self.checks.lock().unwrap_or_else(|e| e.into_inner());
+        checks
+            .remove(id)
+            .filter(|check| check.uploaded.elapsed() < PENDING_CHECK_TTL)
+    }
+}
+
+/// 128 random bits as hex, so one client cannot guess another's id.
+/// `RandomState`'s SipHash keys are seeded from the OS's randomness.
+fn new_id() -> String {
+    let word = || {
+        std::collections::hash_map::RandomState::new()
+            .build_hasher()
+            .finish()
+    };
+    format!("{:016x}{:016x}", word(), word())
 }
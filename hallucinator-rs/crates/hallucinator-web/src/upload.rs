@@ -1,4 +1,6 @@
 use axum::extract::Multipart;
+use axum::extract::multipart::MultipartError;
+use axum::http::StatusCode;
 
 /// The type of uploaded file.
 #[derive(Debug)]
@@ -24,8 +26,39 @@ pub struct FormFields {
     pub disabled_dbs: Vec<String>,
 }
 
+/// A rejected upload, with the HTTP status it should be answered with.
+#[derive(Debug)]
+pub struct UploadError {
+    pub status: StatusCode,
+    pub message: String,
+}
+
+impl UploadError {
+    fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::BAD_REQUEST,
+            message: message.into(),
+        }
+    }
+
+    /// Keep the multipart error's own status, so an upload over the body
+    /// limit is reported as 413.
+    fn multipart(context: &str, e: MultipartError) -> Self {
+        Self {
+            status: e.status(),
+            message: format!("{}: {}", context, e.body_text()),
+        }
+    }
+}
+
+impl From<UploadError> for String {
+    fn from(e: UploadError) -> Self {
+        e.message
+    }
+}
+
 /// Parse a multipart form upload into structured form fields.
-pub async fn parse_multipart(mut multipart: Multipart) -> Result<FormFields, String> {
+pub async fn parse_multipart(mut multipart: Multipart) -> Result<FormFields, UploadError> {
     let mut file: Option<UploadedFile> = None;
     let mut openalex_key: Option<String> = None;
     let mut s2_api_key: Option<String> = None;
@@ -35,7 +68,7 @@ pub async fn parse_multipart(mut multipart: Multipart) -> Result<FormFields, Str
     while let Some(field) = multipart
         .next_field()
         .await
-        .map_err(|e| format!("Failed to read form field: {}", e))?
+        .map_err(|e| UploadError::multipart("Failed to read form field", e))?
     {
         let name = field.name().unwrap_or("").to_string();
 
@@ -45,10 +78,11 @@ pub async fn parse_multipart(mut multipart: Multipart) -> Result<FormFields, Str
                 let data = field
                     .bytes()
                     .await
-                    .map_err(|e| format!("Failed to read file data: {}", e))?
+                    .map_err(|e| UploadError::multipart("Failed to read file data", e))?
                     .to_vec();
 
-                let file_type = detect_file_type(&filename, &data)?;
+                let file_type =
+                    detect_file_type(&filename, &data).map_err(UploadError::bad_request)?;
 
                 file = Some(UploadedFile {
                     filename,
@@ -60,7 +94,7 @@ pub async fn parse_multipart(mut multipart: Multipart) -> Result<FormFields, Str
                 let val = field
                     .text()
                     .await
-                    .map_err(|e| format!("Failed to read openalex_key: {}", e))?;
+                    .map_err(|e| UploadError::multipart("Failed to read openalex_key", e))?;
                 if !val.is_empty() {
                     openalex_key = Some(val);
                 }
@@ -69,23 +103,22 @@ pub async fn parse_multipart(mut multipart: Multipart) -> Result<FormFields, Str
                 let val = field
                     .text()
                     .await
-                    .map_err(|e| format!("Failed to read s2_api_key: {}", e))?;
+                    .map_err(|e| UploadError::multipart("Failed to read s2_api_key", e))?;
                 if !val.is_empty() {
                     s2_api_key = Some(val);
                 }
             }
             "check_openalex_authors" => {
-                let val = field
-                    .text()
-                    .await
-                    .map_err(|e| format!("Failed to read check_openalex_authors: {}", e))?;
+                let val = field.text().await.map_err(|e| {
+                    UploadError::multipart("Failed to read check_openalex_authors", e)
+                })?;
                 check_openalex_authors = val == "true";
             }
             "disabled_dbs" => {
                 let val = field
                     .text()
                     .await
-                    .map_err(|e| format!("Failed to read disabled_dbs: {}", e))?;
+                    .map_err(|e| UploadError::multipart("Failed to read disabled_dbs", e))?;
                 if !val.is_empty()
                     && let Ok(dbs) = serde_json::from_str::<Vec<String>>(&val)
                 {
@@ -99,7 +132,7 @@ pub async fn parse_multipart(mut multipart: Multipart) -> Result<FormFields, Str
         }
     }
 
-    let file = file.ok_or("No file uploaded")?;
+    let file = file.ok_or_else(|| UploadError::bad_request("No file uploaded"))?;
 
     Ok(FormFields {
         file,
@@ -101,6 +101,12 @@ class PdfExtractor:
 
     # ── Config methods (forwarded to native) ──
 
+    def add_section_heading(self, heading):
+        self._native.add_section_heading(heading)
+
+    def set_section_headings(self, headings):
+        self._native.set_section_headings(headings)
+
     def add_venue_cutoff_pattern(self, pattern):
         self._native.add_venue_cutoff_pattern(pattern)
 
@@ -221,6 +227,8 @@ class PdfExtractor:
                     url_only += 1
                 elif skip_reason == "short_title":
                     short_title += 1
+                elif skip_reason == "no_title":
+                    no_title += 1
             elif ref is not None:
                 if ref.title is None:
                     no_title += 1
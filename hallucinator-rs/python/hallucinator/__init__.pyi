@@ -35,6 +35,8 @@ class PdfExtractor:
     max_authors: int
 
     # Config methods
+    def add_section_heading(self, heading: str) -> None: ...
+    def set_section_headings(self, headings: list[str]) -> None: ...
     def add_venue_cutoff_pattern(self, pattern: str) -> None: ...
     def set_venue_cutoff_patterns(self, patterns: list[str]) -> None: ...
     def add_quote_pattern(self, pattern: str) -> None: ...
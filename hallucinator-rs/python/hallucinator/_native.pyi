@@ -15,6 +15,8 @@ class Reference:
     def doi(self) -> Optional[str]: ...
     @property
     def arxiv_id(self) -> Optional[str]: ...
+    @property
+    def arxiv_version(self) -> Optional[int]: ...
 
 class SkipStats:
     """Statistics about references that were skipped during extraction."""
@@ -26,6 +28,8 @@ class SkipStats:
     @property
     def no_title(self) -> int: ...
     @property
+    def unresolved_footnote(self) -> int: ...
+    @property
     def no_authors(self) -> int: ...
     @property
     def total_raw(self) -> int: ...
@@ -37,6 +41,8 @@ class ExtractionResult:
     def references(self) -> list[Reference]: ...
     @property
     def skip_stats(self) -> SkipStats: ...
+    @property
+    def ocr_used(self) -> bool: ...
     def __len__(self) -> int: ...
     @staticmethod
     def _from_parts(
@@ -67,6 +73,8 @@ class NativePdfExtractor:
     min_title_words: int
     max_authors: int
 
+    def add_section_heading(self, heading: str) -> None: ...
+    def set_section_headings(self, headings: list[str]) -> None: ...
     def add_venue_cutoff_pattern(self, pattern: str) -> None: ...
     def set_venue_cutoff_patterns(self, patterns: list[str]) -> None: ...
     def add_quote_pattern(self, pattern: str) -> None: ...
@@ -96,14 +104,39 @@ class ValidatorConfig:
 
     openalex_key: Optional[str]
     s2_api_key: Optional[str]
+    ncbi_api_key: Optional[str]
+    core_api_key: Optional[str]
     dblp_offline_path: Optional[str]
+    dblp_max_age_days: int
     acl_offline_path: Optional[str]
+    local_bib_path: Optional[str]
+    retraction_db_path: Optional[str]
+    retraction_max_age_days: int
     max_concurrent_refs: int
+    dedup: bool
+    adaptive_concurrency: bool
     db_timeout_secs: int
     db_timeout_short_secs: int
+    db_timeouts: dict[str, int]
+    max_retry_passes: int
+    max_references: Optional[int]
+    run_deadline: Optional[float]
     disabled_dbs: list[str]
+    only_dbs: list[str]
+    per_db_threshold: dict[str, float]
     check_openalex_authors: bool
+    author_match_min_overlap: float
+    require_first_author_match: bool
+    author_match_threshold: float
+    min_match_score: float
+    consensus_mode: bool
+    consensus_min: int
+    strict_title: bool
+    orcid_check: bool
     crossref_mailto: Optional[str]
+    user_agent: Optional[str]
+    proxy: Optional[str]
+    extra_ca_cert: Optional[str]
 
 class Validator:
     """Validates references against academic databases."""
@@ -130,14 +163,30 @@ class ValidationResult:
     @property
     def status(self) -> str: ...
     @property
+    def reason(self) -> Optional[str]: ...
+    @property
     def source(self) -> Optional[str]: ...
     @property
+    def agreeing_dbs(self) -> list[str]: ...
+    @property
     def found_authors(self) -> list[str]: ...
     @property
+    def match_score(self) -> Optional[float]: ...
+    @property
+    def title_near_miss(self) -> Optional[tuple[str, str, float]]: ...
+    @property
+    def venue(self) -> Optional[str]: ...
+    @property
+    def year(self) -> Optional[int]: ...
+    @property
+    def orcid(self) -> Optional[str]: ...
+    @property
     def paper_url(self) -> Optional[str]: ...
     @property
     def failed_dbs(self) -> list[str]: ...
     @property
+    def failed_db_reasons(self) -> list[tuple[str, str]]: ...
+    @property
     def db_results(self) -> list["DbResult"]: ...
     @property
     def doi_info(self) -> Optional["DoiInfo"]: ...
@@ -156,8 +205,12 @@ class DbResult:
     @property
     def elapsed_ms(self) -> Optional[float]: ...
     @property
+    def found_title(self) -> Optional[str]: ...
+    @property
     def found_authors(self) -> list[str]: ...
     @property
+    def score(self) -> Optional[float]: ...
+    @property
     def paper_url(self) -> Optional[str]: ...
 
 class DoiInfo:
@@ -168,7 +221,11 @@ class DoiInfo:
     @property
     def valid(self) -> bool: ...
     @property
+    def structurally_valid(self) -> bool: ...
+    @property
     def title(self) -> Optional[str]: ...
+    @property
+    def title_mismatch(self) -> bool: ...
 
 class ArxivInfo:
     """Information about an arXiv lookup."""
@@ -179,6 +236,10 @@ class ArxivInfo:
     def valid(self) -> bool: ...
     @property
     def title(self) -> Optional[str]: ...
+    @property
+    def cited_version(self) -> Optional[int]: ...
+    @property
+    def latest_version(self) -> Optional[int]: ...
 
 class RetractionInfo:
     """Information about a retraction check."""
@@ -219,6 +280,14 @@ class ProgressEvent:
     def db_status(self) -> Optional[str]: ...
     @property
     def elapsed_ms(self) -> Optional[float]: ...
+    @property
+    def retry_after_ms(self) -> Optional[float]: ...
+    @property
+    def attempt(self) -> Optional[int]: ...
+    @property
+    def slowdown_factors(self) -> Optional[dict[str, int]]: ...
+    @property
+    def concurrency(self) -> Optional[int]: ...
 
 class CheckStats:
     """Summary statistics for a validation run."""
@@ -182,6 +182,23 @@ def test_add_compound_suffix():
     assert "AI-powered" in ref.title
 
 
+# ── Config: section headings ──
+
+
+def test_add_section_heading():
+    text = (
+        "Body text citing [1].\n"
+        "7 Quellenangaben\n"
+        "[1] J. Smith, \"Detecting Fabricated Citations in Scientific Papers,\" "
+        "in Proc. ACL, 2023.\n"
+        + "Further tables and figures follow in this appendix.\n" * 40
+    )
+    ext = PdfExtractor()
+    assert ext.find_section(text) is None
+    ext.add_section_heading("Quellenangaben")
+    assert ext.find_section(text).lstrip().startswith("[1] J. Smith")
+
+
 # ── Config: venue cutoff patterns ──
 
 